@@ -567,6 +567,7 @@ pub struct AddTokenManagerFlowLimiter<'info> {
     #[account(mut)]
     payer: Signer<'info>,
     payer_roles_pda: AccountInfo<'info>,
+    #[account(mut)]
     token_manager_pda: AccountInfo<'info>,
     flow_limiter: AccountInfo<'info>,
     #[account(mut)]
@@ -604,6 +605,7 @@ pub struct TransferTokenManagerOperatorship<'info> {
     payer: Signer<'info>,
     #[account(mut)]
     payer_roles_pda: AccountInfo<'info>,
+    #[account(mut)]
     token_manager_pda: AccountInfo<'info>,
     to: AccountInfo<'info>,
     #[account(mut)]
@@ -633,6 +635,7 @@ pub struct AcceptTokenManagerOperatorship<'info> {
     payer: Signer<'info>,
     #[account(mut)]
     payer_roles_pda: AccountInfo<'info>,
+    #[account(mut)]
     token_manager_pda: AccountInfo<'info>,
     from: AccountInfo<'info>,
     #[account(mut)]
@@ -648,6 +651,7 @@ pub struct HandoverMintAuthority<'info> {
     #[account(mut)]
     mint: AccountInfo<'info>,
     its_root_pda: AccountInfo<'info>,
+    #[account(mut)]
     token_manager_pda: AccountInfo<'info>,
     #[account(mut)]
     minter_roles_pda: AccountInfo<'info>,
@@ -676,6 +680,7 @@ pub struct TransferInterchainTokenMintership<'info> {
     payer: Signer<'info>,
     #[account(mut)]
     payer_roles_pda: AccountInfo<'info>,
+    #[account(mut)]
     token_manager_pda: AccountInfo<'info>,
     to: AccountInfo<'info>,
     #[account(mut)]
@@ -705,6 +710,7 @@ pub struct AcceptInterchainTokenMintership<'info> {
     payer: Signer<'info>,
     #[account(mut)]
     payer_roles_pda: AccountInfo<'info>,
+    #[account(mut)]
     token_manager_pda: AccountInfo<'info>,
     from: AccountInfo<'info>,
     #[account(mut)]
@@ -10,6 +10,7 @@ import {
   readVecU8,
   readU8,
   readU64LE,
+  readBool,
   MissingDataError,
   TrailingSegmentsError,
   BaseEvent,
@@ -598,6 +599,134 @@ export class TrustedChainRemoved extends BaseEvent {
   }
 }
 
+type TrustedChainSetV2Fields = { chainName: string; authority: PublicKey };
+
+export class TrustedChainSetV2 extends BaseEvent {
+  static override readonly EVENT_NAME = "TrustedChainSetV2";
+  static override readonly DISC = calculateDiscriminant(this.EVENT_NAME);
+  readonly chainName: string;
+  readonly authority: PublicKey;
+
+  constructor(fields: TrustedChainSetV2Fields) {
+    super();
+    this.chainName = fields.chainName;
+    this.authority = fields.authority;
+  }
+
+  static override deserialize(segments: Buffer[]): TrustedChainSetV2 {
+    let i = 0;
+    const next = (f: string): Buffer => {
+      if (i >= segments.length) throw new MissingDataError(f);
+      return segments[i++];
+    };
+    const fields: TrustedChainSetV2Fields = {
+      chainName: readString(next("chainName"), "chainName"),
+      authority: readPubkey(next("authority"), "authority"),
+    };
+    if (i < segments.length)
+      throw new TrailingSegmentsError(segments.length - i);
+    return new TrustedChainSetV2(fields);
+  }
+}
+
+type TrustedChainRemovedV2Fields = { chainName: string; authority: PublicKey };
+
+export class TrustedChainRemovedV2 extends BaseEvent {
+  static override readonly EVENT_NAME = "TrustedChainRemovedV2";
+  static override readonly DISC = calculateDiscriminant(this.EVENT_NAME);
+  readonly chainName: string;
+  readonly authority: PublicKey;
+
+  constructor(fields: TrustedChainRemovedV2Fields) {
+    super();
+
+    this.chainName = fields.chainName;
+    this.authority = fields.authority;
+  }
+  static override deserialize(segments: Buffer[]): TrustedChainRemovedV2 {
+    let i = 0;
+    const next = (f: string): Buffer => {
+      if (i >= segments.length) throw new MissingDataError(f);
+      return segments[i++];
+    };
+    const fields: TrustedChainRemovedV2Fields = {
+      chainName: readString(next("chainName"), "chainName"),
+      authority: readPubkey(next("authority"), "authority"),
+    };
+    if (i < segments.length)
+      throw new TrailingSegmentsError(segments.length - i);
+    return new TrustedChainRemovedV2(fields);
+  }
+}
+
+type TrustedChainRenamedFields = {
+  oldChainName: string;
+  newChainName: string;
+  authority: PublicKey;
+};
+
+export class TrustedChainRenamed extends BaseEvent {
+  static override readonly EVENT_NAME = "TrustedChainRenamed";
+  static override readonly DISC = calculateDiscriminant(this.EVENT_NAME);
+  readonly oldChainName: string;
+  readonly newChainName: string;
+  readonly authority: PublicKey;
+
+  constructor(fields: TrustedChainRenamedFields) {
+    super();
+
+    this.oldChainName = fields.oldChainName;
+    this.newChainName = fields.newChainName;
+    this.authority = fields.authority;
+  }
+  static override deserialize(segments: Buffer[]): TrustedChainRenamed {
+    let i = 0;
+    const next = (f: string): Buffer => {
+      if (i >= segments.length) throw new MissingDataError(f);
+      return segments[i++];
+    };
+    const fields: TrustedChainRenamedFields = {
+      oldChainName: readString(next("oldChainName"), "oldChainName"),
+      newChainName: readString(next("newChainName"), "newChainName"),
+      authority: readPubkey(next("authority"), "authority"),
+    };
+    if (i < segments.length)
+      throw new TrailingSegmentsError(segments.length - i);
+    return new TrustedChainRenamed(fields);
+  }
+}
+
+type PauseStatusChangedFields = { paused: boolean; authority: PublicKey };
+
+export class PauseStatusChanged extends BaseEvent {
+  static override readonly EVENT_NAME = "PauseStatusChanged";
+  static override readonly DISC = calculateDiscriminant(this.EVENT_NAME);
+  readonly paused: boolean;
+  readonly authority: PublicKey;
+
+  constructor(fields: PauseStatusChangedFields) {
+    super();
+
+    this.paused = fields.paused;
+    this.authority = fields.authority;
+  }
+
+  static override deserialize(segments: Buffer[]): PauseStatusChanged {
+    let i = 0;
+    const next = (f: string): Buffer => {
+      if (i >= segments.length) throw new MissingDataError(f);
+      return segments[i++];
+    };
+    const fields: PauseStatusChangedFields = {
+      paused: readBool(next("paused"), "paused"),
+      authority: readPubkey(next("authority"), "authority"),
+    };
+    if (i < segments.length)
+      throw new TrailingSegmentsError(segments.length - i);
+    return new PauseStatusChanged(fields);
+  }
+}
+
 export const ITS_KNOWN_EVENT_CLASSES: EventClassType[] = [
   InterchainTransfer,
   InterchainTransferReceived,
@@ -611,7 +740,11 @@ export const ITS_KNOWN_EVENT_CLASSES: EventClassType[] = [
   RevokeRemoteInterchainTokenApproval,
   FlowLimitSet,
   TrustedChainSet,
+  TrustedChainSetV2,
   TrustedChainRemoved,
+  TrustedChainRemovedV2,
+  TrustedChainRenamed,
+  PauseStatusChanged,
 ];
 
 // Map DISC (hex string) to the corresponding event class constructor
@@ -8,16 +8,18 @@ use axelar_solana_encoding::hasher::NativeHasher;
 use axelar_solana_encoding::types::execute_data::{
     ExecuteData, MerkleisedMessage, MerkleisedPayload,
 };
-use axelar_solana_encoding::types::messages::{CrossChainId, Message, Messages};
+use axelar_solana_encoding::types::messages::{CrossChainId, Message, MessageLeaf, Messages};
 use axelar_solana_encoding::types::payload::Payload;
 use axelar_solana_encoding::types::verifier_set::{verifier_set_hash, VerifierSet};
 use axelar_solana_encoding::{borsh, hash_payload};
 use axelar_solana_gateway::error::GatewayError;
-use axelar_solana_gateway::instructions::InitialVerifierSet;
+use axelar_solana_gateway::instructions::{InitialVerifierSet, InitializeConfig};
 use axelar_solana_gateway::num_traits::FromPrimitive;
-use axelar_solana_gateway::state::incoming_message::{command_id, IncomingMessage};
+use axelar_solana_gateway::state::incoming_message::{
+    command_id, IncomingMessage, IncomingMessageExtension,
+};
 use axelar_solana_gateway::state::signature_verification_pda::SignatureVerificationSessionData;
-use axelar_solana_gateway::state::verifier_set_tracker::VerifierSetTracker;
+use axelar_solana_gateway::state::verifier_set_tracker::{SignatureScheme, VerifierSetTracker};
 use axelar_solana_gateway::state::GatewayConfig;
 use axelar_solana_gateway::{
     get_gateway_root_config_pda, get_incoming_message_pda, get_verifier_set_tracker_pda,
@@ -36,7 +38,9 @@ use solana_sdk::signer::Signer as _;
 use solana_sdk::transaction::TransactionError;
 
 use crate::base::{workspace_root_dir, TestFixture};
-use crate::test_signer::{create_signer_with_weight, SigningVerifierSet};
+use crate::test_signer::{
+    create_ed25519_signer_with_weight, create_signer_with_weight, SigningVerifierSet,
+};
 
 /// Contains metadata information about the initialised Gateway config
 pub struct SolanaAxelarIntegrationMetadata {
@@ -93,16 +97,33 @@ impl SolanaAxelarIntegrationMetadata {
     ) -> Result<Pubkey, BanksTransactionResultWithMetadata> {
         let (gateway_config_pda, _) = axelar_solana_gateway::get_gateway_root_config_pda();
         let initial_verifier_set = self.init_gateway_config_verifier_set_data();
-        let ix = axelar_solana_gateway::instructions::initialize_config(
-            self.fixture.payer.pubkey(),
-            self.upgrade_authority.pubkey(),
-            self.domain_separator,
-            initial_verifier_set,
-            self.minimum_rotate_signers_delay_seconds,
-            self.operator.pubkey(),
-            self.previous_signers_retention.into(),
-            gateway_config_pda,
-        )
+        let ix = match self.signers.signature_scheme() {
+            SignatureScheme::Secp256k1 => axelar_solana_gateway::instructions::initialize_config(
+                self.fixture.payer.pubkey(),
+                self.upgrade_authority.pubkey(),
+                self.domain_separator,
+                initial_verifier_set,
+                self.minimum_rotate_signers_delay_seconds,
+                self.operator.pubkey(),
+                self.previous_signers_retention.into(),
+                gateway_config_pda,
+            ),
+            signature_scheme @ SignatureScheme::Ed25519 => {
+                axelar_solana_gateway::instructions::initialize_config_with_signature_scheme(
+                    self.fixture.payer.pubkey(),
+                    self.upgrade_authority.pubkey(),
+                    InitializeConfig {
+                        domain_separator: self.domain_separator,
+                        initial_verifier_set,
+                        minimum_rotation_delay: self.minimum_rotate_signers_delay_seconds,
+                        operator: self.operator.pubkey(),
+                        previous_verifier_retention: self.previous_signers_retention.into(),
+                    },
+                    signature_scheme,
+                    gateway_config_pda,
+                )
+            }
+        }
         .unwrap();
 
         // Due to Axelar protocol constraints, the Gateway's initialization requires the upgrade authority signature.
@@ -149,7 +170,16 @@ impl SolanaAxelarIntegrationMetadata {
         let (verifier_set_tracker_pda, _verifier_set_tracker_bump) =
             get_verifier_set_tracker_pda(execute_data.signing_verifier_set_merkle_root);
 
+        let mut accumulated_weight = 0_u128;
         for signature_leaves in &execute_data.signing_verifier_set_leaves {
+            // The session is valid once the quorum is reached, the gateway
+            // rejects further signatures
+            if accumulated_weight >= signature_leaves.leaf.quorum {
+                break;
+            }
+            accumulated_weight =
+                accumulated_weight.saturating_add(signature_leaves.leaf.signer_weight);
+
             // Verify the signature
             let (verification_session_pda, _) =
                 axelar_solana_gateway::get_signature_verification_pda(
@@ -164,12 +194,16 @@ impl SolanaAxelarIntegrationMetadata {
                 signature_leaves.clone(),
             )
             .unwrap();
-            let tx_result = self
-                .send_tx(&[
-                    ComputeBudgetInstruction::set_compute_unit_limit(250_000),
-                    ix,
-                ])
-                .await?;
+            let ixs = core::iter::once(ComputeBudgetInstruction::set_compute_unit_limit(250_000))
+                .chain(
+                    axelar_solana_gateway::instructions::ed25519_signature_verification(
+                        signature_leaves,
+                        &execute_data.payload_merkle_root,
+                    ),
+                )
+                .chain([ix])
+                .collect::<Vec<_>>();
+            let tx_result = self.send_tx(&ixs).await?;
             tx_result.result.unwrap();
         }
 
@@ -311,6 +345,26 @@ impl SolanaAxelarIntegrationMetadata {
         self.send_tx(&[ix]).await
     }
 
+    /// Approve the only message of a single-message payload on the Gateway,
+    /// without a merkle proof
+    pub async fn approve_single_message(
+        &mut self,
+        leaf: MessageLeaf,
+        verification_session_pda: Pubkey,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksTransactionResultWithMetadata> {
+        let command_id = command_id(&leaf.message.cc_id.chain, &leaf.message.cc_id.id);
+        let (incoming_message_pda, _) = get_incoming_message_pda(&command_id);
+        let ix = axelar_solana_gateway::instructions::approve_single_message(
+            leaf,
+            self.gateway_root_pda,
+            self.payer.pubkey(),
+            verification_session_pda,
+            incoming_message_pda,
+        )
+        .unwrap();
+        self.send_tx(&[ix]).await
+    }
+
     /// Start a new payload verification session for signer rotation, and rotate
     /// the signers.
     pub async fn sign_session_and_rotate_signers(
@@ -479,7 +533,7 @@ impl SolanaAxelarIntegrationMetadata {
         let gateway_root_pda_account = self
             .get_account(&gateway_root_pda, &axelar_solana_gateway::id())
             .await;
-        *GatewayConfig::read(gateway_root_pda_account.data()).unwrap()
+        *GatewayConfig::read_versioned(gateway_root_pda_account.data()).unwrap()
     }
 
     /// Get the verifier set tracker data
@@ -498,7 +552,19 @@ impl SolanaAxelarIntegrationMetadata {
         let incoming_message_pda_acc = self
             .get_account(&incoming_message_pda, &axelar_solana_gateway::id())
             .await;
-        *IncomingMessage::read(incoming_message_pda_acc.data()).unwrap()
+        *IncomingMessage::read_versioned(incoming_message_pda_acc.data()).unwrap()
+    }
+
+    /// Get the extension following the incoming message data, `None` for
+    /// messages approved before it was introduced
+    pub async fn incoming_message_extension(
+        &mut self,
+        incoming_message_pda: Pubkey,
+    ) -> Option<IncomingMessageExtension> {
+        let incoming_message_pda_acc = self
+            .get_account(&incoming_message_pda, &axelar_solana_gateway::id())
+            .await;
+        IncomingMessageExtension::read(incoming_message_pda_acc.data())
     }
 
     /// Upload a message payload to the PDA account
@@ -770,6 +836,22 @@ pub fn make_verifier_set(
     SigningVerifierSet::new(signers, nonce, domain_separator)
 }
 
+/// Create a new verifier set of Ed25519 signers
+pub fn make_ed25519_verifier_set(
+    weights: &[u128],
+    nonce: u64,
+    domain_separator: [u8; 32],
+) -> SigningVerifierSet {
+    let signers = weights
+        .iter()
+        .copied()
+        .map(create_ed25519_signer_with_weight)
+        .collect::<Vec<_>>();
+    let signers = Arc::from(signers);
+
+    SigningVerifierSet::new(signers, nonce, domain_separator)
+}
+
 /// Create a new verifier set with a custom quorum
 pub fn make_verifiers_with_quorum(
     weights: &[u128],
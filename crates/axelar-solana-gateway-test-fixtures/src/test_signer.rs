@@ -5,6 +5,7 @@ use std::sync::Arc;
 use axelar_solana_encoding::hasher::{Hasher, NativeHasher};
 use axelar_solana_encoding::types::pubkey::{PublicKey, Signature};
 use axelar_solana_encoding::types::verifier_set::{verifier_set_hash, VerifierSet};
+use axelar_solana_gateway::state::verifier_set_tracker::SignatureScheme;
 use solana_sdk::pubkey::Pubkey;
 
 /// Uitility verifier set representation that has access to the signing keys
@@ -58,6 +59,16 @@ impl SigningVerifierSet {
         axelar_solana_gateway::get_verifier_set_tracker_pda(hash)
     }
 
+    /// The signature scheme of the verifier set, the one of its first signer
+    #[must_use]
+    pub fn signature_scheme(&self) -> SignatureScheme {
+        self.signers
+            .first()
+            .map_or(SignatureScheme::Secp256k1, |signer| {
+                SignatureScheme::of(&signer.public_key)
+            })
+    }
+
     /// Transform into the verifier set that the gateway expects to operate on
     #[must_use]
     pub fn verifier_set(&self) -> VerifierSet {
@@ -97,6 +108,18 @@ pub fn create_signer_with_weight(weight: u128) -> TestSigner {
     }
 }
 
+/// Create a new Ed25519 signer with the given weight
+#[must_use]
+pub fn create_ed25519_signer_with_weight(weight: u128) -> TestSigner {
+    let (secret_key, public_key) = random_ed25519_keypair();
+
+    TestSigner {
+        public_key,
+        secret_key,
+        weight,
+    }
+}
+
 /// Test signer for signing payloads
 #[derive(Clone)]
 pub enum TestSigningKey {
@@ -145,14 +168,10 @@ impl TestSigningKey {
 /// Genetrate a random keypair
 #[must_use]
 pub fn random_keypair() -> (TestSigningKey, PublicKey) {
-    // NOTE: the Gateway can't process Ed25519 signatures yet, so this function will
-    // issue only ECDSA keypairs for now.
-    //
-    // if OsRng.gen_bool(0.5) {
+    // NOTE: all the signers of a verifier set sign with the same kind of keys,
+    // so this function only issues ECDSA keypairs. Ed25519 verifier sets are
+    // built from `create_ed25519_signer_with_weight`.
     random_ecdsa_keypair()
-    // } else {
-    //     random_ed25519_keypair()
-    // }
 }
 
 /// New random ED25519 keypair
@@ -0,0 +1,151 @@
+//! Module that contains utilities to drive inbound GMP messages to ITS
+
+use axelar_solana_encoding::types::messages::Message;
+use axelar_solana_gateway::state::incoming_message::command_id;
+use axelar_solana_gateway_test_fixtures::gateway::random_message;
+use axelar_solana_its::instruction::ExecuteInstructionInputs;
+use axelar_solana_its::state::token_observer::TokenObserver;
+use interchain_token_transfer_gmp::alloy_primitives::{Bytes, U256};
+use interchain_token_transfer_gmp::{GMPPayload, InterchainTransfer, ReceiveFromHub};
+use solana_program_test::BanksTransactionResultWithMetadata;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer as _;
+
+use crate::{SolanaItsIntegrationMetadata, TestToken, ITS_HUB_TRUSTED_CONTRACT_ADDRESS};
+
+impl SolanaItsIntegrationMetadata {
+    /// Relay an ITS message sent from `source_chain`, routing it through the
+    /// ITS Hub, and execute it on ITS.
+    ///
+    /// `mint` is required for interchain transfers and `token_program` must
+    /// own it.
+    pub async fn relay_to_its(
+        &mut self,
+        source_chain: &str,
+        payload: &GMPPayload,
+        mint: Option<Pubkey>,
+        token_program: Pubkey,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksTransactionResultWithMetadata> {
+        let payload = receive_from_hub(source_chain, payload);
+        let ix = self
+            .approve_its_message(payload, mint, token_program)
+            .await?;
+
+        self.send_tx(&[ix]).await
+    }
+
+    /// Relay an interchain transfer of `token` from `source_chain` to
+    /// `destination`. With non-empty `data`, `destination` must be a program
+    /// executable by ITS and `data` its encoded executable payload.
+    pub async fn relay_interchain_transfer(
+        &mut self,
+        source_chain: &str,
+        token: &TestToken,
+        destination: Pubkey,
+        amount: u64,
+        data: Vec<u8>,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksTransactionResultWithMetadata> {
+        let payload = GMPPayload::InterchainTransfer(InterchainTransfer {
+            selector: U256::from(InterchainTransfer::MESSAGE_TYPE_ID),
+            token_id: token.token_id.into(),
+            source_address: Bytes::from(vec![0; 20]),
+            destination_address: Bytes::from(destination.to_bytes().to_vec()),
+            amount: U256::from(amount),
+            data: Bytes::from(data),
+        });
+
+        self.relay_to_its(
+            source_chain,
+            &payload,
+            Some(token.mint),
+            token.token_program,
+        )
+        .await
+    }
+
+    /// Approve an ITS Hub message carrying `payload` on the gateway, upload
+    /// the payload and return the ITS instruction executing it.
+    pub async fn approve_its_message(
+        &mut self,
+        payload: GMPPayload,
+        mint: Option<Pubkey>,
+        token_program: Pubkey,
+    ) -> Result<Instruction, BanksTransactionResultWithMetadata> {
+        let encoded_payload = payload.encode();
+        let payload_hash = solana_sdk::keccak::hash(&encoded_payload).to_bytes();
+        let message = its_hub_message(payload_hash);
+
+        let signers = self.signers.clone();
+        let merkleised_message = self
+            .sign_session_and_approve_messages(&signers, &[message.clone()])
+            .await?
+            .into_iter()
+            .find(|merkleised| merkleised.leaf.message.cc_id == message.cc_id)
+            .expect("the message was approved");
+
+        let message_payload_pda = self
+            .upload_message_payload(&message, &encoded_payload)
+            .await?;
+        let (incoming_message_pda, _) = axelar_solana_gateway::get_incoming_message_pda(
+            &command_id(&message.cc_id.chain, &message.cc_id.id),
+        );
+
+        let token_observer = self.registered_token_observer(&payload).await;
+
+        let inputs = ExecuteInstructionInputs::builder()
+            .payer(self.fixture.payer.pubkey())
+            .incoming_message_pda(incoming_message_pda)
+            .message_payload_pda(message_payload_pda)
+            .message(merkleised_message.leaf.message)
+            .payload(payload)
+            .token_program(token_program)
+            .mint_opt(mint)
+            .token_observer_opt(token_observer)
+            .build();
+
+        Ok(axelar_solana_its::instruction::execute(inputs).unwrap())
+    }
+
+    /// Returns the observer program registered for the token of the payload,
+    /// if any.
+    pub async fn registered_token_observer(&mut self, payload: &GMPPayload) -> Option<Pubkey> {
+        let token_id = payload.token_id().ok()?;
+        let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+        let (token_manager_pda, _) =
+            axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+        let (token_observer_pda, _) =
+            axelar_solana_its::find_token_observer_pda(&token_manager_pda);
+
+        self.try_get_account_no_checks(&token_observer_pda)
+            .await
+            .ok()??;
+
+        Some(
+            self.load_borsh_pda::<TokenObserver>(&token_observer_pda)
+                .await
+                .program_id,
+        )
+    }
+}
+
+/// Wrap an ITS message sent from `source_chain` the way the ITS Hub delivers
+/// it.
+#[must_use]
+pub fn receive_from_hub(source_chain: &str, payload: &GMPPayload) -> GMPPayload {
+    GMPPayload::ReceiveFromHub(ReceiveFromHub {
+        selector: U256::from(ReceiveFromHub::MESSAGE_TYPE_ID),
+        source_chain: source_chain.to_owned(),
+        payload: payload.encode().into(),
+    })
+}
+
+/// A random GMP message from the ITS Hub to ITS with the given payload hash.
+#[must_use]
+pub fn its_hub_message(payload_hash: [u8; 32]) -> Message {
+    let mut message = random_message();
+    message.destination_address = axelar_solana_its::id().to_string();
+    message.payload_hash = payload_hash;
+    ITS_HUB_TRUSTED_CONTRACT_ADDRESS.clone_into(&mut message.source_address);
+    message
+}
@@ -0,0 +1,187 @@
+//! Module that contains the ITS deployment utilities
+
+use std::path::PathBuf;
+
+use axelar_solana_gateway_test_fixtures::base::workspace_root_dir;
+use axelar_solana_gateway_test_fixtures::gas_service::GasServiceUtils;
+use axelar_solana_gateway_test_fixtures::{
+    SolanaAxelarIntegration, SolanaAxelarIntegrationMetadata,
+};
+use program_utils::pda::BorshPda;
+use solana_program_test::BanksTransactionResultWithMetadata;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer as _;
+use solana_sdk::system_instruction;
+
+use crate::ITS_HUB_TRUSTED_CONTRACT_ADDRESS;
+
+/// Contains metadata information about the deployed ITS
+pub struct SolanaItsIntegrationMetadata {
+    /// the gateway integration ITS was deployed on
+    pub gateway: SolanaAxelarIntegrationMetadata,
+    /// the gas service ITS pays gas to
+    pub gas_utils: GasServiceUtils,
+    /// the chain name ITS was initialized with
+    pub chain_name: String,
+    /// the ITS operator, also the payer of the test fixture
+    pub operator: Pubkey,
+}
+
+impl core::ops::Deref for SolanaItsIntegrationMetadata {
+    type Target = SolanaAxelarIntegrationMetadata;
+
+    fn deref(&self) -> &Self::Target {
+        &self.gateway
+    }
+}
+
+impl core::ops::DerefMut for SolanaItsIntegrationMetadata {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.gateway
+    }
+}
+
+impl SolanaItsIntegrationMetadata {
+    /// Load the token manager of the given token
+    pub async fn token_manager(
+        &mut self,
+        token_id: &[u8; 32],
+    ) -> axelar_solana_its::state::token_manager::TokenManager {
+        let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+        let (token_manager_pda, _) =
+            axelar_solana_its::find_token_manager_pda(&its_root_pda, token_id);
+
+        self.load_borsh_pda(&token_manager_pda).await
+    }
+
+    /// Load a Borsh serialized ITS PDA
+    pub async fn load_borsh_pda<T: BorshPda>(&mut self, pda: &Pubkey) -> T {
+        let mut account = self.get_account(pda, &axelar_solana_its::id()).await;
+        let account_info =
+            solana_sdk::account_info::IntoAccountInfo::into_account_info((pda, &mut account));
+
+        T::load(&account_info).unwrap()
+    }
+
+    /// Trust a new chain
+    pub async fn set_trusted_chain(
+        &mut self,
+        chain_name: String,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksTransactionResultWithMetadata> {
+        let ix = axelar_solana_its::instruction::set_trusted_chain(
+            self.operator,
+            self.operator,
+            chain_name,
+        )
+        .unwrap();
+
+        self.send_tx(&[ix]).await
+    }
+}
+
+/// Test fixture builder for the Solana ITS integration
+#[derive(Debug, typed_builder::TypedBuilder)]
+pub struct SolanaItsIntegration {
+    #[builder(default = "solana-localnet".to_owned())]
+    chain_name: String,
+    /// The chains ITS accepts messages from and sends messages to
+    #[builder(default = vec!["ethereum".to_owned()])]
+    trusted_chains: Vec<String>,
+    #[builder(default = vec![42])]
+    initial_signer_weights: Vec<u128>,
+    /// The ITS program .so file, resolved from the `target/deploy` directory
+    /// of the workspace when not an existing path
+    #[builder(default = "axelar_solana_its.so".into())]
+    its_program: PathBuf,
+    #[builder(default)]
+    /// Extra programs (besides the gateway and ITS) that we need to deploy,
+    /// e.g. the programs receiving interchain tokens with data.
+    /// The parameters -- name of the program .so file (with the extension)
+    /// and the program id
+    ///
+    /// ```ignore
+    /// vec![("my_executable.so".into(), my_executable::id())]
+    /// ```
+    programs_to_deploy: Vec<(PathBuf, Pubkey)>,
+}
+
+impl SolanaItsIntegration {
+    /// Setup the gateway, the gas service and ITS, initialized with the
+    /// fixture payer as operator and trusting the configured chains.
+    pub async fn setup(self) -> SolanaItsIntegrationMetadata {
+        let mut programs = vec![
+            (self.its_program, axelar_solana_its::id()),
+            (mpl_token_metadata_program(), mpl_token_metadata::ID),
+        ];
+        programs.extend(self.programs_to_deploy);
+
+        let mut gateway = SolanaAxelarIntegration::builder()
+            .initial_signer_weights(self.initial_signer_weights)
+            .programs_to_deploy(programs)
+            .build()
+            .setup()
+            .await;
+
+        let gas_utils = gateway.fixture.deploy_gas_service().await;
+        gateway.fixture.init_gas_config(&gas_utils).await.unwrap();
+
+        let operator = gateway.fixture.payer.pubkey();
+        let upgrade_authority = gateway.upgrade_authority.insecure_clone();
+        let payer = gateway.fixture.payer.insecure_clone();
+
+        let mut ixs = vec![
+            system_instruction::transfer(&operator, &upgrade_authority.pubkey(), u32::MAX.into()),
+            axelar_solana_its::instruction::initialize(
+                upgrade_authority.pubkey(),
+                operator,
+                self.chain_name.clone(),
+                ITS_HUB_TRUSTED_CONTRACT_ADDRESS.to_owned(),
+            )
+            .unwrap(),
+        ];
+        ixs.extend(self.trusted_chains.into_iter().map(|chain_name| {
+            axelar_solana_its::instruction::set_trusted_chain(
+                operator,
+                upgrade_authority.pubkey(),
+                chain_name,
+            )
+            .unwrap()
+        }));
+
+        gateway
+            .fixture
+            .send_tx_with_custom_signers(&ixs, &[&upgrade_authority, &payer])
+            .await
+            .expect("failed to initialize ITS");
+
+        SolanaItsIntegrationMetadata {
+            gateway,
+            gas_utils,
+            chain_name: self.chain_name,
+            operator,
+        }
+    }
+}
+
+/// Path to the Metaplex token metadata program ITS reads and writes token
+/// metadata with.
+#[must_use]
+pub fn mpl_token_metadata_program() -> PathBuf {
+    let in_repo = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("..")
+        .join("programs")
+        .join("axelar-solana-its")
+        .join("tests")
+        .join("mpl_token_metadata.so");
+
+    if in_repo.exists() {
+        in_repo
+    } else {
+        workspace_root_dir()
+            .join("programs")
+            .join("axelar-solana-its")
+            .join("tests")
+            .join("mpl_token_metadata.so")
+    }
+}
@@ -0,0 +1,27 @@
+//! Test utilities for the Solana Interchain Token Service
+//!
+//! Programs integrating with ITS can use these fixtures to write integration
+//! tests: deploy the gateway, gas service and ITS with
+//! [`SolanaItsIntegration`], create tokens of every token manager type and
+//! relay inbound GMP messages, such as interchain transfers with data to an
+//! executable program, as if they were coming from the ITS Hub.
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::missing_errors_doc)]
+#![allow(clippy::multiple_inherent_impl)]
+
+pub mod gmp;
+pub mod its;
+pub mod token;
+
+pub use its::{SolanaItsIntegration, SolanaItsIntegrationMetadata};
+pub use token::TestToken;
+
+/// The chain name ITS uses for the ITS Hub.
+pub const ITS_HUB_CHAIN_NAME: &str = "axelar";
+
+/// The ITS Hub address ITS is initialized with, inbound messages are relayed
+/// from it.
+pub const ITS_HUB_TRUSTED_CONTRACT_ADDRESS: &str =
+    "axelar157hl7gpuknjmhtac2qnphuazv2yerfagva7lsu9vuj2pgn32z22qa26dk4";
@@ -0,0 +1,257 @@
+//! Module that contains utilities to create tokens managed by ITS
+
+use axelar_solana_its::state::token_manager::Type as TokenManagerType;
+use solana_program_test::BanksTransactionResultWithMetadata;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer as _;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+use crate::SolanaItsIntegrationMetadata;
+
+/// Transfer fee, in basis points, of the mints created for
+/// [`TokenManagerType::LockUnlockFee`] tokens.
+pub const TEST_TRANSFER_FEE_BASIS_POINTS: u16 = 100;
+
+/// Maximum transfer fee of the mints created for
+/// [`TokenManagerType::LockUnlockFee`] tokens.
+pub const TEST_MAXIMUM_TRANSFER_FEE: u64 = 1_000;
+
+/// A token registered with ITS
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TestToken {
+    /// the ITS token id
+    pub token_id: [u8; 32],
+    /// the Solana mint of the token
+    pub mint: Pubkey,
+    /// the token program owning the mint
+    pub token_program: Pubkey,
+    /// the type of the token manager
+    pub token_manager_type: TokenManagerType,
+}
+
+impl TestToken {
+    /// The associated token account of `owner` for the token
+    #[must_use]
+    pub fn associated_token_account(&self, owner: &Pubkey) -> Pubkey {
+        get_associated_token_address_with_program_id(owner, &self.mint, &self.token_program)
+    }
+
+    /// The token manager PDA of the token
+    #[must_use]
+    pub fn token_manager_pda(&self) -> Pubkey {
+        let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &self.token_id).0
+    }
+}
+
+impl SolanaItsIntegrationMetadata {
+    /// Create a token managed by a token manager of the given type, with the
+    /// fixture payer as deployer, operator and, where applicable, minter.
+    ///
+    /// - [`TokenManagerType::NativeInterchainToken`]: deploys an interchain
+    ///   token under `token_program`.
+    /// - [`TokenManagerType::MintBurn`] and [`TokenManagerType::MintBurnFrom`]:
+    ///   creates a mint, registers it and hands its mint authority over to
+    ///   the token manager.
+    /// - [`TokenManagerType::LockUnlock`]: creates a mint and registers it.
+    /// - [`TokenManagerType::LockUnlockFee`]: creates a Token-2022 mint with a
+    ///   transfer fee and registers it.
+    pub async fn create_token(
+        &mut self,
+        token_manager_type: TokenManagerType,
+        token_program: Pubkey,
+        salt: [u8; 32],
+        decimals: u8,
+    ) -> TestToken {
+        if token_manager_type == TokenManagerType::NativeInterchainToken {
+            return self
+                .deploy_interchain_token_with_token_program(
+                    salt,
+                    "Test Token",
+                    "TT",
+                    decimals,
+                    0,
+                    token_program,
+                )
+                .await;
+        }
+
+        let operator = self.operator;
+        let mint = if token_manager_type == TokenManagerType::LockUnlockFee {
+            self.init_new_mint_with_fee(
+                &operator,
+                &token_program,
+                TEST_TRANSFER_FEE_BASIS_POINTS,
+                TEST_MAXIMUM_TRANSFER_FEE,
+                decimals,
+                None,
+                None,
+            )
+            .await
+        } else {
+            self.init_new_mint(operator, token_program, decimals).await
+        };
+
+        let token = self
+            .register_custom_token(salt, mint, token_manager_type, token_program)
+            .await;
+
+        if matches!(
+            token_manager_type,
+            TokenManagerType::MintBurn | TokenManagerType::MintBurnFrom
+        ) {
+            let ix = axelar_solana_its::instruction::token_manager::handover_mint_authority(
+                self.operator,
+                self.operator,
+                token.token_id,
+                mint,
+                token_program,
+            )
+            .unwrap();
+            self.send_tx(&[ix])
+                .await
+                .expect("failed to hand over the mint authority");
+        }
+
+        token
+    }
+
+    /// Deploy a native interchain token, minting `initial_supply` to the
+    /// fixture payer.
+    pub async fn deploy_interchain_token(
+        &mut self,
+        salt: [u8; 32],
+        name: &str,
+        symbol: &str,
+        decimals: u8,
+        initial_supply: u64,
+    ) -> TestToken {
+        self.deploy_interchain_token_with_token_program(
+            salt,
+            name,
+            symbol,
+            decimals,
+            initial_supply,
+            spl_token_2022::id(),
+        )
+        .await
+    }
+
+    /// Deploy a native interchain token whose mint is owned by
+    /// `token_program`, minting `initial_supply` to the fixture payer.
+    pub async fn deploy_interchain_token_with_token_program(
+        &mut self,
+        salt: [u8; 32],
+        name: &str,
+        symbol: &str,
+        decimals: u8,
+        initial_supply: u64,
+        token_program: Pubkey,
+    ) -> TestToken {
+        let ix = axelar_solana_its::instruction::deploy_interchain_token_with_token_program(
+            self.operator,
+            self.operator,
+            salt,
+            name.to_owned(),
+            symbol.to_owned(),
+            decimals,
+            initial_supply,
+            Some(self.operator),
+            token_program,
+        )
+        .unwrap();
+        self.send_tx(&[ix])
+            .await
+            .expect("failed to deploy the interchain token");
+
+        let token_id = axelar_solana_its::interchain_token_id(&self.operator, &salt);
+        let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+        let (mint, _) = axelar_solana_its::find_interchain_token_pda(&its_root_pda, &token_id);
+
+        TestToken {
+            token_id,
+            mint,
+            token_program,
+            token_manager_type: TokenManagerType::NativeInterchainToken,
+        }
+    }
+
+    /// Register an existing mint with ITS
+    pub async fn register_custom_token(
+        &mut self,
+        salt: [u8; 32],
+        mint: Pubkey,
+        token_manager_type: TokenManagerType,
+        token_program: Pubkey,
+    ) -> TestToken {
+        let ix = axelar_solana_its::instruction::register_custom_token(
+            self.operator,
+            self.operator,
+            salt,
+            mint,
+            token_manager_type,
+            token_program,
+            Some(self.operator),
+        )
+        .unwrap();
+        self.send_tx(&[ix])
+            .await
+            .expect("failed to register the custom token");
+
+        TestToken {
+            token_id: axelar_solana_its::linked_token_id(&self.operator, &salt),
+            mint,
+            token_program,
+            token_manager_type,
+        }
+    }
+
+    /// Mint `amount` tokens to the associated token account of `owner`,
+    /// creating it if needed. The fixture payer must hold the minter role
+    /// for interchain tokens or be the mint authority for other tokens.
+    pub async fn mint_test_tokens(
+        &mut self,
+        token: &TestToken,
+        owner: &Pubkey,
+        amount: u64,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksTransactionResultWithMetadata> {
+        let payer = self.fixture.payer.pubkey();
+        let token_account = token.associated_token_account(owner);
+        let create_ata_ix =
+            spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+                &payer,
+                owner,
+                &token.mint,
+                &token.token_program,
+            );
+
+        let mint_ix = match token.token_manager_type {
+            TokenManagerType::NativeInterchainToken
+            | TokenManagerType::MintBurn
+            | TokenManagerType::MintBurnFrom => {
+                axelar_solana_its::instruction::interchain_token::mint(
+                    token.token_id,
+                    token.mint,
+                    token_account,
+                    payer,
+                    token.token_program,
+                    amount,
+                )
+                .unwrap()
+            }
+            TokenManagerType::LockUnlock | TokenManagerType::LockUnlockFee => {
+                spl_token_2022::instruction::mint_to(
+                    &token.token_program,
+                    &token.mint,
+                    &token_account,
+                    &payer,
+                    &[],
+                    amount,
+                )
+                .unwrap()
+            }
+        };
+
+        self.send_tx(&[create_ata_ix, mint_ix]).await
+    }
+}
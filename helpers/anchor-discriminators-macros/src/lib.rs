@@ -249,13 +249,54 @@ impl Parse for AccountArgs {
     }
 }
 
+/// Name of the field attribute marking fields appended to an existing
+/// account layout.
+const APPENDED_FIELD_ATTRIBUTE: &str = "appended";
+
+/// Strips the `#[appended]` attribute from the fields of the struct and
+/// returns, for each field, whether it was marked.
+///
+/// Appended fields must come after every other field, so that accounts
+/// written before they were added end right before them.
+fn take_appended_fields(account_strct: &mut syn::ItemStruct) -> syn::Result<Vec<bool>> {
+    let mut appended_fields = Vec::new();
+
+    for field in account_strct.fields.iter_mut() {
+        let attrs_count = field.attrs.len();
+        field
+            .attrs
+            .retain(|attr| !attr.path.is_ident(APPENDED_FIELD_ATTRIBUTE));
+        let appended = field.attrs.len() != attrs_count;
+
+        if !appended && appended_fields.last() == Some(&true) {
+            return Err(syn::Error::new_spanned(
+                field,
+                "fields following an #[appended] field must be #[appended] as well",
+            ));
+        }
+        appended_fields.push(appended);
+    }
+
+    Ok(appended_fields)
+}
+
+/// Attribute macro for account structs, generating their discriminator and,
+/// unless `zero_copy` is given, their Borsh implementations.
+///
+/// Fields added to an account after it was deployed can be marked
+/// `#[appended]`: accounts written with the previous layout end before
+/// them, and load with their `Default` value.
 #[proc_macro_attribute]
 #[allow(clippy::wildcard_enum_match_arm)]
 pub fn account(
     args: proc_macro::TokenStream,
     input: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
-    let account_strct = parse_macro_input!(input as syn::ItemStruct);
+    let mut account_strct = parse_macro_input!(input as syn::ItemStruct);
+    let appended_fields = match take_appended_fields(&mut account_strct) {
+        Ok(appended_fields) => appended_fields,
+        Err(err) => return err.to_compile_error().into(),
+    };
     let account_name = &account_strct.ident;
 
     // Parse arguments using our custom Parse implementation
@@ -286,6 +327,22 @@ pub fn account(
         }
     };
 
+    let field_deserializers = field_names
+        .iter()
+        .zip(&field_types)
+        .zip(&appended_fields)
+        .map(|((field_name, field_type), appended)| {
+            if *appended {
+                quote! {
+                    let #field_name = anchor_discriminators::deserialize_or_default::<#field_type, _>(reader)?;
+                }
+            } else {
+                quote! {
+                    let #field_name = <#field_type as borsh::BorshDeserialize>::deserialize_reader(reader)?;
+                }
+            }
+        });
+
     let borsh_impls = if is_zero_copy {
         quote!()
     } else {
@@ -319,9 +376,7 @@ pub fn account(
                     }
 
                     // Deserialize each field
-                    #(
-                        let #field_names = <#field_types as borsh::BorshDeserialize>::deserialize_reader(reader)?;
-                    )*
+                    #(#field_deserializers)*
 
                     Ok(Self {
                         #(#field_names),*
@@ -78,6 +78,56 @@ pub(crate) mod v1_tests {
         let deserialized: FlowState = from_slice(&bytes).unwrap();
         assert_eq!(flow, deserialized);
     }
+
+    /// [`FlowState`] with fields appended after it was deployed.
+    #[account]
+    #[derive(Debug, Eq, PartialEq, Clone)]
+    pub(crate) struct AppendedFlowState {
+        pub flow_limit: Option<u64>,
+        pub flow_in: u64,
+        pub flow_out: u64,
+        pub epoch: u64,
+        #[appended]
+        pub paused: bool,
+        #[appended]
+        pub operator: Option<Pubkey>,
+    }
+
+    #[test]
+    fn test_account_appended_fields_default_when_missing() {
+        let flow = FlowState {
+            flow_limit: Some(100),
+            flow_in: 50,
+            flow_out: 30,
+            epoch: 1,
+        };
+        let mut bytes = to_vec(&flow).unwrap();
+        bytes[..8].copy_from_slice(AppendedFlowState::DISCRIMINATOR);
+
+        let deserialized: AppendedFlowState = from_slice(&bytes).unwrap();
+        assert_eq!(
+            deserialized,
+            AppendedFlowState {
+                flow_limit: Some(100),
+                flow_in: 50,
+                flow_out: 30,
+                epoch: 1,
+                paused: false,
+                operator: None,
+            }
+        );
+
+        let appended = AppendedFlowState {
+            paused: true,
+            operator: Some(Pubkey::new_unique()),
+            ..deserialized
+        };
+        let bytes = to_vec(&appended).unwrap();
+        assert_eq!(from_slice::<AppendedFlowState>(&bytes).unwrap(), appended);
+
+        // Data ending in the middle of an appended field is still rejected
+        assert!(from_slice::<AppendedFlowState>(&bytes[..bytes.len() - 1]).is_err());
+    }
 }
 
 // Defining it here since Anchor uses `crate::ID` inside expanded code
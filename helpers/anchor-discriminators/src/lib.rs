@@ -1,4 +1,6 @@
-use borsh::BorshSerialize;
+use std::io::Read;
+
+use borsh::{BorshDeserialize, BorshSerialize};
 
 pub mod hash;
 
@@ -51,3 +53,25 @@ pub trait InstructionData: Discriminator + BorshSerialize {
         self.serialize(&mut data).unwrap();
     }
 }
+
+/// Deserializes a field appended to an account layout, returning its default
+/// value when the data ends before it, as it does for accounts written
+/// before the field was added.
+///
+/// Used by the `#[account]` macro for the fields marked `#[appended]`.
+///
+/// # Errors
+///
+/// Returns an error if some data is left but it doesn't deserialize as `T`.
+pub fn deserialize_or_default<T, R>(reader: &mut R) -> std::io::Result<T>
+where
+    T: BorshDeserialize + Default,
+    R: Read,
+{
+    let mut first_byte = [0_u8; 1];
+    if reader.read(&mut first_byte)? == 0 {
+        return Ok(T::default());
+    }
+
+    T::deserialize_reader(&mut first_byte.as_slice().chain(reader))
+}
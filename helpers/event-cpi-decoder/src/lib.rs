@@ -0,0 +1,585 @@
+//! Decoding of the events the Axelar programs emit through event CPI.
+//!
+//! An event is emitted as an instruction the emitting program invokes on
+//! itself, signed by its event authority PDA, whose data is
+//! [`EVENT_IX_TAG_LE`], the discriminator of the event and the borsh encoding
+//! of the event. This crate finds these inner instructions in transactions
+//! fetched over RPC and decodes the events of the Gateway, ITS and Gas Service
+//! programs, so that the relayer and the explorers share one implementation.
+//!
+//! The program IDs are the ones of the network selected through the crate
+//! features, like for the programs themselves.
+
+use anchor_discriminators::Discriminator;
+use axelar_solana_gas_service::events as gas_service;
+use axelar_solana_gateway::events as gateway;
+use axelar_solana_its::events as its;
+use event_cpi::{EVENT_AUTHORITY_SEED, EVENT_IX_TAG_LE};
+use solana_sdk::pubkey::Pubkey;
+use solana_transaction_status::option_serializer::OptionSerializer;
+use solana_transaction_status::{
+    EncodedConfirmedTransactionWithStatusMeta, EncodedTransaction,
+    EncodedTransactionWithStatusMeta, UiInstruction, UiMessage, UiParsedInstruction,
+};
+
+/// Size of the discriminator of an event.
+const DISCRIMINATOR_LEN: usize = 8;
+
+/// Errors that may occur while decoding events.
+#[derive(Debug, thiserror::Error)]
+pub enum DecodeError {
+    /// An account key of the transaction isn't a valid public key.
+    #[error("Invalid account key: {0}")]
+    InvalidAccountKey(String),
+
+    /// An instruction refers to an account the transaction doesn't list.
+    #[error("Account index {0} out of range")]
+    AccountIndexOutOfRange(u8),
+
+    /// The data of an instruction isn't valid base58.
+    #[error("Invalid instruction data: {0}")]
+    InvalidInstructionData(#[from] bs58::decode::Error),
+
+    /// The event data is too short to hold a discriminator.
+    #[error("Missing event discriminator")]
+    MissingDiscriminator,
+
+    /// The program emitted an event this crate doesn't know of.
+    #[error("Unknown event discriminator {0:?}")]
+    UnknownDiscriminator(Vec<u8>),
+
+    /// The event data doesn't decode as the event its discriminator names.
+    #[error("Failed to deserialize {event}: {source}")]
+    Deserialize {
+        /// The name of the event.
+        event: &'static str,
+        /// The borsh error.
+        source: std::io::Error,
+    },
+}
+
+/// Declares the events of a program, as an enum with one variant per event
+/// named after the event struct, along with their discriminator table.
+macro_rules! program_events {
+    (
+        $(#[$attr:meta])*
+        $name:ident from $module:ident {
+            $($event:ident),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum $name {
+            $(
+                #[doc = concat!("See [`", stringify!($module), "::", stringify!($event), "`].")]
+                $event($module::$event),
+            )*
+        }
+
+        impl $name {
+            /// The name and discriminator of every event of the program.
+            pub const DISCRIMINATORS: &'static [(&'static str, &'static [u8])] = &[
+                $((stringify!($event), <$module::$event as Discriminator>::DISCRIMINATOR),)*
+            ];
+
+            /// Decodes the borsh encoded `data` of the event identified by
+            /// `discriminator`.
+            ///
+            /// # Errors
+            ///
+            /// - [`DecodeError::UnknownDiscriminator`] if no event of the
+            ///   program has the discriminator.
+            /// - [`DecodeError::Deserialize`] if the data doesn't decode.
+            pub fn decode(discriminator: &[u8], data: &[u8]) -> Result<Self, DecodeError> {
+                $(
+                    if discriminator == <$module::$event as Discriminator>::DISCRIMINATOR {
+                        return borsh::from_slice(data)
+                            .map(Self::$event)
+                            .map_err(|source| DecodeError::Deserialize {
+                                event: stringify!($event),
+                                source,
+                            });
+                    }
+                )*
+
+                Err(DecodeError::UnknownDiscriminator(discriminator.to_vec()))
+            }
+        }
+    };
+}
+
+program_events! {
+    /// An event emitted by the Gateway program.
+    GatewayEvent from gateway {
+        CallContractEvent,
+        VerifierSetRotatedEvent,
+        VerifierSetRotationDetailsEvent,
+        OperatorshipTransferredEvent,
+        OperatorshipRecoveryInitiatedEvent,
+        OperatorshipRecoveredEvent,
+        MessageApprovedEvent,
+        MessageApprovalDetailsEvent,
+        BatchApprovedEvent,
+        MessageExecutedEvent,
+        MessageExecutedEventV2,
+        VerifierSetTrackerPrunedEvent,
+        EventAuthorityVerifiedEvent,
+        ChainRegistrationSetEvent,
+        MessageCancelledEvent,
+        SignatureVerifiedTraceEvent,
+        MessageApprovalTraceEvent,
+        MessageValidationTraceEvent,
+    }
+}
+
+program_events! {
+    /// An event emitted by the ITS program.
+    ItsEvent from its {
+        InterchainTransfer,
+        InterchainTransferV2,
+        InterchainTransferPartiallyFilled,
+        InterchainTransferReceived,
+        InterchainTransferReceivedV2,
+        TokenMetadataRegistered,
+        LinkTokenStarted,
+        LinkTokenStartedV2,
+        InterchainTokenDeploymentStarted,
+        TokenManagerDeployed,
+        InterchainTokenDeployed,
+        InterchainTokenIdClaimed,
+        DeployRemoteInterchainTokenApproval,
+        RevokeRemoteInterchainTokenApproval,
+        FlowLimitSet,
+        TokenObserverSet,
+        DestinationOwnerPolicySet,
+        TokenManagerMaxSupplySet,
+        TokenManagerFeeCollectorSet,
+        FlowSlotMigrated,
+        WithheldFeesHarvested,
+        TokenManagerVerified,
+        TokenMetadataAuthoritySet,
+        DestinationOwnerMismatch,
+        TrustedChainSet,
+        TrustedChainSetV2,
+        TrustedChainRemoved,
+        TrustedChainRemovedV2,
+        TrustedChainRenamed,
+        PauseStatusChanged,
+        PauseFlagsSet,
+        MaxPayloadSizeSet,
+        DestinationExecutionPolicySet,
+        DestinationExecutionFailed,
+        OperatorshipTransferredWithGracePeriod,
+        GracePeriodOperatorUsed,
+        TokenManagerAuthorityMismatch,
+        HubMessageSent,
+        TokenManagerDecommissioned,
+        GasQuoteUsed,
+    }
+}
+
+program_events! {
+    /// An event emitted by the Gas Service program.
+    GasServiceEvent from gas_service {
+        GasPaidEvent,
+        GasPaidEventV2,
+        GasAddedEvent,
+        GasAddedEventV2,
+        GasAddedByPayloadHashEvent,
+        GasRefundedEvent,
+        GasRefundedEventV2,
+        GasCollectedEvent,
+        GasCollectedEventV2,
+        BalanceChangedEvent,
+        ApproversSetEvent,
+        OperationApprovedEvent,
+        ChainPauseSetEvent,
+        RefundWindowSetEvent,
+        RefundAddressPolicySetEvent,
+        RefundNoticeSourceSetEvent,
+        GasReceiptClosedEvent,
+        GasReceiptDeliveredEvent,
+        RefundEnqueuedEvent,
+        RefundSkippedEvent,
+        GasPriceFeedSetEvent,
+        PayloadHashPreRegisteredEvent,
+        PreRegisteredGasPaidEvent,
+    }
+}
+
+/// An event emitted by one of the Axelar programs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KnownEvent {
+    /// An event emitted by the Gateway program.
+    Gateway(GatewayEvent),
+    /// An event emitted by the ITS program.
+    Its(ItsEvent),
+    /// An event emitted by the Gas Service program.
+    GasService(GasServiceEvent),
+}
+
+/// Decodes the event emitted by the instruction invoking `program_id` with
+/// `data`, whose first account is `event_authority`.
+///
+/// Returns `None` if the instruction isn't an event CPI of one of the Axelar
+/// programs, signed by the event authority of the program.
+///
+/// # Errors
+///
+/// If the instruction is an event CPI of one of the Axelar programs, but the
+/// event can't be decoded.
+pub fn decode_event(
+    program_id: &Pubkey,
+    event_authority: &Pubkey,
+    data: &[u8],
+) -> Result<Option<KnownEvent>, DecodeError> {
+    let decode: fn(&[u8], &[u8]) -> Result<KnownEvent, DecodeError> =
+        if *program_id == axelar_solana_gateway::ID {
+            |discriminator, data| GatewayEvent::decode(discriminator, data).map(KnownEvent::Gateway)
+        } else if *program_id == axelar_solana_its::ID {
+            |discriminator, data| ItsEvent::decode(discriminator, data).map(KnownEvent::Its)
+        } else if *program_id == axelar_solana_gas_service::ID {
+            |discriminator, data| {
+                GasServiceEvent::decode(discriminator, data).map(KnownEvent::GasService)
+            }
+        } else {
+            return Ok(None);
+        };
+
+    let Some(event_data) = data.strip_prefix(EVENT_IX_TAG_LE) else {
+        return Ok(None);
+    };
+    let (expected_authority, _) = Pubkey::find_program_address(&[EVENT_AUTHORITY_SEED], program_id);
+    if *event_authority != expected_authority {
+        return Ok(None);
+    }
+
+    let (discriminator, event_data) = event_data
+        .split_at_checked(DISCRIMINATOR_LEN)
+        .ok_or(DecodeError::MissingDiscriminator)?;
+
+    decode(discriminator, event_data).map(Some)
+}
+
+/// Decodes the events emitted by the Axelar programs in `tx`, in the order
+/// they were emitted, skipping the ones that can't be decoded. A failed
+/// transaction emits no events.
+///
+/// See [`try_decode_events_from_transaction`] to surface decoding errors.
+pub fn decode_events_from_transaction(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+) -> Vec<KnownEvent> {
+    decode_events(&tx.transaction)
+        .into_iter()
+        .filter_map(Result::ok)
+        .collect()
+}
+
+/// Like [`decode_events_from_transaction`], failing on the first event that
+/// can't be decoded.
+///
+/// # Errors
+///
+/// If an event CPI of one of the Axelar programs can't be decoded.
+pub fn try_decode_events_from_transaction(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+) -> Result<Vec<KnownEvent>, DecodeError> {
+    decode_events(&tx.transaction).into_iter().collect()
+}
+
+fn decode_events(tx: &EncodedTransactionWithStatusMeta) -> Vec<Result<KnownEvent, DecodeError>> {
+    let Some(meta) = tx.meta.as_ref().filter(|meta| meta.err.is_none()) else {
+        return Vec::new();
+    };
+    let OptionSerializer::Some(inner_instructions) = &meta.inner_instructions else {
+        return Vec::new();
+    };
+
+    let account_keys = match account_keys(tx) {
+        Ok(account_keys) => account_keys,
+        Err(err) => return vec![Err(err)],
+    };
+    let account_key = |index: u8| {
+        account_keys
+            .get(usize::from(index))
+            .copied()
+            .ok_or(DecodeError::AccountIndexOutOfRange(index))
+    };
+
+    inner_instructions
+        .iter()
+        .flat_map(|inner_instructions| &inner_instructions.instructions)
+        .filter_map(|instruction| {
+            let (program_id, event_authority, data) = match instruction {
+                UiInstruction::Compiled(instruction) => (
+                    account_key(instruction.program_id_index),
+                    instruction.accounts.first().copied().map(account_key),
+                    &instruction.data,
+                ),
+                UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(instruction)) => (
+                    parse_key(&instruction.program_id),
+                    instruction.accounts.first().map(|key| parse_key(key)),
+                    &instruction.data,
+                ),
+                // Only instructions of programs known to the RPC node are
+                // parsed, which never include event CPIs
+                UiInstruction::Parsed(UiParsedInstruction::Parsed(_)) => return None,
+            };
+
+            // Event CPIs always pass the event authority
+            decode_instruction(program_id, event_authority?, data).transpose()
+        })
+        .collect()
+}
+
+fn decode_instruction(
+    program_id: Result<Pubkey, DecodeError>,
+    event_authority: Result<Pubkey, DecodeError>,
+    data: &str,
+) -> Result<Option<KnownEvent>, DecodeError> {
+    let data = bs58::decode(data).into_vec()?;
+
+    decode_event(&program_id?, &event_authority?, &data)
+}
+
+/// The account keys the compiled instructions of `tx` index into: the static
+/// keys of the message followed by the addresses loaded from lookup tables.
+fn account_keys(tx: &EncodedTransactionWithStatusMeta) -> Result<Vec<Pubkey>, DecodeError> {
+    let mut account_keys = match &tx.transaction {
+        EncodedTransaction::Json(transaction) => match &transaction.message {
+            UiMessage::Raw(message) => parse_keys(&message.account_keys)?,
+            // Parsed messages already list the loaded addresses
+            UiMessage::Parsed(message) => {
+                return message
+                    .account_keys
+                    .iter()
+                    .map(|account| parse_key(&account.pubkey))
+                    .collect();
+            }
+        },
+        EncodedTransaction::Accounts(accounts) => {
+            return accounts
+                .account_keys
+                .iter()
+                .map(|account| parse_key(&account.pubkey))
+                .collect();
+        }
+        EncodedTransaction::LegacyBinary(_) | EncodedTransaction::Binary(..) => tx
+            .transaction
+            .decode()
+            .map(|transaction| transaction.message.static_account_keys().to_vec())
+            .unwrap_or_default(),
+    };
+
+    if let Some(OptionSerializer::Some(loaded_addresses)) =
+        tx.meta.as_ref().map(|meta| &meta.loaded_addresses)
+    {
+        account_keys.extend(parse_keys(&loaded_addresses.writable)?);
+        account_keys.extend(parse_keys(&loaded_addresses.readonly)?);
+    }
+
+    Ok(account_keys)
+}
+
+fn parse_keys(keys: &[String]) -> Result<Vec<Pubkey>, DecodeError> {
+    keys.iter().map(|key| parse_key(key)).collect()
+}
+
+fn parse_key(key: &str) -> Result<Pubkey, DecodeError> {
+    key.parse()
+        .map_err(|_err| DecodeError::InvalidAccountKey(key.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use event_cpi::CpiEvent;
+    use solana_sdk::hash::Hash;
+    use solana_sdk::instruction::CompiledInstruction;
+    use solana_sdk::message::MessageHeader;
+    use solana_sdk::transaction::TransactionError;
+    use solana_transaction_status::{
+        InnerInstruction, InnerInstructions, TransactionStatusMeta, UiRawMessage, UiTransaction,
+    };
+
+    use super::*;
+
+    fn event_authority(program_id: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[EVENT_AUTHORITY_SEED], program_id).0
+    }
+
+    fn event_cpi_data(event: &impl CpiEvent) -> Vec<u8> {
+        let mut data = EVENT_IX_TAG_LE.to_vec();
+        data.extend(event.data());
+        data
+    }
+
+    fn trusted_chain_set() -> its::TrustedChainSetV2 {
+        its::TrustedChainSetV2 {
+            chain_name: "ethereum".to_owned(),
+            authority: Pubkey::new_unique(),
+        }
+    }
+
+    /// A transaction with the event CPIs `(program, data)` as inner
+    /// instructions of its first instruction.
+    fn transaction(
+        event_cpis: &[(Pubkey, Vec<u8>)],
+        status: Result<(), TransactionError>,
+    ) -> EncodedConfirmedTransactionWithStatusMeta {
+        let mut account_keys = vec![Pubkey::new_unique()];
+        let mut index_of = |key: Pubkey| {
+            let index = account_keys
+                .iter()
+                .position(|account_key| *account_key == key)
+                .unwrap_or_else(|| {
+                    account_keys.push(key);
+                    account_keys.len() - 1
+                });
+            u8::try_from(index).unwrap()
+        };
+
+        let instructions = event_cpis
+            .iter()
+            .map(|(program_id, data)| InnerInstruction {
+                instruction: CompiledInstruction {
+                    program_id_index: index_of(*program_id),
+                    accounts: vec![index_of(event_authority(program_id))],
+                    data: data.clone(),
+                },
+                stack_height: Some(2),
+            })
+            .collect();
+        let meta = TransactionStatusMeta {
+            status,
+            inner_instructions: Some(vec![InnerInstructions {
+                index: 0,
+                instructions,
+            }]),
+            ..TransactionStatusMeta::default()
+        };
+
+        EncodedConfirmedTransactionWithStatusMeta {
+            slot: 0,
+            transaction: EncodedTransactionWithStatusMeta {
+                transaction: EncodedTransaction::Json(UiTransaction {
+                    signatures: Vec::new(),
+                    message: UiMessage::Raw(UiRawMessage {
+                        header: MessageHeader::default(),
+                        account_keys: account_keys.iter().map(ToString::to_string).collect(),
+                        recent_blockhash: Hash::default().to_string(),
+                        instructions: Vec::new(),
+                        address_table_lookups: None,
+                    }),
+                }),
+                meta: Some(meta.into()),
+                version: None,
+            },
+            block_time: None,
+        }
+    }
+
+    #[test]
+    fn test_discriminators_are_unique_per_program() {
+        for discriminators in [
+            GatewayEvent::DISCRIMINATORS,
+            ItsEvent::DISCRIMINATORS,
+            GasServiceEvent::DISCRIMINATORS,
+        ] {
+            let unique: HashSet<_> = discriminators
+                .iter()
+                .map(|(_, discriminator)| discriminator)
+                .collect();
+            assert_eq!(unique.len(), discriminators.len());
+        }
+    }
+
+    #[test]
+    fn test_decode_event() {
+        let event = trusted_chain_set();
+        let program_id = axelar_solana_its::ID;
+
+        let decoded = decode_event(
+            &program_id,
+            &event_authority(&program_id),
+            &event_cpi_data(&event),
+        )
+        .unwrap();
+
+        assert_eq!(
+            decoded,
+            Some(KnownEvent::Its(ItsEvent::TrustedChainSetV2(event)))
+        );
+    }
+
+    #[test]
+    fn test_events_not_signed_by_the_event_authority_are_ignored() {
+        let program_id = axelar_solana_its::ID;
+        let data = event_cpi_data(&trusted_chain_set());
+
+        assert!(decode_event(&program_id, &Pubkey::new_unique(), &data)
+            .unwrap()
+            .is_none());
+        // The event authority of another program
+        assert!(
+            decode_event(&Pubkey::new_unique(), &event_authority(&program_id), &data)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_unknown_events_fail_to_decode() {
+        let program_id = axelar_solana_gas_service::ID;
+        // An ITS event, emitted by the gas service
+        let data = event_cpi_data(&trusted_chain_set());
+
+        assert!(matches!(
+            decode_event(&program_id, &event_authority(&program_id), &data),
+            Err(DecodeError::UnknownDiscriminator(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_events_from_transaction() {
+        let its_event = trusted_chain_set();
+        let gateway_event = gateway::ChainRegistrationSetEvent {
+            chain_name: "ethereum".to_owned(),
+            registered: true,
+        };
+        let tx = transaction(
+            &[
+                (axelar_solana_its::ID, event_cpi_data(&its_event)),
+                // Not an event CPI
+                (axelar_solana_gateway::ID, vec![1, 2, 3]),
+                (axelar_solana_gateway::ID, event_cpi_data(&gateway_event)),
+                // Undecodable
+                (axelar_solana_its::ID, EVENT_IX_TAG_LE.to_vec()),
+            ],
+            Ok(()),
+        );
+
+        assert_eq!(
+            decode_events_from_transaction(&tx),
+            vec![
+                KnownEvent::Its(ItsEvent::TrustedChainSetV2(its_event)),
+                KnownEvent::Gateway(GatewayEvent::ChainRegistrationSetEvent(gateway_event)),
+            ]
+        );
+        assert!(matches!(
+            try_decode_events_from_transaction(&tx),
+            Err(DecodeError::MissingDiscriminator)
+        ));
+    }
+
+    #[test]
+    fn test_failed_transactions_emit_no_events() {
+        let tx = transaction(
+            &[(axelar_solana_its::ID, event_cpi_data(&trusted_chain_set()))],
+            Err(TransactionError::AccountNotFound),
+        );
+
+        assert!(decode_events_from_transaction(&tx).is_empty());
+    }
+}
@@ -0,0 +1,49 @@
+//! Reads the flow of interchain tokens, for wallets showing how much of the
+//! flow limit of a token is left before a transfer is attempted.
+
+use core::ops::Deref;
+
+use axelar_solana_its::state::flow_limit::FlowStatus;
+use axelar_solana_its::state::token_manager::TokenManager;
+use borsh::BorshDeserialize;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::clock::Clock;
+use solana_sdk::program_error::ProgramError;
+use solana_sdk::sysvar;
+
+/// Returns the flow of the token with `token_id` in the current epoch, the
+/// off-chain counterpart of
+/// [`GetFlowStatus`](axelar_solana_its::instruction::InterchainTokenServiceInstruction::GetFlowStatus).
+///
+/// The epoch is derived from the cluster clock rather than the local one, so
+/// the status matches what the next transfer is checked against.
+///
+/// # Errors
+///
+/// If the token manager or the clock sysvar can't be fetched or decoded.
+pub async fn get_flow_status<C>(
+    rpc_client: C,
+    token_id: [u8; 32],
+) -> Result<FlowStatus, ProgramError>
+where
+    C: Deref<Target = RpcClient> + Send + Sync,
+{
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+
+    let token_manager_data = rpc_client
+        .get_account_data(&token_manager_pda)
+        .await
+        .map_err(|_err| ProgramError::InvalidAccountData)?;
+    let token_manager = TokenManager::try_from_slice(&token_manager_data)?;
+
+    let clock_account = rpc_client
+        .get_account(&sysvar::clock::ID)
+        .await
+        .map_err(|_err| ProgramError::InvalidAccountData)?;
+    let clock: Clock = solana_sdk::account::from_account(&clock_account)
+        .ok_or(ProgramError::InvalidAccountData)?;
+
+    token_manager.flow_slot.status(clock.unix_timestamp)
+}
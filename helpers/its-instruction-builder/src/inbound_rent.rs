@@ -0,0 +1,114 @@
+//! Estimates the rent a relayer pays when executing an inbound interchain
+//! transfer, so that it can be funded before the message is executed.
+
+use core::ops::Deref;
+
+use axelar_solana_its::inbound_rent::{AccountSnapshot, InboundRentEstimate};
+use axelar_solana_its::state::token_manager::TokenManager;
+use borsh::BorshDeserialize;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::account::Account;
+use solana_sdk::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
+use solana_sdk::{system_program, sysvar};
+
+/// Returns the accounts the execution of the inbound transfer carried by
+/// `abi_payload` would create and the rent they require, the off-chain
+/// counterpart of
+/// [`EstimateInboundRent`](axelar_solana_its::instruction::InterchainTokenServiceInstruction::EstimateInboundRent).
+///
+/// The payload can either be the ITS Hub wrapped payload or the inner ITS
+/// message. The mint and its token program are read from the token manager
+/// of the transferred token.
+///
+/// # Errors
+///
+/// If the payload isn't an interchain transfer, or the accounts or the rent
+/// sysvar can't be fetched or decoded.
+pub async fn estimate_inbound_rent<C>(
+    rpc_client: C,
+    abi_payload: &[u8],
+) -> Result<InboundRentEstimate, ProgramError>
+where
+    C: Deref<Target = RpcClient> + Send + Sync,
+{
+    let transfer = axelar_solana_its::inbound_rent::decode_inbound_transfer(abi_payload)?;
+
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &transfer.token_id.0);
+    let token_manager_data = rpc_client
+        .get_account_data(&token_manager_pda)
+        .await
+        .map_err(|_err| ProgramError::InvalidAccountData)?;
+    let token_manager = TokenManager::try_from_slice(&token_manager_data)?;
+
+    let mint = token_manager.token_address;
+    let mint_account = rpc_client
+        .get_account(&mint)
+        .await
+        .map_err(|_err| ProgramError::InvalidAccountData)?;
+    let token_program = mint_account.owner;
+
+    // The accounts are derived the same way as for the on-chain estimate
+    let estimate_ix = axelar_solana_its::instruction::estimate_inbound_rent(
+        abi_payload.to_vec(),
+        mint,
+        token_program,
+    )?;
+    let [_, destination, destination_ata, _, transfer_execute] = estimate_ix.accounts.as_slice()
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let mut addresses = vec![destination.pubkey, destination_ata.pubkey];
+    if !transfer.data.is_empty() {
+        addresses.push(transfer_execute.pubkey);
+    }
+    let accounts = rpc_client
+        .get_multiple_accounts(&addresses)
+        .await
+        .map_err(|_err| ProgramError::InvalidAccountData)?;
+
+    let rent_account = rpc_client
+        .get_account(&sysvar::rent::ID)
+        .await
+        .map_err(|_err| ProgramError::InvalidAccountData)?;
+    let rent: Rent =
+        solana_sdk::account::from_account(&rent_account).ok_or(ProgramError::InvalidAccountData)?;
+
+    let snapshots = addresses
+        .iter()
+        .zip(&accounts)
+        .map(|(address, account)| snapshot(*address, account.as_ref()))
+        .collect::<Vec<_>>();
+    let [destination, destination_ata, transfer_execute @ ..] = snapshots.as_slice() else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    axelar_solana_its::inbound_rent::estimate_inbound_transfer_rent(
+        &rent,
+        &token_program,
+        &snapshot(mint, Some(&mint_account)),
+        destination,
+        destination_ata,
+        transfer_execute.first(),
+    )
+}
+
+/// The snapshot of a fetched account, accounts that don't exist being owned
+/// by the system program without data, as the runtime sees them.
+fn snapshot(address: Pubkey, account: Option<&Account>) -> AccountSnapshot<'_> {
+    account.map_or(
+        AccountSnapshot {
+            address,
+            owner: system_program::ID,
+            data: &[],
+        },
+        |account| AccountSnapshot {
+            address,
+            owner: account.owner,
+            data: &account.data,
+        },
+    )
+}
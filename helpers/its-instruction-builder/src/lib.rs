@@ -1,11 +1,15 @@
 //! Helper crate for building ITS instructions.
 
+pub mod flow_limit;
+pub mod inbound_rent;
+
 use core::ops::Deref;
 
 use axelar_solana_encoding::types::messages::Message;
 use axelar_solana_gateway::executable::AxelarMessagePayload;
 use axelar_solana_its::instruction::ExecuteInstructionInputs;
 use axelar_solana_its::state::token_manager::TokenManager;
+use axelar_solana_its::state::token_observer::TokenObserver;
 use borsh::BorshDeserialize;
 use interchain_token_transfer_gmp::GMPPayload;
 use solana_client::nonblocking::rpc_client::RpcClient;
@@ -39,6 +43,12 @@ where
             .map_err(|_err| ProgramError::InvalidArgument)?,
     );
 
+    let token_observer = if is_interchain_transfer(&payload)? {
+        try_fetch_token_observer(&token_manager_pda, &rpc_client).await?
+    } else {
+        None
+    };
+
     let (mint, token_program) =
         try_infer_mint_and_program(&token_manager_pda, &payload, rpc_client).await?;
 
@@ -50,6 +60,7 @@ where
         .payload(payload)
         .token_program(token_program)
         .mint_opt(mint)
+        .token_observer_opt(token_observer)
         .build();
 
     axelar_solana_its::instruction::execute(inputs)
@@ -111,6 +122,42 @@ where
     }
 }
 
+fn is_interchain_transfer(payload: &GMPPayload) -> Result<bool, ProgramError> {
+    match payload {
+        GMPPayload::InterchainTransfer(_) => Ok(true),
+        GMPPayload::ReceiveFromHub(inner) => {
+            let inner_payload =
+                GMPPayload::decode(&inner.payload).map_err(|_err| ProgramError::InvalidArgument)?;
+            is_interchain_transfer(&inner_payload)
+        }
+        GMPPayload::SendToHub(_)
+        | GMPPayload::LinkToken(_)
+        | GMPPayload::RegisterTokenMetadata(_)
+        | GMPPayload::DeployInterchainToken(_) => Ok(false),
+    }
+}
+
+/// Returns the observer program registered for the token, if any.
+async fn try_fetch_token_observer(
+    token_manager_pda: &Pubkey,
+    rpc_client: &RpcClient,
+) -> Result<Option<Pubkey>, ProgramError> {
+    let (token_observer_pda, _) = axelar_solana_its::find_token_observer_pda(token_manager_pda);
+    let token_observer_account = rpc_client
+        .get_account_with_commitment(&token_observer_pda, rpc_client.commitment())
+        .await
+        .map_err(|_err| ProgramError::InvalidAccountData)?
+        .value;
+
+    let Some(token_observer_account) = token_observer_account else {
+        return Ok(None);
+    };
+
+    let token_observer = TokenObserver::try_from_slice(&token_observer_account.data)?;
+
+    Ok(Some(token_observer.program_id))
+}
+
 fn ensure_payer_is_not_forwarded(payer: Pubkey, payload: &GMPPayload) -> Result<(), ProgramError> {
     match payload {
         GMPPayload::InterchainTransfer(transfer) => {
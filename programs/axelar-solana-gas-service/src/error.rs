@@ -0,0 +1,111 @@
+//! Error types
+
+use solana_program::program_error::ProgramError;
+
+/// Errors that may be returned by the Gas Service program.
+#[repr(u32)]
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum GasServiceError {
+    /// The operator paused gas payments towards the destination chain.
+    #[error("Destination chain is paused")]
+    DestinationChainPaused = 0,
+
+    /// [`MAX_PAUSED_CHAINS`](crate::state::MAX_PAUSED_CHAINS) chains are
+    /// already paused.
+    #[error("Too many paused chains")]
+    TooManyPausedChains,
+
+    /// The refunded payment is older than the
+    /// [`RefundPolicy`](crate::state::RefundPolicy) allows.
+    #[error("Refund window expired")]
+    RefundWindowExpired,
+
+    /// The timestamp of the refunded payment lies in the future.
+    #[error("Invalid payment timestamp")]
+    InvalidPaymentTimestamp,
+
+    /// No gateway `CallContract` instruction of the transaction matches a
+    /// linked gas payment.
+    #[error("Linked contract call not found")]
+    LinkedContractCallNotFound,
+
+    /// Gas for the same contract call was already paid with another refund
+    /// address.
+    #[error("Refund address mismatch")]
+    RefundAddressMismatch,
+
+    /// The refund notice was not sent by the
+    /// [`RefundNoticeSource`](crate::state::RefundNoticeSource).
+    #[error("Unauthorized refund notice")]
+    UnauthorizedRefundNotice,
+
+    /// [`MAX_QUEUED_REFUNDS`](crate::state::MAX_QUEUED_REFUNDS) refunds are
+    /// already queued.
+    #[error("Refund queue is full")]
+    RefundQueueFull,
+
+    /// No refund is queued.
+    #[error("Refund queue is empty")]
+    RefundQueueEmpty,
+
+    /// The message id doesn't fit in a
+    /// [`QueuedRefund`](crate::state::QueuedRefund).
+    #[error("Message id too long")]
+    MessageIdTooLong,
+
+    /// The [`Config`](crate::state::Config) event sequence number would
+    /// overflow.
+    #[error("Event sequence overflow")]
+    EventSequenceOverflow,
+
+    /// A [`GasPriceFeed`](crate::state::GasPriceFeed) rate has a zero
+    /// component.
+    #[error("Invalid conversion rate")]
+    InvalidConversionRate,
+
+    /// The operator didn't set a
+    /// [`GasPriceFeed`](crate::state::GasPriceFeed) for the destination
+    /// chain.
+    #[error("Gas price feed not set")]
+    GasPriceFeedNotSet,
+
+    /// The converted gas amount doesn't fit in a `u64`.
+    #[error("Gas conversion overflow")]
+    GasConversionOverflow,
+
+    /// The converted gas amount exceeds the maximum the payer accepted.
+    #[error("Converted gas exceeds maximum")]
+    ConvertedGasExceedsMaximum,
+
+    /// The [`RefundAddressPolicy`](crate::state::RefundAddressPolicy)
+    /// requires the refund address of a payment to be its payer.
+    #[error("Refund address must be the payer")]
+    RefundAddressNotPayer,
+
+    /// The pre-registration window is zero or longer than
+    /// [`MAX_PRE_REGISTRATION_SLOTS`](crate::state::MAX_PRE_REGISTRATION_SLOTS).
+    #[error("Invalid pre-registration window")]
+    InvalidPreRegistrationWindow,
+
+    /// The [`PayloadPreRegistration`](crate::state::PayloadPreRegistration)
+    /// linked to a payment has expired.
+    #[error("Payload pre-registration expired")]
+    PayloadPreRegistrationExpired,
+
+    /// The refunded amount exceeds the gas left in the
+    /// [`GasReceipt`](crate::state::GasReceipt).
+    #[error("Refund exceeds gas receipt")]
+    RefundExceedsReceipt,
+
+    /// The message of the [`GasReceipt`](crate::state::GasReceipt) is neither
+    /// delivered nor refunded yet.
+    #[error("Gas receipt is pending")]
+    GasReceiptPending,
+}
+
+impl From<GasServiceError> for ProgramError {
+    fn from(error: GasServiceError) -> Self {
+        // GasServiceError's memory representation is an u32, so this is safe
+        Self::Custom(error as u32)
+    }
+}
@@ -1,10 +1,26 @@
 //! Events emitted by the Axelar Solana Gas service
+//!
+//! Every event carries a `sequence` number, incremented by one for each event
+//! the program emits, so that consumers can detect missed events by looking
+//! for gaps and re-sync from the last sequence number they processed.
+//!
+//! Events are decoded by their name-derived discriminator, so an existing
+//! event never changes its layout. An event gaining fields, including the
+//! sequence number, is emitted under a new versioned name instead, such as
+//! [`GasPaidEventV2`], so that decoders of the previous layout skip it rather
+//! than failing on it. The previous layout is kept to decode past
+//! transactions.
 
 use anchor_discriminators::Discriminator;
+use borsh::{BorshDeserialize, BorshSerialize};
 use event_cpi_macros::event;
 use solana_program::pubkey::Pubkey;
 
-/// Represents the event emitted when gas is paid for a contract call.
+use crate::state::RefundAddressPolicy;
+
+/// Represents the event emitted by earlier versions of the program when gas
+/// is paid for a contract call, superseded by [`GasPaidEventV2`] and kept to
+/// decode past transactions.
 #[event]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct GasPaidEvent {
@@ -24,7 +40,44 @@ pub struct GasPaidEvent {
     pub spl_token_account: Option<Pubkey>,
 }
 
-/// Represents the event emitted when gas is added.
+/// Represents the event emitted when gas is paid for a contract call.
+///
+/// Replaces the [`GasPaidEvent`] of earlier versions, extending it with the
+/// `mint`, `linked_call_contract_index`, `destination_gas_amount` and
+/// `sequence` fields.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GasPaidEventV2 {
+    /// The sender/payer of gas
+    pub sender: Pubkey,
+    /// Destination chain on the Axelar network
+    pub destination_chain: String,
+    /// Destination address on the Axelar network
+    pub destination_address: String,
+    /// The payload hash for the event we're paying for
+    pub payload_hash: [u8; 32],
+    /// The amount paid
+    pub amount: u64,
+    /// The refund address
+    pub refund_address: Pubkey,
+    /// Optional SPL token account (sender)
+    pub spl_token_account: Option<Pubkey>,
+    /// Mint of the SPL token the gas was paid in, `None` for native SOL
+    pub mint: Option<Pubkey>,
+    /// Index, within the transaction, of the gateway `CallContract`
+    /// instruction the payment was linked to, if any
+    pub linked_call_contract_index: Option<u16>,
+    /// Gas requested in destination chain native units when the payment was
+    /// converted with a [`GasPriceFeed`](crate::state::GasPriceFeed), `amount`
+    /// then being the converted lamports
+    pub destination_gas_amount: Option<u64>,
+    /// The event sequence number, see [`Config`](crate::state::Config)
+    pub sequence: u64,
+}
+
+/// Represents the event emitted by earlier versions of the program when gas
+/// is added, superseded by [`GasAddedEventV2`] and kept to decode past
+/// transactions.
 #[event]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct GasAddedEvent {
@@ -40,7 +93,47 @@ pub struct GasAddedEvent {
     pub spl_token_account: Option<Pubkey>,
 }
 
-/// Represents the event emitted when gas is refunded.
+/// Represents the event emitted when gas is added.
+///
+/// Replaces the [`GasAddedEvent`] of earlier versions, extending it with the
+/// `sequence` field.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GasAddedEventV2 {
+    /// The sender/payer of gas
+    pub sender: Pubkey,
+    /// Message Id
+    pub message_id: String,
+    /// The amount added
+    pub amount: u64,
+    /// The refund address
+    pub refund_address: Pubkey,
+    /// Optional SPL token account (sender)
+    pub spl_token_account: Option<Pubkey>,
+    /// The event sequence number, see [`Config`](crate::state::Config)
+    pub sequence: u64,
+}
+
+/// Represents the event emitted when gas is added to a contract call
+/// identified by its payload hash.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GasAddedByPayloadHashEvent {
+    /// The sender/payer of gas
+    pub sender: Pubkey,
+    /// The payload hash of the contract call the gas is added to
+    pub payload_hash: [u8; 32],
+    /// The amount added
+    pub amount: u64,
+    /// The refund address
+    pub refund_address: Pubkey,
+    /// The event sequence number, see [`Config`](crate::state::Config)
+    pub sequence: u64,
+}
+
+/// Represents the event emitted by earlier versions of the program when gas
+/// is refunded, superseded by [`GasRefundedEventV2`] and kept to decode past
+/// transactions.
 #[event]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct GasRefundedEvent {
@@ -54,7 +147,28 @@ pub struct GasRefundedEvent {
     pub spl_token_account: Option<Pubkey>,
 }
 
-/// Represents the event emitted when accumulated gas is collected.
+/// Represents the event emitted when gas is refunded.
+///
+/// Replaces the [`GasRefundedEvent`] of earlier versions, extending it with
+/// the `sequence` field.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GasRefundedEventV2 {
+    /// The receiver of the refund
+    pub receiver: Pubkey,
+    /// Message Id
+    pub message_id: String,
+    /// The amount refunded
+    pub amount: u64,
+    /// Optional SPL token account (receiver)
+    pub spl_token_account: Option<Pubkey>,
+    /// The event sequence number, see [`Config`](crate::state::Config)
+    pub sequence: u64,
+}
+
+/// Represents the event emitted by earlier versions of the program when
+/// accumulated gas is collected, superseded by [`GasCollectedEventV2`] and
+/// kept to decode past transactions.
 #[event]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct GasCollectedEvent {
@@ -65,3 +179,263 @@ pub struct GasCollectedEvent {
     /// Optional SPL token account (receiver)
     pub spl_token_account: Option<Pubkey>,
 }
+
+/// Represents the event emitted when accumulated gas is collected.
+///
+/// Replaces the [`GasCollectedEvent`] of earlier versions, extending it with
+/// the `mint` and `sequence` fields.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GasCollectedEventV2 {
+    /// The receiver of the gas
+    pub receiver: Pubkey,
+    /// The amount collected
+    pub amount: u64,
+    /// Optional SPL token account (receiver)
+    pub spl_token_account: Option<Pubkey>,
+    /// Mint of the SPL token collected, `None` for native SOL
+    pub mint: Option<Pubkey>,
+    /// The event sequence number, see [`Config`](crate::state::Config)
+    pub sequence: u64,
+}
+
+/// Why the lamport balance of the config PDA changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BorshSerialize, BorshDeserialize)]
+pub enum BalanceChangeReason {
+    /// Gas was paid for a contract call
+    GasPaid,
+    /// Gas was added to an already sent message
+    GasAdded,
+    /// Accumulated fees were collected by the operator
+    FeesCollected,
+    /// Gas was refunded by the operator
+    FeesRefunded,
+}
+
+/// Represents the event emitted by every instruction changing the lamport
+/// balance of the config PDA, next to the instruction specific event, so the
+/// balance history can be reconstructed from these events alone.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BalanceChangedEvent {
+    /// The balance before the instruction
+    pub old_balance: u64,
+    /// The balance after the instruction
+    pub new_balance: u64,
+    /// The kind of instruction that changed the balance
+    pub reason: BalanceChangeReason,
+    /// The event sequence number, see [`Config`](crate::state::Config)
+    pub sequence: u64,
+}
+
+/// Represents the event emitted when the operator updates the approver set.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ApproversSetEvent {
+    /// The registered approvers
+    pub approvers: Vec<Pubkey>,
+    /// Number of approvals required for guarded operations
+    pub threshold: u8,
+    /// Operations moving more than this amount need approvals
+    pub amount_threshold: u64,
+    /// How long, in seconds, approvals stay valid
+    pub approval_ttl: i64,
+    /// The event sequence number, see [`Config`](crate::state::Config)
+    pub sequence: u64,
+}
+
+/// Represents the event emitted when an approver approves an operation.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct OperationApprovedEvent {
+    /// The approver
+    pub approver: Pubkey,
+    /// Hash of the approved operation
+    pub operation_hash: [u8; 32],
+    /// Number of approvals collected so far
+    pub approvals: u8,
+    /// Unix timestamp after which the approvals expire
+    pub expires_at: i64,
+    /// The event sequence number, see [`Config`](crate::state::Config)
+    pub sequence: u64,
+}
+
+/// Represents the event emitted when the operator pauses or resumes gas
+/// payments towards a destination chain.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ChainPauseSetEvent {
+    /// The destination chain
+    pub destination_chain: String,
+    /// Whether gas payments towards the chain are paused
+    pub paused: bool,
+    /// The event sequence number, see [`Config`](crate::state::Config)
+    pub sequence: u64,
+}
+
+/// Represents the event emitted when the operator updates the refund window.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RefundWindowSetEvent {
+    /// Maximum age, in seconds, of a refundable payment, zero if unlimited
+    pub max_refund_age: i64,
+    /// The event sequence number, see [`Config`](crate::state::Config)
+    pub sequence: u64,
+}
+
+/// Represents the event emitted when the operator updates the refund address
+/// policy.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RefundAddressPolicySetEvent {
+    /// The refund addresses payments may name from now on
+    pub policy: RefundAddressPolicy,
+    /// The event sequence number, see [`Config`](crate::state::Config)
+    pub sequence: u64,
+}
+
+/// Represents the event emitted when the operator sets the source refund
+/// notices are accepted from.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RefundNoticeSourceSetEvent {
+    /// The source chain of refund notices
+    pub source_chain: String,
+    /// The source address of refund notices
+    pub source_address: String,
+    /// The event sequence number, see [`Config`](crate::state::Config)
+    pub sequence: u64,
+}
+
+/// Represents the event emitted when a sender closes one of its gas
+/// receipts.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GasReceiptClosedEvent {
+    /// The sender that paid the gas
+    pub sender: Pubkey,
+    /// The closed gas receipt PDA
+    pub gas_receipt: Pubkey,
+    /// The amount left in the receipt, no longer refundable
+    pub amount: u64,
+    /// The event sequence number, see [`Config`](crate::state::Config)
+    pub sequence: u64,
+}
+
+/// Represents the event emitted when the operator reports the message of a
+/// gas receipt as delivered.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GasReceiptDeliveredEvent {
+    /// The message id the gas was paid for
+    pub message_id: String,
+    /// The delivered gas receipt PDA
+    pub gas_receipt: Pubkey,
+    /// The event sequence number, see [`Config`](crate::state::Config)
+    pub sequence: u64,
+}
+
+/// Represents the event emitted when the operator queues a refund for
+/// keepers to process.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RefundEnqueuedEvent {
+    /// The receiver of the refund
+    pub receiver: Pubkey,
+    /// Message Id
+    pub message_id: String,
+    /// The amount to refund
+    pub amount: u64,
+    /// Number of refunds queued, including this one
+    pub queued: u8,
+    /// The event sequence number, see [`Config`](crate::state::Config)
+    pub sequence: u64,
+}
+
+/// Represents the event emitted when the operator drops the oldest queued
+/// refund without paying it out.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RefundSkippedEvent {
+    /// The receiver of the skipped refund
+    pub receiver: Pubkey,
+    /// Message Id
+    pub message_id: String,
+    /// The amount that was not refunded
+    pub amount: u64,
+    /// Number of refunds still queued
+    pub queued: u8,
+    /// The event sequence number, see [`Config`](crate::state::Config)
+    pub sequence: u64,
+}
+
+/// Represents the event emitted when the operator updates the conversion
+/// rate of a destination chain.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GasPriceFeedSetEvent {
+    /// The destination chain
+    pub destination_chain: String,
+    /// Lamports charged per `destination_units`
+    pub lamports: u64,
+    /// Destination chain native units worth `lamports`
+    pub destination_units: u64,
+    /// Destination chain native units charged per byte of payload
+    pub payload_byte_units: u32,
+    /// The event sequence number, see [`Config`](crate::state::Config)
+    pub sequence: u64,
+}
+
+/// Represents the event emitted when a sender pre-registers a contract call
+/// it intends to pay gas for, so relayers can prefetch its payload.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PayloadHashPreRegisteredEvent {
+    /// The sender that will pay the gas
+    pub sender: Pubkey,
+    /// Destination chain on the Axelar network
+    pub destination_chain: String,
+    /// Destination address on the Axelar network
+    pub destination_address: String,
+    /// The payload hash of the announced contract call
+    pub payload_hash: [u8; 32],
+    /// The pre-registration PDA, referenced by the linked payment
+    pub pre_registration: Pubkey,
+    /// Slot after which the pre-registration can no longer be linked to a
+    /// payment
+    pub expires_at_slot: u64,
+    /// The event sequence number, see [`Config`](crate::state::Config)
+    pub sequence: u64,
+}
+
+/// Represents the event emitted, next to the [`GasPaidEventV2`], when gas is
+/// paid for a pre-registered contract call.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PreRegisteredGasPaidEvent {
+    /// The sender/payer of gas
+    pub sender: Pubkey,
+    /// The payload hash of the contract call
+    pub payload_hash: [u8; 32],
+    /// The pre-registration PDA the payment is linked to, as in the
+    /// [`PayloadHashPreRegisteredEvent`]
+    pub pre_registration: Pubkey,
+    /// The event sequence number, see [`Config`](crate::state::Config)
+    pub sequence: u64,
+}
+
+/// Represents the event emitted when the operator simulates a refund or a fee
+/// collection with the devnet faucet. Only emitted by `devnet-faucet` builds.
+#[cfg(feature = "devnet-faucet")]
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DevnetFaucetEvent {
+    /// The receiver of the simulated amount
+    pub receiver: Pubkey,
+    /// The simulated amount
+    pub amount: u64,
+    /// Message Id of the simulated refund, `None` for a simulated collection
+    pub message_id: Option<String>,
+    /// The event sequence number, see [`Config`](crate::state::Config)
+    pub sequence: u64,
+}
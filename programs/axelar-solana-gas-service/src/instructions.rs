@@ -2,13 +2,17 @@
 //!
 //! This module provides constructors and definitions for all instructions that can be issued to the
 
+use crate::state::{GuardedOperation, PayloadPreRegistration, RefundAddressPolicy, RefundNotice};
 use anchor_discriminators_macros::InstructionDiscriminator;
+use axelar_solana_encoding::types::messages::Message;
+use axelar_solana_gateway::state::incoming_message::command_id;
 use solana_program::program_error::ProgramError;
-use solana_program::system_program;
 use solana_program::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
 };
+use solana_program::{system_program, sysvar};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
 
 /// Top-level instructions supported by the Axelar Solana Gas Service program.
 #[repr(u8)]
@@ -33,10 +37,19 @@ pub enum GasServiceInstruction {
 
     /// Pay gas fees for a contract call using native SOL.
     ///
+    /// Fails with [`crate::error::GasServiceError::DestinationChainPaused`] if the
+    /// operator paused payments towards `destination_chain`. The optional
+    /// `paused_chains_pda` account is required once the operator used
+    /// [`GasServiceInstruction::SetChainPaused`], and
+    /// [`pay_gas_instruction`] always passes it.
+    ///
     /// Accounts expected:
     /// 0. `[signer, writable]` The account (`sender`) paying the gas fee in lamports.
     /// 1. `[writable]` The `config_pda` account that receives the lamports.
     /// 2. `[]` The `system_program` account.
+    /// 3. `[]` The event authority PDA.
+    /// 4. `[]` The gas service program account.
+    /// 5. `[]` Optional: the `paused_chains_pda` account.
     PayGas {
         /// The target blockchain for the contract call.
         destination_chain: String,
@@ -67,10 +80,21 @@ pub enum GasServiceInstruction {
 
     /// Collect accrued native SOL fees (operator only).
     ///
+    /// Amounts above the [`ApproverSet`](crate::state::ApproverSet) threshold
+    /// also need the operation to be approved, see
+    /// [`GasServiceInstruction::ApproveOperation`]. The `approver_set_pda` is
+    /// required while approvers are registered, the `operation_approval_pda`
+    /// only for amounts above the threshold.
+    ///
     /// Accounts expected:
     /// 1. `[signer, read-only]` The `operator` account authorized to collect fees.
     /// 2. `[writable]` The `config_pda` account holding the accrued lamports to collect.
     /// 3. `[writable]` The `receiver` account where the collected lamports will be sent.
+    /// 4. `[]` The event authority PDA.
+    /// 5. `[]` The gas service program account.
+    /// 6. `[]` Optional: the `approver_set_pda` account.
+    /// 7. `[writable]` Optional: the `operation_approval_pda` account, closed
+    ///    into the `config_pda` when consumed.
     CollectFees {
         /// The amount of SOL to collect as fees.
         amount: u64,
@@ -78,16 +102,616 @@ pub enum GasServiceInstruction {
 
     /// Refund previously collected native SOL fees (operator only).
     ///
+    /// Amounts above the [`ApproverSet`](crate::state::ApproverSet) threshold
+    /// also need the operation to be approved, see
+    /// [`GasServiceInstruction::ApproveOperation`]. The `approver_set_pda` is
+    /// required while approvers are registered, the `operation_approval_pda`
+    /// only for amounts above the threshold.
+    ///
+    /// The refund is taken out of the [`GasReceipt`](crate::state::GasReceipt)
+    /// of the payment, whose payment time is checked against the
+    /// [`RefundPolicy`](crate::state::RefundPolicy) window, see
+    /// [`GasServiceInstruction::SetRefundWindow`]. The receipt is closed once
+    /// fully refunded, its rent going to the `receiver`.
+    ///
     /// Accounts expected:
     /// 1. `[signer, read-only]` The `operator` account authorized to issue refunds.
-    /// 2. `[writable]` The `receiver` account that will receive the refunded lamports.
+    /// 2. `[writable]` The `receiver` account, the refund address of the receipt.
     /// 3. `[writable]` The `config_pda` account from which lamports are refunded.
+    /// 4. `[]` The event authority PDA.
+    /// 5. `[]` The gas service program account.
+    /// 6. `[]` The `refund_policy_pda` account.
+    /// 7. `[writable]` The `gas_receipt_pda` account of the refunded payment.
+    /// 8. `[]` Optional: the `approver_set_pda` account.
+    /// 9. `[writable]` Optional: the `operation_approval_pda` account, closed
+    ///    into the `config_pda` when consumed.
     RefundFees {
         /// Message Id
         message_id: String,
         /// The amount of SOL to be refunded.
         amount: u64,
     },
+
+    /// Register the approvers guarding collect and refund operations above
+    /// `amount_threshold` (operator only). An empty list disables the scheme.
+    ///
+    /// Once approvers are registered, replacing or disabling them is a
+    /// [`GuardedOperation::SetApprovers`](crate::state::GuardedOperation::SetApprovers)
+    /// that needs `threshold` approvals of the registered approvers, see
+    /// [`GasServiceInstruction::ApproveOperation`].
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The account (`payer`) paying for PDA creation
+    /// 1. `[signer, writable]` The `operator` account.
+    /// 2. `[writable]` The `config_pda` account.
+    /// 3. `[writable]` The `approver_set_pda` account.
+    /// 4. `[writable]` The `operation_approval_pda` account, closed when consumed.
+    /// 5. `[]` The `system_program` account.
+    SetApprovers {
+        /// The approver keys, at most
+        /// [`MAX_APPROVERS`](crate::state::MAX_APPROVERS).
+        approvers: Vec<Pubkey>,
+        /// Number of approvals required for a guarded operation.
+        threshold: u8,
+        /// Operations moving more than this amount need approvals.
+        amount_threshold: u64,
+        /// How long, in seconds, approvals stay valid.
+        approval_ttl: i64,
+    },
+
+    /// Approve a guarded operation (approvers only).
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The account (`payer`) paying for PDA creation
+    /// 1. `[signer]` The `approver` account.
+    /// 2. `[writable]` The `config_pda` account.
+    /// 3. `[]` The `approver_set_pda` account.
+    /// 4. `[writable]` The `operation_approval_pda` account.
+    /// 5. `[]` The `system_program` account.
+    ApproveOperation {
+        /// The approved operation.
+        operation: GuardedOperation,
+    },
+
+    /// Pause or resume gas payments towards a destination chain (operator
+    /// only). At most [`MAX_PAUSED_CHAINS`](crate::state::MAX_PAUSED_CHAINS)
+    /// chains can be paused at once.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The account (`payer`) paying for PDA creation
+    /// 1. `[signer]` The `operator` account.
+    /// 2. `[writable]` The `config_pda` account.
+    /// 3. `[writable]` The `paused_chains_pda` account.
+    /// 4. `[]` The `system_program` account.
+    SetChainPaused {
+        /// The destination chain to pause or resume payments towards.
+        destination_chain: String,
+        /// Whether payments towards the chain are paused.
+        paused: bool,
+    },
+
+    /// Set how long after the original payment refunds can be issued
+    /// (operator only).
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The account (`payer`) paying for PDA creation
+    /// 1. `[signer]` The `operator` account.
+    /// 2. `[writable]` The `config_pda` account.
+    /// 3. `[writable]` The `refund_policy_pda` account.
+    /// 4. `[]` The `system_program` account.
+    SetRefundWindow {
+        /// Maximum age, in seconds, of a payment that can still be refunded.
+        /// Zero disables the window.
+        max_refund_age: i64,
+    },
+
+    /// Pay gas fees for an already sent message using native SOL, recording
+    /// the payment in a [`GasReceipt`](crate::state::GasReceipt) so it is
+    /// refunded automatically if the message turns out to be undeliverable,
+    /// see [`GasServiceInstruction::ProcessRefundNotice`].
+    ///
+    /// The message id names the transaction that called the gateway, so the
+    /// gas is paid in a later transaction, as with
+    /// [`GasServiceInstruction::AddGas`]. Payments for the same message add
+    /// up in the same receipt, without restarting the refund window, and fail
+    /// with [`crate::error::GasServiceError::RefundAddressMismatch`] if they
+    /// name another refund address. The sender that opened the receipt closes
+    /// it with [`GasServiceInstruction::CloseGasReceipt`] once the message was
+    /// delivered or refunded.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The account (`sender`) paying the gas fee in lamports.
+    /// 1. `[writable]` The `config_pda` account that receives the lamports.
+    /// 2. `[writable]` The `gas_receipt_pda` account of the message.
+    /// 3. `[]` The `system_program` account.
+    PayGasWithReceipt {
+        /// The id of the paid message: the signature of the transaction that
+        /// called the gateway and the index of its `CallContractEvent`.
+        message_id: String,
+        /// The amount of SOL to pay as gas fees.
+        amount: u64,
+        /// Where refunds should be sent.
+        refund_address: Pubkey,
+    },
+
+    /// Set the only source chain and address refund notices are accepted
+    /// from (operator only).
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The account (`payer`) paying for PDA creation
+    /// 1. `[signer]` The `operator` account.
+    /// 2. `[writable]` The `config_pda` account.
+    /// 3. `[writable]` The `refund_notice_source_pda` account.
+    /// 4. `[]` The `system_program` account.
+    SetRefundNoticeSource {
+        /// The source chain of refund notices, normally the Axelar hub.
+        source_chain: String,
+        /// The source address of refund notices.
+        source_address: String,
+    },
+
+    /// Refund the gas recorded in a [`GasReceipt`](crate::state::GasReceipt)
+    /// to its refund address, as requested by a
+    /// [`RefundNotice`](crate::state::RefundNotice) GMP message from the
+    /// [`RefundNoticeSource`](crate::state::RefundNoticeSource). Anyone can
+    /// relay the notice once the gateway approved it.
+    ///
+    /// The receipt is closed, its rent going to the refund address as well.
+    /// Payments older than the [`RefundPolicy`](crate::state::RefundPolicy)
+    /// window are not refunded.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The account (`payer`) that stored the message payload.
+    /// 1. `[writable]` The gateway `incoming_message_pda` account.
+    /// 2. `[]` The gateway `message_payload_pda` account.
+    /// 3. `[]` The gateway validate message `signing_pda` of this program.
+    /// 4. `[]` The `gateway_root_pda` account.
+    /// 5. `[]` The gateway event authority account.
+    /// 6. `[]` The gateway program account.
+    /// 7. `[writable]` The `config_pda` account from which lamports are refunded.
+    /// 8. `[]` The `refund_notice_source_pda` account.
+    /// 9. `[]` The `refund_policy_pda` account.
+    /// 10. `[writable]` The `gas_receipt_pda` account, closed when refunded.
+    /// 11. `[writable]` The `refund_address` account of the receipt.
+    ProcessRefundNotice {
+        /// The GMP message carrying the notice. The payload is read from the
+        /// gateway message payload account.
+        message: Message,
+    },
+
+    /// Queue a native SOL refund for anyone to process with
+    /// [`GasServiceInstruction::ProcessNextRefund`] (operator only).
+    ///
+    /// The refund is authorized when queued, as with
+    /// [`GasServiceInstruction::RefundGasReceipt`]: it is taken out of a
+    /// [`GasReceipt`](crate::state::GasReceipt) whose payment must be within
+    /// the [`RefundPolicy`](crate::state::RefundPolicy) window, and amounts
+    /// above the [`ApproverSet`](crate::state::ApproverSet) threshold need the
+    /// same approval as [`GasServiceInstruction::RefundFees`]. At most
+    /// [`MAX_QUEUED_REFUNDS`](crate::state::MAX_QUEUED_REFUNDS) refunds can
+    /// be queued at once.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The account (`payer`) paying for PDA creation
+    /// 1. `[signer, writable]` The `operator` account.
+    /// 2. `[writable]` The `receiver` account, the refund address of the receipt.
+    /// 3. `[writable]` The `config_pda` account.
+    /// 4. `[]` The `approver_set_pda` account.
+    /// 5. `[writable]` The `operation_approval_pda` account, closed when consumed.
+    /// 6. `[]` The `refund_policy_pda` account.
+    /// 7. `[writable]` The `gas_receipt_pda` account of the refunded payment.
+    /// 8. `[writable]` The `refund_queue_pda` account.
+    /// 9. `[]` The `system_program` account.
+    EnqueueRefund {
+        /// Message Id, at most
+        /// [`MAX_QUEUED_MESSAGE_ID_LEN`](crate::state::MAX_QUEUED_MESSAGE_ID_LEN)
+        /// bytes.
+        message_id: String,
+        /// The amount of SOL to be refunded.
+        amount: u64,
+    },
+
+    /// Pay out the oldest refund of the refund queue. Anyone can process the
+    /// queue. A refund that can't be paid out is dropped by the operator with
+    /// [`GasServiceInstruction::SkipNextRefund`].
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` The `config_pda` account from which lamports are refunded.
+    /// 1. `[writable]` The `refund_queue_pda` account.
+    /// 2. `[writable]` The `receiver` account of the oldest queued refund.
+    ProcessNextRefund,
+
+    /// Set the rate converting gas in the native units of a destination
+    /// chain to lamports (operator only), see
+    /// [`GasServiceInstruction::PayGasInDestinationUnits`].
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The account (`payer`) paying for PDA creation
+    /// 1. `[signer]` The `operator` account.
+    /// 2. `[writable]` The `config_pda` account.
+    /// 3. `[writable]` The `gas_price_feed_pda` account of `destination_chain`.
+    /// 4. `[]` The `system_program` account.
+    SetGasPriceFeed {
+        /// The destination chain the rate applies to.
+        destination_chain: String,
+        /// Lamports charged per `destination_units`, non-zero.
+        lamports: u64,
+        /// Destination chain native units worth `lamports`, non-zero.
+        destination_units: u64,
+        /// Destination chain native units charged per byte of payload by
+        /// [`GasServiceInstruction::QuoteFee`].
+        payload_byte_units: u32,
+    },
+
+    /// Pay native SOL gas fees for a contract call, specifying the gas in
+    /// the native units of the destination chain. The lamports charged are
+    /// computed with the [`GasPriceFeed`](crate::state::GasPriceFeed) of
+    /// `destination_chain`, rounding up.
+    ///
+    /// Fails with
+    /// [`crate::error::GasServiceError::ConvertedGasExceedsMaximum`] if the
+    /// rate changed so that more than `max_amount` lamports would be charged.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The account (`sender`) paying the gas fee in lamports.
+    /// 1. `[writable]` The `config_pda` account that receives the lamports.
+    /// 2. `[]` The `paused_chains_pda` account.
+    /// 3. `[]` The `gas_price_feed_pda` account of `destination_chain`.
+    /// 4. `[]` The `system_program` account.
+    PayGasInDestinationUnits {
+        /// The target blockchain for the contract call.
+        destination_chain: String,
+        /// The destination address on the target chain.
+        destination_address: String,
+        /// A 32-byte hash representing the payload.
+        payload_hash: [u8; 32],
+        /// The amount of gas to pay for, in destination chain native units.
+        destination_gas_amount: u64,
+        /// The maximum amount of SOL the payment may cost.
+        max_amount: u64,
+        /// Where refunds should be sent.
+        refund_address: Pubkey,
+    },
+
+    /// Quote the lamports to pay for a contract call towards
+    /// `destination_chain`, computed with its
+    /// [`GasPriceFeed`](crate::state::GasPriceFeed) and written to the return
+    /// data as a little-endian `u64`.
+    ///
+    /// Takes no signer and changes no state, so wallets can call it with
+    /// `simulateTransaction` to show the cost of a call before paying it.
+    ///
+    /// Accounts expected:
+    /// 0. `[]` The `config_pda` account.
+    /// 1. `[]` The `paused_chains_pda` account.
+    /// 2. `[]` The `gas_price_feed_pda` account of `destination_chain`.
+    QuoteFee {
+        /// The target blockchain for the contract call.
+        destination_chain: String,
+        /// The size of the payload of the contract call, in bytes.
+        payload_size: u32,
+        /// The execution gas limit, in destination chain native units.
+        execution_limit: u64,
+    },
+
+    /// Set which refund addresses gas payments may name (operator only).
+    ///
+    /// Enforced by [`GasServiceInstruction::PayGas`],
+    /// [`GasServiceInstruction::PayLinkedGas`],
+    /// [`GasServiceInstruction::PaySplGas`], [`GasServiceInstruction::AddGas`],
+    /// [`GasServiceInstruction::AddGasByPayloadHash`],
+    /// [`GasServiceInstruction::PayGasWithReceipt`] and
+    /// [`GasServiceInstruction::PayGasInDestinationUnits`].
+    ///
+    /// Accounts expected:
+    /// 0. `[signer]` The `operator` account.
+    /// 1. `[writable]` The `config_pda` account.
+    SetRefundAddressPolicy {
+        /// The refund addresses payments may name from now on.
+        policy: RefundAddressPolicy,
+    },
+
+    /// Add more native SOL gas to a contract call identified by its payload
+    /// hash, for senders that don't know the message id of the original
+    /// payment.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The account (`sender`) providing the additional lamports.
+    /// 1. `[writable]` The `config_pda` account that receives the additional lamports.
+    /// 2. `[]` The `system_program` account.
+    AddGasByPayloadHash {
+        /// The payload hash of the contract call the gas is added to.
+        payload_hash: [u8; 32],
+        /// The additional SOL to add as gas.
+        amount: u64,
+        /// Where refunds should be sent.
+        refund_address: Pubkey,
+    },
+
+    /// Announce a contract call the sender intends to pay gas for within
+    /// `valid_for_slots` slots, so relayers can prefetch its payload and start
+    /// validating it ahead of the payment. Pay for it with
+    /// [`GasServiceInstruction::PayPreRegisteredGas`], the events of both
+    /// instructions reference the same pre-registration PDA.
+    ///
+    /// Pre-registering the same contract call again moves its expiry.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The account (`sender`) that will pay the gas, funding the PDA.
+    /// 1. `[writable]` The `config_pda` account.
+    /// 2. `[writable]` The `payload_pre_registration_pda` account of the sender and contract call.
+    /// 3. `[]` The `system_program` account.
+    PreRegisterPayloadHash {
+        /// The target blockchain for the contract call.
+        destination_chain: String,
+        /// The destination address on the target chain.
+        destination_address: String,
+        /// A 32-byte hash representing the payload.
+        payload_hash: [u8; 32],
+        /// Number of slots the pre-registration can be linked to a payment
+        /// for, at most
+        /// [`MAX_PRE_REGISTRATION_SLOTS`](crate::state::MAX_PRE_REGISTRATION_SLOTS).
+        valid_for_slots: u64,
+    },
+
+    /// Pay gas fees using native SOL for a contract call pre-registered with
+    /// [`GasServiceInstruction::PreRegisterPayloadHash`], closing the
+    /// pre-registration and refunding its rent to the sender.
+    ///
+    /// Fails with
+    /// [`crate::error::GasServiceError::PayloadPreRegistrationExpired`] once
+    /// the pre-registration window is over.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The account (`sender`) paying the gas fee in lamports.
+    /// 1. `[writable]` The `config_pda` account that receives the lamports.
+    /// 2. `[]` The `paused_chains_pda` account.
+    /// 3. `[writable]` The `payload_pre_registration_pda` account of the sender and contract call.
+    /// 4. `[]` The `system_program` account.
+    PayPreRegisteredGas {
+        /// The target blockchain for the contract call.
+        destination_chain: String,
+        /// The destination address on the target chain.
+        destination_address: String,
+        /// A 32-byte hash representing the payload.
+        payload_hash: [u8; 32],
+        /// The amount of SOL to pay as gas fees.
+        amount: u64,
+        /// Where refunds should be sent.
+        refund_address: Pubkey,
+    },
+
+    /// Pay native SOL gas fees for a contract call, charging the fee quoted
+    /// on-chain by the [`GasPriceFeed`](crate::state::GasPriceFeed) of
+    /// `destination_chain`, as [`GasServiceInstruction::QuoteFee`] would.
+    ///
+    /// The sender names the most it is willing to pay with `max_amount`, and
+    /// only the quoted fee is taken from it, so the sender never overpays
+    /// when the rate goes down between quoting and paying. Fails with
+    /// [`crate::error::GasServiceError::ConvertedGasExceedsMaximum`] if the
+    /// quote exceeds `max_amount`.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The account (`sender`) paying the gas fee in lamports.
+    /// 1. `[writable]` The `config_pda` account that receives the lamports.
+    /// 2. `[]` The `paused_chains_pda` account.
+    /// 3. `[]` The `gas_price_feed_pda` account of `destination_chain`.
+    /// 4. `[]` The `system_program` account.
+    PayGasWithQuote {
+        /// The target blockchain for the contract call.
+        destination_chain: String,
+        /// The destination address on the target chain.
+        destination_address: String,
+        /// A 32-byte hash representing the payload.
+        payload_hash: [u8; 32],
+        /// The size of the payload of the contract call, in bytes.
+        payload_size: u32,
+        /// The execution gas limit, in destination chain native units.
+        execution_limit: u64,
+        /// The maximum amount of SOL the payment may cost.
+        max_amount: u64,
+        /// Where refunds should be sent.
+        refund_address: Pubkey,
+    },
+
+    /// Validate a native SOL gas payment without moving any funds.
+    ///
+    /// Performs every check done by [`GasServiceInstruction::PayGas`] so that
+    /// wallets and relayers can simulate it as a cheap preflight.
+    ///
+    /// Accounts expected:
+    /// 0. `[]` The account (`sender`) that would pay the gas fee in lamports.
+    /// 1. `[]` The `config_pda` account.
+    /// 2. `[]` The `paused_chains_pda` account.
+    ValidatePayment {
+        /// The target blockchain for the contract call.
+        destination_chain: String,
+        /// A 32-byte hash representing the payload.
+        payload_hash: [u8; 32],
+        /// Where refunds would be sent.
+        refund_address: Pubkey,
+        /// The amount of SOL that would be paid as gas fees.
+        amount: u64,
+    },
+
+    /// Pay gas fees for a contract call using an SPL token.
+    ///
+    /// The tokens are moved into the `config_pda` associated token account for
+    /// the mint, which must already exist.
+    ///
+    /// Fails with [`crate::error::GasServiceError::DestinationChainPaused`] if the
+    /// operator paused payments towards `destination_chain`.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer]` The owner or delegate (`sender`) of the source token account.
+    /// 1. `[writable]` The `sender_token_account` the tokens are paid from.
+    /// 2. `[writable]` The `config_pda` account.
+    /// 3. `[]` The `paused_chains_pda` account.
+    /// 4. `[writable]` The `config_pda_token_account` that receives the tokens.
+    /// 5. `[]` The `mint` of the token being paid.
+    /// 6. `[]` The `token_program` account that owns the mint.
+    PaySplGas {
+        /// The target blockchain for the contract call.
+        destination_chain: String,
+        /// The destination address on the target chain.
+        destination_address: String,
+        /// A 32-byte hash representing the payload.
+        payload_hash: [u8; 32],
+        /// The amount of tokens to pay as gas fees.
+        amount: u64,
+        /// Where refunds should be sent.
+        refund_address: Pubkey,
+    },
+
+    /// Collect accrued SPL token fees (operator only).
+    ///
+    /// Amounts above the [`ApproverSet`](crate::state::ApproverSet) threshold
+    /// also need the operation to be approved, see
+    /// [`GasServiceInstruction::ApproveOperation`].
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The `operator` account authorized to collect fees.
+    /// 1. `[writable]` The `receiver_token_account` where the collected tokens will be sent.
+    /// 2. `[writable]` The `config_pda` account.
+    /// 3. `[writable]` The `config_pda_token_account` holding the accrued tokens.
+    /// 4. `[]` The `mint` of the token being collected.
+    /// 5. `[]` The `token_program` account that owns the mint.
+    /// 6. `[]` The `approver_set_pda` account.
+    /// 7. `[writable]` The `operation_approval_pda` account, closed when consumed.
+    CollectSplFees {
+        /// The amount of tokens to collect as fees.
+        amount: u64,
+    },
+
+    /// Pay gas fees for a contract call using native SOL, linked to a gateway
+    /// `CallContract` instruction in the same transaction.
+    ///
+    /// Behaves like [`GasServiceInstruction::PayGas`] but fails with
+    /// [`crate::error::GasServiceError::LinkedContractCallNotFound`] unless a
+    /// top-level gateway `CallContract` instruction of the transaction
+    /// targets `destination_chain` and `destination_address` with a payload
+    /// hashing to `payload_hash`. The index of that instruction is recorded in
+    /// the emitted [`crate::events::GasPaidEventV2`].
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The account (`sender`) paying the gas fee in lamports.
+    /// 1. `[writable]` The `config_pda` account that receives the lamports.
+    /// 2. `[]` The `paused_chains_pda` account.
+    /// 3. `[]` The `system_program` account.
+    /// 4. `[]` The `instructions` sysvar account.
+    PayLinkedGas {
+        /// The target blockchain for the contract call.
+        destination_chain: String,
+        /// The destination address on the target chain.
+        destination_address: String,
+        /// A 32-byte hash representing the payload.
+        payload_hash: [u8; 32],
+        /// The amount of SOL to pay as gas fees.
+        amount: u64,
+        /// Where refunds should be sent.
+        refund_address: Pubkey,
+    },
+
+    /// Drop the oldest refund of the refund queue without paying it out
+    /// (operator only), for refunds that can't be paid and would otherwise
+    /// block [`GasServiceInstruction::ProcessNextRefund`]. The lamports stay
+    /// in the `config_pda`.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer]` The `operator` account.
+    /// 1. `[writable]` The `config_pda` account.
+    /// 2. `[writable]` The `refund_queue_pda` account.
+    SkipNextRefund {
+        /// Message Id of the oldest queued refund, guarding against skipping
+        /// a refund the queue was cranked past.
+        message_id: String,
+    },
+
+    /// Grow a configuration PDA created before the refund address policy and
+    /// the event sequence were added to the current layout (operator only).
+    /// Until then, every instruction reading the config fails. The payer
+    /// covers the additional rent.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The account (`payer`) paying for the additional rent
+    /// 1. `[signer]` The `operator` account of this PDA.
+    /// 2. `[writable]` The `config_pda` account.
+    /// 3. `[]` The `system_program` account.
+    MigrateConfig,
+
+    /// Close a [`GasReceipt`](crate::state::GasReceipt) of the sender,
+    /// refunding its rent to the sender. Only receipts of messages that were
+    /// delivered or partly refunded can be closed, see
+    /// [`GasReceiptStatus`](crate::state::GasReceiptStatus): the gas left in
+    /// the receipt can no longer be refunded afterwards.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The `sender` account that paid the gas.
+    /// 1. `[writable]` The `config_pda` account.
+    /// 2. `[writable]` The `gas_receipt_pda` account to close.
+    CloseGasReceipt,
+
+    /// Refund native SOL fees recorded in a
+    /// [`GasReceipt`](crate::state::GasReceipt) to its refund address
+    /// (operator only).
+    ///
+    /// Same as [`GasServiceInstruction::RefundFees`], with the accounts of the
+    /// threshold scheme always passed: payments older than the
+    /// [`RefundPolicy`](crate::state::RefundPolicy) window can't be refunded,
+    /// and amounts above the [`ApproverSet`](crate::state::ApproverSet)
+    /// threshold need the same approval. The receipt is closed once fully
+    /// refunded, its rent going to the `receiver`.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The `operator` account authorized to issue refunds.
+    /// 1. `[writable]` The `receiver` account, the refund address of the receipt.
+    /// 2. `[writable]` The `config_pda` account from which lamports are refunded.
+    /// 3. `[]` The `approver_set_pda` account.
+    /// 4. `[writable]` The `operation_approval_pda` account, closed when consumed.
+    /// 5. `[]` The `refund_policy_pda` account.
+    /// 6. `[writable]` The `gas_receipt_pda` account of the refunded payment.
+    RefundGasReceipt {
+        /// Message Id
+        message_id: String,
+        /// The amount of SOL to be refunded.
+        amount: u64,
+    },
+
+    /// Report the message of a [`GasReceipt`](crate::state::GasReceipt) as
+    /// delivered (operator only), so that its sender can close it with
+    /// [`GasServiceInstruction::CloseGasReceipt`].
+    ///
+    /// Accounts expected:
+    /// 0. `[signer]` The `operator` account.
+    /// 1. `[writable]` The `config_pda` account.
+    /// 2. `[writable]` The `gas_receipt_pda` account of the message.
+    MarkGasReceiptDelivered {
+        /// The id of the delivered message.
+        message_id: String,
+    },
+
+    /// Simulates a refund or a collection of native SOL fees (operator only),
+    /// so that integrations can be tested against the gas service. Only
+    /// compiled into builds with the `devnet-faucet` feature.
+    ///
+    /// The `operator` funds the simulated amount: the lamports go from the
+    /// `operator` to the `receiver` through the `config_pda`, whose balance
+    /// is left unchanged, and a [`crate::events::DevnetFaucetEvent`] is
+    /// emitted rather than the event of a real refund or collection.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The `operator` account funding the simulated amount.
+    /// 1. `[writable]` The `receiver` account of the simulated operation.
+    /// 2. `[writable]` The `config_pda` account.
+    /// 3. `[]` The `system_program` account.
+    #[cfg(feature = "devnet-faucet")]
+    DevnetFaucet {
+        /// The amount of SOL of the simulated operation.
+        amount: u64,
+        /// The message id of the simulated refund, `None` to simulate a fee
+        /// collection.
+        message_id: Option<String>,
+    },
 }
 
 /// Builds an instruction to initialize the configuration PDA.
@@ -112,6 +736,29 @@ pub fn init_config(payer: &Pubkey, operator: &Pubkey) -> Result<Instruction, Pro
     })
 }
 
+/// Builds an instruction to grow a configuration PDA in the legacy layout to
+/// the current one.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn migrate_config(payer: &Pubkey, operator: &Pubkey) -> Result<Instruction, ProgramError> {
+    let ix_data = borsh::to_vec(&GasServiceInstruction::MigrateConfig)?;
+    let (config_pda, _bump) = crate::get_config_pda();
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*operator, true),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
 /// Builds an instruction to transfer operatorship of the gas service.
 ///
 /// # Errors
@@ -157,6 +804,7 @@ pub fn pay_gas_instruction(
         amount,
     })?;
     let (config_pda, _bump) = crate::get_config_pda();
+    let (paused_chains_pda, _bump) = crate::get_paused_chains_pda();
 
     let (event_authority, _bump) =
         Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
@@ -167,6 +815,7 @@ pub fn pay_gas_instruction(
         AccountMeta::new_readonly(system_program::ID, false),
         AccountMeta::new_readonly(event_authority, false),
         AccountMeta::new_readonly(crate::ID, false),
+        AccountMeta::new_readonly(paused_chains_pda, false),
     ];
 
     Ok(Instruction {
@@ -176,16 +825,190 @@ pub fn pay_gas_instruction(
     })
 }
 
-/// Builds an instruction to add native SOL gas.
+/// Builds an instruction to pay native SOL for a contract call made by a
+/// gateway `CallContract` instruction in the same transaction.
 ///
 /// # Errors
 /// - ix data cannot be serialized
-pub fn add_gas_instruction(
+pub fn pay_linked_gas_instruction(
     sender: &Pubkey,
-    message_id: String,
-    amount: u64,
-    refund_address: Pubkey,
-) -> Result<Instruction, ProgramError> {
+    destination_chain: String,
+    destination_address: String,
+    payload_hash: [u8; 32],
+    refund_address: Pubkey,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let ix_data = borsh::to_vec(&GasServiceInstruction::PayLinkedGas {
+        destination_chain,
+        destination_address,
+        payload_hash,
+        refund_address,
+        amount,
+    })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+    let (paused_chains_pda, _bump) = crate::get_paused_chains_pda();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(*sender, true),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new_readonly(paused_chains_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction to pay for a contract call with an SPL token.
+///
+/// # Errors
+/// - ix data cannot be serialized
+#[allow(clippy::too_many_arguments)]
+pub fn pay_spl_gas_instruction(
+    sender: &Pubkey,
+    sender_token_account: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    destination_chain: String,
+    destination_address: String,
+    payload_hash: [u8; 32],
+    refund_address: Pubkey,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let ix_data = borsh::to_vec(&GasServiceInstruction::PaySplGas {
+        destination_chain,
+        destination_address,
+        payload_hash,
+        amount,
+        refund_address,
+    })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+    let (paused_chains_pda, _bump) = crate::get_paused_chains_pda();
+    let config_pda_token_account =
+        get_associated_token_address_with_program_id(&config_pda, mint, token_program);
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*sender, true),
+        AccountMeta::new(*sender_token_account, false),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new_readonly(paused_chains_pda, false),
+        AccountMeta::new(config_pda_token_account, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the operator to collect SPL token fees.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn collect_spl_fees_instruction(
+    operator: &Pubkey,
+    receiver_token_account: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let ix_data = borsh::to_vec(&GasServiceInstruction::CollectSplFees { amount })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+    let config_pda_token_account =
+        get_associated_token_address_with_program_id(&config_pda, mint, token_program);
+    let (approver_set_pda, _bump) = crate::get_approver_set_pda();
+    let (operation_approval_pda, _bump) = crate::get_operation_approval_pda(
+        &GuardedOperation::CollectSplFees {
+            receiver_token_account: *receiver_token_account,
+            mint: *mint,
+            amount,
+        }
+        .hash(),
+    );
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(*operator, true),
+        AccountMeta::new(*receiver_token_account, false),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new(config_pda_token_account, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(approver_set_pda, false),
+        AccountMeta::new(operation_approval_pda, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction to validate a native SOL payment without executing it.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn validate_payment_instruction(
+    sender: &Pubkey,
+    destination_chain: String,
+    payload_hash: [u8; 32],
+    refund_address: Pubkey,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let ix_data = borsh::to_vec(&GasServiceInstruction::ValidatePayment {
+        destination_chain,
+        payload_hash,
+        refund_address,
+        amount,
+    })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+    let (paused_chains_pda, _bump) = crate::get_paused_chains_pda();
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*sender, false),
+        AccountMeta::new_readonly(config_pda, false),
+        AccountMeta::new_readonly(paused_chains_pda, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction to add native SOL gas.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn add_gas_instruction(
+    sender: &Pubkey,
+    message_id: String,
+    amount: u64,
+    refund_address: Pubkey,
+) -> Result<Instruction, ProgramError> {
     let ix_data = borsh::to_vec(&GasServiceInstruction::AddGas {
         message_id,
         amount,
@@ -211,6 +1034,42 @@ pub fn add_gas_instruction(
     })
 }
 
+/// Builds an instruction to add native SOL gas to the contract call with the
+/// given payload hash.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn add_gas_by_payload_hash_instruction(
+    sender: &Pubkey,
+    payload_hash: [u8; 32],
+    amount: u64,
+    refund_address: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let ix_data = borsh::to_vec(&GasServiceInstruction::AddGasByPayloadHash {
+        payload_hash,
+        amount,
+        refund_address,
+    })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(*sender, true),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
 /// Builds an instruction for the operator to collect native SOL fees.
 ///
 /// # Errors
@@ -222,6 +1081,14 @@ pub fn collect_fees_instruction(
 ) -> Result<Instruction, ProgramError> {
     let ix_data = borsh::to_vec(&GasServiceInstruction::CollectFees { amount })?;
     let (config_pda, _bump) = crate::get_config_pda();
+    let (approver_set_pda, _bump) = crate::get_approver_set_pda();
+    let (operation_approval_pda, _bump) = crate::get_operation_approval_pda(
+        &GuardedOperation::CollectFees {
+            receiver: *receiver,
+            amount,
+        }
+        .hash(),
+    );
 
     let (event_authority, _bump) =
         Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
@@ -232,6 +1099,8 @@ pub fn collect_fees_instruction(
         AccountMeta::new(config_pda, false),
         AccountMeta::new_readonly(event_authority, false),
         AccountMeta::new_readonly(crate::ID, false),
+        AccountMeta::new_readonly(approver_set_pda, false),
+        AccountMeta::new(operation_approval_pda, false),
     ];
 
     Ok(Instruction {
@@ -241,7 +1110,8 @@ pub fn collect_fees_instruction(
     })
 }
 
-/// Builds an instruction for the operator to refund previously collected native SOL fees.
+/// Builds an instruction for the operator to refund native SOL fees recorded
+/// in the gas receipt of the message `message_id`.
 ///
 /// # Errors
 /// - ix data cannot be serialized
@@ -251,8 +1121,19 @@ pub fn refund_fees_instruction(
     message_id: String,
     amount: u64,
 ) -> Result<Instruction, ProgramError> {
+    let (operation_approval_pda, _bump) = crate::get_operation_approval_pda(
+        &GuardedOperation::RefundFees {
+            receiver: *receiver,
+            message_id: message_id.clone(),
+            amount,
+        }
+        .hash(),
+    );
+    let (gas_receipt_pda, _bump) = crate::get_gas_receipt_pda(&message_id);
     let ix_data = borsh::to_vec(&GasServiceInstruction::RefundFees { message_id, amount })?;
     let (config_pda, _) = crate::get_config_pda();
+    let (approver_set_pda, _bump) = crate::get_approver_set_pda();
+    let (refund_policy_pda, _bump) = crate::get_refund_policy_pda();
 
     let (event_authority, _bump) =
         Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
@@ -263,6 +1144,850 @@ pub fn refund_fees_instruction(
         AccountMeta::new(config_pda, false),
         AccountMeta::new_readonly(event_authority, false),
         AccountMeta::new_readonly(crate::ID, false),
+        AccountMeta::new_readonly(refund_policy_pda, false),
+        AccountMeta::new(gas_receipt_pda, false),
+        AccountMeta::new_readonly(approver_set_pda, false),
+        AccountMeta::new(operation_approval_pda, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the operator to refund native SOL fees recorded
+/// in the gas receipt of the message `message_id`, always passing the
+/// accounts of the threshold scheme.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn refund_gas_receipt_instruction(
+    operator: &Pubkey,
+    receiver: &Pubkey,
+    message_id: String,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let (operation_approval_pda, _bump) = crate::get_operation_approval_pda(
+        &GuardedOperation::RefundFees {
+            receiver: *receiver,
+            message_id: message_id.clone(),
+            amount,
+        }
+        .hash(),
+    );
+    let (gas_receipt_pda, _bump) = crate::get_gas_receipt_pda(&message_id);
+    let ix_data = borsh::to_vec(&GasServiceInstruction::RefundGasReceipt { message_id, amount })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+    let (approver_set_pda, _bump) = crate::get_approver_set_pda();
+    let (refund_policy_pda, _bump) = crate::get_refund_policy_pda();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(*operator, true),
+        AccountMeta::new(*receiver, false),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new_readonly(approver_set_pda, false),
+        AccountMeta::new(operation_approval_pda, false),
+        AccountMeta::new_readonly(refund_policy_pda, false),
+        AccountMeta::new(gas_receipt_pda, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the operator to register the approvers guarding
+/// large collect and refund operations.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn set_approvers_instruction(
+    payer: &Pubkey,
+    operator: &Pubkey,
+    approvers: Vec<Pubkey>,
+    threshold: u8,
+    amount_threshold: u64,
+    approval_ttl: i64,
+) -> Result<Instruction, ProgramError> {
+    let (operation_approval_pda, _bump) = crate::get_operation_approval_pda(
+        &GuardedOperation::SetApprovers {
+            approvers: approvers.clone(),
+            threshold,
+            amount_threshold,
+            approval_ttl,
+        }
+        .hash(),
+    );
+    let ix_data = borsh::to_vec(&GasServiceInstruction::SetApprovers {
+        approvers,
+        threshold,
+        amount_threshold,
+        approval_ttl,
+    })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+    let (approver_set_pda, _bump) = crate::get_approver_set_pda();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new(*operator, true),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new(approver_set_pda, false),
+        AccountMeta::new(operation_approval_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for an approver to approve a guarded operation.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn approve_operation_instruction(
+    payer: &Pubkey,
+    approver: &Pubkey,
+    operation: GuardedOperation,
+) -> Result<Instruction, ProgramError> {
+    let (operation_approval_pda, _bump) = crate::get_operation_approval_pda(&operation.hash());
+    let ix_data = borsh::to_vec(&GasServiceInstruction::ApproveOperation { operation })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+    let (approver_set_pda, _bump) = crate::get_approver_set_pda();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*approver, true),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new_readonly(approver_set_pda, false),
+        AccountMeta::new(operation_approval_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the operator to pause or resume gas payments
+/// towards a destination chain.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn set_chain_paused_instruction(
+    payer: &Pubkey,
+    operator: &Pubkey,
+    destination_chain: String,
+    paused: bool,
+) -> Result<Instruction, ProgramError> {
+    let ix_data = borsh::to_vec(&GasServiceInstruction::SetChainPaused {
+        destination_chain,
+        paused,
+    })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+    let (paused_chains_pda, _bump) = crate::get_paused_chains_pda();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*operator, true),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new(paused_chains_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the operator to set the maximum age of
+/// refundable payments.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn set_refund_window_instruction(
+    payer: &Pubkey,
+    operator: &Pubkey,
+    max_refund_age: i64,
+) -> Result<Instruction, ProgramError> {
+    let ix_data = borsh::to_vec(&GasServiceInstruction::SetRefundWindow { max_refund_age })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+    let (refund_policy_pda, _bump) = crate::get_refund_policy_pda();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*operator, true),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new(refund_policy_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction to pay native SOL for an already sent message,
+/// recording the payment in a gas receipt.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn pay_gas_with_receipt_instruction(
+    sender: &Pubkey,
+    message_id: String,
+    refund_address: Pubkey,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let (gas_receipt_pda, _bump) = crate::get_gas_receipt_pda(&message_id);
+    let ix_data = borsh::to_vec(&GasServiceInstruction::PayGasWithReceipt {
+        message_id,
+        amount,
+        refund_address,
+    })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(*sender, true),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new(gas_receipt_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the operator to set the source refund notices
+/// are accepted from.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn set_refund_notice_source_instruction(
+    payer: &Pubkey,
+    operator: &Pubkey,
+    source_chain: String,
+    source_address: String,
+) -> Result<Instruction, ProgramError> {
+    let ix_data = borsh::to_vec(&GasServiceInstruction::SetRefundNoticeSource {
+        source_chain,
+        source_address,
+    })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+    let (refund_notice_source_pda, _bump) = crate::get_refund_notice_source_pda();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*operator, true),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new(refund_notice_source_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction relaying a refund notice approved by the gateway.
+///
+/// `payer` is the account that stored the message payload in the gateway and
+/// `refund_address` the refund address recorded in the gas receipt of the
+/// message named by `notice`.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn process_refund_notice_instruction(
+    payer: &Pubkey,
+    message: Message,
+    notice: &RefundNotice,
+    refund_address: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let command_id = command_id(&message.cc_id.chain, &message.cc_id.id);
+    let (incoming_message_pda, _bump) =
+        axelar_solana_gateway::get_incoming_message_pda(&command_id);
+    let (message_payload_pda, _bump) =
+        axelar_solana_gateway::find_message_payload_pda(incoming_message_pda, *payer);
+    let (signing_pda, _bump) =
+        axelar_solana_gateway::get_validate_message_signing_pda(crate::ID, command_id);
+    let (gateway_root_pda, _bump) = axelar_solana_gateway::get_gateway_root_config_pda();
+    let (gateway_event_authority, _bump) = Pubkey::find_program_address(
+        &[event_cpi::EVENT_AUTHORITY_SEED],
+        &axelar_solana_gateway::ID,
+    );
+
+    let ix_data = borsh::to_vec(&GasServiceInstruction::ProcessRefundNotice { message })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+    let (refund_notice_source_pda, _bump) = crate::get_refund_notice_source_pda();
+    let (refund_policy_pda, _bump) = crate::get_refund_policy_pda();
+    let (gas_receipt_pda, _bump) = crate::get_gas_receipt_pda(&notice.message_id);
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new(incoming_message_pda, false),
+        AccountMeta::new_readonly(message_payload_pda, false),
+        AccountMeta::new_readonly(signing_pda, false),
+        AccountMeta::new_readonly(gateway_root_pda, false),
+        AccountMeta::new_readonly(gateway_event_authority, false),
+        AccountMeta::new_readonly(axelar_solana_gateway::ID, false),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new_readonly(refund_notice_source_pda, false),
+        AccountMeta::new_readonly(refund_policy_pda, false),
+        AccountMeta::new(gas_receipt_pda, false),
+        AccountMeta::new(*refund_address, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the sender to close its gas receipt of a
+/// message.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn close_gas_receipt_instruction(
+    sender: &Pubkey,
+    message_id: &str,
+) -> Result<Instruction, ProgramError> {
+    let ix_data = borsh::to_vec(&GasServiceInstruction::CloseGasReceipt)?;
+    let (config_pda, _bump) = crate::get_config_pda();
+    let (gas_receipt_pda, _bump) = crate::get_gas_receipt_pda(message_id);
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(*sender, true),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new(gas_receipt_pda, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the operator to report the message of a gas
+/// receipt as delivered.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn mark_gas_receipt_delivered_instruction(
+    operator: &Pubkey,
+    message_id: String,
+) -> Result<Instruction, ProgramError> {
+    let (gas_receipt_pda, _bump) = crate::get_gas_receipt_pda(&message_id);
+    let ix_data = borsh::to_vec(&GasServiceInstruction::MarkGasReceiptDelivered { message_id })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*operator, true),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new(gas_receipt_pda, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the operator to queue a native SOL refund taken
+/// out of the gas receipt of the message `message_id`.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn enqueue_refund_instruction(
+    payer: &Pubkey,
+    operator: &Pubkey,
+    receiver: &Pubkey,
+    message_id: String,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let (operation_approval_pda, _bump) = crate::get_operation_approval_pda(
+        &GuardedOperation::RefundFees {
+            receiver: *receiver,
+            message_id: message_id.clone(),
+            amount,
+        }
+        .hash(),
+    );
+    let (gas_receipt_pda, _bump) = crate::get_gas_receipt_pda(&message_id);
+    let ix_data = borsh::to_vec(&GasServiceInstruction::EnqueueRefund { message_id, amount })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+    let (approver_set_pda, _bump) = crate::get_approver_set_pda();
+    let (refund_policy_pda, _bump) = crate::get_refund_policy_pda();
+    let (refund_queue_pda, _bump) = crate::get_refund_queue_pda();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new(*operator, true),
+        AccountMeta::new(*receiver, false),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new_readonly(approver_set_pda, false),
+        AccountMeta::new(operation_approval_pda, false),
+        AccountMeta::new_readonly(refund_policy_pda, false),
+        AccountMeta::new(gas_receipt_pda, false),
+        AccountMeta::new(refund_queue_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction paying out the oldest queued refund, which must go
+/// to `receiver`.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn process_next_refund_instruction(receiver: &Pubkey) -> Result<Instruction, ProgramError> {
+    let ix_data = borsh::to_vec(&GasServiceInstruction::ProcessNextRefund)?;
+    let (config_pda, _bump) = crate::get_config_pda();
+    let (refund_queue_pda, _bump) = crate::get_refund_queue_pda();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new(refund_queue_pda, false),
+        AccountMeta::new(*receiver, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the operator to drop the oldest queued refund,
+/// which must be for `message_id`.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn skip_next_refund_instruction(
+    operator: &Pubkey,
+    message_id: String,
+) -> Result<Instruction, ProgramError> {
+    let ix_data = borsh::to_vec(&GasServiceInstruction::SkipNextRefund { message_id })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+    let (refund_queue_pda, _bump) = crate::get_refund_queue_pda();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*operator, true),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new(refund_queue_pda, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the operator to set the rate converting gas in
+/// the native units of `destination_chain` to lamports.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn set_gas_price_feed_instruction(
+    payer: &Pubkey,
+    operator: &Pubkey,
+    destination_chain: String,
+    lamports: u64,
+    destination_units: u64,
+    payload_byte_units: u32,
+) -> Result<Instruction, ProgramError> {
+    let (gas_price_feed_pda, _bump) = crate::get_gas_price_feed_pda(&destination_chain);
+    let ix_data = borsh::to_vec(&GasServiceInstruction::SetGasPriceFeed {
+        destination_chain,
+        lamports,
+        destination_units,
+        payload_byte_units,
+    })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*operator, true),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new(gas_price_feed_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction to pay native SOL for a contract call, specifying
+/// the gas in destination chain native units.
+///
+/// # Errors
+/// - ix data cannot be serialized
+#[allow(clippy::too_many_arguments)]
+pub fn pay_gas_in_destination_units_instruction(
+    sender: &Pubkey,
+    destination_chain: String,
+    destination_address: String,
+    payload_hash: [u8; 32],
+    refund_address: Pubkey,
+    destination_gas_amount: u64,
+    max_amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let (gas_price_feed_pda, _bump) = crate::get_gas_price_feed_pda(&destination_chain);
+    let ix_data = borsh::to_vec(&GasServiceInstruction::PayGasInDestinationUnits {
+        destination_chain,
+        destination_address,
+        payload_hash,
+        destination_gas_amount,
+        max_amount,
+        refund_address,
+    })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+    let (paused_chains_pda, _bump) = crate::get_paused_chains_pda();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(*sender, true),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new_readonly(paused_chains_pda, false),
+        AccountMeta::new_readonly(gas_price_feed_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction quoting the lamports to pay for a contract call,
+/// returned in the return data of the (simulated) transaction.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn quote_fee_instruction(
+    destination_chain: String,
+    payload_size: u32,
+    execution_limit: u64,
+) -> Result<Instruction, ProgramError> {
+    let (gas_price_feed_pda, _bump) = crate::get_gas_price_feed_pda(&destination_chain);
+    let ix_data = borsh::to_vec(&GasServiceInstruction::QuoteFee {
+        destination_chain,
+        payload_size,
+        execution_limit,
+    })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+    let (paused_chains_pda, _bump) = crate::get_paused_chains_pda();
+
+    let accounts = vec![
+        AccountMeta::new_readonly(config_pda, false),
+        AccountMeta::new_readonly(paused_chains_pda, false),
+        AccountMeta::new_readonly(gas_price_feed_pda, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the operator to set which refund addresses gas
+/// payments may name.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn set_refund_address_policy_instruction(
+    operator: &Pubkey,
+    policy: RefundAddressPolicy,
+) -> Result<Instruction, ProgramError> {
+    let ix_data = borsh::to_vec(&GasServiceInstruction::SetRefundAddressPolicy { policy })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*operator, true),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction to pre-register a contract call the sender intends
+/// to pay gas for within `valid_for_slots` slots.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn pre_register_payload_hash_instruction(
+    sender: &Pubkey,
+    destination_chain: String,
+    destination_address: String,
+    payload_hash: [u8; 32],
+    valid_for_slots: u64,
+) -> Result<Instruction, ProgramError> {
+    let (payload_pre_registration_pda, _bump) = crate::get_payload_pre_registration_pda(
+        sender,
+        &PayloadPreRegistration::call_hash(&destination_chain, &destination_address, &payload_hash),
+    );
+    let ix_data = borsh::to_vec(&GasServiceInstruction::PreRegisterPayloadHash {
+        destination_chain,
+        destination_address,
+        payload_hash,
+        valid_for_slots,
+    })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(*sender, true),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new(payload_pre_registration_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction to pay native SOL for a pre-registered contract
+/// call.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn pay_pre_registered_gas_instruction(
+    sender: &Pubkey,
+    destination_chain: String,
+    destination_address: String,
+    payload_hash: [u8; 32],
+    refund_address: Pubkey,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let (payload_pre_registration_pda, _bump) = crate::get_payload_pre_registration_pda(
+        sender,
+        &PayloadPreRegistration::call_hash(&destination_chain, &destination_address, &payload_hash),
+    );
+    let ix_data = borsh::to_vec(&GasServiceInstruction::PayPreRegisteredGas {
+        destination_chain,
+        destination_address,
+        payload_hash,
+        amount,
+        refund_address,
+    })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+    let (paused_chains_pda, _bump) = crate::get_paused_chains_pda();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(*sender, true),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new_readonly(paused_chains_pda, false),
+        AccountMeta::new(payload_pre_registration_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction to pay native SOL for a contract call, charging the
+/// fee quoted on-chain for its payload size and execution limit.
+///
+/// # Errors
+/// - ix data cannot be serialized
+#[allow(clippy::too_many_arguments)]
+pub fn pay_gas_with_quote_instruction(
+    sender: &Pubkey,
+    destination_chain: String,
+    destination_address: String,
+    payload_hash: [u8; 32],
+    refund_address: Pubkey,
+    payload_size: u32,
+    execution_limit: u64,
+    max_amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let (gas_price_feed_pda, _bump) = crate::get_gas_price_feed_pda(&destination_chain);
+    let ix_data = borsh::to_vec(&GasServiceInstruction::PayGasWithQuote {
+        destination_chain,
+        destination_address,
+        payload_hash,
+        payload_size,
+        execution_limit,
+        max_amount,
+        refund_address,
+    })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+    let (paused_chains_pda, _bump) = crate::get_paused_chains_pda();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(*sender, true),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new_readonly(paused_chains_pda, false),
+        AccountMeta::new_readonly(gas_price_feed_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction simulating a refund, or a fee collection if
+/// `message_id` is `None`, funded by the `operator`. Only available in
+/// `devnet-faucet` builds.
+///
+/// # Errors
+/// - ix data cannot be serialized
+#[cfg(feature = "devnet-faucet")]
+pub fn devnet_faucet_instruction(
+    operator: &Pubkey,
+    receiver: &Pubkey,
+    amount: u64,
+    message_id: Option<String>,
+) -> Result<Instruction, ProgramError> {
+    let ix_data = borsh::to_vec(&GasServiceInstruction::DevnetFaucet { amount, message_id })?;
+    let (config_pda, _bump) = crate::get_config_pda();
+
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(*operator, true),
+        AccountMeta::new(*receiver, false),
+        AccountMeta::new(config_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
     ];
 
     Ok(Instruction {
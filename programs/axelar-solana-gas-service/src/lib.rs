@@ -1,6 +1,7 @@
 //! Axelar Gas Service program for the Solana blockchain
 #![allow(clippy::little_endian_bytes)]
 pub mod entrypoint;
+pub mod error;
 pub mod events;
 pub mod instructions;
 pub mod processor;
@@ -32,6 +33,24 @@ solana_program::declare_id!("gas1111111111111111111111111111111111111111");
 pub mod seed_prefixes {
     /// The seed used when deriving the configuration PDA.
     pub const CONFIG_SEED: &[u8] = b"gas-service";
+    /// The seed used when deriving the approver set PDA.
+    pub const APPROVER_SET_SEED: &[u8] = b"gas-service-approver-set";
+    /// The seed used when deriving an operation approval PDA.
+    pub const OPERATION_APPROVAL_SEED: &[u8] = b"gas-service-operation-approval";
+    /// The seed used when deriving the paused chains PDA.
+    pub const PAUSED_CHAINS_SEED: &[u8] = b"gas-service-paused-chains";
+    /// The seed used when deriving the refund policy PDA.
+    pub const REFUND_POLICY_SEED: &[u8] = b"gas-service-refund-policy";
+    /// The seed used when deriving a gas receipt PDA.
+    pub const GAS_RECEIPT_SEED: &[u8] = b"gas-service-receipt";
+    /// The seed used when deriving the refund notice source PDA.
+    pub const REFUND_NOTICE_SOURCE_SEED: &[u8] = b"gas-service-refund-notice-source";
+    /// The seed used when deriving the refund queue PDA.
+    pub const REFUND_QUEUE_SEED: &[u8] = b"gas-service-refund-queue";
+    /// The seed used when deriving a gas price feed PDA.
+    pub const GAS_PRICE_FEED_SEED: &[u8] = b"gas-service-gas-price-feed";
+    /// The seed used when deriving a payload pre-registration PDA.
+    pub const PAYLOAD_PRE_REGISTRATION_SEED: &[u8] = b"gas-service-payload-pre-registration";
 }
 
 /// Checks that the provided `program_id` matches the current program’s ID.
@@ -79,3 +98,328 @@ pub fn assert_valid_config_pda(bump: u8, expected_pubkey: &Pubkey) -> Result<(),
         Err(ProgramError::IncorrectProgramId)
     }
 }
+
+/// Derives the approver set PDA for this program.
+#[inline]
+#[must_use]
+pub fn get_approver_set_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[seed_prefixes::APPROVER_SET_SEED], &crate::ID)
+}
+
+/// Checks that the given `expected_pubkey` matches the approver set PDA
+/// derived with `bump`.
+///
+/// # Errors
+///
+/// - if the derived PDA does not match the `expected_pubkey`.
+#[inline]
+pub fn assert_valid_approver_set_pda(
+    bump: u8,
+    expected_pubkey: &Pubkey,
+) -> Result<(), ProgramError> {
+    let derived_pubkey =
+        Pubkey::create_program_address(&[seed_prefixes::APPROVER_SET_SEED, &[bump]], &crate::ID)
+            .map_err(|_err| ProgramError::InvalidSeeds)?;
+
+    if &derived_pubkey == expected_pubkey {
+        Ok(())
+    } else {
+        msg!("Error: Invalid Approver Set PDA");
+        Err(ProgramError::InvalidSeeds)
+    }
+}
+
+/// Derives the PDA collecting the approvals of the operation with the given
+/// hash, see [`state::GuardedOperation::hash`].
+#[inline]
+#[must_use]
+pub fn get_operation_approval_pda(operation_hash: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seed_prefixes::OPERATION_APPROVAL_SEED, operation_hash],
+        &crate::ID,
+    )
+}
+
+/// Checks that the given `expected_pubkey` matches the operation approval PDA
+/// derived with `operation_hash` and `bump`.
+///
+/// # Errors
+///
+/// - if the derived PDA does not match the `expected_pubkey`.
+#[inline]
+pub fn assert_valid_operation_approval_pda(
+    operation_hash: &[u8; 32],
+    bump: u8,
+    expected_pubkey: &Pubkey,
+) -> Result<(), ProgramError> {
+    let derived_pubkey = Pubkey::create_program_address(
+        &[
+            seed_prefixes::OPERATION_APPROVAL_SEED,
+            operation_hash,
+            &[bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_err| ProgramError::InvalidSeeds)?;
+
+    if &derived_pubkey == expected_pubkey {
+        Ok(())
+    } else {
+        msg!("Error: Invalid Operation Approval PDA");
+        Err(ProgramError::InvalidSeeds)
+    }
+}
+
+/// Derives the paused chains PDA for this program.
+#[inline]
+#[must_use]
+pub fn get_paused_chains_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[seed_prefixes::PAUSED_CHAINS_SEED], &crate::ID)
+}
+
+/// Checks that the given `expected_pubkey` matches the paused chains PDA
+/// derived with `bump`.
+///
+/// # Errors
+///
+/// - if the derived PDA does not match the `expected_pubkey`.
+#[inline]
+pub fn assert_valid_paused_chains_pda(
+    bump: u8,
+    expected_pubkey: &Pubkey,
+) -> Result<(), ProgramError> {
+    let derived_pubkey =
+        Pubkey::create_program_address(&[seed_prefixes::PAUSED_CHAINS_SEED, &[bump]], &crate::ID)
+            .map_err(|_err| ProgramError::InvalidSeeds)?;
+
+    if &derived_pubkey == expected_pubkey {
+        Ok(())
+    } else {
+        msg!("Error: Invalid Paused Chains PDA");
+        Err(ProgramError::InvalidSeeds)
+    }
+}
+
+/// Derives the refund policy PDA for this program.
+#[inline]
+#[must_use]
+pub fn get_refund_policy_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[seed_prefixes::REFUND_POLICY_SEED], &crate::ID)
+}
+
+/// Checks that the given `expected_pubkey` matches the refund policy PDA
+/// derived with `bump`.
+///
+/// # Errors
+///
+/// - if the derived PDA does not match the `expected_pubkey`.
+#[inline]
+pub fn assert_valid_refund_policy_pda(
+    bump: u8,
+    expected_pubkey: &Pubkey,
+) -> Result<(), ProgramError> {
+    let derived_pubkey =
+        Pubkey::create_program_address(&[seed_prefixes::REFUND_POLICY_SEED, &[bump]], &crate::ID)
+            .map_err(|_err| ProgramError::InvalidSeeds)?;
+
+    if &derived_pubkey == expected_pubkey {
+        Ok(())
+    } else {
+        msg!("Error: Invalid Refund Policy PDA");
+        Err(ProgramError::InvalidSeeds)
+    }
+}
+
+/// Derives the PDA recording the gas paid for the message with the given id,
+/// see [`state::GasReceipt::message_id_hash`].
+#[inline]
+#[must_use]
+pub fn get_gas_receipt_pda(message_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seed_prefixes::GAS_RECEIPT_SEED,
+            &state::GasReceipt::message_id_hash(message_id),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Checks that the given `expected_pubkey` matches the gas receipt PDA
+/// derived with `message_id_hash` and `bump`.
+///
+/// # Errors
+///
+/// - if the derived PDA does not match the `expected_pubkey`.
+#[inline]
+pub fn assert_valid_gas_receipt_pda(
+    message_id_hash: &[u8; 32],
+    bump: u8,
+    expected_pubkey: &Pubkey,
+) -> Result<(), ProgramError> {
+    let derived_pubkey = Pubkey::create_program_address(
+        &[seed_prefixes::GAS_RECEIPT_SEED, message_id_hash, &[bump]],
+        &crate::ID,
+    )
+    .map_err(|_err| ProgramError::InvalidSeeds)?;
+
+    if &derived_pubkey == expected_pubkey {
+        Ok(())
+    } else {
+        msg!("Error: Invalid Gas Receipt PDA");
+        Err(ProgramError::InvalidSeeds)
+    }
+}
+
+/// Derives the refund notice source PDA for this program.
+#[inline]
+#[must_use]
+pub fn get_refund_notice_source_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[seed_prefixes::REFUND_NOTICE_SOURCE_SEED], &crate::ID)
+}
+
+/// Checks that the given `expected_pubkey` matches the refund notice source
+/// PDA derived with `bump`.
+///
+/// # Errors
+///
+/// - if the derived PDA does not match the `expected_pubkey`.
+#[inline]
+pub fn assert_valid_refund_notice_source_pda(
+    bump: u8,
+    expected_pubkey: &Pubkey,
+) -> Result<(), ProgramError> {
+    let derived_pubkey = Pubkey::create_program_address(
+        &[seed_prefixes::REFUND_NOTICE_SOURCE_SEED, &[bump]],
+        &crate::ID,
+    )
+    .map_err(|_err| ProgramError::InvalidSeeds)?;
+
+    if &derived_pubkey == expected_pubkey {
+        Ok(())
+    } else {
+        msg!("Error: Invalid Refund Notice Source PDA");
+        Err(ProgramError::InvalidSeeds)
+    }
+}
+
+/// Derives the refund queue PDA for this program.
+#[inline]
+#[must_use]
+pub fn get_refund_queue_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[seed_prefixes::REFUND_QUEUE_SEED], &crate::ID)
+}
+
+/// Checks that the given `expected_pubkey` matches the refund queue PDA
+/// derived with `bump`.
+///
+/// # Errors
+///
+/// - if the derived PDA does not match the `expected_pubkey`.
+#[inline]
+pub fn assert_valid_refund_queue_pda(
+    bump: u8,
+    expected_pubkey: &Pubkey,
+) -> Result<(), ProgramError> {
+    let derived_pubkey =
+        Pubkey::create_program_address(&[seed_prefixes::REFUND_QUEUE_SEED, &[bump]], &crate::ID)
+            .map_err(|_err| ProgramError::InvalidSeeds)?;
+
+    if &derived_pubkey == expected_pubkey {
+        Ok(())
+    } else {
+        msg!("Error: Invalid Refund Queue PDA");
+        Err(ProgramError::InvalidSeeds)
+    }
+}
+
+/// Derives the PDA holding the [`state::GasPriceFeed`] of
+/// `destination_chain`.
+#[inline]
+#[must_use]
+pub fn get_gas_price_feed_pda(destination_chain: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seed_prefixes::GAS_PRICE_FEED_SEED,
+            &state::PausedChains::chain_hash(destination_chain),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Checks that the given `expected_pubkey` matches the gas price feed PDA
+/// derived with `destination_chain` and `bump`.
+///
+/// # Errors
+///
+/// - if the derived PDA does not match the `expected_pubkey`.
+#[inline]
+pub fn assert_valid_gas_price_feed_pda(
+    destination_chain: &str,
+    bump: u8,
+    expected_pubkey: &Pubkey,
+) -> Result<(), ProgramError> {
+    let derived_pubkey = Pubkey::create_program_address(
+        &[
+            seed_prefixes::GAS_PRICE_FEED_SEED,
+            &state::PausedChains::chain_hash(destination_chain),
+            &[bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_err| ProgramError::InvalidSeeds)?;
+
+    if &derived_pubkey == expected_pubkey {
+        Ok(())
+    } else {
+        msg!("Error: Invalid Gas Price Feed PDA");
+        Err(ProgramError::InvalidSeeds)
+    }
+}
+
+/// Derives the PDA of the [`state::PayloadPreRegistration`] of `sender` for
+/// the contract call with the given hash, see
+/// [`state::PayloadPreRegistration::call_hash`].
+#[inline]
+#[must_use]
+pub fn get_payload_pre_registration_pda(sender: &Pubkey, call_hash: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seed_prefixes::PAYLOAD_PRE_REGISTRATION_SEED,
+            sender.as_ref(),
+            call_hash,
+        ],
+        &crate::ID,
+    )
+}
+
+/// Checks that the given `expected_pubkey` matches the payload
+/// pre-registration PDA derived with `sender`, `call_hash` and `bump`.
+///
+/// # Errors
+///
+/// - if the derived PDA does not match the `expected_pubkey`.
+#[inline]
+pub fn assert_valid_payload_pre_registration_pda(
+    sender: &Pubkey,
+    call_hash: &[u8; 32],
+    bump: u8,
+    expected_pubkey: &Pubkey,
+) -> Result<(), ProgramError> {
+    let derived_pubkey = Pubkey::create_program_address(
+        &[
+            seed_prefixes::PAYLOAD_PRE_REGISTRATION_SEED,
+            sender.as_ref(),
+            call_hash,
+            &[bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_err| ProgramError::InvalidSeeds)?;
+
+    if &derived_pubkey == expected_pubkey {
+        Ok(())
+    } else {
+        msg!("Error: Invalid Payload Pre-Registration PDA");
+        Err(ProgramError::InvalidSeeds)
+    }
+}
@@ -6,16 +6,44 @@ use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubke
 
 use crate::{check_program_account, instructions::GasServiceInstruction};
 
+#[cfg(feature = "devnet-faucet")]
+use self::devnet_faucet::process_devnet_faucet;
 use self::{
-    initialize::process_initialize_config,
+    approvals::{process_approve_operation, process_set_approvers},
+    gas_price_feed::{
+        process_pay_gas_in_destination_units, process_pay_gas_with_quote, process_quote_fee,
+        process_set_gas_price_feed,
+    },
+    initialize::{process_initialize_config, process_migrate_config},
     native::{
-        add_native_gas, collect_fees_native, process_pay_native_for_contract_call, refund_native,
+        add_native_gas, add_native_gas_by_payload_hash, collect_fees_native,
+        process_pay_native_for_contract_call, refund_gas_receipt, refund_native,
+        validate_native_payment,
+    },
+    paused_chains::process_set_chain_paused,
+    pre_registration::{process_pay_pre_registered_gas, process_pre_register_payload_hash},
+    refund_notice::{
+        process_close_gas_receipt, process_mark_gas_receipt_delivered,
+        process_pay_gas_with_receipt, process_refund_notice, process_set_refund_notice_source,
     },
+    refund_policy::{process_set_refund_address_policy, process_set_refund_window},
+    refund_queue::{process_enqueue_refund, process_next_refund, process_skip_next_refund},
+    spl::{collect_fees_spl, process_pay_spl_for_contract_call},
     transfer_operatorship::process_transfer_operatorship,
 };
 
+mod approvals;
+#[cfg(feature = "devnet-faucet")]
+mod devnet_faucet;
+mod gas_price_feed;
 mod initialize;
 mod native;
+mod paused_chains;
+mod pre_registration;
+mod refund_notice;
+mod refund_policy;
+mod refund_queue;
+mod spl;
 mod transfer_operatorship;
 
 /// Processes an instruction.
@@ -36,6 +64,7 @@ pub fn process_instruction(
 
     match instruction {
         GasServiceInstruction::Initialize => process_initialize_config(program_id, accounts),
+        GasServiceInstruction::MigrateConfig => process_migrate_config(program_id, accounts),
         GasServiceInstruction::TransferOperatorship => {
             process_transfer_operatorship(program_id, accounts)
         }
@@ -55,6 +84,58 @@ pub fn process_instruction(
             payload_hash,
             refund_address,
             amount,
+            false,
+        ),
+
+        GasServiceInstruction::PayLinkedGas {
+            destination_chain,
+            destination_address,
+            payload_hash,
+            refund_address,
+            amount,
+        } => process_pay_native_for_contract_call(
+            program_id,
+            accounts,
+            destination_chain,
+            destination_address,
+            payload_hash,
+            refund_address,
+            amount,
+            true,
+        ),
+
+        // SPL token instructions
+        GasServiceInstruction::PaySplGas {
+            destination_chain,
+            destination_address,
+            payload_hash,
+            amount,
+            refund_address,
+        } => process_pay_spl_for_contract_call(
+            program_id,
+            accounts,
+            destination_chain,
+            destination_address,
+            payload_hash,
+            refund_address,
+            amount,
+        ),
+
+        GasServiceInstruction::CollectSplFees { amount } => {
+            collect_fees_spl(program_id, accounts, amount)
+        }
+
+        GasServiceInstruction::ValidatePayment {
+            destination_chain,
+            payload_hash: _,
+            refund_address,
+            amount,
+        } => validate_native_payment(
+            program_id,
+            accounts,
+            &destination_chain,
+            refund_address,
+            amount,
         ),
 
         GasServiceInstruction::AddGas {
@@ -63,6 +144,18 @@ pub fn process_instruction(
             refund_address,
         } => add_native_gas(program_id, accounts, message_id, amount, refund_address),
 
+        GasServiceInstruction::AddGasByPayloadHash {
+            payload_hash,
+            amount,
+            refund_address,
+        } => add_native_gas_by_payload_hash(
+            program_id,
+            accounts,
+            payload_hash,
+            amount,
+            refund_address,
+        ),
+
         GasServiceInstruction::CollectFees { amount } => {
             collect_fees_native(program_id, accounts, amount)
         }
@@ -70,5 +163,174 @@ pub fn process_instruction(
         GasServiceInstruction::RefundFees { message_id, amount } => {
             refund_native(program_id, accounts, message_id, amount)
         }
+
+        // Threshold approval instructions
+        GasServiceInstruction::SetApprovers {
+            approvers,
+            threshold,
+            amount_threshold,
+            approval_ttl,
+        } => process_set_approvers(
+            program_id,
+            accounts,
+            approvers,
+            threshold,
+            amount_threshold,
+            approval_ttl,
+        ),
+
+        GasServiceInstruction::ApproveOperation { operation } => {
+            process_approve_operation(program_id, accounts, &operation)
+        }
+
+        GasServiceInstruction::SetChainPaused {
+            destination_chain,
+            paused,
+        } => process_set_chain_paused(program_id, accounts, destination_chain, paused),
+
+        GasServiceInstruction::SetRefundWindow { max_refund_age } => {
+            process_set_refund_window(program_id, accounts, max_refund_age)
+        }
+
+        // Refund notice instructions
+        GasServiceInstruction::PayGasWithReceipt {
+            message_id,
+            amount,
+            refund_address,
+        } => process_pay_gas_with_receipt(program_id, accounts, message_id, amount, refund_address),
+
+        GasServiceInstruction::SetRefundNoticeSource {
+            source_chain,
+            source_address,
+        } => process_set_refund_notice_source(program_id, accounts, source_chain, source_address),
+
+        GasServiceInstruction::ProcessRefundNotice { message } => {
+            process_refund_notice(program_id, accounts, &message)
+        }
+
+        GasServiceInstruction::CloseGasReceipt => process_close_gas_receipt(program_id, accounts),
+
+        GasServiceInstruction::MarkGasReceiptDelivered { message_id } => {
+            process_mark_gas_receipt_delivered(program_id, accounts, message_id)
+        }
+
+        GasServiceInstruction::RefundGasReceipt { message_id, amount } => {
+            refund_gas_receipt(program_id, accounts, message_id, amount)
+        }
+
+        // Refund queue instructions
+        GasServiceInstruction::EnqueueRefund { message_id, amount } => {
+            process_enqueue_refund(program_id, accounts, message_id, amount)
+        }
+
+        GasServiceInstruction::ProcessNextRefund => process_next_refund(program_id, accounts),
+        GasServiceInstruction::SkipNextRefund { message_id } => {
+            process_skip_next_refund(program_id, accounts, message_id)
+        }
+
+        // Converted gas payment instructions
+        GasServiceInstruction::SetGasPriceFeed {
+            destination_chain,
+            lamports,
+            destination_units,
+            payload_byte_units,
+        } => process_set_gas_price_feed(
+            program_id,
+            accounts,
+            destination_chain,
+            lamports,
+            destination_units,
+            payload_byte_units,
+        ),
+
+        GasServiceInstruction::PayGasInDestinationUnits {
+            destination_chain,
+            destination_address,
+            payload_hash,
+            destination_gas_amount,
+            max_amount,
+            refund_address,
+        } => process_pay_gas_in_destination_units(
+            program_id,
+            accounts,
+            destination_chain,
+            destination_address,
+            payload_hash,
+            refund_address,
+            destination_gas_amount,
+            max_amount,
+        ),
+
+        GasServiceInstruction::QuoteFee {
+            destination_chain,
+            payload_size,
+            execution_limit,
+        } => process_quote_fee(
+            program_id,
+            accounts,
+            &destination_chain,
+            payload_size,
+            execution_limit,
+        ),
+
+        GasServiceInstruction::SetRefundAddressPolicy { policy } => {
+            process_set_refund_address_policy(program_id, accounts, policy)
+        }
+
+        // Pre-registration instructions
+        GasServiceInstruction::PreRegisterPayloadHash {
+            destination_chain,
+            destination_address,
+            payload_hash,
+            valid_for_slots,
+        } => process_pre_register_payload_hash(
+            program_id,
+            accounts,
+            destination_chain,
+            destination_address,
+            payload_hash,
+            valid_for_slots,
+        ),
+
+        GasServiceInstruction::PayPreRegisteredGas {
+            destination_chain,
+            destination_address,
+            payload_hash,
+            amount,
+            refund_address,
+        } => process_pay_pre_registered_gas(
+            program_id,
+            accounts,
+            destination_chain,
+            destination_address,
+            payload_hash,
+            refund_address,
+            amount,
+        ),
+
+        GasServiceInstruction::PayGasWithQuote {
+            destination_chain,
+            destination_address,
+            payload_hash,
+            payload_size,
+            execution_limit,
+            max_amount,
+            refund_address,
+        } => process_pay_gas_with_quote(
+            program_id,
+            accounts,
+            destination_chain,
+            destination_address,
+            payload_hash,
+            refund_address,
+            payload_size,
+            execution_limit,
+            max_amount,
+        ),
+
+        #[cfg(feature = "devnet-faucet")]
+        GasServiceInstruction::DevnetFaucet { amount, message_id } => {
+            process_devnet_faucet(program_id, accounts, amount, message_id)
+        }
     }
 }
@@ -0,0 +1,448 @@
+use std::collections::BTreeSet;
+
+use event_cpi_macros::{emit_cpi, event_cpi_accounts};
+use program_utils::pda::{BytemuckedPda, ValidPDA};
+use program_utils::validate_system_account_key;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use crate::events::{ApproversSetEvent, OperationApprovedEvent};
+use crate::state::{ApproverSet, Config, GuardedOperation, OperationApproval, MAX_APPROVERS};
+use crate::{
+    assert_valid_approver_set_pda, assert_valid_operation_approval_pda, get_approver_set_pda,
+    get_operation_approval_pda, seed_prefixes,
+};
+
+use super::native::{next_event_sequence, try_load_config};
+
+/// Registers the approvers guarding collect and refund operations above
+/// `amount_threshold`. An empty set disables the threshold scheme.
+///
+/// The operator alone registers the first approvers. Replacing or disabling
+/// them afterwards needs the approval of the registered approvers.
+pub(crate) fn process_set_approvers(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    approvers: Vec<Pubkey>,
+    threshold: u8,
+    amount_threshold: u64,
+    approval_ttl: i64,
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let operator = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let approver_set_pda = next_account_info(accounts)?;
+    let operation_approval_pda = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
+
+    validate_system_account_key(system_program.key)?;
+
+    let config = try_load_config(program_id, config_pda)?;
+
+    // Check: Operator matches
+    if operator.key != &config.operator {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Check: Operator is signer
+    if !operator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    validate_approvers(&approvers, threshold, approval_ttl)?;
+
+    // Check: The registered approvers approved their replacement
+    if approver_set_pda.is_initialized_pda(program_id) {
+        let approver_set = try_load_approver_set(program_id, approver_set_pda)?;
+        if approver_set.threshold > 0 {
+            consume_operation_approval(
+                program_id,
+                operator,
+                &approver_set,
+                operation_approval_pda,
+                &GuardedOperation::SetApprovers {
+                    approvers: approvers.clone(),
+                    threshold,
+                    amount_threshold,
+                    approval_ttl,
+                },
+            )?;
+        }
+    }
+
+    let mut registered = [Pubkey::default(); MAX_APPROVERS];
+    registered
+        .get_mut(..approvers.len())
+        .ok_or(ProgramError::InvalidInstructionData)?
+        .copy_from_slice(&approvers);
+    let approver_count =
+        u8::try_from(approvers.len()).map_err(|_err| ProgramError::InvalidInstructionData)?;
+
+    if approver_set_pda.is_initialized_pda(program_id) {
+        let mut data = approver_set_pda.try_borrow_mut_data()?;
+        let approver_set =
+            ApproverSet::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        assert_valid_approver_set_pda(approver_set.bump, approver_set_pda.key)?;
+
+        *approver_set = ApproverSet::new(
+            registered,
+            approver_count,
+            threshold,
+            amount_threshold,
+            approval_ttl,
+            approver_set.epoch.wrapping_add(1),
+            approver_set.bump,
+        );
+    } else {
+        let (_, bump) = get_approver_set_pda();
+        assert_valid_approver_set_pda(bump, approver_set_pda.key)?;
+
+        program_utils::pda::init_pda_raw(
+            payer,
+            approver_set_pda,
+            program_id,
+            system_program,
+            ApproverSet::pda_size()
+                .try_into()
+                .expect("must be valid u64"),
+            &[seed_prefixes::APPROVER_SET_SEED, &[bump]],
+        )?;
+        let mut data = approver_set_pda.try_borrow_mut_data()?;
+        let approver_set =
+            ApproverSet::init_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+
+        *approver_set = ApproverSet::new(
+            registered,
+            approver_count,
+            threshold,
+            amount_threshold,
+            approval_ttl,
+            0,
+            bump,
+        );
+    }
+
+    {
+        let mut data = config_pda.try_borrow_mut_data()?;
+        let config = Config::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        config.set_approvals_required(threshold > 0);
+    }
+
+    emit_cpi!(ApproversSetEvent {
+        approvers,
+        threshold,
+        amount_threshold,
+        approval_ttl,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+fn validate_approvers(approvers: &[Pubkey], threshold: u8, approval_ttl: i64) -> ProgramResult {
+    if approvers.len() > MAX_APPROVERS {
+        msg!("At most {} approvers can be registered", MAX_APPROVERS);
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if approvers.iter().collect::<BTreeSet<_>>().len() != approvers.len() {
+        msg!("Approvers must be unique");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if approvers.is_empty() {
+        if threshold != 0 {
+            msg!("Threshold must be zero when no approvers are registered");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        return Ok(());
+    }
+
+    if threshold == 0 || usize::from(threshold) > approvers.len() {
+        msg!(
+            "Threshold must be between 1 and the number of approvers ({})",
+            approvers.len()
+        );
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if approval_ttl <= 0 {
+        msg!("Approval TTL must be positive");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    Ok(())
+}
+
+/// Records the approval of `operation` by one of the registered approvers.
+///
+/// The first approval starts the validity window of [`ApproverSet::approval_ttl`]
+/// seconds. Approving an expired operation, or one approved by a previous
+/// approver set, starts over.
+pub(crate) fn process_approve_operation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    operation: &GuardedOperation,
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let approver = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let approver_set_pda = next_account_info(accounts)?;
+    let operation_approval_pda = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
+
+    validate_system_account_key(system_program.key)?;
+
+    try_load_config(program_id, config_pda)?;
+
+    // Check: Approver is signer
+    if !approver.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let approver_set = try_load_approver_set(program_id, approver_set_pda)?;
+    let Some(approver_index) = approver_set.approver_index(approver.key) else {
+        msg!("{} is not a registered approver", approver.key);
+        return Err(ProgramError::InvalidAccountOwner);
+    };
+    let approver_bit = 1_u16
+        .checked_shl(u32::try_from(approver_index).map_err(|_err| ProgramError::InvalidArgument)?)
+        .ok_or(ProgramError::InvalidArgument)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let expires_at = now
+        .checked_add(approver_set.approval_ttl)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let operation_hash = operation.hash();
+
+    if !operation_approval_pda.is_initialized_pda(program_id) {
+        let (_, bump) = get_operation_approval_pda(&operation_hash);
+        assert_valid_operation_approval_pda(&operation_hash, bump, operation_approval_pda.key)?;
+
+        program_utils::pda::init_pda_raw(
+            payer,
+            operation_approval_pda,
+            program_id,
+            system_program,
+            OperationApproval::pda_size()
+                .try_into()
+                .expect("must be valid u64"),
+            &[
+                seed_prefixes::OPERATION_APPROVAL_SEED,
+                &operation_hash,
+                &[bump],
+            ],
+        )?;
+        let mut data = operation_approval_pda.try_borrow_mut_data()?;
+        let operation_approval =
+            OperationApproval::init_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+
+        *operation_approval =
+            OperationApproval::new(operation_hash, expires_at, approver_set.epoch, bump);
+    }
+
+    let mut data = operation_approval_pda.try_borrow_mut_data()?;
+    let operation_approval =
+        OperationApproval::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+    assert_valid_operation_approval_pda(
+        &operation_hash,
+        operation_approval.bump,
+        operation_approval_pda.key,
+    )?;
+
+    if operation_approval.is_expired(now)
+        || operation_approval.approver_set_epoch != approver_set.epoch
+    {
+        operation_approval.expires_at = expires_at;
+        operation_approval.approver_set_epoch = approver_set.epoch;
+        operation_approval.approvals = 0;
+    }
+
+    if operation_approval.approvals & approver_bit != 0 {
+        msg!("Operation already approved by {}", approver.key);
+        return Err(ProgramError::InvalidArgument);
+    }
+    operation_approval.approvals |= approver_bit;
+
+    let approvals = u8::try_from(operation_approval.approval_count())
+        .map_err(|_err| ProgramError::InvalidAccountData)?;
+    let expires_at = operation_approval.expires_at;
+    drop(data);
+
+    emit_cpi!(OperationApprovedEvent {
+        approver: *approver.key,
+        operation_hash,
+        approvals,
+        expires_at,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+/// Checks that `operation` collected enough approvals when the approver set
+/// requires them for `amount`, consuming the approvals and returning the rent
+/// of the approval PDA to the operator.
+pub(super) fn ensure_operation_approved(
+    program_id: &Pubkey,
+    operator: &AccountInfo<'_>,
+    approver_set_pda: &AccountInfo<'_>,
+    operation_approval_pda: &AccountInfo<'_>,
+    operation: &GuardedOperation,
+    amount: u64,
+) -> ProgramResult {
+    if !approver_set_pda.is_initialized_pda(program_id) {
+        // Check: the threshold scheme is not configured, not just skipped
+        if approver_set_pda.key != &get_approver_set_pda().0 {
+            msg!("Error: Invalid Approver Set PDA");
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        return Ok(());
+    }
+
+    ensure_operation_approved_with(
+        program_id,
+        operator,
+        &try_load_approver_set(program_id, approver_set_pda)?,
+        Some(operation_approval_pda),
+        operation,
+        amount,
+    )
+}
+
+/// Like [`ensure_operation_approved`], for instructions that predate the
+/// threshold scheme and take its accounts as optional trailing accounts.
+///
+/// The approver set PDA is required once the config records that approvals
+/// are required, and the operation approval PDA only when `amount` is above
+/// the threshold. The operator of these instructions is read-only, so the rent
+/// of the approval PDA goes to the config PDA instead.
+pub(super) fn ensure_operation_approved_optional(
+    program_id: &Pubkey,
+    config: &Config,
+    config_pda: &AccountInfo<'_>,
+    approver_set_pda: Option<&AccountInfo<'_>>,
+    operation_approval_pda: Option<&AccountInfo<'_>>,
+    operation: &GuardedOperation,
+    amount: u64,
+) -> ProgramResult {
+    if !config.approvals_required() {
+        return Ok(());
+    }
+
+    let Some(approver_set_pda) = approver_set_pda else {
+        msg!("Approver Set PDA is required while approvers are registered");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    ensure_operation_approved_with(
+        program_id,
+        config_pda,
+        &try_load_approver_set(program_id, approver_set_pda)?,
+        operation_approval_pda,
+        operation,
+        amount,
+    )
+}
+
+fn ensure_operation_approved_with(
+    program_id: &Pubkey,
+    rent_destination: &AccountInfo<'_>,
+    approver_set: &ApproverSet,
+    operation_approval_pda: Option<&AccountInfo<'_>>,
+    operation: &GuardedOperation,
+    amount: u64,
+) -> ProgramResult {
+    if !approver_set.requires_approval(amount) {
+        return Ok(());
+    }
+
+    let Some(operation_approval_pda) =
+        operation_approval_pda.filter(|pda| pda.is_initialized_pda(program_id))
+    else {
+        msg!(
+            "Operations above {} require {} approvals",
+            approver_set.amount_threshold,
+            approver_set.threshold
+        );
+        return Err(ProgramError::MissingRequiredSignature);
+    };
+
+    consume_operation_approval(
+        program_id,
+        rent_destination,
+        approver_set,
+        operation_approval_pda,
+        operation,
+    )
+}
+
+/// Checks that `operation` collected the approvals the approver set requires,
+/// consuming the approvals and returning the rent of the approval PDA to
+/// `rent_destination`.
+fn consume_operation_approval(
+    program_id: &Pubkey,
+    rent_destination: &AccountInfo<'_>,
+    approver_set: &ApproverSet,
+    operation_approval_pda: &AccountInfo<'_>,
+    operation: &GuardedOperation,
+) -> ProgramResult {
+    if !operation_approval_pda.is_initialized_pda(program_id) {
+        msg!("Operation requires {} approvals", approver_set.threshold);
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    {
+        let data = operation_approval_pda.try_borrow_data()?;
+        let operation_approval =
+            OperationApproval::read(&data).ok_or(ProgramError::InvalidAccountData)?;
+        assert_valid_operation_approval_pda(
+            &operation.hash(),
+            operation_approval.bump,
+            operation_approval_pda.key,
+        )?;
+
+        if operation_approval.is_expired(Clock::get()?.unix_timestamp) {
+            msg!("Operation approvals expired");
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if operation_approval.approver_set_epoch != approver_set.epoch {
+            msg!("Operation was approved by a previous approver set");
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if operation_approval.approval_count() < u32::from(approver_set.threshold) {
+            msg!(
+                "Operation has {} of the {} required approvals",
+                operation_approval.approval_count(),
+                approver_set.threshold
+            );
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+    }
+
+    program_utils::pda::close_pda(rent_destination, operation_approval_pda, program_id)
+}
+
+fn try_load_approver_set(
+    program_id: &Pubkey,
+    approver_set_pda: &AccountInfo<'_>,
+) -> Result<ApproverSet, ProgramError> {
+    approver_set_pda.check_initialized_pda_without_deserialization(program_id)?;
+    let data = approver_set_pda.try_borrow_data()?;
+    let approver_set = ApproverSet::read(&data).ok_or(ProgramError::InvalidAccountData)?;
+    assert_valid_approver_set_pda(approver_set.bump, approver_set_pda.key)?;
+    Ok(*approver_set)
+}
@@ -0,0 +1,67 @@
+use event_cpi_macros::{emit_cpi, event_cpi_accounts};
+use program_utils::{transfer_lamports, validate_system_account_key};
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program::invoke;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_instruction;
+
+use crate::events::DevnetFaucetEvent;
+
+use super::native::{next_event_sequence, try_load_config};
+
+/// Moves `amount` lamports from the operator to the receiver through the
+/// config PDA and emits a [`DevnetFaucetEvent`] for the simulated refund or
+/// collection.
+///
+/// Nothing is taken from the fees held by the config PDA, and the events of
+/// real refunds and collections are never emitted, so indexers can't mistake
+/// a simulated operation for a real one.
+pub(crate) fn process_devnet_faucet(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    amount: u64,
+    message_id: Option<String>,
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Gas fee amount cannot be zero");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let accounts = &mut accounts.iter();
+    let operator = next_account_info(accounts)?;
+    let receiver = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
+
+    validate_system_account_key(system_program.key)?;
+    let config = try_load_config(program_id, config_pda)?;
+
+    // Check: Operator matches
+    if operator.key != &config.operator {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Check: Operator is signer
+    if !operator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    invoke(
+        &system_instruction::transfer(operator.key, config_pda.key, amount),
+        &[operator.clone(), config_pda.clone(), system_program.clone()],
+    )?;
+    transfer_lamports(config_pda, receiver, amount)?;
+
+    emit_cpi!(DevnetFaucetEvent {
+        receiver: *receiver.key,
+        amount,
+        message_id,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
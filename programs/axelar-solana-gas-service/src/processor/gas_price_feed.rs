@@ -0,0 +1,276 @@
+use event_cpi_macros::{emit_cpi, event_cpi_accounts};
+use program_utils::pda::{BytemuckedPda, ValidPDA};
+use program_utils::validate_system_account_key;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program::{invoke, set_return_data};
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_instruction;
+
+use crate::error::GasServiceError;
+use crate::events::{
+    BalanceChangeReason, BalanceChangedEvent, GasPaidEventV2, GasPriceFeedSetEvent,
+};
+use crate::state::{GasPriceFeed, PausedChains};
+use crate::{assert_valid_gas_price_feed_pda, get_gas_price_feed_pda, seed_prefixes};
+
+use super::native::{next_event_sequence, try_load_config};
+use super::paused_chains::ensure_chain_not_paused;
+
+/// Sets the rate converting gas in the native units of `destination_chain`
+/// to lamports, creating the gas price feed PDA of the chain on first use.
+pub(crate) fn process_set_gas_price_feed(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    destination_chain: String,
+    lamports: u64,
+    destination_units: u64,
+    payload_byte_units: u32,
+) -> ProgramResult {
+    if lamports == 0 || destination_units == 0 {
+        msg!("Conversion rate components cannot be zero");
+        return Err(GasServiceError::InvalidConversionRate.into());
+    }
+
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let operator = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let gas_price_feed_pda = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
+
+    validate_system_account_key(system_program.key)?;
+
+    let config = try_load_config(program_id, config_pda)?;
+
+    // Check: Operator matches
+    if operator.key != &config.operator {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Check: Operator is signer
+    if !operator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if gas_price_feed_pda.is_initialized_pda(program_id) {
+        let mut data = gas_price_feed_pda.try_borrow_mut_data()?;
+        let gas_price_feed =
+            GasPriceFeed::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        assert_valid_gas_price_feed_pda(
+            &destination_chain,
+            gas_price_feed.bump,
+            gas_price_feed_pda.key,
+        )?;
+        gas_price_feed.lamports = lamports;
+        gas_price_feed.destination_units = destination_units;
+        gas_price_feed.payload_byte_units = payload_byte_units;
+    } else {
+        let (_, bump) = get_gas_price_feed_pda(&destination_chain);
+        assert_valid_gas_price_feed_pda(&destination_chain, bump, gas_price_feed_pda.key)?;
+
+        program_utils::pda::init_pda_raw(
+            payer,
+            gas_price_feed_pda,
+            program_id,
+            system_program,
+            GasPriceFeed::pda_size()
+                .try_into()
+                .expect("must be valid u64"),
+            &[
+                seed_prefixes::GAS_PRICE_FEED_SEED,
+                &PausedChains::chain_hash(&destination_chain),
+                &[bump],
+            ],
+        )?;
+        let mut data = gas_price_feed_pda.try_borrow_mut_data()?;
+        let gas_price_feed =
+            GasPriceFeed::init_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        *gas_price_feed = GasPriceFeed::new(lamports, destination_units, payload_byte_units, bump);
+    }
+
+    emit_cpi!(GasPriceFeedSetEvent {
+        destination_chain,
+        lamports,
+        destination_units,
+        payload_byte_units,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+/// Pays native gas for a contract call, converting `destination_gas_amount`
+/// to lamports with the gas price feed of `destination_chain`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn process_pay_gas_in_destination_units(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    destination_chain: String,
+    destination_address: String,
+    payload_hash: [u8; 32],
+    refund_address: Pubkey,
+    destination_gas_amount: u64,
+    max_amount: u64,
+) -> ProgramResult {
+    pay_converted_gas(
+        program_id,
+        accounts,
+        destination_chain,
+        destination_address,
+        payload_hash,
+        refund_address,
+        max_amount,
+        |_gas_price_feed| Ok(destination_gas_amount),
+    )
+}
+
+/// Pays native gas for a contract call, charging the fee the gas price feed
+/// of `destination_chain` quotes for `payload_size` and `execution_limit`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn process_pay_gas_with_quote(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    destination_chain: String,
+    destination_address: String,
+    payload_hash: [u8; 32],
+    refund_address: Pubkey,
+    payload_size: u32,
+    execution_limit: u64,
+    max_amount: u64,
+) -> ProgramResult {
+    pay_converted_gas(
+        program_id,
+        accounts,
+        destination_chain,
+        destination_address,
+        payload_hash,
+        refund_address,
+        max_amount,
+        |gas_price_feed| gas_price_feed.quote_destination_units(payload_size, execution_limit),
+    )
+}
+
+/// Transfers the lamports worth the destination chain native units computed
+/// by `destination_gas_amount` from the sender, as long as they don't exceed
+/// `max_amount`.
+#[allow(clippy::too_many_arguments)]
+fn pay_converted_gas(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    destination_chain: String,
+    destination_address: String,
+    payload_hash: [u8; 32],
+    refund_address: Pubkey,
+    max_amount: u64,
+    destination_gas_amount: impl FnOnce(&GasPriceFeed) -> Result<u64, GasServiceError>,
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let sender = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let paused_chains_pda = next_account_info(accounts)?;
+    let gas_price_feed_pda = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
+
+    validate_system_account_key(system_program.key)?;
+
+    try_load_config(program_id, config_pda)?
+        .ensure_refund_address_allowed(sender.key, &refund_address)?;
+    ensure_chain_not_paused(program_id, paused_chains_pda, &destination_chain)?;
+
+    let gas_price_feed = load_gas_price_feed(program_id, gas_price_feed_pda, &destination_chain)?;
+
+    let destination_gas_amount = destination_gas_amount(&gas_price_feed)?;
+    let amount = gas_price_feed.to_lamports(destination_gas_amount)?;
+    if amount == 0 {
+        msg!("Gas fee amount cannot be zero");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if amount > max_amount {
+        msg!(
+            "Converted gas of {} lamports exceeds the maximum of {}",
+            amount,
+            max_amount
+        );
+        return Err(GasServiceError::ConvertedGasExceedsMaximum.into());
+    }
+
+    let old_balance = config_pda.lamports();
+    invoke(
+        &system_instruction::transfer(sender.key, config_pda.key, amount),
+        &[sender.clone(), config_pda.clone(), system_program.clone()],
+    )?;
+
+    emit_cpi!(GasPaidEventV2 {
+        sender: *sender.key,
+        destination_chain,
+        destination_address,
+        payload_hash,
+        amount,
+        refund_address,
+        spl_token_account: None,
+        mint: None,
+        linked_call_contract_index: None,
+        destination_gas_amount: Some(destination_gas_amount),
+        sequence: next_event_sequence(config_pda)?,
+    });
+    emit_cpi!(BalanceChangedEvent {
+        old_balance,
+        new_balance: config_pda.lamports(),
+        reason: BalanceChangeReason::GasPaid,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+/// Writes the lamports to pay for a contract call towards
+/// `destination_chain` to the return data, as a little-endian `u64`.
+pub(crate) fn process_quote_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    destination_chain: &str,
+    payload_size: u32,
+    execution_limit: u64,
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let config_pda = next_account_info(accounts)?;
+    let paused_chains_pda = next_account_info(accounts)?;
+    let gas_price_feed_pda = next_account_info(accounts)?;
+
+    try_load_config(program_id, config_pda)?;
+    ensure_chain_not_paused(program_id, paused_chains_pda, destination_chain)?;
+
+    let gas_price_feed = load_gas_price_feed(program_id, gas_price_feed_pda, destination_chain)?;
+    let fee = gas_price_feed.quote(payload_size, execution_limit)?;
+    set_return_data(&fee.to_le_bytes());
+
+    Ok(())
+}
+
+fn load_gas_price_feed(
+    program_id: &Pubkey,
+    gas_price_feed_pda: &AccountInfo<'_>,
+    destination_chain: &str,
+) -> Result<GasPriceFeed, ProgramError> {
+    if !gas_price_feed_pda.is_initialized_pda(program_id) {
+        msg!("No gas price feed for {}", destination_chain);
+        return Err(GasServiceError::GasPriceFeedNotSet.into());
+    }
+    let gas_price_feed = {
+        let data = gas_price_feed_pda.try_borrow_data()?;
+        *GasPriceFeed::read(&data).ok_or(ProgramError::InvalidAccountData)?
+    };
+    assert_valid_gas_price_feed_pda(
+        destination_chain,
+        gas_price_feed.bump,
+        gas_price_feed_pda.key,
+    )?;
+
+    Ok(gas_price_feed)
+}
@@ -1,9 +1,13 @@
-use program_utils::pda::BytemuckedPda;
+use program_utils::pda::{BytemuckedPda, ValidPDA};
 use solana_program::account_info::{next_account_info, AccountInfo};
 use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program::invoke;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
-use solana_program::system_program;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+use solana_program::{system_instruction, system_program};
 
 use crate::state::Config;
 use crate::{assert_valid_config_pda, get_config_pda, seed_prefixes};
@@ -45,10 +49,62 @@ pub(crate) fn process_initialize_config(
     let mut data = config_pda.try_borrow_mut_data()?;
     let gateway_config = Config::init_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
 
-    *gateway_config = Config {
-        bump,
-        operator: *operator.key,
+    *gateway_config = Config::new(*operator.key, bump);
+
+    Ok(())
+}
+
+/// Grows a config created before the refund address policy and the event
+/// sequence were added to the current layout, topping up its rent from the
+/// payer so that the collected fees are left untouched.
+pub(crate) fn process_migrate_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let operator = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let system_account = next_account_info(accounts)?;
+
+    // Check: System Program Account
+    if !system_program::check_id(system_account.key) {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    config_pda.check_initialized_pda_without_deserialization(program_id)?;
+    let (config_operator, bump) = {
+        let data = config_pda.try_borrow_data()?;
+        Config::read_legacy(&data).ok_or_else(|| {
+            msg!("Config is not in the legacy layout");
+            ProgramError::InvalidAccountData
+        })?
     };
+    assert_valid_config_pda(bump, config_pda.key)?;
+
+    // Check: Operator matches
+    if operator.key != &config_operator {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Check: Operator is signer
+    if !operator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let rent = Rent::get()?;
+    let lamports_needed = rent
+        .minimum_balance(Config::pda_size())
+        .saturating_sub(rent.minimum_balance(config_pda.data_len()));
+    invoke(
+        &system_instruction::transfer(payer.key, config_pda.key, lamports_needed),
+        &[payer.clone(), config_pda.clone(), system_account.clone()],
+    )?;
+
+    config_pda.realloc(Config::pda_size(), true)?;
+    let mut data = config_pda.try_borrow_mut_data()?;
+    let config = Config::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+    *config = Config::new(config_operator, bump);
 
     Ok(())
 }
@@ -1,6 +1,12 @@
 use crate::assert_valid_config_pda;
-use crate::events::{GasAddedEvent, GasCollectedEvent, GasPaidEvent, GasRefundedEvent};
-use crate::state::Config;
+use crate::error::GasServiceError;
+use crate::events::{
+    BalanceChangeReason, BalanceChangedEvent, GasAddedByPayloadHashEvent, GasAddedEventV2,
+    GasCollectedEventV2, GasPaidEventV2, GasRefundedEventV2,
+};
+use crate::state::{Config, GuardedOperation};
+use axelar_solana_gateway::instructions::GatewayInstruction;
+use borsh::BorshDeserialize;
 use event_cpi_macros::{emit_cpi, event_cpi_accounts};
 use program_utils::{
     pda::{BytemuckedPda, ValidPDA},
@@ -12,8 +18,14 @@ use solana_program::msg;
 use solana_program::program::invoke;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
-use solana_program::system_instruction;
+use solana_program::sysvar::instructions::load_instruction_at_checked;
+use solana_program::{keccak, system_instruction, sysvar};
+
+use super::approvals::{ensure_operation_approved, ensure_operation_approved_optional};
+use super::paused_chains::ensure_chain_not_paused;
+use super::refund_notice::debit_gas_receipt;
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn process_pay_native_for_contract_call(
     program_id: &Pubkey,
     accounts: &[AccountInfo<'_>],
@@ -22,6 +34,7 @@ pub(crate) fn process_pay_native_for_contract_call(
     payload_hash: [u8; 32],
     refund_address: Pubkey,
     amount: u64,
+    linked: bool,
 ) -> ProgramResult {
     if amount == 0 {
         msg!("Gas fee amount cannot be zero");
@@ -31,20 +44,48 @@ pub(crate) fn process_pay_native_for_contract_call(
     let accounts = &mut accounts.iter();
     let sender = next_account_info(accounts)?;
     let config_pda = next_account_info(accounts)?;
+    // `PayGas` predates the chain pause and takes the paused chains PDA as an
+    // optional trailing account instead
+    let paused_chains_pda = linked.then(|| next_account_info(accounts)).transpose()?;
     let system_program = next_account_info(accounts)?;
+    let instructions_sysvar = linked.then(|| next_account_info(accounts)).transpose()?;
     event_cpi_accounts!(accounts);
+    let paused_chains_pda = paused_chains_pda.or_else(|| accounts.next());
 
     validate_system_account_key(system_program.key)?;
 
-    try_load_config(program_id, config_pda)?;
+    let config = try_load_config(program_id, config_pda)?;
+    config.ensure_refund_address_allowed(sender.key, &refund_address)?;
+    match paused_chains_pda {
+        Some(paused_chains_pda) => {
+            ensure_chain_not_paused(program_id, paused_chains_pda, &destination_chain)?;
+        }
+        None if config.paused_chains_required() => {
+            msg!("Paused Chains PDA is required once chains can be paused");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        None => {}
+    }
 
+    let linked_call_contract_index = instructions_sysvar
+        .map(|sysvar| {
+            find_linked_call_contract(
+                sysvar,
+                &destination_chain,
+                &destination_address,
+                &payload_hash,
+            )
+        })
+        .transpose()?;
+
+    let old_balance = config_pda.lamports();
     invoke(
         &system_instruction::transfer(sender.key, config_pda.key, amount),
         &[sender.clone(), config_pda.clone(), system_program.clone()],
     )?;
 
     // Emit an event
-    emit_cpi!(GasPaidEvent {
+    emit_cpi!(GasPaidEventV2 {
         sender: *sender.key,
         destination_chain,
         destination_address,
@@ -52,23 +93,138 @@ pub(crate) fn process_pay_native_for_contract_call(
         amount,
         refund_address,
         spl_token_account: None,
+        mint: None,
+        linked_call_contract_index,
+        destination_gas_amount: None,
+        sequence: next_event_sequence(config_pda)?,
     });
+    emit_cpi!(BalanceChangedEvent {
+        old_balance,
+        new_balance: config_pda.lamports(),
+        reason: BalanceChangeReason::GasPaid,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+/// Returns the index of the first top-level gateway `CallContract`
+/// instruction of the transaction matching the paid contract call.
+///
+/// Calls made through CPI are not visible in the instructions sysvar, so only
+/// transactions invoking the gateway directly can be linked.
+fn find_linked_call_contract(
+    instructions_sysvar: &AccountInfo<'_>,
+    destination_chain: &str,
+    destination_address: &str,
+    payload_hash: &[u8; 32],
+) -> Result<u16, ProgramError> {
+    if !sysvar::instructions::check_id(instructions_sysvar.key) {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    let mut index = 0_u16;
+
+    while let Ok(instruction) = load_instruction_at_checked(index.into(), instructions_sysvar) {
+        if instruction.program_id == axelar_solana_gateway::ID {
+            if let Ok(GatewayInstruction::CallContract {
+                destination_chain: call_destination_chain,
+                destination_contract_address,
+                payload,
+                ..
+            }) = GatewayInstruction::try_from_slice(&instruction.data)
+            {
+                if call_destination_chain == destination_chain
+                    && destination_contract_address == destination_address
+                    && &keccak::hash(&payload).to_bytes() == payload_hash
+                {
+                    return Ok(index);
+                }
+            }
+        }
+
+        index = index
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
+
+    msg!("No matching gateway call contract instruction in the transaction");
+    Err(GasServiceError::LinkedContractCallNotFound.into())
+}
+
+/// Performs every check [`process_pay_native_for_contract_call`] performs
+/// without moving any funds, so relayers and wallets can cheaply simulate a
+/// payment before submitting it.
+pub(crate) fn validate_native_payment(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    destination_chain: &str,
+    refund_address: Pubkey,
+    amount: u64,
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Gas fee amount cannot be zero");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let accounts = &mut accounts.iter();
+    let sender = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let paused_chains_pda = next_account_info(accounts)?;
+
+    try_load_config(program_id, config_pda)?
+        .ensure_refund_address_allowed(sender.key, &refund_address)?;
+    ensure_chain_not_paused(program_id, paused_chains_pda, destination_chain)?;
+
+    // Same conditions the system program enforces on the transfer source
+    if sender.owner != &solana_program::system_program::ID || !sender.data_is_empty() {
+        msg!("Sender must be a system account without data");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if sender.lamports() < amount {
+        msg!(
+            "Insufficient funds: balance {}, required {}",
+            sender.lamports(),
+            amount
+        );
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    msg!(
+        "Payment of {} lamports to {} is valid",
+        amount,
+        destination_chain
+    );
 
     Ok(())
 }
 
 /// Performs all the config checks and returns the config if it is valid
-fn try_load_config(
+pub(super) fn try_load_config(
     program_id: &Pubkey,
     config_pda: &AccountInfo<'_>,
 ) -> Result<Config, ProgramError> {
     config_pda.check_initialized_pda_without_deserialization(program_id)?;
     let data = config_pda.try_borrow_data()?;
-    let config = Config::read(&data).ok_or(ProgramError::InvalidAccountData)?;
+    let config = Config::read(&data).ok_or_else(|| {
+        if data.len() == Config::LEGACY_PDA_SIZE {
+            msg!("Config must be migrated with MigrateConfig");
+        }
+        ProgramError::InvalidAccountData
+    })?;
     assert_valid_config_pda(config.bump, config_pda.key)?;
     Ok(*config)
 }
 
+/// Returns the sequence number of the next event, advancing the counter
+/// persisted in the config PDA. The config PDA must have been validated.
+pub(super) fn next_event_sequence(config_pda: &AccountInfo<'_>) -> Result<u64, ProgramError> {
+    let mut data = config_pda.try_borrow_mut_data()?;
+    let config = Config::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+    Ok(config.next_event_sequence()?)
+}
+
 pub(crate) fn add_native_gas(
     program_id: &Pubkey,
     accounts: &[AccountInfo<'_>],
@@ -89,20 +245,75 @@ pub(crate) fn add_native_gas(
 
     validate_system_account_key(system_program.key)?;
 
-    try_load_config(program_id, config_pda)?;
+    try_load_config(program_id, config_pda)?
+        .ensure_refund_address_allowed(sender.key, &refund_address)?;
 
+    let old_balance = config_pda.lamports();
     invoke(
         &system_instruction::transfer(sender.key, config_pda.key, amount),
         &[sender.clone(), config_pda.clone(), system_program.clone()],
     )?;
 
     // Emit an event
-    emit_cpi!(GasAddedEvent {
+    emit_cpi!(GasAddedEventV2 {
         sender: *sender.key,
         message_id,
         amount,
         refund_address,
         spl_token_account: None,
+        sequence: next_event_sequence(config_pda)?,
+    });
+    emit_cpi!(BalanceChangedEvent {
+        old_balance,
+        new_balance: config_pda.lamports(),
+        reason: BalanceChangeReason::GasAdded,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn add_native_gas_by_payload_hash(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    payload_hash: [u8; 32],
+    amount: u64,
+    refund_address: Pubkey,
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Gas fee amount cannot be zero");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let accounts = &mut accounts.iter();
+    let sender = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
+
+    validate_system_account_key(system_program.key)?;
+
+    try_load_config(program_id, config_pda)?
+        .ensure_refund_address_allowed(sender.key, &refund_address)?;
+
+    let old_balance = config_pda.lamports();
+    invoke(
+        &system_instruction::transfer(sender.key, config_pda.key, amount),
+        &[sender.clone(), config_pda.clone(), system_program.clone()],
+    )?;
+
+    emit_cpi!(GasAddedByPayloadHashEvent {
+        sender: *sender.key,
+        payload_hash,
+        amount,
+        refund_address,
+        sequence: next_event_sequence(config_pda)?,
+    });
+    emit_cpi!(BalanceChangedEvent {
+        old_balance,
+        new_balance: config_pda.lamports(),
+        reason: BalanceChangeReason::GasAdded,
+        sequence: next_event_sequence(config_pda)?,
     });
 
     Ok(())
@@ -113,50 +324,144 @@ pub(crate) fn collect_fees_native(
     accounts: &[AccountInfo<'_>],
     amount: u64,
 ) -> ProgramResult {
-    send_native(program_id, accounts, amount)?;
+    if amount == 0 {
+        msg!("Gas fee amount cannot be zero");
+        return Err(ProgramError::InvalidInstructionData);
+    }
 
     let accounts = &mut accounts.iter();
-    let _operator = next_account_info(accounts)?;
+    let operator = next_account_info(accounts)?;
     let receiver = next_account_info(accounts)?;
-    let _config_pda = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
     event_cpi_accounts!(accounts);
+    // `CollectFees` predates the threshold scheme and takes its PDAs as
+    // optional trailing accounts
+    let approver_set_pda = accounts.next();
+    let operation_approval_pda = accounts.next();
+
+    let old_balance = send_native(
+        program_id,
+        operator,
+        receiver,
+        config_pda,
+        amount,
+        |config| {
+            ensure_operation_approved_optional(
+                program_id,
+                config,
+                config_pda,
+                approver_set_pda,
+                operation_approval_pda,
+                &GuardedOperation::CollectFees {
+                    receiver: *receiver.key,
+                    amount,
+                },
+                amount,
+            )
+        },
+    )?;
 
     // Emit an event
-    emit_cpi!(GasCollectedEvent {
+    emit_cpi!(GasCollectedEventV2 {
         receiver: *receiver.key,
         amount,
         spl_token_account: None,
+        mint: None,
+        sequence: next_event_sequence(config_pda)?,
+    });
+    emit_cpi!(BalanceChangedEvent {
+        old_balance,
+        new_balance: config_pda.lamports(),
+        reason: BalanceChangeReason::FeesCollected,
+        sequence: next_event_sequence(config_pda)?,
     });
 
     Ok(())
 }
 
+/// Refunds gas out of a gas receipt, like [`refund_gas_receipt`] but with the
+/// approval PDAs as optional trailing accounts.
 pub(crate) fn refund_native(
     program_id: &Pubkey,
     accounts: &[AccountInfo<'_>],
     message_id: String,
     amount: u64,
 ) -> ProgramResult {
-    send_native(program_id, accounts, amount)?;
+    if amount == 0 {
+        msg!("Gas fee amount cannot be zero");
+        return Err(ProgramError::InvalidInstructionData);
+    }
 
     let accounts = &mut accounts.iter();
-    let _operator = next_account_info(accounts)?;
+    let operator = next_account_info(accounts)?;
     let receiver = next_account_info(accounts)?;
-    let _config_pda = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
     event_cpi_accounts!(accounts);
+    // `RefundFees` predates the refund window and the threshold scheme and
+    // takes their PDAs as trailing accounts, only the approval ones optional
+    let refund_policy_pda = next_account_info(accounts)?;
+    let gas_receipt_pda = next_account_info(accounts)?;
+    let approver_set_pda = accounts.next();
+    let operation_approval_pda = accounts.next();
+
+    let old_balance = send_native(
+        program_id,
+        operator,
+        receiver,
+        config_pda,
+        amount,
+        |config| {
+            ensure_operation_approved_optional(
+                program_id,
+                config,
+                config_pda,
+                approver_set_pda,
+                operation_approval_pda,
+                &GuardedOperation::RefundFees {
+                    receiver: *receiver.key,
+                    message_id: message_id.clone(),
+                    amount,
+                },
+                amount,
+            )
+        },
+    )?;
+
+    debit_gas_receipt(
+        program_id,
+        refund_policy_pda,
+        gas_receipt_pda,
+        receiver,
+        &message_id,
+        amount,
+    )?;
 
     // Emit an event
-    emit_cpi!(GasRefundedEvent {
+    emit_cpi!(GasRefundedEventV2 {
         receiver: *receiver.key,
         message_id,
         amount,
         spl_token_account: None,
+        sequence: next_event_sequence(config_pda)?,
+    });
+    emit_cpi!(BalanceChangedEvent {
+        old_balance,
+        new_balance: config_pda.lamports(),
+        reason: BalanceChangeReason::FeesRefunded,
+        sequence: next_event_sequence(config_pda)?,
     });
 
     Ok(())
 }
 
-fn send_native(program_id: &Pubkey, accounts: &[AccountInfo<'_>], amount: u64) -> ProgramResult {
+/// Refunds gas recorded in a gas receipt, checking the refund window against
+/// the payment time recorded in the receipt.
+pub(crate) fn refund_gas_receipt(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    message_id: String,
+    amount: u64,
+) -> ProgramResult {
     if amount == 0 {
         msg!("Gas fee amount cannot be zero");
         return Err(ProgramError::InvalidInstructionData);
@@ -166,15 +471,72 @@ fn send_native(program_id: &Pubkey, accounts: &[AccountInfo<'_>], amount: u64) -
     let operator = next_account_info(accounts)?;
     let receiver = next_account_info(accounts)?;
     let config_pda = next_account_info(accounts)?;
+    let approver_set_pda = next_account_info(accounts)?;
+    let operation_approval_pda = next_account_info(accounts)?;
+    let refund_policy_pda = next_account_info(accounts)?;
+    let gas_receipt_pda = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
 
-    {
-        // Check: Valid Config PDA
-        let config = try_load_config(program_id, config_pda)?;
+    let old_balance = send_native(program_id, operator, receiver, config_pda, amount, |_| {
+        ensure_operation_approved(
+            program_id,
+            operator,
+            approver_set_pda,
+            operation_approval_pda,
+            &GuardedOperation::RefundFees {
+                receiver: *receiver.key,
+                message_id: message_id.clone(),
+                amount,
+            },
+            amount,
+        )
+    })?;
+
+    debit_gas_receipt(
+        program_id,
+        refund_policy_pda,
+        gas_receipt_pda,
+        receiver,
+        &message_id,
+        amount,
+    )?;
 
-        // Check: Operator matches
-        if operator.key != &config.operator {
-            return Err(ProgramError::InvalidAccountOwner);
-        }
+    // Emit an event
+    emit_cpi!(GasRefundedEventV2 {
+        receiver: *receiver.key,
+        message_id,
+        amount,
+        spl_token_account: None,
+        sequence: next_event_sequence(config_pda)?,
+    });
+    emit_cpi!(BalanceChangedEvent {
+        old_balance,
+        new_balance: config_pda.lamports(),
+        reason: BalanceChangeReason::FeesRefunded,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+/// Sends `amount` lamports from the config PDA to the receiver, once the
+/// operator signed and `authorize` accepted the operation.
+///
+/// Returns the balance of the config PDA before the transfer.
+fn send_native(
+    program_id: &Pubkey,
+    operator: &AccountInfo<'_>,
+    receiver: &AccountInfo<'_>,
+    config_pda: &AccountInfo<'_>,
+    amount: u64,
+    authorize: impl FnOnce(&Config) -> ProgramResult,
+) -> Result<u64, ProgramError> {
+    // Check: Valid Config PDA
+    let config = try_load_config(program_id, config_pda)?;
+
+    // Check: Operator matches
+    if operator.key != &config.operator {
+        return Err(ProgramError::InvalidAccountOwner);
     }
 
     // Check: Operator is signer
@@ -182,9 +544,13 @@ fn send_native(program_id: &Pubkey, accounts: &[AccountInfo<'_>], amount: u64) -
         return Err(ProgramError::MissingRequiredSignature);
     }
 
+    // Check: Threshold approvals for large amounts
+    authorize(&config)?;
+
+    let old_balance = config_pda.lamports();
     transfer_lamports(config_pda, receiver, amount)?;
 
-    Ok(())
+    Ok(old_balance)
 }
 
 #[cfg(test)]
@@ -209,6 +575,7 @@ mod tests {
             payload_hash,
             refund_address,
             amount,
+            false,
         );
 
         assert_eq!(result, Err(ProgramError::InvalidInstructionData));
@@ -227,6 +594,35 @@ mod tests {
         assert_eq!(result, Err(ProgramError::InvalidInstructionData));
     }
 
+    #[test]
+    fn test_add_native_gas_by_payload_hash_cannot_accept_zero_amount() {
+        let program_id = Pubkey::new_unique();
+        let accounts = vec![];
+        let refund_address = Pubkey::new_unique();
+
+        let result =
+            add_native_gas_by_payload_hash(&program_id, &accounts, [42; 32], 0, refund_address);
+
+        assert_eq!(result, Err(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_validate_native_payment_cannot_accept_zero_amount() {
+        let program_id = Pubkey::new_unique();
+        let accounts = vec![];
+        let refund_address = Pubkey::new_unique();
+
+        let result = validate_native_payment(
+            &program_id,
+            &accounts,
+            "destination_chain",
+            refund_address,
+            0,
+        );
+
+        assert_eq!(result, Err(ProgramError::InvalidInstructionData));
+    }
+
     #[test]
     fn test_collect_fees_native_cannot_accept_zero_amount() {
         let program_id = Pubkey::new_unique();
@@ -0,0 +1,120 @@
+use event_cpi_macros::{emit_cpi, event_cpi_accounts};
+use program_utils::pda::{BytemuckedPda, ValidPDA};
+use program_utils::validate_system_account_key;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::error::GasServiceError;
+use crate::events::ChainPauseSetEvent;
+use crate::state::{Config, PausedChains};
+use crate::{assert_valid_paused_chains_pda, get_paused_chains_pda, seed_prefixes};
+
+use super::native::{next_event_sequence, try_load_config};
+
+/// Pauses or resumes gas payments towards `destination_chain`, creating the
+/// paused chains PDA on first use.
+pub(crate) fn process_set_chain_paused(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    destination_chain: String,
+    paused: bool,
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let operator = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let paused_chains_pda = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
+
+    validate_system_account_key(system_program.key)?;
+
+    let config = try_load_config(program_id, config_pda)?;
+
+    // Check: Operator matches
+    if operator.key != &config.operator {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Check: Operator is signer
+    if !operator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !paused_chains_pda.is_initialized_pda(program_id) {
+        let (_, bump) = get_paused_chains_pda();
+        assert_valid_paused_chains_pda(bump, paused_chains_pda.key)?;
+
+        program_utils::pda::init_pda_raw(
+            payer,
+            paused_chains_pda,
+            program_id,
+            system_program,
+            PausedChains::pda_size()
+                .try_into()
+                .expect("must be valid u64"),
+            &[seed_prefixes::PAUSED_CHAINS_SEED, &[bump]],
+        )?;
+        let mut data = paused_chains_pda.try_borrow_mut_data()?;
+        let paused_chains =
+            PausedChains::init_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        *paused_chains = PausedChains::new(bump);
+    }
+
+    {
+        let mut data = paused_chains_pda.try_borrow_mut_data()?;
+        let paused_chains =
+            PausedChains::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        assert_valid_paused_chains_pda(paused_chains.bump, paused_chains_pda.key)?;
+
+        paused_chains.set_paused(&destination_chain, paused)?;
+    }
+
+    {
+        let mut data = config_pda.try_borrow_mut_data()?;
+        let config = Config::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        config.set_paused_chains_required(true);
+    }
+
+    emit_cpi!(ChainPauseSetEvent {
+        destination_chain,
+        paused,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+/// Checks that the operator didn't pause gas payments towards
+/// `destination_chain`.
+///
+/// The paused chains PDA only exists once a chain was paused, before that it
+/// must still be the canonical PDA address.
+pub(super) fn ensure_chain_not_paused(
+    program_id: &Pubkey,
+    paused_chains_pda: &AccountInfo<'_>,
+    destination_chain: &str,
+) -> ProgramResult {
+    if !paused_chains_pda.is_initialized_pda(program_id) {
+        if paused_chains_pda.key != &get_paused_chains_pda().0 {
+            msg!("Error: Invalid Paused Chains PDA");
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        return Ok(());
+    }
+
+    let data = paused_chains_pda.try_borrow_data()?;
+    let paused_chains = PausedChains::read(&data).ok_or(ProgramError::InvalidAccountData)?;
+    assert_valid_paused_chains_pda(paused_chains.bump, paused_chains_pda.key)?;
+
+    if paused_chains.is_paused(destination_chain) {
+        msg!("Gas payments towards {} are paused", destination_chain);
+        return Err(GasServiceError::DestinationChainPaused.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,215 @@
+use event_cpi_macros::{emit_cpi, event_cpi_accounts};
+use program_utils::pda::{close_pda, BytemuckedPda, ValidPDA};
+use program_utils::validate_system_account_key;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program::invoke;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use crate::error::GasServiceError;
+use crate::events::{
+    BalanceChangeReason, BalanceChangedEvent, GasPaidEventV2, PayloadHashPreRegisteredEvent,
+    PreRegisteredGasPaidEvent,
+};
+use crate::state::{PayloadPreRegistration, MAX_PRE_REGISTRATION_SLOTS};
+use crate::{
+    assert_valid_payload_pre_registration_pda, get_payload_pre_registration_pda, seed_prefixes,
+};
+
+use super::native::{next_event_sequence, try_load_config};
+use super::paused_chains::ensure_chain_not_paused;
+
+/// Announces a contract call the sender intends to pay gas for within
+/// `valid_for_slots` slots, creating its pre-registration PDA on first use.
+pub(crate) fn process_pre_register_payload_hash(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    destination_chain: String,
+    destination_address: String,
+    payload_hash: [u8; 32],
+    valid_for_slots: u64,
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let sender = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let pre_registration_pda = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
+
+    validate_system_account_key(system_program.key)?;
+
+    if !sender.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    try_load_config(program_id, config_pda)?;
+
+    if valid_for_slots == 0 || valid_for_slots > MAX_PRE_REGISTRATION_SLOTS {
+        msg!(
+            "Pre-registrations are valid for 1 to {} slots",
+            MAX_PRE_REGISTRATION_SLOTS
+        );
+        return Err(GasServiceError::InvalidPreRegistrationWindow.into());
+    }
+
+    let expires_at_slot = Clock::get()?
+        .slot
+        .checked_add(valid_for_slots)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let call_hash =
+        PayloadPreRegistration::call_hash(&destination_chain, &destination_address, &payload_hash);
+    if pre_registration_pda.is_initialized_pda(program_id) {
+        let mut data = pre_registration_pda.try_borrow_mut_data()?;
+        let pre_registration =
+            PayloadPreRegistration::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        assert_valid_payload_pre_registration_pda(
+            sender.key,
+            &call_hash,
+            pre_registration.bump,
+            pre_registration_pda.key,
+        )?;
+
+        pre_registration.expires_at_slot = expires_at_slot;
+    } else {
+        let (_, bump) = get_payload_pre_registration_pda(sender.key, &call_hash);
+        assert_valid_payload_pre_registration_pda(
+            sender.key,
+            &call_hash,
+            bump,
+            pre_registration_pda.key,
+        )?;
+
+        program_utils::pda::init_pda_raw(
+            sender,
+            pre_registration_pda,
+            program_id,
+            system_program,
+            PayloadPreRegistration::pda_size()
+                .try_into()
+                .expect("must be valid u64"),
+            &[
+                seed_prefixes::PAYLOAD_PRE_REGISTRATION_SEED,
+                sender.key.as_ref(),
+                &call_hash,
+                &[bump],
+            ],
+        )?;
+        let mut data = pre_registration_pda.try_borrow_mut_data()?;
+        let pre_registration =
+            PayloadPreRegistration::init_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        *pre_registration = PayloadPreRegistration::new(expires_at_slot, bump);
+    }
+
+    emit_cpi!(PayloadHashPreRegisteredEvent {
+        sender: *sender.key,
+        destination_chain,
+        destination_address,
+        payload_hash,
+        pre_registration: *pre_registration_pda.key,
+        expires_at_slot,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+/// Pays native gas for a pre-registered contract call and closes its
+/// pre-registration, refunding the rent to the sender.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn process_pay_pre_registered_gas(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    destination_chain: String,
+    destination_address: String,
+    payload_hash: [u8; 32],
+    refund_address: Pubkey,
+    amount: u64,
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Gas fee amount cannot be zero");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let accounts = &mut accounts.iter();
+    let sender = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let paused_chains_pda = next_account_info(accounts)?;
+    let pre_registration_pda = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
+
+    validate_system_account_key(system_program.key)?;
+
+    try_load_config(program_id, config_pda)?
+        .ensure_refund_address_allowed(sender.key, &refund_address)?;
+    ensure_chain_not_paused(program_id, paused_chains_pda, &destination_chain)?;
+
+    // Scope the account data borrow so it's dropped before calling close_pda
+    {
+        pre_registration_pda.check_initialized_pda_without_deserialization(program_id)?;
+        let data = pre_registration_pda.try_borrow_data()?;
+        let pre_registration =
+            PayloadPreRegistration::read(&data).ok_or(ProgramError::InvalidAccountData)?;
+        let call_hash = PayloadPreRegistration::call_hash(
+            &destination_chain,
+            &destination_address,
+            &payload_hash,
+        );
+        assert_valid_payload_pre_registration_pda(
+            sender.key,
+            &call_hash,
+            pre_registration.bump,
+            pre_registration_pda.key,
+        )?;
+
+        if Clock::get()?.slot > pre_registration.expires_at_slot {
+            msg!(
+                "Pre-registration expired at slot {}",
+                pre_registration.expires_at_slot
+            );
+            return Err(GasServiceError::PayloadPreRegistrationExpired.into());
+        }
+    }
+
+    close_pda(sender, pre_registration_pda, program_id)?;
+
+    let old_balance = config_pda.lamports();
+    invoke(
+        &system_instruction::transfer(sender.key, config_pda.key, amount),
+        &[sender.clone(), config_pda.clone(), system_program.clone()],
+    )?;
+
+    emit_cpi!(GasPaidEventV2 {
+        sender: *sender.key,
+        destination_chain,
+        destination_address,
+        payload_hash,
+        amount,
+        refund_address,
+        spl_token_account: None,
+        mint: None,
+        linked_call_contract_index: None,
+        destination_gas_amount: None,
+        sequence: next_event_sequence(config_pda)?,
+    });
+    emit_cpi!(PreRegisteredGasPaidEvent {
+        sender: *sender.key,
+        payload_hash,
+        pre_registration: *pre_registration_pda.key,
+        sequence: next_event_sequence(config_pda)?,
+    });
+    emit_cpi!(BalanceChangedEvent {
+        old_balance,
+        new_balance: config_pda.lamports(),
+        reason: BalanceChangeReason::GasPaid,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,480 @@
+use axelar_solana_encoding::types::messages::Message;
+use axelar_solana_gateway::executable::{validate_with_gmp_metadata, PROGRAM_ACCOUNTS_START_INDEX};
+use axelar_solana_gateway::state::message_payload::ImmutMessagePayload;
+use borsh::BorshDeserialize;
+use event_cpi_macros::{emit_cpi, event_cpi_accounts};
+use program_utils::pda::{close_pda, BytemuckedPda, ValidPDA};
+use program_utils::{transfer_lamports, validate_system_account_key};
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program::invoke;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use crate::error::GasServiceError;
+use crate::events::{
+    BalanceChangeReason, BalanceChangedEvent, GasAddedEventV2, GasReceiptClosedEvent,
+    GasReceiptDeliveredEvent, GasRefundedEventV2, RefundNoticeSourceSetEvent,
+};
+use crate::state::{GasReceipt, GasReceiptStatus, RefundNotice, RefundNoticeSource};
+use crate::{
+    assert_valid_gas_receipt_pda, assert_valid_refund_notice_source_pda, get_gas_receipt_pda,
+    get_refund_notice_source_pda, seed_prefixes,
+};
+
+use super::native::{next_event_sequence, try_load_config};
+use super::refund_policy::ensure_refund_within_window;
+
+/// Index of the gateway message payload account among the accounts of
+/// [`crate::instructions::GasServiceInstruction::ProcessRefundNotice`].
+const MESSAGE_PAYLOAD_ACCOUNT_INDEX: usize = 2;
+
+pub(crate) fn process_pay_gas_with_receipt(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    message_id: String,
+    amount: u64,
+    refund_address: Pubkey,
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Gas fee amount cannot be zero");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let accounts = &mut accounts.iter();
+    let sender = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let gas_receipt_pda = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
+
+    validate_system_account_key(system_program.key)?;
+
+    try_load_config(program_id, config_pda)?
+        .ensure_refund_address_allowed(sender.key, &refund_address)?;
+
+    let message_id_hash = GasReceipt::message_id_hash(&message_id);
+    if gas_receipt_pda.is_initialized_pda(program_id) {
+        let mut data = gas_receipt_pda.try_borrow_mut_data()?;
+        let receipt = GasReceipt::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        assert_valid_gas_receipt_pda(&message_id_hash, receipt.bump, gas_receipt_pda.key)?;
+
+        // Check: The refund goes to a single address
+        if receipt.refund_address != refund_address {
+            msg!(
+                "Gas for this message is refunded to {}",
+                receipt.refund_address
+            );
+            return Err(GasServiceError::RefundAddressMismatch.into());
+        }
+
+        // The refund window keeps running from the first payment
+        receipt.amount = receipt
+            .amount
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    } else {
+        let (_, bump) = get_gas_receipt_pda(&message_id);
+        assert_valid_gas_receipt_pda(&message_id_hash, bump, gas_receipt_pda.key)?;
+
+        program_utils::pda::init_pda_raw(
+            sender,
+            gas_receipt_pda,
+            program_id,
+            system_program,
+            GasReceipt::pda_size()
+                .try_into()
+                .expect("must be valid u64"),
+            &[seed_prefixes::GAS_RECEIPT_SEED, &message_id_hash, &[bump]],
+        )?;
+        let mut data = gas_receipt_pda.try_borrow_mut_data()?;
+        let receipt = GasReceipt::init_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        *receipt = GasReceipt::new(
+            *sender.key,
+            message_id_hash,
+            refund_address,
+            amount,
+            Clock::get()?.unix_timestamp,
+            bump,
+        );
+    }
+
+    let old_balance = config_pda.lamports();
+    invoke(
+        &system_instruction::transfer(sender.key, config_pda.key, amount),
+        &[sender.clone(), config_pda.clone(), system_program.clone()],
+    )?;
+
+    emit_cpi!(GasAddedEventV2 {
+        sender: *sender.key,
+        message_id,
+        amount,
+        refund_address,
+        spl_token_account: None,
+        sequence: next_event_sequence(config_pda)?,
+    });
+    emit_cpi!(BalanceChangedEvent {
+        old_balance,
+        new_balance: config_pda.lamports(),
+        reason: BalanceChangeReason::GasAdded,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+/// Sets the source refund notices are accepted from, creating the refund
+/// notice source PDA on first use.
+pub(crate) fn process_set_refund_notice_source(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    source_chain: String,
+    source_address: String,
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let operator = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let refund_notice_source_pda = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
+
+    validate_system_account_key(system_program.key)?;
+
+    let config = try_load_config(program_id, config_pda)?;
+
+    // Check: Operator matches
+    if operator.key != &config.operator {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Check: Operator is signer
+    if !operator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if refund_notice_source_pda.is_initialized_pda(program_id) {
+        let mut data = refund_notice_source_pda.try_borrow_mut_data()?;
+        let source =
+            RefundNoticeSource::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        assert_valid_refund_notice_source_pda(source.bump, refund_notice_source_pda.key)?;
+        *source = RefundNoticeSource::new(&source_chain, &source_address, source.bump);
+    } else {
+        let (_, bump) = get_refund_notice_source_pda();
+        assert_valid_refund_notice_source_pda(bump, refund_notice_source_pda.key)?;
+
+        program_utils::pda::init_pda_raw(
+            payer,
+            refund_notice_source_pda,
+            program_id,
+            system_program,
+            RefundNoticeSource::pda_size()
+                .try_into()
+                .expect("must be valid u64"),
+            &[seed_prefixes::REFUND_NOTICE_SOURCE_SEED, &[bump]],
+        )?;
+        let mut data = refund_notice_source_pda.try_borrow_mut_data()?;
+        let source =
+            RefundNoticeSource::init_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        *source = RefundNoticeSource::new(&source_chain, &source_address, bump);
+    }
+
+    emit_cpi!(RefundNoticeSourceSetEvent {
+        source_chain,
+        source_address,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+/// Refunds the gas receipt named by a refund notice the gateway approved.
+///
+/// Validating the message through the gateway marks it as executed, and the
+/// receipt is closed once refunded, so a notice can't be replayed.
+pub(crate) fn process_refund_notice(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    message: &Message,
+) -> ProgramResult {
+    if accounts.len() < PROGRAM_ACCOUNTS_START_INDEX {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (gateway_accounts, refund_accounts) = accounts.split_at(PROGRAM_ACCOUNTS_START_INDEX);
+
+    validate_with_gmp_metadata(gateway_accounts, message)?;
+
+    let accounts_iter = &mut refund_accounts.iter();
+    let config_pda = next_account_info(accounts_iter)?;
+    let refund_notice_source_pda = next_account_info(accounts_iter)?;
+    let refund_policy_pda = next_account_info(accounts_iter)?;
+    let gas_receipt_pda = next_account_info(accounts_iter)?;
+    let refund_address = next_account_info(accounts_iter)?;
+    event_cpi_accounts!(accounts_iter);
+
+    try_load_config(program_id, config_pda)?;
+
+    // Check: The notice comes from the configured source
+    {
+        refund_notice_source_pda.check_initialized_pda_without_deserialization(program_id)?;
+        let data = refund_notice_source_pda.try_borrow_data()?;
+        let source = RefundNoticeSource::read(&data).ok_or(ProgramError::InvalidAccountData)?;
+        assert_valid_refund_notice_source_pda(source.bump, refund_notice_source_pda.key)?;
+
+        if !source.matches(&message.cc_id.chain, &message.source_address) {
+            msg!(
+                "Refund notice sent by {} on {} is not accepted",
+                message.source_address,
+                message.cc_id.chain
+            );
+            return Err(GasServiceError::UnauthorizedRefundNotice.into());
+        }
+    }
+
+    let notice = {
+        let message_payload_account = gateway_accounts
+            .get(MESSAGE_PAYLOAD_ACCOUNT_INDEX)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let data = message_payload_account.try_borrow_data()?;
+        let message_payload: ImmutMessagePayload<'_> = (**data).try_into()?;
+        RefundNotice::try_from_slice(message_payload.raw_payload).map_err(|err| {
+            msg!("Invalid refund notice: {}", err);
+            ProgramError::InvalidInstructionData
+        })?
+    };
+
+    let receipt = {
+        gas_receipt_pda.check_initialized_pda_without_deserialization(program_id)?;
+        let data = gas_receipt_pda.try_borrow_data()?;
+        let receipt = *GasReceipt::read(&data).ok_or(ProgramError::InvalidAccountData)?;
+        assert_valid_gas_receipt_pda(
+            &GasReceipt::message_id_hash(&notice.message_id),
+            receipt.bump,
+            gas_receipt_pda.key,
+        )?;
+        receipt
+    };
+
+    // Check: The refund goes to the address recorded at payment time
+    if refund_address.key != &receipt.refund_address {
+        msg!("Refund must be sent to {}", receipt.refund_address);
+        return Err(GasServiceError::RefundAddressMismatch.into());
+    }
+
+    // Check: The payment is still within the refund window
+    ensure_refund_within_window(program_id, refund_policy_pda, receipt.paid_at)?;
+
+    let old_balance = config_pda.lamports();
+    transfer_lamports(config_pda, refund_address, receipt.amount)?;
+    close_pda(refund_address, gas_receipt_pda, program_id)?;
+
+    emit_cpi!(GasRefundedEventV2 {
+        receiver: *refund_address.key,
+        message_id: notice.message_id,
+        amount: receipt.amount,
+        spl_token_account: None,
+        sequence: next_event_sequence(config_pda)?,
+    });
+    emit_cpi!(BalanceChangedEvent {
+        old_balance,
+        new_balance: config_pda.lamports(),
+        reason: BalanceChangeReason::FeesRefunded,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+/// Takes `amount` lamports refunded to `receiver` out of the gas receipt of
+/// the message `message_id`, closing the receipt once fully refunded with its
+/// rent going to `receiver`.
+///
+/// The refund must go to the refund address of the receipt and the payment
+/// must still be within the refund window.
+pub(super) fn debit_gas_receipt<'a>(
+    program_id: &Pubkey,
+    refund_policy_pda: &AccountInfo<'a>,
+    gas_receipt_pda: &AccountInfo<'a>,
+    receiver: &AccountInfo<'a>,
+    message_id: &str,
+    amount: u64,
+) -> ProgramResult {
+    gas_receipt_pda.check_initialized_pda_without_deserialization(program_id)?;
+
+    // Scope the account data borrow so it's dropped before calling close_pda
+    let remaining = {
+        let mut data = gas_receipt_pda.try_borrow_mut_data()?;
+        let receipt = GasReceipt::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        assert_valid_gas_receipt_pda(
+            &GasReceipt::message_id_hash(message_id),
+            receipt.bump,
+            gas_receipt_pda.key,
+        )?;
+
+        // Check: The refund goes to the address recorded at payment time
+        if receiver.key != &receipt.refund_address {
+            msg!("Refund must be sent to {}", receipt.refund_address);
+            return Err(GasServiceError::RefundAddressMismatch.into());
+        }
+
+        // Check: The payment is still within the refund window
+        ensure_refund_within_window(program_id, refund_policy_pda, receipt.paid_at)?;
+
+        receipt.amount = receipt.amount.checked_sub(amount).ok_or_else(|| {
+            msg!("Only {} lamports are left to refund", receipt.amount);
+            GasServiceError::RefundExceedsReceipt
+        })?;
+        receipt.set_status(GasReceiptStatus::Refunded);
+        receipt.amount
+    };
+
+    if remaining == 0 {
+        close_pda(receiver, gas_receipt_pda, program_id)?;
+    }
+
+    Ok(())
+}
+
+/// Reports the message of a gas receipt as delivered, so that its sender can
+/// close it.
+pub(crate) fn process_mark_gas_receipt_delivered(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    message_id: String,
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let operator = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let gas_receipt_pda = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
+
+    let config = try_load_config(program_id, config_pda)?;
+
+    // Check: Operator matches
+    if operator.key != &config.operator {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Check: Operator is signer
+    if !operator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    {
+        gas_receipt_pda.check_initialized_pda_without_deserialization(program_id)?;
+        let mut data = gas_receipt_pda.try_borrow_mut_data()?;
+        let receipt = GasReceipt::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        assert_valid_gas_receipt_pda(
+            &GasReceipt::message_id_hash(&message_id),
+            receipt.bump,
+            gas_receipt_pda.key,
+        )?;
+        receipt.set_status(GasReceiptStatus::Delivered);
+    }
+
+    emit_cpi!(GasReceiptDeliveredEvent {
+        message_id,
+        gas_receipt: *gas_receipt_pda.key,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+/// Closes a gas receipt of the sender, refunding its rent to the sender.
+/// Only receipts of delivered or refunded messages can be closed, the gas
+/// left in the receipt can no longer be refunded afterwards.
+pub(crate) fn process_close_gas_receipt(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let sender = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let gas_receipt_pda = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
+
+    // Check: Sender is signer
+    if !sender.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    try_load_config(program_id, config_pda)?;
+
+    // Scope the account data borrow so it's dropped before calling close_pda
+    let receipt = {
+        gas_receipt_pda.check_initialized_pda_without_deserialization(program_id)?;
+        let data = gas_receipt_pda.try_borrow_data()?;
+        let receipt = *GasReceipt::read(&data).ok_or(ProgramError::InvalidAccountData)?;
+        assert_valid_gas_receipt_pda(&receipt.message_id_hash, receipt.bump, gas_receipt_pda.key)?;
+        receipt
+    };
+
+    // Check: Only the sender that opened the receipt closes it
+    if sender.key != &receipt.sender {
+        msg!("Gas receipt was opened by {}", receipt.sender);
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Check: The message was delivered or refunded
+    if receipt.status() == GasReceiptStatus::Pending {
+        msg!("Gas receipt can't be closed before the message is delivered or refunded");
+        return Err(GasServiceError::GasReceiptPending.into());
+    }
+
+    close_pda(sender, gas_receipt_pda, program_id)?;
+
+    emit_cpi!(GasReceiptClosedEvent {
+        sender: *sender.key,
+        gas_receipt: *gas_receipt_pda.key,
+        amount: receipt.amount,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_pay_gas_with_receipt_cannot_accept_zero_amount() {
+        let program_id = Pubkey::new_unique();
+        let accounts = vec![];
+
+        let result = process_pay_gas_with_receipt(
+            &program_id,
+            &accounts,
+            "tx-sig-2.1".to_owned(),
+            0,
+            Pubkey::new_unique(),
+        );
+
+        assert_eq!(result, Err(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_gas_receipts_are_keyed_by_message_id() {
+        assert_ne!(
+            crate::get_gas_receipt_pda("tx-sig-2.1").0,
+            crate::get_gas_receipt_pda("tx-sig-2.2").0
+        );
+
+        let mut receipt = GasReceipt::new(
+            Pubkey::new_unique(),
+            GasReceipt::message_id_hash("tx-sig-2.1"),
+            Pubkey::new_unique(),
+            1,
+            0,
+            255,
+        );
+        assert_eq!(receipt.status(), GasReceiptStatus::Pending);
+        receipt.set_status(GasReceiptStatus::Delivered);
+        assert_eq!(receipt.status(), GasReceiptStatus::Delivered);
+    }
+}
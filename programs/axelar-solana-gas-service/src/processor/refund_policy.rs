@@ -0,0 +1,156 @@
+use event_cpi_macros::{emit_cpi, event_cpi_accounts};
+use program_utils::pda::{BytemuckedPda, ValidPDA};
+use program_utils::validate_system_account_key;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use crate::events::{RefundAddressPolicySetEvent, RefundWindowSetEvent};
+use crate::state::{Config, RefundAddressPolicy, RefundPolicy};
+use crate::{assert_valid_refund_policy_pda, get_refund_policy_pda, seed_prefixes};
+
+use super::native::{next_event_sequence, try_load_config};
+
+/// Sets the maximum age of refundable payments, creating the refund policy
+/// PDA on first use.
+pub(crate) fn process_set_refund_window(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    max_refund_age: i64,
+) -> ProgramResult {
+    if max_refund_age < 0 {
+        msg!("Refund window cannot be negative");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let operator = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let refund_policy_pda = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
+
+    validate_system_account_key(system_program.key)?;
+
+    let config = try_load_config(program_id, config_pda)?;
+
+    // Check: Operator matches
+    if operator.key != &config.operator {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Check: Operator is signer
+    if !operator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if refund_policy_pda.is_initialized_pda(program_id) {
+        let mut data = refund_policy_pda.try_borrow_mut_data()?;
+        let refund_policy =
+            RefundPolicy::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        assert_valid_refund_policy_pda(refund_policy.bump, refund_policy_pda.key)?;
+        refund_policy.max_refund_age = max_refund_age;
+    } else {
+        let (_, bump) = get_refund_policy_pda();
+        assert_valid_refund_policy_pda(bump, refund_policy_pda.key)?;
+
+        program_utils::pda::init_pda_raw(
+            payer,
+            refund_policy_pda,
+            program_id,
+            system_program,
+            RefundPolicy::pda_size()
+                .try_into()
+                .expect("must be valid u64"),
+            &[seed_prefixes::REFUND_POLICY_SEED, &[bump]],
+        )?;
+        let mut data = refund_policy_pda.try_borrow_mut_data()?;
+        let refund_policy =
+            RefundPolicy::init_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        *refund_policy = RefundPolicy::new(max_refund_age, bump);
+    }
+
+    emit_cpi!(RefundWindowSetEvent {
+        max_refund_age,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+/// Sets which refund addresses gas payments may name.
+pub(crate) fn process_set_refund_address_policy(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    policy: RefundAddressPolicy,
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let operator = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
+
+    let config = try_load_config(program_id, config_pda)?;
+
+    // Check: Operator matches
+    if operator.key != &config.operator {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Check: Operator is signer
+    if !operator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    {
+        let mut data = config_pda.try_borrow_mut_data()?;
+        let config = Config::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        config.set_refund_address_policy(policy);
+    }
+
+    emit_cpi!(RefundAddressPolicySetEvent {
+        policy,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+/// Checks that a payment made at `paid_at` is still within the refund
+/// window.
+///
+/// The refund policy PDA only exists once a window was set, before that it
+/// must still be the canonical PDA address and only payments dated in the
+/// future are rejected.
+pub(super) fn ensure_refund_within_window(
+    program_id: &Pubkey,
+    refund_policy_pda: &AccountInfo<'_>,
+    paid_at: i64,
+) -> ProgramResult {
+    let now = Clock::get()?.unix_timestamp;
+
+    let refund_policy = if refund_policy_pda.is_initialized_pda(program_id) {
+        let data = refund_policy_pda.try_borrow_data()?;
+        let refund_policy = *RefundPolicy::read(&data).ok_or(ProgramError::InvalidAccountData)?;
+        assert_valid_refund_policy_pda(refund_policy.bump, refund_policy_pda.key)?;
+        refund_policy
+    } else {
+        let (pda, bump) = get_refund_policy_pda();
+        if refund_policy_pda.key != &pda {
+            msg!("Error: Invalid Refund Policy PDA");
+            return Err(ProgramError::InvalidSeeds);
+        }
+        RefundPolicy::new(0, bump)
+    };
+
+    refund_policy
+        .ensure_refundable(paid_at, now)
+        .map_err(|err| {
+            msg!("Payment made at {} can't be refunded at {}", paid_at, now);
+            err.into()
+        })
+}
@@ -0,0 +1,291 @@
+use event_cpi_macros::{emit_cpi, event_cpi_accounts};
+use program_utils::pda::{BytemuckedPda, ValidPDA};
+use program_utils::{transfer_lamports, validate_system_account_key};
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::error::GasServiceError;
+use crate::events::{
+    BalanceChangeReason, BalanceChangedEvent, GasRefundedEventV2, RefundEnqueuedEvent,
+    RefundSkippedEvent,
+};
+use crate::state::{GuardedOperation, QueuedRefund, RefundQueue};
+use crate::{assert_valid_refund_queue_pda, get_refund_queue_pda, seed_prefixes};
+
+use super::approvals::ensure_operation_approved;
+use super::native::{next_event_sequence, try_load_config};
+use super::refund_notice::debit_gas_receipt;
+
+/// Queues a refund authorized by the operator, creating the refund queue PDA
+/// on first use.
+pub(crate) fn process_enqueue_refund(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    message_id: String,
+    amount: u64,
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Gas fee amount cannot be zero");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let operator = next_account_info(accounts)?;
+    let receiver = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let approver_set_pda = next_account_info(accounts)?;
+    let operation_approval_pda = next_account_info(accounts)?;
+    let refund_policy_pda = next_account_info(accounts)?;
+    let gas_receipt_pda = next_account_info(accounts)?;
+    let refund_queue_pda = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
+
+    validate_system_account_key(system_program.key)?;
+
+    let config = try_load_config(program_id, config_pda)?;
+
+    // Check: Operator matches
+    if operator.key != &config.operator {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Check: Operator is signer
+    if !operator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let refund = QueuedRefund::new(*receiver.key, &message_id, amount)?;
+
+    // Check: Threshold approvals for large amounts, as for a direct refund
+    ensure_operation_approved(
+        program_id,
+        operator,
+        approver_set_pda,
+        operation_approval_pda,
+        &GuardedOperation::RefundFees {
+            receiver: *receiver.key,
+            message_id: message_id.clone(),
+            amount,
+        },
+        amount,
+    )?;
+
+    // Check: The refund is taken out of a gas receipt, within the refund window
+    debit_gas_receipt(
+        program_id,
+        refund_policy_pda,
+        gas_receipt_pda,
+        receiver,
+        &message_id,
+        amount,
+    )?;
+
+    if !refund_queue_pda.is_initialized_pda(program_id) {
+        let (_, bump) = get_refund_queue_pda();
+        assert_valid_refund_queue_pda(bump, refund_queue_pda.key)?;
+
+        program_utils::pda::init_pda_raw(
+            payer,
+            refund_queue_pda,
+            program_id,
+            system_program,
+            RefundQueue::pda_size()
+                .try_into()
+                .expect("must be valid u64"),
+            &[seed_prefixes::REFUND_QUEUE_SEED, &[bump]],
+        )?;
+        let mut data = refund_queue_pda.try_borrow_mut_data()?;
+        let refund_queue =
+            RefundQueue::init_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        *refund_queue = RefundQueue::new(bump);
+    }
+
+    let queued = {
+        let mut data = refund_queue_pda.try_borrow_mut_data()?;
+        let refund_queue =
+            RefundQueue::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        assert_valid_refund_queue_pda(refund_queue.bump, refund_queue_pda.key)?;
+        refund_queue.push_back(refund)?;
+        refund_queue.len
+    };
+
+    emit_cpi!(RefundEnqueuedEvent {
+        receiver: *receiver.key,
+        message_id,
+        amount,
+        queued,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+/// Pays out the oldest queued refund. Anyone can crank the queue, the
+/// refunds were authorized by the operator when queued.
+pub(crate) fn process_next_refund(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let config_pda = next_account_info(accounts)?;
+    let refund_queue_pda = next_account_info(accounts)?;
+    let receiver = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
+
+    try_load_config(program_id, config_pda)?;
+
+    refund_queue_pda.check_initialized_pda_without_deserialization(program_id)?;
+    let refund = {
+        let mut data = refund_queue_pda.try_borrow_mut_data()?;
+        let refund_queue =
+            RefundQueue::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        assert_valid_refund_queue_pda(refund_queue.bump, refund_queue_pda.key)?;
+
+        let refund = refund_queue
+            .pop_front()
+            .ok_or(GasServiceError::RefundQueueEmpty)?;
+
+        // Check: The refund goes to the queued receiver
+        if receiver.key != &refund.receiver {
+            msg!("Next queued refund goes to {}", refund.receiver);
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        refund
+    };
+
+    let old_balance = config_pda.lamports();
+    transfer_lamports(config_pda, receiver, refund.amount)?;
+
+    emit_cpi!(GasRefundedEventV2 {
+        receiver: refund.receiver,
+        message_id: refund.message_id().to_owned(),
+        amount: refund.amount,
+        spl_token_account: None,
+        sequence: next_event_sequence(config_pda)?,
+    });
+    emit_cpi!(BalanceChangedEvent {
+        old_balance,
+        new_balance: config_pda.lamports(),
+        reason: BalanceChangeReason::FeesRefunded,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+/// Drops the oldest queued refund without paying it out, so a refund that
+/// can't be paid doesn't block the refunds queued after it. The lamports stay
+/// in the config PDA.
+pub(crate) fn process_skip_next_refund(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    message_id: String,
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let operator = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let refund_queue_pda = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
+
+    let config = try_load_config(program_id, config_pda)?;
+
+    // Check: Operator matches
+    if operator.key != &config.operator {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Check: Operator is signer
+    if !operator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    refund_queue_pda.check_initialized_pda_without_deserialization(program_id)?;
+    let (refund, queued) = {
+        let mut data = refund_queue_pda.try_borrow_mut_data()?;
+        let refund_queue =
+            RefundQueue::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        assert_valid_refund_queue_pda(refund_queue.bump, refund_queue_pda.key)?;
+
+        let refund = refund_queue
+            .pop_front()
+            .ok_or(GasServiceError::RefundQueueEmpty)?;
+
+        // Check: The operator skips the refund it meant to
+        if refund.message_id() != message_id {
+            msg!("Next queued refund is for {}", refund.message_id());
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        (refund, refund_queue.len)
+    };
+
+    emit_cpi!(RefundSkippedEvent {
+        receiver: refund.receiver,
+        message_id,
+        amount: refund.amount,
+        queued,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::MAX_QUEUED_REFUNDS;
+
+    #[test]
+    fn test_process_enqueue_refund_cannot_accept_zero_amount() {
+        let program_id = Pubkey::new_unique();
+        let accounts = vec![];
+
+        let result = process_enqueue_refund(&program_id, &accounts, "tx-sig-2.1".to_owned(), 0);
+
+        assert_eq!(result, Err(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_refund_queue_is_first_in_first_out() {
+        let mut queue = RefundQueue::new(255);
+        assert!(queue.pop_front().is_none());
+
+        // Wrap around the ring buffer a couple of times
+        for round in 0..3_u64 {
+            for i in 0..MAX_QUEUED_REFUNDS as u64 {
+                let refund =
+                    QueuedRefund::new(Pubkey::new_unique(), &format!("tx-{round}-{i}"), i + 1)
+                        .unwrap();
+                queue.push_back(refund).unwrap();
+            }
+            let overflow = QueuedRefund::new(Pubkey::new_unique(), "tx", 1).unwrap();
+            assert_eq!(
+                queue.push_back(overflow),
+                Err(GasServiceError::RefundQueueFull)
+            );
+
+            for i in 0..MAX_QUEUED_REFUNDS as u64 {
+                let refund = queue.pop_front().unwrap();
+                assert_eq!(refund.amount, i + 1);
+                assert_eq!(refund.message_id(), format!("tx-{round}-{i}"));
+            }
+            assert!(queue.pop_front().is_none());
+        }
+    }
+
+    #[test]
+    fn test_queued_refund_rejects_long_message_id() {
+        let message_id = "a".repeat(crate::state::MAX_QUEUED_MESSAGE_ID_LEN + 1);
+
+        assert_eq!(
+            QueuedRefund::new(Pubkey::new_unique(), &message_id, 1),
+            Err(GasServiceError::MessageIdTooLong)
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use crate::events::{GasCollectedEventV2, GasPaidEventV2};
+use crate::seed_prefixes;
+use crate::state::GuardedOperation;
+use event_cpi_macros::{emit_cpi, event_cpi_accounts};
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program::{invoke, invoke_signed};
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::extension::StateWithExtensions;
+use spl_token_2022::state::{Account, Mint};
+
+use super::approvals::ensure_operation_approved;
+use super::native::{next_event_sequence, try_load_config};
+use super::paused_chains::ensure_chain_not_paused;
+
+pub(crate) fn process_pay_spl_for_contract_call(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    destination_chain: String,
+    destination_address: String,
+    payload_hash: [u8; 32],
+    refund_address: Pubkey,
+    amount: u64,
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Gas fee amount cannot be zero");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let accounts = &mut accounts.iter();
+    let sender = next_account_info(accounts)?;
+    let sender_token_account = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let paused_chains_pda = next_account_info(accounts)?;
+    let config_pda_token_account = next_account_info(accounts)?;
+    let mint = next_account_info(accounts)?;
+    let token_program = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
+
+    try_load_config(program_id, config_pda)?
+        .ensure_refund_address_allowed(sender.key, &refund_address)?;
+    ensure_chain_not_paused(program_id, paused_chains_pda, &destination_chain)?;
+    let decimals =
+        validate_token_accounts(config_pda, config_pda_token_account, mint, token_program)?;
+
+    invoke(
+        &spl_token_2022::instruction::transfer_checked(
+            token_program.key,
+            sender_token_account.key,
+            mint.key,
+            config_pda_token_account.key,
+            sender.key,
+            &[],
+            amount,
+            decimals,
+        )?,
+        &[
+            sender_token_account.clone(),
+            mint.clone(),
+            config_pda_token_account.clone(),
+            sender.clone(),
+            token_program.clone(),
+        ],
+    )?;
+
+    // Emit an event
+    emit_cpi!(GasPaidEventV2 {
+        sender: *sender.key,
+        destination_chain,
+        destination_address,
+        payload_hash,
+        amount,
+        refund_address,
+        spl_token_account: Some(*sender_token_account.key),
+        mint: Some(*mint.key),
+        linked_call_contract_index: None,
+        destination_gas_amount: None,
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn collect_fees_spl(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    amount: u64,
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Gas fee amount cannot be zero");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let accounts = &mut accounts.iter();
+    let operator = next_account_info(accounts)?;
+    let receiver_token_account = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let config_pda_token_account = next_account_info(accounts)?;
+    let mint = next_account_info(accounts)?;
+    let token_program = next_account_info(accounts)?;
+    let approver_set_pda = next_account_info(accounts)?;
+    let operation_approval_pda = next_account_info(accounts)?;
+    event_cpi_accounts!(accounts);
+
+    let config = try_load_config(program_id, config_pda)?;
+
+    // Check: Operator matches
+    if operator.key != &config.operator {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Check: Operator is signer
+    if !operator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Check: Threshold approvals for large amounts
+    ensure_operation_approved(
+        program_id,
+        operator,
+        approver_set_pda,
+        operation_approval_pda,
+        &GuardedOperation::CollectSplFees {
+            receiver_token_account: *receiver_token_account.key,
+            mint: *mint.key,
+            amount,
+        },
+        amount,
+    )?;
+
+    let decimals =
+        validate_token_accounts(config_pda, config_pda_token_account, mint, token_program)?;
+
+    invoke_signed(
+        &spl_token_2022::instruction::transfer_checked(
+            token_program.key,
+            config_pda_token_account.key,
+            mint.key,
+            receiver_token_account.key,
+            config_pda.key,
+            &[],
+            amount,
+            decimals,
+        )?,
+        &[
+            config_pda_token_account.clone(),
+            mint.clone(),
+            receiver_token_account.clone(),
+            config_pda.clone(),
+            token_program.clone(),
+        ],
+        &[&[seed_prefixes::CONFIG_SEED, &[config.bump]]],
+    )?;
+
+    let receiver = {
+        let data = receiver_token_account.try_borrow_data()?;
+        StateWithExtensions::<Account>::unpack(&data)?.base.owner
+    };
+
+    // Emit an event
+    emit_cpi!(GasCollectedEventV2 {
+        receiver,
+        amount,
+        spl_token_account: Some(*receiver_token_account.key),
+        mint: Some(*mint.key),
+        sequence: next_event_sequence(config_pda)?,
+    });
+
+    Ok(())
+}
+
+/// Checks the token program, mint and the config PDA token account, returning
+/// the mint decimals needed for `transfer_checked`.
+fn validate_token_accounts(
+    config_pda: &AccountInfo<'_>,
+    config_pda_token_account: &AccountInfo<'_>,
+    mint: &AccountInfo<'_>,
+    token_program: &AccountInfo<'_>,
+) -> Result<u8, ProgramError> {
+    spl_token_2022::check_spl_token_program_account(token_program.key)?;
+
+    if mint.owner != token_program.key {
+        msg!("Mint is not owned by the given token program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let expected_token_account =
+        get_associated_token_address_with_program_id(config_pda.key, mint.key, token_program.key);
+    if config_pda_token_account.key != &expected_token_account {
+        msg!("Invalid config token account");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+
+    Ok(mint.base.decimals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_pay_spl_for_contract_call_cannot_accept_zero_amount() {
+        let program_id = Pubkey::new_unique();
+        let accounts = vec![];
+
+        let result = process_pay_spl_for_contract_call(
+            &program_id,
+            &accounts,
+            "destination_chain".to_owned(),
+            "destination_address".to_owned(),
+            [0; 32],
+            Pubkey::new_unique(),
+            0,
+        );
+
+        assert_eq!(result, Err(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_collect_fees_spl_cannot_accept_zero_amount() {
+        let program_id = Pubkey::new_unique();
+        let accounts = vec![];
+
+        let result = collect_fees_spl(&program_id, &accounts, 0);
+
+        assert_eq!(result, Err(ProgramError::InvalidInstructionData));
+    }
+}
@@ -1,19 +1,940 @@
 //! State module for the Axelar Solana Gas Service
 
+use anchor_discriminators::Discriminator;
 use anchor_discriminators_macros::account;
+use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Pod, Zeroable};
 use program_utils::pda::BytemuckedPda;
 use solana_program::pubkey::Pubkey;
 
+use crate::error::GasServiceError;
+
 /// Keep track of the gas collector for aggregating gas payments
 #[repr(C)]
 #[account(zero_copy)]
+#[allow(clippy::partial_pub_fields)]
 #[derive(Zeroable, Pod, Clone, Copy, PartialEq, Eq, Debug)]
 pub struct Config {
     /// Operator with permission to give refunds & withdraw funds
     pub operator: Pubkey,
     /// The bump seed used to derive the PDA, ensuring the address is valid.
     pub bump: u8,
+    /// The [`RefundAddressPolicy`] enforced on gas payments, see
+    /// [`Config::refund_address_policy`].
+    refund_address_policy: u8,
+    /// Whether the [`ApproverSet`] guards collects and refunds, see
+    /// [`Config::approvals_required`].
+    approvals_required: u8,
+    /// Whether gas payments must pass the paused chains PDA, see
+    /// [`Config::paused_chains_required`].
+    paused_chains_required: u8,
+    /// padding for bump, refund address policy and the flags
+    _padding: [u8; 4],
+    /// Sequence number of the next event emitted by the gas service. Every
+    /// event carries its own sequence number so that accounting systems can
+    /// detect missed events.
+    pub event_sequence: u64,
+}
+
+impl Config {
+    /// Size of the config accounts created before the refund address policy
+    /// and the event sequence were added, holding only the discriminator,
+    /// the operator and the bump. Such accounts are grown to the current
+    /// layout with [`GasServiceInstruction::MigrateConfig`].
+    ///
+    /// [`GasServiceInstruction::MigrateConfig`]: crate::instructions::GasServiceInstruction::MigrateConfig
+    pub const LEGACY_PDA_SIZE: usize = 8 + 32 + 1;
+
+    /// Reads the operator and the bump of a config account in the legacy
+    /// layout, see [`Config::LEGACY_PDA_SIZE`].
+    #[must_use]
+    pub fn read_legacy(data: &[u8]) -> Option<(Pubkey, u8)> {
+        if data.len() != Self::LEGACY_PDA_SIZE {
+            return None;
+        }
+        let (operator, bump) = data
+            .strip_prefix(Self::DISCRIMINATOR)?
+            .split_first_chunk::<32>()?;
+
+        Some((Pubkey::new_from_array(*operator), *bump.first()?))
+    }
+
+    /// Creates a new `Config` whose first event gets sequence number zero.
+    #[must_use]
+    pub const fn new(operator: Pubkey, bump: u8) -> Self {
+        Self {
+            operator,
+            bump,
+            refund_address_policy: RefundAddressPolicy::Any as u8,
+            approvals_required: 0,
+            paused_chains_required: 0,
+            _padding: [0; 4],
+            event_sequence: 0,
+        }
+    }
+
+    /// The refund addresses gas payments may name.
+    #[must_use]
+    pub const fn refund_address_policy(&self) -> RefundAddressPolicy {
+        match self.refund_address_policy {
+            0 => RefundAddressPolicy::Any,
+            _ => RefundAddressPolicy::PayerOnly,
+        }
+    }
+
+    /// Sets the refund addresses gas payments may name.
+    pub fn set_refund_address_policy(&mut self, policy: RefundAddressPolicy) {
+        self.refund_address_policy = policy as u8;
+    }
+
+    /// Whether an [`ApproverSet`] with a non-zero threshold is registered, so
+    /// that collects and refunds must pass the approver set PDA.
+    #[must_use]
+    pub const fn approvals_required(&self) -> bool {
+        self.approvals_required != 0
+    }
+
+    /// Sets whether collects and refunds must pass the approver set PDA.
+    pub fn set_approvals_required(&mut self, required: bool) {
+        self.approvals_required = required.into();
+    }
+
+    /// Whether the operator has paused chains through [`PausedChains`], so
+    /// that gas payments taking the paused chains PDA as an optional account
+    /// must pass it.
+    #[must_use]
+    pub const fn paused_chains_required(&self) -> bool {
+        self.paused_chains_required != 0
+    }
+
+    /// Sets whether gas payments must pass the paused chains PDA.
+    pub fn set_paused_chains_required(&mut self, required: bool) {
+        self.paused_chains_required = required.into();
+    }
+
+    /// Checks that a gas payment by `payer` may name `refund_address`.
+    ///
+    /// # Errors
+    ///
+    /// - [`GasServiceError::RefundAddressNotPayer`] if the policy is
+    ///   [`RefundAddressPolicy::PayerOnly`] and `refund_address` is not
+    ///   `payer`.
+    pub fn ensure_refund_address_allowed(
+        &self,
+        payer: &Pubkey,
+        refund_address: &Pubkey,
+    ) -> Result<(), GasServiceError> {
+        match self.refund_address_policy() {
+            RefundAddressPolicy::Any => Ok(()),
+            RefundAddressPolicy::PayerOnly if refund_address == payer => Ok(()),
+            RefundAddressPolicy::PayerOnly => Err(GasServiceError::RefundAddressNotPayer),
+        }
+    }
+
+    /// Returns the sequence number of the next event and advances the
+    /// counter.
+    ///
+    /// # Errors
+    ///
+    /// - [`GasServiceError::EventSequenceOverflow`] if the counter overflows.
+    pub fn next_event_sequence(&mut self) -> Result<u64, GasServiceError> {
+        let sequence = self.event_sequence;
+        self.event_sequence = sequence
+            .checked_add(1)
+            .ok_or(GasServiceError::EventSequenceOverflow)?;
+        Ok(sequence)
+    }
 }
 
 impl BytemuckedPda for Config {}
+
+/// Which refund addresses the operator lets gas payments name.
+#[repr(u8)]
+#[derive(
+    BorshSerialize, BorshDeserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Default,
+)]
+#[borsh(use_discriminant = true)]
+pub enum RefundAddressPolicy {
+    /// Payments may name any refund address.
+    #[default]
+    Any = 0,
+    /// Payments must name their payer as refund address, so refunds always
+    /// return to the account that paid.
+    PayerOnly = 1,
+}
+
+/// Maximum number of approvers that can be registered in the [`ApproverSet`].
+pub const MAX_APPROVERS: usize = 16;
+
+/// Optional M-of-N scheme guarding collect and refund operations above a
+/// configurable amount. Operations at or below `amount_threshold` only need
+/// the operator signature.
+#[repr(C)]
+#[account(zero_copy)]
+#[allow(clippy::partial_pub_fields)]
+#[derive(Zeroable, Pod, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ApproverSet {
+    /// The registered approver keys, only the first `approver_count` are set.
+    pub approvers: [Pubkey; MAX_APPROVERS],
+    /// Operations moving more than this amount need `threshold` approvals.
+    pub amount_threshold: u64,
+    /// How long, in seconds, an approval stays valid after the first
+    /// approver signed it.
+    pub approval_ttl: i64,
+    /// Incremented on every update of the set, invalidating the approvals
+    /// collected from the previous approvers.
+    pub epoch: u64,
+    /// Number of registered approvers.
+    pub approver_count: u8,
+    /// Number of approvals required for a guarded operation.
+    pub threshold: u8,
+    /// The bump seed used to derive the PDA, ensuring the address is valid.
+    pub bump: u8,
+    /// padding for bump
+    _padding: [u8; 5],
+}
+
+impl ApproverSet {
+    /// Creates a new `ApproverSet`.
+    #[must_use]
+    pub const fn new(
+        approvers: [Pubkey; MAX_APPROVERS],
+        approver_count: u8,
+        threshold: u8,
+        amount_threshold: u64,
+        approval_ttl: i64,
+        epoch: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            approvers,
+            amount_threshold,
+            approval_ttl,
+            epoch,
+            approver_count,
+            threshold,
+            bump,
+            _padding: [0; 5],
+        }
+    }
+
+    /// The registered approvers.
+    #[must_use]
+    pub fn approvers(&self) -> &[Pubkey] {
+        self.approvers
+            .get(..usize::from(self.approver_count))
+            .unwrap_or_default()
+    }
+
+    /// Returns the index of `approver` in the set, if registered.
+    #[must_use]
+    pub fn approver_index(&self, approver: &Pubkey) -> Option<usize> {
+        self.approvers().iter().position(|key| key == approver)
+    }
+
+    /// Whether moving `amount` requires threshold approvals.
+    #[must_use]
+    pub const fn requires_approval(&self, amount: u64) -> bool {
+        self.threshold > 0 && amount > self.amount_threshold
+    }
+}
+
+impl BytemuckedPda for ApproverSet {}
+
+/// Approvals collected for a single [`GuardedOperation`].
+#[repr(C)]
+#[account(zero_copy)]
+#[allow(clippy::partial_pub_fields)]
+#[derive(Zeroable, Pod, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OperationApproval {
+    /// Hash of the approved [`GuardedOperation`].
+    pub operation_hash: [u8; 32],
+    /// Unix timestamp after which the approvals can no longer be used.
+    pub expires_at: i64,
+    /// The [`ApproverSet::epoch`] the approvals were collected in.
+    pub approver_set_epoch: u64,
+    /// Bitmap of the [`ApproverSet`] indices that approved the operation.
+    pub approvals: u16,
+    /// The bump seed used to derive the PDA, ensuring the address is valid.
+    pub bump: u8,
+    /// padding for bump
+    _padding: [u8; 5],
+}
+
+impl OperationApproval {
+    /// Creates a new `OperationApproval` without any approvals.
+    #[must_use]
+    pub const fn new(
+        operation_hash: [u8; 32],
+        expires_at: i64,
+        approver_set_epoch: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            operation_hash,
+            expires_at,
+            approver_set_epoch,
+            approvals: 0,
+            bump,
+            _padding: [0; 5],
+        }
+    }
+
+    /// Number of approvals collected so far.
+    #[must_use]
+    pub const fn approval_count(&self) -> u32 {
+        self.approvals.count_ones()
+    }
+
+    /// Whether the approvals can no longer be used at `now`.
+    #[must_use]
+    pub const fn is_expired(&self, now: i64) -> bool {
+        now > self.expires_at
+    }
+}
+
+impl BytemuckedPda for OperationApproval {}
+
+/// Maximum number of destination chains that can be paused at once, see
+/// [`PausedChains`].
+pub const MAX_PAUSED_CHAINS: usize = 16;
+
+/// Destination chains the operator halted gas payments towards, e.g. while
+/// they undergo an incident. Payments towards other chains keep working.
+#[repr(C)]
+#[account(zero_copy)]
+#[allow(clippy::partial_pub_fields)]
+#[derive(Zeroable, Pod, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PausedChains {
+    /// Hashes of the paused chain names, see [`PausedChains::chain_hash`].
+    /// Only the first `count` are set.
+    pub chain_hashes: [[u8; 32]; MAX_PAUSED_CHAINS],
+    /// Number of paused chains.
+    pub count: u8,
+    /// The bump seed used to derive the PDA, ensuring the address is valid.
+    pub bump: u8,
+    /// padding for bump
+    _padding: [u8; 6],
+}
+
+impl PausedChains {
+    /// Creates a new `PausedChains` without any paused chain.
+    #[must_use]
+    pub const fn new(bump: u8) -> Self {
+        Self {
+            chain_hashes: [[0; 32]; MAX_PAUSED_CHAINS],
+            count: 0,
+            bump,
+            _padding: [0; 6],
+        }
+    }
+
+    /// The hash a chain name is tracked by.
+    #[must_use]
+    pub fn chain_hash(chain: &str) -> [u8; 32] {
+        solana_program::keccak::hash(chain.as_bytes()).to_bytes()
+    }
+
+    /// The hashes of the paused chains.
+    #[must_use]
+    pub fn paused(&self) -> &[[u8; 32]] {
+        self.chain_hashes
+            .get(..usize::from(self.count))
+            .unwrap_or_default()
+    }
+
+    /// Whether gas payments towards `chain` are paused.
+    #[must_use]
+    pub fn is_paused(&self, chain: &str) -> bool {
+        self.paused().contains(&Self::chain_hash(chain))
+    }
+
+    /// Pauses or resumes gas payments towards `chain`. Pausing a paused chain
+    /// or resuming an active one is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// - [`GasServiceError::TooManyPausedChains`] if [`MAX_PAUSED_CHAINS`]
+    ///   chains are already paused.
+    pub fn set_paused(&mut self, chain: &str, paused: bool) -> Result<(), GasServiceError> {
+        let chain_hash = Self::chain_hash(chain);
+        let position = self.paused().iter().position(|hash| hash == &chain_hash);
+
+        match (position, paused) {
+            (None, true) => {
+                let slot = self
+                    .chain_hashes
+                    .get_mut(usize::from(self.count))
+                    .ok_or(GasServiceError::TooManyPausedChains)?;
+                *slot = chain_hash;
+                self.count = self.count.saturating_add(1);
+            }
+            (Some(index), false) => {
+                // Move the last paused chain into the freed slot
+                let last = usize::from(self.count.saturating_sub(1));
+                self.chain_hashes.swap(index, last);
+                if let Some(slot) = self.chain_hashes.get_mut(last) {
+                    *slot = [0; 32];
+                }
+                self.count = self.count.saturating_sub(1);
+            }
+            (None, false) | (Some(_), true) => {}
+        }
+
+        Ok(())
+    }
+}
+
+impl BytemuckedPda for PausedChains {}
+
+/// Limits how long after the original payment the operator can still refund
+/// it, so bookkeeping that was settled long ago can't be reopened. Applies to
+/// refunds of payments recorded in a [`GasReceipt`].
+#[repr(C)]
+#[account(zero_copy)]
+#[allow(clippy::partial_pub_fields)]
+#[derive(Zeroable, Pod, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RefundPolicy {
+    /// Maximum age, in seconds, of a payment that can still be refunded. Zero
+    /// disables the window.
+    pub max_refund_age: i64,
+    /// The bump seed used to derive the PDA, ensuring the address is valid.
+    pub bump: u8,
+    /// padding for bump
+    _padding: [u8; 7],
+}
+
+impl RefundPolicy {
+    /// Creates a new `RefundPolicy`.
+    #[must_use]
+    pub const fn new(max_refund_age: i64, bump: u8) -> Self {
+        Self {
+            max_refund_age,
+            bump,
+            _padding: [0; 7],
+        }
+    }
+
+    /// Checks that a payment made at `paid_at` can still be refunded at
+    /// `now`.
+    ///
+    /// # Errors
+    ///
+    /// - [`GasServiceError::InvalidPaymentTimestamp`] if the payment is dated
+    ///   in the future.
+    /// - [`GasServiceError::RefundWindowExpired`] if the payment is older than
+    ///   `max_refund_age`.
+    pub const fn ensure_refundable(&self, paid_at: i64, now: i64) -> Result<(), GasServiceError> {
+        if paid_at > now {
+            return Err(GasServiceError::InvalidPaymentTimestamp);
+        }
+
+        if self.max_refund_age > 0 && now.saturating_sub(paid_at) > self.max_refund_age {
+            return Err(GasServiceError::RefundWindowExpired);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytemuckedPda for RefundPolicy {}
+
+/// Conversion rate, set by the operator, from gas in the native units of a
+/// destination chain to lamports, so users can pay gas by the amount the
+/// destination chain charges and have the program compute the SOL price.
+#[repr(C)]
+#[account(zero_copy)]
+#[allow(clippy::partial_pub_fields)]
+#[derive(Zeroable, Pod, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GasPriceFeed {
+    /// Lamports charged per `destination_units`.
+    pub lamports: u64,
+    /// Destination chain native units worth `lamports`.
+    pub destination_units: u64,
+    /// The bump seed used to derive the PDA, ensuring the address is valid.
+    pub bump: u8,
+    /// padding for bump
+    _padding: [u8; 3],
+    /// Destination chain native units charged per byte of payload, see
+    /// [`GasPriceFeed::quote`].
+    pub payload_byte_units: u32,
+}
+
+impl GasPriceFeed {
+    /// Creates a new `GasPriceFeed` charging `lamports` per
+    /// `destination_units`, and `payload_byte_units` destination units per
+    /// byte of payload.
+    #[must_use]
+    pub const fn new(
+        lamports: u64,
+        destination_units: u64,
+        payload_byte_units: u32,
+        bump: u8,
+    ) -> Self {
+        Self {
+            lamports,
+            destination_units,
+            bump,
+            _padding: [0; 3],
+            payload_byte_units,
+        }
+    }
+
+    /// Quotes the lamports required for a contract call with a payload of
+    /// `payload_size` bytes and an `execution_limit` in destination chain
+    /// native units: the execution limit plus `payload_byte_units` per
+    /// payload byte, converted with [`GasPriceFeed::to_lamports`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`GasPriceFeed::to_lamports`], and
+    /// [`GasServiceError::GasConversionOverflow`] if the destination units
+    /// don't fit in a `u64`.
+    pub fn quote(&self, payload_size: u32, execution_limit: u64) -> Result<u64, GasServiceError> {
+        self.to_lamports(self.quote_destination_units(payload_size, execution_limit)?)
+    }
+
+    /// Destination chain native units charged for a contract call with a
+    /// payload of `payload_size` bytes and an `execution_limit`, before their
+    /// conversion to lamports by [`GasPriceFeed::quote`].
+    ///
+    /// # Errors
+    ///
+    /// [`GasServiceError::GasConversionOverflow`] if the destination units
+    /// don't fit in a `u64`.
+    pub fn quote_destination_units(
+        &self,
+        payload_size: u32,
+        execution_limit: u64,
+    ) -> Result<u64, GasServiceError> {
+        u64::from(payload_size)
+            .checked_mul(u64::from(self.payload_byte_units))
+            .and_then(|payload_units| payload_units.checked_add(execution_limit))
+            .ok_or(GasServiceError::GasConversionOverflow)
+    }
+
+    /// Converts `destination_amount` destination chain native units to
+    /// lamports, rounding up so the gas service never receives less than
+    /// the rate asks for.
+    ///
+    /// # Errors
+    ///
+    /// - [`GasServiceError::InvalidConversionRate`] if `destination_units`
+    ///   is zero.
+    /// - [`GasServiceError::GasConversionOverflow`] if the lamports don't
+    ///   fit in a `u64`.
+    pub fn to_lamports(&self, destination_amount: u64) -> Result<u64, GasServiceError> {
+        if self.destination_units == 0 {
+            return Err(GasServiceError::InvalidConversionRate);
+        }
+
+        let lamports = u128::from(destination_amount)
+            .checked_mul(u128::from(self.lamports))
+            .ok_or(GasServiceError::GasConversionOverflow)?
+            .div_ceil(u128::from(self.destination_units));
+
+        u64::try_from(lamports).map_err(|_err| GasServiceError::GasConversionOverflow)
+    }
+}
+
+impl BytemuckedPda for GasPriceFeed {}
+
+/// Native gas paid for a single message, kept so the payment can be refunded
+/// automatically when the Axelar network reports the message as
+/// undeliverable, see [`RefundNotice`].
+///
+/// Payments for the same message add up in the same receipt. The sender that
+/// opened the receipt closes it once the message was delivered or refunded,
+/// see [`GasReceiptStatus`].
+#[repr(C)]
+#[account(zero_copy)]
+#[allow(clippy::partial_pub_fields)]
+#[derive(Zeroable, Pod, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GasReceipt {
+    /// The account that opened the receipt, the only one that can close it.
+    pub sender: Pubkey,
+    /// The [`GasReceipt::message_id_hash`] of the paid message, used to
+    /// derive the PDA.
+    pub message_id_hash: [u8; 32],
+    /// Where the refund is sent.
+    pub refund_address: Pubkey,
+    /// The lamports paid for the message and not refunded yet.
+    pub amount: u64,
+    /// Unix timestamp of the first payment, checked against the
+    /// [`RefundPolicy`] window. Later payments don't restart the window.
+    pub paid_at: i64,
+    /// The bump seed used to derive the PDA, ensuring the address is valid.
+    pub bump: u8,
+    /// The [`GasReceiptStatus`] of the receipt, see [`GasReceipt::status`].
+    status: u8,
+    /// padding for bump and status
+    _padding: [u8; 6],
+}
+
+impl GasReceipt {
+    /// Creates a new pending `GasReceipt`.
+    #[must_use]
+    pub const fn new(
+        sender: Pubkey,
+        message_id_hash: [u8; 32],
+        refund_address: Pubkey,
+        amount: u64,
+        paid_at: i64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            sender,
+            message_id_hash,
+            refund_address,
+            amount,
+            paid_at,
+            bump,
+            status: GasReceiptStatus::Pending as u8,
+            _padding: [0; 6],
+        }
+    }
+
+    /// The hash a message is tracked by, used to derive the [`GasReceipt`]
+    /// PDA.
+    #[must_use]
+    pub fn message_id_hash(message_id: &str) -> [u8; 32] {
+        solana_program::keccak::hash(message_id.as_bytes()).to_bytes()
+    }
+
+    /// Whether the message was delivered or refunded.
+    #[must_use]
+    pub const fn status(&self) -> GasReceiptStatus {
+        match self.status {
+            0 => GasReceiptStatus::Pending,
+            1 => GasReceiptStatus::Delivered,
+            _ => GasReceiptStatus::Refunded,
+        }
+    }
+
+    /// Sets whether the message was delivered or refunded.
+    pub fn set_status(&mut self, status: GasReceiptStatus) {
+        self.status = status as u8;
+    }
+}
+
+impl BytemuckedPda for GasReceipt {}
+
+/// Where the message of a [`GasReceipt`] stands.
+#[repr(u8)]
+#[derive(
+    BorshSerialize, BorshDeserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Default,
+)]
+#[borsh(use_discriminant = true)]
+pub enum GasReceiptStatus {
+    /// The message is neither delivered nor refunded yet.
+    #[default]
+    Pending = 0,
+    /// The operator reported the message as delivered, see
+    /// [`GasServiceInstruction::MarkGasReceiptDelivered`].
+    ///
+    /// [`GasServiceInstruction::MarkGasReceiptDelivered`]: crate::instructions::GasServiceInstruction::MarkGasReceiptDelivered
+    Delivered = 1,
+    /// Part of the gas was refunded.
+    Refunded = 2,
+}
+
+/// Longest window, in slots, a payload hash can be pre-registered for, see
+/// [`PayloadPreRegistration`].
+pub const MAX_PRE_REGISTRATION_SLOTS: u64 = 1_500;
+
+/// A contract call a sender announced before paying gas for it, so relayers
+/// can fetch its payload and start validating it early. Closed by the gas
+/// payment linked to it.
+#[repr(C)]
+#[account(zero_copy)]
+#[allow(clippy::partial_pub_fields)]
+#[derive(Zeroable, Pod, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PayloadPreRegistration {
+    /// Slot after which the pre-registration can no longer be linked to a
+    /// payment.
+    pub expires_at_slot: u64,
+    /// The bump seed used to derive the PDA, ensuring the address is valid.
+    pub bump: u8,
+    /// padding for bump
+    _padding: [u8; 7],
+}
+
+impl PayloadPreRegistration {
+    /// Creates a new `PayloadPreRegistration`.
+    #[must_use]
+    pub const fn new(expires_at_slot: u64, bump: u8) -> Self {
+        Self {
+            expires_at_slot,
+            bump,
+            _padding: [0; 7],
+        }
+    }
+
+    /// The hash a contract call is tracked by, used to derive the
+    /// [`PayloadPreRegistration`] PDA.
+    #[must_use]
+    pub fn call_hash(
+        destination_chain: &str,
+        destination_address: &str,
+        payload_hash: &[u8; 32],
+    ) -> [u8; 32] {
+        solana_program::keccak::hashv(&[
+            &solana_program::keccak::hash(destination_chain.as_bytes()).to_bytes(),
+            &solana_program::keccak::hash(destination_address.as_bytes()).to_bytes(),
+            payload_hash,
+        ])
+        .to_bytes()
+    }
+}
+
+impl BytemuckedPda for PayloadPreRegistration {}
+
+/// The only source chain and address [`RefundNotice`] messages are accepted
+/// from, normally the Axelar hub.
+#[repr(C)]
+#[account(zero_copy)]
+#[allow(clippy::partial_pub_fields)]
+#[derive(Zeroable, Pod, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RefundNoticeSource {
+    /// Hash of the source chain name.
+    pub chain_hash: [u8; 32],
+    /// Hash of the source address.
+    pub address_hash: [u8; 32],
+    /// The bump seed used to derive the PDA, ensuring the address is valid.
+    pub bump: u8,
+    /// padding for bump
+    _padding: [u8; 7],
+}
+
+impl RefundNoticeSource {
+    /// Creates a new `RefundNoticeSource`.
+    #[must_use]
+    pub fn new(source_chain: &str, source_address: &str, bump: u8) -> Self {
+        Self {
+            chain_hash: solana_program::keccak::hash(source_chain.as_bytes()).to_bytes(),
+            address_hash: solana_program::keccak::hash(source_address.as_bytes()).to_bytes(),
+            bump,
+            _padding: [0; 7],
+        }
+    }
+
+    /// Whether a message sent by `source_address` on `source_chain` comes
+    /// from this source.
+    #[must_use]
+    pub fn matches(&self, source_chain: &str, source_address: &str) -> bool {
+        self.chain_hash == solana_program::keccak::hash(source_chain.as_bytes()).to_bytes()
+            && self.address_hash
+                == solana_program::keccak::hash(source_address.as_bytes()).to_bytes()
+    }
+}
+
+impl BytemuckedPda for RefundNoticeSource {}
+
+/// Borsh encoded payload of the GMP message the [`RefundNoticeSource`] sends
+/// to the gas service when a message could not be delivered.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct RefundNotice {
+    /// The id of the undeliverable message, naming its [`GasReceipt`].
+    pub message_id: String,
+}
+
+/// Maximum number of refunds the [`RefundQueue`] holds at once.
+pub const MAX_QUEUED_REFUNDS: usize = 32;
+
+/// Maximum length, in bytes, of the message id of a [`QueuedRefund`].
+pub const MAX_QUEUED_MESSAGE_ID_LEN: usize = 128;
+
+/// A refund the operator authorized, waiting in the [`RefundQueue`].
+#[repr(C)]
+#[allow(clippy::partial_pub_fields)]
+#[derive(Zeroable, Pod, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct QueuedRefund {
+    /// The account receiving the refund.
+    pub receiver: Pubkey,
+    /// The amount of lamports to refund.
+    pub amount: u64,
+    /// The message the refund is for, only the first `message_id_len` bytes
+    /// are set.
+    pub message_id: [u8; MAX_QUEUED_MESSAGE_ID_LEN],
+    /// Length of the message id.
+    pub message_id_len: u8,
+    /// padding for `message_id_len`
+    _padding: [u8; 7],
+}
+
+impl QueuedRefund {
+    /// Creates a new `QueuedRefund`.
+    ///
+    /// # Errors
+    ///
+    /// - [`GasServiceError::MessageIdTooLong`] if `message_id` is longer than
+    ///   [`MAX_QUEUED_MESSAGE_ID_LEN`] bytes.
+    pub fn new(receiver: Pubkey, message_id: &str, amount: u64) -> Result<Self, GasServiceError> {
+        let mut refund = Self {
+            receiver,
+            amount,
+            message_id: [0; MAX_QUEUED_MESSAGE_ID_LEN],
+            message_id_len: u8::try_from(message_id.len())
+                .map_err(|_err| GasServiceError::MessageIdTooLong)?,
+            _padding: [0; 7],
+        };
+        refund
+            .message_id
+            .get_mut(..message_id.len())
+            .ok_or(GasServiceError::MessageIdTooLong)?
+            .copy_from_slice(message_id.as_bytes());
+
+        Ok(refund)
+    }
+
+    /// The message the refund is for.
+    #[must_use]
+    pub fn message_id(&self) -> &str {
+        self.message_id
+            .get(..usize::from(self.message_id_len))
+            .and_then(|bytes| core::str::from_utf8(bytes).ok())
+            .unwrap_or_default()
+    }
+}
+
+/// First-in first-out queue of refunds the operator authorized, which anyone
+/// can then process, so refunds keep flowing while the operator is offline.
+#[repr(C)]
+#[account(zero_copy)]
+#[allow(clippy::partial_pub_fields)]
+#[derive(Zeroable, Pod, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RefundQueue {
+    /// Ring buffer of the queued refunds, `len` of them starting at `head`.
+    pub refunds: [QueuedRefund; MAX_QUEUED_REFUNDS],
+    /// Index of the next refund to process.
+    pub head: u8,
+    /// Number of queued refunds.
+    pub len: u8,
+    /// The bump seed used to derive the PDA, ensuring the address is valid.
+    pub bump: u8,
+    /// padding for bump
+    _padding: [u8; 5],
+}
+
+impl RefundQueue {
+    /// Creates a new empty `RefundQueue`.
+    #[must_use]
+    pub const fn new(bump: u8) -> Self {
+        Self {
+            refunds: [QueuedRefund {
+                receiver: Pubkey::new_from_array([0; 32]),
+                amount: 0,
+                message_id: [0; MAX_QUEUED_MESSAGE_ID_LEN],
+                message_id_len: 0,
+                _padding: [0; 7],
+            }; MAX_QUEUED_REFUNDS],
+            head: 0,
+            len: 0,
+            bump,
+            _padding: [0; 5],
+        }
+    }
+
+    /// The next refund to process, if any.
+    #[must_use]
+    pub fn front(&self) -> Option<&QueuedRefund> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.refunds.get(usize::from(self.head))
+    }
+
+    /// Appends `refund` to the back of the queue.
+    ///
+    /// # Errors
+    ///
+    /// - [`GasServiceError::RefundQueueFull`] if [`MAX_QUEUED_REFUNDS`]
+    ///   refunds are already queued.
+    pub fn push_back(&mut self, refund: QueuedRefund) -> Result<(), GasServiceError> {
+        let len = usize::from(self.len);
+        if len >= MAX_QUEUED_REFUNDS {
+            return Err(GasServiceError::RefundQueueFull);
+        }
+
+        let tail = (usize::from(self.head) + len)
+            .checked_rem(MAX_QUEUED_REFUNDS)
+            .ok_or(GasServiceError::RefundQueueFull)?;
+        let slot = self
+            .refunds
+            .get_mut(tail)
+            .ok_or(GasServiceError::RefundQueueFull)?;
+        *slot = refund;
+        self.len = self.len.saturating_add(1);
+
+        Ok(())
+    }
+
+    /// Removes and returns the next refund to process, if any.
+    pub fn pop_front(&mut self) -> Option<QueuedRefund> {
+        let refund = *self.front()?;
+        let next_head =
+            u8::try_from((usize::from(self.head) + 1).checked_rem(MAX_QUEUED_REFUNDS)?).ok()?;
+        if let Some(slot) = self.refunds.get_mut(usize::from(self.head)) {
+            *slot = Zeroable::zeroed();
+        }
+        self.head = next_head;
+        self.len = self.len.saturating_sub(1);
+
+        Some(refund)
+    }
+}
+
+impl BytemuckedPda for RefundQueue {}
+
+/// An operator operation that can require threshold approvals, see
+/// [`ApproverSet`].
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum GuardedOperation {
+    /// Collect native SOL fees.
+    CollectFees {
+        /// The account receiving the lamports.
+        receiver: Pubkey,
+        /// The amount of lamports to collect.
+        amount: u64,
+    },
+    /// Collect SPL token fees.
+    CollectSplFees {
+        /// The token account receiving the tokens.
+        receiver_token_account: Pubkey,
+        /// The mint of the collected token.
+        mint: Pubkey,
+        /// The amount of tokens to collect.
+        amount: u64,
+    },
+    /// Refund native SOL fees.
+    RefundFees {
+        /// The account receiving the refund.
+        receiver: Pubkey,
+        /// The message the refund is for.
+        message_id: String,
+        /// The amount of lamports to refund.
+        amount: u64,
+    },
+    /// Replace or disable the registered approvers.
+    SetApprovers {
+        /// The new approver keys, empty to disable the threshold scheme.
+        approvers: Vec<Pubkey>,
+        /// Number of approvals required for a guarded operation.
+        threshold: u8,
+        /// Operations moving more than this amount need approvals.
+        amount_threshold: u64,
+        /// How long, in seconds, approvals stay valid.
+        approval_ttl: i64,
+    },
+}
+
+impl GuardedOperation {
+    /// The hash approvers sign off on, used to derive the
+    /// [`OperationApproval`] PDA.
+    #[must_use]
+    pub fn hash(&self) -> [u8; 32] {
+        let data = borsh::to_vec(self).expect("operation serialization cannot fail");
+        solana_program::keccak::hash(&data).to_bytes()
+    }
+}
@@ -0,0 +1,517 @@
+use axelar_solana_gas_service::events::OperationApprovedEvent;
+use axelar_solana_gas_service::state::GuardedOperation;
+use axelar_solana_gateway_test_fixtures::{
+    assert_msg_present_in_logs, base::TestFixture, gas_service::GasServiceUtils,
+};
+use event_cpi_test_utils::get_first_event_cpi_occurrence;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+const AMOUNT_THRESHOLD: u64 = 10_000_000;
+const APPROVAL_TTL: i64 = 3_600;
+
+async fn setup_with_approvers(approvers: &[Keypair]) -> (TestFixture, GasServiceUtils) {
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    test_fixture
+        .fund_account(&gas_utils.config_pda, 1_000_000_000)
+        .await;
+
+    let ix = axelar_solana_gas_service::instructions::set_approvers_instruction(
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        approvers.iter().map(Keypair::pubkey).collect(),
+        2,
+        AMOUNT_THRESHOLD,
+        APPROVAL_TTL,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    (test_fixture, gas_utils)
+}
+
+async fn approve(test_fixture: &mut TestFixture, approver: &Keypair, operation: GuardedOperation) {
+    let ix = axelar_solana_gas_service::instructions::approve_operation_instruction(
+        &test_fixture.payer.pubkey(),
+        &approver.pubkey(),
+        operation,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), approver])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_small_collect_does_not_need_approvals() {
+    let approvers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let (mut test_fixture, gas_utils) = setup_with_approvers(&approvers).await;
+
+    let ix = axelar_solana_gas_service::instructions::collect_fees_instruction(
+        &gas_utils.operator.pubkey(),
+        &Keypair::new().pubkey(),
+        AMOUNT_THRESHOLD,
+    )
+    .unwrap();
+
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_collect_without_approval_accounts() {
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    test_fixture
+        .fund_account(&gas_utils.config_pda, 1_000_000_000)
+        .await;
+
+    // Without approvers, the accounts of the threshold scheme can be omitted
+    let mut ix = axelar_solana_gas_service::instructions::collect_fees_instruction(
+        &gas_utils.operator.pubkey(),
+        &Keypair::new().pubkey(),
+        AMOUNT_THRESHOLD + 1,
+    )
+    .unwrap();
+    ix.accounts.truncate(5);
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    let approvers = [Keypair::new(), Keypair::new()];
+    let ix = axelar_solana_gas_service::instructions::set_approvers_instruction(
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        approvers.iter().map(Keypair::pubkey).collect(),
+        2,
+        AMOUNT_THRESHOLD,
+        APPROVAL_TTL,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    // Once approvers are registered, the approver set PDA is required
+    let mut ix = axelar_solana_gas_service::instructions::collect_fees_instruction(
+        &gas_utils.operator.pubkey(),
+        &Keypair::new().pubkey(),
+        AMOUNT_THRESHOLD,
+    )
+    .unwrap();
+    ix.accounts.truncate(5);
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix.clone()],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await;
+    assert_msg_present_in_logs(
+        res.unwrap_err(),
+        "Approver Set PDA is required while approvers are registered",
+    );
+
+    // The operation approval PDA is only required above the threshold
+    let mut ix = axelar_solana_gas_service::instructions::collect_fees_instruction(
+        &gas_utils.operator.pubkey(),
+        &Keypair::new().pubkey(),
+        AMOUNT_THRESHOLD,
+    )
+    .unwrap();
+    ix.accounts.truncate(6);
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_large_collect_needs_threshold_approvals() {
+    let approvers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let (mut test_fixture, gas_utils) = setup_with_approvers(&approvers).await;
+
+    let receiver = Keypair::new().pubkey();
+    let amount = AMOUNT_THRESHOLD + 1;
+    let operation = GuardedOperation::CollectFees { receiver, amount };
+    let ix = axelar_solana_gas_service::instructions::collect_fees_instruction(
+        &gas_utils.operator.pubkey(),
+        &receiver,
+        amount,
+    )
+    .unwrap();
+
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix.clone()],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await;
+    assert_msg_present_in_logs(res.unwrap_err(), "require 2 approvals");
+
+    approve(&mut test_fixture, &approvers[0], operation.clone()).await;
+
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix.clone()],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await;
+    assert_msg_present_in_logs(
+        res.unwrap_err(),
+        "Operation has 1 of the 2 required approvals",
+    );
+
+    // Check the event of the second approval
+    let approve_ix = axelar_solana_gas_service::instructions::approve_operation_instruction(
+        &test_fixture.payer.pubkey(),
+        &approvers[2].pubkey(),
+        operation.clone(),
+    )
+    .unwrap();
+    let simulation_result = test_fixture
+        .simulate_tx_with_custom_signers(
+            &[approve_ix],
+            &[&test_fixture.payer.insecure_clone(), &approvers[2]],
+        )
+        .await
+        .unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    let event = get_first_event_cpi_occurrence::<OperationApprovedEvent>(&inner_ixs).unwrap();
+    assert_eq!(event.approver, approvers[2].pubkey());
+    assert_eq!(event.operation_hash, operation.hash());
+    assert_eq!(event.approvals, 2);
+
+    approve(&mut test_fixture, &approvers[2], operation.clone()).await;
+
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix.clone()],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    let receiver_balance = test_fixture
+        .try_get_account_no_checks(&receiver)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(receiver_balance, amount);
+
+    // Approvals are consumed by the operation
+    let (operation_approval_pda, _) =
+        axelar_solana_gas_service::get_operation_approval_pda(&operation.hash());
+    assert!(test_fixture
+        .try_get_account_no_checks(&operation_approval_pda)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_approvals_are_bound_to_the_operation() {
+    let approvers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let (mut test_fixture, gas_utils) = setup_with_approvers(&approvers).await;
+
+    let receiver = Keypair::new().pubkey();
+    let message_id = "tx-sig-2.1".to_owned();
+    let amount = AMOUNT_THRESHOLD * 2;
+    let operation = GuardedOperation::RefundFees {
+        receiver,
+        message_id: message_id.clone(),
+        amount,
+    };
+    approve(&mut test_fixture, &approvers[0], operation.clone()).await;
+    approve(&mut test_fixture, &approvers[1], operation).await;
+
+    let sender = Keypair::new();
+    test_fixture
+        .fund_account(&sender.pubkey(), 1_000_000_000)
+        .await;
+    let ix = axelar_solana_gas_service::instructions::pay_gas_with_receipt_instruction(
+        &sender.pubkey(),
+        message_id.clone(),
+        receiver,
+        amount + 1,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &sender])
+        .await
+        .unwrap();
+
+    // Refunding a different amount does not use the approvals
+    let ix = axelar_solana_gas_service::instructions::refund_fees_instruction(
+        &gas_utils.operator.pubkey(),
+        &receiver,
+        message_id.clone(),
+        amount + 1,
+    )
+    .unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await;
+    assert!(res.is_err());
+
+    let ix = axelar_solana_gas_service::instructions::refund_fees_instruction(
+        &gas_utils.operator.pubkey(),
+        &receiver,
+        message_id,
+        amount,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_approvals_from_previous_approver_set_are_invalid() {
+    let approvers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let (mut test_fixture, gas_utils) = setup_with_approvers(&approvers).await;
+
+    let receiver = Keypair::new().pubkey();
+    let amount = AMOUNT_THRESHOLD + 1;
+    let operation = GuardedOperation::CollectFees { receiver, amount };
+    approve(&mut test_fixture, &approvers[0], operation.clone()).await;
+    approve(&mut test_fixture, &approvers[1], operation).await;
+
+    let set_approvers = GuardedOperation::SetApprovers {
+        approvers: approvers.iter().map(Keypair::pubkey).collect(),
+        threshold: 2,
+        amount_threshold: AMOUNT_THRESHOLD,
+        approval_ttl: APPROVAL_TTL,
+    };
+    approve(&mut test_fixture, &approvers[0], set_approvers.clone()).await;
+    approve(&mut test_fixture, &approvers[1], set_approvers).await;
+    let ix = axelar_solana_gas_service::instructions::set_approvers_instruction(
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        approvers.iter().map(Keypair::pubkey).collect(),
+        2,
+        AMOUNT_THRESHOLD,
+        APPROVAL_TTL,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    let ix = axelar_solana_gas_service::instructions::collect_fees_instruction(
+        &gas_utils.operator.pubkey(),
+        &receiver,
+        amount,
+    )
+    .unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await;
+    assert_msg_present_in_logs(
+        res.unwrap_err(),
+        "Operation was approved by a previous approver set",
+    );
+}
+
+#[tokio::test]
+async fn test_replacing_approvers_needs_threshold_approvals() {
+    let approvers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let (mut test_fixture, gas_utils) = setup_with_approvers(&approvers).await;
+
+    // The operator alone can neither disable nor replace the approvers
+    let disable_ix = axelar_solana_gas_service::instructions::set_approvers_instruction(
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        vec![],
+        0,
+        0,
+        0,
+    )
+    .unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[disable_ix.clone()],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await;
+    assert_msg_present_in_logs(res.unwrap_err(), "Operation requires 2 approvals");
+
+    let new_approver = Keypair::new();
+    let replace_ix = axelar_solana_gas_service::instructions::set_approvers_instruction(
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        vec![new_approver.pubkey()],
+        1,
+        AMOUNT_THRESHOLD,
+        APPROVAL_TTL,
+    )
+    .unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[replace_ix.clone()],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await;
+    assert_msg_present_in_logs(res.unwrap_err(), "Operation requires 2 approvals");
+
+    // Approvals of the disable operation don't authorize another set
+    let disable = GuardedOperation::SetApprovers {
+        approvers: vec![],
+        threshold: 0,
+        amount_threshold: 0,
+        approval_ttl: 0,
+    };
+    approve(&mut test_fixture, &approvers[0], disable.clone()).await;
+    approve(&mut test_fixture, &approvers[1], disable).await;
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[replace_ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await;
+    assert!(res.is_err());
+
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[disable_ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    // Large collects only need the operator once the approvers are disabled
+    let ix = axelar_solana_gas_service::instructions::collect_fees_instruction(
+        &gas_utils.operator.pubkey(),
+        &Keypair::new().pubkey(),
+        AMOUNT_THRESHOLD + 1,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_only_registered_approvers_can_approve() {
+    let approvers = [Keypair::new(), Keypair::new()];
+    let (mut test_fixture, _gas_utils) = setup_with_approvers(&approvers).await;
+
+    let stranger = Keypair::new();
+    let ix = axelar_solana_gas_service::instructions::approve_operation_instruction(
+        &test_fixture.payer.pubkey(),
+        &stranger.pubkey(),
+        GuardedOperation::CollectFees {
+            receiver: stranger.pubkey(),
+            amount: AMOUNT_THRESHOLD + 1,
+        },
+    )
+    .unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &stranger])
+        .await;
+    assert_msg_present_in_logs(res.unwrap_err(), "is not a registered approver");
+}
+
+#[tokio::test]
+async fn test_only_operator_can_set_approvers() {
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let stranger = Keypair::new();
+    let ix = axelar_solana_gas_service::instructions::set_approvers_instruction(
+        &test_fixture.payer.pubkey(),
+        &stranger.pubkey(),
+        vec![stranger.pubkey(), Pubkey::new_unique()],
+        1,
+        AMOUNT_THRESHOLD,
+        APPROVAL_TTL,
+    )
+    .unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &stranger])
+        .await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_set_approvers_rejects_invalid_threshold() {
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let ix = axelar_solana_gas_service::instructions::set_approvers_instruction(
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        vec![Pubkey::new_unique(), Pubkey::new_unique()],
+        3,
+        AMOUNT_THRESHOLD,
+        APPROVAL_TTL,
+    )
+    .unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await;
+    assert_msg_present_in_logs(
+        res.unwrap_err(),
+        "Threshold must be between 1 and the number of approvers",
+    );
+}
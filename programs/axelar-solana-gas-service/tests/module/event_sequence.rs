@@ -0,0 +1,120 @@
+use axelar_solana_gas_service::events::{
+    BalanceChangedEvent, GasPaidEventV2, RefundWindowSetEvent,
+};
+use axelar_solana_gas_service::instructions::{pay_gas_instruction, set_refund_window_instruction};
+use axelar_solana_gateway_test_fixtures::base::TestFixture;
+use axelar_solana_gateway_test_fixtures::gas_service::GasServiceUtils;
+use event_cpi_test_utils::get_first_event_cpi_occurrence;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::inner_instruction::InnerInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+/// Simulates then sends `ix`, returning the instructions it invoked.
+async fn send(
+    test_fixture: &mut TestFixture,
+    ix: Instruction,
+    signer: &Keypair,
+) -> Vec<InnerInstruction> {
+    let signers = [&test_fixture.payer.insecure_clone(), signer];
+    let simulation_result = test_fixture
+        .simulate_tx_with_custom_signers(&[ix.clone()], &signers)
+        .await
+        .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &signers)
+        .await
+        .unwrap();
+
+    simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap()
+}
+
+fn pay_gas(payer: &Keypair, amount: u64) -> Instruction {
+    pay_gas_instruction(
+        &payer.pubkey(),
+        "ethereum".to_owned(),
+        "destination addr 123".to_owned(),
+        [42; 32],
+        Pubkey::new_unique(),
+        amount,
+    )
+    .unwrap()
+}
+
+async fn event_sequence(test_fixture: &mut TestFixture, gas_utils: &GasServiceUtils) -> u64 {
+    test_fixture
+        .gas_service_config_state(gas_utils.config_pda)
+        .await
+        .event_sequence
+}
+
+#[tokio::test]
+async fn test_events_are_numbered_consecutively_across_instructions() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    let payer = Keypair::new();
+    test_fixture
+        .fund_account(&payer.pubkey(), 1_000_000_000)
+        .await;
+
+    // Action
+    let inner_ixs = send(&mut test_fixture, pay_gas(&payer, 1_000_000), &payer).await;
+    let gas_paid = get_first_event_cpi_occurrence::<GasPaidEventV2>(&inner_ixs).unwrap();
+    let balance_changed =
+        get_first_event_cpi_occurrence::<BalanceChangedEvent>(&inner_ixs).unwrap();
+
+    let ix = set_refund_window_instruction(
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        3_600,
+    )
+    .unwrap();
+    let inner_ixs = send(&mut test_fixture, ix, &gas_utils.operator).await;
+    let refund_window_set =
+        get_first_event_cpi_occurrence::<RefundWindowSetEvent>(&inner_ixs).unwrap();
+
+    // Assert
+    assert_eq!(gas_paid.sequence, 0);
+    assert_eq!(balance_changed.sequence, 1);
+    assert_eq!(refund_window_set.sequence, 2);
+    assert_eq!(event_sequence(&mut test_fixture, &gas_utils).await, 3);
+}
+
+#[tokio::test]
+async fn test_failed_instruction_does_not_consume_sequence_numbers() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    let payer = Keypair::new();
+    test_fixture.fund_account(&payer.pubkey(), 1_000_000).await;
+
+    // Action: the payment exceeds the payer balance
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[pay_gas(&payer, 2_000_000)],
+            &[&test_fixture.payer.insecure_clone(), &payer],
+        )
+        .await;
+
+    // Assert
+    assert!(res.is_err());
+    assert_eq!(event_sequence(&mut test_fixture, &gas_utils).await, 0);
+
+    let inner_ixs = send(&mut test_fixture, pay_gas(&payer, 500_000), &payer).await;
+    let gas_paid = get_first_event_cpi_occurrence::<GasPaidEventV2>(&inner_ixs).unwrap();
+    assert_eq!(gas_paid.sequence, 0);
+}
@@ -1,6 +1,12 @@
+use anchor_discriminators::Discriminator;
+use axelar_solana_gas_service::instructions::{migrate_config, transfer_operatorship};
 use axelar_solana_gas_service::state::Config;
 use axelar_solana_gateway_test_fixtures::base::TestFixture;
+use program_utils::pda::BytemuckedPda;
 use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::account::{Account, ReadableAccount};
+use solana_sdk::rent::Rent;
+use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 
 #[tokio::test]
@@ -19,9 +25,132 @@ async fn test_successfully_initialize_config() {
         .await;
     assert_eq!(
         config,
-        Config {
-            operator: gas_utils.operator.pubkey(),
-            bump: config.bump
-        }
+        Config::new(gas_utils.operator.pubkey(), config.bump)
     );
+    assert_eq!(config.event_sequence, 0);
+}
+
+/// Replaces the config with one in the layout of the earlier program versions,
+/// holding only the operator and the bump.
+fn set_legacy_config(test_fixture: &mut TestFixture, operator: &Keypair, fees: u64) -> u8 {
+    let (config_pda, bump) = axelar_solana_gas_service::get_config_pda();
+    let mut data = Config::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(operator.pubkey().as_ref());
+    data.push(bump);
+    assert_eq!(
+        data.len(),
+        Config::LEGACY_PDA_SIZE,
+        "legacy config must hold only the operator and the bump"
+    );
+
+    test_fixture.set_account_state(
+        &config_pda,
+        Account {
+            lamports: Rent::default().minimum_balance(data.len()) + fees,
+            data,
+            owner: axelar_solana_gas_service::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    bump
+}
+
+#[tokio::test]
+async fn test_operator_migrates_legacy_config() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    let fees = 1_000_000;
+    let bump = set_legacy_config(&mut test_fixture, &gas_utils.operator, fees);
+
+    // The legacy config can't be read until migrated
+    let new_operator = Keypair::new();
+    let ix = transfer_operatorship(&gas_utils.operator.pubkey(), &new_operator.pubkey()).unwrap();
+    assert!(test_fixture
+        .send_tx_with_custom_signers(
+            &[ix.clone()],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .is_err());
+
+    // Action
+    let ix_migrate =
+        migrate_config(&test_fixture.payer.pubkey(), &gas_utils.operator.pubkey()).unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix_migrate],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let account = test_fixture
+        .get_account(&gas_utils.config_pda, &axelar_solana_gas_service::ID)
+        .await;
+    assert_eq!(account.data().len(), Config::pda_size());
+    assert_eq!(
+        account.lamports(),
+        Rent::default().minimum_balance(Config::pda_size()) + fees
+    );
+    assert_eq!(
+        *Config::read(account.data()).unwrap(),
+        Config::new(gas_utils.operator.pubkey(), bump)
+    );
+
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_only_operator_can_migrate_legacy_config() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    set_legacy_config(&mut test_fixture, &gas_utils.operator, 0);
+    let impostor = Keypair::new();
+
+    // Action
+    let ix = migrate_config(&test_fixture.payer.pubkey(), &impostor.pubkey()).unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &impostor])
+        .await;
+
+    // Assert
+    assert!(res.is_err());
+    let account = test_fixture
+        .get_account(&gas_utils.config_pda, &axelar_solana_gas_service::ID)
+        .await;
+    assert_eq!(account.data().len(), Config::LEGACY_PDA_SIZE);
+}
+
+#[tokio::test]
+async fn test_migrate_config_rejects_current_layout() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    // Action
+    let ix = migrate_config(&test_fixture.payer.pubkey(), &gas_utils.operator.pubkey()).unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await;
+
+    // Assert
+    assert!(res.is_err());
 }
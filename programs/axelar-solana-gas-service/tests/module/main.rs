@@ -10,12 +10,31 @@
     unused_must_use
 )]
 
+mod approvals;
+mod event_sequence;
 mod initialize;
+mod native_gas_lifecycle_example;
+mod paused_chains;
+mod refund_address_policy;
 mod transfer_operatorship;
 
 mod native {
     mod add_gas;
     mod collect_fees;
+    #[cfg(feature = "devnet-faucet")]
+    mod devnet_faucet;
     mod pay_for_contract_call;
+    mod pay_gas_in_destination_units;
+    mod pay_gas_with_quote;
+    mod pay_linked_gas;
+    mod pre_registered_gas;
+    mod quote_fee;
     mod refund_gas;
+    mod refund_notice;
+    mod refund_queue;
+    mod validate_payment;
+}
+
+mod spl {
+    mod pay_for_contract_call;
 }
@@ -1,4 +1,6 @@
-use axelar_solana_gas_service::events::GasAddedEvent;
+use axelar_solana_gas_service::events::{
+    BalanceChangeReason, BalanceChangedEvent, GasAddedByPayloadHashEvent, GasAddedEventV2,
+};
 use axelar_solana_gateway_test_fixtures::base::TestFixture;
 use event_cpi_test_utils::assert_event_cpi;
 use solana_program_test::{tokio, ProgramTest};
@@ -67,12 +69,22 @@ async fn test_add_native_gas() {
         .unwrap();
     assert!(!inner_ixs.is_empty());
 
-    let expected_event = GasAddedEvent {
+    let expected_event = GasAddedEventV2 {
         sender: payer.pubkey(),
         message_id,
         amount: gas_amount,
         refund_address,
         spl_token_account: None,
+        sequence: 0,
+    };
+
+    assert_event_cpi(&expected_event, &inner_ixs);
+
+    let expected_event = BalanceChangedEvent {
+        old_balance: config_pda_balance_before,
+        new_balance: config_pda_balance_before + gas_amount,
+        reason: BalanceChangeReason::GasAdded,
+        sequence: 1,
     };
 
     assert_event_cpi(&expected_event, &inner_ixs);
@@ -150,3 +162,83 @@ async fn fails_if_payer_not_signer() {
         .await;
     assert!(res.is_err());
 }
+
+#[tokio::test]
+async fn test_add_native_gas_by_payload_hash() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let payer = Keypair::new();
+    test_fixture
+        .fund_account(&payer.pubkey(), 1_000_000_000)
+        .await;
+    let config_pda_balance_before = test_fixture
+        .try_get_account_no_checks(&gas_utils.config_pda)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    // Action
+    let refund_address = Pubkey::new_unique();
+    let gas_amount = 1_000_000;
+    let payload_hash = [42; 32];
+    let ix = axelar_solana_gas_service::instructions::add_gas_by_payload_hash_instruction(
+        &payer.pubkey(),
+        payload_hash,
+        gas_amount,
+        refund_address,
+    )
+    .unwrap();
+    let signers = [&test_fixture.payer.insecure_clone(), &payer];
+    let simulation_result = test_fixture
+        .simulate_tx_with_custom_signers(&[ix.clone()], &signers)
+        .await
+        .unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &signers)
+        .await
+        .unwrap();
+
+    // Assert
+    assert_event_cpi(
+        &GasAddedByPayloadHashEvent {
+            sender: payer.pubkey(),
+            payload_hash,
+            amount: gas_amount,
+            refund_address,
+            sequence: 0,
+        },
+        &inner_ixs,
+    );
+    assert_event_cpi(
+        &BalanceChangedEvent {
+            old_balance: config_pda_balance_before,
+            new_balance: config_pda_balance_before + gas_amount,
+            reason: BalanceChangeReason::GasAdded,
+            sequence: 1,
+        },
+        &inner_ixs,
+    );
+    let config_pda_balance_after = test_fixture
+        .try_get_account_no_checks(&gas_utils.config_pda)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        config_pda_balance_after,
+        config_pda_balance_before + gas_amount
+    );
+}
@@ -1,4 +1,6 @@
-use axelar_solana_gas_service::events::GasCollectedEvent;
+use axelar_solana_gas_service::events::{
+    BalanceChangeReason, BalanceChangedEvent, GasCollectedEventV2,
+};
 use axelar_solana_gateway_test_fixtures::base::TestFixture;
 use event_cpi_test_utils::assert_event_cpi;
 use solana_program_test::{tokio, ProgramTest};
@@ -59,10 +61,21 @@ async fn test_receive_funds() {
         .unwrap();
     assert!(!inner_ixs.is_empty());
 
-    let expected_event = GasCollectedEvent {
+    let expected_event = GasCollectedEventV2 {
         receiver: receiver.pubkey(),
         amount: sol_amount,
         spl_token_account: None,
+        mint: None,
+        sequence: 0,
+    };
+
+    assert_event_cpi(&expected_event, &inner_ixs);
+
+    let expected_event = BalanceChangedEvent {
+        old_balance: config_pda_balance_before,
+        new_balance: config_pda_balance_before - sol_amount,
+        reason: BalanceChangeReason::FeesCollected,
+        sequence: 1,
     };
 
     assert_event_cpi(&expected_event, &inner_ixs);
@@ -0,0 +1,174 @@
+use axelar_solana_gas_service::events::DevnetFaucetEvent;
+use axelar_solana_gateway_test_fixtures::base::TestFixture;
+use event_cpi_test_utils::assert_event_cpi;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::inner_instruction::InnerInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+async fn run_faucet(
+    test_fixture: &mut TestFixture,
+    config_pda: &Pubkey,
+    operator: &Keypair,
+    ix: Instruction,
+) -> Vec<InnerInstruction> {
+    let payer = test_fixture.payer.insecure_clone();
+    let config_pda_balance_before = test_fixture
+        .try_get_account_no_checks(config_pda)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let inner_ixs = test_fixture
+        .simulate_tx_with_custom_signers(&[ix.clone()], &[&payer, operator])
+        .await
+        .unwrap()
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&payer, operator])
+        .await
+        .unwrap();
+
+    // The simulated amount doesn't come out of the collected fees
+    let config_pda_balance_after = test_fixture
+        .try_get_account_no_checks(config_pda)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(config_pda_balance_after, config_pda_balance_before);
+
+    inner_ixs
+}
+
+#[tokio::test]
+async fn test_devnet_faucet_simulates_refund() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    test_fixture
+        .fund_account(&gas_utils.operator.pubkey(), 1_000_000_000)
+        .await;
+
+    // Action
+    let receiver = Keypair::new();
+    let sol_amount = 1_000_000;
+    let message_id = "tx-sig-1.1".to_owned();
+    let ix = axelar_solana_gas_service::instructions::devnet_faucet_instruction(
+        &gas_utils.operator.pubkey(),
+        &receiver.pubkey(),
+        sol_amount,
+        Some(message_id.clone()),
+    )
+    .unwrap();
+
+    let inner_ixs = run_faucet(
+        &mut test_fixture,
+        &gas_utils.config_pda,
+        &gas_utils.operator,
+        ix,
+    )
+    .await;
+
+    // Assert
+    let expected_event = DevnetFaucetEvent {
+        receiver: receiver.pubkey(),
+        amount: sol_amount,
+        message_id: Some(message_id),
+        sequence: 0,
+    };
+    assert_event_cpi(&expected_event, &inner_ixs);
+
+    let receiver_balance = test_fixture
+        .try_get_account_no_checks(&receiver.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(receiver_balance, sol_amount);
+}
+
+#[tokio::test]
+async fn test_devnet_faucet_simulates_fee_collection() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    test_fixture
+        .fund_account(&gas_utils.operator.pubkey(), 1_000_000_000)
+        .await;
+
+    // Action
+    let receiver = Keypair::new();
+    let sol_amount = 1_000_000;
+    let ix = axelar_solana_gas_service::instructions::devnet_faucet_instruction(
+        &gas_utils.operator.pubkey(),
+        &receiver.pubkey(),
+        sol_amount,
+        None,
+    )
+    .unwrap();
+
+    let inner_ixs = run_faucet(
+        &mut test_fixture,
+        &gas_utils.config_pda,
+        &gas_utils.operator,
+        ix,
+    )
+    .await;
+
+    // Assert
+    let expected_event = DevnetFaucetEvent {
+        receiver: receiver.pubkey(),
+        amount: sol_amount,
+        message_id: None,
+        sequence: 0,
+    };
+    assert_event_cpi(&expected_event, &inner_ixs);
+}
+
+#[tokio::test]
+async fn test_only_operator_can_use_devnet_faucet() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    let impostor = Keypair::new();
+    test_fixture
+        .fund_account(&impostor.pubkey(), 1_000_000_000)
+        .await;
+
+    // Action
+    let receiver = Keypair::new();
+    let ix = axelar_solana_gas_service::instructions::devnet_faucet_instruction(
+        &impostor.pubkey(),
+        &receiver.pubkey(),
+        1_000_000,
+        Some("tx-sig-1.1".to_owned()),
+    )
+    .unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &impostor])
+        .await;
+
+    // Assert
+    assert!(res.is_err());
+    assert!(test_fixture
+        .try_get_account_no_checks(&receiver.pubkey())
+        .await
+        .unwrap()
+        .is_none());
+}
@@ -1,10 +1,11 @@
-use axelar_solana_gas_service::events::GasPaidEvent;
+use axelar_solana_gas_service::events::{BalanceChangeReason, BalanceChangedEvent, GasPaidEventV2};
 use axelar_solana_gateway_test_fixtures::base::TestFixture;
 use event_cpi_test_utils::assert_event_cpi;
 use solana_program_test::{tokio, ProgramTest};
 use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
 
 #[tokio::test]
+#[allow(clippy::too_many_lines)]
 async fn test_pay_native_for_contract_call() {
     // Setup
     let pt = ProgramTest::default();
@@ -71,7 +72,7 @@ async fn test_pay_native_for_contract_call() {
         .unwrap();
     assert!(!inner_ixs.is_empty());
 
-    let expected_event = GasPaidEvent {
+    let expected_event = GasPaidEventV2 {
         sender: payer.pubkey(),
         destination_chain: destination_chain.clone(),
         destination_address: destination_addr.clone(),
@@ -79,6 +80,19 @@ async fn test_pay_native_for_contract_call() {
         amount: gas_amount,
         refund_address,
         spl_token_account: None,
+        mint: None,
+        linked_call_contract_index: None,
+        destination_gas_amount: None,
+        sequence: 0,
+    };
+
+    assert_event_cpi(&expected_event, &inner_ixs);
+
+    let expected_event = BalanceChangedEvent {
+        old_balance: config_pda_balance_before,
+        new_balance: config_pda_balance_before + gas_amount,
+        reason: BalanceChangeReason::GasPaid,
+        sequence: 1,
     };
 
     assert_event_cpi(&expected_event, &inner_ixs);
@@ -0,0 +1,248 @@
+use axelar_solana_gas_service::error::GasServiceError;
+use axelar_solana_gas_service::events::{GasPaidEventV2, GasPriceFeedSetEvent};
+use axelar_solana_gas_service::instructions::{
+    pay_gas_in_destination_units_instruction, set_gas_price_feed_instruction,
+};
+use axelar_solana_gas_service::state::GasPriceFeed;
+use axelar_solana_gateway_test_fixtures::base::TestFixture;
+use event_cpi_test_utils::{assert_event_cpi, get_first_event_cpi_occurrence};
+use program_utils::pda::BytemuckedPda;
+use solana_program_test::{tokio, BanksTransactionResultWithMetadata, ProgramTest};
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+async fn set_gas_price_feed(
+    test_fixture: &mut TestFixture,
+    operator: &Keypair,
+    lamports: u64,
+    destination_units: u64,
+) -> Result<BanksTransactionResultWithMetadata, BanksTransactionResultWithMetadata> {
+    let ix = set_gas_price_feed_instruction(
+        &test_fixture.payer.pubkey(),
+        &operator.pubkey(),
+        "ethereum".to_owned(),
+        lamports,
+        destination_units,
+        0,
+    )
+    .unwrap();
+
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), operator])
+        .await
+}
+
+fn pay_gas(payer: &Keypair, destination_gas_amount: u64, max_amount: u64) -> Instruction {
+    pay_gas_in_destination_units_instruction(
+        &payer.pubkey(),
+        "ethereum".to_owned(),
+        "destination addr 123".to_owned(),
+        [42; 32],
+        Pubkey::new_unique(),
+        destination_gas_amount,
+        max_amount,
+    )
+    .unwrap()
+}
+
+fn custom_error(res: BanksTransactionResultWithMetadata) -> TransactionError {
+    res.result.unwrap_err()
+}
+
+#[tokio::test]
+async fn test_pay_gas_in_destination_units_converts_with_feed() {
+    // Setup: 3 lamports per 2 destination units
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let payer = Keypair::new();
+    test_fixture
+        .fund_account(&payer.pubkey(), 1_000_000_000)
+        .await;
+    let ix = set_gas_price_feed_instruction(
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        "ethereum".to_owned(),
+        3,
+        2,
+        0,
+    )
+    .unwrap();
+    let simulation_result = test_fixture
+        .simulate_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    assert_event_cpi(
+        &GasPriceFeedSetEvent {
+            destination_chain: "ethereum".to_owned(),
+            lamports: 3,
+            destination_units: 2,
+            payload_byte_units: 0,
+            sequence: 0,
+        },
+        &inner_ixs,
+    );
+    set_gas_price_feed(&mut test_fixture, &gas_utils.operator, 3, 2)
+        .await
+        .unwrap();
+
+    let (gas_price_feed_pda, _) = axelar_solana_gas_service::get_gas_price_feed_pda("ethereum");
+    let account = test_fixture
+        .try_get_account_no_checks(&gas_price_feed_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let gas_price_feed = GasPriceFeed::read(&account.data).unwrap();
+    assert_eq!(gas_price_feed.lamports, 3);
+    assert_eq!(gas_price_feed.destination_units, 2);
+
+    let config_balance_before = test_fixture
+        .try_get_account_no_checks(&gas_utils.config_pda)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    // Action: 1_000_001 destination units cost 1_500_001.5 lamports, rounded up
+    let ix = pay_gas(&payer, 1_000_001, 1_500_002);
+    let signers = [&test_fixture.payer.insecure_clone(), &payer];
+    let simulation_result = test_fixture
+        .simulate_tx_with_custom_signers(&[ix.clone()], &signers)
+        .await
+        .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &signers)
+        .await
+        .unwrap();
+
+    // Assert
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    let gas_paid = get_first_event_cpi_occurrence::<GasPaidEventV2>(&inner_ixs).unwrap();
+    assert_eq!(gas_paid.amount, 1_500_002);
+    assert_eq!(gas_paid.destination_gas_amount, Some(1_000_001));
+
+    let config_balance_after = test_fixture
+        .try_get_account_no_checks(&gas_utils.config_pda)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(config_balance_after - config_balance_before, 1_500_002);
+}
+
+#[tokio::test]
+async fn test_pay_gas_in_destination_units_respects_max_amount() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let payer = Keypair::new();
+    test_fixture
+        .fund_account(&payer.pubkey(), 1_000_000_000)
+        .await;
+    set_gas_price_feed(&mut test_fixture, &gas_utils.operator, 3, 2)
+        .await
+        .unwrap();
+
+    // Action
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[pay_gas(&payer, 1_000_000, 1_499_999)],
+            &[&test_fixture.payer.insecure_clone(), &payer],
+        )
+        .await;
+
+    // Assert
+    assert_eq!(
+        custom_error(res.unwrap_err()),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(GasServiceError::ConvertedGasExceedsMaximum as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_pay_gas_in_destination_units_requires_feed() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let payer = Keypair::new();
+    test_fixture
+        .fund_account(&payer.pubkey(), 1_000_000_000)
+        .await;
+
+    // Action
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[pay_gas(&payer, 1_000_000, u64::MAX)],
+            &[&test_fixture.payer.insecure_clone(), &payer],
+        )
+        .await;
+
+    // Assert
+    assert_eq!(
+        custom_error(res.unwrap_err()),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(GasServiceError::GasPriceFeedNotSet as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_only_operator_sets_valid_gas_price_feed() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let payer = Keypair::new();
+    test_fixture
+        .fund_account(&payer.pubkey(), 1_000_000_000)
+        .await;
+    let impostor = Keypair::new();
+
+    // Action
+    let unauthorized = set_gas_price_feed(&mut test_fixture, &impostor, 3, 2).await;
+    let zero_rate = set_gas_price_feed(&mut test_fixture, &gas_utils.operator, 3, 0).await;
+
+    // Assert
+    assert!(unauthorized.is_err());
+    assert_eq!(
+        custom_error(zero_rate.unwrap_err()),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(GasServiceError::InvalidConversionRate as u32)
+        )
+    );
+}
@@ -0,0 +1,158 @@
+use axelar_solana_gas_service::error::GasServiceError;
+use axelar_solana_gas_service::events::GasPaidEventV2;
+use axelar_solana_gas_service::instructions::{
+    pay_gas_with_quote_instruction, set_gas_price_feed_instruction,
+};
+use axelar_solana_gateway_test_fixtures::base::TestFixture;
+use event_cpi_test_utils::get_first_event_cpi_occurrence;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+const PAYLOAD_SIZE: u32 = 100;
+const EXECUTION_LIMIT: u64 = 1_000_000;
+// 100 payload bytes at 10 units each, plus the execution limit
+const DESTINATION_UNITS: u64 = 1_001_000;
+// 3 lamports per 2 destination units
+const QUOTED_FEE: u64 = 1_501_500;
+
+fn pay_gas(sender: &Keypair, max_amount: u64) -> Instruction {
+    pay_gas_with_quote_instruction(
+        &sender.pubkey(),
+        "ethereum".to_owned(),
+        "destination addr 123".to_owned(),
+        [42; 32],
+        Pubkey::new_unique(),
+        PAYLOAD_SIZE,
+        EXECUTION_LIMIT,
+        max_amount,
+    )
+    .unwrap()
+}
+
+async fn balance(test_fixture: &mut TestFixture, account: &Pubkey) -> u64 {
+    test_fixture
+        .try_get_account_no_checks(account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports
+}
+
+#[tokio::test]
+async fn test_pay_gas_with_quote_charges_only_the_quoted_fee() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let ix = set_gas_price_feed_instruction(
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        "ethereum".to_owned(),
+        3,
+        2,
+        10,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    let sender = Keypair::new();
+    test_fixture
+        .fund_account(&sender.pubkey(), 1_000_000_000)
+        .await;
+    let config_balance_before = balance(&mut test_fixture, &gas_utils.config_pda).await;
+    let sender_balance_before = balance(&mut test_fixture, &sender.pubkey()).await;
+
+    // Action: the sender accepts to pay up to twice the quote
+    let ix = pay_gas(&sender, QUOTED_FEE * 2);
+    let signers = [&test_fixture.payer.insecure_clone(), &sender];
+    let simulation_result = test_fixture
+        .simulate_tx_with_custom_signers(&[ix.clone()], &signers)
+        .await
+        .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &signers)
+        .await
+        .unwrap();
+
+    // Assert
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    let gas_paid = get_first_event_cpi_occurrence::<GasPaidEventV2>(&inner_ixs).unwrap();
+    assert_eq!(gas_paid.amount, QUOTED_FEE);
+    assert_eq!(gas_paid.destination_gas_amount, Some(DESTINATION_UNITS));
+
+    assert_eq!(
+        balance(&mut test_fixture, &gas_utils.config_pda).await - config_balance_before,
+        QUOTED_FEE
+    );
+    assert_eq!(
+        sender_balance_before - balance(&mut test_fixture, &sender.pubkey()).await,
+        QUOTED_FEE
+    );
+}
+
+#[tokio::test]
+async fn test_pay_gas_with_quote_respects_max_amount() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let ix = set_gas_price_feed_instruction(
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        "ethereum".to_owned(),
+        3,
+        2,
+        10,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    let sender = Keypair::new();
+    test_fixture
+        .fund_account(&sender.pubkey(), 1_000_000_000)
+        .await;
+
+    // Action
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[pay_gas(&sender, QUOTED_FEE - 1)],
+            &[&test_fixture.payer.insecure_clone(), &sender],
+        )
+        .await;
+
+    // Assert
+    assert_eq!(
+        res.unwrap_err().result.unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(GasServiceError::ConvertedGasExceedsMaximum as u32)
+        )
+    );
+}
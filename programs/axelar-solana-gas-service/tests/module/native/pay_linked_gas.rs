@@ -0,0 +1,161 @@
+use axelar_solana_gas_service::error::GasServiceError;
+use axelar_solana_gas_service::events::GasPaidEventV2;
+use axelar_solana_gas_service::instructions::pay_linked_gas_instruction;
+use axelar_solana_gateway_test_fixtures::gateway::SolanaAxelarIntegration;
+use axelar_solana_gateway_test_fixtures::SolanaAxelarIntegrationMetadata;
+use event_cpi_test_utils::assert_event_cpi;
+use solana_program_test::tokio;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::keccak;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+fn call_contract_ix(
+    metadata: &SolanaAxelarIntegrationMetadata,
+    sender: &Keypair,
+    payload: &[u8],
+) -> Instruction {
+    axelar_solana_gateway::instructions::call_contract(
+        axelar_solana_gateway::id(),
+        metadata.gateway_root_pda,
+        sender.pubkey(),
+        None,
+        "ethereum".to_owned(),
+        "destination addr 123".to_owned(),
+        payload.to_vec(),
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_pay_linked_gas_records_the_call_contract_index() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42])
+        .build()
+        .setup()
+        .await;
+    let gas_utils = metadata.deploy_gas_service().await;
+    metadata.init_gas_config(&gas_utils).await.unwrap();
+
+    let sender = Keypair::new();
+    metadata.fund_account(&sender.pubkey(), 1_000_000_000).await;
+    let payload = b"linked payload".to_vec();
+    let payload_hash = keccak::hash(&payload).to_bytes();
+    let refund_address = Pubkey::new_unique();
+
+    // Action
+    let ixs = [
+        call_contract_ix(&metadata, &sender, &payload),
+        pay_linked_gas_instruction(
+            &sender.pubkey(),
+            "ethereum".to_owned(),
+            "destination addr 123".to_owned(),
+            payload_hash,
+            refund_address,
+            1_000_000,
+        )
+        .unwrap(),
+    ];
+    let payer = metadata.payer.insecure_clone();
+    let signers = [&payer, &sender];
+    let simulation_result = metadata
+        .simulate_tx_with_custom_signers(&ixs, &signers)
+        .await
+        .unwrap();
+
+    // Assert
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .get(1)
+        .cloned()
+        .unwrap();
+    assert_event_cpi(
+        &GasPaidEventV2 {
+            sender: sender.pubkey(),
+            destination_chain: "ethereum".to_owned(),
+            destination_address: "destination addr 123".to_owned(),
+            payload_hash,
+            amount: 1_000_000,
+            refund_address,
+            spl_token_account: None,
+            mint: None,
+            linked_call_contract_index: Some(0),
+            destination_gas_amount: None,
+            sequence: 0,
+        },
+        &inner_ixs,
+    );
+
+    metadata
+        .send_tx_with_custom_signers(&ixs, &signers)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_pay_linked_gas_fails_without_matching_call_contract() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42])
+        .build()
+        .setup()
+        .await;
+    let gas_utils = metadata.deploy_gas_service().await;
+    metadata.init_gas_config(&gas_utils).await.unwrap();
+
+    let sender = Keypair::new();
+    metadata.fund_account(&sender.pubkey(), 1_000_000_000).await;
+    let payload = b"linked payload".to_vec();
+    let pay_ix = |payload_hash: [u8; 32], destination_chain: &str| {
+        pay_linked_gas_instruction(
+            &sender.pubkey(),
+            destination_chain.to_owned(),
+            "destination addr 123".to_owned(),
+            payload_hash,
+            Pubkey::new_unique(),
+            1_000_000,
+        )
+        .unwrap()
+    };
+
+    let mismatches = [
+        // No gateway call at all
+        vec![pay_ix(keccak::hash(&payload).to_bytes(), "ethereum")],
+        // Payload hash mismatch
+        vec![
+            call_contract_ix(&metadata, &sender, &payload),
+            pay_ix([42; 32], "ethereum"),
+        ],
+        // Destination mismatch
+        vec![
+            call_contract_ix(&metadata, &sender, &payload),
+            pay_ix(keccak::hash(&payload).to_bytes(), "avalanche"),
+        ],
+    ];
+
+    let payer = metadata.payer.insecure_clone();
+    for ixs in mismatches {
+        // Action
+        let tx = metadata
+            .send_tx_with_custom_signers(&ixs, &[&payer, &sender])
+            .await
+            .unwrap_err();
+
+        // Assert
+        let Err(TransactionError::InstructionError(_index, InstructionError::Custom(error_code))) =
+            tx.result
+        else {
+            panic!("unexpected error")
+        };
+        assert_eq!(
+            error_code,
+            GasServiceError::LinkedContractCallNotFound as u32
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use axelar_solana_gas_service::error::GasServiceError;
+use axelar_solana_gas_service::events::{
+    GasPaidEventV2, PayloadHashPreRegisteredEvent, PreRegisteredGasPaidEvent,
+};
+use axelar_solana_gas_service::state::{PayloadPreRegistration, MAX_PRE_REGISTRATION_SLOTS};
+use axelar_solana_gateway_test_fixtures::base::TestFixture;
+use event_cpi_test_utils::assert_event_cpi;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::clock::Clock;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+const DESTINATION_CHAIN: &str = "ethereum";
+const DESTINATION_ADDRESS: &str = "0x036d3f1d1B1b3e4E5F6a7B8c9D0e1F2a3B4c5D6E";
+const PAYLOAD_HASH: [u8; 32] = [42; 32];
+
+fn pre_register_ix(sender: &Keypair, valid_for_slots: u64) -> Instruction {
+    axelar_solana_gas_service::instructions::pre_register_payload_hash_instruction(
+        &sender.pubkey(),
+        DESTINATION_CHAIN.to_owned(),
+        DESTINATION_ADDRESS.to_owned(),
+        PAYLOAD_HASH,
+        valid_for_slots,
+    )
+    .unwrap()
+}
+
+fn pay_ix(sender: &Keypair, amount: u64) -> Instruction {
+    axelar_solana_gas_service::instructions::pay_pre_registered_gas_instruction(
+        &sender.pubkey(),
+        DESTINATION_CHAIN.to_owned(),
+        DESTINATION_ADDRESS.to_owned(),
+        PAYLOAD_HASH,
+        sender.pubkey(),
+        amount,
+    )
+    .unwrap()
+}
+
+fn pre_registration_pda(sender: &Keypair) -> Pubkey {
+    axelar_solana_gas_service::get_payload_pre_registration_pda(
+        &sender.pubkey(),
+        &PayloadPreRegistration::call_hash(DESTINATION_CHAIN, DESTINATION_ADDRESS, &PAYLOAD_HASH),
+    )
+    .0
+}
+
+#[tokio::test]
+async fn test_pay_pre_registered_gas() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let sender = Keypair::new();
+    test_fixture
+        .fund_account(&sender.pubkey(), 1_000_000_000)
+        .await;
+    let tx_payer = test_fixture.payer.insecure_clone();
+    let pre_registration = pre_registration_pda(&sender);
+    let slot = test_fixture.get_sysvar::<Clock>().await.slot;
+
+    // Action: pre-register the payload hash
+    let ix = pre_register_ix(&sender, 100);
+    let simulation_result = test_fixture
+        .simulate_tx_with_custom_signers(&[ix.clone()], &[&tx_payer, &sender])
+        .await
+        .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&tx_payer, &sender])
+        .await
+        .unwrap();
+
+    // Assert
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    assert_event_cpi(
+        &PayloadHashPreRegisteredEvent {
+            sender: sender.pubkey(),
+            destination_chain: DESTINATION_CHAIN.to_owned(),
+            destination_address: DESTINATION_ADDRESS.to_owned(),
+            payload_hash: PAYLOAD_HASH,
+            pre_registration,
+            expires_at_slot: slot + 100,
+            sequence: 0,
+        },
+        &inner_ixs,
+    );
+    assert!(test_fixture
+        .try_get_account_no_checks(&pre_registration)
+        .await
+        .unwrap()
+        .is_some());
+
+    // Action: pay the gas of the pre-registered call
+    let gas_amount = 1_000_000;
+    let ix = pay_ix(&sender, gas_amount);
+    let simulation_result = test_fixture
+        .simulate_tx_with_custom_signers(&[ix.clone()], &[&tx_payer, &sender])
+        .await
+        .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&tx_payer, &sender])
+        .await
+        .unwrap();
+
+    // Assert
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    assert_event_cpi(
+        &GasPaidEventV2 {
+            sender: sender.pubkey(),
+            destination_chain: DESTINATION_CHAIN.to_owned(),
+            destination_address: DESTINATION_ADDRESS.to_owned(),
+            payload_hash: PAYLOAD_HASH,
+            amount: gas_amount,
+            refund_address: sender.pubkey(),
+            spl_token_account: None,
+            mint: None,
+            linked_call_contract_index: None,
+            destination_gas_amount: None,
+            sequence: 1,
+        },
+        &inner_ixs,
+    );
+    assert_event_cpi(
+        &PreRegisteredGasPaidEvent {
+            sender: sender.pubkey(),
+            payload_hash: PAYLOAD_HASH,
+            pre_registration,
+            sequence: 2,
+        },
+        &inner_ixs,
+    );
+    assert!(test_fixture
+        .try_get_account_no_checks(&pre_registration)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_pay_pre_registered_gas_fails_after_expiry() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let sender = Keypair::new();
+    test_fixture
+        .fund_account(&sender.pubkey(), 1_000_000_000)
+        .await;
+    let tx_payer = test_fixture.payer.insecure_clone();
+    test_fixture
+        .send_tx_with_custom_signers(&[pre_register_ix(&sender, 1)], &[&tx_payer, &sender])
+        .await
+        .unwrap();
+    let slot = test_fixture.get_sysvar::<Clock>().await.slot;
+    test_fixture.warp_to_slot(slot + 10);
+
+    // Action
+    let res = test_fixture
+        .send_tx_with_custom_signers(&[pay_ix(&sender, 1_000_000)], &[&tx_payer, &sender])
+        .await;
+
+    // Assert
+    assert_eq!(
+        res.unwrap_err().result.unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(GasServiceError::PayloadPreRegistrationExpired as u32)
+        )
+    );
+    assert!(test_fixture
+        .try_get_account_no_checks(&pre_registration_pda(&sender))
+        .await
+        .unwrap()
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_pay_pre_registered_gas_requires_pre_registration() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let sender = Keypair::new();
+    test_fixture
+        .fund_account(&sender.pubkey(), 1_000_000_000)
+        .await;
+    let tx_payer = test_fixture.payer.insecure_clone();
+
+    // Action
+    let res = test_fixture
+        .send_tx_with_custom_signers(&[pay_ix(&sender, 1_000_000)], &[&tx_payer, &sender])
+        .await;
+
+    // Assert
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_pre_registration_window_is_bounded() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let sender = Keypair::new();
+    test_fixture
+        .fund_account(&sender.pubkey(), 1_000_000_000)
+        .await;
+    let tx_payer = test_fixture.payer.insecure_clone();
+
+    for valid_for_slots in [0, MAX_PRE_REGISTRATION_SLOTS + 1] {
+        // Action
+        let res = test_fixture
+            .send_tx_with_custom_signers(
+                &[pre_register_ix(&sender, valid_for_slots)],
+                &[&tx_payer, &sender],
+            )
+            .await;
+
+        // Assert
+        assert_eq!(
+            res.unwrap_err().result.unwrap_err(),
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(GasServiceError::InvalidPreRegistrationWindow as u32)
+            )
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use axelar_solana_gas_service::error::GasServiceError;
+use axelar_solana_gas_service::instructions::{
+    quote_fee_instruction, set_chain_paused_instruction, set_gas_price_feed_instruction,
+};
+use axelar_solana_gateway_test_fixtures::base::TestFixture;
+use axelar_solana_gateway_test_fixtures::gas_service::GasServiceUtils;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+async fn set_gas_price_feed(
+    test_fixture: &mut TestFixture,
+    gas_utils: &GasServiceUtils,
+    payload_byte_units: u32,
+) {
+    // 3 lamports per 2 destination units
+    let ix = set_gas_price_feed_instruction(
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        "ethereum".to_owned(),
+        3,
+        2,
+        payload_byte_units,
+    )
+    .unwrap();
+
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_quote_fee_returns_lamports_in_return_data() {
+    // Setup: 16 destination units per payload byte
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    set_gas_price_feed(&mut test_fixture, &gas_utils, 16).await;
+
+    // Action: 100 bytes and 100_001 units cost (1_600 + 100_001) * 3 / 2
+    // lamports, rounded up
+    let ix = quote_fee_instruction("ethereum".to_owned(), 100, 100_001).unwrap();
+    let simulation_result = test_fixture.simulate_tx(&[ix]).await.unwrap();
+
+    // Assert
+    let return_data = simulation_result
+        .simulation_details
+        .unwrap()
+        .return_data
+        .unwrap();
+    assert_eq!(return_data.program_id, axelar_solana_gas_service::ID);
+    #[allow(clippy::little_endian_bytes)]
+    let fee = u64::from_le_bytes(return_data.data.try_into().unwrap());
+    assert_eq!(fee, 152_402);
+}
+
+#[tokio::test]
+async fn test_quote_fee_requires_feed_and_unpaused_chain() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    let ix = quote_fee_instruction("ethereum".to_owned(), 100, 100_000).unwrap();
+
+    // Action: no feed
+    let no_feed = test_fixture.simulate_tx(&[ix.clone()]).await.unwrap();
+
+    // Assert
+    assert_eq!(
+        no_feed.result.unwrap().unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(GasServiceError::GasPriceFeedNotSet as u32)
+        )
+    );
+
+    // Action: paused chain
+    set_gas_price_feed(&mut test_fixture, &gas_utils, 0).await;
+    let pause = set_chain_paused_instruction(
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        "ethereum".to_owned(),
+        true,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[pause],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+    let paused = test_fixture.simulate_tx(&[ix]).await.unwrap();
+
+    // Assert
+    assert!(paused.result.unwrap().is_err());
+}
@@ -1,9 +1,47 @@
-use axelar_solana_gas_service::events::GasRefundedEvent;
+use axelar_solana_gas_service::error::GasServiceError;
+use axelar_solana_gas_service::events::{
+    BalanceChangeReason, BalanceChangedEvent, GasRefundedEventV2,
+};
+use axelar_solana_gas_service::instructions::{
+    pay_gas_with_receipt_instruction, refund_gas_receipt_instruction, set_refund_window_instruction,
+};
+use axelar_solana_gas_service::state::{GasReceipt, RefundPolicy};
 use axelar_solana_gateway_test_fixtures::{assert_msg_present_in_logs, base::TestFixture};
 use event_cpi_test_utils::assert_event_cpi;
+use program_utils::pda::BytemuckedPda;
 use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::TransactionError;
 use solana_sdk::{signature::Keypair, signer::Signer};
 
+/// Pays `amount` lamports of gas for `message_id` with a receipt refunded to
+/// `refund_address`, returning the gas receipt PDA.
+async fn pay_with_receipt(
+    test_fixture: &mut TestFixture,
+    message_id: &str,
+    refund_address: Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let sender = Keypair::new();
+    test_fixture
+        .fund_account(&sender.pubkey(), 1_000_000_000)
+        .await;
+    let ix = pay_gas_with_receipt_instruction(
+        &sender.pubkey(),
+        message_id.to_owned(),
+        refund_address,
+        amount,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &sender])
+        .await
+        .unwrap();
+
+    axelar_solana_gas_service::get_gas_receipt_pda(message_id).0
+}
+
 #[tokio::test]
 async fn test_refund_native() {
     // Setup
@@ -12,11 +50,18 @@ async fn test_refund_native() {
     let gas_utils = test_fixture.deploy_gas_service().await;
     test_fixture.init_gas_config(&gas_utils).await.unwrap();
 
-    // Record balances before the transaction
-    test_fixture
-        .fund_account(&gas_utils.config_pda, 1_000_000_000)
-        .await;
     let refunded_user = Keypair::new();
+    let gas_amount = 1_000_000;
+    let message_id = "tx-sig-2.1".to_owned();
+    let gas_receipt_pda = pay_with_receipt(
+        &mut test_fixture,
+        &message_id,
+        refunded_user.pubkey(),
+        2 * gas_amount,
+    )
+    .await;
+
+    // Record balances before the transaction
     let refunder_balance_before = 0;
     let config_pda_balance_before = test_fixture
         .try_get_account_no_checks(&gas_utils.config_pda)
@@ -26,8 +71,6 @@ async fn test_refund_native() {
         .lamports;
 
     // Action
-    let gas_amount = 1_000_000;
-    let message_id = "tx-sig-2.1".to_owned();
     let ix = axelar_solana_gas_service::instructions::refund_fees_instruction(
         &gas_utils.operator.pubkey(),
         &refunded_user.pubkey(),
@@ -61,11 +104,21 @@ async fn test_refund_native() {
         .unwrap();
     assert!(!inner_ixs.is_empty());
 
-    let expected_event = GasRefundedEvent {
+    let expected_event = GasRefundedEventV2 {
         receiver: refunded_user.pubkey(),
         message_id,
         amount: gas_amount,
         spl_token_account: None,
+        sequence: 2,
+    };
+
+    assert_event_cpi(&expected_event, &inner_ixs);
+
+    let expected_event = BalanceChangedEvent {
+        old_balance: config_pda_balance_before,
+        new_balance: config_pda_balance_before - gas_amount,
+        reason: BalanceChangeReason::FeesRefunded,
+        sequence: 3,
     };
 
     assert_event_cpi(&expected_event, &inner_ixs);
@@ -103,6 +156,14 @@ async fn test_refund_native() {
         config_pda_balance_before - gas_amount
     );
     assert_eq!(refunder_balance_after, refunder_balance_before + gas_amount);
+
+    // Half of the gas is left in the receipt
+    let account = test_fixture
+        .try_get_account_no_checks(&gas_receipt_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(GasReceipt::read(&account.data).unwrap().amount, gas_amount);
 }
 
 #[tokio::test]
@@ -182,3 +243,217 @@ async fn test_refund_native_fails_with_zero_fee() {
     assert!(res.is_err());
     assert_msg_present_in_logs(res.unwrap_err(), "Gas fee amount cannot be zero");
 }
+
+fn custom_error(err: &TransactionError) -> u32 {
+    let TransactionError::InstructionError(_index, InstructionError::Custom(error_code)) = err
+    else {
+        panic!("unexpected error: {err:?}")
+    };
+    *error_code
+}
+
+#[tokio::test]
+#[allow(clippy::too_many_lines)]
+async fn test_refund_gas_receipt_enforces_refund_window() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    let payer = test_fixture.payer.insecure_clone();
+
+    let now = 1_700_000_000;
+    let max_refund_age = 3_600;
+    test_fixture.set_time(now).await;
+
+    let ix = set_refund_window_instruction(
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        max_refund_age,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&payer, &gas_utils.operator])
+        .await
+        .unwrap();
+
+    let (refund_policy_pda, _) = axelar_solana_gas_service::get_refund_policy_pda();
+    let account = test_fixture
+        .try_get_account_no_checks(&refund_policy_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let refund_policy = RefundPolicy::read(&account.data).unwrap();
+    assert_eq!(refund_policy.max_refund_age, max_refund_age);
+
+    let sender = Keypair::new();
+    test_fixture
+        .fund_account(&sender.pubkey(), 1_000_000_000)
+        .await;
+    let refund_address = Keypair::new().pubkey();
+    let message_id = "tx-sig-2.1";
+    let pay = |amount: u64| {
+        pay_gas_with_receipt_instruction(
+            &sender.pubkey(),
+            message_id.to_owned(),
+            refund_address,
+            amount,
+        )
+        .unwrap()
+    };
+    let (gas_receipt_pda, _) = axelar_solana_gas_service::get_gas_receipt_pda(message_id);
+    let refund = |receiver: &Pubkey, amount: u64| {
+        refund_gas_receipt_instruction(
+            &gas_utils.operator.pubkey(),
+            receiver,
+            message_id.to_owned(),
+            amount,
+        )
+        .unwrap()
+    };
+    let gas_amount = 1_000_000;
+    test_fixture
+        .send_tx_with_custom_signers(&[pay(gas_amount)], &[&payer, &sender])
+        .await
+        .unwrap();
+
+    // Action & Assert: the refund goes to the refund address of the receipt
+    let err = test_fixture
+        .send_tx_with_custom_signers(
+            &[refund(&Keypair::new().pubkey(), gas_amount)],
+            &[&payer, &gas_utils.operator],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        custom_error(&err.result.unwrap_err()),
+        GasServiceError::RefundAddressMismatch as u32
+    );
+
+    // Only the gas left in the receipt is refunded
+    let err = test_fixture
+        .send_tx_with_custom_signers(
+            &[refund(&refund_address, gas_amount + 1)],
+            &[&payer, &gas_utils.operator],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        custom_error(&err.result.unwrap_err()),
+        GasServiceError::RefundExceedsReceipt as u32
+    );
+
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[refund(&refund_address, 400_000)],
+            &[&payer, &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+    let account = test_fixture
+        .try_get_account_no_checks(&gas_receipt_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(GasReceipt::read(&account.data).unwrap().amount, 600_000);
+
+    // The window runs from the payment time recorded in the receipt
+    test_fixture.set_time(now + max_refund_age + 1).await;
+    let err = test_fixture
+        .send_tx_with_custom_signers(
+            &[refund(&refund_address, 600_000)],
+            &[&payer, &gas_utils.operator],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        custom_error(&err.result.unwrap_err()),
+        GasServiceError::RefundWindowExpired as u32
+    );
+    let ix = axelar_solana_gas_service::instructions::refund_fees_instruction(
+        &gas_utils.operator.pubkey(),
+        &refund_address,
+        message_id.to_owned(),
+        600_000,
+    )
+    .unwrap();
+    let err = test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&payer, &gas_utils.operator])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        custom_error(&err.result.unwrap_err()),
+        GasServiceError::RefundWindowExpired as u32
+    );
+
+    // Topping up the receipt doesn't restart the window
+    test_fixture
+        .send_tx_with_custom_signers(&[pay(100_000)], &[&payer, &sender])
+        .await
+        .unwrap();
+    let account = test_fixture
+        .try_get_account_no_checks(&gas_receipt_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let receipt = GasReceipt::read(&account.data).unwrap();
+    assert_eq!(receipt.amount, 700_000);
+    assert_eq!(receipt.paid_at, now);
+    let err = test_fixture
+        .send_tx_with_custom_signers(
+            &[refund(&refund_address, 700_000)],
+            &[&payer, &gas_utils.operator],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        custom_error(&err.result.unwrap_err()),
+        GasServiceError::RefundWindowExpired as u32
+    );
+}
+
+#[tokio::test]
+async fn test_fully_refunded_gas_receipt_is_closed() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let refund_address = Keypair::new().pubkey();
+    let message_id = "tx-sig-2.1";
+    let gas_amount = 1_000_000;
+    let gas_receipt_pda =
+        pay_with_receipt(&mut test_fixture, message_id, refund_address, gas_amount).await;
+
+    // Action
+    let ix = refund_gas_receipt_instruction(
+        &gas_utils.operator.pubkey(),
+        &refund_address,
+        message_id.to_owned(),
+        gas_amount,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    // Assert: the receipt rent goes to the refund address as well
+    let receipt_account = test_fixture
+        .try_get_account_no_checks(&gas_receipt_pda)
+        .await
+        .unwrap()
+        .filter(|account| account.owner == axelar_solana_gas_service::ID);
+    assert!(receipt_account.is_none());
+    let refunded_balance = test_fixture
+        .try_get_account_no_checks(&refund_address)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert!(refunded_balance > gas_amount);
+}
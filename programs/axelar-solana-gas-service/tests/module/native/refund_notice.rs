@@ -0,0 +1,572 @@
+use axelar_solana_encoding::types::messages::{CrossChainId, Message};
+use axelar_solana_gas_service::error::GasServiceError;
+use axelar_solana_gas_service::events::{
+    BalanceChangeReason, BalanceChangedEvent, GasReceiptClosedEvent, GasRefundedEventV2,
+};
+use axelar_solana_gas_service::instructions::{
+    close_gas_receipt_instruction, mark_gas_receipt_delivered_instruction,
+    pay_gas_with_receipt_instruction, process_refund_notice_instruction,
+    set_refund_notice_source_instruction,
+};
+use axelar_solana_gas_service::state::{
+    GasReceipt, GasReceiptStatus, RefundNotice, RefundNoticeSource,
+};
+use axelar_solana_gateway_test_fixtures::gateway::SolanaAxelarIntegration;
+use axelar_solana_gateway_test_fixtures::SolanaAxelarIntegrationMetadata;
+use event_cpi_test_utils::assert_event_cpi;
+use program_utils::pda::BytemuckedPda;
+use solana_program_test::tokio;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::keccak;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+const HUB_CHAIN: &str = "axelar";
+const HUB_ADDRESS: &str = "axelar1hubaddress";
+
+struct Setup {
+    metadata: SolanaAxelarIntegrationMetadata,
+    operator: Keypair,
+    sender: Keypair,
+}
+
+async fn set_refund_notice_source(setup: &mut Setup, source_chain: &str, source_address: &str) {
+    let ix = set_refund_notice_source_instruction(
+        &setup.metadata.payer.pubkey(),
+        &setup.operator.pubkey(),
+        source_chain.to_owned(),
+        source_address.to_owned(),
+    )
+    .unwrap();
+    let payer = setup.metadata.payer.insecure_clone();
+    setup
+        .metadata
+        .send_tx_with_custom_signers(&[ix], &[&payer, &setup.operator])
+        .await
+        .unwrap();
+}
+
+async fn pay_gas_with_receipt(
+    setup: &mut Setup,
+    notice: &RefundNotice,
+    refund_address: Pubkey,
+    amount: u64,
+) -> Result<(), TransactionError> {
+    let ix = pay_gas_with_receipt_instruction(
+        &setup.sender.pubkey(),
+        notice.message_id.clone(),
+        refund_address,
+        amount,
+    )
+    .unwrap();
+    let payer = setup.metadata.payer.insecure_clone();
+    setup
+        .metadata
+        .send_tx_with_custom_signers(&[ix], &[&payer, &setup.sender])
+        .await
+        .map(|_| ())
+        .map_err(|err| err.result.unwrap_err())
+}
+
+async fn gas_receipt(setup: &mut Setup, notice: &RefundNotice) -> Option<GasReceipt> {
+    let (gas_receipt_pda, _) = axelar_solana_gas_service::get_gas_receipt_pda(&notice.message_id);
+    setup
+        .metadata
+        .try_get_account_no_checks(&gas_receipt_pda)
+        .await
+        .unwrap()
+        .filter(|account| account.owner == axelar_solana_gas_service::ID)
+        .map(|account| *GasReceipt::read(&account.data).unwrap())
+}
+
+/// Approves a refund notice sent by `source_address` on `source_chain` at the
+/// gateway and stores its payload.
+async fn approve_refund_notice(
+    setup: &mut Setup,
+    source_chain: &str,
+    source_address: &str,
+    notice: &RefundNotice,
+) -> Message {
+    let payload = borsh::to_vec(notice).unwrap();
+    let message = Message {
+        cc_id: CrossChainId {
+            chain: source_chain.to_owned(),
+            id: notice.message_id.clone(),
+        },
+        source_address: source_address.to_owned(),
+        destination_chain: "solana".to_owned(),
+        destination_address: axelar_solana_gas_service::ID.to_string(),
+        payload_hash: keccak::hash(&payload).to_bytes(),
+    };
+
+    let signers = setup.metadata.signers.clone();
+    setup
+        .metadata
+        .sign_session_and_approve_messages(&signers, &[message.clone()])
+        .await
+        .unwrap();
+    setup
+        .metadata
+        .upload_message_payload(&message, &payload)
+        .await
+        .unwrap();
+
+    message
+}
+
+fn sample_notice(message_id: &str) -> RefundNotice {
+    RefundNotice {
+        message_id: message_id.to_owned(),
+    }
+}
+
+fn custom_error(err: &TransactionError) -> u32 {
+    let TransactionError::InstructionError(_index, InstructionError::Custom(error_code)) = err
+    else {
+        panic!("unexpected error: {err:?}")
+    };
+    *error_code
+}
+
+#[tokio::test]
+async fn test_pay_gas_with_receipt_accumulates_payments() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42])
+        .build()
+        .setup()
+        .await;
+    let gas_utils = metadata.deploy_gas_service().await;
+    metadata.init_gas_config(&gas_utils).await.unwrap();
+    let sender = Keypair::new();
+    metadata.fund_account(&sender.pubkey(), 1_000_000_000).await;
+    let mut setup = Setup {
+        metadata,
+        operator: gas_utils.operator,
+        sender,
+    };
+    let notice = sample_notice("tx-sig-1.1");
+    let refund_address = Pubkey::new_unique();
+
+    // Action
+    pay_gas_with_receipt(&mut setup, &notice, refund_address, 1_000)
+        .await
+        .unwrap();
+    pay_gas_with_receipt(&mut setup, &notice, refund_address, 500)
+        .await
+        .unwrap();
+
+    // Assert
+    let receipt = gas_receipt(&mut setup, &notice).await.unwrap();
+    assert_eq!(receipt.sender, setup.sender.pubkey());
+    assert_eq!(receipt.refund_address, refund_address);
+    assert_eq!(receipt.amount, 1_500);
+    assert_eq!(receipt.status(), GasReceiptStatus::Pending);
+}
+
+#[tokio::test]
+async fn test_pay_gas_with_receipt_rejects_another_refund_address() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42])
+        .build()
+        .setup()
+        .await;
+    let gas_utils = metadata.deploy_gas_service().await;
+    metadata.init_gas_config(&gas_utils).await.unwrap();
+    let sender = Keypair::new();
+    metadata.fund_account(&sender.pubkey(), 1_000_000_000).await;
+    let mut setup = Setup {
+        metadata,
+        operator: gas_utils.operator,
+        sender,
+    };
+    let notice = sample_notice("tx-sig-1.1");
+    pay_gas_with_receipt(&mut setup, &notice, Pubkey::new_unique(), 1_000)
+        .await
+        .unwrap();
+
+    // Action
+    let err = pay_gas_with_receipt(&mut setup, &notice, Pubkey::new_unique(), 1_000)
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert_eq!(
+        custom_error(&err),
+        GasServiceError::RefundAddressMismatch as u32
+    );
+    let receipt = gas_receipt(&mut setup, &notice).await.unwrap();
+    assert_eq!(receipt.amount, 1_000);
+}
+
+#[tokio::test]
+async fn test_pay_gas_with_receipt_keeps_a_receipt_per_message() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42])
+        .build()
+        .setup()
+        .await;
+    let gas_utils = metadata.deploy_gas_service().await;
+    metadata.init_gas_config(&gas_utils).await.unwrap();
+    let sender = Keypair::new();
+    metadata.fund_account(&sender.pubkey(), 1_000_000_000).await;
+    let mut setup = Setup {
+        metadata,
+        operator: gas_utils.operator,
+        sender,
+    };
+    let notice = sample_notice("tx-sig-1.1");
+    let refund_address = Pubkey::new_unique();
+    pay_gas_with_receipt(&mut setup, &notice, refund_address, 1_000)
+        .await
+        .unwrap();
+
+    // Action: the same contract call is made again in another message
+    let other_notice = sample_notice("tx-sig-1.2");
+    let other_refund_address = Pubkey::new_unique();
+    pay_gas_with_receipt(&mut setup, &other_notice, other_refund_address, 500)
+        .await
+        .unwrap();
+
+    // Assert
+    let receipt = gas_receipt(&mut setup, &notice).await.unwrap();
+    assert_eq!(receipt.refund_address, refund_address);
+    assert_eq!(receipt.amount, 1_000);
+    let other_receipt = gas_receipt(&mut setup, &other_notice).await.unwrap();
+    assert_eq!(other_receipt.refund_address, other_refund_address);
+    assert_eq!(other_receipt.amount, 500);
+}
+
+#[tokio::test]
+#[allow(clippy::too_many_lines)]
+async fn test_close_gas_receipt() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42])
+        .build()
+        .setup()
+        .await;
+    let gas_utils = metadata.deploy_gas_service().await;
+    metadata.init_gas_config(&gas_utils).await.unwrap();
+    let sender = Keypair::new();
+    metadata.fund_account(&sender.pubkey(), 1_000_000_000).await;
+    let mut setup = Setup {
+        metadata,
+        operator: gas_utils.operator,
+        sender,
+    };
+    let notice = sample_notice("tx-sig-1.1");
+    pay_gas_with_receipt(&mut setup, &notice, Pubkey::new_unique(), 1_000)
+        .await
+        .unwrap();
+    let (gas_receipt_pda, _) = axelar_solana_gas_service::get_gas_receipt_pda(&notice.message_id);
+    let payer = setup.metadata.payer.insecure_clone();
+    let sender = setup.sender.insecure_clone();
+    let ix = close_gas_receipt_instruction(&sender.pubkey(), &notice.message_id).unwrap();
+
+    // The receipt of a pending message can't be closed
+    let err = setup
+        .metadata
+        .send_tx_with_custom_signers(&[ix.clone()], &[&payer, &sender])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        custom_error(&err.result.unwrap_err()),
+        GasServiceError::GasReceiptPending as u32
+    );
+
+    // Only the operator reports the message as delivered
+    let impostor = Keypair::new();
+    let mark_delivered =
+        mark_gas_receipt_delivered_instruction(&impostor.pubkey(), notice.message_id.clone())
+            .unwrap();
+    let res = setup
+        .metadata
+        .send_tx_with_custom_signers(&[mark_delivered], &[&payer, &impostor])
+        .await;
+    assert!(res.is_err());
+    let mark_delivered =
+        mark_gas_receipt_delivered_instruction(&setup.operator.pubkey(), notice.message_id.clone())
+            .unwrap();
+    let operator = setup.operator.insecure_clone();
+    setup
+        .metadata
+        .send_tx_with_custom_signers(&[mark_delivered], &[&payer, &operator])
+        .await
+        .unwrap();
+    let receipt = gas_receipt(&mut setup, &notice).await.unwrap();
+    assert_eq!(receipt.status(), GasReceiptStatus::Delivered);
+
+    // Only the sender can close its receipt
+    let impostor_ix =
+        close_gas_receipt_instruction(&impostor.pubkey(), &notice.message_id).unwrap();
+    let res = setup
+        .metadata
+        .send_tx_with_custom_signers(&[impostor_ix], &[&payer, &impostor])
+        .await;
+    assert!(res.is_err());
+
+    let sender_balance_before = setup
+        .metadata
+        .try_get_account_no_checks(&setup.sender.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    // Action
+    let simulation_result = setup
+        .metadata
+        .simulate_tx_with_custom_signers(&[ix.clone()], &[&payer, &sender])
+        .await
+        .unwrap();
+    setup
+        .metadata
+        .send_tx_with_custom_signers(&[ix], &[&payer, &sender])
+        .await
+        .unwrap();
+
+    // Assert
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    assert_event_cpi(
+        &GasReceiptClosedEvent {
+            sender: sender.pubkey(),
+            gas_receipt: gas_receipt_pda,
+            amount: 1_000,
+            sequence: 3,
+        },
+        &inner_ixs,
+    );
+    assert!(gas_receipt(&mut setup, &notice).await.is_none());
+    let sender_balance_after = setup
+        .metadata
+        .try_get_account_no_checks(&sender.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert!(sender_balance_after > sender_balance_before);
+}
+
+#[tokio::test]
+async fn test_set_refund_notice_source() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42])
+        .build()
+        .setup()
+        .await;
+    let gas_utils = metadata.deploy_gas_service().await;
+    metadata.init_gas_config(&gas_utils).await.unwrap();
+    let sender = Keypair::new();
+    metadata.fund_account(&sender.pubkey(), 1_000_000_000).await;
+    let mut setup = Setup {
+        metadata,
+        operator: gas_utils.operator,
+        sender,
+    };
+
+    // Action
+    set_refund_notice_source(&mut setup, HUB_CHAIN, HUB_ADDRESS).await;
+    set_refund_notice_source(&mut setup, HUB_CHAIN, "axelar1otherhub").await;
+
+    // Assert
+    let (refund_notice_source_pda, _) = axelar_solana_gas_service::get_refund_notice_source_pda();
+    let account = setup
+        .metadata
+        .try_get_account_no_checks(&refund_notice_source_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let source = RefundNoticeSource::read(&account.data).unwrap();
+    assert!(source.matches(HUB_CHAIN, "axelar1otherhub"));
+    assert!(!source.matches(HUB_CHAIN, HUB_ADDRESS));
+}
+
+#[tokio::test]
+async fn test_set_refund_notice_source_requires_operator() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42])
+        .build()
+        .setup()
+        .await;
+    let gas_utils = metadata.deploy_gas_service().await;
+    metadata.init_gas_config(&gas_utils).await.unwrap();
+    let sender = Keypair::new();
+    metadata.fund_account(&sender.pubkey(), 1_000_000_000).await;
+    let mut setup = Setup {
+        metadata,
+        operator: gas_utils.operator,
+        sender,
+    };
+    let impostor = Keypair::new();
+    let ix = set_refund_notice_source_instruction(
+        &setup.metadata.payer.pubkey(),
+        &impostor.pubkey(),
+        HUB_CHAIN.to_owned(),
+        HUB_ADDRESS.to_owned(),
+    )
+    .unwrap();
+    let payer = setup.metadata.payer.insecure_clone();
+
+    // Action
+    let res = setup
+        .metadata
+        .send_tx_with_custom_signers(&[ix], &[&payer, &impostor])
+        .await;
+
+    // Assert
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_refund_notice_refunds_the_gas_receipt() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42])
+        .build()
+        .setup()
+        .await;
+    let gas_utils = metadata.deploy_gas_service().await;
+    metadata.init_gas_config(&gas_utils).await.unwrap();
+    let sender = Keypair::new();
+    metadata.fund_account(&sender.pubkey(), 1_000_000_000).await;
+    let mut setup = Setup {
+        metadata,
+        operator: gas_utils.operator,
+        sender,
+    };
+    set_refund_notice_source(&mut setup, HUB_CHAIN, HUB_ADDRESS).await;
+    let notice = sample_notice("tx-sig-1.1");
+    let refund_address = Pubkey::new_unique();
+    let gas_amount = 1_000_000;
+    pay_gas_with_receipt(&mut setup, &notice, refund_address, gas_amount)
+        .await
+        .unwrap();
+    let message = approve_refund_notice(&mut setup, HUB_CHAIN, HUB_ADDRESS, &notice).await;
+
+    let (config_pda, _) = axelar_solana_gas_service::get_config_pda();
+    let config_pda_balance_before = setup
+        .metadata
+        .try_get_account_no_checks(&config_pda)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let payer = setup.metadata.payer.insecure_clone();
+    let ix = process_refund_notice_instruction(&payer.pubkey(), message, &notice, &refund_address)
+        .unwrap();
+
+    // Action
+    let simulation_result = setup
+        .metadata
+        .simulate_tx_with_custom_signers(&[ix.clone()], &[&payer])
+        .await
+        .unwrap();
+    setup
+        .metadata
+        .send_tx_with_custom_signers(&[ix.clone()], &[&payer])
+        .await
+        .unwrap();
+
+    // Assert
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    assert_event_cpi(
+        &GasRefundedEventV2 {
+            receiver: refund_address,
+            message_id: notice.message_id.clone(),
+            amount: gas_amount,
+            spl_token_account: None,
+            sequence: 3,
+        },
+        &inner_ixs,
+    );
+    assert_event_cpi(
+        &BalanceChangedEvent {
+            old_balance: config_pda_balance_before,
+            new_balance: config_pda_balance_before - gas_amount,
+            reason: BalanceChangeReason::FeesRefunded,
+            sequence: 4,
+        },
+        &inner_ixs,
+    );
+
+    let refunded_balance = setup
+        .metadata
+        .try_get_account_no_checks(&refund_address)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert!(refunded_balance > gas_amount);
+    assert!(gas_receipt(&mut setup, &notice).await.is_none());
+
+    // A notice can only be processed once
+    let res = setup
+        .metadata
+        .send_tx_with_custom_signers(&[ix], &[&payer])
+        .await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_refund_notice_from_unknown_source_is_rejected() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42])
+        .build()
+        .setup()
+        .await;
+    let gas_utils = metadata.deploy_gas_service().await;
+    metadata.init_gas_config(&gas_utils).await.unwrap();
+    let sender = Keypair::new();
+    metadata.fund_account(&sender.pubkey(), 1_000_000_000).await;
+    let mut setup = Setup {
+        metadata,
+        operator: gas_utils.operator,
+        sender,
+    };
+    set_refund_notice_source(&mut setup, HUB_CHAIN, HUB_ADDRESS).await;
+    let notice = sample_notice("tx-sig-1.1");
+    let refund_address = Pubkey::new_unique();
+    pay_gas_with_receipt(&mut setup, &notice, refund_address, 1_000_000)
+        .await
+        .unwrap();
+    let message = approve_refund_notice(&mut setup, "ethereum", HUB_ADDRESS, &notice).await;
+    let payer = setup.metadata.payer.insecure_clone();
+    let ix = process_refund_notice_instruction(&payer.pubkey(), message, &notice, &refund_address)
+        .unwrap();
+
+    // Action
+    let err = setup
+        .metadata
+        .send_tx_with_custom_signers(&[ix], &[&payer])
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert_eq!(
+        custom_error(&err.result.unwrap_err()),
+        GasServiceError::UnauthorizedRefundNotice as u32
+    );
+    assert!(gas_receipt(&mut setup, &notice).await.is_some());
+}
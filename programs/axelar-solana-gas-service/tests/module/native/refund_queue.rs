@@ -0,0 +1,543 @@
+use axelar_solana_gas_service::error::GasServiceError;
+use axelar_solana_gas_service::events::{
+    BalanceChangeReason, BalanceChangedEvent, GasRefundedEventV2, RefundEnqueuedEvent,
+    RefundSkippedEvent,
+};
+use axelar_solana_gas_service::instructions::{
+    enqueue_refund_instruction, pay_gas_with_receipt_instruction, process_next_refund_instruction,
+    skip_next_refund_instruction,
+};
+use axelar_solana_gas_service::state::{GasReceipt, RefundQueue};
+use axelar_solana_gateway_test_fixtures::base::TestFixture;
+use axelar_solana_gateway_test_fixtures::gas_service::GasServiceUtils;
+use event_cpi_test_utils::assert_event_cpi;
+use program_utils::pda::BytemuckedPda;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
+use solana_sdk::transaction::TransactionError;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+/// Pays `amount` lamports of gas refundable to `refund_address` for the
+/// message `message_id`, returning the gas receipt PDA.
+async fn pay_gas_with_receipt(
+    test_fixture: &mut TestFixture,
+    refund_address: &Pubkey,
+    message_id: &str,
+    amount: u64,
+) -> Pubkey {
+    let sender = Keypair::new();
+    test_fixture
+        .fund_account(&sender.pubkey(), 1_000_000_000)
+        .await;
+    let ix = pay_gas_with_receipt_instruction(
+        &sender.pubkey(),
+        message_id.to_owned(),
+        *refund_address,
+        amount,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &sender])
+        .await
+        .unwrap();
+
+    axelar_solana_gas_service::get_gas_receipt_pda(message_id).0
+}
+
+async fn enqueue_refund(
+    test_fixture: &mut TestFixture,
+    gas_utils: &GasServiceUtils,
+    receiver: &Pubkey,
+    message_id: &str,
+    amount: u64,
+) {
+    pay_gas_with_receipt(test_fixture, receiver, message_id, amount).await;
+    let ix = enqueue_refund_instruction(
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        receiver,
+        message_id.to_owned(),
+        amount,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+}
+
+/// Rent of a gas receipt, going to the refund address once the receipt is
+/// fully refunded.
+fn gas_receipt_rent() -> u64 {
+    Rent::default().minimum_balance(GasReceipt::pda_size())
+}
+
+async fn refund_queue(test_fixture: &mut TestFixture) -> RefundQueue {
+    let (refund_queue_pda, _) = axelar_solana_gas_service::get_refund_queue_pda();
+    let account = test_fixture
+        .try_get_account_no_checks(&refund_queue_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    *RefundQueue::read(&account.data).unwrap()
+}
+
+fn custom_error(err: &TransactionError) -> u32 {
+    let TransactionError::InstructionError(_index, InstructionError::Custom(error_code)) = err
+    else {
+        panic!("unexpected error: {err:?}")
+    };
+    *error_code
+}
+
+#[tokio::test]
+async fn test_keeper_processes_queued_refund() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    test_fixture
+        .fund_account(&gas_utils.config_pda, 1_000_000_000)
+        .await;
+    let receiver = Keypair::new().pubkey();
+    let gas_amount = 1_000_000;
+    let message_id = "tx-sig-2.1";
+
+    pay_gas_with_receipt(&mut test_fixture, &receiver, message_id, gas_amount).await;
+
+    let ix = enqueue_refund_instruction(
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        &receiver,
+        message_id.to_owned(),
+        gas_amount,
+    )
+    .unwrap();
+    let simulation_result = test_fixture
+        .simulate_tx_with_custom_signers(
+            &[ix.clone()],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    assert_event_cpi(
+        &RefundEnqueuedEvent {
+            receiver,
+            message_id: message_id.to_owned(),
+            amount: gas_amount,
+            queued: 1,
+            sequence: 2,
+        },
+        &inner_ixs,
+    );
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+    assert_eq!(refund_queue(&mut test_fixture).await.len, 1);
+
+    let config_pda_balance_before = test_fixture
+        .try_get_account_no_checks(&gas_utils.config_pda)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    // Action: the queue is cranked without the operator signature
+    let ix = process_next_refund_instruction(&receiver).unwrap();
+    let simulation_result = test_fixture.simulate_tx(&[ix.clone()]).await.unwrap();
+    test_fixture.send_tx(&[ix]).await.unwrap();
+
+    // Assert
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    assert_event_cpi(
+        &GasRefundedEventV2 {
+            receiver,
+            message_id: message_id.to_owned(),
+            amount: gas_amount,
+            spl_token_account: None,
+            sequence: 3,
+        },
+        &inner_ixs,
+    );
+    assert_event_cpi(
+        &BalanceChangedEvent {
+            old_balance: config_pda_balance_before,
+            new_balance: config_pda_balance_before - gas_amount,
+            reason: BalanceChangeReason::FeesRefunded,
+            sequence: 4,
+        },
+        &inner_ixs,
+    );
+
+    let receiver_balance = test_fixture
+        .try_get_account_no_checks(&receiver)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(receiver_balance, gas_amount + gas_receipt_rent());
+    assert_eq!(refund_queue(&mut test_fixture).await.len, 0);
+}
+
+#[tokio::test]
+async fn test_refunds_are_processed_in_queue_order() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    test_fixture
+        .fund_account(&gas_utils.config_pda, 1_000_000_000)
+        .await;
+    let first_receiver = Keypair::new().pubkey();
+    let second_receiver = Keypair::new().pubkey();
+    enqueue_refund(
+        &mut test_fixture,
+        &gas_utils,
+        &first_receiver,
+        "tx-sig-1.1",
+        1_000_000,
+    )
+    .await;
+    enqueue_refund(
+        &mut test_fixture,
+        &gas_utils,
+        &second_receiver,
+        "tx-sig-2.1",
+        2_000_000,
+    )
+    .await;
+
+    // Action: the second refund can't jump the queue
+    let ix = process_next_refund_instruction(&second_receiver).unwrap();
+    let res = test_fixture.send_tx(&[ix]).await;
+
+    // Assert
+    assert!(res.is_err());
+    assert_eq!(refund_queue(&mut test_fixture).await.len, 2);
+
+    for (receiver, amount) in [(first_receiver, 1_000_000), (second_receiver, 2_000_000)] {
+        let ix = process_next_refund_instruction(&receiver).unwrap();
+        test_fixture.send_tx(&[ix]).await.unwrap();
+        let balance = test_fixture
+            .try_get_account_no_checks(&receiver)
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports;
+        assert_eq!(balance, amount + gas_receipt_rent());
+    }
+    assert_eq!(refund_queue(&mut test_fixture).await.len, 0);
+}
+
+#[tokio::test]
+async fn test_process_next_refund_fails_on_empty_queue() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    test_fixture
+        .fund_account(&gas_utils.config_pda, 1_000_000_000)
+        .await;
+    let receiver = Keypair::new().pubkey();
+    enqueue_refund(
+        &mut test_fixture,
+        &gas_utils,
+        &receiver,
+        "tx-sig-1.1",
+        1_000_000,
+    )
+    .await;
+    let ix = process_next_refund_instruction(&receiver).unwrap();
+    test_fixture.send_tx(&[ix]).await.unwrap();
+
+    // Action
+    let ix = process_next_refund_instruction(&receiver).unwrap();
+    let err = test_fixture.send_tx(&[ix]).await.unwrap_err();
+
+    // Assert
+    assert_eq!(
+        custom_error(&err.result.unwrap_err()),
+        GasServiceError::RefundQueueEmpty as u32
+    );
+}
+
+#[tokio::test]
+async fn test_enqueued_refunds_are_taken_out_of_the_gas_receipt() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    let receiver = Keypair::new().pubkey();
+    let gas_receipt_pda =
+        pay_gas_with_receipt(&mut test_fixture, &receiver, "tx-sig-1.1", 1_000_000).await;
+    let enqueue = |amount: u64| {
+        enqueue_refund_instruction(
+            &test_fixture.payer.pubkey(),
+            &gas_utils.operator.pubkey(),
+            &receiver,
+            "tx-sig-1.1".to_owned(),
+            amount,
+        )
+        .unwrap()
+    };
+    let first_ix = enqueue(600_000);
+    let second_ix = enqueue(500_000);
+
+    // Action
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[first_ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+    let err = test_fixture
+        .send_tx_with_custom_signers(
+            &[second_ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap_err();
+
+    // Assert: the same payment can't be refunded twice
+    assert_eq!(
+        custom_error(&err.result.unwrap_err()),
+        GasServiceError::RefundExceedsReceipt as u32
+    );
+    assert_eq!(refund_queue(&mut test_fixture).await.len, 1);
+    let account = test_fixture
+        .try_get_account_no_checks(&gas_receipt_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(GasReceipt::read(&account.data).unwrap().amount, 400_000);
+}
+
+#[tokio::test]
+async fn test_only_operator_can_enqueue_refunds() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    test_fixture
+        .fund_account(&gas_utils.config_pda, 1_000_000_000)
+        .await;
+    let impostor = Keypair::new();
+    pay_gas_with_receipt(
+        &mut test_fixture,
+        &impostor.pubkey(),
+        "tx-sig-1.1",
+        1_000_000,
+    )
+    .await;
+    let ix = enqueue_refund_instruction(
+        &test_fixture.payer.pubkey(),
+        &impostor.pubkey(),
+        &impostor.pubkey(),
+        "tx-sig-1.1".to_owned(),
+        1_000_000,
+    )
+    .unwrap();
+
+    // Action
+    let res = test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &impostor])
+        .await;
+
+    // Assert
+    assert!(res.is_err());
+    let (refund_queue_pda, _) = axelar_solana_gas_service::get_refund_queue_pda();
+    assert!(test_fixture
+        .try_get_account_no_checks(&refund_queue_pda)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_operator_skips_unpayable_refund() {
+    // Setup: the first refund is below the rent exempt minimum of its empty
+    // receiver, so it can't be paid out. Its receipt is not fully refunded,
+    // so the receiver doesn't get the receipt rent either.
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    test_fixture
+        .fund_account(&gas_utils.config_pda, 1_000_000_000)
+        .await;
+    let first_receiver = Keypair::new().pubkey();
+    let second_receiver = Keypair::new().pubkey();
+
+    pay_gas_with_receipt(&mut test_fixture, &first_receiver, "tx-sig-1.1", 2).await;
+    let ix = enqueue_refund_instruction(
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        &first_receiver,
+        "tx-sig-1.1".to_owned(),
+        1,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+    enqueue_refund(
+        &mut test_fixture,
+        &gas_utils,
+        &second_receiver,
+        "tx-sig-2.1",
+        2_000_000,
+    )
+    .await;
+    let ix = process_next_refund_instruction(&first_receiver).unwrap();
+    assert!(test_fixture.send_tx(&[ix]).await.is_err());
+
+    // Action
+    let ix = skip_next_refund_instruction(&gas_utils.operator.pubkey(), "tx-sig-1.1".to_owned())
+        .unwrap();
+    let simulation_result = test_fixture
+        .simulate_tx_with_custom_signers(
+            &[ix.clone()],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    assert_event_cpi(
+        &RefundSkippedEvent {
+            receiver: first_receiver,
+            message_id: "tx-sig-1.1".to_owned(),
+            amount: 1,
+            queued: 1,
+            sequence: 6,
+        },
+        &inner_ixs,
+    );
+
+    let ix = process_next_refund_instruction(&second_receiver).unwrap();
+    test_fixture.send_tx(&[ix]).await.unwrap();
+    let balance = test_fixture
+        .try_get_account_no_checks(&second_receiver)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(balance, 2_000_000 + gas_receipt_rent());
+    assert_eq!(refund_queue(&mut test_fixture).await.len, 0);
+}
+
+#[tokio::test]
+async fn test_skip_next_refund_checks_message_id() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    test_fixture
+        .fund_account(&gas_utils.config_pda, 1_000_000_000)
+        .await;
+    let receiver = Keypair::new().pubkey();
+    enqueue_refund(
+        &mut test_fixture,
+        &gas_utils,
+        &receiver,
+        "tx-sig-1.1",
+        1_000_000,
+    )
+    .await;
+
+    // Action
+    let ix = skip_next_refund_instruction(&gas_utils.operator.pubkey(), "tx-sig-2.1".to_owned())
+        .unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await;
+
+    // Assert
+    assert!(res.is_err());
+    assert_eq!(refund_queue(&mut test_fixture).await.len, 1);
+}
+
+#[tokio::test]
+async fn test_only_operator_can_skip_refunds() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    test_fixture
+        .fund_account(&gas_utils.config_pda, 1_000_000_000)
+        .await;
+    let receiver = Keypair::new().pubkey();
+    enqueue_refund(
+        &mut test_fixture,
+        &gas_utils,
+        &receiver,
+        "tx-sig-1.1",
+        1_000_000,
+    )
+    .await;
+    let impostor = Keypair::new();
+
+    // Action
+    let ix = skip_next_refund_instruction(&impostor.pubkey(), "tx-sig-1.1".to_owned()).unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &impostor])
+        .await;
+
+    // Assert
+    assert!(res.is_err());
+    assert_eq!(refund_queue(&mut test_fixture).await.len, 1);
+}
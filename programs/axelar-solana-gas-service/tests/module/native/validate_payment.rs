@@ -0,0 +1,111 @@
+use axelar_solana_gateway_test_fixtures::base::TestFixture;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+#[tokio::test]
+async fn test_validate_payment_does_not_move_funds() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let sender = Keypair::new();
+    test_fixture
+        .fund_account(&sender.pubkey(), 1_000_000_000)
+        .await;
+    let sender_balance_before = test_fixture
+        .try_get_account_no_checks(&sender.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let config_pda_balance_before = test_fixture
+        .try_get_account_no_checks(&gas_utils.config_pda)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    // Action
+    let ix = axelar_solana_gas_service::instructions::validate_payment_instruction(
+        &sender.pubkey(),
+        "ethereum".to_owned(),
+        [42; 32],
+        sender.pubkey(),
+        1_000_000,
+    )
+    .unwrap();
+    test_fixture.send_tx(&[ix]).await.unwrap();
+
+    // Assert: no lamports moved
+    let sender_balance_after = test_fixture
+        .try_get_account_no_checks(&sender.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let config_pda_balance_after = test_fixture
+        .try_get_account_no_checks(&gas_utils.config_pda)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    assert_eq!(sender_balance_after, sender_balance_before);
+    assert_eq!(config_pda_balance_after, config_pda_balance_before);
+}
+
+#[tokio::test]
+async fn fails_if_sender_cannot_cover_amount() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let sender = Keypair::new();
+    test_fixture.fund_account(&sender.pubkey(), 1_000_000).await;
+
+    // Action
+    let ix = axelar_solana_gas_service::instructions::validate_payment_instruction(
+        &sender.pubkey(),
+        "ethereum".to_owned(),
+        [42; 32],
+        sender.pubkey(),
+        1_000_001,
+    )
+    .unwrap();
+    let res = test_fixture.send_tx(&[ix]).await;
+
+    // Assert
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn fails_if_amount_is_zero() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let sender = Keypair::new();
+    test_fixture
+        .fund_account(&sender.pubkey(), 1_000_000_000)
+        .await;
+
+    // Action
+    let ix = axelar_solana_gas_service::instructions::validate_payment_instruction(
+        &sender.pubkey(),
+        "ethereum".to_owned(),
+        [42; 32],
+        sender.pubkey(),
+        0,
+    )
+    .unwrap();
+    let res = test_fixture.send_tx(&[ix]).await;
+
+    // Assert
+    assert!(res.is_err());
+}
@@ -0,0 +1,274 @@
+//! End-to-end example of the native gas lifecycle of a contract call, meant
+//! to be read top to bottom by integrators:
+//!
+//! 1. The sender calls the gateway `CallContract` instruction and pays the
+//!    gas for it in the same transaction.
+//! 2. The gas turns out to be insufficient, and more is added for the message
+//!    later on, recording the payment in a gas receipt.
+//! 3. The gas service operator collects part of the fees.
+//! 4. The operator refunds the leftover gas out of the receipt to the refund
+//!    address given when paying.
+//!
+//! Every step asserts the events relayers index and the balances it moves.
+
+use axelar_solana_gas_service::events::{
+    BalanceChangeReason, BalanceChangedEvent, GasAddedEventV2, GasCollectedEventV2, GasPaidEventV2,
+    GasRefundedEventV2,
+};
+use axelar_solana_gas_service::instructions::{
+    collect_fees_instruction, pay_gas_instruction, pay_gas_with_receipt_instruction,
+    refund_fees_instruction,
+};
+use axelar_solana_gateway::events::CallContractEvent;
+use axelar_solana_gateway_test_fixtures::gateway::SolanaAxelarIntegration;
+use axelar_solana_gateway_test_fixtures::SolanaAxelarIntegrationMetadata;
+use event_cpi_test_utils::assert_event_cpi;
+use solana_banks_interface::BanksTransactionResultWithSimulation;
+use solana_program_test::tokio;
+use solana_sdk::inner_instruction::InnerInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::keccak;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+const DESTINATION_CHAIN: &str = "ethereum";
+const DESTINATION_ADDRESS: &str = "0x4F4495243837681061C4743b74B3eEdf548D56A5";
+const GAS_PAID: u64 = 1_000_000;
+const GAS_ADDED: u64 = 2_000_000;
+const FEES_COLLECTED: u64 = 1_500_000;
+const GAS_REFUNDED: u64 = GAS_PAID + GAS_ADDED - FEES_COLLECTED;
+
+async fn balance(metadata: &mut SolanaAxelarIntegrationMetadata, account: &Pubkey) -> u64 {
+    metadata
+        .try_get_account_no_checks(account)
+        .await
+        .unwrap()
+        .map_or(0, |account| account.lamports)
+}
+
+/// Simulates `ixs` to capture the events they emit, then executes them.
+async fn send(
+    metadata: &mut SolanaAxelarIntegrationMetadata,
+    ixs: &[Instruction],
+    signer: &Keypair,
+) -> Vec<Vec<InnerInstruction>> {
+    let payer = metadata.payer.insecure_clone();
+    let signers = [&payer, signer];
+    let BanksTransactionResultWithSimulation {
+        simulation_details, ..
+    } = metadata
+        .simulate_tx_with_custom_signers(ixs, &signers)
+        .await
+        .unwrap();
+    metadata
+        .send_tx_with_custom_signers(ixs, &signers)
+        .await
+        .unwrap();
+
+    simulation_details.unwrap().inner_instructions.unwrap()
+}
+
+#[tokio::test]
+#[allow(clippy::too_many_lines)]
+async fn native_gas_lifecycle_of_a_contract_call() {
+    // Setup: a gateway and a gas service operated by `gas_utils.operator`
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42])
+        .build()
+        .setup()
+        .await;
+    let gas_utils = metadata.deploy_gas_service().await;
+    metadata.init_gas_config(&gas_utils).await.unwrap();
+    let config_pda = gas_utils.config_pda;
+
+    let sender = Keypair::new();
+    metadata.fund_account(&sender.pubkey(), 1_000_000_000).await;
+    let refund_address = Keypair::new().pubkey();
+    let fee_receiver = Keypair::new().pubkey();
+
+    let payload = b"hello from solana".to_vec();
+    let payload_hash = keccak::hash(&payload).to_bytes();
+
+    // 1. Call the gateway and pay the gas for the call in the same transaction
+    let config_balance = balance(&mut metadata, &config_pda).await;
+    let sender_balance = balance(&mut metadata, &sender.pubkey()).await;
+    let ixs = [
+        axelar_solana_gateway::instructions::call_contract(
+            axelar_solana_gateway::id(),
+            metadata.gateway_root_pda,
+            sender.pubkey(),
+            None,
+            DESTINATION_CHAIN.to_owned(),
+            DESTINATION_ADDRESS.to_owned(),
+            payload.clone(),
+        )
+        .unwrap(),
+        pay_gas_instruction(
+            &sender.pubkey(),
+            DESTINATION_CHAIN.to_owned(),
+            DESTINATION_ADDRESS.to_owned(),
+            payload_hash,
+            refund_address,
+            GAS_PAID,
+        )
+        .unwrap(),
+    ];
+    let inner_ixs = send(&mut metadata, &ixs, &sender).await;
+
+    assert_event_cpi(
+        &CallContractEvent {
+            sender: sender.pubkey(),
+            payload_hash,
+            destination_chain: DESTINATION_CHAIN.to_owned(),
+            destination_contract_address: DESTINATION_ADDRESS.to_owned(),
+            payload,
+        },
+        &inner_ixs[0],
+    );
+    assert_event_cpi(
+        &GasPaidEventV2 {
+            sender: sender.pubkey(),
+            destination_chain: DESTINATION_CHAIN.to_owned(),
+            destination_address: DESTINATION_ADDRESS.to_owned(),
+            payload_hash,
+            amount: GAS_PAID,
+            refund_address,
+            spl_token_account: None,
+            mint: None,
+            linked_call_contract_index: None,
+            destination_gas_amount: None,
+            sequence: 0,
+        },
+        &inner_ixs[1],
+    );
+    assert_event_cpi(
+        &BalanceChangedEvent {
+            old_balance: config_balance,
+            new_balance: config_balance + GAS_PAID,
+            reason: BalanceChangeReason::GasPaid,
+            sequence: 1,
+        },
+        &inner_ixs[1],
+    );
+    assert_eq!(
+        balance(&mut metadata, &config_pda).await,
+        config_balance + GAS_PAID
+    );
+    assert_eq!(
+        balance(&mut metadata, &sender.pubkey()).await,
+        sender_balance - GAS_PAID
+    );
+
+    // 2. Add gas for the message later on, recording it in a gas receipt the
+    //    operator refunds out of. Relayers identify the message by the
+    //    signature of the transaction that called the gateway and the index
+    //    of the `CallContractEvent` in it.
+    let message_id = "2ZxFcbM9vk3hH5cWpVUJpN1xW4Zr8EFWfbC6N3yA6MXz-0.1".to_owned();
+    let (gas_receipt_pda, _) = axelar_solana_gas_service::get_gas_receipt_pda(&message_id);
+    let config_balance = balance(&mut metadata, &config_pda).await;
+    let sender_balance = balance(&mut metadata, &sender.pubkey()).await;
+    let ix = pay_gas_with_receipt_instruction(
+        &sender.pubkey(),
+        message_id.clone(),
+        refund_address,
+        GAS_ADDED,
+    )
+    .unwrap();
+    let inner_ixs = send(&mut metadata, &[ix], &sender).await;
+
+    assert_event_cpi(
+        &GasAddedEventV2 {
+            sender: sender.pubkey(),
+            message_id: message_id.clone(),
+            amount: GAS_ADDED,
+            refund_address,
+            spl_token_account: None,
+            sequence: 2,
+        },
+        &inner_ixs[0],
+    );
+    assert_event_cpi(
+        &BalanceChangedEvent {
+            old_balance: config_balance,
+            new_balance: config_balance + GAS_ADDED,
+            reason: BalanceChangeReason::GasAdded,
+            sequence: 3,
+        },
+        &inner_ixs[0],
+    );
+    assert_eq!(
+        balance(&mut metadata, &config_pda).await,
+        config_balance + GAS_ADDED
+    );
+    // The sender also pays the rent of the gas receipt
+    let receipt_rent = balance(&mut metadata, &gas_receipt_pda).await;
+    assert_eq!(
+        balance(&mut metadata, &sender.pubkey()).await,
+        sender_balance - GAS_ADDED - receipt_rent
+    );
+
+    // 3. Once the message is executed, the operator collects the fees it
+    //    consumed
+    let config_balance = balance(&mut metadata, &config_pda).await;
+    let ix = collect_fees_instruction(&gas_utils.operator.pubkey(), &fee_receiver, FEES_COLLECTED)
+        .unwrap();
+    let inner_ixs = send(&mut metadata, &[ix], &gas_utils.operator).await;
+
+    assert_event_cpi(
+        &GasCollectedEventV2 {
+            receiver: fee_receiver,
+            amount: FEES_COLLECTED,
+            spl_token_account: None,
+            mint: None,
+            sequence: 4,
+        },
+        &inner_ixs[0],
+    );
+    assert_event_cpi(
+        &BalanceChangedEvent {
+            old_balance: config_balance,
+            new_balance: config_balance - FEES_COLLECTED,
+            reason: BalanceChangeReason::FeesCollected,
+            sequence: 5,
+        },
+        &inner_ixs[0],
+    );
+    assert_eq!(balance(&mut metadata, &fee_receiver).await, FEES_COLLECTED);
+
+    // 4. The operator refunds the gas left over to the refund address
+    let config_balance = balance(&mut metadata, &config_pda).await;
+    let ix = refund_fees_instruction(
+        &gas_utils.operator.pubkey(),
+        &refund_address,
+        message_id.clone(),
+        GAS_REFUNDED,
+    )
+    .unwrap();
+    let inner_ixs = send(&mut metadata, &[ix], &gas_utils.operator).await;
+
+    assert_event_cpi(
+        &GasRefundedEventV2 {
+            receiver: refund_address,
+            message_id,
+            amount: GAS_REFUNDED,
+            spl_token_account: None,
+            sequence: 6,
+        },
+        &inner_ixs[0],
+    );
+    assert_event_cpi(
+        &BalanceChangedEvent {
+            old_balance: config_balance,
+            new_balance: config_balance - GAS_REFUNDED,
+            reason: BalanceChangeReason::FeesRefunded,
+            sequence: 7,
+        },
+        &inner_ixs[0],
+    );
+    assert_eq!(balance(&mut metadata, &refund_address).await, GAS_REFUNDED);
+    assert_eq!(
+        balance(&mut metadata, &config_pda).await,
+        config_balance - GAS_REFUNDED
+    );
+}
@@ -0,0 +1,246 @@
+use axelar_solana_gas_service::error::GasServiceError;
+use axelar_solana_gas_service::events::ChainPauseSetEvent;
+use axelar_solana_gas_service::instructions::{pay_gas_instruction, set_chain_paused_instruction};
+use axelar_solana_gas_service::state::PausedChains;
+use axelar_solana_gateway_test_fixtures::base::TestFixture;
+use axelar_solana_gateway_test_fixtures::gas_service::GasServiceUtils;
+use event_cpi_test_utils::assert_event_cpi;
+use program_utils::pda::BytemuckedPda;
+use solana_program_test::{tokio, BanksTransactionResultWithMetadata, ProgramTest};
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+async fn set_chain_paused(
+    test_fixture: &mut TestFixture,
+    gas_utils: &GasServiceUtils,
+    destination_chain: &str,
+    paused: bool,
+) {
+    let ix = set_chain_paused_instruction(
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        destination_chain.to_owned(),
+        paused,
+    )
+    .unwrap();
+
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+}
+
+async fn pay_gas(
+    test_fixture: &mut TestFixture,
+    payer: &Keypair,
+    destination_chain: &str,
+) -> Result<BanksTransactionResultWithMetadata, BanksTransactionResultWithMetadata> {
+    let ix = pay_gas_instruction(
+        &payer.pubkey(),
+        destination_chain.to_owned(),
+        "destination addr 123".to_owned(),
+        [42; 32],
+        Pubkey::new_unique(),
+        1_000_000,
+    )
+    .unwrap();
+
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), payer])
+        .await
+}
+
+#[tokio::test]
+async fn test_pause_halts_payments_towards_paused_chain_only() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let payer = Keypair::new();
+    test_fixture
+        .fund_account(&payer.pubkey(), 1_000_000_000)
+        .await;
+
+    // Payments work before the paused chains PDA exists
+    pay_gas(&mut test_fixture, &payer, "ethereum")
+        .await
+        .unwrap();
+
+    // Action
+    let ix = set_chain_paused_instruction(
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        "ethereum".to_owned(),
+        true,
+    )
+    .unwrap();
+    let simulation_result = test_fixture
+        .simulate_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    assert_event_cpi(
+        &ChainPauseSetEvent {
+            destination_chain: "ethereum".to_owned(),
+            paused: true,
+            sequence: 2,
+        },
+        &inner_ixs,
+    );
+
+    set_chain_paused(&mut test_fixture, &gas_utils, "ethereum", true).await;
+
+    // Assert
+    let (paused_chains_pda, _) = axelar_solana_gas_service::get_paused_chains_pda();
+    let account = test_fixture
+        .try_get_account_no_checks(&paused_chains_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let paused_chains = PausedChains::read(&account.data).unwrap();
+    assert!(paused_chains.is_paused("ethereum"));
+    assert!(!paused_chains.is_paused("avalanche"));
+
+    let tx = pay_gas(&mut test_fixture, &payer, "ethereum")
+        .await
+        .unwrap_err();
+    let Err(TransactionError::InstructionError(_index, InstructionError::Custom(error_code))) =
+        tx.result
+    else {
+        panic!("unexpected error")
+    };
+    assert_eq!(error_code, GasServiceError::DestinationChainPaused as u32);
+
+    pay_gas(&mut test_fixture, &payer, "avalanche")
+        .await
+        .unwrap();
+
+    // Resuming lets payments through again
+    set_chain_paused(&mut test_fixture, &gas_utils, "ethereum", false).await;
+    pay_gas(&mut test_fixture, &payer, "ethereum")
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_fail_set_chain_paused_invalid_operator() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    // Action
+    let wrong_operator = Keypair::new();
+    let ix = set_chain_paused_instruction(
+        &test_fixture.payer.pubkey(),
+        &wrong_operator.pubkey(),
+        "ethereum".to_owned(),
+        true,
+    )
+    .unwrap();
+    let result = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &wrong_operator],
+        )
+        .await;
+
+    // Assert
+    assert!(result.is_err());
+    let (paused_chains_pda, _) = axelar_solana_gas_service::get_paused_chains_pda();
+    assert!(test_fixture
+        .try_get_account_no_checks(&paused_chains_pda)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_pay_gas_without_paused_chains_account_keeps_working() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let payer = Keypair::new();
+    test_fixture
+        .fund_account(&payer.pubkey(), 1_000_000_000)
+        .await;
+
+    // Action: clients built before the chain pause don't pass the trailing
+    // paused chains PDA
+    let mut ix = pay_gas_instruction(
+        &payer.pubkey(),
+        "ethereum".to_owned(),
+        "destination addr 123".to_owned(),
+        [42; 32],
+        Pubkey::new_unique(),
+        1_000_000,
+    )
+    .unwrap();
+    let (paused_chains_pda, _) = axelar_solana_gas_service::get_paused_chains_pda();
+    assert_eq!(ix.accounts.pop().unwrap().pubkey, paused_chains_pda);
+
+    // Assert
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &payer])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_pay_gas_cannot_skip_the_pause() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    set_chain_paused(&mut test_fixture, &gas_utils, "ethereum", true).await;
+
+    let payer = Keypair::new();
+    test_fixture
+        .fund_account(&payer.pubkey(), 1_000_000_000)
+        .await;
+
+    // Action: leave out the paused chains PDA to skip the pause
+    let mut ix = pay_gas_instruction(
+        &payer.pubkey(),
+        "ethereum".to_owned(),
+        "destination addr 123".to_owned(),
+        [42; 32],
+        Pubkey::new_unique(),
+        1_000_000,
+    )
+    .unwrap();
+    ix.accounts.pop();
+    let err = test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &payer])
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert_eq!(
+        err.result.unwrap_err(),
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+}
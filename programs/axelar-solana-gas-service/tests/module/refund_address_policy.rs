@@ -0,0 +1,247 @@
+use axelar_solana_gas_service::error::GasServiceError;
+use axelar_solana_gas_service::events::RefundAddressPolicySetEvent;
+use axelar_solana_gas_service::instructions::{
+    add_gas_instruction, pay_gas_instruction, set_refund_address_policy_instruction,
+    validate_payment_instruction,
+};
+use axelar_solana_gas_service::state::{Config, RefundAddressPolicy};
+use axelar_solana_gateway_test_fixtures::base::TestFixture;
+use event_cpi_test_utils::assert_event_cpi;
+use program_utils::pda::BytemuckedPda;
+use solana_program_test::{tokio, BanksTransactionResultWithMetadata, ProgramTest};
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+async fn send(
+    test_fixture: &mut TestFixture,
+    signer: &Keypair,
+    ix: Instruction,
+) -> Result<BanksTransactionResultWithMetadata, BanksTransactionResultWithMetadata> {
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), signer])
+        .await
+}
+
+fn pay_gas(payer: &Keypair, refund_address: Pubkey) -> Instruction {
+    pay_gas_instruction(
+        &payer.pubkey(),
+        "ethereum".to_owned(),
+        "destination addr 123".to_owned(),
+        [42; 32],
+        refund_address,
+        1_000_000,
+    )
+    .unwrap()
+}
+
+fn add_gas(payer: &Keypair, refund_address: Pubkey) -> Instruction {
+    add_gas_instruction(
+        &payer.pubkey(),
+        "tx-sig-1".to_owned(),
+        1_000,
+        refund_address,
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_payer_only_policy_rejects_other_refund_addresses() {
+    // Setup: any refund address is accepted by default
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let payer = Keypair::new();
+    test_fixture
+        .fund_account(&payer.pubkey(), 1_000_000_000)
+        .await;
+    send(
+        &mut test_fixture,
+        &payer,
+        pay_gas(&payer, Pubkey::new_unique()),
+    )
+    .await
+    .unwrap();
+
+    // Action
+    let ix = set_refund_address_policy_instruction(
+        &gas_utils.operator.pubkey(),
+        RefundAddressPolicy::PayerOnly,
+    )
+    .unwrap();
+    let simulation_result = test_fixture
+        .simulate_tx_with_custom_signers(
+            &[ix.clone()],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    send(&mut test_fixture, &gas_utils.operator, ix)
+        .await
+        .unwrap();
+
+    // Assert
+    assert_event_cpi(
+        &RefundAddressPolicySetEvent {
+            policy: RefundAddressPolicy::PayerOnly,
+            sequence: 2,
+        },
+        &inner_ixs,
+    );
+    let config = test_fixture
+        .get_account(&gas_utils.config_pda, &axelar_solana_gas_service::ID)
+        .await;
+    assert_eq!(
+        Config::read(&config.data).unwrap().refund_address_policy(),
+        RefundAddressPolicy::PayerOnly
+    );
+
+    for ix in [
+        pay_gas(&payer, Pubkey::new_unique()),
+        add_gas(&payer, Pubkey::new_unique()),
+    ] {
+        let tx = send(&mut test_fixture, &payer, ix).await.unwrap_err();
+        assert_eq!(
+            tx.result.unwrap_err(),
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(GasServiceError::RefundAddressNotPayer as u32)
+            )
+        );
+    }
+
+    send(&mut test_fixture, &payer, pay_gas(&payer, payer.pubkey()))
+        .await
+        .unwrap();
+    send(&mut test_fixture, &payer, add_gas(&payer, payer.pubkey()))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_validate_payment_applies_refund_address_policy() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let payer = Keypair::new();
+    test_fixture
+        .fund_account(&payer.pubkey(), 1_000_000_000)
+        .await;
+    let ix = set_refund_address_policy_instruction(
+        &gas_utils.operator.pubkey(),
+        RefundAddressPolicy::PayerOnly,
+    )
+    .unwrap();
+    send(&mut test_fixture, &gas_utils.operator, ix)
+        .await
+        .unwrap();
+    let validate_payment = |refund_address| {
+        validate_payment_instruction(
+            &payer.pubkey(),
+            "ethereum".to_owned(),
+            [42; 32],
+            refund_address,
+            1_000_000,
+        )
+        .unwrap()
+    };
+
+    // Action
+    let tx = test_fixture
+        .send_tx(&[validate_payment(Pubkey::new_unique())])
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert_eq!(
+        tx.result.unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(GasServiceError::RefundAddressNotPayer as u32)
+        )
+    );
+    test_fixture
+        .send_tx(&[validate_payment(payer.pubkey())])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_policy_can_be_relaxed_again() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let payer = Keypair::new();
+    test_fixture
+        .fund_account(&payer.pubkey(), 1_000_000_000)
+        .await;
+    for policy in [RefundAddressPolicy::PayerOnly, RefundAddressPolicy::Any] {
+        let ix =
+            set_refund_address_policy_instruction(&gas_utils.operator.pubkey(), policy).unwrap();
+        send(&mut test_fixture, &gas_utils.operator, ix)
+            .await
+            .unwrap();
+    }
+
+    // Action
+    let res = send(
+        &mut test_fixture,
+        &payer,
+        pay_gas(&payer, Pubkey::new_unique()),
+    )
+    .await;
+
+    // Assert
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn test_fail_set_refund_address_policy_invalid_operator() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let payer = Keypair::new();
+    test_fixture
+        .fund_account(&payer.pubkey(), 1_000_000_000)
+        .await;
+
+    // Action
+    let wrong_operator = Keypair::new();
+    let ix = set_refund_address_policy_instruction(
+        &wrong_operator.pubkey(),
+        RefundAddressPolicy::PayerOnly,
+    )
+    .unwrap();
+    let result = send(&mut test_fixture, &wrong_operator, ix).await;
+
+    // Assert
+    assert!(result.is_err());
+    let config = test_fixture
+        .get_account(&gas_utils.config_pda, &axelar_solana_gas_service::ID)
+        .await;
+    assert_eq!(
+        Config::read(&config.data).unwrap().refund_address_policy(),
+        RefundAddressPolicy::Any
+    );
+}
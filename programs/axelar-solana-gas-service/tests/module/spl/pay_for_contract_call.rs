@@ -0,0 +1,266 @@
+use axelar_solana_gas_service::events::{GasCollectedEventV2, GasPaidEventV2};
+use axelar_solana_gateway_test_fixtures::base::TestFixture;
+use event_cpi_test_utils::assert_event_cpi;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::program_pack::Pack;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+use spl_token_2022::extension::StateWithExtensions;
+use spl_token_2022::state::{Account, Mint};
+
+const DECIMALS: u8 = 6;
+
+/// Creates a Token-2022 mint owned by the fixture payer.
+#[allow(clippy::disallowed_methods)]
+async fn create_mint(test_fixture: &mut TestFixture) -> Pubkey {
+    let mint = Keypair::new();
+    let rent = test_fixture.get_rent(Mint::LEN).await;
+    let payer = test_fixture.payer.pubkey();
+
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[
+                system_instruction::create_account(
+                    &payer,
+                    &mint.pubkey(),
+                    rent,
+                    u64::try_from(Mint::LEN).unwrap(),
+                    &spl_token_2022::ID,
+                ),
+                spl_token_2022::instruction::initialize_mint2(
+                    &spl_token_2022::ID,
+                    &mint.pubkey(),
+                    &payer,
+                    None,
+                    DECIMALS,
+                )
+                .unwrap(),
+            ],
+            &[&test_fixture.payer.insecure_clone(), &mint],
+        )
+        .await
+        .unwrap();
+
+    mint.pubkey()
+}
+
+/// Creates the ATA of `owner` for `mint` and mints `amount` tokens to it.
+async fn fund_token_account(
+    test_fixture: &mut TestFixture,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let payer = test_fixture.payer.pubkey();
+    let ata = get_associated_token_address_with_program_id(owner, mint, &spl_token_2022::ID);
+
+    let mut ixs = vec![create_associated_token_account_idempotent(
+        &payer,
+        owner,
+        mint,
+        &spl_token_2022::ID,
+    )];
+    if amount > 0 {
+        ixs.push(
+            spl_token_2022::instruction::mint_to(
+                &spl_token_2022::ID,
+                mint,
+                &ata,
+                &payer,
+                &[],
+                amount,
+            )
+            .unwrap(),
+        );
+    }
+    test_fixture.send_tx(&ixs).await.unwrap();
+
+    ata
+}
+
+async fn token_balance(test_fixture: &mut TestFixture, token_account: &Pubkey) -> u64 {
+    let account = test_fixture
+        .try_get_account_no_checks(token_account)
+        .await
+        .unwrap()
+        .unwrap();
+
+    StateWithExtensions::<Account>::unpack(&account.data)
+        .unwrap()
+        .base
+        .amount
+}
+
+#[tokio::test]
+#[allow(clippy::too_many_lines)]
+async fn test_pay_spl_for_contract_call() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let mint = create_mint(&mut test_fixture).await;
+    let sender = Keypair::new();
+    let sender_ata = fund_token_account(&mut test_fixture, &sender.pubkey(), &mint, 1_000).await;
+    let config_ata = fund_token_account(&mut test_fixture, &gas_utils.config_pda, &mint, 0).await;
+
+    // Action
+    let refund_address = Pubkey::new_unique();
+    let gas_amount = 250;
+    let destination_chain = "ethereum".to_owned();
+    let destination_addr = "destination addr 123".to_owned();
+    let payload_hash = [42; 32];
+    let ix = axelar_solana_gas_service::instructions::pay_spl_gas_instruction(
+        &sender.pubkey(),
+        &sender_ata,
+        &mint,
+        &spl_token_2022::ID,
+        destination_chain.clone(),
+        destination_addr.clone(),
+        payload_hash,
+        refund_address,
+        gas_amount,
+    )
+    .unwrap();
+
+    // First simulate to check events
+    let simulation_result = test_fixture
+        .simulate_tx_with_custom_signers(
+            &[ix.clone()],
+            &[&test_fixture.payer.insecure_clone(), &sender],
+        )
+        .await
+        .unwrap();
+
+    // Assert event emitted
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    assert!(!inner_ixs.is_empty());
+
+    let expected_event = GasPaidEventV2 {
+        sender: sender.pubkey(),
+        destination_chain,
+        destination_address: destination_addr,
+        payload_hash,
+        amount: gas_amount,
+        refund_address,
+        spl_token_account: Some(sender_ata),
+        mint: Some(mint),
+        linked_call_contract_index: None,
+        destination_gas_amount: None,
+        sequence: 0,
+    };
+
+    assert_event_cpi(&expected_event, &inner_ixs);
+
+    // Execute the transaction
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &sender])
+        .await
+        .unwrap();
+
+    // assert that the tokens get transferred
+    assert_eq!(
+        token_balance(&mut test_fixture, &sender_ata).await,
+        1_000 - gas_amount
+    );
+    assert_eq!(
+        token_balance(&mut test_fixture, &config_ata).await,
+        gas_amount
+    );
+
+    // The operator can collect the accrued tokens
+    let receiver = Keypair::new();
+    let receiver_ata = fund_token_account(&mut test_fixture, &receiver.pubkey(), &mint, 0).await;
+    let ix = axelar_solana_gas_service::instructions::collect_spl_fees_instruction(
+        &gas_utils.operator.pubkey(),
+        &receiver_ata,
+        &mint,
+        &spl_token_2022::ID,
+        gas_amount,
+    )
+    .unwrap();
+
+    let simulation_result = test_fixture
+        .simulate_tx_with_custom_signers(
+            &[ix.clone()],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+
+    let expected_event = GasCollectedEventV2 {
+        receiver: receiver.pubkey(),
+        amount: gas_amount,
+        spl_token_account: Some(receiver_ata),
+        mint: Some(mint),
+        sequence: 1,
+    };
+    assert_event_cpi(&expected_event, &inner_ixs);
+
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(token_balance(&mut test_fixture, &config_ata).await, 0);
+    assert_eq!(
+        token_balance(&mut test_fixture, &receiver_ata).await,
+        gas_amount
+    );
+}
+
+#[tokio::test]
+async fn fails_with_wrong_config_token_account() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let mint = create_mint(&mut test_fixture).await;
+    let sender = Keypair::new();
+    let sender_ata = fund_token_account(&mut test_fixture, &sender.pubkey(), &mint, 1_000).await;
+    let attacker = Keypair::new();
+    let attacker_ata = fund_token_account(&mut test_fixture, &attacker.pubkey(), &mint, 0).await;
+
+    // Action
+    let mut ix = axelar_solana_gas_service::instructions::pay_spl_gas_instruction(
+        &sender.pubkey(),
+        &sender_ata,
+        &mint,
+        &spl_token_2022::ID,
+        "ethereum".to_owned(),
+        "destination addr 123".to_owned(),
+        [42; 32],
+        Pubkey::new_unique(),
+        250,
+    )
+    .unwrap();
+    ix.accounts[4].pubkey = attacker_ata;
+
+    let res = test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &sender])
+        .await;
+    assert!(res.is_err());
+    assert_eq!(token_balance(&mut test_fixture, &attacker_ata).await, 0);
+}
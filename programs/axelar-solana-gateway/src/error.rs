@@ -1,10 +1,10 @@
 //! Error types
 
 use num_derive::{FromPrimitive, ToPrimitive};
-use num_traits::ToPrimitive;
 use solana_program::program_error::ProgramError;
 
-// Error codes 0-6 are recoverable, 6+ are irrecoverable
+// Error codes 0-5 are recoverable, 6+ are irrecoverable unless listed in
+// `GatewayError::should_relayer_proceed`
 const IRRECOVERABLE_ERROR: u32 = 6;
 
 /// Errors that may be returned by the Gateway program.
@@ -14,8 +14,8 @@ const IRRECOVERABLE_ERROR: u32 = 6;
 /// some other errors can be interpreted as "this action has already been executed".
 ///
 /// Because of this the errors are categorized as follows:
-/// "Already completed" errors (codes 0-6): Action has already been completed by another actor. Relayer can interpret as "assume that this action completed successfully".
-/// Irrecoverable errors (codes 7+): Action cannot be completed with the provided arguments.
+/// "Already completed" errors (codes 0-5, and the ones appended later like [`GatewayError::SigningSessionAlreadyValid`]): Action has already been completed by another actor. Relayer can interpret as "assume that this action completed successfully".
+/// Irrecoverable errors (codes 6+): Action cannot be completed with the provided arguments.
 #[repr(u32)]
 #[derive(Clone, Debug, Eq, thiserror::Error, FromPrimitive, ToPrimitive, PartialEq)]
 pub enum GatewayError {
@@ -138,16 +138,96 @@ pub enum GatewayError {
     /// Message domain separator does not match gateway domain separator.
     #[error("Invalid domain separator")]
     InvalidDomainSeparator,
+
+    /// The requested config account size is smaller than its current size.
+    #[error("Invalid config account size")]
+    InvalidConfigSize,
+
+    /// Message has not been executed yet.
+    #[error("Message not executed")]
+    MessageNotExecuted,
+
+    /// An execution receipt was already recorded for the message.
+    #[error("Execution receipt already recorded")]
+    ExecutionReceiptAlreadyRecorded,
+
+    /// The reported compute units are zero or above the transaction limit.
+    #[error("Invalid execution compute units")]
+    InvalidExecutionComputeUnits,
+
+    /// The event authority account is not the canonical, empty event-cpi PDA
+    /// of the gateway.
+    #[error("Invalid event authority")]
+    InvalidEventAuthority,
+
+    /// The destination chain of an outbound call is not in the chain
+    /// registry.
+    #[error("Destination chain not registered")]
+    DestinationChainNotRegistered,
+
+    /// The chain registry is full.
+    #[error("Too many registered chains")]
+    TooManyRegisteredChains,
+
+    /// The executable interface version of the deployed gateway is not
+    /// compatible with the one a destination program was compiled against.
+    #[error("Incompatible executable interface")]
+    IncompatibleExecutableInterface,
+
+    /// A gateway can only be initialized under a non-default namespace when
+    /// the program is built with the `namespaced-pdas` feature.
+    #[error("Namespaced PDAs are disabled")]
+    NamespacedPdasDisabled,
+
+    /// The message passed as a cancellation is not addressed to the gateway or
+    /// does not carry the cancellation payload of the cancelled message.
+    #[error("Invalid cancellation message")]
+    InvalidCancellationMessage,
+
+    /// A message can only be cancelled by a message from its own source chain
+    /// and source address.
+    #[error("Unauthorized message cancellation")]
+    UnauthorizedMessageCancellation,
+
+    /// The verifier set tracker is still within the retention window and
+    /// cannot be pruned.
+    #[error("Verifier set still retained")]
+    VerifierSetStillRetained,
+
+    /// The timelock of the pending operatorship recovery has not elapsed yet.
+    #[error("Operatorship recovery timelocked")]
+    OperatorshipRecoveryTimelocked,
+
+    /// The signature scheme of a verifier set is unknown, or a signer key
+    /// doesn't belong to it.
+    #[error("Invalid signature scheme")]
+    InvalidSignatureScheme,
+
+    /// The message was approved before execution receipts were introduced and
+    /// has no room to record one.
+    #[error("Execution receipt not supported")]
+    ExecutionReceiptNotSupported,
+
+    /// The signing session already reached the quorum, further signatures
+    /// are not verified.
+    #[error("Signing session already valid")]
+    SigningSessionAlreadyValid,
 }
 
 impl GatewayError {
     /// This is a utility function for the relayer when it's expecting the error for an unsuccessful transaction.
     #[must_use]
-    pub fn should_relayer_proceed(&self) -> bool {
-        let Some(error_num) = self.to_u32() else {
-            return false;
-        };
-        error_num < IRRECOVERABLE_ERROR
+    pub const fn should_relayer_proceed(&self) -> bool {
+        matches!(
+            self,
+            Self::SlotAlreadyVerified
+                | Self::MessageAlreadyInitialised
+                | Self::VerificationSessionPDAInitialised
+                | Self::VerifierSetTrackerAlreadyInitialised
+                | Self::MessagePayloadAlreadyInitialized
+                | Self::MessagePayloadAlreadyCommitted
+                | Self::SigningSessionAlreadyValid
+        )
     }
 }
 
@@ -162,23 +242,27 @@ impl From<GatewayError> for ProgramError {
 #[cfg(test)]
 mod tests {
     use itertools::Itertools;
-    use num_traits::FromPrimitive;
+    use num_traits::{FromPrimitive, ToPrimitive};
     use pretty_assertions::assert_eq;
 
     use super::*;
 
     #[test]
     fn test_should_relayer_proceed() {
-        let errors_to_proceed = (0..6).map_while(GatewayError::from_u32).collect_vec();
-        let errors_to_not_proceed = (6..u32::MAX)
+        let (appended_errors_to_proceed, errors_to_not_proceed): (Vec<_>, Vec<_>) = (6..u32::MAX)
+            .map_while(GatewayError::from_u32)
+            .partition(|error| *error == GatewayError::SigningSessionAlreadyValid);
+        let errors_to_proceed = (0..6)
             .map_while(GatewayError::from_u32)
+            .chain(appended_errors_to_proceed)
             .collect_vec();
 
         // confidence check that we derived the errors correctly
-        assert_eq!(errors_to_proceed.len(), 6);
-        assert_eq!(errors_to_not_proceed.len(), 23);
+        assert_eq!(errors_to_proceed.len(), 7);
+        assert_eq!(errors_to_not_proceed.len(), 38);
 
-        // Errors that should cause the relayer to proceed (error numbers < 6)
+        // Errors that should cause the relayer to proceed (error numbers < 6
+        // and the appended "already completed" errors)
         for error in errors_to_proceed {
             assert!(
                 error.should_relayer_proceed(),
@@ -188,7 +272,7 @@ mod tests {
             );
         }
 
-        // Errors that should NOT cause the relayer to proceed (error numbers >= 6)
+        // Errors that should NOT cause the relayer to proceed
         for error in errors_to_not_proceed {
             assert!(
                 !error.should_relayer_proceed(),
@@ -5,6 +5,10 @@ use axelar_message_primitives::U256;
 use event_cpi_macros::event;
 use solana_program::pubkey::Pubkey;
 
+pub mod batch;
+#[cfg(feature = "canonical-events")]
+pub mod canonical;
+
 /// Event emitted when a contract call is initiated.
 /// This event is emitted during the `call_contract` instruction.
 /// - `sender_key`: 32-byte Solana public key
@@ -38,6 +42,30 @@ pub struct VerifierSetRotatedEvent {
     pub verifier_set_hash: [u8; 32],
 }
 
+/// Event emitted alongside [`VerifierSetRotatedEvent`] with the details
+/// monitoring needs to assess a rotation.
+/// This event is emitted during the `rotate_signers` instruction.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifierSetRotationDetailsEvent {
+    /// The epoch of the verifier set that signed the rotation
+    pub previous_epoch: U256,
+    /// The epoch of the new verifier set
+    pub epoch: U256,
+    /// Hash of the verifier set that signed the rotation
+    pub previous_verifier_set_hash: [u8; 32],
+    /// Hash of the new verifier set
+    pub verifier_set_hash: [u8; 32],
+    /// The threshold of the new verifier set, if a leaf of it was proven
+    pub threshold: Option<u128>,
+    /// The number of signers in the new verifier set, if a leaf of it was
+    /// proven
+    pub signer_count: Option<u16>,
+    /// Whether the minimum rotation delay was enforced, i.e. the rotation was
+    /// not performed by the operator
+    pub rotation_delay_enforced: bool,
+}
+
 /// Event emitted when operatorship is transferred.
 /// This event is emitted during the `transfer_operatorship` instruction.
 #[event]
@@ -47,6 +75,30 @@ pub struct OperatorshipTransferredEvent {
     pub new_operator: Pubkey,
 }
 
+/// Event emitted when the upgrade authority initiates the recovery of the
+/// gateway operatorship.
+/// This event is emitted during the `recover_operatorship` instruction.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperatorshipRecoveryInitiatedEvent {
+    /// The operator the gateway is recovered to
+    pub new_operator: Pubkey,
+    /// The unix timestamp from which the recovery can be applied
+    pub eligible_at: u64,
+}
+
+/// Event emitted when a pending recovery of the gateway operatorship is
+/// applied.
+/// This event is emitted during the `recover_operatorship` instruction.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperatorshipRecoveredEvent {
+    /// The operator that was replaced
+    pub previous_operator: Pubkey,
+    /// The new operator's public key
+    pub new_operator: Pubkey,
+}
+
 /// Event emitted when a message is approved by the gateway.
 /// This event is emitted during the `approve_message` instruction.
 #[event]
@@ -68,6 +120,32 @@ pub struct MessageApprovedEvent {
     pub destination_chain: String,
 }
 
+/// Event emitted alongside [`MessageApprovedEvent`] with the time at which the
+/// message was approved.
+/// This event is emitted during the `approve_message` instruction.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageApprovalDetailsEvent {
+    /// The command ID for the message (32 bytes)
+    pub command_id: [u8; 32],
+    /// The slot in which the message was approved
+    pub approved_at_slot: u64,
+    /// The unix timestamp at which the message was approved
+    pub approved_at: i64,
+}
+
+/// Event emitted when the last message of a batch is approved.
+/// This event is emitted during the `approve_message` instruction, after the
+/// [`MessageApprovedEvent`] of that message.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchApprovedEvent {
+    /// The payload merkle root all messages of the batch were approved for
+    pub payload_merkle_root: [u8; 32],
+    /// The number of messages in the batch
+    pub message_count: u16,
+}
+
 /// Event emitted when a message is executed.
 /// This event is emitted during the `validate_message` instruction.
 #[event]
@@ -89,6 +167,157 @@ pub struct MessageExecutedEvent {
     pub destination_chain: String,
 }
 
+/// Event emitted when the execution receipt of a message is recorded.
+/// This event is emitted during the `record_execution_receipt` instruction.
+///
+/// Carries the fields of the `MessageExecutedEvent` along with the relayer
+/// that executed the message and the compute units it reported.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageExecutedEventV2 {
+    /// The command ID for the message (32 bytes)
+    pub command_id: [u8; 32],
+    /// The destination address where the message was delivered
+    pub destination_address: Pubkey,
+    /// Hash of the message payload
+    pub payload_hash: [u8; 32],
+    /// The source chain identifier
+    pub source_chain: String,
+    /// The command ID as string from the cross-chain ID
+    pub cc_id: String,
+    /// The source address that sent the message
+    pub source_address: String,
+    /// The destination chain identifier
+    pub destination_chain: String,
+    /// The relayer that executed the message
+    pub relayer: Pubkey,
+    /// Compute units consumed by the execution, as reported by the relayer
+    pub compute_units: u32,
+}
+
+/// Event emitted when the tracker of a verifier set that fell out of the
+/// retention window is closed.
+/// This event is emitted during the `prune_verifier_set_tracker` instruction.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifierSetTrackerPrunedEvent {
+    /// Hash of the pruned verifier set
+    pub verifier_set_hash: [u8; 32],
+    /// The epoch of the pruned verifier set
+    pub epoch: U256,
+    /// The epoch of the active verifier set at the time of pruning
+    pub current_epoch: U256,
+    /// The number of most recent epochs the gateway retains
+    pub retention: U256,
+}
+
+/// Event emitted when the event authority PDA of the gateway is verified.
+/// This event is emitted during the `initialize_config` and
+/// `verify_event_authority` instructions.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventAuthorityVerifiedEvent {
+    /// The event authority PDA that signs the event CPIs
+    pub event_authority: Pubkey,
+    /// The canonical bump of the event authority PDA
+    pub bump: u8,
+}
+
+/// Event emitted when the operator registers or unregisters a destination
+/// chain. This event is emitted during the `set_chain_registered` instruction.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainRegistrationSetEvent {
+    /// The name of the destination chain
+    pub chain_name: String,
+    /// Whether the chain is now registered
+    pub registered: bool,
+}
+
+/// Event emitted when an approved message is cancelled by its source.
+/// This event is emitted during the `cancel_message` instruction.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageCancelledEvent {
+    /// The command ID of the cancelled message
+    pub command_id: [u8; 32],
+    /// The command ID of the message that cancelled it
+    pub cancellation_command_id: [u8; 32],
+    /// The source chain identifier
+    pub source_chain: String,
+    /// The command ID as string from the cross-chain ID of the cancelled message
+    pub cc_id: String,
+    /// The source address that sent both messages
+    pub source_address: String,
+}
+
+/// Trace event emitted for every verified signature when the gateway is built
+/// with the `audit-trace` feature.
+/// This event is emitted during the `verify_signature` instruction.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureVerifiedTraceEvent {
+    /// The signature verification session PDA
+    pub verification_session: Pubkey,
+    /// The Merkle root of the signed payload
+    pub payload_merkle_root: [u8; 32],
+    /// The hash of the verifier set the signer belongs to
+    pub signing_verifier_set_hash: [u8; 32],
+    /// The position of the signer in the verifier set
+    pub signer_position: u16,
+    /// The weight of the signer
+    pub signer_weight: u128,
+    /// The weight of all the signatures verified in the session so far,
+    /// including this one
+    pub accumulated_weight: u128,
+    /// The weight required to reach the quorum
+    pub quorum: u128,
+}
+
+/// Trace event emitted for every approved message when the gateway is built
+/// with the `audit-trace` feature.
+/// This event is emitted during the `approve_message` instruction, after the
+/// `MessageApprovedEvent`.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageApprovalTraceEvent {
+    /// The command ID of the approved message
+    pub command_id: [u8; 32],
+    /// The signature verification session PDA that reached the quorum
+    pub verification_session: Pubkey,
+    /// The Merkle root of the signed payload
+    pub payload_merkle_root: [u8; 32],
+    /// The hash of the verifier set that signed the payload
+    pub signing_verifier_set_hash: [u8; 32],
+    /// The index of the message leaf in the payload Merkle tree
+    pub leaf_index: u16,
+    /// The number of messages signed in the payload
+    pub set_size: u16,
+    /// Bit field of the verifier set positions whose signatures were verified
+    /// in the session, least significant bit first
+    pub signature_slots: [u8; 32],
+}
+
+/// Trace event emitted for every validated message when the gateway is built
+/// with the `audit-trace` feature.
+/// This event is emitted during the `validate_message` instruction, after the
+/// `MessageExecutedEvent`.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageValidationTraceEvent {
+    /// The command ID of the validated message
+    pub command_id: [u8; 32],
+    /// The incoming message PDA
+    pub incoming_message: Pubkey,
+    /// The signing PDA of the destination program that validated the message
+    pub signing_pda: Pubkey,
+    /// The hash of the validated message
+    pub message_hash: [u8; 32],
+    /// The slot at which the message was approved, `0` for messages approved
+    /// before the approval slot was recorded
+    pub approved_at_slot: u64,
+}
+
 /// Represents the various events emitted by the Gateway.
 ///
 /// The `GatewayEvent` enum encapsulates all possible events that can be emitted by the Gateway.
@@ -106,21 +335,86 @@ pub enum GatewayEvent {
     /// Represents a `VerifierSetRotatedEvent` event.
     VerifierSetRotated(VerifierSetRotatedEvent),
 
+    /// Represents a `VerifierSetRotationDetails` event.
+    ///
+    /// This event is emitted after every `VerifierSetRotated` event with the
+    /// previous verifier set and the threshold and size of the new one.
+    VerifierSetRotationDetails(VerifierSetRotationDetailsEvent),
+
     /// Represents an `OperatorshipTransferred` event.
     ///
     /// This event is emitted when the operatorship is transferred to a new operator.
     /// It includes the public key of the new operator.
     OperatorshipTransferred(OperatorshipTransferredEvent),
 
+    /// Represents an `OperatorshipRecoveryInitiated` event.
+    ///
+    /// This event is emitted when the upgrade authority initiates the recovery of the operatorship.
+    OperatorshipRecoveryInitiated(OperatorshipRecoveryInitiatedEvent),
+
+    /// Represents an `OperatorshipRecovered` event.
+    ///
+    /// This event is emitted when a pending recovery of the operatorship is applied.
+    OperatorshipRecovered(OperatorshipRecoveredEvent),
+
     /// Represents a `MessageApproved` event.
     ///
     /// This event is emitted when a message is approved for execution by the Gateway.
     MessageApproved(MessageApprovedEvent),
 
+    /// Represents a `MessageApprovalDetails` event.
+    ///
+    /// This event is emitted after every `MessageApproved` event with the time of the approval.
+    MessageApprovalDetails(MessageApprovalDetailsEvent),
+    /// Represents a `BatchApproved` event.
+    ///
+    /// This event is emitted when all messages signed under a payload merkle root have been approved.
+    BatchApproved(BatchApprovedEvent),
+
     /// Represents a `MessageExecuted` event.
     ///
     /// This event is emitted when a message has been received & execution has begun on the destination contract.
     MessageExecuted(MessageExecutedEvent),
+
+    /// Represents a `MessageExecutedV2` event.
+    ///
+    /// This event is emitted when the relayer records the cost of executing a message.
+    MessageExecutedV2(MessageExecutedEventV2),
+
+    /// Represents an `EventAuthorityVerified` event.
+    ///
+    /// This event is emitted when the event authority PDA is checked to be usable for event CPIs.
+    EventAuthorityVerified(EventAuthorityVerifiedEvent),
+
+    /// Represents a `ChainRegistrationSet` event.
+    ///
+    /// This event is emitted when the operator updates the destination chain registry.
+    ChainRegistrationSet(ChainRegistrationSetEvent),
+
+    /// Represents a `MessageCancelled` event.
+    ///
+    /// This event is emitted when the source of an approved message cancels it before execution.
+    MessageCancelled(MessageCancelledEvent),
+
+    /// Represents a `VerifierSetTrackerPruned` event.
+    ///
+    /// This event is emitted when the tracker of a verifier set outside of the retention window is closed.
+    VerifierSetTrackerPruned(VerifierSetTrackerPrunedEvent),
+
+    /// Represents a `SignatureVerifiedTrace` event.
+    ///
+    /// This event is emitted in `audit-trace` builds when a signature of a verification session is verified.
+    SignatureVerifiedTrace(SignatureVerifiedTraceEvent),
+
+    /// Represents a `MessageApprovalTrace` event.
+    ///
+    /// This event is emitted in `audit-trace` builds when a message is approved.
+    MessageApprovalTrace(MessageApprovalTraceEvent),
+
+    /// Represents a `MessageValidationTrace` event.
+    ///
+    /// This event is emitted in `audit-trace` builds when a message is validated.
+    MessageValidationTrace(MessageValidationTraceEvent),
 }
 
 #[cfg(test)]
@@ -144,4 +438,34 @@ mod tests {
         let data = &data[..8];
         assert_eq!(data, CallContractEvent::DISCRIMINATOR);
     }
+
+    #[test]
+    fn test_discriminators_are_unique() {
+        let discriminators = [
+            CallContractEvent::DISCRIMINATOR,
+            VerifierSetRotatedEvent::DISCRIMINATOR,
+            VerifierSetRotationDetailsEvent::DISCRIMINATOR,
+            OperatorshipTransferredEvent::DISCRIMINATOR,
+            OperatorshipRecoveryInitiatedEvent::DISCRIMINATOR,
+            OperatorshipRecoveredEvent::DISCRIMINATOR,
+            MessageApprovedEvent::DISCRIMINATOR,
+            MessageApprovalDetailsEvent::DISCRIMINATOR,
+            BatchApprovedEvent::DISCRIMINATOR,
+            MessageExecutedEvent::DISCRIMINATOR,
+            MessageExecutedEventV2::DISCRIMINATOR,
+            EventAuthorityVerifiedEvent::DISCRIMINATOR,
+            ChainRegistrationSetEvent::DISCRIMINATOR,
+            MessageCancelledEvent::DISCRIMINATOR,
+            VerifierSetTrackerPrunedEvent::DISCRIMINATOR,
+            SignatureVerifiedTraceEvent::DISCRIMINATOR,
+            MessageApprovalTraceEvent::DISCRIMINATOR,
+            MessageValidationTraceEvent::DISCRIMINATOR,
+        ];
+
+        for (i, a) in discriminators.iter().enumerate() {
+            for b in discriminators.iter().skip(i.saturating_add(1)) {
+                assert_ne!(a, b);
+            }
+        }
+    }
 }
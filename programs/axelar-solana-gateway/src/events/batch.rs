@@ -0,0 +1,180 @@
+//! Batch approvals, derived off-chain from the approval instructions.
+//!
+//! The gateway emits a [`BatchApprovedEvent`](crate::events::BatchApprovedEvent)
+//! once the last message of a payload is approved, but the event only carries
+//! the merkle root and the message count. Indexers can feed the approval
+//! instructions of successful transactions to [`BatchApprovals`], which ties
+//! the command IDs of a payload to its merkle root once all its messages are
+//! approved.
+
+use std::collections::HashMap;
+
+use axelar_solana_encoding::hasher::SolanaSyscallHasher;
+use axelar_solana_encoding::types::messages::MessageLeaf;
+use axelar_solana_encoding::LeafHash;
+
+use crate::instructions::GatewayInstruction;
+use crate::state::incoming_message::command_id;
+
+/// Every message of a payload was approved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchApproved {
+    /// The payload merkle root all messages of the batch were approved for
+    pub payload_merkle_root: [u8; 32],
+    /// The command IDs of the messages, in their order within the payload
+    pub command_ids: Vec<[u8; 32]>,
+}
+
+/// Tracks the approved messages of every payload until the whole batch is.
+#[derive(Debug, Default)]
+pub struct BatchApprovals {
+    pending: HashMap<[u8; 32], Vec<Option<[u8; 32]>>>,
+}
+
+impl BatchApprovals {
+    /// Records the messages approved by a gateway instruction of a successful
+    /// transaction, returning the batches it completes. Instructions other
+    /// than the approvals are ignored.
+    #[allow(clippy::wildcard_enum_match_arm)]
+    pub fn record_instruction(&mut self, instruction: &GatewayInstruction) -> Vec<BatchApproved> {
+        match instruction {
+            GatewayInstruction::ApproveMessage {
+                message,
+                payload_merkle_root,
+            } => self
+                .record(*payload_merkle_root, &message.leaf)
+                .into_iter()
+                .collect(),
+            GatewayInstruction::ApproveSingleMessage { message } => self
+                .record(message.hash::<SolanaSyscallHasher>(), message)
+                .into_iter()
+                .collect(),
+            GatewayInstruction::ApproveMessagesBatch {
+                messages,
+                payload_merkle_root,
+            } => messages
+                .iter()
+                .filter_map(|message| self.record(*payload_merkle_root, &message.leaf))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Records the approval of a message leaf of the payload, returning the
+    /// batch once all its messages are approved. Leaves whose position is
+    /// outside of their set are rejected.
+    pub fn record(
+        &mut self,
+        payload_merkle_root: [u8; 32],
+        leaf: &MessageLeaf,
+    ) -> Option<BatchApproved> {
+        if leaf.position >= leaf.set_size {
+            return None;
+        }
+
+        let approvals = self
+            .pending
+            .entry(payload_merkle_root)
+            .or_insert_with(|| vec![None; leaf.set_size.into()]);
+        let approval = approvals.get_mut(usize::from(leaf.position))?;
+        *approval = Some(command_id(
+            &leaf.message.cc_id.chain,
+            &leaf.message.cc_id.id,
+        ));
+
+        if approvals.iter().any(Option::is_none) {
+            return None;
+        }
+
+        let command_ids = self
+            .pending
+            .remove(&payload_merkle_root)?
+            .into_iter()
+            .flatten()
+            .collect();
+        Some(BatchApproved {
+            payload_merkle_root,
+            command_ids,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axelar_solana_encoding::types::messages::{CrossChainId, Message};
+
+    use super::*;
+
+    fn leaf(position: u16, set_size: u16) -> MessageLeaf {
+        MessageLeaf {
+            message: Message {
+                cc_id: CrossChainId {
+                    chain: "ethereum".to_owned(),
+                    id: format!("0xabc-{position}"),
+                },
+                source_address: "0xsource".to_owned(),
+                destination_chain: "solana".to_owned(),
+                destination_address: "destination".to_owned(),
+                payload_hash: [1; 32],
+            },
+            position,
+            set_size,
+            domain_separator: [2; 32],
+        }
+    }
+
+    fn leaf_command_id(leaf: &MessageLeaf) -> [u8; 32] {
+        command_id(&leaf.message.cc_id.chain, &leaf.message.cc_id.id)
+    }
+
+    #[test]
+    fn test_batch_is_approved_with_its_last_message() {
+        let mut approvals = BatchApprovals::default();
+        let leaves = [leaf(0, 3), leaf(1, 3), leaf(2, 3)];
+
+        // Messages can be approved in any order
+        assert_eq!(approvals.record([7; 32], &leaves[2]), None);
+        assert_eq!(approvals.record([8; 32], &leaf(0, 2)), None);
+        assert_eq!(approvals.record([7; 32], &leaves[0]), None);
+        assert_eq!(
+            approvals.record([7; 32], &leaves[1]),
+            Some(BatchApproved {
+                payload_merkle_root: [7; 32],
+                command_ids: leaves.iter().map(leaf_command_id).collect(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_single_message_instruction_completes_its_payload() {
+        let mut approvals = BatchApprovals::default();
+        let single = leaf(0, 1);
+
+        let completed = approvals.record_instruction(&GatewayInstruction::ApproveSingleMessage {
+            message: single.clone(),
+        });
+
+        assert_eq!(
+            completed,
+            vec![BatchApproved {
+                payload_merkle_root: single.hash::<SolanaSyscallHasher>(),
+                command_ids: vec![leaf_command_id(&single)],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_position_is_ignored() {
+        let mut approvals = BatchApprovals::default();
+
+        assert_eq!(approvals.record([7; 32], &leaf(1, 1)), None);
+        assert_eq!(approvals.record([9; 32], &leaf(0, 0)), None);
+        assert!(approvals.pending.is_empty());
+        assert_eq!(
+            approvals
+                .record([7; 32], &leaf(0, 1))
+                .map(|batch| batch.command_ids),
+            Some(vec![leaf_command_id(&leaf(0, 1))])
+        );
+    }
+}
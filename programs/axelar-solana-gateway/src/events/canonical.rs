@@ -0,0 +1,712 @@
+//! Canonical serialized forms of the gateway events.
+//!
+//! Relayers written in other languages decode the gateway events from these
+//! forms, so they are specified here once and pinned by the test vectors in
+//! `tests/goldens/events.v1.json`:
+//!
+//! - **Borsh**: the 8 byte discriminator of the event followed by the Borsh
+//!   encoding of its fields, in declaration order. This is the data the
+//!   gateway emits through event CPI, after the event CPI instruction tag.
+//! - **JSON**: an object `{"version": 1, "event": <name>, "data": {...}}`
+//!   where `<name>` is the [`GatewayEvent`] variant and `data` holds every
+//!   field of the event under its Rust name. Public keys are base58 strings,
+//!   byte arrays are lowercase `0x` prefixed hex strings, integers wider than
+//!   32 bits are decimal strings so that no consumer loses precision,
+//!   narrower integers and booleans are JSON numbers and booleans, and absent
+//!   optional values are `null`. Values that aren't in this exact form,
+//!   unknown fields and other versions are rejected.
+//!
+//! A change to either form bumps [`CANONICAL_JSON_VERSION`] and gets its own
+//! set of test vectors.
+//!
+//! Only off-chain consumers need these forms, so the module is behind the
+//! `canonical-events` feature and the program doesn't link `serde_json`.
+
+use std::str::FromStr;
+
+use anchor_discriminators::Discriminator;
+use axelar_message_primitives::{BnumU256, U256};
+use event_cpi::CpiEvent;
+use serde_json::{json, Map, Value};
+use solana_program::pubkey::Pubkey;
+
+use super::{
+    BatchApprovedEvent, CallContractEvent, ChainRegistrationSetEvent, EventAuthorityVerifiedEvent,
+    GatewayEvent, MessageApprovalDetailsEvent, MessageApprovalTraceEvent, MessageApprovedEvent,
+    MessageCancelledEvent, MessageExecutedEvent, MessageExecutedEventV2,
+    MessageValidationTraceEvent, OperatorshipRecoveredEvent, OperatorshipRecoveryInitiatedEvent,
+    OperatorshipTransferredEvent, SignatureVerifiedTraceEvent, VerifierSetRotatedEvent,
+    VerifierSetRotationDetailsEvent, VerifierSetTrackerPrunedEvent,
+};
+
+/// The version of the canonical JSON form.
+pub const CANONICAL_JSON_VERSION: u8 = 1;
+
+/// Size of the discriminator prefixing the Borsh form.
+const DISCRIMINATOR_LEN: usize = 8;
+
+/// Errors that may occur while decoding the canonical forms of an event.
+#[derive(Debug, thiserror::Error)]
+pub enum CanonicalEventError {
+    /// The discriminator doesn't belong to any gateway event.
+    #[error("Unknown event discriminator")]
+    UnknownDiscriminator,
+
+    /// The data doesn't decode as the event its discriminator names.
+    #[error("Invalid Borsh encoding: {0}")]
+    Borsh(#[from] std::io::Error),
+
+    /// The JSON form has another version than [`CANONICAL_JSON_VERSION`].
+    #[error("Unsupported canonical JSON version {0}")]
+    UnsupportedVersion(u64),
+
+    /// The JSON form names an event the gateway doesn't emit.
+    #[error("Unknown event {0}")]
+    UnknownEvent(String),
+
+    /// A field of the JSON form is missing.
+    #[error("Missing field {0}")]
+    MissingField(&'static str),
+
+    /// The JSON form has a field the event doesn't have.
+    #[error("Unknown field {0}")]
+    UnknownField(String),
+
+    /// A value of the JSON form isn't in its canonical form.
+    #[error("Invalid value for {0}")]
+    InvalidValue(&'static str),
+}
+
+/// A value of an event field, in its canonical JSON form.
+trait CanonicalValue: Sized {
+    fn to_json(&self) -> Value;
+
+    fn from_json(value: &Value) -> Option<Self>;
+}
+
+impl CanonicalValue for Pubkey {
+    fn to_json(&self) -> Value {
+        Value::String(self.to_string())
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        canonical_str(value)
+    }
+}
+
+impl CanonicalValue for [u8; 32] {
+    fn to_json(&self) -> Value {
+        hex_value(self)
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        Vec::from_json(value)?.try_into().ok()
+    }
+}
+
+impl CanonicalValue for Vec<u8> {
+    fn to_json(&self) -> Value {
+        hex_value(self)
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        let bytes = hex::decode(value.as_str()?.strip_prefix("0x")?).ok()?;
+        (hex_value(&bytes) == *value).then_some(bytes)
+    }
+}
+
+impl CanonicalValue for String {
+    fn to_json(&self) -> Value {
+        Value::String(self.clone())
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        value.as_str().map(str::to_owned)
+    }
+}
+
+impl CanonicalValue for bool {
+    fn to_json(&self) -> Value {
+        Value::Bool(*self)
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        value.as_bool()
+    }
+}
+
+impl CanonicalValue for U256 {
+    fn to_json(&self) -> Value {
+        Value::String(self.to_string())
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        let parsed = BnumU256::from_str_radix(value.as_str()?, 10).ok()?;
+        let parsed = Self::from_le_bytes(bytemuck::cast(*parsed.digits()));
+        (parsed.to_json() == *value).then_some(parsed)
+    }
+}
+
+impl<T: CanonicalValue> CanonicalValue for Option<T> {
+    fn to_json(&self) -> Value {
+        self.as_ref().map_or(Value::Null, T::to_json)
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        if value.is_null() {
+            return Some(None);
+        }
+
+        T::from_json(value).map(Some)
+    }
+}
+
+/// Integers encoded as JSON numbers.
+macro_rules! number_values {
+    ($($int:ty),*) => {
+        $(
+            impl CanonicalValue for $int {
+                fn to_json(&self) -> Value {
+                    Value::from(*self)
+                }
+
+                fn from_json(value: &Value) -> Option<Self> {
+                    value.as_u64()?.try_into().ok()
+                }
+            }
+        )*
+    };
+}
+
+number_values!(u8, u16, u32);
+
+/// Integers encoded as decimal strings.
+macro_rules! decimal_values {
+    ($($int:ty),*) => {
+        $(
+            impl CanonicalValue for $int {
+                fn to_json(&self) -> Value {
+                    Value::String(self.to_string())
+                }
+
+                fn from_json(value: &Value) -> Option<Self> {
+                    canonical_str(value)
+                }
+            }
+        )*
+    };
+}
+
+decimal_values!(u64, i64, u128);
+
+fn hex_value(bytes: &[u8]) -> Value {
+    Value::String(format!("0x{}", hex::encode(bytes)))
+}
+
+/// Parses a string value, which must be the one `T` displays as.
+fn canonical_str<T: FromStr + ToString>(value: &Value) -> Option<T> {
+    let value = value.as_str()?;
+    let parsed = value.parse::<T>().ok()?;
+    (parsed.to_string() == value).then_some(parsed)
+}
+
+fn reject_unknown_fields(
+    object: &Map<String, Value>,
+    fields: &[&str],
+) -> Result<(), CanonicalEventError> {
+    match object.keys().find(|key| !fields.contains(&key.as_str())) {
+        Some(key) => Err(CanonicalEventError::UnknownField(key.clone())),
+        None => Ok(()),
+    }
+}
+
+/// Implements the canonical forms of the events, listing every field of each
+/// event. Leaving a field out fails to compile.
+macro_rules! canonical_events {
+    ($($variant:ident($event:ident) { $($field:ident),* $(,)? })*) => {
+        $(
+            impl $event {
+                fn to_json_data(&self) -> Value {
+                    let mut data = Map::new();
+                    $(data.insert(stringify!($field).to_owned(), self.$field.to_json());)*
+                    Value::Object(data)
+                }
+
+                fn from_json_data(data: &Value) -> Result<Self, CanonicalEventError> {
+                    let data = data
+                        .as_object()
+                        .ok_or(CanonicalEventError::InvalidValue("data"))?;
+                    reject_unknown_fields(data, &[$(stringify!($field)),*])?;
+
+                    Ok(Self {
+                        $(
+                            $field: CanonicalValue::from_json(
+                                data.get(stringify!($field))
+                                    .ok_or(CanonicalEventError::MissingField(stringify!($field)))?,
+                            )
+                            .ok_or(CanonicalEventError::InvalidValue(stringify!($field)))?,
+                        )*
+                    })
+                }
+            }
+        )*
+
+        impl GatewayEvent {
+            /// The name of the event in the canonical JSON form.
+            #[must_use]
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant(_) => stringify!($variant),)*
+                }
+            }
+
+            /// Encodes the event in its canonical Borsh form.
+            #[must_use]
+            pub fn to_borsh(&self) -> Vec<u8> {
+                match self {
+                    $(Self::$variant(event) => event.data(),)*
+                }
+            }
+
+            /// Decodes an event from its canonical Borsh form.
+            ///
+            /// # Errors
+            ///
+            /// - [`CanonicalEventError::UnknownDiscriminator`] if the data
+            ///   doesn't start with the discriminator of a gateway event.
+            /// - [`CanonicalEventError::Borsh`] if the rest of the data isn't
+            ///   exactly the encoding of the event.
+            pub fn from_borsh(data: &[u8]) -> Result<Self, CanonicalEventError> {
+                let (discriminator, data) = data
+                    .split_at_checked(DISCRIMINATOR_LEN)
+                    .ok_or(CanonicalEventError::UnknownDiscriminator)?;
+
+                $(
+                    if discriminator == $event::DISCRIMINATOR {
+                        return Ok(Self::$variant(borsh::from_slice(data)?));
+                    }
+                )*
+
+                Err(CanonicalEventError::UnknownDiscriminator)
+            }
+
+            /// Encodes the event in its canonical JSON form.
+            #[must_use]
+            pub fn to_json(&self) -> Value {
+                let data = match self {
+                    $(Self::$variant(event) => event.to_json_data(),)*
+                };
+
+                json!({
+                    "version": CANONICAL_JSON_VERSION,
+                    "event": self.name(),
+                    "data": data,
+                })
+            }
+
+            /// Decodes an event from its canonical JSON form.
+            ///
+            /// # Errors
+            ///
+            /// If the value isn't the canonical JSON form of a gateway event
+            /// in the current [`CANONICAL_JSON_VERSION`].
+            pub fn from_json(value: &Value) -> Result<Self, CanonicalEventError> {
+                let object = value
+                    .as_object()
+                    .ok_or(CanonicalEventError::InvalidValue("event"))?;
+                reject_unknown_fields(object, &["version", "event", "data"])?;
+
+                let version = object
+                    .get("version")
+                    .ok_or(CanonicalEventError::MissingField("version"))?
+                    .as_u64()
+                    .ok_or(CanonicalEventError::InvalidValue("version"))?;
+                if version != u64::from(CANONICAL_JSON_VERSION) {
+                    return Err(CanonicalEventError::UnsupportedVersion(version));
+                }
+
+                let name = object
+                    .get("event")
+                    .ok_or(CanonicalEventError::MissingField("event"))?
+                    .as_str()
+                    .ok_or(CanonicalEventError::InvalidValue("event"))?;
+                let data = object
+                    .get("data")
+                    .ok_or(CanonicalEventError::MissingField("data"))?;
+
+                $(
+                    if name == stringify!($variant) {
+                        return $event::from_json_data(data).map(Self::$variant);
+                    }
+                )*
+
+                Err(CanonicalEventError::UnknownEvent(name.to_owned()))
+            }
+        }
+    };
+}
+
+canonical_events! {
+    CallContract(CallContractEvent) {
+        sender,
+        payload_hash,
+        destination_chain,
+        destination_contract_address,
+        payload,
+    }
+    VerifierSetRotated(VerifierSetRotatedEvent) {
+        epoch,
+        verifier_set_hash,
+    }
+    VerifierSetRotationDetails(VerifierSetRotationDetailsEvent) {
+        previous_epoch,
+        epoch,
+        previous_verifier_set_hash,
+        verifier_set_hash,
+        threshold,
+        signer_count,
+        rotation_delay_enforced,
+    }
+    OperatorshipTransferred(OperatorshipTransferredEvent) {
+        new_operator,
+    }
+    OperatorshipRecoveryInitiated(OperatorshipRecoveryInitiatedEvent) {
+        new_operator,
+        eligible_at,
+    }
+    OperatorshipRecovered(OperatorshipRecoveredEvent) {
+        previous_operator,
+        new_operator,
+    }
+    MessageApproved(MessageApprovedEvent) {
+        command_id,
+        destination_address,
+        payload_hash,
+        source_chain,
+        cc_id,
+        source_address,
+        destination_chain,
+    }
+    MessageApprovalDetails(MessageApprovalDetailsEvent) {
+        command_id,
+        approved_at_slot,
+        approved_at,
+    }
+    BatchApproved(BatchApprovedEvent) {
+        payload_merkle_root,
+        message_count,
+    }
+    MessageExecuted(MessageExecutedEvent) {
+        command_id,
+        destination_address,
+        payload_hash,
+        source_chain,
+        cc_id,
+        source_address,
+        destination_chain,
+    }
+    MessageExecutedV2(MessageExecutedEventV2) {
+        command_id,
+        destination_address,
+        payload_hash,
+        source_chain,
+        cc_id,
+        source_address,
+        destination_chain,
+        relayer,
+        compute_units,
+    }
+    EventAuthorityVerified(EventAuthorityVerifiedEvent) {
+        event_authority,
+        bump,
+    }
+    ChainRegistrationSet(ChainRegistrationSetEvent) {
+        chain_name,
+        registered,
+    }
+    MessageCancelled(MessageCancelledEvent) {
+        command_id,
+        cancellation_command_id,
+        source_chain,
+        cc_id,
+        source_address,
+    }
+    VerifierSetTrackerPruned(VerifierSetTrackerPrunedEvent) {
+        verifier_set_hash,
+        epoch,
+        current_epoch,
+        retention,
+    }
+    SignatureVerifiedTrace(SignatureVerifiedTraceEvent) {
+        verification_session,
+        payload_merkle_root,
+        signing_verifier_set_hash,
+        signer_position,
+        signer_weight,
+        accumulated_weight,
+        quorum,
+    }
+    MessageApprovalTrace(MessageApprovalTraceEvent) {
+        command_id,
+        verification_session,
+        payload_merkle_root,
+        signing_verifier_set_hash,
+        leaf_index,
+        set_size,
+        signature_slots,
+    }
+    MessageValidationTrace(MessageValidationTraceEvent) {
+        command_id,
+        incoming_message,
+        signing_pda,
+        message_hash,
+        approved_at_slot,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BatchApprovedEvent, CallContractEvent, ChainRegistrationSetEvent,
+        EventAuthorityVerifiedEvent, GatewayEvent, MessageApprovalDetailsEvent,
+        MessageApprovalTraceEvent, MessageApprovedEvent, MessageCancelledEvent,
+        MessageExecutedEvent, MessageExecutedEventV2, MessageValidationTraceEvent,
+        OperatorshipRecoveredEvent, OperatorshipRecoveryInitiatedEvent,
+        OperatorshipTransferredEvent, SignatureVerifiedTraceEvent, VerifierSetRotatedEvent,
+        VerifierSetRotationDetailsEvent, VerifierSetTrackerPrunedEvent,
+    };
+    use anchor_discriminators::Discriminator;
+
+    const SOURCE_ADDRESS: &str = "0x4F4495243837681061C4743b74B3eEdf548D56A5";
+
+    /// The events of the version 1 test vectors, in order.
+    fn events() -> Vec<GatewayEvent> {
+        let key = |byte: u8| Pubkey::new_from_array([byte; 32]);
+
+        vec![
+            GatewayEvent::CallContract(CallContractEvent {
+                sender: key(1),
+                payload_hash: [2; 32],
+                destination_chain: "ethereum".to_owned(),
+                destination_contract_address: SOURCE_ADDRESS.to_owned(),
+                payload: vec![0xde, 0xad, 0xbe, 0xef],
+            }),
+            GatewayEvent::VerifierSetRotated(VerifierSetRotatedEvent {
+                epoch: U256::from_le_bytes([0xff; 32]),
+                verifier_set_hash: [3; 32],
+            }),
+            GatewayEvent::VerifierSetRotationDetails(VerifierSetRotationDetailsEvent {
+                previous_epoch: U256::from_u64(1),
+                epoch: U256::from_u64(2),
+                previous_verifier_set_hash: [4; 32],
+                verifier_set_hash: [3; 32],
+                threshold: Some(100_000_000_000_000_000_000),
+                signer_count: Some(3),
+                rotation_delay_enforced: true,
+            }),
+            GatewayEvent::OperatorshipTransferred(OperatorshipTransferredEvent {
+                new_operator: key(5),
+            }),
+            GatewayEvent::OperatorshipRecoveryInitiated(OperatorshipRecoveryInitiatedEvent {
+                new_operator: key(5),
+                eligible_at: 1_700_000_000,
+            }),
+            GatewayEvent::OperatorshipRecovered(OperatorshipRecoveredEvent {
+                previous_operator: key(6),
+                new_operator: key(5),
+            }),
+            GatewayEvent::MessageApproved(MessageApprovedEvent {
+                command_id: [7; 32],
+                destination_address: key(8),
+                payload_hash: [9; 32],
+                source_chain: "ethereum".to_owned(),
+                cc_id: "0xabc-1".to_owned(),
+                source_address: SOURCE_ADDRESS.to_owned(),
+                destination_chain: "solana".to_owned(),
+            }),
+            GatewayEvent::MessageApprovalDetails(MessageApprovalDetailsEvent {
+                command_id: [7; 32],
+                approved_at_slot: 42,
+                approved_at: 1_700_000_000,
+            }),
+            GatewayEvent::BatchApproved(BatchApprovedEvent {
+                payload_merkle_root: [10; 32],
+                message_count: 3,
+            }),
+            GatewayEvent::MessageExecuted(MessageExecutedEvent {
+                command_id: [7; 32],
+                destination_address: key(8),
+                payload_hash: [9; 32],
+                source_chain: "ethereum".to_owned(),
+                cc_id: "0xabc-1".to_owned(),
+                source_address: SOURCE_ADDRESS.to_owned(),
+                destination_chain: "solana".to_owned(),
+            }),
+            GatewayEvent::MessageExecutedV2(MessageExecutedEventV2 {
+                command_id: [7; 32],
+                destination_address: key(8),
+                payload_hash: [9; 32],
+                source_chain: "ethereum".to_owned(),
+                cc_id: "0xabc-1".to_owned(),
+                source_address: SOURCE_ADDRESS.to_owned(),
+                destination_chain: "solana".to_owned(),
+                relayer: key(11),
+                compute_units: 200_000,
+            }),
+            GatewayEvent::EventAuthorityVerified(EventAuthorityVerifiedEvent {
+                event_authority: key(12),
+                bump: 255,
+            }),
+            GatewayEvent::ChainRegistrationSet(ChainRegistrationSetEvent {
+                chain_name: "ethereum".to_owned(),
+                registered: true,
+            }),
+            GatewayEvent::MessageCancelled(MessageCancelledEvent {
+                command_id: [7; 32],
+                cancellation_command_id: [13; 32],
+                source_chain: "ethereum".to_owned(),
+                cc_id: "0xabc-1".to_owned(),
+                source_address: SOURCE_ADDRESS.to_owned(),
+            }),
+            GatewayEvent::VerifierSetTrackerPruned(VerifierSetTrackerPrunedEvent {
+                verifier_set_hash: [4; 32],
+                epoch: U256::from_u64(1),
+                current_epoch: U256::from_u64(6),
+                retention: U256::from_u64(5),
+            }),
+            GatewayEvent::SignatureVerifiedTrace(SignatureVerifiedTraceEvent {
+                verification_session: key(14),
+                payload_merkle_root: [10; 32],
+                signing_verifier_set_hash: [4; 32],
+                signer_position: 1,
+                signer_weight: 1,
+                accumulated_weight: 2,
+                quorum: 2,
+            }),
+            GatewayEvent::MessageApprovalTrace(MessageApprovalTraceEvent {
+                command_id: [7; 32],
+                verification_session: key(14),
+                payload_merkle_root: [10; 32],
+                signing_verifier_set_hash: [4; 32],
+                leaf_index: 0,
+                set_size: 3,
+                signature_slots: [0b11; 32],
+            }),
+            GatewayEvent::MessageValidationTrace(MessageValidationTraceEvent {
+                command_id: [7; 32],
+                incoming_message: key(15),
+                signing_pda: key(16),
+                message_hash: [17; 32],
+                approved_at_slot: 42,
+            }),
+        ]
+    }
+
+    /// The version 1 test vectors: the canonical forms of [`events`].
+    fn vectors() -> Vec<(Vec<u8>, Value)> {
+        let vectors: Value =
+            serde_json::from_str(include_str!("../../tests/goldens/events.v1.json")).unwrap();
+
+        vectors
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|vector| {
+                let borsh = vector["borsh"]
+                    .as_str()
+                    .unwrap()
+                    .strip_prefix("0x")
+                    .unwrap();
+                (hex::decode(borsh).unwrap(), vector["json"].clone())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_vectors_cover_every_event() {
+        let mut names: Vec<_> = events().iter().map(GatewayEvent::name).collect();
+        names.sort_unstable();
+        names.dedup();
+
+        assert_eq!(names.len(), 18);
+        assert_eq!(names.len(), vectors().len());
+    }
+
+    #[test]
+    fn test_events_encode_to_the_vectors() {
+        for (event, (borsh, json)) in events().iter().zip(vectors()) {
+            assert_eq!(event.to_borsh(), borsh, "{}", event.name());
+            assert_eq!(event.to_json(), json, "{}", event.name());
+        }
+    }
+
+    #[test]
+    fn test_vectors_decode_to_the_events() {
+        for (event, (borsh, json)) in events().into_iter().zip(vectors()) {
+            assert_eq!(GatewayEvent::from_borsh(&borsh).unwrap(), event);
+            assert_eq!(GatewayEvent::from_json(&json).unwrap(), event);
+        }
+    }
+
+    #[test]
+    fn test_other_versions_are_rejected() {
+        let mut json = events()[0].to_json();
+        json["version"] = Value::from(2);
+
+        assert!(matches!(
+            GatewayEvent::from_json(&json),
+            Err(CanonicalEventError::UnsupportedVersion(2))
+        ));
+    }
+
+    #[test]
+    fn test_non_canonical_json_is_rejected() {
+        let json = events()[1].to_json();
+
+        let mut unknown_field = json.clone();
+        unknown_field["data"]["extra"] = Value::Bool(true);
+        assert!(matches!(
+            GatewayEvent::from_json(&unknown_field),
+            Err(CanonicalEventError::UnknownField(_))
+        ));
+
+        let mut missing_field = json.clone();
+        missing_field["data"]
+            .as_object_mut()
+            .unwrap()
+            .remove("epoch");
+        assert!(matches!(
+            GatewayEvent::from_json(&missing_field),
+            Err(CanonicalEventError::MissingField("epoch"))
+        ));
+
+        for (field, value) in [
+            ("epoch", json!("0001")),
+            ("epoch", json!(1)),
+            ("verifier_set_hash", json!(format!("0x{}", "AB".repeat(32)))),
+            ("verifier_set_hash", json!(format!("0x{}", "ab".repeat(31)))),
+        ] {
+            let mut invalid = json.clone();
+            invalid["data"][field] = value;
+            assert!(matches!(
+                GatewayEvent::from_json(&invalid),
+                Err(CanonicalEventError::InvalidValue(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_trailing_borsh_data_is_rejected() {
+        let mut borsh = events()[0].to_borsh();
+        borsh.push(0);
+
+        assert!(matches!(
+            GatewayEvent::from_borsh(&borsh),
+            Err(CanonicalEventError::Borsh(_))
+        ));
+        assert!(matches!(
+            GatewayEvent::from_borsh(&[0; 8]),
+            Err(CanonicalEventError::UnknownDiscriminator)
+        ));
+    }
+}
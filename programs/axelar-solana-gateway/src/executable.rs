@@ -4,16 +4,19 @@ use crate::error::GatewayError;
 use crate::state::incoming_message::{command_id, IncomingMessage};
 use crate::state::message_payload::ImmutMessagePayload;
 use crate::{
-    create_message_payload_pda, get_gateway_root_config_pda, get_validate_message_signing_pda,
-    BytemuckedPda,
+    create_incoming_message_pda, create_message_payload_pda, get_gateway_root_config_pda,
+    get_validate_message_signing_pda,
 };
+use axelar_solana_encoding::hasher::SolanaSyscallHasher;
 use axelar_solana_encoding::types::messages::Message;
+use axelar_solana_encoding::LeafHash;
+use borsh::{BorshDeserialize, BorshSerialize};
 use core::str::FromStr;
 use solana_program::account_info::{next_account_info, AccountInfo};
 use solana_program::entrypoint::ProgramResult;
 use solana_program::instruction::{AccountMeta, Instruction};
 use solana_program::msg;
-use solana_program::program::invoke_signed;
+use solana_program::program::{get_return_data, invoke, invoke_signed};
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 
@@ -25,10 +28,57 @@ pub use axelar_payload::{
 /// Axelar executable command prefix
 pub const AXELAR_EXECUTE: &[u8; 16] = b"axelar-execute__";
 
+/// Prefix of the instructions the gateway sends to a destination program
+/// through `ExecuteAndCall`
+pub const AXELAR_GATEWAY_CALL: &[u8; 16] = b"axelar-gw-call__";
+
 /// The index of the first account that is expected to be passed to the
 /// destination program.
 pub const PROGRAM_ACCOUNTS_START_INDEX: usize = 7;
 
+/// The index of the first account that is expected to be passed to the
+/// destination program when it is called by the gateway through
+/// `ExecuteAndCall`.
+pub const GATEWAY_CALL_ACCOUNTS_START_INDEX: usize = 1;
+
+/// Version of the executable interface (the account layouts, instruction
+/// prefixes and validation CPIs defined in this module) that this crate was
+/// compiled against.
+///
+/// The minor version is bumped for backwards compatible additions, the major
+/// version for breaking changes.
+pub const EXECUTABLE_INTERFACE_VERSION: ExecutableInterfaceVersion =
+    ExecutableInterfaceVersion { major: 1, minor: 0 };
+
+/// Version of the executable interface exposed by a gateway, see
+/// [`EXECUTABLE_INTERFACE_VERSION`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ExecutableInterfaceVersion {
+    /// Incremented on breaking changes
+    pub major: u16,
+    /// Incremented on backwards compatible additions
+    pub minor: u16,
+}
+
+impl ExecutableInterfaceVersion {
+    /// Whether a destination program compiled against `self` can be executed
+    /// by a gateway exposing the `deployed` version.
+    #[must_use]
+    pub const fn is_compatible_with(self, deployed: Self) -> bool {
+        self.major == deployed.major && deployed.minor >= self.minor
+    }
+}
+
+/// A message executed by the gateway on behalf of the destination program,
+/// along with the payload stripped from its accounts.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct GatewayCall {
+    /// The executed message
+    pub message: Message,
+    /// The payload of the message, without the encoded accounts
+    pub payload: Vec<u8>,
+}
+
 /// Perform CPI call to the Axelar Gateway to ensure that the given message is
 /// approved.
 ///
@@ -66,7 +116,7 @@ pub fn validate_message(accounts: &[AccountInfo<'_>], message: &Message) -> Prog
         }
 
         let incoming_message_data = incoming_message_pda.try_borrow_data()?;
-        let incoming_message = IncomingMessage::read(&incoming_message_data)
+        let incoming_message = IncomingMessage::read_versioned(&incoming_message_data)
             .ok_or(GatewayError::BytemuckDataLenInvalid)?;
         incoming_message_payload_hash = incoming_message.payload_hash;
         incoming_message.signing_pda_bump
@@ -153,7 +203,7 @@ pub fn validate_with_gmp_metadata(
     let signing_pda_bump = {
         // scope to release the account after reading the data we want
         let incoming_message_data = incoming_message_pda.try_borrow_data()?;
-        let incoming_message = IncomingMessage::read(&incoming_message_data)
+        let incoming_message = IncomingMessage::read_versioned(&incoming_message_data)
             .ok_or(GatewayError::BytemuckDataLenInvalid)?;
         incoming_message.signing_pda_bump
     };
@@ -246,6 +296,99 @@ fn validate_message_internal(
     Ok(())
 }
 
+/// Ensures that a [`GatewayCall`] was sent by the gateway through
+/// `ExecuteAndCall`, which already validated and executed the message.
+///
+/// Destination programs called this way must not CPI back into the gateway,
+/// as Solana does not allow reentrancy.
+///
+/// Expected accounts:
+/// 0. `gateway_incoming_message` - `IncomingMessage` PDA, signed by the gateway
+/// N. accounts provided in the payload
+///
+/// # Errors
+/// - if not enough accounts were provided
+/// - if the incoming message is not the gateway signed PDA of the message
+/// - if the message has not been executed
+pub fn validate_gateway_call(accounts: &[AccountInfo<'_>], message: &Message) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let incoming_message_pda = next_account_info(accounts_iter)?;
+
+    // Check: Incoming Message account is owned by the Gateway and signed by it
+    if incoming_message_pda.owner != &crate::ID {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    if !incoming_message_pda.is_signer {
+        msg!("Incoming message PDA must be signed by the gateway");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let incoming_message_data = incoming_message_pda.try_borrow_data()?;
+    let incoming_message = IncomingMessage::read_versioned(&incoming_message_data)
+        .ok_or(GatewayError::BytemuckDataLenInvalid)?;
+
+    // Check: the incoming message PDA belongs to the given message
+    let command_id = command_id(&message.cc_id.chain, &message.cc_id.id);
+    if create_incoming_message_pda(command_id, incoming_message.bump)? != *incoming_message_pda.key
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if incoming_message.message_hash != message.hash::<SolanaSyscallHasher>() {
+        return Err(GatewayError::MessageHasBeenTamperedWith.into());
+    }
+    if !incoming_message.status.is_executed() {
+        return Err(GatewayError::MessageNotExecuted.into());
+    }
+
+    Ok(())
+}
+
+/// Queries the executable interface version of the deployed gateway and
+/// ensures it is compatible with [`EXECUTABLE_INTERFACE_VERSION`].
+///
+/// Meant to be called by destination programs while they are initialized, so
+/// that a program built against an incompatible gateway crate can't be set
+/// up.
+///
+/// # Errors
+/// - if `gateway_program` is not the gateway program account
+/// - if the CPI to the gateway failed or returned no version
+/// - if the deployed version is not compatible
+pub fn ensure_compatible_gateway(gateway_program: &AccountInfo<'_>) -> ProgramResult {
+    if gateway_program.key != &crate::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    invoke(
+        &crate::instructions::get_executable_interface_version()?,
+        &[gateway_program.clone()],
+    )?;
+
+    let deployed = match get_return_data() {
+        Some((program_id, data)) if program_id == crate::ID => {
+            ExecutableInterfaceVersion::try_from_slice(&data)
+                .map_err(|borsh_error| ProgramError::BorshIoError(borsh_error.to_string()))?
+        }
+        _ => {
+            msg!("Gateway did not return its executable interface version");
+            return Err(ProgramError::InvalidAccountData);
+        }
+    };
+
+    if !EXECUTABLE_INTERFACE_VERSION.is_compatible_with(deployed) {
+        msg!(
+            "Compiled against executable interface {}.{}, gateway exposes {}.{}",
+            EXECUTABLE_INTERFACE_VERSION.major,
+            EXECUTABLE_INTERFACE_VERSION.minor,
+            deployed.major,
+            deployed.minor
+        );
+        return Err(GatewayError::IncompatibleExecutableInterface.into());
+    }
+
+    Ok(())
+}
+
 /// # Create a generic `Execute` instruction
 ///
 /// Intended to be used by the relayer when it is about to call the
@@ -323,6 +466,40 @@ fn serialize_message(message: &Message) -> Result<Vec<u8>, ProgramError> {
     Ok(buffer)
 }
 
+/// Serializes a [`GatewayCall`] prefixed by [`AXELAR_GATEWAY_CALL`].
+pub(crate) fn serialize_gateway_call(call: &GatewayCall) -> Result<Vec<u8>, ProgramError> {
+    let mut buffer = Vec::with_capacity(
+        AXELAR_GATEWAY_CALL
+            .len()
+            .saturating_add(256)
+            .saturating_add(call.payload.len()),
+    );
+    buffer.extend_from_slice(AXELAR_GATEWAY_CALL);
+    borsh::to_writer(&mut buffer, call)
+        .map_err(|borsh_error| ProgramError::BorshIoError(borsh_error.to_string()))?;
+    Ok(buffer)
+}
+
+/// Tries to parse input into a [`GatewayCall`] sent by the gateway through
+/// `ExecuteAndCall`.
+///
+/// # Errors
+/// Will return a `ProgramError::BorshIoError` if parsing fails.
+#[allow(clippy::indexing_slicing)]
+#[must_use]
+pub fn parse_gateway_call(input: &[u8]) -> Option<Result<GatewayCall, ProgramError>> {
+    // Same pre-parsing check as for `parse_axelar_message`
+    if !input.starts_with(AXELAR_GATEWAY_CALL) {
+        return None;
+    }
+
+    // Slicing: we already checked that slice's lower bound above.
+    Some(
+        borsh::from_slice(&input[AXELAR_GATEWAY_CALL.len()..])
+            .map_err(|borsh_error| ProgramError::BorshIoError(borsh_error.to_string())),
+    )
+}
+
 /// Tries to parse input into an Axelar's message.
 ///
 /// # Errors
@@ -357,4 +534,37 @@ mod tests {
         let deserialized = parse_axelar_message(&serialized).unwrap().unwrap();
         assert_eq!(message, deserialized);
     }
+
+    #[test]
+    fn test_gateway_call_serialization() {
+        let call = GatewayCall {
+            message: random_message(),
+            payload: vec![1, 2, 3],
+        };
+        let serialized = serialize_gateway_call(&call).unwrap();
+        assert!(parse_axelar_message(&serialized).is_none());
+        let deserialized = parse_gateway_call(&serialized).unwrap().unwrap();
+        assert_eq!(call, deserialized);
+    }
+
+    #[test]
+    fn test_executable_interface_version_compatibility() {
+        let compiled = ExecutableInterfaceVersion { major: 1, minor: 2 };
+        let compatible = [
+            ExecutableInterfaceVersion { major: 1, minor: 2 },
+            ExecutableInterfaceVersion { major: 1, minor: 3 },
+        ];
+        let incompatible = [
+            ExecutableInterfaceVersion { major: 1, minor: 1 },
+            ExecutableInterfaceVersion { major: 0, minor: 9 },
+            ExecutableInterfaceVersion { major: 2, minor: 2 },
+        ];
+
+        for deployed in compatible {
+            assert!(compiled.is_compatible_with(deployed), "{deployed:?}");
+        }
+        for deployed in incompatible {
+            assert!(!compiled.is_compatible_with(deployed), "{deployed:?}");
+        }
+    }
 }
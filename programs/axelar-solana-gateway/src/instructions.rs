@@ -1,19 +1,27 @@
 //! Instruction types
 
 use core::fmt::Debug;
+use core::str::FromStr;
 
 use anchor_discriminators_macros::InstructionDiscriminator;
 use axelar_solana_encoding::types::execute_data::{MerkleisedMessage, SigningVerifierSetInfo};
-use axelar_solana_encoding::types::messages::Message;
+use axelar_solana_encoding::types::messages::{Message, MessageLeaf};
+use axelar_solana_encoding::types::pubkey::{PublicKey, Signature};
+use axelar_solana_encoding::types::verifier_set::VerifierSetLeaf;
 use borsh::{to_vec, BorshDeserialize, BorshSerialize};
 use solana_program::bpf_loader_upgradeable;
 use solana_program::instruction::{AccountMeta, Instruction};
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 
-use crate::get_gateway_root_config_pda;
 use crate::state::config::{RotationDelaySecs, VerifierSetEpoch};
-use crate::state::verifier_set_tracker::VerifierSetHash;
+use crate::state::incoming_message::command_id;
+use crate::state::signature_verification::{ed25519_instruction_data, offchain_message_hash};
+use crate::state::verifier_set_tracker::{SignatureScheme, VerifierSetHash};
+use crate::{get_gateway_root_config_pda, GatewayNamespace};
+
+pub mod offline;
+pub mod relay;
 
 /// Instructions supported by the gateway program.
 #[repr(u8)]
@@ -24,9 +32,11 @@ pub enum GatewayInstruction {
     /// Accounts expected by this instruction:
     /// 0. [] Gateway Root Config PDA account
     /// 1. [WRITE, SIGNER] Payer account
-    /// 2. [] Verification Session PDA account (should be valid)
+    /// 2. [WRITE] Verification Session PDA account (should be valid)
     /// 3. [WRITE] Incoming Message PDA account
     /// 4. [] System Program account
+    /// 5. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
+    /// 6. [] The gateway program account.
     ApproveMessage {
         /// The message that's to be approved
         message: MerkleisedMessage,
@@ -46,6 +56,8 @@ pub enum GatewayInstruction {
     /// 4. [WRITE, SIGNER] The payer for creating a new PDA
     /// 5. [] The system program
     /// 6. [SIGNER] (Optional) Operator account
+    /// 7. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
+    /// 8. [] The gateway program account.
     RotateSigners {
         /// The merkle root of the new verifier set
         new_verifier_set_merkle_root: [u8; 32],
@@ -57,6 +69,11 @@ pub enum GatewayInstruction {
     /// 0. [] Sender (origin) of the message, program id
     /// 1. [SIGNER] PDA created by the `sender`, works as authorization token for a given program id
     /// 2. [] Gateway Root Config PDA account
+    /// 3. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
+    /// 4. [] The gateway program account.
+    /// 5. [] Optional Chain Registry PDA account. When passed, the call fails
+    ///    unless the destination chain is registered, see
+    ///    [`GatewayInstruction::SetChainRegistered`].
     CallContract {
         /// The name of the target blockchain.
         destination_chain: String,
@@ -77,6 +94,8 @@ pub enum GatewayInstruction {
     /// 3. [WRITE] Gateway Root Config PDA account
     /// 4. [] System Program account
     /// 5. [WRITE] uninitialized `VerifierSetTracker` PDA account
+    /// 6. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
+    /// 7. [] The gateway program account.
     InitializeConfig(InitializeConfig),
 
     /// Initializes a verification session for a given Payload root.
@@ -99,6 +118,12 @@ pub enum GatewayInstruction {
     /// 1. [WRITE] Verification session PDA buffer account
     /// 2. [] Verifier Setr Tracker PDA account (the one that signed the
     ///    Payload's Merkle root)
+    /// 3. [] Event authority PDA, only required by `audit-trace` builds
+    /// 4. [] Gateway program, only required by `audit-trace` builds
+    /// 5. [] Instructions sysvar, only required by
+    ///    [`SignatureScheme::Ed25519`] verifier sets, whose signature must be
+    ///    verified by the preceding instruction, see
+    ///    [`ed25519_signature_verification`]
     VerifySignature {
         /// The Merkle root for the Payload being verified.
         payload_merkle_root: [u8; 32],
@@ -166,11 +191,16 @@ pub enum GatewayInstruction {
 
     /// Closes the message payload account and reclaim its lamports.
     ///
+    /// The funding account doesn't need to sign once the message is executed,
+    /// so that anyone can garbage collect the account, the lamports still
+    /// going to the funding account.
+    ///
     /// This instruction will revert on the following circumstances:
-    /// 1. SIGNER is not the authority for the Message Payload account.
+    /// 1. The funding account is not the authority for the Message Payload account.
+    /// 2. The funding account is not a signer while the message is not executed.
     ///
     /// Accounts expected by this instruction:
-    /// 0. [SIGNER] Funding account and authority for the Message Payload account.
+    /// 0. [WRITE, SIGNER?] Funding account and authority for the Message Payload account.
     /// 1. [] Gateway Root PDA account
     /// 2. [] Incoming Message PDA account
     /// 3. [WRITE] Message Payload PDA account
@@ -192,6 +222,8 @@ pub enum GatewayInstruction {
     /// 2. [] Gateway Root Config PDA account
     /// 3. [SIGNER] PDA signer account (caller). Derived from the destination
     ///    program id.
+    /// 4. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
+    /// 5. [] The gateway program account.
     ValidateMessage {
         /// The Message that we want to approve
         message: Message,
@@ -208,7 +240,286 @@ pub enum GatewayInstruction {
     ///    programdata account
     /// 3. [] Gateway programdata account (owned by `bpf_loader_upgradeable`)
     /// 4. [] New operator
+    /// 5. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
+    /// 6. [] The gateway program account.
     TransferOperatorship,
+
+    /// Grows the Gateway Root Config PDA account, making room for fields
+    /// added in future versions of [`GatewayConfig`](crate::state::GatewayConfig).
+    ///
+    /// Only the current operator OR Gateway program owner can resize the config.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. [WRITE, SIGNER] Funding account for the rent top-up
+    /// 1. [SIGNER] Current operator OR the upgrade authority of the Gateway
+    ///    programdata account
+    /// 2. [] Gateway programdata account (owned by `bpf_loader_upgradeable`)
+    /// 3. [WRITE] Gateway Root Config PDA account
+    /// 4. [] System Program account
+    ResizeConfig {
+        /// The new size of the config account, in bytes. Must not be smaller
+        /// than the current size.
+        new_size: u64,
+    },
+
+    /// Records the execution receipt of an executed message: the relayer that
+    /// executed it and the compute units consumed, as reported by the relayer.
+    /// Meant to be sent by the relayer right after the execution, before the
+    /// Message Payload account is closed. A receipt can only be recorded once,
+    /// and only for messages approved since receipts were introduced.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. [SIGNER] The relayer, authority of the Message Payload account
+    /// 1. [] Gateway Root PDA account
+    /// 2. [WRITE] Incoming Message PDA account
+    /// 3. [] Message Payload PDA account
+    /// 4. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
+    /// 5. [] The gateway program account.
+    RecordExecutionReceipt {
+        /// The executed message
+        message: Message,
+        /// Compute units consumed by the execution, bounded by
+        /// [`MAX_EXECUTION_COMPUTE_UNITS`](crate::state::incoming_message::MAX_EXECUTION_COMPUTE_UNITS)
+        compute_units: u32,
+    },
+
+    /// Asserts that the event authority PDA is still the canonical, empty
+    /// event-cpi PDA of the gateway and emits a test event through it.
+    /// Meant to be run after a redeploy (e.g. on a new cluster) to confirm
+    /// that event emission works before resuming operations.
+    ///
+    /// Only the current operator OR Gateway program owner can verify the event authority.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. [SIGNER] Current operator OR the upgrade authority of the Gateway
+    ///    programdata account
+    /// 1. [] Gateway programdata account (owned by `bpf_loader_upgradeable`)
+    /// 2. [] Gateway Root Config PDA account
+    /// 3. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
+    /// 4. [] The gateway program account.
+    VerifyEventAuthority,
+
+    /// Validates an approved message against its committed payload, marks it
+    /// as executed and calls the destination program with the accounts
+    /// encoded in the payload, so that relayers don't need to build the
+    /// destination program instruction.
+    ///
+    /// The destination program receives a
+    /// [`GatewayCall`](crate::executable::GatewayCall) signed by the Incoming
+    /// Message PDA and must check it with
+    /// [`validate_gateway_call`](crate::executable::validate_gateway_call). It
+    /// cannot CPI back into the gateway.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. [] Message Payload PDA payer, used to derive the Message Payload PDA
+    /// 1. [WRITE] Incoming Message PDA account
+    /// 2. [] Message Payload PDA account
+    /// 3. [] Gateway Root Config PDA account
+    /// 4. [] The destination program
+    /// 5. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
+    /// 6. [] The gateway program account.
+    /// N. [..] The accounts encoded in the message payload
+    ExecuteAndCall {
+        /// The message to execute
+        message: Message,
+    },
+
+    /// Registers or unregisters a destination chain in the chain registry,
+    /// creating the Chain Registry PDA on first use. Once a chain is
+    /// registered, `CallContract` instructions passing the registry are
+    /// rejected unless their destination chain is registered.
+    ///
+    /// Only the current operator OR Gateway program owner can update the
+    /// registry.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. [WRITE, SIGNER] Funding account
+    /// 1. [SIGNER] Current operator OR the upgrade authority of the Gateway
+    ///    programdata account
+    /// 2. [] Gateway programdata account (owned by `bpf_loader_upgradeable`)
+    /// 3. [] Gateway Root Config PDA account
+    /// 4. [WRITE] Chain Registry PDA account
+    /// 5. [] System Program account
+    /// 6. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
+    /// 7. [] The gateway program account.
+    SetChainRegistered {
+        /// The name of the destination chain, as used in `CallContract`.
+        chain_name: String,
+        /// Whether the chain is registered or unregistered.
+        registered: bool,
+    },
+
+    /// Returns the borsh serialized
+    /// [`ExecutableInterfaceVersion`](crate::executable::ExecutableInterfaceVersion)
+    /// of the gateway as return data. Destination programs query it through
+    /// [`ensure_compatible_gateway`](crate::executable::ensure_compatible_gateway).
+    ///
+    /// Accounts expected by this instruction: none.
+    GetExecutableInterfaceVersion,
+
+    /// Writes the
+    /// [`GatewayConfig::state_digest`](crate::state::GatewayConfig::state_digest)
+    /// of the gateway to the return data, so that audits and monitoring can
+    /// compare the deployed state against the expected one with a single
+    /// 32-byte value.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. [] Gateway Root Config PDA account
+    /// 1. [] Verifier Set Tracker PDA of the current epoch
+    /// 2. [] Verifier Set Tracker PDA of the previous epoch, required unless
+    ///    the gateway is still at its first epoch
+    StateDigest,
+
+    /// Initializes the configuration PDA of a gateway instance in a
+    /// non-default [`GatewayNamespace`]. Requires the program to be built with
+    /// the `namespaced-pdas` feature.
+    ///
+    /// Accounts expected by this instruction: the same as
+    /// [`GatewayInstruction::InitializeConfig`], with the Gateway Root Config
+    /// PDA account of the namespace.
+    InitializeNamespacedConfig {
+        /// The namespace of the gateway instance
+        namespace: GatewayNamespace,
+        /// The initial configuration of the gateway instance
+        config: InitializeConfig,
+    },
+
+    /// Cancels an approved but not yet executed message.
+    ///
+    /// The cancellation is itself a message approved by the gateway, sent
+    /// from the source chain and source address of the cancelled message to
+    /// the gateway program, with the payload described in
+    /// [`cancel_message_payload_hash`](crate::state::incoming_message::cancel_message_payload_hash).
+    /// Both messages are consumed: the cancellation is marked as executed and
+    /// the cancelled message as cancelled, so it can never be executed.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. [] Gateway Root Config PDA account
+    /// 1. [WRITE] Incoming Message PDA account of the cancellation
+    /// 2. [WRITE] Incoming Message PDA account of the cancelled message
+    /// 3. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
+    /// 4. [] The gateway program account.
+    CancelMessage {
+        /// The approved message carrying the cancellation
+        cancellation: Message,
+        /// The approved message to cancel
+        message: Message,
+    },
+
+    /// Approves the only message of a single-message payload. The payload
+    /// merkle root of such a payload is the hash of its leaf, so the message
+    /// is checked against the verification session without a merkle proof.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. [] Gateway Root Config PDA account
+    /// 1. [WRITE, SIGNER] Payer account
+    /// 2. [WRITE] Verification Session PDA account (should be valid)
+    /// 3. [WRITE] Incoming Message PDA account
+    /// 4. [] System Program account
+    /// 5. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
+    /// 6. [] The gateway program account.
+    ApproveSingleMessage {
+        /// The leaf of the message that's to be approved
+        message: MessageLeaf,
+    },
+
+    /// Closes the tracker PDA of a verifier set that fell out of the
+    /// retention window (`previous_verifier_set_retention` epochs) and can no
+    /// longer sign. Anyone can prune a tracker, its rent is refunded to the
+    /// gateway operator.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. [] Gateway Root Config PDA account
+    /// 1. [WRITE] Verifier Set Tracker PDA account to close
+    /// 2. [WRITE] The gateway operator, receiving the rent of the tracker
+    /// 3. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
+    /// 4. [] The gateway program account.
+    PruneVerifierSetTracker,
+
+    /// Break-glass recovery of the gateway operatorship, for when the
+    /// operator is set to an unusable key. Only the upgrade authority of the
+    /// Gateway program can recover the operatorship, and only after a
+    /// timelock of
+    /// [`OPERATORSHIP_RECOVERY_DELAY`](crate::state::operatorship_recovery::OPERATORSHIP_RECOVERY_DELAY)
+    /// seconds.
+    ///
+    /// The first call initiates the recovery to `new_operator` by creating
+    /// the Operatorship Recovery PDA. Calling it again with the same
+    /// `new_operator` once the timelock elapsed sets the operator and closes
+    /// the PDA, refunding its rent to the upgrade authority. Calling it with a
+    /// different `new_operator` restarts the recovery.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. [WRITE] Gateway Root Config PDA account
+    /// 1. [WRITE, SIGNER] The upgrade authority of the Gateway programdata
+    ///    account, paying for the Operatorship Recovery PDA
+    /// 2. [] Gateway programdata account (owned by `bpf_loader_upgradeable`)
+    /// 3. [WRITE] Operatorship Recovery PDA account
+    /// 4. [] System Program account
+    /// 5. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
+    /// 6. [] The gateway program account.
+    RecoverOperatorship {
+        /// The operator to recover the gateway to
+        new_operator: Pubkey,
+    },
+
+    /// Approves a batch of messages of the same signed payload, initializing
+    /// the incoming message PDA of every message in one instruction.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. [] Gateway Root Config PDA account
+    /// 1. [WRITE, SIGNER] Payer account
+    /// 2. [WRITE] Verification Session PDA account (should be valid)
+    /// 3..3+N. [WRITE] Incoming Message PDA accounts, one per message in the
+    ///    same order as `messages`
+    /// 3+N. [] System Program account
+    /// 4+N. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
+    /// 5+N. [] The gateway program account.
+    ApproveMessagesBatch {
+        /// The messages that are to be approved, with their merkle proofs
+        messages: Vec<MerkleisedMessage>,
+        /// The merkle root of the message batch
+        payload_merkle_root: [u8; 32],
+    },
+
+    /// Rotate signers like [`GatewayInstruction::RotateSigners`], proving a
+    /// leaf of the new verifier set. The threshold and the signer count of
+    /// the new verifier set are then included in the emitted
+    /// [`crate::events::VerifierSetRotationDetailsEvent`], and the new
+    /// verifier set signs with the [`SignatureScheme`] of the key of the leaf,
+    /// which is how a gateway rotates to an Ed25519 verifier set.
+    ///
+    /// Accounts expected by this instruction: the same as
+    /// [`GatewayInstruction::RotateSigners`].
+    RotateSignersWithLeaf {
+        /// The merkle root of the new verifier set
+        new_verifier_set_merkle_root: [u8; 32],
+        /// A leaf of the new verifier set and its merkle proof
+        new_verifier_set_leaf: VerifierSetLeafProof,
+    },
+
+    /// Initializes the Gateway configuration PDA account like
+    /// [`GatewayInstruction::InitializeConfig`], with an initial verifier set
+    /// signing with the given [`SignatureScheme`] instead of
+    /// [`SignatureScheme::Secp256k1`].
+    ///
+    /// Accounts expected by this instruction: the same as
+    /// [`GatewayInstruction::InitializeConfig`].
+    InitializeConfigWithSignatureScheme {
+        /// The initial configuration of the gateway
+        config: InitializeConfig,
+        /// The kind of keys the initial verifiers sign with
+        signature_scheme: SignatureScheme,
+    },
+}
+
+/// A leaf of a verifier set together with the merkle proof of its inclusion.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct VerifierSetLeafProof {
+    /// The leaf of the verifier set
+    pub leaf: VerifierSetLeaf,
+    /// The merkle proof of the leaf, as returned by `rs_merkle`
+    pub merkle_proof: Vec<u8>,
 }
 
 /// Represents an initial verifier set with its hash and PDA
@@ -260,7 +571,7 @@ pub fn approve_message(
     let accounts = vec![
         AccountMeta::new_readonly(gateway_root_pda, false),
         AccountMeta::new(payer, true),
-        AccountMeta::new_readonly(verification_session_pda, false),
+        AccountMeta::new(verification_session_pda, false),
         AccountMeta::new(incoming_message_pda, false),
         AccountMeta::new_readonly(solana_program::system_program::id(), false),
         AccountMeta::new_readonly(event_authority, false),
@@ -274,6 +585,37 @@ pub fn approve_message(
     })
 }
 
+/// Creates a [`GatewayInstruction::ApproveSingleMessage`] instruction.
+///
+/// # Errors
+///
+/// Returns a [`ProgramError::BorshIoError`] if the instruction serialization fails.
+pub fn approve_single_message(
+    message: MessageLeaf,
+    gateway_root_pda: Pubkey,
+    payer: Pubkey,
+    verification_session_pda: Pubkey,
+    incoming_message_pda: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let data = to_vec(&GatewayInstruction::ApproveSingleMessage { message })?;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(gateway_root_pda, false),
+        AccountMeta::new(payer, true),
+        AccountMeta::new(verification_session_pda, false),
+        AccountMeta::new(incoming_message_pda, false),
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new_readonly(crate::get_event_authority_pda().0, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
 /// Creates a [`GatewayInstruction::RotateSigners`] instruction.
 ///
 /// # Errors
@@ -289,9 +631,46 @@ pub fn rotate_signers(
     operator: Option<Pubkey>,
     new_verifier_set_merkle_root: [u8; 32],
 ) -> Result<Instruction, ProgramError> {
-    let data = to_vec(&GatewayInstruction::RotateSigners {
+    rotate_signers_with_leaf_proof(
+        gateway_root_pda,
+        verification_session_account,
+        current_verifier_set_tracker_pda,
+        new_verifier_set_tracker_pda,
+        payer,
+        operator,
         new_verifier_set_merkle_root,
-    })?;
+        None,
+    )
+}
+
+/// Creates a [`GatewayInstruction::RotateSignersWithLeaf`] instruction that
+/// proves a leaf of the new verifier set, so that its threshold and signer
+/// count are reported in the rotation details event. Without a leaf, a
+/// [`GatewayInstruction::RotateSigners`] instruction is created.
+///
+/// # Errors
+///
+/// Returns a [`ProgramError::BorshIoError`] if the instruction serialization fails.
+#[allow(clippy::too_many_arguments)]
+pub fn rotate_signers_with_leaf_proof(
+    gateway_root_pda: Pubkey,
+    verification_session_account: Pubkey,
+    current_verifier_set_tracker_pda: Pubkey,
+    new_verifier_set_tracker_pda: Pubkey,
+    payer: Pubkey,
+    operator: Option<Pubkey>,
+    new_verifier_set_merkle_root: [u8; 32],
+    new_verifier_set_leaf: Option<VerifierSetLeafProof>,
+) -> Result<Instruction, ProgramError> {
+    let data = match new_verifier_set_leaf {
+        Some(new_verifier_set_leaf) => to_vec(&GatewayInstruction::RotateSignersWithLeaf {
+            new_verifier_set_merkle_root,
+            new_verifier_set_leaf,
+        })?,
+        None => to_vec(&GatewayInstruction::RotateSigners {
+            new_verifier_set_merkle_root,
+        })?,
+    };
 
     let (event_authority, _bump) =
         Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
@@ -366,6 +745,38 @@ pub fn call_contract(
     })
 }
 
+/// Creates a [`CallContract`] instruction that passes the chain registry, so
+/// that the call fails if the operator didn't register `destination_chain`.
+///
+/// # Errors
+///
+/// Returns a [`ProgramError::BorshIoError`] if the instruction serialization fails.
+pub fn call_contract_to_registered_chain(
+    gateway_program_id: Pubkey,
+    gateway_root_pda: Pubkey,
+    sender: Pubkey,
+    sender_call_contract_pda: Option<(Pubkey, u8)>,
+    destination_chain: String,
+    destination_contract_address: String,
+    payload: Vec<u8>,
+) -> Result<Instruction, ProgramError> {
+    let mut instruction = call_contract(
+        gateway_program_id,
+        gateway_root_pda,
+        sender,
+        sender_call_contract_pda,
+        destination_chain,
+        destination_contract_address,
+        payload,
+    )?;
+    let (chain_registry_pda, _) = crate::get_chain_registry_pda();
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(chain_registry_pda, false));
+
+    Ok(instruction)
+}
+
 /// Creates a [`GatewayInstruction::InitializeConfig`] instruction.
 ///
 /// # Errors
@@ -382,17 +793,12 @@ pub fn initialize_config(
     previous_verifier_retention: VerifierSetEpoch,
     gateway_config_pda: Pubkey,
 ) -> Result<Instruction, ProgramError> {
-    let gateway_program_data =
-        solana_program::bpf_loader_upgradeable::get_program_data_address(&crate::ID);
-
-    let accounts = vec![
-        AccountMeta::new(payer, true),
-        AccountMeta::new_readonly(upgrade_authority, true),
-        AccountMeta::new_readonly(gateway_program_data, false),
-        AccountMeta::new(gateway_config_pda, false),
-        AccountMeta::new_readonly(solana_program::system_program::id(), false),
-        AccountMeta::new(initial_verifier_set.pda, false),
-    ];
+    let accounts = initialize_config_accounts(
+        payer,
+        upgrade_authority,
+        gateway_config_pda,
+        initial_verifier_set.pda,
+    );
 
     let data = to_vec(&GatewayInstruction::InitializeConfig(InitializeConfig {
         domain_separator,
@@ -408,6 +814,96 @@ pub fn initialize_config(
     })
 }
 
+/// Creates a [`GatewayInstruction::InitializeConfigWithSignatureScheme`]
+/// instruction, for an initial verifier set signing with `signature_scheme`.
+///
+/// # Errors
+///
+/// Returns a [`ProgramError::BorshIoError`] if the instruction serialization fails.
+pub fn initialize_config_with_signature_scheme(
+    payer: Pubkey,
+    upgrade_authority: Pubkey,
+    config: InitializeConfig,
+    signature_scheme: SignatureScheme,
+    gateway_config_pda: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let accounts = initialize_config_accounts(
+        payer,
+        upgrade_authority,
+        gateway_config_pda,
+        config.initial_verifier_set.pda,
+    );
+
+    let data = to_vec(&GatewayInstruction::InitializeConfigWithSignatureScheme {
+        config,
+        signature_scheme,
+    })?;
+    Ok(Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
+/// Creates a [`GatewayInstruction::InitializeNamespacedConfig`] instruction.
+///
+/// The gateway config PDA is derived with
+/// [`get_gateway_root_config_pda_with_namespace`](crate::get_gateway_root_config_pda_with_namespace),
+/// and the initial verifier set tracker PDA with
+/// [`get_verifier_set_tracker_pda_with_namespace`](crate::get_verifier_set_tracker_pda_with_namespace),
+/// overriding the one in `config`.
+///
+/// # Errors
+///
+/// Returns a [`ProgramError::BorshIoError`] if the instruction serialization fails.
+pub fn initialize_namespaced_config(
+    namespace: GatewayNamespace,
+    payer: Pubkey,
+    upgrade_authority: Pubkey,
+    mut config: InitializeConfig,
+) -> Result<Instruction, ProgramError> {
+    let (gateway_config_pda, _) = crate::get_gateway_root_config_pda_with_namespace(namespace);
+    config.initial_verifier_set.pda = crate::get_verifier_set_tracker_pda_with_namespace(
+        namespace,
+        config.initial_verifier_set.hash,
+    )
+    .0;
+    let accounts = initialize_config_accounts(
+        payer,
+        upgrade_authority,
+        gateway_config_pda,
+        config.initial_verifier_set.pda,
+    );
+
+    let data = to_vec(&GatewayInstruction::InitializeNamespacedConfig { namespace, config })?;
+    Ok(Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
+fn initialize_config_accounts(
+    payer: Pubkey,
+    upgrade_authority: Pubkey,
+    gateway_config_pda: Pubkey,
+    initial_verifier_set_pda: Pubkey,
+) -> Vec<AccountMeta> {
+    let gateway_program_data =
+        solana_program::bpf_loader_upgradeable::get_program_data_address(&crate::ID);
+
+    vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(upgrade_authority, true),
+        AccountMeta::new_readonly(gateway_program_data, false),
+        AccountMeta::new(gateway_config_pda, false),
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new(initial_verifier_set_pda, false),
+        AccountMeta::new_readonly(crate::get_event_authority_pda().0, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ]
+}
+
 /// Creates a [`GatewayInstruction::InitializePayloadVerificationSession`]
 /// instruction.
 ///
@@ -458,10 +954,18 @@ pub fn verify_signature(
     payload_merkle_root: [u8; 32],
     verifier_info: SigningVerifierSetInfo,
 ) -> Result<Instruction, ProgramError> {
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    // The event accounts are only read by `audit-trace` builds, passing them
+    // keeps the instruction valid for both kinds of builds.
     let accounts = vec![
         AccountMeta::new_readonly(gateway_config_pda, false),
         AccountMeta::new(verification_session_pda, false),
         AccountMeta::new_readonly(verifier_set_tracker_pda, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+        AccountMeta::new_readonly(solana_program::sysvar::instructions::ID, false),
     ];
 
     let data = to_vec(&GatewayInstruction::VerifySignature {
@@ -476,6 +980,34 @@ pub fn verify_signature(
     })
 }
 
+/// Creates the Ed25519 program instruction verifying the signature of
+/// `verifier_info`, to be placed right before its
+/// [`GatewayInstruction::VerifySignature`] instruction.
+///
+/// Returns `None` for signatures of other schemes, which the gateway
+/// verifies on its own.
+#[must_use]
+pub fn ed25519_signature_verification(
+    verifier_info: &SigningVerifierSetInfo,
+    payload_merkle_root: &[u8; 32],
+) -> Option<Instruction> {
+    let (Signature::Ed25519(signature), PublicKey::Ed25519(pubkey)) =
+        (&verifier_info.signature, &verifier_info.leaf.signer_pubkey)
+    else {
+        return None;
+    };
+
+    Some(Instruction {
+        program_id: solana_program::ed25519_program::ID,
+        accounts: vec![],
+        data: ed25519_instruction_data(
+            pubkey,
+            signature,
+            &offchain_message_hash(payload_merkle_root),
+        ),
+    })
+}
+
 /// Creates a [`GatewayInstruction::ValidateMessage`] instruction.
 ///
 /// # Errors
@@ -601,6 +1133,43 @@ pub fn commit_message_payload(
     })
 }
 
+/// Creates a [`GatewayInstruction::RecordExecutionReceipt`] instruction.
+///
+/// # Errors
+///
+/// Returns a [`ProgramError::BorshIoError`] if the instruction serialization fails.
+pub fn record_execution_receipt(
+    gateway_root_pda: Pubkey,
+    relayer: Pubkey,
+    message: Message,
+    compute_units: u32,
+) -> Result<Instruction, ProgramError> {
+    let command_id = command_id(&message.cc_id.chain, &message.cc_id.id);
+    let (incoming_message_pda, _) = crate::get_incoming_message_pda(&command_id);
+    let (message_payload_pda, _) = crate::find_message_payload_pda(incoming_message_pda, relayer);
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(relayer, true),
+        AccountMeta::new_readonly(gateway_root_pda, false),
+        AccountMeta::new(incoming_message_pda, false),
+        AccountMeta::new_readonly(message_payload_pda, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    let instruction = GatewayInstruction::RecordExecutionReceipt {
+        message,
+        compute_units,
+    };
+    Ok(Instruction {
+        program_id: crate::id(),
+        accounts,
+        data: borsh::to_vec(&instruction)?,
+    })
+}
+
 /// Creates a [`GatewayInstrucon::CloseMessagePayload`] instruction.
 ///
 /// # Errors
@@ -627,6 +1196,26 @@ pub fn close_message_payload(
     })
 }
 
+/// Creates a [`GatewayInstruction::CloseMessagePayload`] instruction closing
+/// the message payload account of an executed message on behalf of `payer`,
+/// who gets the lamports back without signing.
+///
+/// # Errors
+///
+/// Returns a [`ProgramError::BorshIoError`] if the instruction serialization fails.
+pub fn close_executed_message_payload(
+    gateway_root_pda: Pubkey,
+    payer: Pubkey,
+    command_id: [u8; 32],
+) -> Result<Instruction, ProgramError> {
+    let mut instruction = close_message_payload(gateway_root_pda, payer, command_id)?;
+    if let Some(payer_meta) = instruction.accounts.first_mut() {
+        payer_meta.is_signer = false;
+    }
+
+    Ok(instruction)
+}
+
 /// Creates a [`GatewayInstruction::TransferOperatorship`] instruction.
 ///
 /// # Errors
@@ -661,3 +1250,350 @@ pub fn transfer_operatorship(
         data,
     })
 }
+
+/// Creates a [`GatewayInstruction::ResizeConfig`] instruction.
+///
+/// # Errors
+///
+/// Returns a [`ProgramError::BorshIoError`] if the instruction serialization fails.
+pub fn resize_config(
+    payer: Pubkey,
+    operator_or_upgrade_authority: Pubkey,
+    new_size: u64,
+) -> Result<Instruction, ProgramError> {
+    let gateway_root_pda = get_gateway_root_config_pda().0;
+    let programdata_pubkey = bpf_loader_upgradeable::get_program_data_address(&crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(operator_or_upgrade_authority, true),
+        AccountMeta::new_readonly(programdata_pubkey, false),
+        AccountMeta::new(gateway_root_pda, false),
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+    ];
+
+    let data = borsh::to_vec(&GatewayInstruction::ResizeConfig { new_size })?;
+
+    Ok(Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
+/// Creates a [`GatewayInstruction::VerifyEventAuthority`] instruction.
+///
+/// # Errors
+///
+/// Returns a [`ProgramError::BorshIoError`] if the instruction serialization fails.
+pub fn verify_event_authority(
+    operator_or_upgrade_authority: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let gateway_root_pda = get_gateway_root_config_pda().0;
+    let programdata_pubkey = bpf_loader_upgradeable::get_program_data_address(&crate::ID);
+    let (event_authority, _bump) = crate::get_event_authority_pda();
+
+    let accounts = vec![
+        AccountMeta::new_readonly(operator_or_upgrade_authority, true),
+        AccountMeta::new_readonly(programdata_pubkey, false),
+        AccountMeta::new_readonly(gateway_root_pda, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    let data = borsh::to_vec(&GatewayInstruction::VerifyEventAuthority)?;
+
+    Ok(Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
+/// Creates a [`GatewayInstruction::ExecuteAndCall`] instruction.
+///
+/// # Errors
+///
+/// Returns a [`ProgramError`] if:
+/// * The destination address is not a valid base58 encoded pubkey
+/// * The `axelar_message_payload` could not be decoded
+/// * The instruction serialization fails
+pub fn execute_and_call(
+    message_payload_payer: Pubkey,
+    message: Message,
+    axelar_message_payload: &[u8],
+) -> Result<Instruction, ProgramError> {
+    let passed_in_accounts =
+        crate::executable::AxelarMessagePayload::decode(axelar_message_payload)?.account_meta();
+    let destination_address = Pubkey::from_str(&message.destination_address)
+        .map_err(|_err| ProgramError::InvalidAccountData)?;
+
+    let command_id =
+        crate::state::incoming_message::command_id(&message.cc_id.chain, &message.cc_id.id);
+    let (incoming_message_pda, _) = crate::get_incoming_message_pda(&command_id);
+    let (message_payload_pda, _) =
+        crate::find_message_payload_pda(incoming_message_pda, message_payload_payer);
+    let gateway_root_pda = get_gateway_root_config_pda().0;
+    let (event_authority, _bump) = crate::get_event_authority_pda();
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(message_payload_payer, false),
+        AccountMeta::new(incoming_message_pda, false),
+        AccountMeta::new_readonly(message_payload_pda, false),
+        AccountMeta::new_readonly(gateway_root_pda, false),
+        AccountMeta::new_readonly(destination_address, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+    accounts.extend(passed_in_accounts);
+
+    let data = borsh::to_vec(&GatewayInstruction::ExecuteAndCall { message })?;
+
+    Ok(Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
+/// Creates a [`GatewayInstruction::SetChainRegistered`] instruction.
+///
+/// # Errors
+///
+/// Returns a [`ProgramError::BorshIoError`] if the instruction serialization fails.
+pub fn set_chain_registered(
+    payer: Pubkey,
+    operator_or_upgrade_authority: Pubkey,
+    chain_name: String,
+    registered: bool,
+) -> Result<Instruction, ProgramError> {
+    let gateway_root_pda = get_gateway_root_config_pda().0;
+    let programdata_pubkey = bpf_loader_upgradeable::get_program_data_address(&crate::ID);
+    let (chain_registry_pda, _) = crate::get_chain_registry_pda();
+    let (event_authority, _bump) = crate::get_event_authority_pda();
+
+    let accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(operator_or_upgrade_authority, true),
+        AccountMeta::new_readonly(programdata_pubkey, false),
+        AccountMeta::new_readonly(gateway_root_pda, false),
+        AccountMeta::new(chain_registry_pda, false),
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    let data = borsh::to_vec(&GatewayInstruction::SetChainRegistered {
+        chain_name,
+        registered,
+    })?;
+
+    Ok(Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
+/// Creates a [`GatewayInstruction::GetExecutableInterfaceVersion`] instruction.
+///
+/// # Errors
+///
+/// Returns a [`ProgramError::BorshIoError`] if the instruction serialization fails.
+pub fn get_executable_interface_version() -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id: crate::id(),
+        accounts: vec![],
+        data: borsh::to_vec(&GatewayInstruction::GetExecutableInterfaceVersion)?,
+    })
+}
+
+/// Creates a [`GatewayInstruction::StateDigest`] instruction.
+///
+/// # Errors
+///
+/// Returns a [`ProgramError::BorshIoError`] if the instruction serialization fails.
+pub fn state_digest(
+    current_verifier_set_hash: VerifierSetHash,
+    previous_verifier_set_hash: Option<VerifierSetHash>,
+) -> Result<Instruction, ProgramError> {
+    let gateway_root_pda = get_gateway_root_config_pda().0;
+    let (current_verifier_set_tracker_pda, _) =
+        crate::get_verifier_set_tracker_pda(current_verifier_set_hash);
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(gateway_root_pda, false),
+        AccountMeta::new_readonly(current_verifier_set_tracker_pda, false),
+    ];
+    if let Some(previous_verifier_set_hash) = previous_verifier_set_hash {
+        let (previous_verifier_set_tracker_pda, _) =
+            crate::get_verifier_set_tracker_pda(previous_verifier_set_hash);
+        accounts.push(AccountMeta::new_readonly(
+            previous_verifier_set_tracker_pda,
+            false,
+        ));
+    }
+
+    Ok(Instruction {
+        program_id: crate::id(),
+        accounts,
+        data: borsh::to_vec(&GatewayInstruction::StateDigest)?,
+    })
+}
+
+/// Creates a [`GatewayInstruction::CancelMessage`] instruction.
+///
+/// # Errors
+///
+/// Returns a [`ProgramError::BorshIoError`] if the instruction serialization fails.
+pub fn cancel_message(
+    cancellation: Message,
+    message: Message,
+) -> Result<Instruction, ProgramError> {
+    let gateway_root_pda = get_gateway_root_config_pda().0;
+    let (cancellation_pda, _) = crate::get_incoming_message_pda(&command_id(
+        &cancellation.cc_id.chain,
+        &cancellation.cc_id.id,
+    ));
+    let (incoming_message_pda, _) =
+        crate::get_incoming_message_pda(&command_id(&message.cc_id.chain, &message.cc_id.id));
+
+    let accounts = vec![
+        AccountMeta::new_readonly(gateway_root_pda, false),
+        AccountMeta::new(cancellation_pda, false),
+        AccountMeta::new(incoming_message_pda, false),
+        AccountMeta::new_readonly(crate::get_event_authority_pda().0, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::id(),
+        accounts,
+        data: borsh::to_vec(&GatewayInstruction::CancelMessage {
+            cancellation,
+            message,
+        })?,
+    })
+}
+
+/// Creates a [`GatewayInstruction::PruneVerifierSetTracker`] instruction.
+///
+/// # Errors
+///
+/// Returns a [`ProgramError::BorshIoError`] if the instruction serialization fails.
+pub fn prune_verifier_set_tracker(
+    verifier_set_tracker_pda: Pubkey,
+    operator: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let data = to_vec(&GatewayInstruction::PruneVerifierSetTracker)?;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(get_gateway_root_config_pda().0, false),
+        AccountMeta::new(verifier_set_tracker_pda, false),
+        AccountMeta::new(operator, false),
+        AccountMeta::new_readonly(crate::get_event_authority_pda().0, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
+/// Creates a [`GatewayInstruction::RecoverOperatorship`] instruction.
+///
+/// # Errors
+///
+/// Returns a [`ProgramError::BorshIoError`] if the instruction serialization fails.
+pub fn recover_operatorship(
+    upgrade_authority: Pubkey,
+    new_operator: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (programdata_pubkey, _) =
+        Pubkey::try_find_program_address(&[crate::id().as_ref()], &bpf_loader_upgradeable::id())
+            .ok_or(ProgramError::IncorrectProgramId)?;
+    let gateway_root_pda = get_gateway_root_config_pda().0;
+
+    let data = to_vec(&GatewayInstruction::RecoverOperatorship { new_operator })?;
+
+    let accounts = vec![
+        AccountMeta::new(gateway_root_pda, false),
+        AccountMeta::new(upgrade_authority, true),
+        AccountMeta::new_readonly(programdata_pubkey, false),
+        AccountMeta::new(
+            crate::get_operatorship_recovery_pda(&gateway_root_pda).0,
+            false,
+        ),
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new_readonly(crate::get_event_authority_pda().0, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
+/// Creates a [`GatewayInstruction::ApproveMessagesBatch`] instruction,
+/// approving every message with its incoming message PDA.
+///
+/// # Chunking
+///
+/// All messages must belong to the payload signed in the verification
+/// session, but they don't have to be approved in a single batch. The compute
+/// units consumed grow linearly with the number of messages, each of them
+/// creating its incoming message PDA, verifying its merkle proof and
+/// emitting its events, and every message adds its leaf and proof to the
+/// instruction data. Relayers should split the messages of a payload into
+/// chunks of at most
+/// [`MAX_TRANSACTION_COMPUTE_UNITS`](relay::MAX_TRANSACTION_COMPUTE_UNITS) /
+/// [`APPROVE_MESSAGE_COMPUTE_UNITS`](relay::APPROVE_MESSAGE_COMPUTE_UNITS)
+/// messages that also fit in
+/// [`MAX_TRANSACTION_SIZE`](relay::MAX_TRANSACTION_SIZE), and request
+/// `APPROVE_MESSAGE_COMPUTE_UNITS` per message with
+/// `ComputeBudgetInstruction::set_compute_unit_limit`. Chunks can land in any
+/// order, and the [`BatchApprovedEvent`](crate::events::BatchApprovedEvent)
+/// is emitted once the last message of the payload is approved.
+///
+/// # Errors
+///
+/// Returns a [`ProgramError::BorshIoError`] if the instruction serialization fails.
+pub fn approve_messages_batch(
+    messages: Vec<(MerkleisedMessage, Pubkey)>,
+    payload_merkle_root: [u8; 32],
+    gateway_root_pda: Pubkey,
+    payer: Pubkey,
+    verification_session_pda: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (messages, incoming_message_pdas): (Vec<_>, Vec<_>) = messages.into_iter().unzip();
+    let data = to_vec(&GatewayInstruction::ApproveMessagesBatch {
+        messages,
+        payload_merkle_root,
+    })?;
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(gateway_root_pda, false),
+        AccountMeta::new(payer, true),
+        AccountMeta::new(verification_session_pda, false),
+    ];
+    accounts.extend(
+        incoming_message_pdas
+            .into_iter()
+            .map(|incoming_message_pda| AccountMeta::new(incoming_message_pda, false)),
+    );
+    accounts.extend([
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new_readonly(crate::get_event_authority_pda().0, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ]);
+
+    Ok(Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
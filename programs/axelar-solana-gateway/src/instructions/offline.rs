@@ -0,0 +1,298 @@
+//! Unsigned transactions for operator actions signed on an air-gapped machine.
+//!
+//! The operator and the upgrade authority of the gateway are meant to be cold
+//! keys. [`OperatorTransactionBuilder`] builds the complete message of the
+//! transaction of every action gated by them, so that it can be carried to
+//! the offline signer, signed there and carried back to be broadcast.
+//!
+//! A transaction built on a recent blockhash expires after about a minute,
+//! which rarely leaves enough time for a signing ceremony. With a
+//! [`Lifetime::DurableNonce`] the transaction stays valid until the nonce is
+//! advanced, the advance being the first instruction of the transaction.
+
+use solana_program::hash::Hash;
+use solana_program::instruction::Instruction;
+use solana_program::message::Message;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::get_gateway_root_config_pda;
+
+/// What an offline transaction commits to, in place of a signature of the
+/// current state of the chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lifetime {
+    /// A recent blockhash, the transaction expiring with it.
+    RecentBlockhash(Hash),
+    /// A durable nonce account, the transaction staying valid until the
+    /// nonce is advanced.
+    DurableNonce {
+        /// The nonce account.
+        nonce_account: Pubkey,
+        /// The authority of the nonce account, signing the transaction.
+        nonce_authority: Pubkey,
+        /// The nonce currently stored in the nonce account.
+        nonce: Hash,
+    },
+}
+
+/// An unsigned transaction, ready to be signed offline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OfflineTransaction {
+    /// The message the signers have to sign.
+    pub message: Message,
+}
+
+impl OfflineTransaction {
+    /// The accounts that have to sign [`OfflineTransaction::message`], in
+    /// the order their signatures are expected in the transaction.
+    #[must_use]
+    pub fn signers(&self) -> &[Pubkey] {
+        let num_signers = usize::from(self.message.header.num_required_signatures);
+        self.message
+            .account_keys
+            .get(..num_signers)
+            .unwrap_or(&self.message.account_keys)
+    }
+
+    /// The serialized message, the bytes each signer signs.
+    #[must_use]
+    pub fn message_data(&self) -> Vec<u8> {
+        self.message.serialize()
+    }
+}
+
+/// Builds the unsigned transactions of the actions gated by the gateway
+/// operator or the upgrade authority of the gateway program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperatorTransactionBuilder {
+    /// The gateway root config PDA the actions apply to.
+    pub gateway_root_pda: Pubkey,
+    /// The account paying the transaction fees and the rent of the accounts
+    /// the actions create.
+    pub fee_payer: Pubkey,
+    /// The operator or the upgrade authority signing the actions.
+    pub authority: Pubkey,
+    /// What the transactions commit to.
+    pub lifetime: Lifetime,
+}
+
+impl OperatorTransactionBuilder {
+    /// Creates a builder for the actions `authority` signs on the gateway
+    /// root config PDA, with the fees paid by `fee_payer`.
+    #[must_use]
+    pub fn new(fee_payer: Pubkey, authority: Pubkey, lifetime: Lifetime) -> Self {
+        Self {
+            gateway_root_pda: get_gateway_root_config_pda().0,
+            fee_payer,
+            authority,
+            lifetime,
+        }
+    }
+
+    /// Transfers the operatorship of the gateway to `new_operator`, see
+    /// [`super::transfer_operatorship`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ProgramError`] if the instruction can't be built.
+    pub fn transfer_operatorship(
+        &self,
+        new_operator: Pubkey,
+    ) -> Result<OfflineTransaction, ProgramError> {
+        Ok(self.build(super::transfer_operatorship(
+            self.gateway_root_pda,
+            self.authority,
+            new_operator,
+        )?))
+    }
+
+    /// Rotates the verifier set without waiting for the minimum rotation
+    /// delay, see [`super::rotate_signers`]. The verification session of the
+    /// rotation has to be complete by the time the transaction lands.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ProgramError`] if the instruction can't be built.
+    pub fn rotate_signers(
+        &self,
+        verification_session_account: Pubkey,
+        current_verifier_set_tracker_pda: Pubkey,
+        new_verifier_set_tracker_pda: Pubkey,
+        new_verifier_set_merkle_root: [u8; 32],
+    ) -> Result<OfflineTransaction, ProgramError> {
+        Ok(self.build(super::rotate_signers(
+            self.gateway_root_pda,
+            verification_session_account,
+            current_verifier_set_tracker_pda,
+            new_verifier_set_tracker_pda,
+            self.fee_payer,
+            Some(self.authority),
+            new_verifier_set_merkle_root,
+        )?))
+    }
+
+    /// Resizes the gateway root config PDA, see [`super::resize_config`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ProgramError`] if the instruction can't be built.
+    pub fn resize_config(&self, new_size: u64) -> Result<OfflineTransaction, ProgramError> {
+        Ok(self.build(super::resize_config(
+            self.fee_payer,
+            self.authority,
+            new_size,
+        )?))
+    }
+
+    /// Verifies the event authority of the gateway, see
+    /// [`super::verify_event_authority`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ProgramError`] if the instruction can't be built.
+    pub fn verify_event_authority(&self) -> Result<OfflineTransaction, ProgramError> {
+        Ok(self.build(super::verify_event_authority(self.authority)?))
+    }
+
+    /// Registers or unregisters `chain_name` as a destination chain, see
+    /// [`super::set_chain_registered`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ProgramError`] if the instruction can't be built.
+    pub fn set_chain_registered(
+        &self,
+        chain_name: String,
+        registered: bool,
+    ) -> Result<OfflineTransaction, ProgramError> {
+        Ok(self.build(super::set_chain_registered(
+            self.fee_payer,
+            self.authority,
+            chain_name,
+            registered,
+        )?))
+    }
+
+    /// Initiates or completes the recovery of the operatorship to
+    /// `new_operator`, see [`super::recover_operatorship`]. Only the upgrade
+    /// authority can sign it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ProgramError`] if the instruction can't be built.
+    pub fn recover_operatorship(
+        &self,
+        new_operator: Pubkey,
+    ) -> Result<OfflineTransaction, ProgramError> {
+        Ok(self.build(super::recover_operatorship(self.authority, new_operator)?))
+    }
+
+    /// Builds the unsigned transaction of `instruction`, prefixed with the
+    /// advance of the durable nonce if any.
+    #[must_use]
+    pub fn build(&self, instruction: Instruction) -> OfflineTransaction {
+        let message = match self.lifetime {
+            Lifetime::RecentBlockhash(blockhash) => {
+                Message::new_with_blockhash(&[instruction], Some(&self.fee_payer), &blockhash)
+            }
+            Lifetime::DurableNonce {
+                nonce_account,
+                nonce_authority,
+                nonce,
+            } => {
+                let mut message = Message::new_with_nonce(
+                    vec![instruction],
+                    Some(&self.fee_payer),
+                    &nonce_account,
+                    &nonce_authority,
+                );
+                message.recent_blockhash = nonce;
+                message
+            }
+        };
+
+        OfflineTransaction { message }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::system_program;
+
+    use super::*;
+
+    fn builder(lifetime: Lifetime) -> (OperatorTransactionBuilder, Pubkey, Pubkey) {
+        let fee_payer = Pubkey::new_unique();
+        let operator = Pubkey::new_unique();
+
+        (
+            OperatorTransactionBuilder::new(fee_payer, operator, lifetime),
+            fee_payer,
+            operator,
+        )
+    }
+
+    #[test]
+    fn recent_blockhash_transaction_is_signed_by_fee_payer_and_authority() {
+        let blockhash = Hash::new_unique();
+        let (builder, fee_payer, operator) = builder(Lifetime::RecentBlockhash(blockhash));
+
+        let transaction = builder.transfer_operatorship(Pubkey::new_unique()).unwrap();
+
+        assert_eq!(transaction.message.recent_blockhash, blockhash);
+        assert_eq!(transaction.signers(), &[fee_payer, operator]);
+        assert_eq!(transaction.message.instructions.len(), 1);
+        assert_eq!(
+            transaction.message.instructions[0].program_id(&transaction.message.account_keys),
+            &crate::ID
+        );
+    }
+
+    #[test]
+    fn durable_nonce_transaction_advances_the_nonce_first() {
+        let nonce_account = Pubkey::new_unique();
+        let nonce_authority = Pubkey::new_unique();
+        let nonce = Hash::new_unique();
+        let (builder, fee_payer, operator) = builder(Lifetime::DurableNonce {
+            nonce_account,
+            nonce_authority,
+            nonce,
+        });
+
+        let transaction = builder
+            .set_chain_registered("ethereum".to_owned(), true)
+            .unwrap();
+
+        let message = &transaction.message;
+        assert_eq!(message.recent_blockhash, nonce);
+        assert_eq!(message.instructions.len(), 2);
+        assert_eq!(
+            message.instructions[0].program_id(&message.account_keys),
+            &system_program::ID
+        );
+        assert_eq!(
+            message.instructions[1].program_id(&message.account_keys),
+            &crate::ID
+        );
+        assert_eq!(transaction.signers().len(), 3);
+        assert_eq!(transaction.signers()[0], fee_payer);
+        assert!(transaction.signers().contains(&operator));
+        assert!(transaction.signers().contains(&nonce_authority));
+    }
+
+    #[test]
+    fn fee_payer_can_be_the_authority() {
+        let operator = Pubkey::new_unique();
+        let builder = OperatorTransactionBuilder::new(
+            operator,
+            operator,
+            Lifetime::RecentBlockhash(Hash::new_unique()),
+        );
+
+        let transaction = builder.resize_config(4096).unwrap();
+
+        assert_eq!(transaction.signers(), &[operator]);
+        assert_eq!(transaction.message_data(), transaction.message.serialize());
+    }
+}
@@ -0,0 +1,573 @@
+//! Packing of the gateway relay flow into transactions.
+//!
+//! Relaying a batch of messages takes many instructions: the signature
+//! verification of the payload, the approval of every message, the upload of
+//! every payload to its `MessagePayload` PDA and the call to the destination
+//! program. [`plan_approve_and_execute`] builds all of them and packs them, in
+//! order, into as few transactions as the size and compute limits allow,
+//! assuming the accounts returned in [`RelayPlan::lookup_table`] are loaded
+//! from an address lookup table by v0 transactions.
+
+use std::collections::{BTreeSet, HashSet};
+
+use axelar_solana_encoding::types::execute_data::{ExecuteData, MerkleisedMessage};
+use solana_program::instruction::Instruction;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::incoming_message::command_id;
+
+/// Maximum size of a serialized transaction.
+pub const MAX_TRANSACTION_SIZE: usize = 1232;
+
+/// Maximum compute units a transaction can request.
+pub const MAX_TRANSACTION_COMPUTE_UNITS: u32 = 1_400_000;
+
+/// Maximum number of addresses an address lookup table can hold.
+pub const MAX_LOOKUP_TABLE_ADDRESSES: usize = 256;
+
+/// Number of payload bytes uploaded by a single `WriteMessagePayload`
+/// instruction, small enough for the instruction to fit in a transaction
+/// together with the compute budget instructions.
+pub const PAYLOAD_CHUNK_SIZE: usize = 800;
+
+/// Compute units budgeted for `InitializePayloadVerificationSession`.
+pub const INITIALIZE_SESSION_COMPUTE_UNITS: u32 = 30_000;
+
+/// Compute units budgeted for each `VerifySignature`.
+pub const VERIFY_SIGNATURE_COMPUTE_UNITS: u32 = 250_000;
+
+/// Compute units budgeted for `ApproveMessage` and `ApproveSingleMessage`.
+pub const APPROVE_MESSAGE_COMPUTE_UNITS: u32 = 60_000;
+
+/// Compute units budgeted for each instruction managing a `MessagePayload`
+/// PDA.
+pub const MESSAGE_PAYLOAD_COMPUTE_UNITS: u32 = 20_000;
+
+/// A message to approve and execute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelayedMessage {
+    /// The message leaf with its merkle proof, out of the signed payload.
+    pub message: MerkleisedMessage,
+    /// The raw payload of the message.
+    pub payload: Vec<u8>,
+    /// The instruction executing the message on its destination program.
+    pub execute: Instruction,
+    /// Compute units budgeted for `execute`.
+    pub execute_compute_units: u32,
+}
+
+/// Limits every planned transaction is kept within.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelayLimits {
+    /// Maximum size of a serialized transaction.
+    pub max_transaction_size: usize,
+    /// Maximum compute units of a transaction.
+    pub max_compute_units: u32,
+    /// Bytes kept free in each transaction for the instructions the relayer
+    /// adds on its own, such as the compute budget ones.
+    pub reserved_bytes: usize,
+}
+
+impl Default for RelayLimits {
+    fn default() -> Self {
+        Self {
+            max_transaction_size: MAX_TRANSACTION_SIZE,
+            max_compute_units: MAX_TRANSACTION_COMPUTE_UNITS,
+            // Program id of the compute budget program, and the
+            // `SetComputeUnitLimit` and `SetComputeUnitPrice` instructions.
+            reserved_bytes: 32 + 2 * 12,
+        }
+    }
+}
+
+/// A transaction of a [`RelayPlan`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RelayTransaction {
+    /// The instructions of the transaction, in execution order.
+    pub instructions: Vec<Instruction>,
+    /// Compute units budgeted for the instructions.
+    pub compute_units: u32,
+}
+
+/// The transactions relaying a batch of messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelayPlan {
+    /// The accounts to store in the address lookup table the transactions
+    /// are sent with.
+    pub lookup_table: Vec<Pubkey>,
+    /// The transactions to send, one after the other.
+    pub transactions: Vec<RelayTransaction>,
+}
+
+/// Errors returned while planning a relay.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RelayPlanError {
+    /// An instruction of the relay couldn't be built.
+    #[error("Failed to build a relay instruction: {0}")]
+    Instruction(#[from] ProgramError),
+
+    /// An instruction exceeds the limits of a transaction on its own.
+    #[error("Relay instruction {0} exceeds the transaction limits on its own")]
+    InstructionTooLarge(usize),
+}
+
+/// Builds the instructions relaying `messages`, signed by `execute_data`,
+/// and packs them into transactions within `limits`.
+///
+/// The signature verification stops as soon as the quorum is reached.
+/// Ed25519 signatures are verified by an Ed25519 program instruction kept in
+/// the same transaction as, and right before, their `VerifySignature`.
+/// Messages alone in their payload are approved without a merkle proof.
+/// Payloads are uploaded in chunks of [`PAYLOAD_CHUNK_SIZE`] bytes and their
+/// `MessagePayload` PDA is closed once the message is executed.
+///
+/// # Errors
+///
+/// [`RelayPlanError`] if an instruction can't be built or doesn't fit in a
+/// transaction on its own.
+pub fn plan_approve_and_execute(
+    payer: Pubkey,
+    execute_data: &ExecuteData,
+    messages: Vec<RelayedMessage>,
+    limits: &RelayLimits,
+) -> Result<RelayPlan, RelayPlanError> {
+    let instructions = relay_instructions(payer, execute_data, messages)?;
+    let lookup_table = lookup_table_addresses(&instructions);
+    let lookup_set = lookup_table.iter().copied().collect::<HashSet<_>>();
+
+    let fits = |transaction: &RelayTransaction| {
+        transaction.compute_units <= limits.max_compute_units
+            && transaction_size(&transaction.instructions, &lookup_set) + limits.reserved_bytes
+                <= limits.max_transaction_size
+    };
+
+    let mut transactions = Vec::new();
+    let mut current = RelayTransaction::default();
+    for (index, (instruction, compute_units)) in instructions.into_iter().enumerate() {
+        let previous_compute_units = current.compute_units;
+        current.instructions.push(instruction);
+        current.compute_units = previous_compute_units.saturating_add(compute_units);
+        if fits(&current) {
+            continue;
+        }
+
+        // Move the instruction to a new transaction, along with the Ed25519
+        // instruction verifying its signature, which must precede it
+        let instruction = current
+            .instructions
+            .pop()
+            .expect("instruction was just pushed");
+        let mut moved = vec![instruction];
+        if current
+            .instructions
+            .last()
+            .is_some_and(|last| last.program_id == solana_program::ed25519_program::ID)
+        {
+            moved.extend(current.instructions.pop());
+            moved.reverse();
+        }
+        if !current.instructions.is_empty() {
+            current.compute_units = previous_compute_units;
+            transactions.push(core::mem::take(&mut current));
+        }
+        current = RelayTransaction {
+            instructions: moved,
+            compute_units,
+        };
+        if !fits(&current) {
+            return Err(RelayPlanError::InstructionTooLarge(index));
+        }
+    }
+    if !current.instructions.is_empty() {
+        transactions.push(current);
+    }
+
+    Ok(RelayPlan {
+        lookup_table,
+        transactions,
+    })
+}
+
+/// Builds the relay instructions, in execution order, with their compute
+/// budget.
+fn relay_instructions(
+    payer: Pubkey,
+    execute_data: &ExecuteData,
+    messages: Vec<RelayedMessage>,
+) -> Result<Vec<(Instruction, u32)>, ProgramError> {
+    let (gateway_root_pda, _) = crate::get_gateway_root_config_pda();
+    let payload_merkle_root = execute_data.payload_merkle_root;
+    let signing_verifier_set_hash = execute_data.signing_verifier_set_merkle_root;
+    let (verifier_set_tracker_pda, _) =
+        crate::get_verifier_set_tracker_pda(signing_verifier_set_hash);
+    let (verification_session_pda, _) =
+        crate::get_signature_verification_pda(&payload_merkle_root, &signing_verifier_set_hash);
+
+    let mut instructions = vec![(
+        super::initialize_payload_verification_session(
+            payer,
+            gateway_root_pda,
+            payload_merkle_root,
+            signing_verifier_set_hash,
+        )?,
+        INITIALIZE_SESSION_COMPUTE_UNITS,
+    )];
+
+    let mut accumulated_weight = 0_u128;
+    for signature in &execute_data.signing_verifier_set_leaves {
+        if accumulated_weight >= signature.leaf.quorum {
+            break;
+        }
+        accumulated_weight = accumulated_weight.saturating_add(signature.leaf.signer_weight);
+        // The Ed25519 program doesn't consume compute units of the transaction
+        if let Some(ed25519) =
+            super::ed25519_signature_verification(signature, &payload_merkle_root)
+        {
+            instructions.push((ed25519, 0));
+        }
+        instructions.push((
+            super::verify_signature(
+                gateway_root_pda,
+                verifier_set_tracker_pda,
+                verification_session_pda,
+                payload_merkle_root,
+                signature.clone(),
+            )?,
+            VERIFY_SIGNATURE_COMPUTE_UNITS,
+        ));
+    }
+
+    for relayed in messages {
+        let cc_id = &relayed.message.leaf.message.cc_id;
+        let command_id = command_id(&cc_id.chain, &cc_id.id);
+        let (incoming_message_pda, _) = crate::get_incoming_message_pda(&command_id);
+
+        let approve = if relayed.message.leaf.set_size == 1 {
+            super::approve_single_message(
+                relayed.message.leaf,
+                gateway_root_pda,
+                payer,
+                verification_session_pda,
+                incoming_message_pda,
+            )?
+        } else {
+            super::approve_message(
+                relayed.message,
+                payload_merkle_root,
+                gateway_root_pda,
+                payer,
+                verification_session_pda,
+                incoming_message_pda,
+            )?
+        };
+        instructions.push((approve, APPROVE_MESSAGE_COMPUTE_UNITS));
+
+        let buffer_size =
+            u64::try_from(relayed.payload.len()).map_err(|_err| ProgramError::InvalidArgument)?;
+        instructions.push((
+            super::initialize_message_payload(gateway_root_pda, payer, command_id, buffer_size)?,
+            MESSAGE_PAYLOAD_COMPUTE_UNITS,
+        ));
+        let mut offset = 0_u64;
+        for chunk in relayed.payload.chunks(PAYLOAD_CHUNK_SIZE) {
+            instructions.push((
+                super::write_message_payload(gateway_root_pda, payer, command_id, chunk, offset)?,
+                MESSAGE_PAYLOAD_COMPUTE_UNITS,
+            ));
+            offset += chunk.len() as u64;
+        }
+        instructions.push((
+            super::commit_message_payload(gateway_root_pda, payer, command_id)?,
+            MESSAGE_PAYLOAD_COMPUTE_UNITS,
+        ));
+        instructions.push((relayed.execute, relayed.execute_compute_units));
+        instructions.push((
+            super::close_message_payload(gateway_root_pda, payer, command_id)?,
+            MESSAGE_PAYLOAD_COMPUTE_UNITS,
+        ));
+    }
+
+    Ok(instructions)
+}
+
+/// The accounts worth loading from a lookup table: every account that's
+/// neither a signer nor an invoked program, which v0 transactions require to
+/// be listed in full.
+fn lookup_table_addresses(instructions: &[(Instruction, u32)]) -> Vec<Pubkey> {
+    let static_keys = instructions
+        .iter()
+        .flat_map(|(instruction, _)| {
+            instruction
+                .accounts
+                .iter()
+                .filter(|meta| meta.is_signer)
+                .map(|meta| meta.pubkey)
+                .chain(core::iter::once(instruction.program_id))
+        })
+        .collect::<HashSet<_>>();
+
+    let mut seen = HashSet::new();
+    instructions
+        .iter()
+        .flat_map(|(instruction, _)| instruction.accounts.iter())
+        .map(|meta| meta.pubkey)
+        .filter(|key| !static_keys.contains(key) && seen.insert(*key))
+        .take(MAX_LOOKUP_TABLE_ADDRESSES)
+        .collect()
+}
+
+/// Size of a v0 transaction carrying `instructions`, with the accounts of
+/// `lookup_table` loaded from a single address lookup table.
+fn transaction_size(instructions: &[Instruction], lookup_table: &HashSet<Pubkey>) -> usize {
+    let signers = instructions
+        .iter()
+        .flat_map(|instruction| instruction.accounts.iter())
+        .filter(|meta| meta.is_signer)
+        .map(|meta| meta.pubkey)
+        .collect::<BTreeSet<_>>();
+    let programs = instructions
+        .iter()
+        .map(|instruction| instruction.program_id)
+        .collect::<BTreeSet<_>>();
+    let accounts = instructions
+        .iter()
+        .flat_map(|instruction| instruction.accounts.iter())
+        .map(|meta| meta.pubkey)
+        .filter(|key| !signers.contains(key) && !programs.contains(key))
+        .collect::<BTreeSet<_>>();
+    let (looked_up, unlisted): (Vec<_>, Vec<_>) = accounts
+        .into_iter()
+        .partition(|key| lookup_table.contains(key));
+    let static_keys = signers.len() + programs.len() + unlisted.len();
+
+    let signatures = compact_len(signers.len()) + signers.len() * 64;
+    // Version prefix, message header and recent blockhash.
+    let fixed = 1 + 3 + 32;
+    let keys = compact_len(static_keys) + static_keys * 32;
+    let instructions_size =
+        instructions
+            .iter()
+            .fold(compact_len(instructions.len()), |size, instruction| {
+                size + 1
+                    + compact_len(instruction.accounts.len())
+                    + instruction.accounts.len()
+                    + compact_len(instruction.data.len())
+                    + instruction.data.len()
+            });
+    // Table address, and the writable and readonly index lists.
+    let lookups = if looked_up.is_empty() {
+        compact_len(0)
+    } else {
+        compact_len(1) + 32 + 2 * compact_len(looked_up.len()) + looked_up.len()
+    };
+
+    signatures + fixed + keys + instructions_size + lookups
+}
+
+/// Size of the compact-u16 encoding of `len`.
+const fn compact_len(len: usize) -> usize {
+    if len < 0x80 {
+        1
+    } else if len < 0x4000 {
+        2
+    } else {
+        3
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axelar_solana_encoding::types::execute_data::{MerkleisedPayload, SigningVerifierSetInfo};
+    use axelar_solana_encoding::types::messages::{CrossChainId, Message, MessageLeaf};
+    use axelar_solana_encoding::types::pubkey::{PublicKey, Signature};
+    use axelar_solana_encoding::types::verifier_set::VerifierSetLeaf;
+    use borsh::BorshDeserialize;
+    use solana_program::instruction::AccountMeta;
+
+    use super::*;
+    use crate::instructions::GatewayInstruction;
+
+    fn execute_data(signer_count: u16, quorum: u128) -> ExecuteData {
+        ExecuteData {
+            signing_verifier_set_merkle_root: [1; 32],
+            signing_verifier_set_leaves: (0..signer_count)
+                .map(|position| SigningVerifierSetInfo {
+                    signature: Signature::EcdsaRecoverable([2; 65]),
+                    leaf: VerifierSetLeaf {
+                        nonce: 0,
+                        quorum,
+                        signer_pubkey: PublicKey::Secp256k1([3; 33]),
+                        signer_weight: 1,
+                        position,
+                        set_size: signer_count,
+                        domain_separator: [4; 32],
+                    },
+                    merkle_proof: vec![5; 32 * 4],
+                })
+                .collect(),
+            payload_merkle_root: [6; 32],
+            payload_items: MerkleisedPayload::NewMessages { messages: vec![] },
+        }
+    }
+
+    fn relayed_message(id: &str, set_size: u16, payload_len: usize) -> RelayedMessage {
+        RelayedMessage {
+            message: MerkleisedMessage {
+                leaf: MessageLeaf {
+                    message: Message {
+                        cc_id: CrossChainId {
+                            chain: "ethereum".to_owned(),
+                            id: id.to_owned(),
+                        },
+                        source_address: "0xsource".to_owned(),
+                        destination_chain: "solana".to_owned(),
+                        destination_address: Pubkey::new_unique().to_string(),
+                        payload_hash: [7; 32],
+                    },
+                    position: 0,
+                    set_size,
+                    domain_separator: [4; 32],
+                },
+                proof: vec![8; 32],
+            },
+            payload: vec![9; payload_len],
+            execute: Instruction {
+                program_id: Pubkey::new_unique(),
+                accounts: vec![AccountMeta::new(Pubkey::new_unique(), false)],
+                data: vec![10; 64],
+            },
+            execute_compute_units: 200_000,
+        }
+    }
+
+    fn gateway_instruction(instruction: &Instruction) -> GatewayInstruction {
+        GatewayInstruction::try_from_slice(&instruction.data).unwrap()
+    }
+
+    #[test]
+    fn test_plan_keeps_order_and_limits() {
+        let payer = Pubkey::new_unique();
+        let execute_data = execute_data(10, 7);
+        let messages = vec![relayed_message("1", 2, 2_000), relayed_message("2", 2, 100)];
+        let expected = relay_instructions(payer, &execute_data, messages.clone()).unwrap();
+        let limits = RelayLimits::default();
+
+        let plan = plan_approve_and_execute(payer, &execute_data, messages, &limits).unwrap();
+
+        let planned = plan
+            .transactions
+            .iter()
+            .flat_map(|transaction| transaction.instructions.iter().cloned())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            planned,
+            expected.into_iter().map(|(ix, _)| ix).collect::<Vec<_>>()
+        );
+        let lookup_table = plan.lookup_table.iter().copied().collect::<HashSet<_>>();
+        for transaction in &plan.transactions {
+            assert!(transaction.compute_units <= limits.max_compute_units);
+            assert!(
+                transaction_size(&transaction.instructions, &lookup_table) + limits.reserved_bytes
+                    <= limits.max_transaction_size
+            );
+        }
+        assert!(!plan.lookup_table.contains(&payer));
+        assert!(plan.transactions.len() < planned.len());
+    }
+
+    #[test]
+    fn test_plan_stops_verification_at_quorum() {
+        let payer = Pubkey::new_unique();
+        let instructions = relay_instructions(payer, &execute_data(10, 3), vec![]).unwrap();
+
+        let verifications = instructions
+            .iter()
+            .filter(|(ix, _)| {
+                matches!(
+                    gateway_instruction(ix),
+                    GatewayInstruction::VerifySignature { .. }
+                )
+            })
+            .count();
+        assert_eq!(verifications, 3);
+    }
+
+    #[test]
+    fn test_plan_keeps_ed25519_verification_before_its_signature() {
+        let payer = Pubkey::new_unique();
+        let mut execute_data = execute_data(10, 10);
+        for signature in &mut execute_data.signing_verifier_set_leaves {
+            signature.signature = Signature::Ed25519([2; 64]);
+            signature.leaf.signer_pubkey = PublicKey::Ed25519([3; 32]);
+        }
+        // Room for a few signature verifications per transaction at most
+        let limits = RelayLimits {
+            max_compute_units: 3 * VERIFY_SIGNATURE_COMPUTE_UNITS,
+            ..RelayLimits::default()
+        };
+
+        let plan = plan_approve_and_execute(payer, &execute_data, vec![], &limits).unwrap();
+
+        let mut verifications = 0;
+        for transaction in &plan.transactions {
+            for (index, instruction) in transaction.instructions.iter().enumerate() {
+                if instruction.program_id != crate::ID
+                    || !matches!(
+                        gateway_instruction(instruction),
+                        GatewayInstruction::VerifySignature { .. }
+                    )
+                {
+                    continue;
+                }
+                verifications += 1;
+                let preceding = index
+                    .checked_sub(1)
+                    .and_then(|index| transaction.instructions.get(index))
+                    .unwrap();
+                assert_eq!(preceding.program_id, solana_program::ed25519_program::ID);
+            }
+        }
+        assert_eq!(verifications, 10);
+        assert!(plan.transactions.len() > 1);
+    }
+
+    #[test]
+    fn test_plan_approves_single_message_payload_without_proof() {
+        let payer = Pubkey::new_unique();
+        let instructions = relay_instructions(
+            payer,
+            &execute_data(1, 1),
+            vec![relayed_message("1", 1, 100)],
+        )
+        .unwrap();
+
+        assert!(instructions.iter().any(|(ix, _)| matches!(
+            gateway_instruction(ix),
+            GatewayInstruction::ApproveSingleMessage { .. }
+        )));
+        assert!(!instructions.iter().any(|(ix, _)| matches!(
+            gateway_instruction(ix),
+            GatewayInstruction::ApproveMessage { .. }
+        )));
+    }
+
+    #[test]
+    fn test_plan_rejects_oversized_instruction() {
+        let payer = Pubkey::new_unique();
+        let mut message = relayed_message("1", 2, 100);
+        message.execute_compute_units = MAX_TRANSACTION_COMPUTE_UNITS + 1;
+
+        let result = plan_approve_and_execute(
+            payer,
+            &execute_data(1, 1),
+            vec![message],
+            &RelayLimits::default(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(RelayPlanError::InstructionTooLarge(_))
+        ));
+    }
+}
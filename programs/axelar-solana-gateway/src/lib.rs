@@ -22,7 +22,7 @@ use solana_program::program_error::ProgramError;
 use solana_program::pubkey::{Pubkey, PubkeyError};
 
 use crate::error::GatewayError;
-use crate::state::GatewayConfig;
+use crate::state::{AccountView, GatewayConfig};
 use program_utils::pda::ValidPDA;
 
 ensure_single_feature!("devnet-amplifier", "stagenet", "testnet", "mainnet");
@@ -56,6 +56,10 @@ pub mod seed_prefixes {
     /// The seed prefix for deriving validate message signing PDAs
     /// This corresponds to the hardcoded value in `axelar_message_primitives::destination_program_id::DestinationProgramId::signing_pda`
     pub const VALIDATE_MESSAGE_SIGNING_SEED: &[u8] = b"gtw-validate-msg";
+    /// The seed prefix for deriving the chain registry PDA
+    pub const CHAIN_REGISTRY_SEED: &[u8] = b"gtw-chain-registry";
+    /// The seed prefix for deriving operatorship recovery PDAs
+    pub const OPERATORSHIP_RECOVERY_SEED: &[u8] = b"gtw-op-recovery";
 }
 
 /// Checks that the supplied program ID is the correct one
@@ -72,28 +76,85 @@ pub fn check_program_account(program_id: Pubkey) -> ProgramResult {
     Ok(())
 }
 
-/// Get the root PDA and bump seed for the given program ID.
+/// Namespace of a gateway instance.
+///
+/// Several gateways can run from the same program id, e.g. to test parallel
+/// verifier sets on a staging cluster. Each one is initialized under its own
+/// namespace, which is mixed into the seeds of its root config, verifier set
+/// tracker, signature verification session and incoming message PDAs, so
+/// instances sharing a program id never share state.
+///
+/// Initializing a gateway under a non-default namespace requires the
+/// `namespaced-pdas` feature. The client-side instruction builders and the
+/// [`executable`] helpers keep targeting the [`DEFAULT_NAMESPACE`].
+pub type GatewayNamespace = u8;
+
+/// The namespace of the canonical gateway instance. Its PDAs are derived
+/// without a namespace seed, so their addresses are the same as before
+/// namespaces were introduced.
+pub const DEFAULT_NAMESPACE: GatewayNamespace = 0;
+
+/// Returns the seed that separates the PDAs of `namespace` from the ones of
+/// other gateway instances.
+///
+/// The seed is empty for the [`DEFAULT_NAMESPACE`], which leaves the derived
+/// addresses untouched.
 #[inline]
-pub(crate) fn get_gateway_root_config_internal(program_id: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[seed_prefixes::GATEWAY_SEED], program_id)
+#[must_use]
+pub fn namespace_seed(namespace: &GatewayNamespace) -> &[u8] {
+    if *namespace == DEFAULT_NAMESPACE {
+        &[]
+    } else {
+        core::slice::from_ref(namespace)
+    }
+}
+
+/// Get the root PDA and bump seed for the given program ID and namespace.
+#[inline]
+pub(crate) fn get_gateway_root_config_internal(
+    program_id: &Pubkey,
+    namespace: GatewayNamespace,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seed_prefixes::GATEWAY_SEED, namespace_seed(&namespace)],
+        program_id,
+    )
 }
 
 /// Get the root PDA and bump seed for the given program ID.
 #[inline]
 #[must_use]
 pub fn get_gateway_root_config_pda() -> (Pubkey, u8) {
-    get_gateway_root_config_internal(&crate::ID)
+    get_gateway_root_config_pda_with_namespace(DEFAULT_NAMESPACE)
+}
+
+/// Get the root PDA and bump seed of the gateway instance in `namespace`.
+#[inline]
+#[must_use]
+pub fn get_gateway_root_config_pda_with_namespace(namespace: GatewayNamespace) -> (Pubkey, u8) {
+    get_gateway_root_config_internal(&crate::ID, namespace)
 }
 
 /// Assert that the gateway PDA has been initialized and is valid
+///
+/// Returns the namespace of the gateway instance, which the PDAs it owns are
+/// derived with.
+///
+/// # Errors
+///
+/// Returns [`ProgramError`] if the account is not an initialized gateway
+/// config PDA.
 pub fn assert_initialized_and_valid_gateway_root_pda(
     gw_root_pda: &AccountInfo<'_>,
-) -> Result<(), ProgramError> {
+) -> Result<GatewayNamespace, ProgramError> {
     gw_root_pda.check_initialized_pda_without_deserialization(&crate::ID)?;
-    let gateway_data = gw_root_pda.try_borrow_data()?;
-    let gateway_config =
-        GatewayConfig::read(&gateway_data).ok_or(GatewayError::BytemuckDataLenInvalid)?;
-    assert_valid_gateway_root_pda(gateway_config.bump, gw_root_pda.key)
+    let gateway_config = GatewayConfig::view(gw_root_pda)?;
+    assert_valid_gateway_root_pda(
+        gateway_config.namespace,
+        gateway_config.bump,
+        gw_root_pda.key,
+    )?;
+    Ok(gateway_config.namespace)
 }
 
 /// Assert that the gateway PDA has been derived correctly
@@ -107,10 +168,20 @@ pub fn assert_initialized_and_valid_gateway_root_pda(
 /// Returns [`ProgramError::IncorrectProgramId`] if the derived PDA does not match the expected pubkey.
 #[inline]
 #[track_caller]
-fn assert_valid_gateway_root_pda(bump: u8, expected_pubkey: &Pubkey) -> Result<(), ProgramError> {
-    let derived_pubkey =
-        Pubkey::create_program_address(&[seed_prefixes::GATEWAY_SEED, &[bump]], &crate::ID)
-            .expect("invalid bump for the root pda");
+fn assert_valid_gateway_root_pda(
+    namespace: GatewayNamespace,
+    bump: u8,
+    expected_pubkey: &Pubkey,
+) -> Result<(), ProgramError> {
+    let derived_pubkey = Pubkey::create_program_address(
+        &[
+            seed_prefixes::GATEWAY_SEED,
+            namespace_seed(&namespace),
+            &[bump],
+        ],
+        &crate::ID,
+    )
+    .expect("invalid bump for the root pda");
     if &derived_pubkey != expected_pubkey {
         solana_program::msg!("Error: Invalid Gateway Root PDA ");
         return Err(ProgramError::IncorrectProgramId);
@@ -118,12 +189,136 @@ fn assert_valid_gateway_root_pda(bump: u8, expected_pubkey: &Pubkey) -> Result<(
     Ok(())
 }
 
+/// Get the event authority PDA & bump used to sign the gateway event CPIs
+#[inline]
+#[must_use]
+pub fn get_event_authority_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID)
+}
+
+/// Assert that the event authority account is the canonical event-cpi PDA of
+/// the gateway and that it is still a plain system account without data, so
+/// the gateway can keep signing event CPIs with it.
+///
+/// Returns the canonical bump of the event authority PDA.
+///
+/// # Errors
+///
+/// Returns [`GatewayError::InvalidEventAuthority`] if the derivation does not match, or the
+/// account has been assigned to another program or allocated data.
+pub fn assert_valid_event_authority(event_authority: &AccountInfo<'_>) -> Result<u8, ProgramError> {
+    let (derived_pubkey, bump) = get_event_authority_pda();
+    if *event_authority.key != derived_pubkey {
+        solana_program::msg!("Error: Invalid event authority PDA derivation");
+        return Err(GatewayError::InvalidEventAuthority.into());
+    }
+    if !solana_program::system_program::check_id(event_authority.owner)
+        || !event_authority.data_is_empty()
+    {
+        solana_program::msg!("Error: event authority must be an empty system account");
+        return Err(GatewayError::InvalidEventAuthority.into());
+    }
+    Ok(bump)
+}
+
+/// Get the chain registry PDA & bump
+#[inline]
+#[must_use]
+pub fn get_chain_registry_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[seed_prefixes::CHAIN_REGISTRY_SEED], &crate::ID)
+}
+
+/// Assert that the chain registry PDA has been derived correctly
+///
+/// # Panics
+///
+/// Panics if the bump seed produces an invalid program derived address.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::InvalidSeeds`] if the derived PDA does not match the expected pubkey.
+#[inline]
+#[track_caller]
+pub fn assert_valid_chain_registry_pda(
+    bump: u8,
+    expected_pubkey: &Pubkey,
+) -> Result<(), ProgramError> {
+    let derived_pubkey =
+        Pubkey::create_program_address(&[seed_prefixes::CHAIN_REGISTRY_SEED, &[bump]], &crate::ID)
+            .expect("invalid bump for the chain registry PDA");
+    if &derived_pubkey != expected_pubkey {
+        solana_program::msg!("Error: Invalid Chain Registry PDA ");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    Ok(())
+}
+
+/// Get the operatorship recovery PDA & bump of the gateway config at
+/// `gateway_root_pda`
+#[inline]
+#[must_use]
+pub fn get_operatorship_recovery_pda(gateway_root_pda: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seed_prefixes::OPERATORSHIP_RECOVERY_SEED,
+            gateway_root_pda.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Assert that the operatorship recovery PDA has been derived correctly
+///
+/// # Panics
+///
+/// Panics if the bump seed produces an invalid program derived address.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::InvalidSeeds`] if the derived PDA does not match the expected pubkey.
+#[inline]
+#[track_caller]
+pub fn assert_valid_operatorship_recovery_pda(
+    gateway_root_pda: &Pubkey,
+    bump: u8,
+    expected_pubkey: &Pubkey,
+) -> Result<(), ProgramError> {
+    let derived_pubkey = Pubkey::create_program_address(
+        &[
+            seed_prefixes::OPERATORSHIP_RECOVERY_SEED,
+            gateway_root_pda.as_ref(),
+            &[bump],
+        ],
+        &crate::ID,
+    )
+    .expect("invalid bump for the operatorship recovery PDA");
+    if &derived_pubkey != expected_pubkey {
+        solana_program::msg!("Error: Invalid Operatorship Recovery PDA ");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    Ok(())
+}
+
 /// Get the incoming message PDA & bump
 #[inline]
 #[must_use]
 pub fn get_incoming_message_pda(command_id: &[u8]) -> (Pubkey, u8) {
+    get_incoming_message_pda_with_namespace(DEFAULT_NAMESPACE, command_id)
+}
+
+/// Get the incoming message PDA & bump of the gateway instance in `namespace`
+#[inline]
+#[must_use]
+pub fn get_incoming_message_pda_with_namespace(
+    namespace: GatewayNamespace,
+    command_id: &[u8],
+) -> (Pubkey, u8) {
     Pubkey::find_program_address(
-        &[seed_prefixes::INCOMING_MESSAGE_SEED, command_id],
+        &[
+            seed_prefixes::INCOMING_MESSAGE_SEED,
+            namespace_seed(&namespace),
+            command_id,
+        ],
         &crate::ID,
     )
 }
@@ -137,8 +332,28 @@ pub fn get_incoming_message_pda(command_id: &[u8]) -> (Pubkey, u8) {
 /// a valid program derived address.
 #[inline]
 pub fn create_incoming_message_pda(command_id: [u8; 32], bump: u8) -> Result<Pubkey, PubkeyError> {
+    create_incoming_message_pda_with_namespace(DEFAULT_NAMESPACE, command_id, bump)
+}
+
+/// Namespaced counterpart of [`create_incoming_message_pda`].
+///
+/// # Errors
+///
+/// Returns a [`PubkeyError`] if the derived address lies on the ed25519 curve and is therefore not
+/// a valid program derived address.
+#[inline]
+pub fn create_incoming_message_pda_with_namespace(
+    namespace: GatewayNamespace,
+    command_id: [u8; 32],
+    bump: u8,
+) -> Result<Pubkey, PubkeyError> {
     Pubkey::create_program_address(
-        &[seed_prefixes::INCOMING_MESSAGE_SEED, &command_id, &[bump]],
+        &[
+            seed_prefixes::INCOMING_MESSAGE_SEED,
+            namespace_seed(&namespace),
+            &command_id,
+            &[bump],
+        ],
         &crate::ID,
     )
 }
@@ -158,9 +373,39 @@ pub fn assert_valid_incoming_message_pda(
     command_id: &[u8],
     bump: u8,
     expected_pubkey: &Pubkey,
+) -> Result<(), ProgramError> {
+    assert_valid_incoming_message_pda_with_namespace(
+        DEFAULT_NAMESPACE,
+        command_id,
+        bump,
+        expected_pubkey,
+    )
+}
+
+/// Namespaced counterpart of [`assert_valid_incoming_message_pda`].
+///
+/// # Panics
+///
+/// Panics if the bump seed produces an invalid program derived address.
+///
+/// # Errors
+///
+/// Returns [`ProgramError::IncorrectProgramId`] if the derived PDA does not match the expected pubkey.
+#[inline]
+#[track_caller]
+pub fn assert_valid_incoming_message_pda_with_namespace(
+    namespace: GatewayNamespace,
+    command_id: &[u8],
+    bump: u8,
+    expected_pubkey: &Pubkey,
 ) -> Result<(), ProgramError> {
     let derived_pubkey = Pubkey::create_program_address(
-        &[seed_prefixes::INCOMING_MESSAGE_SEED, command_id, &[bump]],
+        &[
+            seed_prefixes::INCOMING_MESSAGE_SEED,
+            namespace_seed(&namespace),
+            command_id,
+            &[bump],
+        ],
         &crate::ID,
     )
     .expect("invalid bump for the incoming message PDA");
@@ -203,9 +448,24 @@ pub fn assert_valid_message_payload_pda(
 #[must_use]
 pub fn get_verifier_set_tracker_pda(
     hash: crate::state::verifier_set_tracker::VerifierSetHash,
+) -> (Pubkey, u8) {
+    get_verifier_set_tracker_pda_with_namespace(DEFAULT_NAMESPACE, hash)
+}
+
+/// Get the `VerifierSetTracker` PDA and bump seed of the gateway instance in
+/// `namespace` for a given verifier set hash.
+#[inline]
+#[must_use]
+pub fn get_verifier_set_tracker_pda_with_namespace(
+    namespace: GatewayNamespace,
+    hash: crate::state::verifier_set_tracker::VerifierSetHash,
 ) -> (Pubkey, u8) {
     Pubkey::find_program_address(
-        &[seed_prefixes::VERIFIER_SET_TRACKER_SEED, hash.as_slice()],
+        &[
+            seed_prefixes::VERIFIER_SET_TRACKER_SEED,
+            namespace_seed(&namespace),
+            hash.as_slice(),
+        ],
         &crate::ID,
     )
 }
@@ -225,10 +485,35 @@ pub fn get_verifier_set_tracker_pda(
 pub fn assert_valid_verifier_set_tracker_pda(
     tracker: &crate::state::verifier_set_tracker::VerifierSetTracker,
     expected_pubkey: &Pubkey,
+) -> Result<(), ProgramError> {
+    assert_valid_verifier_set_tracker_pda_with_namespace(
+        DEFAULT_NAMESPACE,
+        tracker,
+        expected_pubkey,
+    )
+}
+
+/// Namespaced counterpart of [`assert_valid_verifier_set_tracker_pda`].
+///
+/// # Errors
+///
+/// Returns [`ProgramError::IncorrectProgramId`] if the derived PDA pubkey does not match the
+/// expected pubkey.
+///
+/// # Panics
+///
+/// Panics if PDA creation fails due to an invalid bump seed.
+#[inline]
+#[track_caller]
+pub fn assert_valid_verifier_set_tracker_pda_with_namespace(
+    namespace: GatewayNamespace,
+    tracker: &crate::state::verifier_set_tracker::VerifierSetTracker,
+    expected_pubkey: &Pubkey,
 ) -> Result<(), ProgramError> {
     let derived_pubkey = Pubkey::create_program_address(
         &[
             seed_prefixes::VERIFIER_SET_TRACKER_SEED,
+            namespace_seed(&namespace),
             tracker.verifier_set_hash.as_slice(),
             &[tracker.bump],
         ],
@@ -248,10 +533,27 @@ pub fn assert_valid_verifier_set_tracker_pda(
 pub fn get_signature_verification_pda(
     payload_merkle_root: &[u8; 32],
     signing_verifier_set: &[u8; 32],
+) -> (Pubkey, u8) {
+    get_signature_verification_pda_with_namespace(
+        DEFAULT_NAMESPACE,
+        payload_merkle_root,
+        signing_verifier_set,
+    )
+}
+
+/// Get the PDA and bump seed for a given payload hash in the gateway instance
+/// of `namespace`.
+#[inline]
+#[must_use]
+pub fn get_signature_verification_pda_with_namespace(
+    namespace: GatewayNamespace,
+    payload_merkle_root: &[u8; 32],
+    signing_verifier_set: &[u8; 32],
 ) -> (Pubkey, u8) {
     let (pubkey, bump) = Pubkey::find_program_address(
         &[
             seed_prefixes::SIGNATURE_VERIFICATION_SEED,
+            namespace_seed(&namespace),
             payload_merkle_root,
             signing_verifier_set,
         ],
@@ -278,14 +580,39 @@ pub fn assert_valid_signature_verification_pda(
     bump: u8,
     expected_pubkey: &Pubkey,
 ) -> Result<(), ProgramError> {
-    let derived_pubkey = Pubkey::create_program_address(
-        &[
-            seed_prefixes::SIGNATURE_VERIFICATION_SEED,
-            payload_merkle_root,
-            signing_verifier_set,
-            &[bump],
-        ],
-        &crate::ID,
+    assert_valid_signature_verification_pda_with_namespace(
+        DEFAULT_NAMESPACE,
+        payload_merkle_root,
+        signing_verifier_set,
+        bump,
+        expected_pubkey,
+    )
+}
+
+/// Namespaced counterpart of [`assert_valid_signature_verification_pda`].
+///
+/// # Errors
+///
+/// Returns [`ProgramError::IncorrectProgramId`] if the derived PDA
+/// pubkey does not match the expected pubkey.
+///
+/// # Panics
+///
+/// Panics if PDA creation fails due to an invalid bump seed.
+#[inline]
+#[track_caller]
+pub fn assert_valid_signature_verification_pda_with_namespace(
+    namespace: GatewayNamespace,
+    payload_merkle_root: &[u8; 32],
+    signing_verifier_set: &[u8; 32],
+    bump: u8,
+    expected_pubkey: &Pubkey,
+) -> Result<(), ProgramError> {
+    let derived_pubkey = create_signature_verification_pda_with_namespace(
+        namespace,
+        payload_merkle_root,
+        signing_verifier_set,
+        bump,
     )
     .expect("invalid bump for the pda");
     if &derived_pubkey != expected_pubkey {
@@ -306,10 +633,32 @@ pub fn create_signature_verification_pda(
     payload_merkle_root: &[u8; 32],
     signing_verifier_set: &[u8; 32],
     bump: u8,
+) -> Result<Pubkey, PubkeyError> {
+    create_signature_verification_pda_with_namespace(
+        DEFAULT_NAMESPACE,
+        payload_merkle_root,
+        signing_verifier_set,
+        bump,
+    )
+}
+
+/// Namespaced counterpart of [`create_signature_verification_pda`].
+///
+/// # Errors
+///
+/// Returns a [`PubkeyError`] if the derived address lies on the ed25519 curve and is therefore not
+/// a valid program derived address.
+#[inline]
+pub fn create_signature_verification_pda_with_namespace(
+    namespace: GatewayNamespace,
+    payload_merkle_root: &[u8; 32],
+    signing_verifier_set: &[u8; 32],
+    bump: u8,
 ) -> Result<Pubkey, PubkeyError> {
     Pubkey::create_program_address(
         &[
             seed_prefixes::SIGNATURE_VERIFICATION_SEED,
+            namespace_seed(&namespace),
             payload_merkle_root,
             signing_verifier_set,
             &[bump],
@@ -431,7 +780,10 @@ pub fn create_message_payload_pda(
 
 #[cfg(test)]
 mod tests {
+    use axelar_message_primitives::U256;
+
     use super::*;
+    use crate::state::verifier_set_tracker::VerifierSetTracker;
 
     /// Test that the bump from `get_signature_verification_pda` generates the same
     /// public key when used with the same hash by
@@ -483,13 +835,127 @@ mod tests {
 
     #[test]
     fn test_valid_gateway_root_pda_generation() {
-        let (internal, bump_i) = get_gateway_root_config_internal(&crate::ID);
-        assert_valid_gateway_root_pda(bump_i, &internal).unwrap();
+        let (internal, bump_i) = get_gateway_root_config_internal(&crate::ID, DEFAULT_NAMESPACE);
+        assert_valid_gateway_root_pda(DEFAULT_NAMESPACE, bump_i, &internal).unwrap();
 
         let (external, bump_e) = get_gateway_root_config_pda();
-        assert_valid_gateway_root_pda(bump_e, &external).unwrap();
+        assert_valid_gateway_root_pda(DEFAULT_NAMESPACE, bump_e, &external).unwrap();
 
         assert_eq!(internal, external);
         assert_eq!(bump_i, bump_e);
     }
+
+    /// Test that the default namespace derives the same addresses as the
+    /// seeds without any namespace.
+    #[test]
+    fn test_default_namespace_preserves_pda_addresses() {
+        let command_id: [u8; 32] = rand::random();
+        let payload_merkle_root = rand::random();
+        let signing_verifier_set = rand::random();
+
+        assert_eq!(
+            get_gateway_root_config_pda(),
+            Pubkey::find_program_address(&[seed_prefixes::GATEWAY_SEED], &crate::ID)
+        );
+        assert_eq!(
+            get_incoming_message_pda(&command_id),
+            Pubkey::find_program_address(
+                &[seed_prefixes::INCOMING_MESSAGE_SEED, &command_id],
+                &crate::ID
+            )
+        );
+        assert_eq!(
+            get_signature_verification_pda(&payload_merkle_root, &signing_verifier_set),
+            Pubkey::find_program_address(
+                &[
+                    seed_prefixes::SIGNATURE_VERIFICATION_SEED,
+                    &payload_merkle_root,
+                    &signing_verifier_set
+                ],
+                &crate::ID
+            )
+        );
+        assert_eq!(
+            get_verifier_set_tracker_pda(signing_verifier_set),
+            Pubkey::find_program_address(
+                &[
+                    seed_prefixes::VERIFIER_SET_TRACKER_SEED,
+                    &signing_verifier_set
+                ],
+                &crate::ID
+            )
+        );
+    }
+
+    /// Test that gateway instances in different namespaces never share a PDA,
+    /// and that the namespaced helpers agree with each other.
+    #[test]
+    fn test_namespaced_pdas_are_separated() {
+        let namespace = 7;
+        let command_id: [u8; 32] = rand::random();
+        let payload_merkle_root = rand::random();
+        let signing_verifier_set = rand::random();
+
+        let (root, root_bump) = get_gateway_root_config_pda_with_namespace(namespace);
+        assert_ne!(root, get_gateway_root_config_pda().0);
+        assert_valid_gateway_root_pda(namespace, root_bump, &root).unwrap();
+
+        let (message, message_bump) =
+            get_incoming_message_pda_with_namespace(namespace, &command_id);
+        assert_ne!(message, get_incoming_message_pda(&command_id).0);
+        assert_eq!(
+            create_incoming_message_pda_with_namespace(namespace, command_id, message_bump)
+                .unwrap(),
+            message
+        );
+        assert_valid_incoming_message_pda_with_namespace(
+            namespace,
+            &command_id,
+            message_bump,
+            &message,
+        )
+        .unwrap();
+
+        let (session, session_bump) = get_signature_verification_pda_with_namespace(
+            namespace,
+            &payload_merkle_root,
+            &signing_verifier_set,
+        );
+        assert_ne!(
+            session,
+            get_signature_verification_pda(&payload_merkle_root, &signing_verifier_set).0
+        );
+        assert_valid_signature_verification_pda_with_namespace(
+            namespace,
+            &payload_merkle_root,
+            &signing_verifier_set,
+            session_bump,
+            &session,
+        )
+        .unwrap();
+
+        // A verifier set tracker of one instance is rejected by the other
+        let (tracker_pda, tracker_bump) =
+            get_verifier_set_tracker_pda_with_namespace(namespace, signing_verifier_set);
+        let (default_tracker_pda, default_tracker_bump) =
+            get_verifier_set_tracker_pda(signing_verifier_set);
+        assert_ne!(tracker_pda, default_tracker_pda);
+        let tracker = VerifierSetTracker::new(tracker_bump, U256::ONE, signing_verifier_set);
+        let default_tracker =
+            VerifierSetTracker::new(default_tracker_bump, U256::ONE, signing_verifier_set);
+        assert_valid_verifier_set_tracker_pda_with_namespace(namespace, &tracker, &tracker_pda)
+            .unwrap();
+        assert_eq!(
+            assert_valid_verifier_set_tracker_pda_with_namespace(
+                namespace,
+                &tracker,
+                &default_tracker_pda
+            ),
+            Err(ProgramError::IncorrectProgramId)
+        );
+        assert_eq!(
+            assert_valid_verifier_set_tracker_pda(&default_tracker, &tracker_pda),
+            Err(ProgramError::IncorrectProgramId)
+        );
+    }
 }
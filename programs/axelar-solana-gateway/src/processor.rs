@@ -7,19 +7,31 @@ use solana_program::entrypoint::ProgramResult;
 use solana_program::msg;
 use solana_program::pubkey::Pubkey;
 
-use crate::check_program_account;
+use crate::error::GatewayError;
 use crate::instructions::GatewayInstruction;
+use crate::state::verifier_set_tracker::SignatureScheme;
+use crate::{check_program_account, DEFAULT_NAMESPACE};
 
 mod approve_message;
 mod call_contract;
+mod cancel_message;
 mod close_message_payload;
 mod commit_message_payload;
+mod execute_and_call;
+mod get_executable_interface_version;
 mod initialize_config;
 mod initialize_message_payload;
 mod initialize_payload_verification_session;
+mod prune_verifier_set_tracker;
+mod record_execution_receipt;
+mod recover_operatorship;
+mod resize_config;
 mod rotate_signers;
+mod set_chain_registered;
+mod state_digest;
 mod transfer_operatorship;
 mod validate_message;
+mod verify_event_authority;
 mod verify_signature;
 mod write_message_payload;
 
@@ -71,6 +83,7 @@ impl Processor {
                     program_id,
                     accounts,
                     new_verifier_set_merkle_root,
+                    None,
                 )
             }
             GatewayInstruction::CallContract {
@@ -91,7 +104,13 @@ impl Processor {
             }
             GatewayInstruction::InitializeConfig(init_config) => {
                 msg!("Instruction: Initialize Config");
-                Self::process_initialize_config(program_id, accounts, &init_config)
+                Self::process_initialize_config(
+                    program_id,
+                    accounts,
+                    &init_config,
+                    SignatureScheme::Secp256k1,
+                    DEFAULT_NAMESPACE,
+                )
             }
 
             GatewayInstruction::InitializePayloadVerificationSession {
@@ -155,6 +174,109 @@ impl Processor {
                 msg!("Instruction: Transfer Operatorship");
                 Self::process_transfer_operatorship(program_id, accounts)
             }
+            GatewayInstruction::ResizeConfig { new_size } => {
+                msg!("Instruction: Resize Config");
+                Self::process_resize_config(program_id, accounts, new_size)
+            }
+            GatewayInstruction::RecordExecutionReceipt {
+                message,
+                compute_units,
+            } => {
+                msg!("Instruction: Record Execution Receipt");
+                Self::process_record_execution_receipt(program_id, accounts, message, compute_units)
+            }
+            GatewayInstruction::VerifyEventAuthority => {
+                msg!("Instruction: Verify Event Authority");
+                Self::process_verify_event_authority(program_id, accounts)
+            }
+            GatewayInstruction::ExecuteAndCall { message } => {
+                msg!("Instruction: Execute And Call");
+                Self::process_execute_and_call(program_id, accounts, message)
+            }
+            GatewayInstruction::SetChainRegistered {
+                chain_name,
+                registered,
+            } => {
+                msg!("Instruction: Set Chain Registered");
+                Self::process_set_chain_registered(program_id, accounts, chain_name, registered)
+            }
+            GatewayInstruction::GetExecutableInterfaceVersion => {
+                msg!("Instruction: Get Executable Interface Version");
+                Self::process_get_executable_interface_version()
+            }
+            GatewayInstruction::StateDigest => {
+                msg!("Instruction: State Digest");
+                Self::process_state_digest(program_id, accounts)
+            }
+            GatewayInstruction::InitializeNamespacedConfig { namespace, config } => {
+                msg!("Instruction: Initialize Namespaced Config");
+                if !cfg!(feature = "namespaced-pdas") {
+                    return Err(GatewayError::NamespacedPdasDisabled.into());
+                }
+                Self::process_initialize_config(
+                    program_id,
+                    accounts,
+                    &config,
+                    SignatureScheme::Secp256k1,
+                    namespace,
+                )
+            }
+            GatewayInstruction::CancelMessage {
+                cancellation,
+                message,
+            } => {
+                msg!("Instruction: Cancel Message");
+                Self::process_cancel_message(program_id, accounts, cancellation, message)
+            }
+            GatewayInstruction::ApproveSingleMessage { message } => {
+                msg!("Instruction: Approve Single Message");
+                Self::process_approve_single_message(program_id, accounts, message)
+            }
+            GatewayInstruction::PruneVerifierSetTracker => {
+                msg!("Instruction: Prune Verifier Set Tracker");
+                Self::process_prune_verifier_set_tracker(program_id, accounts)
+            }
+            GatewayInstruction::RecoverOperatorship { new_operator } => {
+                msg!("Instruction: Recover Operatorship");
+                Self::process_recover_operatorship(program_id, accounts, new_operator)
+            }
+            GatewayInstruction::ApproveMessagesBatch {
+                messages,
+                payload_merkle_root,
+            } => {
+                msg!("Instruction: Approve Messages Batch");
+                Self::process_approve_messages_batch(
+                    program_id,
+                    accounts,
+                    messages,
+                    payload_merkle_root,
+                )
+            }
+            GatewayInstruction::RotateSignersWithLeaf {
+                new_verifier_set_merkle_root,
+                new_verifier_set_leaf,
+            } => {
+                msg!("Instruction: Rotate Signers With Leaf");
+                Self::process_rotate_verifier_set(
+                    program_id,
+                    accounts,
+                    new_verifier_set_merkle_root,
+                    Some(&new_verifier_set_leaf),
+                )
+            }
+            GatewayInstruction::InitializeConfigWithSignatureScheme {
+                config,
+                signature_scheme,
+            } => {
+                msg!("Instruction: Initialize Config With Signature Scheme");
+                Self::process_initialize_config(
+                    program_id,
+                    accounts,
+                    &config,
+                    signature_scheme,
+                    DEFAULT_NAMESPACE,
+                )
+            }
         }
     }
 }
@@ -1,5 +1,6 @@
 use axelar_solana_encoding::hasher::SolanaSyscallHasher;
 use axelar_solana_encoding::types::execute_data::MerkleisedMessage;
+use axelar_solana_encoding::types::messages::MessageLeaf;
 use axelar_solana_encoding::{rs_merkle, LeafHash};
 use core::str::FromStr;
 use event_cpi_macros::{emit_cpi, event_cpi_accounts};
@@ -8,20 +9,25 @@ use program_utils::{
     validate_system_account_key,
 };
 use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
 
 use super::Processor;
 use crate::error::GatewayError;
-use crate::events::MessageApprovedEvent;
-use crate::state::incoming_message::{command_id, IncomingMessage, MessageStatus};
+use crate::events::{BatchApprovedEvent, MessageApprovalDetailsEvent, MessageApprovedEvent};
+use crate::state::incoming_message::{
+    command_id, IncomingMessage, IncomingMessageExtension, MessageStatus,
+};
 use crate::state::signature_verification_pda::SignatureVerificationSessionData;
-use crate::state::GatewayConfig;
+use crate::state::{AccountView, GatewayConfig};
 use crate::{
-    assert_valid_gateway_root_pda, assert_valid_incoming_message_pda,
-    assert_valid_signature_verification_pda, get_incoming_message_pda,
-    get_validate_message_signing_pda, seed_prefixes,
+    assert_valid_gateway_root_pda, assert_valid_incoming_message_pda_with_namespace,
+    assert_valid_signature_verification_pda_with_namespace,
+    get_incoming_message_pda_with_namespace, get_validate_message_signing_pda, namespace_seed,
+    seed_prefixes,
 };
 
 impl Processor {
@@ -49,24 +55,105 @@ impl Processor {
     /// * Message Processing:
     ///   * Failed to initialize PDA for incoming message
     ///   * Destination address is invalid and cannot be converted to a `Pubkey`
+    ///   * The approved message count of the verification session overflows
     ///
     /// # Panics
     ///
     /// This function will panic if:
     /// * Converting `IncomingMessage::LEN` to u64 overflows.
-    #[allow(clippy::too_many_lines)]
     pub fn process_approve_message(
         program_id: &Pubkey,
         accounts: &[AccountInfo<'_>],
         merkleised_message: MerkleisedMessage,
         payload_merkle_root: [u8; 32],
+    ) -> ProgramResult {
+        Self::approve_message_leaves(
+            program_id,
+            accounts,
+            vec![(merkleised_message.leaf, Some(merkleised_message.proof))],
+            payload_merkle_root,
+        )
+    }
+
+    /// Approves a batch of messages of the same signed payload, initializing
+    /// the incoming message PDA of every message in a single instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Self::process_approve_message`] for every
+    /// message of the batch, and [`ProgramError::InvalidInstructionData`] if
+    /// the batch is empty.
+    pub fn process_approve_messages_batch(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo<'_>],
+        messages: Vec<MerkleisedMessage>,
+        payload_merkle_root: [u8; 32],
+    ) -> ProgramResult {
+        if messages.is_empty() {
+            solana_program::msg!("The batch holds no messages");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Self::approve_message_leaves(
+            program_id,
+            accounts,
+            messages
+                .into_iter()
+                .map(|message| (message.leaf, Some(message.proof)))
+                .collect(),
+            payload_merkle_root,
+        )
+    }
+
+    /// Approves the only message of a single-message payload. The merkle
+    /// root of a single-leaf tree is the leaf hash itself, so the signatures
+    /// of the verification session cover the leaf directly and no merkle
+    /// proof needs to be parsed or verified.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Self::process_approve_message`], and
+    /// [`GatewayError::LeafNodeNotPartOfMerkleRoot`] if the leaf isn't the
+    /// only leaf of its payload.
+    pub fn process_approve_single_message(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo<'_>],
+        message_leaf: MessageLeaf,
+    ) -> ProgramResult {
+        // Check: the leaf is the whole payload
+        if message_leaf.set_size != 1 || message_leaf.position != 0 {
+            return Err(GatewayError::LeafNodeNotPartOfMerkleRoot.into());
+        }
+
+        let payload_merkle_root = message_leaf.hash::<SolanaSyscallHasher>();
+        Self::approve_message_leaves(
+            program_id,
+            accounts,
+            vec![(message_leaf, None)],
+            payload_merkle_root,
+        )
+    }
+
+    /// Approves message leaves of the signed payload, the incoming message
+    /// PDAs following the verification session PDA in the same order. The
+    /// inclusion proof is only omitted for single-message payloads, whose
+    /// root is the leaf hash.
+    #[allow(clippy::too_many_lines)]
+    fn approve_message_leaves(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo<'_>],
+        message_leaves: Vec<(MessageLeaf, Option<Vec<u8>>)>,
+        payload_merkle_root: [u8; 32],
     ) -> ProgramResult {
         // Accounts
         let accounts_iter = &mut accounts.iter();
         let gateway_root_pda = next_account_info(accounts_iter)?;
         let funder = next_account_info(accounts_iter)?;
         let verification_session_account = next_account_info(accounts_iter)?;
-        let incoming_message_pda = next_account_info(accounts_iter)?;
+        let incoming_message_pdas = message_leaves
+            .iter()
+            .map(|_| next_account_info(accounts_iter))
+            .collect::<Result<Vec<_>, _>>()?;
         let system_program = next_account_info(accounts_iter)?;
         event_cpi_accounts!(accounts_iter);
 
@@ -74,115 +161,160 @@ impl Processor {
 
         // Check: Gateway Root PDA is initialized and valid.
         gateway_root_pda.check_initialized_pda_without_deserialization(&crate::ID)?;
-        let gateway_data = gateway_root_pda.try_borrow_data()?;
-        let gateway_config =
-            GatewayConfig::read(&gateway_data).ok_or(GatewayError::BytemuckDataLenInvalid)?;
-        assert_valid_gateway_root_pda(gateway_config.bump, gateway_root_pda.key)?;
+        let gateway_config = GatewayConfig::view(gateway_root_pda)?;
+        assert_valid_gateway_root_pda(
+            gateway_config.namespace,
+            gateway_config.bump,
+            gateway_root_pda.key,
+        )?;
 
         // Check: Verification session PDA is initialized.
         verification_session_account.check_initialized_pda_without_deserialization(program_id)?;
-        let data = verification_session_account.try_borrow_data()?;
-        let session = SignatureVerificationSessionData::read(&data)
-            .ok_or(GatewayError::BytemuckDataLenInvalid)?;
-        assert_valid_signature_verification_pda(
+        let mut session = SignatureVerificationSessionData::view_mut(verification_session_account)?;
+        assert_valid_signature_verification_pda_with_namespace(
+            gateway_config.namespace,
             &payload_merkle_root,
             &session.signature_verification.signing_verifier_set_hash,
             session.bump,
             verification_session_account.key,
         )?;
 
-        // Check: the incoming message PDA already approved
-        incoming_message_pda
-            .check_uninitialized_pda()
-            .map_err(|_err| GatewayError::MessageAlreadyInitialised)?;
-
         // Check: signature verification session is complete
         if !session.signature_verification.is_valid() {
             return Err(GatewayError::SigningSessionNotValid.into());
         }
 
-        // Check: message domain separator matches the gateway's domain separator
-        if merkleised_message.leaf.domain_separator != gateway_config.domain_separator {
-            return Err(GatewayError::InvalidDomainSeparator.into());
-        }
+        let namespace = gateway_config.namespace;
+        let clock = Clock::get()?;
+        for ((message_leaf, proof), incoming_message_pda) in
+            message_leaves.into_iter().zip(incoming_message_pdas)
+        {
+            // Check: the incoming message PDA already approved
+            incoming_message_pda
+                .check_uninitialized_pda()
+                .map_err(|_err| GatewayError::MessageAlreadyInitialised)?;
 
-        let leaf_hash = merkleised_message.leaf.hash::<SolanaSyscallHasher>();
-        let message_hash = merkleised_message
-            .leaf
-            .message
-            .hash::<SolanaSyscallHasher>();
-        let proof =
-            rs_merkle::MerkleProof::<SolanaSyscallHasher>::from_bytes(&merkleised_message.proof)
-                .map_err(|_err| GatewayError::InvalidMerkleProof)?;
-
-        // Check: leaf node is part of the payload merkle root
-        if !proof.verify(
-            payload_merkle_root,
-            &[merkleised_message.leaf.position.into()],
-            &[leaf_hash],
-            merkleised_message.leaf.set_size.into(),
-        ) {
-            return Err(GatewayError::LeafNodeNotPartOfMerkleRoot.into());
-        }
+            // Check: message domain separator matches the gateway's domain separator
+            if message_leaf.domain_separator != gateway_config.domain_separator {
+                return Err(GatewayError::InvalidDomainSeparator.into());
+            }
 
-        // create a PDA where we write the message metadata contents
-        let message = merkleised_message.leaf.message;
-        let cc_id = message.cc_id;
-        let command_id = command_id(&cc_id.chain, &cc_id.id);
+            let message_hash = message_leaf.message.hash::<SolanaSyscallHasher>();
 
-        let (_, incoming_message_pda_bump) = get_incoming_message_pda(&command_id);
-        assert_valid_incoming_message_pda(
-            &command_id,
-            incoming_message_pda_bump,
-            incoming_message_pda.key,
-        )?;
+            // Check: leaf node is part of the payload merkle root
+            if let Some(proof) = proof {
+                let leaf_hash = message_leaf.hash::<SolanaSyscallHasher>();
+                let proof = rs_merkle::MerkleProof::<SolanaSyscallHasher>::from_bytes(&proof)
+                    .map_err(|_err| GatewayError::InvalidMerkleProof)?;
+                if !proof.verify(
+                    payload_merkle_root,
+                    &[message_leaf.position.into()],
+                    &[leaf_hash],
+                    message_leaf.set_size.into(),
+                ) {
+                    return Err(GatewayError::LeafNodeNotPartOfMerkleRoot.into());
+                }
+            }
 
-        let seeds = &[
-            seed_prefixes::INCOMING_MESSAGE_SEED,
-            &command_id,
-            &[incoming_message_pda_bump],
-        ];
-        program_utils::pda::init_pda_raw(
-            funder,
-            incoming_message_pda,
-            program_id,
-            system_program,
-            IncomingMessage::pda_size().try_into().map_err(|_err| {
-                solana_program::msg!("unexpected u64 overflow in struct size");
-                ProgramError::ArithmeticOverflow
-            })?,
-            seeds,
-        )?;
+            // create a PDA where we write the message metadata contents
+            let set_size = message_leaf.set_size;
+            let message = message_leaf.message;
+            let cc_id = message.cc_id;
+            let command_id = command_id(&cc_id.chain, &cc_id.id);
+
+            let (_, incoming_message_pda_bump) =
+                get_incoming_message_pda_with_namespace(namespace, &command_id);
+            assert_valid_incoming_message_pda_with_namespace(
+                namespace,
+                &command_id,
+                incoming_message_pda_bump,
+                incoming_message_pda.key,
+            )?;
 
-        let destination_address =
-            Pubkey::from_str(&message.destination_address).map_err(|_err| {
-                solana_program::msg!("Invalid destination address");
-                GatewayError::InvalidDestinationAddress
-            })?;
-        let (_, signing_pda_bump) =
-            get_validate_message_signing_pda(destination_address, command_id);
-
-        // Persist a new incoming message with "in progress" status in the PDA data.
-        let mut data = incoming_message_pda.try_borrow_mut_data()?;
-        let incoming_message_data =
-            IncomingMessage::init_mut(&mut data).ok_or(GatewayError::BytemuckDataLenInvalid)?;
-        *incoming_message_data = IncomingMessage::new(
-            incoming_message_pda_bump,
-            signing_pda_bump,
-            MessageStatus::approved(),
-            message_hash,
-            message.payload_hash,
-        );
-
-        emit_cpi!(MessageApprovedEvent {
-            command_id,
-            destination_address,
-            payload_hash: message.payload_hash,
-            source_chain: cc_id.chain,
-            cc_id: cc_id.id,
-            source_address: message.source_address,
-            destination_chain: message.destination_chain,
-        });
+            let seeds = &[
+                seed_prefixes::INCOMING_MESSAGE_SEED,
+                namespace_seed(&namespace),
+                &command_id,
+                &[incoming_message_pda_bump],
+            ];
+            program_utils::pda::init_pda_raw(
+                funder,
+                incoming_message_pda,
+                program_id,
+                system_program,
+                IncomingMessage::extended_pda_size()
+                    .try_into()
+                    .map_err(|_err| {
+                        solana_program::msg!("unexpected u64 overflow in struct size");
+                        ProgramError::ArithmeticOverflow
+                    })?,
+                seeds,
+            )?;
+
+            let destination_address =
+                Pubkey::from_str(&message.destination_address).map_err(|_err| {
+                    solana_program::msg!("Invalid destination address");
+                    GatewayError::InvalidDestinationAddress
+                })?;
+            let (_, signing_pda_bump) =
+                get_validate_message_signing_pda(destination_address, command_id);
+
+            // Persist a new incoming message with "in progress" status in the PDA data,
+            // followed by an extension recording the approval.
+            let mut data = incoming_message_pda.try_borrow_mut_data()?;
+            let incoming_message_data = data
+                .get_mut(..IncomingMessage::pda_size())
+                .and_then(IncomingMessage::init_mut)
+                .ok_or(GatewayError::BytemuckDataLenInvalid)?;
+            *incoming_message_data = IncomingMessage::new(
+                incoming_message_pda_bump,
+                signing_pda_bump,
+                MessageStatus::approved(),
+                message_hash,
+                message.payload_hash,
+            );
+            IncomingMessageExtension::approved(clock.slot, clock.unix_timestamp)
+                .write(&mut data)
+                .ok_or(GatewayError::BytemuckDataLenInvalid)?;
+
+            emit_cpi!(MessageApprovedEvent {
+                command_id,
+                destination_address,
+                payload_hash: message.payload_hash,
+                source_chain: cc_id.chain,
+                cc_id: cc_id.id,
+                source_address: message.source_address,
+                destination_chain: message.destination_chain,
+            });
+            emit_cpi!(MessageApprovalDetailsEvent {
+                command_id,
+                approved_at_slot: clock.slot,
+                approved_at: clock.unix_timestamp,
+            });
+
+            #[cfg(feature = "audit-trace")]
+            emit_cpi!(crate::events::MessageApprovalTraceEvent {
+                command_id,
+                verification_session: *verification_session_account.key,
+                payload_merkle_root,
+                signing_verifier_set_hash: session.signature_verification.signing_verifier_set_hash,
+                leaf_index: message_leaf.position,
+                set_size,
+                signature_slots: session.signature_verification.signature_slots,
+            });
+
+            // Track the approvals of the batch, the last message completes it.
+            session.approved_message_count = session
+                .approved_message_count
+                .checked_add(1)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            if session.approved_message_count == set_size {
+                emit_cpi!(BatchApprovedEvent {
+                    payload_merkle_root,
+                    message_count: set_size,
+                });
+            }
+        }
 
         Ok(())
     }
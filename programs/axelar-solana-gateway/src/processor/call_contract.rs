@@ -3,6 +3,7 @@ use solana_program::account_info::{next_account_info, AccountInfo};
 use solana_program::entrypoint::ProgramResult;
 use solana_program::pubkey::Pubkey;
 
+use super::set_chain_registered::ensure_destination_chain_registered;
 use super::Processor;
 use crate::error::GatewayError;
 use crate::events::CallContractEvent;
@@ -17,6 +18,9 @@ impl Processor {
     /// It requires a valid signing PDA & signing PDA bump to be provided for verifying the
     /// authenticity of the call.
     ///
+    /// When the Chain Registry PDA is passed after the event-cpi accounts, the
+    /// destination chain must be registered in it.
+    ///
     /// # Errors
     ///
     /// Returns [`ProgramError`] if:
@@ -24,9 +28,11 @@ impl Processor {
     /// * Gateway root PDA is not properly initialized
     /// * Gateway root PDA's bump seed is invalid
     /// * Sender is not a signer
+    /// * Chain Registry PDA derivation fails
     ///
     /// Returns [`GatewayError`] if:
     /// * Gateway configuration data is invalid (`BytemuckDataLenInvalid`)
+    /// * The destination chain is not registered (`DestinationChainNotRegistered`)
     ///
     /// # Events
     ///
@@ -49,10 +55,16 @@ impl Processor {
         let sender_signing_pda = next_account_info(accounts_iter)?;
         let gateway_root_pda = next_account_info(accounts_iter)?;
         event_cpi_accounts!(accounts_iter);
+        let chain_registry_pda = accounts_iter.next();
 
         // Check: Gateway Root PDA is initialized.
         assert_initialized_and_valid_gateway_root_pda(gateway_root_pda)?;
 
+        // Check: the destination chain is registered, if the caller asked for it
+        if let Some(chain_registry_pda) = chain_registry_pda {
+            ensure_destination_chain_registered(chain_registry_pda, &destination_chain)?;
+        }
+
         if sender.is_signer {
             // Direct signer, so not a program, continue
         } else {
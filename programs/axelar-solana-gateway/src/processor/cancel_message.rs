@@ -0,0 +1,146 @@
+use core::str::FromStr;
+
+use axelar_solana_encoding::hasher::SolanaSyscallHasher;
+use axelar_solana_encoding::types::messages::Message;
+use axelar_solana_encoding::LeafHash;
+use event_cpi_macros::{emit_cpi, event_cpi_accounts};
+use program_utils::pda::ValidPDA;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use super::Processor;
+use crate::error::GatewayError;
+use crate::events::MessageCancelledEvent;
+use crate::state::incoming_message::{
+    cancel_message_payload_hash, command_id, IncomingMessage, MessageStatus,
+};
+use crate::{
+    assert_initialized_and_valid_gateway_root_pda,
+    assert_valid_incoming_message_pda_with_namespace, GatewayNamespace,
+};
+
+impl Processor {
+    /// Cancels an approved message on behalf of its source, consuming the
+    /// approved cancellation message.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError`] if:
+    /// * Required accounts are missing.
+    /// * Gateway Root PDA or an Incoming Message PDA is not initialized or
+    ///   not derived correctly.
+    /// * Both messages are the same.
+    ///
+    /// Returns [`GatewayError`] if:
+    /// * Either message is not in approved state.
+    /// * Either message hash does not match with its `IncomingMessage`'s.
+    /// * The cancellation is not addressed to the gateway or does not carry
+    ///   the cancellation payload of the message.
+    /// * The cancellation was not sent by the source of the message.
+    pub fn process_cancel_message(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo<'_>],
+        cancellation: Message,
+        message: Message,
+    ) -> ProgramResult {
+        let accounts_iter = &mut accounts.iter();
+        let gateway_root_pda = next_account_info(accounts_iter)?;
+        let cancellation_pda = next_account_info(accounts_iter)?;
+        let incoming_message_pda = next_account_info(accounts_iter)?;
+        event_cpi_accounts!(accounts_iter);
+
+        // Check: Gateway Root PDA is initialized.
+        let namespace = assert_initialized_and_valid_gateway_root_pda(gateway_root_pda)?;
+
+        let cancellation_command_id = command_id(&cancellation.cc_id.chain, &cancellation.cc_id.id);
+        let message_command_id = command_id(&message.cc_id.chain, &message.cc_id.id);
+
+        // Check: a message can't cancel itself
+        if cancellation_command_id == message_command_id {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Check: the cancellation is addressed to the gateway and names the message
+        let destination_address = Pubkey::from_str(&cancellation.destination_address)
+            .map_err(|_err| GatewayError::InvalidDestinationAddress)?;
+        if destination_address != crate::ID
+            || cancellation.payload_hash != cancel_message_payload_hash(&message_command_id)
+        {
+            return Err(GatewayError::InvalidCancellationMessage.into());
+        }
+
+        // Check: the cancellation comes from the source of the message
+        if cancellation.cc_id.chain != message.cc_id.chain
+            || cancellation.source_address != message.source_address
+        {
+            msg!("Error: the cancellation was not sent by the source of the message");
+            return Err(GatewayError::UnauthorizedMessageCancellation.into());
+        }
+
+        cancellation_pda.check_initialized_pda_without_deserialization(program_id)?;
+        incoming_message_pda.check_initialized_pda_without_deserialization(program_id)?;
+
+        let mut cancellation_data = cancellation_pda.try_borrow_mut_data()?;
+        let cancellation_message = load_approved_message(
+            namespace,
+            cancellation_pda.key,
+            &mut cancellation_data,
+            &cancellation,
+            &cancellation_command_id,
+        )?;
+        let mut message_data = incoming_message_pda.try_borrow_mut_data()?;
+        let incoming_message = load_approved_message(
+            namespace,
+            incoming_message_pda.key,
+            &mut message_data,
+            &message,
+            &message_command_id,
+        )?;
+
+        cancellation_message.status = MessageStatus::executed();
+        incoming_message.status = MessageStatus::cancelled();
+
+        emit_cpi!(MessageCancelledEvent {
+            command_id: message_command_id,
+            cancellation_command_id,
+            source_chain: message.cc_id.chain,
+            cc_id: message.cc_id.id,
+            source_address: message.source_address,
+        });
+
+        Ok(())
+    }
+}
+
+/// Loads the `IncomingMessage` of `message` from `data`, checking that it is
+/// still approved and was approved for that very message.
+fn load_approved_message<'a>(
+    namespace: GatewayNamespace,
+    incoming_message_pda: &Pubkey,
+    data: &'a mut [u8],
+    message: &Message,
+    command_id: &[u8; 32],
+) -> Result<&'a mut IncomingMessage, ProgramError> {
+    let incoming_message =
+        IncomingMessage::read_versioned_mut(data).ok_or(GatewayError::BytemuckDataLenInvalid)?;
+    assert_valid_incoming_message_pda_with_namespace(
+        namespace,
+        command_id,
+        incoming_message.bump,
+        incoming_message_pda,
+    )?;
+
+    // Check: message is approved
+    if !incoming_message.status.is_approved() {
+        return Err(GatewayError::MessageNotApproved.into());
+    }
+    // Check: message hashes match
+    if incoming_message.message_hash != message.hash::<SolanaSyscallHasher>() {
+        return Err(GatewayError::MessageHasBeenTamperedWith.into());
+    }
+
+    Ok(incoming_message)
+}
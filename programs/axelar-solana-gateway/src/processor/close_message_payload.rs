@@ -3,7 +3,7 @@ use crate::state::incoming_message::IncomingMessage;
 use crate::state::message_payload::MutMessagePayload;
 
 use super::Processor;
-use program_utils::pda::{BytemuckedPda, ValidPDA};
+use program_utils::pda::ValidPDA;
 use solana_program::account_info::{next_account_info, AccountInfo};
 use solana_program::entrypoint::ProgramResult;
 use solana_program::program_error::ProgramError;
@@ -13,13 +13,14 @@ impl Processor {
     /// Closes a message payload PDA account and reclaims its lamports back to the payer.
     ///
     /// Typically used after a message has been fully processed or when cleaning up unused message
-    /// accounts.
+    /// accounts. Once the message is executed anyone can close the account, the lamports still
+    /// going to the payer the PDA was derived from.
     ///
     /// # Errors
     ///
     /// Returns [`ProgramError`] if:
     /// * Required accounts are missing or in wrong order
-    /// * Payer is not a signer
+    /// * Payer is not a signer and the message is not executed
     /// * Gateway root PDA is not properly initialized
     /// * Message payload account is not properly initialized
     /// * Message payload PDA derivation fails
@@ -36,14 +37,8 @@ impl Processor {
         let incoming_message_account = next_account_info(accounts_iter)?;
         let message_payload_account = next_account_info(accounts_iter)?;
 
-        // Check: payer is signer
-        if !payer.is_signer {
-            solana_program::msg!("Error: payer must be a signer");
-            return Err(ProgramError::MissingRequiredSignature);
-        }
-
         // Check: Gateway root PDA
-        assert_initialized_and_valid_gateway_root_pda(gateway_root_pda)?;
+        let namespace = assert_initialized_and_valid_gateway_root_pda(gateway_root_pda)?;
 
         // Check: Message Payload account is initialized
         message_payload_account.check_initialized_pda_without_deserialization(&crate::ID)?;
@@ -57,19 +52,27 @@ impl Processor {
             // Check: Incoming Message PDA account is initialized and validate it
             incoming_message_account.check_initialized_pda_without_deserialization(program_id)?;
             let incoming_message_data = incoming_message_account.try_borrow_data()?;
-            let incoming_message =
-                IncomingMessage::read(&incoming_message_data).ok_or_else(|| {
+            let incoming_message = IncomingMessage::read_versioned(&incoming_message_data)
+                .ok_or_else(|| {
                     solana_program::msg!("Error: failed to read incoming message account data");
                     ProgramError::InvalidAccountData
                 })?;
 
             // Validate the IncomingMessage PDA using the stored bump
-            crate::assert_valid_incoming_message_pda(
+            crate::assert_valid_incoming_message_pda_with_namespace(
+                namespace,
                 &command_id,
                 incoming_message.bump,
                 incoming_message_account.key,
             )?;
 
+            // Check: payer is signer, unless the message is executed and anyone can close the
+            // account on their behalf
+            if !payer.is_signer && !incoming_message.status.is_executed() {
+                solana_program::msg!("Error: payer must be a signer");
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+
             // Check: Buffer PDA can be derived from provided seeds.
             let incoming_message_pda = *incoming_message_account.key;
             crate::assert_valid_message_payload_pda(
@@ -2,7 +2,7 @@ use super::Processor;
 use crate::assert_initialized_and_valid_gateway_root_pda;
 use crate::state::incoming_message::IncomingMessage;
 use crate::state::message_payload::MutMessagePayload;
-use program_utils::pda::{BytemuckedPda, ValidPDA};
+use program_utils::pda::ValidPDA;
 use solana_program::account_info::{next_account_info, AccountInfo};
 use solana_program::entrypoint::ProgramResult;
 use solana_program::program_error::ProgramError;
@@ -39,7 +39,7 @@ impl Processor {
             return Err(ProgramError::MissingRequiredSignature);
         }
         // Check: Gateway root PDA
-        assert_initialized_and_valid_gateway_root_pda(gateway_root_pda)?;
+        let namespace = assert_initialized_and_valid_gateway_root_pda(gateway_root_pda)?;
 
         // Check: Message Payload account is initialized
         message_payload_account.check_initialized_pda_without_deserialization(&crate::ID)?;
@@ -52,13 +52,15 @@ impl Processor {
         // Check: Incoming Message PDA account is initialized and validate it
         incoming_message_account.check_initialized_pda_without_deserialization(program_id)?;
         let incoming_message_data = incoming_message_account.try_borrow_data()?;
-        let incoming_message = IncomingMessage::read(&incoming_message_data).ok_or_else(|| {
-            solana_program::msg!("Error: failed to read incoming message account data");
-            ProgramError::InvalidAccountData
-        })?;
+        let incoming_message =
+            IncomingMessage::read_versioned(&incoming_message_data).ok_or_else(|| {
+                solana_program::msg!("Error: failed to read incoming message account data");
+                ProgramError::InvalidAccountData
+            })?;
 
         // Validate the IncomingMessage PDA using the stored bump
-        crate::assert_valid_incoming_message_pda(
+        crate::assert_valid_incoming_message_pda_with_namespace(
+            namespace,
             &command_id,
             incoming_message.bump,
             incoming_message_account.key,
@@ -0,0 +1,179 @@
+use core::str::FromStr;
+
+use axelar_solana_encoding::hasher::SolanaSyscallHasher;
+use axelar_solana_encoding::types::messages::Message;
+use axelar_solana_encoding::LeafHash;
+use event_cpi_macros::{emit_cpi, event_cpi_accounts};
+use program_utils::pda::ValidPDA;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::msg;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use super::Processor;
+use crate::error::GatewayError;
+use crate::events::MessageExecutedEvent;
+use crate::executable::{serialize_gateway_call, AxelarMessagePayload, GatewayCall};
+use crate::state::incoming_message::{command_id, IncomingMessage, MessageStatus};
+use crate::state::message_payload::ImmutMessagePayload;
+use crate::state::AccountView;
+use crate::{
+    assert_initialized_and_valid_gateway_root_pda,
+    assert_valid_incoming_message_pda_with_namespace, assert_valid_message_payload_pda,
+    namespace_seed, seed_prefixes,
+};
+
+impl Processor {
+    /// Validates an approved message against its committed payload, marks it
+    /// as executed and calls the destination program with the accounts encoded
+    /// in the payload.
+    ///
+    /// The destination program is invoked with the Incoming Message PDA as its
+    /// first account, signed by the gateway, followed by the payload accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError`] if:
+    /// * Required accounts are missing
+    /// * Gateway root PDA, incoming message or message payload account is not initialized
+    /// * Message payload PDA derivation fails or the payload is not committed
+    /// * The payload hash or the payload accounts do not match the message
+    /// * The destination program fails
+    ///
+    /// Returns [`GatewayError`] if:
+    /// * `Message` not in approved state.
+    /// * `Message` hash does not match with `IncomingMessage`'s.
+    /// * Invalid destination address format, or it does not match the destination program.
+    pub fn process_execute_and_call(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo<'_>],
+        message: Message,
+    ) -> ProgramResult {
+        let accounts_iter = &mut accounts.iter();
+        let message_payload_payer = next_account_info(accounts_iter)?;
+        let incoming_message_pda = next_account_info(accounts_iter)?;
+        let message_payload_account = next_account_info(accounts_iter)?;
+        let gateway_root_pda = next_account_info(accounts_iter)?;
+        let destination_program = next_account_info(accounts_iter)?;
+        event_cpi_accounts!(accounts_iter);
+        let destination_accounts = accounts_iter.as_slice();
+
+        // Check: Gateway Root PDA is initialized.
+        let namespace = assert_initialized_and_valid_gateway_root_pda(gateway_root_pda)?;
+
+        // Check: the destination program is the message destination
+        let destination_address = Pubkey::from_str(&message.destination_address)
+            .map_err(|_err| GatewayError::InvalidDestinationAddress)?;
+        if *destination_program.key != destination_address || !destination_program.executable {
+            msg!("Invalid destination program");
+            return Err(GatewayError::InvalidDestinationAddress.into());
+        }
+
+        let message_hash = message.hash::<SolanaSyscallHasher>();
+        let command_id = command_id(&message.cc_id.chain, &message.cc_id.id);
+
+        // scope to release the incoming message before calling the destination program
+        let incoming_message_bump = {
+            incoming_message_pda.check_initialized_pda_without_deserialization(program_id)?;
+            let mut incoming_message = IncomingMessage::view_mut(incoming_message_pda)?;
+            assert_valid_incoming_message_pda_with_namespace(
+                namespace,
+                &command_id,
+                incoming_message.bump,
+                incoming_message_pda.key,
+            )?;
+
+            // Check: message is approved
+            if !incoming_message.status.is_approved() {
+                return Err(GatewayError::MessageNotApproved.into());
+            }
+            // Check: message hashes match
+            if incoming_message.message_hash != message_hash {
+                return Err(GatewayError::MessageHasBeenTamperedWith.into());
+            }
+
+            incoming_message.status = MessageStatus::executed();
+            incoming_message.bump
+        };
+
+        // scope to release the message payload before calling the destination program
+        let (payload, destination_account_metas) = {
+            message_payload_account.check_initialized_pda_without_deserialization(program_id)?;
+            let message_payload_data = message_payload_account.try_borrow_data()?;
+            let message_payload: ImmutMessagePayload<'_> = (**message_payload_data).try_into()?;
+            assert_valid_message_payload_pda(
+                *incoming_message_pda.key,
+                *message_payload_payer.key,
+                *message_payload.bump,
+                message_payload_account.key,
+            )?;
+
+            // Check: MessagePayload PDA is finalized
+            if !message_payload.committed() {
+                msg!("Error: message payload is not committed");
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            // Check: MessagePayload's payload hash matches the message's
+            if *message_payload.payload_hash != message.payload_hash {
+                msg!("Invalid payload hash");
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            // Check: provided accounts match the ones encoded in the payload
+            let axelar_payload = AxelarMessagePayload::decode(message_payload.raw_payload)?;
+            if !axelar_payload.solana_accounts().eq(destination_accounts) {
+                msg!("Provided accounts do not match the message payload");
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            (
+                axelar_payload.payload_without_accounts().to_vec(),
+                axelar_payload.account_meta(),
+            )
+        };
+
+        let data = serialize_gateway_call(&GatewayCall {
+            message: message.clone(),
+            payload,
+        })?;
+
+        emit_cpi!(MessageExecutedEvent {
+            command_id,
+            destination_address,
+            payload_hash: message.payload_hash,
+            source_chain: message.cc_id.chain,
+            cc_id: message.cc_id.id,
+            source_address: message.source_address,
+            destination_chain: message.destination_chain,
+        });
+
+        let mut account_metas =
+            Vec::with_capacity(destination_account_metas.len().saturating_add(1));
+        account_metas.push(AccountMeta::new_readonly(*incoming_message_pda.key, true));
+        account_metas.extend(destination_account_metas);
+
+        let mut account_infos = Vec::with_capacity(destination_accounts.len().saturating_add(2));
+        account_infos.push(incoming_message_pda.clone());
+        account_infos.extend(destination_accounts.iter().cloned());
+        account_infos.push(destination_program.clone());
+
+        invoke_signed(
+            &Instruction {
+                program_id: destination_address,
+                accounts: account_metas,
+                data,
+            },
+            &account_infos,
+            &[&[
+                seed_prefixes::INCOMING_MESSAGE_SEED,
+                namespace_seed(&namespace),
+                &command_id,
+                &[incoming_message_bump],
+            ]],
+        )
+    }
+}
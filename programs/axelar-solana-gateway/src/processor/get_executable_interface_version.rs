@@ -0,0 +1,20 @@
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::set_return_data;
+
+use super::Processor;
+use crate::executable::EXECUTABLE_INTERFACE_VERSION;
+
+impl Processor {
+    /// Returns the [`EXECUTABLE_INTERFACE_VERSION`] the gateway was built
+    /// with as return data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError::BorshIoError`](solana_program::program_error::ProgramError::BorshIoError)
+    /// if the version can't be serialized.
+    pub fn process_get_executable_interface_version() -> ProgramResult {
+        set_return_data(&borsh::to_vec(&EXECUTABLE_INTERFACE_VERSION)?);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use axelar_message_primitives::U256;
+use event_cpi_macros::{emit_cpi, event_cpi_accounts};
 use program_utils::{
     pda::{BytemuckedPda, ValidPDA},
     validate_system_account_key,
@@ -14,17 +15,25 @@ use solana_program::sysvar::Sysvar;
 
 use super::Processor;
 use crate::error::GatewayError;
+use crate::events::EventAuthorityVerifiedEvent;
 use crate::instructions::InitializeConfig;
-use crate::state::verifier_set_tracker::VerifierSetTracker;
+use crate::state::verifier_set_tracker::{SignatureScheme, VerifierSetTracker};
 use crate::state::GatewayConfig;
 use crate::{
-    assert_valid_gateway_root_pda, assert_valid_verifier_set_tracker_pda,
-    get_gateway_root_config_pda, get_verifier_set_tracker_pda, seed_prefixes,
+    assert_valid_event_authority, assert_valid_gateway_root_pda,
+    assert_valid_verifier_set_tracker_pda_with_namespace,
+    get_gateway_root_config_pda_with_namespace, get_verifier_set_tracker_pda_with_namespace,
+    namespace_seed, seed_prefixes, GatewayNamespace,
 };
 
 impl Processor {
     /// Initializes the gateway program by setting up configuration and a verifier set account.
     ///
+    /// The configuration PDA is derived in the given `namespace`, which the
+    /// gateway instance derives its other PDAs with from then on.
+    ///
+    /// The initial verifier set signs with `signature_scheme`.
+    ///
     /// # Errors
     ///
     /// Returns [`ProgramError`] if:
@@ -37,6 +46,7 @@ impl Processor {
     /// Returns [`GatewayError`] if:
     /// * Data serialization/deserialization fails
     /// * Invalid PDA bumps are provided
+    /// * The event authority is not the canonical, empty event-cpi PDA
     ///
     /// # Security Considerations
     ///
@@ -52,6 +62,8 @@ impl Processor {
         program_id: &Pubkey,
         accounts: &[AccountInfo<'_>],
         init_config: &InitializeConfig,
+        signature_scheme: SignatureScheme,
+        namespace: GatewayNamespace,
     ) -> ProgramResult {
         let accounts = &mut accounts.iter();
         let payer = next_account_info(accounts)?;
@@ -61,6 +73,15 @@ impl Processor {
         let system_account = next_account_info(accounts)?;
         let verifier_set_pda = next_account_info(accounts)?;
 
+        // Check: the event authority can sign event CPIs, so that the gateway
+        // is never initialized with bricked event emission
+        let event_authority = accounts
+            .clone()
+            .next()
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let event_authority_bump = assert_valid_event_authority(event_authority)?;
+        event_cpi_accounts!(accounts);
+
         validate_system_account_key(system_account.key)?;
 
         // Check: Upgrade authority
@@ -76,7 +97,8 @@ impl Processor {
         let epoch = U256::from_u64(1);
         let current_epochs = U256::from_u64(1);
 
-        let (_, pda_bump) = get_verifier_set_tracker_pda(verifier_set_hash);
+        let (_, pda_bump) =
+            get_verifier_set_tracker_pda_with_namespace(namespace, verifier_set_hash);
         verifier_set_pda.check_uninitialized_pda()?;
 
         // Initialize the tracker account
@@ -91,6 +113,7 @@ impl Processor {
             })?,
             &[
                 seed_prefixes::VERIFIER_SET_TRACKER_SEED,
+                namespace_seed(&namespace),
                 verifier_set_hash.as_slice(),
                 &[pda_bump],
             ],
@@ -100,18 +123,23 @@ impl Processor {
         let mut data = verifier_set_pda.try_borrow_mut_data()?;
         let tracker =
             VerifierSetTracker::init_mut(&mut data).ok_or(GatewayError::BytemuckDataLenInvalid)?;
-        *tracker = VerifierSetTracker::new(pda_bump, epoch, verifier_set_hash);
+        *tracker = VerifierSetTracker::new(pda_bump, epoch, verifier_set_hash)
+            .with_signature_scheme(signature_scheme);
 
         // check that everything has been derived correctly
-        assert_valid_verifier_set_tracker_pda(tracker, verifier_set_pda.key)?;
+        assert_valid_verifier_set_tracker_pda_with_namespace(
+            namespace,
+            tracker,
+            verifier_set_pda.key,
+        )?;
 
-        let (_, bump) = get_gateway_root_config_pda();
+        let (_, bump) = get_gateway_root_config_pda_with_namespace(namespace);
 
         // Check: Gateway Root PDA is uninitialized
         gateway_root_pda.check_uninitialized_pda()?;
 
         // Check: Gateway Config account uses the canonical bump.
-        assert_valid_gateway_root_pda(bump, gateway_root_pda.key)?;
+        assert_valid_gateway_root_pda(namespace, bump, gateway_root_pda.key)?;
 
         // Initialize the account
         program_utils::pda::init_pda_raw(
@@ -123,7 +151,11 @@ impl Processor {
                 solana_program::msg!("unexpected u64 overflow in struct size");
                 ProgramError::ArithmeticOverflow
             })?,
-            &[seed_prefixes::GATEWAY_SEED, &[bump]],
+            &[
+                seed_prefixes::GATEWAY_SEED,
+                namespace_seed(&namespace),
+                &[bump],
+            ],
         )?;
         let mut data = gateway_root_pda.try_borrow_mut_data()?;
         let gateway_config =
@@ -142,7 +174,13 @@ impl Processor {
             init_config.operator,
             init_config.domain_separator,
             bump,
-        );
+        )
+        .with_namespace(namespace);
+
+        emit_cpi!(EventAuthorityVerifiedEvent {
+            event_authority: *event_authority.key,
+            bump: event_authority_bump,
+        });
 
         Ok(())
     }
@@ -4,7 +4,7 @@ use crate::{assert_initialized_and_valid_gateway_root_pda, error::GatewayError};
 
 use super::Processor;
 use program_utils::{
-    pda::{init_pda_raw, BytemuckedPda, ValidPDA},
+    pda::{init_pda_raw, ValidPDA},
     validate_system_account_key,
 };
 use solana_program::account_info::{next_account_info, AccountInfo};
@@ -59,7 +59,7 @@ impl Processor {
         }
 
         // Check: Gateway root PDA
-        assert_initialized_and_valid_gateway_root_pda(gateway_root_pda)?;
+        let namespace = assert_initialized_and_valid_gateway_root_pda(gateway_root_pda)?;
 
         // Check: System Program
         validate_system_account_key(system_program.key)?;
@@ -77,13 +77,15 @@ impl Processor {
         // Check: Incoming Message PDA account is initialized and validate it
         incoming_message_account.check_initialized_pda_without_deserialization(program_id)?;
         let incoming_message_data = incoming_message_account.try_borrow_data()?;
-        let incoming_message = IncomingMessage::read(&incoming_message_data).ok_or_else(|| {
-            solana_program::msg!("Error: failed to read incoming message account data");
-            ProgramError::InvalidAccountData
-        })?;
+        let incoming_message =
+            IncomingMessage::read_versioned(&incoming_message_data).ok_or_else(|| {
+                solana_program::msg!("Error: failed to read incoming message account data");
+                ProgramError::InvalidAccountData
+            })?;
 
         // Validate the IncomingMessage PDA using the stored bump
-        crate::assert_valid_incoming_message_pda(
+        crate::assert_valid_incoming_message_pda_with_namespace(
+            namespace,
             &command_id,
             incoming_message.bump,
             incoming_message_account.key,
@@ -10,8 +10,8 @@ use crate::assert_initialized_and_valid_gateway_root_pda;
 use crate::error::GatewayError;
 use crate::state::signature_verification_pda::SignatureVerificationSessionData;
 use crate::state::verifier_set_tracker::VerifierSetTracker;
-use crate::state::GatewayConfig;
-use crate::{assert_valid_verifier_set_tracker_pda, seed_prefixes};
+use crate::state::{AccountView, GatewayConfig};
+use crate::{assert_valid_verifier_set_tracker_pda_with_namespace, namespace_seed, seed_prefixes};
 
 impl Processor {
     /// Initializes a signature verification session PDA account for a given Axelar payload (former
@@ -73,34 +73,32 @@ impl Processor {
         }
 
         // Check: Gateway Root PDA is initialized.
-        assert_initialized_and_valid_gateway_root_pda(gateway_root_pda)?;
+        let namespace = assert_initialized_and_valid_gateway_root_pda(gateway_root_pda)?;
 
         // Check: Signing verifier set is valid and sufficiently recent
         verifier_set_tracker_account.check_initialized_pda_without_deserialization(program_id)?;
         let verifier_set_data = verifier_set_tracker_account.try_borrow_data()?;
         let verifier_set_tracker = VerifierSetTracker::read(&verifier_set_data)
             .ok_or(GatewayError::BytemuckDataLenInvalid)?;
-        assert_valid_verifier_set_tracker_pda(
+        assert_valid_verifier_set_tracker_pda_with_namespace(
+            namespace,
             verifier_set_tracker,
             verifier_set_tracker_account.key,
         )?;
 
         // Check: Verifier set isn't expired
-        gateway_root_pda.try_borrow_data().map(|data| {
-            GatewayConfig::read(&data)
-                .ok_or(GatewayError::BytemuckDataLenInvalid)
-                .and_then(|gateway_config| {
-                    gateway_config.assert_valid_epoch(verifier_set_tracker.epoch)
-                })
-        })??;
+        GatewayConfig::view(gateway_root_pda)?.assert_valid_epoch(verifier_set_tracker.epoch)?;
 
         // Read the signing verifier set hash from the tracker
         let signing_verifier_set_hash = verifier_set_tracker.verifier_set_hash;
 
         // Check: Verification PDA can be derived from provided seeds.
         // using canonical bump for the session account
-        let (verification_session_pda, bump) =
-            crate::get_signature_verification_pda(&merkle_root, &signing_verifier_set_hash);
+        let (verification_session_pda, bump) = crate::get_signature_verification_pda_with_namespace(
+            namespace,
+            &merkle_root,
+            &signing_verifier_set_hash,
+        );
         if verification_session_pda != *verification_session_account.key {
             return Err(GatewayError::InvalidVerificationSessionPDA.into());
         }
@@ -110,10 +108,11 @@ impl Processor {
             .check_uninitialized_pda()
             .map_err(|_err| GatewayError::VerificationSessionPDAInitialised)?;
 
-        // Use the same seeds as `[crate::get_signature_verification_pda]`, plus the
-        // bump seed.
+        // Use the same seeds as `[crate::get_signature_verification_pda_with_namespace]`,
+        // plus the bump seed.
         let signers_seeds = &[
             seed_prefixes::SIGNATURE_VERIFICATION_SEED,
+            namespace_seed(&namespace),
             &merkle_root,
             &signing_verifier_set_hash,
             &[bump],
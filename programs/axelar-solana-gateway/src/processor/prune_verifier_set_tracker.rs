@@ -0,0 +1,105 @@
+use event_cpi_macros::{emit_cpi, event_cpi_accounts};
+use program_utils::pda::{BytemuckedPda, ValidPDA};
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use super::Processor;
+use crate::error::GatewayError;
+use crate::events::VerifierSetTrackerPrunedEvent;
+use crate::state::verifier_set_tracker::VerifierSetTracker;
+use crate::state::{AccountView, GatewayConfig};
+use crate::{assert_valid_gateway_root_pda, assert_valid_verifier_set_tracker_pda_with_namespace};
+
+impl Processor {
+    /// Closes the tracker PDA of a verifier set that fell out of the retention
+    /// window, refunding its rent to the gateway operator.
+    ///
+    /// A verifier set is retained for `previous_verifier_set_retention`
+    /// epochs, the same window [`GatewayConfig::assert_valid_epoch`] enforces
+    /// when it signs. Once outside of it the tracker is of no use anymore, so
+    /// pruning is permissionless. The tracker of the active verifier set is
+    /// never pruned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError`] if:
+    /// * Required accounts are missing or in wrong order
+    /// * Gateway root PDA or verifier set tracker PDA is not initialized
+    /// * Verifier set tracker PDA derivation fails
+    /// * The rent recipient is not the gateway operator
+    ///
+    /// Returns [`GatewayError`] if:
+    /// * Gateway root PDA is invalid
+    /// * The verifier set is still within the retention window
+    pub fn process_prune_verifier_set_tracker(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo<'_>],
+    ) -> ProgramResult {
+        let accounts_iter = &mut accounts.iter();
+        let gateway_root_pda = next_account_info(accounts_iter)?;
+        let verifier_set_tracker_account = next_account_info(accounts_iter)?;
+        let operator = next_account_info(accounts_iter)?;
+        event_cpi_accounts!(accounts_iter);
+
+        // Check: Gateway Root PDA is initialized and valid.
+        gateway_root_pda.check_initialized_pda_without_deserialization(program_id)?;
+        let gateway_config = GatewayConfig::view(gateway_root_pda)?;
+        assert_valid_gateway_root_pda(
+            gateway_config.namespace,
+            gateway_config.bump,
+            gateway_root_pda.key,
+        )?;
+
+        // Check: the rent is refunded to the gateway operator
+        if operator.key != &gateway_config.operator {
+            msg!("Error: rent recipient is not the gateway operator");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Scope the account data borrow so it's dropped before calling close_pda
+        let event = {
+            // Check: Verifier set tracker PDA is initialized and valid.
+            verifier_set_tracker_account
+                .check_initialized_pda_without_deserialization(program_id)?;
+            let verifier_set_data = verifier_set_tracker_account.try_borrow_data()?;
+            let verifier_set_tracker = VerifierSetTracker::read(&verifier_set_data)
+                .ok_or(GatewayError::BytemuckDataLenInvalid)?;
+            assert_valid_verifier_set_tracker_pda_with_namespace(
+                gateway_config.namespace,
+                verifier_set_tracker,
+                verifier_set_tracker_account.key,
+            )?;
+
+            // Check: the verifier set is not the active one and fell out of
+            // the retention window
+            if verifier_set_tracker.epoch == gateway_config.current_epoch
+                || !matches!(
+                    gateway_config.assert_valid_epoch(verifier_set_tracker.epoch),
+                    Err(GatewayError::VerifierSetTooOld)
+                )
+            {
+                msg!(
+                    "Verifier set of epoch {} is still retained",
+                    verifier_set_tracker.epoch
+                );
+                return Err(GatewayError::VerifierSetStillRetained.into());
+            }
+
+            VerifierSetTrackerPrunedEvent {
+                verifier_set_hash: verifier_set_tracker.verifier_set_hash,
+                epoch: verifier_set_tracker.epoch,
+                current_epoch: gateway_config.current_epoch,
+                retention: gateway_config.previous_verifier_set_retention,
+            }
+        };
+
+        program_utils::pda::close_pda(operator, verifier_set_tracker_account, program_id)?;
+
+        emit_cpi!(event);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use core::str::FromStr;
+
+use axelar_solana_encoding::hasher::SolanaSyscallHasher;
+use axelar_solana_encoding::types::messages::Message;
+use axelar_solana_encoding::LeafHash;
+use event_cpi_macros::{emit_cpi, event_cpi_accounts};
+use program_utils::pda::ValidPDA;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use super::Processor;
+use crate::error::GatewayError;
+use crate::events::MessageExecutedEventV2;
+use crate::state::incoming_message::{
+    command_id, IncomingMessage, IncomingMessageExtension, MAX_EXECUTION_COMPUTE_UNITS,
+};
+use crate::state::message_payload::ImmutMessagePayload;
+use crate::state::AccountView;
+use crate::{
+    assert_initialized_and_valid_gateway_root_pda,
+    assert_valid_incoming_message_pda_with_namespace, assert_valid_message_payload_pda,
+};
+
+impl Processor {
+    /// Records the relayer that executed a message and the compute units the
+    /// execution consumed, as reported by that relayer.
+    ///
+    /// The relayer is identified as the authority of the committed Message
+    /// Payload PDA used for the execution, so the receipt must be recorded
+    /// before that account is closed. The receipt is emitted as a
+    /// [`MessageExecutedEventV2`] carrying the relayer and the compute units.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError`] if:
+    /// * Required accounts are missing or in wrong order
+    /// * Relayer is not a signer
+    /// * Gateway root PDA, incoming message or message payload account is not initialized
+    /// * Message payload PDA derivation fails or the payload is not committed
+    ///
+    /// Returns [`GatewayError`] if:
+    /// * `Message` hash does not match with `IncomingMessage`'s
+    /// * Invalid destination address format
+    /// * The message has not been executed
+    /// * The message was approved before execution receipts were introduced
+    /// * A receipt was already recorded for the message
+    /// * `compute_units` is zero or above [`MAX_EXECUTION_COMPUTE_UNITS`]
+    pub fn process_record_execution_receipt(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo<'_>],
+        message: Message,
+        compute_units: u32,
+    ) -> ProgramResult {
+        let accounts_iter = &mut accounts.iter();
+        let relayer = next_account_info(accounts_iter)?;
+        let gateway_root_pda = next_account_info(accounts_iter)?;
+        let incoming_message_account = next_account_info(accounts_iter)?;
+        let message_payload_account = next_account_info(accounts_iter)?;
+        event_cpi_accounts!(accounts_iter);
+
+        // Check: relayer is signer
+        if !relayer.is_signer {
+            msg!("Error: relayer must be a signer");
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Check: Gateway root PDA
+        let namespace = assert_initialized_and_valid_gateway_root_pda(gateway_root_pda)?;
+
+        // Check: reported compute units are within a sane range
+        if compute_units == 0 || compute_units > MAX_EXECUTION_COMPUTE_UNITS {
+            msg!("Invalid execution compute units: {}", compute_units);
+            return Err(GatewayError::InvalidExecutionComputeUnits.into());
+        }
+
+        let message_hash = message.hash::<SolanaSyscallHasher>();
+        let command_id = command_id(&message.cc_id.chain, &message.cc_id.id);
+
+        incoming_message_account.check_initialized_pda_without_deserialization(program_id)?;
+        let incoming_message = *IncomingMessage::view(incoming_message_account)?;
+        assert_valid_incoming_message_pda_with_namespace(
+            namespace,
+            &command_id,
+            incoming_message.bump,
+            incoming_message_account.key,
+        )?;
+
+        // Check: message hashes match
+        if incoming_message.message_hash != message_hash {
+            return Err(GatewayError::MessageHasBeenTamperedWith.into());
+        }
+        let destination_address = Pubkey::from_str(&message.destination_address)
+            .map_err(|_err| GatewayError::InvalidDestinationAddress)?;
+
+        // Check: message was executed and has no receipt yet
+        if !incoming_message.status.is_executed() {
+            return Err(GatewayError::MessageNotExecuted.into());
+        }
+        let mut extension =
+            IncomingMessageExtension::read(&incoming_message_account.try_borrow_data()?)
+                .ok_or(GatewayError::ExecutionReceiptNotSupported)?;
+        if extension.has_execution_receipt() {
+            return Err(GatewayError::ExecutionReceiptAlreadyRecorded.into());
+        }
+
+        // Check: the relayer owns the committed payload used for the execution
+        message_payload_account.check_initialized_pda_without_deserialization(program_id)?;
+        let message_payload_data = message_payload_account.try_borrow_data()?;
+        let message_payload: ImmutMessagePayload<'_> = (**message_payload_data).try_into()?;
+        assert_valid_message_payload_pda(
+            *incoming_message_account.key,
+            *relayer.key,
+            *message_payload.bump,
+            message_payload_account.key,
+        )?;
+        if !message_payload.committed() {
+            msg!("Error: message payload is not committed");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        extension.relayer = *relayer.key;
+        extension.compute_units = compute_units;
+        extension
+            .write(&mut incoming_message_account.try_borrow_mut_data()?)
+            .ok_or(GatewayError::ExecutionReceiptNotSupported)?;
+
+        emit_cpi!(MessageExecutedEventV2 {
+            command_id,
+            destination_address,
+            payload_hash: message.payload_hash,
+            source_chain: message.cc_id.chain,
+            cc_id: message.cc_id.id,
+            source_address: message.source_address,
+            destination_chain: message.destination_chain,
+            relayer: *relayer.key,
+            compute_units,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use event_cpi_macros::{emit_cpi, event_cpi_accounts};
+use program_utils::pda::{BytemuckedPda, ValidPDA};
+use program_utils::validate_system_account_key;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use super::transfer_operatorship::ensure_upgrade_authority;
+use super::Processor;
+use crate::error::GatewayError;
+use crate::events::{OperatorshipRecoveredEvent, OperatorshipRecoveryInitiatedEvent};
+use crate::state::{AccountView, GatewayConfig, OperatorshipRecovery};
+use crate::{
+    assert_valid_gateway_root_pda, assert_valid_operatorship_recovery_pda,
+    get_operatorship_recovery_pda, seed_prefixes,
+};
+
+impl Processor {
+    /// Recovers the gateway operatorship to `new_operator`, authorized by the
+    /// upgrade authority of the gateway program and timelocked by
+    /// [`OPERATORSHIP_RECOVERY_DELAY`](crate::state::operatorship_recovery::OPERATORSHIP_RECOVERY_DELAY).
+    ///
+    /// Without a pending recovery to `new_operator`, the recovery is initiated
+    /// (or restarted when pending to another operator). Once its timelock
+    /// elapsed, the operator is set and the Operatorship Recovery PDA is
+    /// closed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError`] if:
+    /// * Required accounts are missing
+    /// * Operatorship Recovery PDA derivation fails or its creation fails
+    /// * Converting `unix_timestamp` to `u64` results in a negative value
+    ///
+    /// Returns [`GatewayError`] if:
+    /// * Gateway root PDA is invalid
+    /// * `ProgramData` account derivation fails
+    /// * Loader state is invalid
+    /// * Signer is not the upgrade authority
+    /// * The timelock of the pending recovery has not elapsed
+    pub fn process_recover_operatorship(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo<'_>],
+        new_operator: Pubkey,
+    ) -> ProgramResult {
+        let accounts_iter = &mut accounts.iter();
+        let gateway_root_pda = next_account_info(accounts_iter)?;
+        let upgrade_authority = next_account_info(accounts_iter)?;
+        let programdata_account = next_account_info(accounts_iter)?;
+        let recovery_pda = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+        event_cpi_accounts!(accounts_iter);
+
+        validate_system_account_key(system_program.key)?;
+
+        // Check: Gateway Root PDA is initialized and valid.
+        gateway_root_pda.check_initialized_pda_without_deserialization(program_id)?;
+        let mut gateway_config = GatewayConfig::view_mut(gateway_root_pda)?;
+        assert_valid_gateway_root_pda(
+            gateway_config.namespace,
+            gateway_config.bump,
+            gateway_root_pda.key,
+        )?;
+
+        ensure_upgrade_authority(program_id, upgrade_authority, programdata_account)?;
+
+        let now: u64 = Clock::get()?.unix_timestamp.try_into().map_err(|_err| {
+            msg!("received negative timestamp");
+            ProgramError::ArithmeticOverflow
+        })?;
+
+        let (bump, pending_recovery) = if recovery_pda.is_initialized_pda(program_id) {
+            let data = recovery_pda.try_borrow_data()?;
+            let recovery =
+                OperatorshipRecovery::read(&data).ok_or(GatewayError::BytemuckDataLenInvalid)?;
+            assert_valid_operatorship_recovery_pda(
+                gateway_root_pda.key,
+                recovery.bump,
+                recovery_pda.key,
+            )?;
+            (recovery.bump, Some(*recovery))
+        } else {
+            let (_, bump) = get_operatorship_recovery_pda(gateway_root_pda.key);
+            assert_valid_operatorship_recovery_pda(gateway_root_pda.key, bump, recovery_pda.key)?;
+
+            program_utils::pda::init_pda_raw(
+                upgrade_authority,
+                recovery_pda,
+                program_id,
+                system_program,
+                OperatorshipRecovery::pda_size()
+                    .try_into()
+                    .map_err(|_err| {
+                        msg!("unexpected u64 overflow in struct size");
+                        ProgramError::ArithmeticOverflow
+                    })?,
+                &[
+                    seed_prefixes::OPERATORSHIP_RECOVERY_SEED,
+                    gateway_root_pda.key.as_ref(),
+                    &[bump],
+                ],
+            )?;
+            (bump, None)
+        };
+
+        // Initiate the recovery, or restart it when pending to another operator
+        let Some(pending_recovery) =
+            pending_recovery.filter(|recovery| recovery.new_operator == new_operator)
+        else {
+            let recovery = OperatorshipRecovery::new(new_operator, now, bump);
+            let mut data = recovery_pda.try_borrow_mut_data()?;
+            *OperatorshipRecovery::init_mut(&mut data)
+                .ok_or(GatewayError::BytemuckDataLenInvalid)? = recovery;
+
+            emit_cpi!(OperatorshipRecoveryInitiatedEvent {
+                new_operator,
+                eligible_at: recovery.eligible_at,
+            });
+
+            return Ok(());
+        };
+
+        if !pending_recovery.is_eligible(now) {
+            msg!(
+                "Operatorship recovery is eligible at {}",
+                pending_recovery.eligible_at
+            );
+            return Err(GatewayError::OperatorshipRecoveryTimelocked.into());
+        }
+
+        program_utils::pda::close_pda(upgrade_authority, recovery_pda, program_id)?;
+
+        let previous_operator = gateway_config.operator;
+        gateway_config.operator = new_operator;
+
+        emit_cpi!(OperatorshipRecoveredEvent {
+            previous_operator,
+            new_operator,
+        });
+
+        Ok(())
+    }
+}
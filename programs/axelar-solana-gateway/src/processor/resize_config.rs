@@ -0,0 +1,107 @@
+use program_utils::pda::ValidPDA;
+use program_utils::validate_system_account_key;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program::invoke;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use super::transfer_operatorship::ensure_operator_or_upgrade_authority;
+use super::Processor;
+use crate::assert_valid_gateway_root_pda;
+use crate::error::GatewayError;
+use crate::state::{AccountView, GatewayConfig};
+
+impl Processor {
+    /// Grows the Gateway Root Config PDA account to `new_size` bytes, topping up
+    /// its rent from the payer.
+    ///
+    /// The bytes after the current [`GatewayConfig`] layout are zero-initialized
+    /// and reserved for fields added in future versions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError`] if:
+    /// * Required accounts are missing
+    /// * Payer is not a signer
+    /// * The rent top-up or the reallocation fails
+    ///
+    /// Returns [`GatewayError`] if:
+    /// * Gateway root PDA is invalid
+    /// * Signer is neither operator nor upgrade authority
+    /// * `new_size` is smaller than the current account size
+    pub fn process_resize_config(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo<'_>],
+        new_size: u64,
+    ) -> ProgramResult {
+        let accounts_iter = &mut accounts.iter();
+        let payer = next_account_info(accounts_iter)?;
+        let operator_or_upgrade_authority = next_account_info(accounts_iter)?;
+        let programdata_account = next_account_info(accounts_iter)?;
+        let gateway_root_pda = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+
+        validate_system_account_key(system_program.key)?;
+
+        if !payer.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Check: Gateway Root PDA is initialized and valid.
+        gateway_root_pda.check_initialized_pda_without_deserialization(&crate::ID)?;
+        let operator = {
+            let gateway_config = GatewayConfig::view(gateway_root_pda)?;
+            assert_valid_gateway_root_pda(
+                gateway_config.namespace,
+                gateway_config.bump,
+                gateway_root_pda.key,
+            )?;
+            gateway_config.operator
+        };
+
+        ensure_operator_or_upgrade_authority(
+            program_id,
+            &operator,
+            operator_or_upgrade_authority,
+            programdata_account,
+        )?;
+
+        let new_size: usize = new_size
+            .try_into()
+            .map_err(|_err| ProgramError::ArithmeticOverflow)?;
+
+        // Check: the config can only grow, shrinking could drop data
+        if new_size < gateway_root_pda.data_len() {
+            msg!(
+                "New config size {} is smaller than the current size {}",
+                new_size,
+                gateway_root_pda.data_len()
+            );
+            return Err(GatewayError::InvalidConfigSize.into());
+        }
+
+        let lamports_needed = Rent::get()?
+            .minimum_balance(new_size)
+            .saturating_sub(gateway_root_pda.lamports());
+
+        if lamports_needed > 0 {
+            invoke(
+                &system_instruction::transfer(payer.key, gateway_root_pda.key, lamports_needed),
+                &[
+                    payer.clone(),
+                    gateway_root_pda.clone(),
+                    system_program.clone(),
+                ],
+            )?;
+        }
+
+        gateway_root_pda.realloc(new_size, true)?;
+
+        Ok(())
+    }
+}
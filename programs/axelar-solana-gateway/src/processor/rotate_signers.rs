@@ -1,6 +1,9 @@
 use core::convert::TryInto;
 
 use axelar_message_primitives::U256;
+use axelar_solana_encoding::hasher::SolanaSyscallHasher;
+use axelar_solana_encoding::rs_merkle;
+use axelar_solana_encoding::types::verifier_set::VerifierSetLeaf;
 use event_cpi_macros::{emit_cpi, event_cpi_accounts};
 use program_utils::{
     pda::{BytemuckedPda, ValidPDA},
@@ -13,14 +16,20 @@ use solana_program::pubkey::Pubkey;
 use solana_program::sysvar::Sysvar;
 
 use super::Processor;
+use crate::instructions::VerifierSetLeafProof;
+use crate::state::signature_verification::SignatureVerification;
 use crate::state::signature_verification_pda::SignatureVerificationSessionData;
-use crate::state::verifier_set_tracker::VerifierSetTracker;
-use crate::state::GatewayConfig;
+use crate::state::verifier_set_tracker::{SignatureScheme, VerifierSetTracker};
+use crate::state::{AccountView, GatewayConfig};
 use crate::{
-    assert_valid_gateway_root_pda, assert_valid_signature_verification_pda,
-    assert_valid_verifier_set_tracker_pda, get_verifier_set_tracker_pda, seed_prefixes,
+    assert_valid_gateway_root_pda, assert_valid_signature_verification_pda_with_namespace,
+    assert_valid_verifier_set_tracker_pda_with_namespace,
+    get_verifier_set_tracker_pda_with_namespace, namespace_seed, seed_prefixes,
+};
+use crate::{
+    error::GatewayError,
+    events::{VerifierSetRotatedEvent, VerifierSetRotationDetailsEvent},
 };
-use crate::{error::GatewayError, events::VerifierSetRotatedEvent};
 
 impl Processor {
     /// Rotate the weighted signers, signed off by the latest Axelar signers.
@@ -32,6 +41,15 @@ impl Processor {
     ///
     /// Rotation to duplicate signers is rejected.
     ///
+    /// If `new_verifier_set_leaf` is provided, it must be a leaf of the new
+    /// verifier set; its threshold and set size are then reported in the
+    /// [`VerifierSetRotationDetailsEvent`].
+    ///
+    /// The new verifier set signs with the [`SignatureScheme`] of the key of
+    /// `new_verifier_set_leaf`, or with the scheme of the current verifier set
+    /// when no leaf is provided. Rotating to a verifier set of another scheme
+    /// therefore requires proving one of its leaves.
+    ///
     /// Reference implementation: `https://github.com/axelarnetwork/axelar-gmp-sdk-solidity/blob/9dae93af0b799e536005951ddc36284132813579/contracts/gateway/AxelarAmplifierGateway.sol#L94`
     ///
     /// # Errors
@@ -46,16 +64,19 @@ impl Processor {
     /// * Rotation delay hasn't elapsed.
     /// * Proof not signed by latest verifier set.
     /// * New verifier set tracker already exists.
+    /// * The new verifier set leaf is not part of the new verifier set.
     ///
     /// # Panics
     ///
     /// This function will panic if:
     /// * Converting `unix_timestamp` to `u64` results in a negative value (via `expect`)
     /// * Converting `VerifierSetTracker::pda_size` to `u64` overflows (via `expect`)
+    #[allow(clippy::too_many_lines)]
     pub fn process_rotate_verifier_set(
         program_id: &Pubkey,
         accounts: &[AccountInfo<'_>],
         new_verifier_set_merkle_root: [u8; 32],
+        new_verifier_set_leaf: Option<&VerifierSetLeafProof>,
     ) -> ProgramResult {
         // Accounts
         let accounts_iter = &mut accounts.iter();
@@ -73,20 +94,21 @@ impl Processor {
         // Check: Gateway Root PDA is initialized.
         // Check: Gateway Root PDA is initialized and valid.
         gateway_root_pda.check_initialized_pda_without_deserialization(&crate::ID)?;
-        let mut gateway_data = gateway_root_pda.try_borrow_mut_data()?;
-        let gateway_config = GatewayConfig::read_mut(&mut gateway_data)
-            .ok_or(GatewayError::BytemuckDataLenInvalid)?;
-        assert_valid_gateway_root_pda(gateway_config.bump, gateway_root_pda.key)?;
+        let mut gateway_config = GatewayConfig::view_mut(gateway_root_pda)?;
+        assert_valid_gateway_root_pda(
+            gateway_config.namespace,
+            gateway_config.bump,
+            gateway_root_pda.key,
+        )?;
 
         // Check: Verification session PDA is initialized.
         verification_session_account.check_initialized_pda_without_deserialization(program_id)?;
-        let mut session_data = verification_session_account.try_borrow_mut_data()?;
-        let session = SignatureVerificationSessionData::read_mut(&mut session_data)
-            .ok_or(GatewayError::BytemuckDataLenInvalid)?;
+        let session = SignatureVerificationSessionData::view(verification_session_account)?;
 
         // Check: Verification PDA can be derived from seeds stored into the account
         // data itself. New verifier set merkle root is used directly as the payload hash.
-        assert_valid_signature_verification_pda(
+        assert_valid_signature_verification_pda_with_namespace(
+            gateway_config.namespace,
             &new_verifier_set_merkle_root,
             &session.signature_verification.signing_verifier_set_hash,
             session.bump,
@@ -102,7 +124,8 @@ impl Processor {
         let verifier_set_data = verifier_set_tracker_account.try_borrow_data()?;
         let verifier_set_tracker = VerifierSetTracker::read(&verifier_set_data)
             .ok_or(GatewayError::BytemuckDataLenInvalid)?;
-        assert_valid_verifier_set_tracker_pda(
+        assert_valid_verifier_set_tracker_pda_with_namespace(
+            gateway_config.namespace,
             verifier_set_tracker,
             verifier_set_tracker_account.key,
         )?;
@@ -145,22 +168,51 @@ impl Processor {
                 ProgramError::ArithmeticOverflow
             })?;
 
-        if enforce_rotation_delay && !enough_time_till_next_rotation(current_time, gateway_config) {
+        if enforce_rotation_delay && !enough_time_till_next_rotation(current_time, &gateway_config)
+        {
             return Err(GatewayError::RotationCooldownNotDone.into());
         }
 
+        // Check: the leaf belongs to the new verifier set
+        let new_verifier_set_leaf = new_verifier_set_leaf
+            .map(|proof| {
+                verify_new_verifier_set_leaf(
+                    proof,
+                    &new_verifier_set_merkle_root,
+                    &gateway_config.domain_separator,
+                )
+            })
+            .transpose()?;
+        let signature_scheme = match new_verifier_set_leaf {
+            Some(leaf) => SignatureScheme::of(&leaf.signer_pubkey),
+            None => verifier_set_tracker.signature_scheme()?,
+        };
+
         gateway_config.last_rotation_timestamp = current_time;
 
         rotate_signers(
-            gateway_config,
+            &mut gateway_config,
             new_verifier_set_merkle_root,
+            signature_scheme,
             payer,
             new_empty_verifier_set,
             program_id,
             system_account,
             __event_cpi_authority_info,
             __event_cpi_authority_bump,
-        )
+        )?;
+
+        emit_cpi!(VerifierSetRotationDetailsEvent {
+            previous_epoch: verifier_set_tracker.epoch,
+            epoch: gateway_config.current_epoch,
+            previous_verifier_set_hash: verifier_set_tracker.verifier_set_hash,
+            verifier_set_hash: new_verifier_set_merkle_root,
+            threshold: new_verifier_set_leaf.map(|leaf| leaf.quorum),
+            signer_count: new_verifier_set_leaf.map(|leaf| leaf.set_size),
+            rotation_delay_enforced: enforce_rotation_delay,
+        });
+
+        Ok(())
     }
 }
 
@@ -181,6 +233,7 @@ impl Processor {
 fn rotate_signers<'a>(
     gateway_config: &mut GatewayConfig,
     new_verifier_set_merkle_root: [u8; 32],
+    signature_scheme: SignatureScheme,
     payer: &AccountInfo<'a>,
     new_empty_verifier_set: &AccountInfo<'a>,
     program_id: &Pubkey,
@@ -195,7 +248,9 @@ fn rotate_signers<'a>(
         .ok_or(ProgramError::ArithmeticOverflow)?;
 
     // Initialize the new verifier set tracker PDA account
-    let (_, new_verifier_set_bump) = get_verifier_set_tracker_pda(new_verifier_set_merkle_root);
+    let namespace = gateway_config.namespace;
+    let (_, new_verifier_set_bump) =
+        get_verifier_set_tracker_pda_with_namespace(namespace, new_verifier_set_merkle_root);
     program_utils::pda::init_pda_raw(
         payer,
         new_empty_verifier_set,
@@ -206,6 +261,7 @@ fn rotate_signers<'a>(
             .expect("unexpected u64 overflow in struct size"),
         &[
             seed_prefixes::VERIFIER_SET_TRACKER_SEED,
+            namespace_seed(&namespace),
             new_verifier_set_merkle_root.as_slice(),
             &[new_verifier_set_bump],
         ],
@@ -219,10 +275,15 @@ fn rotate_signers<'a>(
         new_verifier_set_bump,
         gateway_config.current_epoch,
         new_verifier_set_merkle_root,
-    );
+    )
+    .with_signature_scheme(signature_scheme);
 
     // Check that everything has been derived correctly
-    assert_valid_verifier_set_tracker_pda(new_verifier_set_tracker, new_empty_verifier_set.key)?;
+    assert_valid_verifier_set_tracker_pda_with_namespace(
+        namespace,
+        new_verifier_set_tracker,
+        new_empty_verifier_set.key,
+    )?;
 
     emit_cpi!(VerifierSetRotatedEvent {
         epoch: new_verifier_set_tracker.epoch,
@@ -232,6 +293,29 @@ fn rotate_signers<'a>(
     Ok(())
 }
 
+/// Checks that `proof` proves a leaf of the verifier set with the given merkle
+/// root, signed for this gateway.
+fn verify_new_verifier_set_leaf(
+    proof: &VerifierSetLeafProof,
+    new_verifier_set_merkle_root: &[u8; 32],
+    domain_separator: &[u8; 32],
+) -> Result<VerifierSetLeaf, GatewayError> {
+    if proof.leaf.domain_separator != *domain_separator {
+        return Err(GatewayError::InvalidDomainSeparator);
+    }
+
+    let merkle_proof =
+        rs_merkle::MerkleProof::<SolanaSyscallHasher>::from_bytes(&proof.merkle_proof)
+            .map_err(|_err| GatewayError::InvalidMerkleProof)?;
+    SignatureVerification::verify_merkle_proof(
+        proof.leaf,
+        &merkle_proof,
+        new_verifier_set_merkle_root,
+    )?;
+
+    Ok(proof.leaf)
+}
+
 fn enough_time_till_next_rotation(current_time: u64, config: &GatewayConfig) -> bool {
     let secs_since_last_rotation = current_time
         .checked_sub(config.last_rotation_timestamp)
@@ -0,0 +1,142 @@
+use event_cpi_macros::{emit_cpi, event_cpi_accounts};
+use program_utils::pda::{BytemuckedPda, ValidPDA};
+use program_utils::validate_system_account_key;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use super::transfer_operatorship::ensure_operator_or_upgrade_authority;
+use super::Processor;
+use crate::error::GatewayError;
+use crate::events::ChainRegistrationSetEvent;
+use crate::state::{AccountView, ChainRegistry, GatewayConfig};
+use crate::{
+    assert_valid_chain_registry_pda, assert_valid_gateway_root_pda, get_chain_registry_pda,
+    seed_prefixes,
+};
+
+impl Processor {
+    /// Registers or unregisters `chain_name` in the chain registry, creating
+    /// the Chain Registry PDA on first use.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError`] if:
+    /// * Required accounts are missing
+    /// * Payer is not a signer
+    /// * Chain Registry PDA derivation fails or its creation fails
+    ///
+    /// Returns [`GatewayError`] if:
+    /// * Gateway root PDA is invalid
+    /// * Signer is neither operator nor upgrade authority
+    /// * The registry is full
+    pub fn process_set_chain_registered(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo<'_>],
+        chain_name: String,
+        registered: bool,
+    ) -> ProgramResult {
+        let accounts_iter = &mut accounts.iter();
+        let payer = next_account_info(accounts_iter)?;
+        let operator_or_upgrade_authority = next_account_info(accounts_iter)?;
+        let programdata_account = next_account_info(accounts_iter)?;
+        let gateway_root_pda = next_account_info(accounts_iter)?;
+        let chain_registry_pda = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+        event_cpi_accounts!(accounts_iter);
+
+        validate_system_account_key(system_program.key)?;
+
+        if !payer.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Check: Gateway Root PDA is initialized and valid.
+        gateway_root_pda.check_initialized_pda_without_deserialization(&crate::ID)?;
+        let operator = {
+            let gateway_config = GatewayConfig::view(gateway_root_pda)?;
+            assert_valid_gateway_root_pda(
+                gateway_config.namespace,
+                gateway_config.bump,
+                gateway_root_pda.key,
+            )?;
+            gateway_config.operator
+        };
+
+        ensure_operator_or_upgrade_authority(
+            program_id,
+            &operator,
+            operator_or_upgrade_authority,
+            programdata_account,
+        )?;
+
+        if !chain_registry_pda.is_initialized_pda(program_id) {
+            let (_, bump) = get_chain_registry_pda();
+            assert_valid_chain_registry_pda(bump, chain_registry_pda.key)?;
+
+            program_utils::pda::init_pda_raw(
+                payer,
+                chain_registry_pda,
+                program_id,
+                system_program,
+                ChainRegistry::pda_size().try_into().map_err(|_err| {
+                    msg!("unexpected u64 overflow in struct size");
+                    ProgramError::ArithmeticOverflow
+                })?,
+                &[seed_prefixes::CHAIN_REGISTRY_SEED, &[bump]],
+            )?;
+            let mut data = chain_registry_pda.try_borrow_mut_data()?;
+            let chain_registry =
+                ChainRegistry::init_mut(&mut data).ok_or(GatewayError::BytemuckDataLenInvalid)?;
+            *chain_registry = ChainRegistry::new(bump);
+        }
+
+        {
+            let mut data = chain_registry_pda.try_borrow_mut_data()?;
+            let chain_registry =
+                ChainRegistry::read_mut(&mut data).ok_or(GatewayError::BytemuckDataLenInvalid)?;
+            assert_valid_chain_registry_pda(chain_registry.bump, chain_registry_pda.key)?;
+
+            chain_registry.set_registered(&chain_name, registered)?;
+        }
+
+        emit_cpi!(ChainRegistrationSetEvent {
+            chain_name,
+            registered,
+        });
+
+        Ok(())
+    }
+}
+
+/// Checks that `destination_chain` is accepted by the chain registry.
+///
+/// The Chain Registry PDA only exists once the operator registered a chain,
+/// before that it must still be the canonical PDA address and every chain is
+/// accepted.
+pub(super) fn ensure_destination_chain_registered(
+    chain_registry_pda: &AccountInfo<'_>,
+    destination_chain: &str,
+) -> ProgramResult {
+    if !chain_registry_pda.is_initialized_pda(&crate::ID) {
+        if chain_registry_pda.key != &get_chain_registry_pda().0 {
+            msg!("Error: Invalid Chain Registry PDA");
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        return Ok(());
+    }
+
+    let data = chain_registry_pda.try_borrow_data()?;
+    let chain_registry = ChainRegistry::read(&data).ok_or(GatewayError::BytemuckDataLenInvalid)?;
+    assert_valid_chain_registry_pda(chain_registry.bump, chain_registry_pda.key)?;
+
+    if !chain_registry.accepts(destination_chain) {
+        msg!("Destination chain {} is not registered", destination_chain);
+        return Err(GatewayError::DestinationChainNotRegistered.into());
+    }
+
+    Ok(())
+}
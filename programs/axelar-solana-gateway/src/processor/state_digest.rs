@@ -0,0 +1,89 @@
+use axelar_message_primitives::U256;
+use program_utils::pda::{BytemuckedPda, ValidPDA};
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::set_return_data;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use super::Processor;
+use crate::error::GatewayError;
+use crate::state::verifier_set_tracker::{Epoch, VerifierSetTracker};
+use crate::state::{AccountView, GatewayConfig};
+use crate::{
+    assert_initialized_and_valid_gateway_root_pda,
+    assert_valid_verifier_set_tracker_pda_with_namespace, GatewayNamespace,
+};
+
+impl Processor {
+    /// Writes the [`GatewayConfig::state_digest`] to the return data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError`] if:
+    /// * Required accounts are missing
+    /// * Gateway root PDA or a verifier set tracker PDA is not initialized
+    ///
+    /// Returns [`GatewayError`] if:
+    /// * A verifier set tracker is not the one of the current or previous
+    ///   epoch
+    pub fn process_state_digest(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo<'_>],
+    ) -> ProgramResult {
+        let accounts_iter = &mut accounts.iter();
+        let gateway_root_pda = next_account_info(accounts_iter)?;
+        let current_verifier_set_account = next_account_info(accounts_iter)?;
+        let previous_verifier_set_account = next_account_info(accounts_iter).ok();
+
+        let namespace = assert_initialized_and_valid_gateway_root_pda(gateway_root_pda)?;
+        let gateway_config = GatewayConfig::view(gateway_root_pda)?;
+        let current_epoch = gateway_config.current_epoch;
+
+        let current_verifier_set = read_verifier_set_tracker(
+            program_id,
+            namespace,
+            current_verifier_set_account,
+            current_epoch,
+        )?;
+
+        // The first epoch has no previous verifier set
+        let previous_epoch = current_epoch
+            .checked_sub(U256::ONE)
+            .filter(|epoch| *epoch != U256::ZERO);
+        let previous_verifier_set = match (previous_epoch, previous_verifier_set_account) {
+            (Some(previous_epoch), Some(account)) => Some(read_verifier_set_tracker(
+                program_id,
+                namespace,
+                account,
+                previous_epoch,
+            )?),
+            (Some(_), None) => return Err(ProgramError::NotEnoughAccountKeys),
+            (None, _) => None,
+        };
+
+        let digest =
+            gateway_config.state_digest(&current_verifier_set, previous_verifier_set.as_ref());
+        set_return_data(&digest);
+
+        Ok(())
+    }
+}
+
+fn read_verifier_set_tracker(
+    program_id: &Pubkey,
+    namespace: GatewayNamespace,
+    account: &AccountInfo<'_>,
+    expected_epoch: Epoch,
+) -> Result<VerifierSetTracker, ProgramError> {
+    account.check_initialized_pda_without_deserialization(program_id)?;
+    let data = account.try_borrow_data()?;
+    let tracker = VerifierSetTracker::read(&data).ok_or(GatewayError::BytemuckDataLenInvalid)?;
+    assert_valid_verifier_set_tracker_pda_with_namespace(namespace, tracker, account.key)?;
+
+    if tracker.epoch != expected_epoch {
+        return Err(GatewayError::InvalidVerifierSetTrackerProvided.into());
+    }
+
+    Ok(*tracker)
+}
@@ -1,15 +1,16 @@
 use event_cpi_macros::{emit_cpi, event_cpi_accounts};
-use program_utils::pda::{BytemuckedPda, ValidPDA};
+use program_utils::pda::ValidPDA;
 use solana_program::account_info::{next_account_info, AccountInfo};
 use solana_program::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
 use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 
 use super::Processor;
 use crate::assert_valid_gateway_root_pda;
 use crate::error::GatewayError;
 use crate::events::OperatorshipTransferredEvent;
-use crate::state::GatewayConfig;
+use crate::state::{AccountView, GatewayConfig};
 
 impl Processor {
     /// Transfers gateway operatorship to a new address, authorized by
@@ -43,50 +44,19 @@ impl Processor {
 
         // Check: Gateway Root PDA is initialized and valid.
         gateway_root_pda.check_initialized_pda_without_deserialization(&crate::ID)?;
-        let mut gateway_data = gateway_root_pda.try_borrow_mut_data()?;
-        let gateway_config = GatewayConfig::read_mut(&mut gateway_data)
-            .ok_or(GatewayError::BytemuckDataLenInvalid)?;
-        assert_valid_gateway_root_pda(gateway_config.bump, gateway_root_pda.key)?;
-
-        // Check: programdata account derived correctly (it holds the upgrade authority
-        // information)
-        if *programdata_account.key
-            != Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id()).0
-        {
-            return Err(GatewayError::InvalidProgramDataDerivation.into());
-        }
-
-        // Check: the programdata state is valid
-        let loader_state = programdata_account
-            .data
-            .borrow()
-            .get(0..UpgradeableLoaderState::size_of_programdata_metadata())
-            .ok_or(GatewayError::InvalidLoaderContent)
-            .and_then(|bytes: &[u8]| {
-                bincode::deserialize::<UpgradeableLoaderState>(bytes)
-                    .map_err(|_err| GatewayError::InvalidLoaderContent)
-            })?;
-
-        let UpgradeableLoaderState::ProgramData {
-            upgrade_authority_address,
-            ..
-        } = loader_state
-        else {
-            return Err(GatewayError::InvalidLoaderState.into());
-        };
-
-        // Check: ensure that the operator_or_upgrade_authority is a signer
-        if !operator_or_upgrade_authority.is_signer {
-            return Err(GatewayError::OperatorOrUpgradeAuthorityMustBeSigner.into());
-        }
-
-        // Check: the signer matches either the current operator or the upgrade
-        // authority
-        if !(gateway_config.operator == *operator_or_upgrade_authority.key
-            || upgrade_authority_address == Some(*operator_or_upgrade_authority.key))
-        {
-            return Err(GatewayError::InvalidOperatorOrAuthorityAccount.into());
-        }
+        let mut gateway_config = GatewayConfig::view_mut(gateway_root_pda)?;
+        assert_valid_gateway_root_pda(
+            gateway_config.namespace,
+            gateway_config.bump,
+            gateway_root_pda.key,
+        )?;
+
+        ensure_operator_or_upgrade_authority(
+            program_id,
+            &gateway_config.operator,
+            operator_or_upgrade_authority,
+            programdata_account,
+        )?;
 
         // Update the operator field
         gateway_config.operator = *new_operator.key;
@@ -98,3 +68,87 @@ impl Processor {
         Ok(())
     }
 }
+
+/// Ensures that `operator_or_upgrade_authority` is a signer and is either the
+/// current gateway operator or the upgrade authority of the gateway program.
+pub(super) fn ensure_operator_or_upgrade_authority(
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    operator_or_upgrade_authority: &AccountInfo<'_>,
+    programdata_account: &AccountInfo<'_>,
+) -> ProgramResult {
+    let upgrade_authority_address = upgrade_authority(program_id, programdata_account)?;
+
+    // Check: ensure that the operator_or_upgrade_authority is a signer
+    if !operator_or_upgrade_authority.is_signer {
+        return Err(GatewayError::OperatorOrUpgradeAuthorityMustBeSigner.into());
+    }
+
+    // Check: the signer matches either the current operator or the upgrade
+    // authority
+    if !(*operator == *operator_or_upgrade_authority.key
+        || upgrade_authority_address == Some(*operator_or_upgrade_authority.key))
+    {
+        return Err(GatewayError::InvalidOperatorOrAuthorityAccount.into());
+    }
+
+    Ok(())
+}
+
+/// Ensures that `upgrade_authority` is a signer and is the upgrade authority
+/// of the gateway program.
+pub(super) fn ensure_upgrade_authority(
+    program_id: &Pubkey,
+    upgrade_authority_account: &AccountInfo<'_>,
+    programdata_account: &AccountInfo<'_>,
+) -> ProgramResult {
+    let upgrade_authority_address = upgrade_authority(program_id, programdata_account)?;
+
+    // Check: ensure that the upgrade authority is a signer
+    if !upgrade_authority_account.is_signer {
+        return Err(GatewayError::OperatorOrUpgradeAuthorityMustBeSigner.into());
+    }
+
+    // Check: the signer matches the upgrade authority
+    if upgrade_authority_address != Some(*upgrade_authority_account.key) {
+        return Err(GatewayError::InvalidOperatorOrAuthorityAccount.into());
+    }
+
+    Ok(())
+}
+
+/// Reads the upgrade authority of the gateway program from its
+/// `programdata_account`.
+fn upgrade_authority(
+    program_id: &Pubkey,
+    programdata_account: &AccountInfo<'_>,
+) -> Result<Option<Pubkey>, ProgramError> {
+    // Check: programdata account derived correctly (it holds the upgrade authority
+    // information)
+    if *programdata_account.key
+        != Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id()).0
+    {
+        return Err(GatewayError::InvalidProgramDataDerivation.into());
+    }
+
+    // Check: the programdata state is valid
+    let loader_state = programdata_account
+        .data
+        .borrow()
+        .get(0..UpgradeableLoaderState::size_of_programdata_metadata())
+        .ok_or(GatewayError::InvalidLoaderContent)
+        .and_then(|bytes: &[u8]| {
+            bincode::deserialize::<UpgradeableLoaderState>(bytes)
+                .map_err(|_err| GatewayError::InvalidLoaderContent)
+        })?;
+
+    let UpgradeableLoaderState::ProgramData {
+        upgrade_authority_address,
+        ..
+    } = loader_state
+    else {
+        return Err(GatewayError::InvalidLoaderState.into());
+    };
+
+    Ok(upgrade_authority_address)
+}
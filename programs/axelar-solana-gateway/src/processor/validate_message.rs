@@ -4,7 +4,7 @@ use axelar_solana_encoding::hasher::SolanaSyscallHasher;
 use axelar_solana_encoding::types::messages::Message;
 use axelar_solana_encoding::LeafHash;
 use event_cpi_macros::{emit_cpi, event_cpi_accounts};
-use program_utils::pda::{BytemuckedPda, ValidPDA};
+use program_utils::pda::ValidPDA;
 use solana_program::account_info::{next_account_info, AccountInfo};
 use solana_program::msg;
 use solana_program::program_error::ProgramError;
@@ -14,9 +14,10 @@ use super::Processor;
 use crate::error::GatewayError;
 use crate::events::MessageExecutedEvent;
 use crate::state::incoming_message::{command_id, IncomingMessage, MessageStatus};
+use crate::state::AccountView;
 use crate::{
-    assert_initialized_and_valid_gateway_root_pda, assert_valid_incoming_message_pda,
-    create_validate_message_signing_pda,
+    assert_initialized_and_valid_gateway_root_pda,
+    assert_valid_incoming_message_pda_with_namespace, create_validate_message_signing_pda,
 };
 
 impl Processor {
@@ -47,7 +48,7 @@ impl Processor {
         event_cpi_accounts!(accounts_iter);
 
         // Check: Gateway Root PDA is initialized.
-        assert_initialized_and_valid_gateway_root_pda(gateway_root_pda)?;
+        let namespace = assert_initialized_and_valid_gateway_root_pda(gateway_root_pda)?;
 
         // compute the message hash
         let message_hash = message.hash::<SolanaSyscallHasher>();
@@ -56,10 +57,9 @@ impl Processor {
         let command_id = command_id(&message.cc_id.chain, &message.cc_id.id);
 
         incoming_message_pda.check_initialized_pda_without_deserialization(program_id)?;
-        let mut data = incoming_message_pda.try_borrow_mut_data()?;
-        let incoming_message =
-            IncomingMessage::read_mut(&mut data).ok_or(GatewayError::BytemuckDataLenInvalid)?;
-        assert_valid_incoming_message_pda(
+        let mut incoming_message = IncomingMessage::view_mut(incoming_message_pda)?;
+        assert_valid_incoming_message_pda_with_namespace(
+            namespace,
             &command_id,
             incoming_message.bump,
             incoming_message_pda.key,
@@ -103,6 +103,23 @@ impl Processor {
             destination_chain: message.destination_chain,
         });
 
+        #[cfg(feature = "audit-trace")]
+        {
+            drop(incoming_message);
+            let approved_at_slot = crate::state::incoming_message::IncomingMessageExtension::read(
+                &incoming_message_pda.try_borrow_data()?,
+            )
+            .map_or(0, |extension| extension.approved_at_slot);
+
+            emit_cpi!(crate::events::MessageValidationTraceEvent {
+                command_id,
+                incoming_message: *incoming_message_pda.key,
+                signing_pda: *caller.key,
+                message_hash,
+                approved_at_slot,
+            });
+        }
+
         Ok(())
     }
 }
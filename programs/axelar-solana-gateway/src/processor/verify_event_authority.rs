@@ -0,0 +1,76 @@
+use event_cpi_macros::{emit_cpi, event_cpi_accounts};
+use program_utils::pda::ValidPDA;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use super::transfer_operatorship::ensure_operator_or_upgrade_authority;
+use super::Processor;
+use crate::events::EventAuthorityVerifiedEvent;
+use crate::state::{AccountView, GatewayConfig};
+use crate::{assert_valid_event_authority, assert_valid_gateway_root_pda};
+
+impl Processor {
+    /// Verifies that the event authority PDA can still sign the gateway event
+    /// CPIs, and emits an [`EventAuthorityVerifiedEvent`] through it.
+    ///
+    /// The event authority holds no state, so there is nothing to
+    /// re-initialize: a successful run proves that the derivation matches the
+    /// deployed program ID and that event emission is not bricked.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError`] if:
+    /// * Required accounts are missing
+    /// * Gateway root PDA is not initialized
+    ///
+    /// Returns [`GatewayError`](crate::error::GatewayError) if:
+    /// * Gateway root PDA is invalid
+    /// * Signer is neither operator nor upgrade authority
+    /// * The event authority is not the canonical, empty event-cpi PDA
+    pub fn process_verify_event_authority(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo<'_>],
+    ) -> ProgramResult {
+        let accounts_iter = &mut accounts.iter();
+        let operator_or_upgrade_authority = next_account_info(accounts_iter)?;
+        let programdata_account = next_account_info(accounts_iter)?;
+        let gateway_root_pda = next_account_info(accounts_iter)?;
+
+        // Check: the event authority derivation and state, before the generic
+        // event-cpi checks, to surface a dedicated error
+        let event_authority = accounts_iter
+            .clone()
+            .next()
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let event_authority_bump = assert_valid_event_authority(event_authority)?;
+        event_cpi_accounts!(accounts_iter);
+
+        // Check: Gateway Root PDA is initialized and valid.
+        gateway_root_pda.check_initialized_pda_without_deserialization(&crate::ID)?;
+        let operator = {
+            let gateway_config = GatewayConfig::view(gateway_root_pda)?;
+            assert_valid_gateway_root_pda(
+                gateway_config.namespace,
+                gateway_config.bump,
+                gateway_root_pda.key,
+            )?;
+            gateway_config.operator
+        };
+
+        ensure_operator_or_upgrade_authority(
+            program_id,
+            &operator,
+            operator_or_upgrade_authority,
+            programdata_account,
+        )?;
+
+        emit_cpi!(EventAuthorityVerifiedEvent {
+            event_authority: *event_authority.key,
+            bump: event_authority_bump,
+        });
+
+        Ok(())
+    }
+}
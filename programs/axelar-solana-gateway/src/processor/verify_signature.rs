@@ -1,23 +1,32 @@
 use axelar_solana_encoding::types::execute_data::SigningVerifierSetInfo;
+#[cfg(feature = "audit-trace")]
+use event_cpi_macros::{emit_cpi, event_cpi_accounts};
 use program_utils::pda::{BytemuckedPda, ValidPDA};
 use solana_program::account_info::{next_account_info, AccountInfo};
 use solana_program::entrypoint::ProgramResult;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
 
 use super::Processor;
 use crate::error::GatewayError;
 use crate::state::signature_verification_pda::SignatureVerificationSessionData;
-use crate::state::verifier_set_tracker::VerifierSetTracker;
-use crate::state::GatewayConfig;
+use crate::state::verifier_set_tracker::{SignatureScheme, VerifierSetTracker};
+use crate::state::{AccountView, GatewayConfig};
 use crate::{
-    assert_valid_gateway_root_pda, assert_valid_signature_verification_pda,
-    assert_valid_verifier_set_tracker_pda,
+    assert_valid_gateway_root_pda, assert_valid_signature_verification_pda_with_namespace,
+    assert_valid_verifier_set_tracker_pda_with_namespace,
 };
 
 impl Processor {
     /// Verifies signatures for a given a payload using the current verifier set.
     ///
+    /// Signatures of [`SignatureScheme::Ed25519`] verifier sets must be
+    /// verified by an Ed25519 program instruction right before this one, read
+    /// through the instructions sysvar.
+    ///
     /// # Errors
     ///
     /// Returns [`ProgramError`] if:
@@ -27,7 +36,10 @@ impl Processor {
     /// Returns [`GatewayError`] if:
     /// * PDA validation fails
     /// * Verifier set is expired.
+    /// * Verification session already reached the quorum.
     /// * Verification session state is invalid.
+    /// * The signer key doesn't belong to the signature scheme of the verifier
+    ///   set.
     /// * Data serialization fails.
     pub fn process_verify_signature(
         program_id: &Pubkey,
@@ -40,20 +52,23 @@ impl Processor {
         let gateway_root_pda = next_account_info(accounts_iter)?;
         let verification_session_account = next_account_info(accounts_iter)?;
         let verifier_set_tracker_account = next_account_info(accounts_iter)?;
+        #[cfg(feature = "audit-trace")]
+        event_cpi_accounts!(accounts_iter);
 
         // Check: Gateway Root PDA is initialized and valid.
         gateway_root_pda.check_initialized_pda_without_deserialization(&crate::ID)?;
-        let gateway_data = gateway_root_pda.try_borrow_data()?;
-        let gateway_config =
-            GatewayConfig::read(&gateway_data).ok_or(GatewayError::BytemuckDataLenInvalid)?;
-        assert_valid_gateway_root_pda(gateway_config.bump, gateway_root_pda.key)?;
+        let gateway_config = GatewayConfig::view(gateway_root_pda)?;
+        assert_valid_gateway_root_pda(
+            gateway_config.namespace,
+            gateway_config.bump,
+            gateway_root_pda.key,
+        )?;
 
         // Check: Verification session PDA is initialized.
         verification_session_account.check_initialized_pda_without_deserialization(program_id)?;
-        let mut data = verification_session_account.try_borrow_mut_data()?;
-        let session = SignatureVerificationSessionData::read_mut(&mut data)
-            .ok_or(GatewayError::BytemuckDataLenInvalid)?;
-        assert_valid_signature_verification_pda(
+        let mut session = SignatureVerificationSessionData::view_mut(verification_session_account)?;
+        assert_valid_signature_verification_pda_with_namespace(
+            gateway_config.namespace,
             &payload_merkle_root,
             &session.signature_verification.signing_verifier_set_hash,
             session.bump,
@@ -65,11 +80,19 @@ impl Processor {
         let data = verifier_set_tracker_account.try_borrow_data()?;
         let verifier_set_tracker =
             VerifierSetTracker::read(&data).ok_or(GatewayError::BytemuckDataLenInvalid)?;
-        assert_valid_verifier_set_tracker_pda(
+        assert_valid_verifier_set_tracker_pda_with_namespace(
+            gateway_config.namespace,
             verifier_set_tracker,
             verifier_set_tracker_account.key,
         )?;
 
+        // Check: Session hasn't reached the quorum yet. Relayers can stop
+        // submitting signatures once this is returned.
+        if session.signature_verification.is_valid() {
+            solana_program::msg!("Signing session already valid, skipping verification");
+            return Err(GatewayError::SigningSessionAlreadyValid.into());
+        }
+
         // Check: Verifier set isn't expired
         gateway_config.assert_valid_epoch(verifier_set_tracker.epoch)?;
 
@@ -78,6 +101,25 @@ impl Processor {
             return Err(GatewayError::InvalidDomainSeparator.into());
         }
 
+        // The instructions sysvar follows the event accounts, which are
+        // always passed
+        let signature_scheme = verifier_set_tracker.signature_scheme()?;
+        let ed25519_instruction = match signature_scheme {
+            SignatureScheme::Secp256k1 => None,
+            SignatureScheme::Ed25519 => {
+                let instructions_sysvar =
+                    accounts.get(5).ok_or(ProgramError::NotEnoughAccountKeys)?;
+                preceding_ed25519_instruction(instructions_sysvar)?
+            }
+        };
+
+        #[cfg(feature = "audit-trace")]
+        let accumulated_weight = session
+            .signature_verification
+            .accumulated_threshold
+            .get()
+            .saturating_add(verifier_info.leaf.signer_weight);
+
         // Verify the signature
         session
             .signature_verification
@@ -85,12 +127,46 @@ impl Processor {
                 verifier_info,
                 &verifier_set_tracker.verifier_set_hash,
                 &payload_merkle_root,
+                signature_scheme,
+                ed25519_instruction.as_deref(),
             )
             .map_err(|error| {
                 solana_program::msg!("Error: {}", error);
                 ProgramError::InvalidInstructionData
             })?;
 
+        #[cfg(feature = "audit-trace")]
+        emit_cpi!(crate::events::SignatureVerifiedTraceEvent {
+            verification_session: *verification_session_account.key,
+            payload_merkle_root,
+            signing_verifier_set_hash: session.signature_verification.signing_verifier_set_hash,
+            signer_position: verifier_info.leaf.position,
+            signer_weight: verifier_info.leaf.signer_weight,
+            accumulated_weight,
+            quorum: verifier_info.leaf.quorum,
+        });
+
         Ok(())
     }
 }
+
+/// The data of the instruction right before the current one, if it's an
+/// instruction of the Ed25519 program.
+fn preceding_ed25519_instruction(
+    instructions_sysvar: &AccountInfo<'_>,
+) -> Result<Option<Vec<u8>>, ProgramError> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    let Some(preceding_index) = current_index.checked_sub(1) else {
+        solana_program::msg!("Ed25519 signatures must be verified by the preceding instruction");
+        return Ok(None);
+    };
+
+    let instruction =
+        load_instruction_at_checked(usize::from(preceding_index), instructions_sysvar)?;
+    if instruction.program_id != solana_program::ed25519_program::ID {
+        solana_program::msg!("Ed25519 signatures must be verified by the preceding instruction");
+        return Ok(None);
+    }
+
+    Ok(Some(instruction.data))
+}
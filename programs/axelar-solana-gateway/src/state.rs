@@ -1,10 +1,18 @@
 //! Module for the Gateway program account structs.
 
+pub mod chain_registry;
 pub mod config;
 pub mod incoming_message;
+pub mod layout;
 pub mod message_payload;
+pub mod operatorship_recovery;
+pub mod scan;
 pub mod signature_verification;
 pub mod signature_verification_pda;
 pub mod verifier_set_tracker;
+pub mod view;
 
+pub use chain_registry::ChainRegistry;
 pub use config::GatewayConfig;
+pub use operatorship_recovery::OperatorshipRecovery;
+pub use view::AccountView;
@@ -0,0 +1,149 @@
+//! Module for the `ChainRegistry` account type.
+
+use anchor_discriminators_macros::account;
+use bytemuck::{Pod, Zeroable};
+use program_utils::pda::BytemuckedPda;
+
+use crate::error::GatewayError;
+
+/// Maximum number of destination chains the [`ChainRegistry`] can hold.
+pub const MAX_REGISTERED_CHAINS: usize = 32;
+
+/// Destination chains registered by the operator. Once at least one chain is
+/// registered, `CallContract` instructions that pass the registry only accept
+/// registered destination chains, so that misspelled chain names fail at the
+/// source instead of being lost in the network.
+#[repr(C)]
+#[account(zero_copy)]
+#[allow(clippy::partial_pub_fields)]
+#[derive(Pod, Zeroable, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ChainRegistry {
+    /// Hashes of the registered chain names, see [`ChainRegistry::chain_hash`].
+    /// Only the first `count` are set.
+    pub chain_hashes: [[u8; 32]; MAX_REGISTERED_CHAINS],
+    /// Number of registered chains.
+    pub count: u8,
+    /// The canonical bump for this account.
+    pub bump: u8,
+    /// padding for bump
+    _padding: [u8; 6],
+}
+
+impl BytemuckedPda for ChainRegistry {}
+
+impl ChainRegistry {
+    /// Creates a new [`ChainRegistry`] without any registered chain.
+    #[must_use]
+    pub const fn new(bump: u8) -> Self {
+        Self {
+            chain_hashes: [[0; 32]; MAX_REGISTERED_CHAINS],
+            count: 0,
+            bump,
+            _padding: [0; 6],
+        }
+    }
+
+    /// The hash a chain name is tracked by.
+    #[must_use]
+    pub fn chain_hash(chain: &str) -> [u8; 32] {
+        solana_program::keccak::hash(chain.as_bytes()).to_bytes()
+    }
+
+    /// The hashes of the registered chains.
+    #[must_use]
+    pub fn registered(&self) -> &[[u8; 32]] {
+        self.chain_hashes
+            .get(..usize::from(self.count))
+            .unwrap_or_default()
+    }
+
+    /// Whether `chain` is an accepted destination chain. Every chain is
+    /// accepted while the registry is empty.
+    #[must_use]
+    pub fn accepts(&self, chain: &str) -> bool {
+        self.count == 0 || self.registered().contains(&Self::chain_hash(chain))
+    }
+
+    /// Registers or unregisters `chain`. Registering a registered chain or
+    /// unregistering an unknown one is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// - [`GatewayError::TooManyRegisteredChains`] if [`MAX_REGISTERED_CHAINS`]
+    ///   chains are already registered.
+    pub fn set_registered(&mut self, chain: &str, registered: bool) -> Result<(), GatewayError> {
+        let chain_hash = Self::chain_hash(chain);
+        let position = self
+            .registered()
+            .iter()
+            .position(|hash| hash == &chain_hash);
+
+        match (position, registered) {
+            (None, true) => {
+                let slot = self
+                    .chain_hashes
+                    .get_mut(usize::from(self.count))
+                    .ok_or(GatewayError::TooManyRegisteredChains)?;
+                *slot = chain_hash;
+                self.count = self.count.saturating_add(1);
+            }
+            (Some(index), false) => {
+                // Move the last registered chain into the freed slot
+                let last = usize::from(self.count.saturating_sub(1));
+                self.chain_hashes.swap(index, last);
+                if let Some(slot) = self.chain_hashes.get_mut(last) {
+                    *slot = [0; 32];
+                }
+                self.count = self.count.saturating_sub(1);
+            }
+            (None, false) | (Some(_), true) => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_registry_accepts_every_chain() {
+        let registry = ChainRegistry::new(255);
+
+        assert!(registry.accepts("ethereum"));
+        assert!(registry.accepts("Ethereum"));
+    }
+
+    #[test]
+    fn test_registry_only_accepts_registered_chains() {
+        let mut registry = ChainRegistry::new(255);
+        registry.set_registered("ethereum", true).unwrap();
+        registry.set_registered("avalanche", true).unwrap();
+        registry.set_registered("ethereum", true).unwrap();
+
+        assert_eq!(registry.count, 2);
+        assert!(registry.accepts("ethereum"));
+        assert!(!registry.accepts("Ethereum"));
+
+        registry.set_registered("ethereum", false).unwrap();
+        assert_eq!(registry.count, 1);
+        assert!(!registry.accepts("ethereum"));
+        assert!(registry.accepts("avalanche"));
+    }
+
+    #[test]
+    fn test_registry_capacity() {
+        let mut registry = ChainRegistry::new(255);
+        for i in 0..MAX_REGISTERED_CHAINS {
+            registry
+                .set_registered(&format!("chain-{i}"), true)
+                .unwrap();
+        }
+
+        assert_eq!(
+            registry.set_registered("one-too-many", true),
+            Err(GatewayError::TooManyRegisteredChains)
+        );
+    }
+}
@@ -7,6 +7,12 @@ use program_utils::pda::BytemuckedPda;
 use solana_program::pubkey::Pubkey;
 
 use crate::error::GatewayError;
+use crate::state::verifier_set_tracker::VerifierSetTracker;
+use crate::{GatewayNamespace, DEFAULT_NAMESPACE};
+
+/// Domain tag of [`GatewayConfig::state_digest`]. Bumped whenever the
+/// digested fields change, so that digests of different layouts never match.
+pub const STATE_DIGEST_DOMAIN: &[u8] = b"axelar-gateway-state-digest-v2";
 
 /// Timestamp alias for when the last signer rotation happened
 pub type Timestamp = u64;
@@ -35,8 +41,12 @@ pub struct GatewayConfig {
     pub domain_separator: [u8; 32],
     /// The canonical bump for this account.
     pub bump: u8,
-    /// padding for bump
-    _padding: [u8; 7],
+    /// The namespace of this gateway instance, see [`GatewayNamespace`].
+    /// Configs created before namespaces were introduced read the
+    /// [`DEFAULT_NAMESPACE`] from their zeroed padding.
+    pub namespace: GatewayNamespace,
+    /// padding for bump and namespace
+    _padding: [u8; 6],
 }
 
 impl BytemuckedPda for GatewayConfig {}
@@ -61,10 +71,71 @@ impl GatewayConfig {
             operator,
             domain_separator,
             bump,
-            _padding: [0; 7],
+            namespace: DEFAULT_NAMESPACE,
+            _padding: [0; 6],
         }
     }
 
+    /// Places the config in the given namespace.
+    #[must_use]
+    pub const fn with_namespace(mut self, namespace: GatewayNamespace) -> Self {
+        self.namespace = namespace;
+        self
+    }
+
+    /// Reads the config from the account data, ignoring any trailing bytes.
+    ///
+    /// The config account can be grown with [`GatewayInstruction::ResizeConfig`]
+    /// to make room for fields added in later versions. The current layout
+    /// always occupies the first [`GatewayConfig::pda_size`] bytes, and the rest
+    /// is reserved for future versions.
+    ///
+    /// [`GatewayInstruction::ResizeConfig`]: crate::instructions::GatewayInstruction::ResizeConfig
+    #[must_use]
+    pub fn read_versioned(data: &[u8]) -> Option<&Self> {
+        Self::read(data.get(..Self::pda_size())?)
+    }
+
+    /// Mutable counterpart of [`GatewayConfig::read_versioned`].
+    #[must_use]
+    pub fn read_versioned_mut(data: &mut [u8]) -> Option<&mut Self> {
+        Self::read_mut(data.get_mut(..Self::pda_size())?)
+    }
+
+    /// Hashes the essential gateway state into a single digest that can be
+    /// compared against the expected deployment.
+    ///
+    /// Covers every config field but the bump, along with the epoch and hash
+    /// of the current verifier set and of the previous one, if any.
+    #[must_use]
+    #[allow(clippy::little_endian_bytes)]
+    pub fn state_digest(
+        &self,
+        current_verifier_set: &VerifierSetTracker,
+        previous_verifier_set: Option<&VerifierSetTracker>,
+    ) -> [u8; 32] {
+        let (previous_epoch, previous_verifier_set_hash) = previous_verifier_set
+            .map_or(([0; 32], [0; 32]), |tracker| {
+                (tracker.epoch.to_le_bytes(), tracker.verifier_set_hash)
+            });
+
+        solana_program::keccak::hashv(&[
+            STATE_DIGEST_DOMAIN,
+            &self.domain_separator,
+            self.operator.as_ref(),
+            &self.current_epoch.to_le_bytes(),
+            &self.previous_verifier_set_retention.to_le_bytes(),
+            &self.minimum_rotation_delay.to_le_bytes(),
+            &self.last_rotation_timestamp.to_le_bytes(),
+            &[self.namespace],
+            &current_verifier_set.epoch.to_le_bytes(),
+            &current_verifier_set.verifier_set_hash,
+            &previous_epoch,
+            &previous_verifier_set_hash,
+        ])
+        .to_bytes()
+    }
+
     /// Asserts that the given epoch is still valid according to the gateway's verifier set
     /// retention policy.
     ///
@@ -1,10 +1,22 @@
 //! Module for the `IncomingMessage` account type.
 
+use core::mem::size_of;
+
+use anchor_discriminators::Discriminator;
 use anchor_discriminators_macros::account;
 use bytemuck::{Pod, Zeroable};
 use program_utils::pda::BytemuckedPda;
+use solana_program::pubkey::Pubkey;
+
+/// Upper bound for the compute units a relayer can report for an execution,
+/// matching the maximum compute unit limit of a Solana transaction.
+pub const MAX_EXECUTION_COMPUTE_UNITS: u32 = 1_400_000;
 
 /// Data for the incoming message (from Axelar to Solana) PDA.
+///
+/// Messages approved since execution receipts were introduced are followed
+/// by an [`IncomingMessageExtension`] in the account data, so the struct is
+/// read with [`IncomingMessage::read_versioned`], which ignores it.
 #[repr(C)]
 #[account(zero_copy)]
 #[allow(clippy::partial_pub_fields)]
@@ -43,12 +55,112 @@ impl IncomingMessage {
             payload_hash,
         }
     }
+
+    /// Size of the PDA of the messages approved with an
+    /// [`IncomingMessageExtension`].
+    #[must_use]
+    pub fn extended_pda_size() -> usize {
+        IncomingMessageExtension::OFFSET + size_of::<IncomingMessageExtension>()
+    }
+
+    /// Reads the message from the account data, ignoring the
+    /// [`IncomingMessageExtension`] following it, if any.
+    #[must_use]
+    pub fn read_versioned(data: &[u8]) -> Option<&Self> {
+        Self::read(data.get(..Self::pda_size())?)
+    }
+
+    /// Mutable counterpart of [`IncomingMessage::read_versioned`].
+    #[must_use]
+    pub fn read_versioned_mut(data: &mut [u8]) -> Option<&mut Self> {
+        Self::read_mut(data.get_mut(..Self::pda_size())?)
+    }
+}
+
+/// Data stored after the [`IncomingMessage`] in the PDA of the messages
+/// approved since it was introduced. The PDAs of messages approved before end
+/// with the [`IncomingMessage`] and have no extension.
+///
+/// The extension starts right after the [`IncomingMessage`], at an offset
+/// that isn't aligned, so it's always copied out of and back into the account
+/// data rather than referenced in place.
+#[repr(C)]
+#[allow(clippy::partial_pub_fields)]
+#[derive(Zeroable, Pod, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct IncomingMessageExtension {
+    /// The relayer that executed the message. Set to the default pubkey until
+    /// an execution receipt is recorded.
+    pub relayer: Pubkey,
+    /// Compute units consumed by the execution, as reported by the relayer.
+    /// Zero until an execution receipt is recorded.
+    pub compute_units: u32,
+    /// Padding for memory alignment.
+    _pad: [u8; 4],
+    /// The slot in which the message was approved.
+    pub approved_at_slot: u64,
+    /// The unix timestamp at which the message was approved, taken from the
+    /// [`Clock`](solana_program::clock::Clock) sysvar rather than from the
+    /// relayer.
+    pub approved_at: i64,
+}
+
+impl IncomingMessageExtension {
+    /// The extension of a message approved at `approved_at_slot` and
+    /// `approved_at`, without an execution receipt.
+    #[must_use]
+    pub fn approved(approved_at_slot: u64, approved_at: i64) -> Self {
+        Self {
+            approved_at_slot,
+            approved_at,
+            ..Self::default()
+        }
+    }
+
+    /// Offset of the extension within the account data, right after the
+    /// discriminator and the [`IncomingMessage`].
+    pub const OFFSET: usize = IncomingMessage::DISCRIMINATOR.len() + size_of::<IncomingMessage>();
+
+    /// Reads the extension from the account data of an [`IncomingMessage`].
+    /// `None` if the message was approved before the extension was introduced.
+    #[must_use]
+    pub fn read(data: &[u8]) -> Option<Self> {
+        let bytes = data.get(Self::OFFSET..)?.get(..size_of::<Self>())?;
+        bytemuck::try_pod_read_unaligned(bytes).ok()
+    }
+
+    /// Writes the extension into the account data of an [`IncomingMessage`].
+    /// `None` if the account has no room for it.
+    #[must_use]
+    pub fn write(&self, data: &mut [u8]) -> Option<()> {
+        data.get_mut(Self::OFFSET..)?
+            .get_mut(..size_of::<Self>())?
+            .copy_from_slice(bytemuck::bytes_of(self));
+        Some(())
+    }
+
+    /// Whether an execution receipt has been recorded for this message.
+    #[must_use]
+    pub fn has_execution_receipt(&self) -> bool {
+        self.relayer != Pubkey::default()
+    }
+
+    /// Seconds elapsed between the approval of the message and `now`, for
+    /// destination programs enforcing a freshness policy. `None` if `now`
+    /// lies before the approval.
+    #[must_use]
+    pub const fn approval_age(&self, now: i64) -> Option<i64> {
+        match now.checked_sub(self.approved_at) {
+            Some(age) if age >= 0 => Some(age),
+            _ => None,
+        }
+    }
 }
 
 impl BytemuckedPda for IncomingMessage {}
 
 /// If this is marked as `Approved`, the command can be used for CPI
-/// [`GatewayInstruction::ValidateMessage`] instruction.
+/// [`GatewayInstruction::ValidateMessage`] instruction. A `Cancelled` message
+/// can never be executed.
 ///
 /// This maps to [these lines in the Solidity Gateway](https://github.com/axelarnetwork/axelar-cgp-solidity/blob/78fde453094074ca93ef7eea1e1395fba65ba4f6/contracts/AxelarGateway.sol#L636-L648)
 #[repr(C)]
@@ -64,13 +176,24 @@ impl MessageStatus {
         self.0 == 0
     }
 
-    /// Bit pattern: any non-zero -> Executed
+    /// Bit pattern: any non-zero but the cancelled one -> Executed
     ///
     /// [`GatewayInstruction::ValidateMessage`] has been called and the command
     /// has been executed by the destination program.
     #[must_use]
     pub const fn is_executed(&self) -> bool {
-        self.0 != 0
+        self.0 != 0 && !self.is_cancelled()
+    }
+
+    /// Bit pattern: `2` -> Cancelled
+    ///
+    /// [`GatewayInstruction::CancelMessage`] has been called by the source of
+    /// the message before it was executed.
+    ///
+    /// [`GatewayInstruction::CancelMessage`]: crate::instructions::GatewayInstruction::CancelMessage
+    #[must_use]
+    pub const fn is_cancelled(&self) -> bool {
+        self.0 == 2
     }
 
     /// Creates a `MessageStatus` value which can be interpreted as "approved".
@@ -82,7 +205,13 @@ impl MessageStatus {
     /// Creates a `MessageStatus` value which can be interpreted as "executed".
     #[must_use]
     pub const fn executed() -> Self {
-        Self(1) // any non-zero value would also work
+        Self(1) // any non-zero value but the cancelled one would also work
+    }
+
+    /// Creates a `MessageStatus` value which can be interpreted as "cancelled".
+    #[must_use]
+    pub const fn cancelled() -> Self {
+        Self(2)
     }
 }
 
@@ -93,6 +222,7 @@ impl MessageStatus {
 /// The key point is that `MessageStatus` type (and not `bytemuck`) has the final
 /// word interpreting the bit pattern for all possible states:
 ///    * `0`      -> Approved
+///    * `2`      -> Cancelled
 ///    * non-zero -> Executed
 /// Therefore no invalid bit patterns are possible.
 ///
@@ -108,3 +238,17 @@ unsafe impl Pod for MessageStatus {}
 pub fn command_id(source_chain: &str, message_id: &str) -> [u8; 32] {
     solana_program::keccak::hashv(&[source_chain.as_bytes(), b"-", message_id.as_bytes()]).0
 }
+
+/// Prefix of the payload of a message cancelling a previously approved
+/// message, see [`GatewayInstruction::CancelMessage`].
+///
+/// [`GatewayInstruction::CancelMessage`]: crate::instructions::GatewayInstruction::CancelMessage
+pub const CANCEL_MESSAGE_PAYLOAD_PREFIX: &[u8] = b"axelar-gateway-cancel-message";
+
+/// Hash of the payload a source sends to the gateway to cancel the message
+/// with the given command id: the [`CANCEL_MESSAGE_PAYLOAD_PREFIX`] followed by
+/// the command id.
+#[must_use]
+pub fn cancel_message_payload_hash(command_id: &[u8; 32]) -> [u8; 32] {
+    solana_program::keccak::hashv(&[CANCEL_MESSAGE_PAYLOAD_PREFIX, command_id]).0
+}
@@ -0,0 +1,298 @@
+//! Byte layout of the gateway's zero-copy accounts.
+//!
+//! Every account listed here is stored as an 8-byte anchor discriminator
+//! followed by the `#[repr(C)]` struct, so a field's offset within the raw
+//! account data is `8 + offset_of!(Struct, field)`. All integers are stored
+//! little-endian, which is what the SBF runtime writes:
+//!
+//! - `U256` values (epochs, retention) are four `u64` limbs, least
+//!   significant limb first, i.e. a 32-byte little-endian integer.
+//! - `U128` values (accumulated thresholds) are a plain 16-byte little-endian
+//!   integer with an alignment of 1.
+//! - Padding fields are always zeroed.
+//!
+//! | Account                              | Size (w/o discriminator) | Alignment |
+//! |--------------------------------------|--------------------------|-----------|
+//! | [`GatewayConfig`]                    | 152                      | 8         |
+//! | [`IncomingMessage`]                  | 70                       | 1         |
+//! | [`VerifierSetTracker`]               | 72                       | 8         |
+//! | [`SignatureVerificationSessionData`] | 96                       | 2         |
+//! | [`ChainRegistry`]                    | 1032                     | 1         |
+//!
+//! The [`IncomingMessage`] of the messages approved since execution receipts
+//! were introduced is followed by a 56-byte
+//! [`IncomingMessageExtension`](super::incoming_message::IncomingMessageExtension),
+//! read unaligned with
+//! [`IncomingMessageExtension::read`](super::incoming_message::IncomingMessageExtension::read).
+//!
+//! Off-chain clients should not hand RPC account data to
+//! [`BytemuckedPda::read`]: the returned buffer is not guaranteed to satisfy
+//! the struct's alignment and, on big-endian hosts, the integers would be
+//! misread. Use [`read_account_data`] instead, which copies the bytes out
+//! unaligned and converts every integer field to the host's byte order.
+
+use axelar_message_primitives::U256;
+use program_utils::pda::BytemuckedPda;
+
+use super::chain_registry::ChainRegistry;
+use super::incoming_message::IncomingMessage;
+use super::signature_verification_pda::SignatureVerificationSessionData;
+use super::verifier_set_tracker::VerifierSetTracker;
+use super::GatewayConfig;
+
+/// A gateway account whose on-chain little-endian layout can be converted to
+/// the host's native representation.
+pub trait AccountLayout: BytemuckedPda {
+    /// Converts every integer field from little-endian to the host's byte
+    /// order. This is the identity on little-endian hosts.
+    // Named after the integer conversions like `u64::from_le`.
+    #[allow(clippy::wrong_self_convention)]
+    #[must_use]
+    fn from_le(self) -> Self;
+}
+
+/// Reads an owned `T` from raw account data, as returned by an RPC node.
+///
+/// The data must start with `T`'s discriminator and hold at least
+/// [`BytemuckedPda::pda_size`] bytes; trailing bytes (e.g. from a resized
+/// account) are ignored. Unlike [`BytemuckedPda::read`] the buffer does not
+/// need to be aligned.
+#[must_use]
+pub fn read_account_data<T: AccountLayout>(data: &[u8]) -> Option<T> {
+    let disc = T::DISCRIMINATOR;
+    if data.get(..disc.len())? != disc {
+        return None;
+    }
+
+    let body = data.get(disc.len()..T::pda_size())?;
+    bytemuck::try_pod_read_unaligned::<T>(body)
+        .ok()
+        .map(T::from_le)
+}
+
+fn u256_from_le(value: U256) -> U256 {
+    let limbs: [u64; 4] = bytemuck::cast(value);
+    bytemuck::cast(limbs.map(u64::from_le))
+}
+
+impl AccountLayout for GatewayConfig {
+    fn from_le(mut self) -> Self {
+        self.current_epoch = u256_from_le(self.current_epoch);
+        self.previous_verifier_set_retention = u256_from_le(self.previous_verifier_set_retention);
+        self.minimum_rotation_delay = u64::from_le(self.minimum_rotation_delay);
+        self.last_rotation_timestamp = u64::from_le(self.last_rotation_timestamp);
+        self
+    }
+}
+
+impl AccountLayout for IncomingMessage {
+    fn from_le(self) -> Self {
+        // Only holds bytes, there is no integer to convert.
+        self
+    }
+}
+
+impl AccountLayout for VerifierSetTracker {
+    fn from_le(mut self) -> Self {
+        self.epoch = u256_from_le(self.epoch);
+        self
+    }
+}
+
+impl AccountLayout for SignatureVerificationSessionData {
+    fn from_le(mut self) -> Self {
+        // The accumulated threshold is stored as little-endian bytes already.
+        self.approved_message_count = u16::from_le(self.approved_message_count);
+        self
+    }
+}
+
+impl AccountLayout for ChainRegistry {
+    fn from_le(self) -> Self {
+        // Only holds bytes, there is no integer to convert.
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::{align_of, offset_of, size_of};
+
+    use anchor_discriminators::Discriminator;
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+    use crate::state::incoming_message::{IncomingMessageExtension, MessageStatus};
+    use crate::state::signature_verification::SignatureVerification;
+    use crate::types::U128;
+
+    #[test]
+    fn test_gateway_config_layout() {
+        assert_eq!(size_of::<GatewayConfig>(), 152);
+        assert_eq!(align_of::<GatewayConfig>(), 8);
+        assert_eq!(offset_of!(GatewayConfig, current_epoch), 0);
+        assert_eq!(
+            offset_of!(GatewayConfig, previous_verifier_set_retention),
+            32
+        );
+        assert_eq!(offset_of!(GatewayConfig, minimum_rotation_delay), 64);
+        assert_eq!(offset_of!(GatewayConfig, last_rotation_timestamp), 72);
+        assert_eq!(offset_of!(GatewayConfig, operator), 80);
+        assert_eq!(offset_of!(GatewayConfig, domain_separator), 112);
+        assert_eq!(offset_of!(GatewayConfig, bump), 144);
+        assert_eq!(offset_of!(GatewayConfig, namespace), 145);
+    }
+
+    #[test]
+    fn test_incoming_message_layout() {
+        assert_eq!(size_of::<IncomingMessage>(), 70);
+        assert_eq!(align_of::<IncomingMessage>(), 1);
+        assert_eq!(offset_of!(IncomingMessage, bump), 0);
+        assert_eq!(offset_of!(IncomingMessage, signing_pda_bump), 1);
+        assert_eq!(offset_of!(IncomingMessage, status), 5);
+        assert_eq!(offset_of!(IncomingMessage, message_hash), 6);
+        assert_eq!(offset_of!(IncomingMessage, payload_hash), 38);
+
+        assert_eq!(size_of::<IncomingMessageExtension>(), 56);
+        assert_eq!(offset_of!(IncomingMessageExtension, relayer), 0);
+        assert_eq!(offset_of!(IncomingMessageExtension, compute_units), 32);
+        assert_eq!(offset_of!(IncomingMessageExtension, approved_at_slot), 40);
+        assert_eq!(offset_of!(IncomingMessageExtension, approved_at), 48);
+        assert_eq!(IncomingMessageExtension::OFFSET, 78);
+    }
+
+    #[test]
+    fn test_verifier_set_tracker_layout() {
+        assert_eq!(size_of::<VerifierSetTracker>(), 72);
+        assert_eq!(align_of::<VerifierSetTracker>(), 8);
+        assert_eq!(offset_of!(VerifierSetTracker, bump), 0);
+        assert_eq!(offset_of!(VerifierSetTracker, signature_scheme), 1);
+        assert_eq!(offset_of!(VerifierSetTracker, epoch), 8);
+        assert_eq!(offset_of!(VerifierSetTracker, verifier_set_hash), 40);
+    }
+
+    #[test]
+    fn test_signature_verification_session_layout() {
+        assert_eq!(size_of::<SignatureVerification>(), 80);
+        assert_eq!(align_of::<SignatureVerification>(), 1);
+        assert_eq!(offset_of!(SignatureVerification, accumulated_threshold), 0);
+        assert_eq!(offset_of!(SignatureVerification, signature_slots), 16);
+        assert_eq!(
+            offset_of!(SignatureVerification, signing_verifier_set_hash),
+            48
+        );
+
+        assert_eq!(size_of::<SignatureVerificationSessionData>(), 96);
+        assert_eq!(align_of::<SignatureVerificationSessionData>(), 2);
+        assert_eq!(
+            offset_of!(SignatureVerificationSessionData, signature_verification),
+            0
+        );
+        assert_eq!(offset_of!(SignatureVerificationSessionData, bump), 80);
+        assert_eq!(
+            offset_of!(SignatureVerificationSessionData, approved_message_count),
+            82
+        );
+    }
+
+    #[test]
+    fn test_chain_registry_layout() {
+        assert_eq!(size_of::<ChainRegistry>(), 1032);
+        assert_eq!(align_of::<ChainRegistry>(), 1);
+        assert_eq!(offset_of!(ChainRegistry, chain_hashes), 0);
+        assert_eq!(offset_of!(ChainRegistry, count), 1024);
+        assert_eq!(offset_of!(ChainRegistry, bump), 1025);
+    }
+
+    #[test]
+    fn test_u128_is_little_endian_and_unaligned() {
+        assert_eq!(align_of::<U128>(), 1);
+        let value = U128::new(0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10);
+        let bytes: [u8; 16] = bytemuck::cast(value);
+        assert_eq!(bytes.first(), Some(&0x10));
+        assert_eq!(bytes.last(), Some(&0x01));
+    }
+
+    fn account_bytes<T: BytemuckedPda>(value: &T) -> Vec<u8> {
+        let mut data = T::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(value));
+        data
+    }
+
+    /// Prefixes `data` with `misalign` zero bytes, so that the account data
+    /// no longer starts on an aligned address.
+    fn misaligned(data: &[u8], misalign: usize) -> Vec<u8> {
+        let mut buffer = vec![0_u8; misalign];
+        buffer.extend_from_slice(data);
+        buffer
+    }
+
+    #[test]
+    fn test_read_gateway_config_from_unaligned_buffer() {
+        let config = GatewayConfig::new(
+            U256::from_u64(7),
+            U256::from_u64(3),
+            3600,
+            1_700_000_000,
+            Pubkey::new_unique(),
+            [9; 32],
+            254,
+        );
+        let buffer = misaligned(&account_bytes(&config), 1);
+        let data = buffer.get(1..).unwrap();
+
+        let read = read_account_data::<GatewayConfig>(data).unwrap();
+        assert_eq!(read, config);
+    }
+
+    #[test]
+    #[allow(clippy::little_endian_bytes)]
+    fn test_read_incoming_message_fields_at_documented_offsets() {
+        let message = IncomingMessage::new(255, 254, MessageStatus::approved(), [1; 32], [2; 32]);
+        let mut account_data = account_bytes(&message);
+        account_data.resize(IncomingMessage::extended_pda_size(), 0);
+        IncomingMessageExtension::approved(42, 1_700_000_000)
+            .write(&mut account_data)
+            .unwrap();
+        let buffer = misaligned(&account_data, 3);
+        let data = buffer.get(3..).unwrap();
+
+        let slot = IncomingMessageExtension::OFFSET
+            + offset_of!(IncomingMessageExtension, approved_at_slot);
+        assert_eq!(data.get(slot..slot + 8).unwrap(), 42_u64.to_le_bytes());
+        let approved_at =
+            IncomingMessageExtension::OFFSET + offset_of!(IncomingMessageExtension, approved_at);
+        assert_eq!(
+            data.get(approved_at..approved_at + 8).unwrap(),
+            1_700_000_000_i64.to_le_bytes()
+        );
+
+        let read = read_account_data::<IncomingMessage>(data).unwrap();
+        assert_eq!(read, message);
+        let extension = IncomingMessageExtension::read(data).unwrap();
+        assert_eq!(extension.approved_at_slot, 42);
+        assert_eq!(extension.approved_at, 1_700_000_000);
+    }
+
+    #[test]
+    fn test_read_tolerates_trailing_bytes() {
+        let tracker = VerifierSetTracker::new(255, U256::from_u64(5), [4; 32]);
+        let mut data = account_bytes(&tracker);
+        data.extend_from_slice(&[0xff; 16]);
+
+        let read = read_account_data::<VerifierSetTracker>(&data).unwrap();
+        assert_eq!(read.epoch, U256::from_u64(5));
+        assert_eq!(read.verifier_set_hash, [4; 32]);
+    }
+
+    #[test]
+    fn test_read_rejects_wrong_discriminator_and_short_data() {
+        let tracker = VerifierSetTracker::new(255, U256::from_u64(5), [4; 32]);
+        let data = account_bytes(&tracker);
+        let truncated = data.get(..data.len() - 1).unwrap();
+
+        assert!(read_account_data::<GatewayConfig>(&data).is_none());
+        assert!(read_account_data::<VerifierSetTracker>(truncated).is_none());
+        assert!(read_account_data::<VerifierSetTracker>(&[]).is_none());
+    }
+}
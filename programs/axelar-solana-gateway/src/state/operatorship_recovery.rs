@@ -0,0 +1,67 @@
+//! Module for the `OperatorshipRecovery` account type.
+
+use anchor_discriminators_macros::account;
+use bytemuck::{Pod, Zeroable};
+use program_utils::pda::BytemuckedPda;
+use solana_program::pubkey::Pubkey;
+
+use super::config::Timestamp;
+
+/// Seconds that must elapse between initiating the recovery of the gateway
+/// operatorship and applying it.
+pub const OPERATORSHIP_RECOVERY_DELAY: u64 = 3 * 24 * 60 * 60;
+
+/// A pending recovery of the gateway operatorship, initiated by the upgrade
+/// authority of the gateway program. The new operator can only be set once
+/// `eligible_at` has passed, leaving time to react to a rogue recovery.
+#[repr(C)]
+#[account(zero_copy)]
+#[allow(clippy::partial_pub_fields)]
+#[derive(Pod, Zeroable, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct OperatorshipRecovery {
+    /// The operator the gateway is recovered to.
+    pub new_operator: Pubkey,
+    /// The unix timestamp from which the recovery can be applied.
+    pub eligible_at: Timestamp,
+    /// The canonical bump for this account.
+    pub bump: u8,
+    /// padding for bump
+    _padding: [u8; 7],
+}
+
+impl BytemuckedPda for OperatorshipRecovery {}
+
+impl OperatorshipRecovery {
+    /// Creates a new [`OperatorshipRecovery`] to `new_operator`, eligible
+    /// [`OPERATORSHIP_RECOVERY_DELAY`] seconds after `now`.
+    #[must_use]
+    pub const fn new(new_operator: Pubkey, now: Timestamp, bump: u8) -> Self {
+        Self {
+            new_operator,
+            eligible_at: now.saturating_add(OPERATORSHIP_RECOVERY_DELAY),
+            bump,
+            _padding: [0; 7],
+        }
+    }
+
+    /// Whether the timelock of the recovery has elapsed at `now`.
+    #[must_use]
+    pub const fn is_eligible(&self, now: Timestamp) -> bool {
+        now >= self.eligible_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recovery_is_timelocked() {
+        let recovery = OperatorshipRecovery::new(Pubkey::new_unique(), 1_000, 255);
+
+        assert_eq!(recovery.eligible_at, 1_000 + OPERATORSHIP_RECOVERY_DELAY);
+        assert!(!recovery.is_eligible(1_000));
+        assert!(!recovery.is_eligible(recovery.eligible_at - 1));
+        assert!(recovery.is_eligible(recovery.eligible_at));
+    }
+}
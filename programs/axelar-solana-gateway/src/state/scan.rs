@@ -0,0 +1,218 @@
+//! Filters for scanning the gateway's [`IncomingMessage`] PDAs with the
+//! `getProgramAccounts` RPC method, e.g. for relayers recovering the messages
+//! that were approved but never executed.
+//!
+//! The offsets below follow the account layout documented in
+//! [`layout`](super::layout) and are stable: they only change together with
+//! the [`IncomingMessage`] discriminator, so a filter built from them never
+//! matches an account of another layout.
+//!
+//! A scan over all the messages of a busy gateway can exceed the response
+//! limits of RPC nodes. [`IncomingMessageScan::pages`] splits a scan on the
+//! first byte of the message hash into [`IncomingMessageScan::PAGE_COUNT`]
+//! pages of roughly equal size, each fetched with its own request:
+//!
+//! ```ignore
+//! for page in IncomingMessageScan::approved().pages() {
+//!     let filters = page
+//!         .filters()
+//!         .into_iter()
+//!         .map(|filter| RpcFilterType::Memcmp(Memcmp::new_raw_bytes(filter.offset, filter.bytes)))
+//!         .collect();
+//!     let config = RpcProgramAccountsConfig { filters: Some(filters), ..Default::default() };
+//!     for (pda, account) in rpc.get_program_accounts_with_config(&axelar_solana_gateway::ID, config)? {
+//!         let message = page.decode(&account.data).expect("filtered by the RPC node");
+//!         // match `message.message_hash` against the messages known to the relayer
+//!     }
+//! }
+//! ```
+
+use anchor_discriminators::Discriminator;
+
+use super::incoming_message::{IncomingMessage, MessageStatus};
+use super::layout::read_account_data;
+
+/// Offset of the discriminator within the [`IncomingMessage`] account data.
+pub const INCOMING_MESSAGE_DISCRIMINATOR_OFFSET: usize = 0;
+
+/// Offset of the [`MessageStatus`] byte within the [`IncomingMessage`]
+/// account data.
+pub const INCOMING_MESSAGE_STATUS_OFFSET: usize = 13;
+
+/// Offset of the 32-byte message hash within the [`IncomingMessage`] account
+/// data.
+pub const INCOMING_MESSAGE_HASH_OFFSET: usize = 14;
+
+/// A `memcmp` filter of `getProgramAccounts`: the account data must hold
+/// `bytes` at `offset`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemcmpFilter {
+    /// Offset within the account data.
+    pub offset: usize,
+    /// Bytes the account data must hold at `offset`.
+    pub bytes: Vec<u8>,
+}
+
+impl MemcmpFilter {
+    /// Whether `data` satisfies the filter.
+    #[must_use]
+    pub fn matches(&self, data: &[u8]) -> bool {
+        data.get(self.offset..)
+            .and_then(|tail| tail.get(..self.bytes.len()))
+            .is_some_and(|bytes| bytes == self.bytes.as_slice())
+    }
+}
+
+/// A scan over the [`IncomingMessage`] PDAs with a given status, optionally
+/// restricted to one page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IncomingMessageScan {
+    status: MessageStatus,
+    page: Option<u8>,
+}
+
+impl IncomingMessageScan {
+    /// Number of pages [`Self::pages`] splits a scan into.
+    pub const PAGE_COUNT: usize = 256;
+
+    /// Scans the messages that were approved but neither executed nor
+    /// cancelled.
+    #[must_use]
+    pub const fn approved() -> Self {
+        Self::with_status(MessageStatus::approved())
+    }
+
+    /// Scans the messages with the given status.
+    ///
+    /// Executed messages are matched on the status byte the gateway writes,
+    /// [`MessageStatus::executed`].
+    #[must_use]
+    pub const fn with_status(status: MessageStatus) -> Self {
+        Self { status, page: None }
+    }
+
+    /// Restricts the scan to the messages whose hash starts with `page`.
+    #[must_use]
+    pub const fn page(self, page: u8) -> Self {
+        Self {
+            page: Some(page),
+            ..self
+        }
+    }
+
+    /// Splits the scan into [`Self::PAGE_COUNT`] disjoint pages that together
+    /// cover all the messages of the scan.
+    pub fn pages(self) -> impl Iterator<Item = Self> {
+        (0..=u8::MAX).map(move |page| self.page(page))
+    }
+
+    /// The `memcmp` filters selecting the accounts of the scan.
+    #[must_use]
+    pub fn filters(&self) -> Vec<MemcmpFilter> {
+        let mut filters = vec![
+            MemcmpFilter {
+                offset: INCOMING_MESSAGE_DISCRIMINATOR_OFFSET,
+                bytes: IncomingMessage::DISCRIMINATOR.to_vec(),
+            },
+            MemcmpFilter {
+                offset: INCOMING_MESSAGE_STATUS_OFFSET,
+                bytes: bytemuck::bytes_of(&self.status).to_vec(),
+            },
+        ];
+        if let Some(page) = self.page {
+            filters.push(MemcmpFilter {
+                offset: INCOMING_MESSAGE_HASH_OFFSET,
+                bytes: vec![page],
+            });
+        }
+
+        filters
+    }
+
+    /// Whether the raw account `data` belongs to the scan.
+    #[must_use]
+    pub fn matches(&self, data: &[u8]) -> bool {
+        self.filters().iter().all(|filter| filter.matches(data))
+    }
+
+    /// Reads the [`IncomingMessage`] from raw account `data`, as returned by
+    /// an RPC node, if it belongs to the scan.
+    #[must_use]
+    pub fn decode(&self, data: &[u8]) -> Option<IncomingMessage> {
+        if !self.matches(data) {
+            return None;
+        }
+
+        read_account_data(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::offset_of;
+
+    use program_utils::pda::BytemuckedPda;
+
+    use super::*;
+
+    fn account_data(status: MessageStatus, message_hash: [u8; 32]) -> Vec<u8> {
+        let message = IncomingMessage::new(255, 254, status, message_hash, [2; 32]);
+        let mut data = IncomingMessage::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&message));
+        data
+    }
+
+    #[test]
+    fn test_offsets_follow_the_account_layout() {
+        let disc = IncomingMessage::DISCRIMINATOR.len();
+
+        assert_eq!(
+            INCOMING_MESSAGE_STATUS_OFFSET,
+            disc + offset_of!(IncomingMessage, status)
+        );
+        assert_eq!(
+            INCOMING_MESSAGE_HASH_OFFSET,
+            disc + offset_of!(IncomingMessage, message_hash)
+        );
+    }
+
+    #[test]
+    fn test_approved_scan_skips_executed_and_cancelled_messages() {
+        let scan = IncomingMessageScan::approved();
+
+        let approved = account_data(MessageStatus::approved(), [1; 32]);
+        assert_eq!(scan.decode(&approved).unwrap().message_hash, [1; 32]);
+        assert!(!scan.matches(&account_data(MessageStatus::executed(), [1; 32])));
+        assert!(!scan.matches(&account_data(MessageStatus::cancelled(), [1; 32])));
+    }
+
+    #[test]
+    fn test_scan_rejects_other_accounts() {
+        let scan = IncomingMessageScan::approved();
+        let mut data = account_data(MessageStatus::approved(), [1; 32]);
+        if let Some(byte) = data.first_mut() {
+            *byte ^= 0xff;
+        }
+
+        assert!(!scan.matches(&data));
+        assert!(!scan.matches(&[]));
+        assert_eq!(scan.decode(&data), None);
+    }
+
+    #[test]
+    fn test_pages_partition_the_scan() {
+        let pages = IncomingMessageScan::approved().pages().collect::<Vec<_>>();
+        assert_eq!(pages.len(), IncomingMessageScan::PAGE_COUNT);
+
+        let mut message_hash = [7; 32];
+        message_hash[0] = 0xab;
+        let data = account_data(MessageStatus::approved(), message_hash);
+        assert_eq!(data.len(), IncomingMessage::pda_size());
+
+        let matching = pages
+            .iter()
+            .filter(|page| page.matches(&data))
+            .collect::<Vec<_>>();
+        assert_eq!(matching, [&IncomingMessageScan::approved().page(0xab)]);
+    }
+}
@@ -2,7 +2,9 @@
 
 use axelar_solana_encoding::hasher::{Hasher, SolanaSyscallHasher};
 use axelar_solana_encoding::types::execute_data::SigningVerifierSetInfo;
-use axelar_solana_encoding::types::pubkey::{PublicKey, Signature};
+use axelar_solana_encoding::types::pubkey::{
+    Ed25519Pubkey, Ed25519Signature, PublicKey, Signature,
+};
 use axelar_solana_encoding::types::verifier_set::VerifierSetLeaf;
 use axelar_solana_encoding::{rs_merkle, LeafHash};
 use bitvec::order::Lsb0;
@@ -13,7 +15,7 @@ use bytemuck::{Pod, Zeroable};
 use crate::error::GatewayError;
 use crate::types::U128;
 
-use super::verifier_set_tracker::VerifierSetHash;
+use super::verifier_set_tracker::{SignatureScheme, VerifierSetHash};
 
 /// Controls the signature verification session for a given payload.
 #[repr(C)]
@@ -58,17 +60,32 @@ impl SignatureVerification {
     /// # Errors
     ///
     /// Returns [`GatewayError`] if any of the following conditions occur:
+    /// * [`GatewayError::SigningSessionAlreadyValid`] if the quorum was already reached
     /// * [`GatewayError::InvalidMerkleProof`] if the Merkle proof bytes are invalid or malformed
     /// * [`GatewayError::SlotAlreadyProcessed`] if the verifier's slot has already been processed
     /// * [`GatewayError::InvalidMerkleProof`] if the Merkle proof verification fails
+    /// * [`GatewayError::InvalidSignatureScheme`] if the signer key doesn't
+    ///   belong to the `signature_scheme` of the verifier set
     /// * [`GatewayError::InvalidSignature`] if the digital signature is invalid
     /// * Additional errors may occur during slot marking or verifier set initialization
+    ///
+    /// Ed25519 signatures are too expensive to verify within the compute
+    /// budget. They're verified by the Ed25519 native program instead, and
+    /// `ed25519_instruction` is the data of the Ed25519 program instruction
+    /// that verified the signature, see [`ed25519_instruction_data`].
     pub fn process_signature(
         &mut self,
         verifier_info: &SigningVerifierSetInfo,
         verifier_set_merkle_root: &[u8; 32],
         payload_merkle_root: &[u8; 32],
+        signature_scheme: SignatureScheme,
+        ed25519_instruction: Option<&[u8]>,
     ) -> Result<(), GatewayError> {
+        // Check: Quorum isn't reached yet
+        if self.is_valid() {
+            return Err(GatewayError::SigningSessionAlreadyValid);
+        }
+
         let merkle_proof =
             rs_merkle::MerkleProof::<SolanaSyscallHasher>::from_bytes(&verifier_info.merkle_proof)
                 .map_err(|_err| GatewayError::InvalidMerkleProof)?;
@@ -79,11 +96,17 @@ impl SignatureVerification {
         // Check: Merkle proof
         Self::verify_merkle_proof(verifier_info.leaf, &merkle_proof, verifier_set_merkle_root)?;
 
+        // Check: Signer key belongs to the signature scheme of the verifier set
+        if SignatureScheme::of(&verifier_info.leaf.signer_pubkey) != signature_scheme {
+            return Err(GatewayError::InvalidSignatureScheme);
+        }
+
         // Check: Digital signature
         Self::verify_digital_signature(
             &verifier_info.leaf.signer_pubkey,
             payload_merkle_root,
             &verifier_info.signature,
+            ed25519_instruction,
         )?;
 
         // Update state
@@ -121,7 +144,7 @@ impl SignatureVerification {
     }
 
     #[inline]
-    fn verify_merkle_proof(
+    pub(crate) fn verify_merkle_proof(
         signature_node: VerifierSetLeaf,
         merkle_proof: &rs_merkle::MerkleProof<SolanaSyscallHasher>,
         verifier_set_merkle_root: &[u8; 32],
@@ -141,22 +164,25 @@ impl SignatureVerification {
     }
 
     #[inline]
-    #[allow(clippy::unimplemented)]
     fn verify_digital_signature(
         public_key: &PublicKey,
         message: &[u8; 32],
         signature: &Signature,
+        ed25519_instruction: Option<&[u8]>,
     ) -> Result<(), GatewayError> {
         let is_valid = match (signature, public_key) {
             (Signature::EcdsaRecoverable(signature), PublicKey::Secp256k1(pubkey)) => {
                 verify_ecdsa_signature_with_prefix(pubkey, signature, message)
             }
-            (Signature::Ed25519(_signature), PublicKey::Ed25519(_pubkey)) => {
-                // TODO: Whenever we implement this, make sure to use the
-                // `verify_eddsa_signature_with_prefix` function instead to account for the chain
-                // prefix, similar to what we do for ECDSA above.
-                unimplemented!()
-            }
+            (Signature::Ed25519(signature), PublicKey::Ed25519(pubkey)) => ed25519_instruction
+                .is_some_and(|data| {
+                    ed25519_instruction_verifies(
+                        data,
+                        pubkey,
+                        signature,
+                        &offchain_message_hash(message),
+                    )
+                }),
             _ => {
                 solana_program::msg!(
                     "Error: Invalid combination of Secp256k1 and Ed25519 signature and public key"
@@ -297,6 +323,111 @@ fn verify_eddsa_signature(
 /// Prefix added to all signature verifications for Solana offchain messages
 const SOLANA_OFFCHAIN_PREFIX: &[u8] = b"\xffsolana offchain";
 
+/// The digest verifiers sign for `message`: the hash of `message` prefixed
+/// with `\xffsolana offchain`.
+#[must_use]
+pub fn offchain_message_hash(message: &[u8; 32]) -> [u8; 32] {
+    let mut prefixed_message = Vec::with_capacity(SOLANA_OFFCHAIN_PREFIX.len() + message.len());
+    prefixed_message.extend_from_slice(SOLANA_OFFCHAIN_PREFIX);
+    prefixed_message.extend_from_slice(message);
+
+    SolanaSyscallHasher::hash(&prefixed_message)
+}
+
+/// Layout of a signature verified by the Ed25519 native program, following
+/// the signature count and a padding byte in the instruction data.
+#[repr(C)]
+#[derive(Zeroable, Pod, Clone, Copy, PartialEq, Eq, Debug)]
+struct Ed25519SignatureOffsets {
+    signature_offset: u16,
+    signature_instruction_index: u16,
+    public_key_offset: u16,
+    public_key_instruction_index: u16,
+    message_data_offset: u16,
+    message_data_size: u16,
+    message_instruction_index: u16,
+}
+
+/// Instruction index telling the Ed25519 program that the data lives in its
+/// own instruction.
+const ED25519_OWN_INSTRUCTION: u16 = u16::MAX;
+
+/// Offset of the [`Ed25519SignatureOffsets`] in the instruction data.
+const ED25519_OFFSETS_START: usize = 2;
+
+/// Offset of the public key in the data built by [`ed25519_instruction_data`].
+#[allow(clippy::cast_possible_truncation)]
+const ED25519_PUBLIC_KEY_OFFSET: u16 =
+    (ED25519_OFFSETS_START + core::mem::size_of::<Ed25519SignatureOffsets>()) as u16;
+
+/// The data of an Ed25519 program instruction verifying a single signature of
+/// `message`, with the public key, the signature and the message all in the
+/// instruction itself.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn ed25519_instruction_data(
+    pubkey: &Ed25519Pubkey,
+    signature: &Ed25519Signature,
+    message: &[u8; 32],
+) -> Vec<u8> {
+    let signature_offset = ED25519_PUBLIC_KEY_OFFSET + pubkey.len() as u16;
+    let message_data_offset = signature_offset + signature.len() as u16;
+    let offsets = Ed25519SignatureOffsets {
+        signature_offset,
+        signature_instruction_index: ED25519_OWN_INSTRUCTION,
+        public_key_offset: ED25519_PUBLIC_KEY_OFFSET,
+        public_key_instruction_index: ED25519_OWN_INSTRUCTION,
+        message_data_offset,
+        message_data_size: message.len() as u16,
+        message_instruction_index: ED25519_OWN_INSTRUCTION,
+    };
+
+    let mut data = Vec::with_capacity(usize::from(message_data_offset) + message.len());
+    // A single signature, and a padding byte
+    data.extend_from_slice(&[1, 0]);
+    data.extend_from_slice(bytemuck::bytes_of(&offsets));
+    data.extend_from_slice(pubkey);
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+    data
+}
+
+/// Whether the data of an Ed25519 program instruction verifies exactly one
+/// signature, `signature` of `message` by `pubkey`, read from the
+/// instruction itself. The runtime rejects the transaction if the Ed25519
+/// program fails to verify it.
+fn ed25519_instruction_verifies(
+    data: &[u8],
+    pubkey: &Ed25519Pubkey,
+    signature: &Ed25519Signature,
+    message: &[u8; 32],
+) -> bool {
+    if data.first() != Some(&1) {
+        solana_program::msg!("Ed25519 instruction must verify a single signature");
+        return false;
+    }
+    let Some(offsets) = data
+        .get(ED25519_OFFSETS_START..)
+        .and_then(|data| data.get(..core::mem::size_of::<Ed25519SignatureOffsets>()))
+        .map(bytemuck::pod_read_unaligned::<Ed25519SignatureOffsets>)
+    else {
+        return false;
+    };
+
+    let bytes_at = |offset: u16, len: usize| {
+        let offset = usize::from(offset);
+        data.get(offset..offset.checked_add(len)?)
+    };
+
+    offsets.signature_instruction_index == ED25519_OWN_INSTRUCTION
+        && offsets.public_key_instruction_index == ED25519_OWN_INSTRUCTION
+        && offsets.message_instruction_index == ED25519_OWN_INSTRUCTION
+        && usize::from(offsets.message_data_size) == message.len()
+        && bytes_at(offsets.public_key_offset, pubkey.len()) == Some(pubkey.as_slice())
+        && bytes_at(offsets.signature_offset, signature.len()) == Some(signature.as_slice())
+        && bytes_at(offsets.message_data_offset, message.len()) == Some(message.as_slice())
+}
+
 /// Wrapper for `verify_ecdsa_signature` that adds the Solana offchain prefix.
 ///
 /// This function prepends `\xffsolana offchain` to the message before verification.
@@ -313,16 +444,7 @@ pub fn verify_ecdsa_signature_with_prefix(
     signature: &axelar_solana_encoding::types::pubkey::EcdsaRecoverableSignature,
     message: &[u8; 32],
 ) -> bool {
-    // Create prefixed message by concatenating prefix + original message
-    let mut prefixed_message = Vec::with_capacity(SOLANA_OFFCHAIN_PREFIX.len() + message.len());
-    prefixed_message.extend_from_slice(SOLANA_OFFCHAIN_PREFIX);
-    prefixed_message.extend_from_slice(message);
-
-    // Hash the prefixed message to get a 32-byte digest
-    let hashed_message = SolanaSyscallHasher::hash(&prefixed_message);
-
-    // Call the original verification function with the hashed prefixed message
-    verify_ecdsa_signature(pubkey, signature, &hashed_message)
+    verify_ecdsa_signature(pubkey, signature, &offchain_message_hash(message))
 }
 
 /// Wrapper for `verify_eddsa_signature` that adds the Solana offchain prefix.
@@ -338,16 +460,7 @@ pub fn verify_eddsa_signature_with_prefix(
     signature: &axelar_solana_encoding::types::pubkey::Ed25519Signature,
     message: &[u8; 32],
 ) -> bool {
-    // Create prefixed message by concatenating prefix + original message
-    let mut prefixed_message = Vec::with_capacity(SOLANA_OFFCHAIN_PREFIX.len() + message.len());
-    prefixed_message.extend_from_slice(SOLANA_OFFCHAIN_PREFIX);
-    prefixed_message.extend_from_slice(message);
-
-    // Hash the prefixed message to get a 32-byte digest
-    let hashed_message = SolanaSyscallHasher::hash(&prefixed_message);
-
-    // Call the original verification function with the hashed prefixed message
-    verify_eddsa_signature(pubkey, signature, &hashed_message)
+    verify_eddsa_signature(pubkey, signature, &offchain_message_hash(message))
 }
 
 #[cfg(test)]
@@ -355,7 +468,9 @@ mod tests {
     use super::*;
     use axelar_solana_encoding::hasher::{Hasher, NativeHasher};
     use axelar_solana_encoding::types::execute_data::SigningVerifierSetInfo;
-    use axelar_solana_encoding::types::pubkey::{PublicKey, Signature};
+    use axelar_solana_encoding::types::pubkey::{
+        Ed25519Pubkey, Ed25519Signature, PublicKey, Signature,
+    };
     use axelar_solana_encoding::types::verifier_set::VerifierSetLeaf;
     use axelar_solana_encoding::{rs_merkle, LeafHash};
     use rand::Rng;
@@ -413,6 +528,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_process_signature_returns_session_already_valid_error() {
+        let mut verification = SignatureVerification {
+            accumulated_threshold: U128::MAX,
+            ..Default::default()
+        };
+
+        let verifier_info = SigningVerifierSetInfo {
+            leaf: VerifierSetLeaf {
+                signer_pubkey: PublicKey::Secp256k1([0; 33]),
+                signer_weight: 1,
+                position: 0u8.into(),
+                quorum: 1,
+                set_size: 1u8.into(),
+                domain_separator: [0; 32],
+                nonce: 0,
+            },
+            signature: Signature::EcdsaRecoverable([0; 65]),
+            merkle_proof: vec![],
+        };
+
+        // The signature is not checked once the quorum is reached
+        assert_eq!(
+            verification.process_signature(
+                &verifier_info,
+                &[0; 32],
+                &[0; 32],
+                SignatureScheme::Secp256k1,
+                None
+            ),
+            Err(GatewayError::SigningSessionAlreadyValid)
+        );
+        assert!(verification.slots_iter().all(|slot| !slot));
+    }
+
     #[test]
     fn test_process_signature_returns_slot_already_verified_error() {
         // Create ECDSA keypair
@@ -485,13 +635,139 @@ mod tests {
 
         // First call should succeed and mark the slot as verified
         assert!(verification
-            .process_signature(&verifier_info, &merkle_root, &payload_merkle_root)
+            .process_signature(
+                &verifier_info,
+                &merkle_root,
+                &payload_merkle_root,
+                SignatureScheme::Secp256k1,
+                None
+            )
             .is_ok());
 
         // Second call with the same input should fail with SlotAlreadyVerified
         assert_eq!(
-            verification.process_signature(&verifier_info, &merkle_root, &payload_merkle_root),
+            verification.process_signature(
+                &verifier_info,
+                &merkle_root,
+                &payload_merkle_root,
+                SignatureScheme::Secp256k1,
+                None
+            ),
             Err(GatewayError::SlotAlreadyVerified)
         );
     }
+
+    /// A single Ed25519 signer of `payload_merkle_root`, with the root of its
+    /// verifier set and the data of the Ed25519 instruction verifying it.
+    fn ed25519_signer(
+        payload_merkle_root: &[u8; 32],
+    ) -> (SigningVerifierSetInfo, [u8; 32], Vec<u8>) {
+        use ed25519_dalek::Signer as _;
+
+        let signing_key = ed25519_dalek::SigningKey::from_bytes(&rand::thread_rng().gen());
+        let pubkey = signing_key.verifying_key().to_bytes();
+        let leaf = VerifierSetLeaf {
+            signer_pubkey: PublicKey::Ed25519(pubkey),
+            position: 0u8.into(),
+            signer_weight: 1,
+            quorum: 1,
+            set_size: 1u8.into(),
+            domain_separator: [0; 32],
+            nonce: 0,
+        };
+        let tree =
+            rs_merkle::MerkleTree::<NativeHasher>::from_leaves(&[leaf.hash::<NativeHasher>()]);
+
+        let message = offchain_message_hash(payload_merkle_root);
+        let signature = signing_key.sign(&message).to_bytes();
+        let verifier_info = SigningVerifierSetInfo {
+            leaf,
+            signature: Signature::Ed25519(signature),
+            merkle_proof: tree.proof(&[0]).to_bytes(),
+        };
+
+        (
+            verifier_info,
+            tree.root().unwrap(),
+            ed25519_instruction_data(&pubkey, &signature, &message),
+        )
+    }
+
+    #[test]
+    fn test_process_signature_accepts_ed25519_signature_verified_by_the_native_program() {
+        let payload_merkle_root = [7; 32];
+        let (verifier_info, merkle_root, instruction_data) = ed25519_signer(&payload_merkle_root);
+        let mut verification = SignatureVerification {
+            signing_verifier_set_hash: merkle_root,
+            ..Default::default()
+        };
+
+        verification
+            .process_signature(
+                &verifier_info,
+                &merkle_root,
+                &payload_merkle_root,
+                SignatureScheme::Ed25519,
+                Some(&instruction_data),
+            )
+            .unwrap();
+
+        assert!(verification.is_valid());
+    }
+
+    #[test]
+    fn test_process_signature_rejects_ed25519_signature_not_verified_by_the_native_program() {
+        let payload_merkle_root = [7; 32];
+        let (verifier_info, merkle_root, instruction_data) = ed25519_signer(&payload_merkle_root);
+        // The Ed25519 instruction verified the signature of another payload
+        let (_, _, other_instruction_data) = ed25519_signer(&[8; 32]);
+        let mut verification = SignatureVerification {
+            signing_verifier_set_hash: merkle_root,
+            ..Default::default()
+        };
+
+        for ed25519_instruction in [None, Some(other_instruction_data.as_slice())] {
+            assert_eq!(
+                verification.process_signature(
+                    &verifier_info,
+                    &merkle_root,
+                    &payload_merkle_root,
+                    SignatureScheme::Ed25519,
+                    ed25519_instruction,
+                ),
+                Err(GatewayError::InvalidDigitalSignature)
+            );
+        }
+        assert_eq!(
+            verification.process_signature(
+                &verifier_info,
+                &merkle_root,
+                &payload_merkle_root,
+                SignatureScheme::Secp256k1,
+                Some(&instruction_data),
+            ),
+            Err(GatewayError::InvalidSignatureScheme)
+        );
+        assert!(verification.slots_iter().all(|slot| !slot));
+    }
+
+    #[test]
+    fn test_ed25519_instruction_data_matches_the_native_program_layout() {
+        let data = ed25519_instruction_data(&[1; 32], &[2; 64], &[3; 32]);
+
+        // Signature count and padding, the offsets, then the public key, the
+        // signature and the message
+        assert_eq!(data.len(), 2 + 14 + 32 + 64 + 32);
+        assert_eq!(&data[..2], &[1, 0]);
+        assert_eq!(&data[2..4], &48_u16.to_le_bytes());
+        assert_eq!(&data[6..8], &16_u16.to_le_bytes());
+        assert_eq!(&data[10..12], &112_u16.to_le_bytes());
+        assert_eq!(&data[16..48], &[1; 32]);
+        assert!(ed25519_instruction_verifies(
+            &data, &[1; 32], &[2; 64], &[3; 32]
+        ));
+        assert!(!ed25519_instruction_verifies(
+            &data, &[1; 32], &[2; 64], &[4; 32]
+        ));
+    }
 }
@@ -21,7 +21,14 @@ pub struct SignatureVerificationSessionData {
     /// Seed bump for this account's PDA
     pub bump: u8,
     /// Padding for memory alignment.
-    _pad: [u8; 15],
+    _pad: [u8; 1],
+    /// Number of messages of the payload approved so far.
+    ///
+    /// Once it reaches the size of the message set, the whole batch is
+    /// approved.
+    pub approved_message_count: u16,
+    /// Padding for memory alignment.
+    _pad_approvals: [u8; 12],
 }
 
 impl BytemuckedPda for SignatureVerificationSessionData {}
@@ -88,7 +95,9 @@ mod tests {
                     signing_verifier_set_hash: [2; 32],
                 },
                 bump: 255,
-                _pad: [0; 15],
+                _pad: [0; 1],
+                approved_message_count: 3,
+                _pad_approvals: [0; 12],
             };
         }
 
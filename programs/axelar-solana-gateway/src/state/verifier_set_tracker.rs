@@ -2,14 +2,53 @@
 
 use anchor_discriminators_macros::account;
 use axelar_message_primitives::U256;
+use axelar_solana_encoding::types::pubkey::PublicKey;
+use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Pod, Zeroable};
 use program_utils::pda::BytemuckedPda;
 
+use crate::error::GatewayError;
+
 /// Ever-incrementing counter for keeping track of the sequence of signer sets
 pub type Epoch = U256;
 /// Verifier set hash
 pub type VerifierSetHash = [u8; 32];
 
+/// The kind of keys the signers of a verifier set sign with.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[borsh(use_discriminant = true)]
+pub enum SignatureScheme {
+    /// ECDSA over secp256k1, recovered with the `secp256k1_recover` syscall.
+    Secp256k1 = 0,
+    /// Ed25519, verified by the Ed25519 native program in the instruction
+    /// preceding each `VerifySignature`.
+    Ed25519 = 1,
+}
+
+impl SignatureScheme {
+    /// The scheme the given signer key belongs to.
+    #[must_use]
+    pub const fn of(public_key: &PublicKey) -> Self {
+        match public_key {
+            PublicKey::Secp256k1(_) => Self::Secp256k1,
+            PublicKey::Ed25519(_) => Self::Ed25519,
+        }
+    }
+}
+
+impl TryFrom<u8> for SignatureScheme {
+    type Error = GatewayError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Secp256k1),
+            1 => Ok(Self::Ed25519),
+            _ => Err(GatewayError::InvalidSignatureScheme),
+        }
+    }
+}
+
 /// PDA that keeps track of core information about the verifier set.
 /// We keep the track of the hash + epoch (sequential order of which verifier
 /// set this is)
@@ -20,8 +59,12 @@ pub type VerifierSetHash = [u8; 32];
 pub struct VerifierSetTracker {
     /// The canonical bump for this account.
     pub bump: u8,
-    /// Padding for the bump
-    _padding: [u8; 7],
+    /// The [`SignatureScheme`] of the verifier set. Trackers created before
+    /// Ed25519 verifier sets were supported read [`SignatureScheme::Secp256k1`]
+    /// from their zeroed padding.
+    pub signature_scheme: u8,
+    /// Padding for the bump and the signature scheme
+    _padding: [u8; 6],
     /// The epoch associated with this verifier set
     pub epoch: Epoch,
     /// The verifier set hash
@@ -29,16 +72,35 @@ pub struct VerifierSetTracker {
 }
 
 impl VerifierSetTracker {
-    /// Create a new [`VerifierSetTracker`].
+    /// Create a new [`VerifierSetTracker`] of a [`SignatureScheme::Secp256k1`]
+    /// verifier set.
     #[must_use]
     pub const fn new(bump: u8, epoch: Epoch, verifier_set_hash: VerifierSetHash) -> Self {
         Self {
             bump,
-            _padding: [0; 7],
+            signature_scheme: SignatureScheme::Secp256k1 as u8,
+            _padding: [0; 6],
             epoch,
             verifier_set_hash,
         }
     }
+
+    /// Sets the signature scheme of the verifier set.
+    #[must_use]
+    pub const fn with_signature_scheme(mut self, signature_scheme: SignatureScheme) -> Self {
+        self.signature_scheme = signature_scheme as u8;
+        self
+    }
+
+    /// The signature scheme of the verifier set.
+    ///
+    /// # Errors
+    ///
+    /// [`GatewayError::InvalidSignatureScheme`] if the stored scheme is
+    /// unknown.
+    pub fn signature_scheme(&self) -> Result<SignatureScheme, GatewayError> {
+        SignatureScheme::try_from(self.signature_scheme)
+    }
 }
 
 impl BytemuckedPda for VerifierSetTracker {}
@@ -47,6 +109,7 @@ impl core::fmt::Debug for VerifierSetTracker {
     fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         fmt.debug_struct("VerifierSetTracker")
             .field("bump", &self.bump)
+            .field("signature_scheme", &self.signature_scheme)
             .field("epoch", &self.epoch)
             .field("verifier_set_hash", &hex::encode(self.verifier_set_hash))
             .finish_non_exhaustive()
@@ -0,0 +1,205 @@
+//! Zero-copy views of the gateway accounts.
+//!
+//! The processor never deserializes its accounts into owned values: the
+//! structs are read and written in place, as references into the account
+//! data, which keeps them off the stack. [`AccountView`] borrows the data of
+//! an account and casts it to the account struct in one step, so the
+//! reference can't outlive the borrow of the data, and every mismatch of the
+//! discriminator or of the data length surfaces as
+//! [`GatewayError::BytemuckDataLenInvalid`].
+
+use core::cell::{Ref, RefMut};
+
+use program_utils::pda::BytemuckedPda;
+use solana_program::account_info::AccountInfo;
+use solana_program::program_error::ProgramError;
+
+use super::incoming_message::IncomingMessage;
+use super::signature_verification_pda::SignatureVerificationSessionData;
+use super::GatewayConfig;
+use crate::error::GatewayError;
+
+/// An account the processor accesses through a zero-copy view of its data.
+pub trait AccountView: BytemuckedPda {
+    /// Casts the account data to `Self`.
+    #[must_use]
+    fn view_data(data: &[u8]) -> Option<&Self> {
+        Self::read(data)
+    }
+
+    /// Mutable counterpart of [`AccountView::view_data`].
+    #[must_use]
+    fn view_data_mut(data: &mut [u8]) -> Option<&mut Self> {
+        Self::read_mut(data)
+    }
+
+    /// Borrows the data of `account` as `Self`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ProgramError::AccountBorrowFailed`] if the data is mutably
+    ///   borrowed.
+    /// - [`GatewayError::BytemuckDataLenInvalid`] if the data doesn't hold a
+    ///   `Self`.
+    fn view<'a>(account: &'a AccountInfo<'_>) -> Result<Ref<'a, Self>, ProgramError> {
+        let data = account.try_borrow_data()?;
+        Ref::filter_map(data, |data| Self::view_data(data))
+            .map_err(|_data| GatewayError::BytemuckDataLenInvalid.into())
+    }
+
+    /// Mutably borrows the data of `account` as `Self`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ProgramError::AccountBorrowFailed`] if the data is already
+    ///   borrowed.
+    /// - [`GatewayError::BytemuckDataLenInvalid`] if the data doesn't hold a
+    ///   `Self`.
+    fn view_mut<'a>(account: &'a AccountInfo<'_>) -> Result<RefMut<'a, Self>, ProgramError> {
+        let data = account.try_borrow_mut_data()?;
+        RefMut::filter_map(data, |data| Self::view_data_mut(data))
+            .map_err(|_data| GatewayError::BytemuckDataLenInvalid.into())
+    }
+}
+
+/// The config is viewed through [`GatewayConfig::read_versioned`], ignoring
+/// the space reserved for future versions.
+impl AccountView for GatewayConfig {
+    fn view_data(data: &[u8]) -> Option<&Self> {
+        Self::read_versioned(data)
+    }
+
+    fn view_data_mut(data: &mut [u8]) -> Option<&mut Self> {
+        Self::read_versioned_mut(data)
+    }
+}
+
+impl AccountView for SignatureVerificationSessionData {}
+
+/// Incoming messages are viewed through [`IncomingMessage::read_versioned`],
+/// ignoring their extension.
+impl AccountView for IncomingMessage {
+    fn view_data(data: &[u8]) -> Option<&Self> {
+        Self::read_versioned(data)
+    }
+
+    fn view_data_mut(data: &mut [u8]) -> Option<&mut Self> {
+        Self::read_versioned_mut(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axelar_message_primitives::U256;
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+    use crate::state::incoming_message::{IncomingMessageExtension, MessageStatus};
+
+    /// Account data holding `value`, aligned like the runtime aligns it and
+    /// followed by `trailing` zero bytes.
+    fn account_words<T: BytemuckedPda>(value: &T, trailing: usize) -> (Vec<u64>, usize) {
+        let mut bytes = T::DISCRIMINATOR.to_vec();
+        bytes.extend_from_slice(bytemuck::bytes_of(value));
+        bytes.resize(bytes.len() + trailing, 0);
+
+        let mut words = vec![0_u64; bytes.len().div_ceil(8)];
+        bytemuck::cast_slice_mut::<u64, u8>(&mut words)
+            .get_mut(..bytes.len())
+            .unwrap()
+            .copy_from_slice(&bytes);
+        (words, bytes.len())
+    }
+
+    fn with_account<R>(words: &mut [u64], len: usize, f: impl FnOnce(&AccountInfo<'_>) -> R) -> R {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let data = bytemuck::cast_slice_mut::<u64, u8>(words)
+            .get_mut(..len)
+            .unwrap();
+        let account =
+            AccountInfo::new(&key, false, true, &mut lamports, data, &crate::ID, false, 0);
+        f(&account)
+    }
+
+    fn config() -> GatewayConfig {
+        GatewayConfig::new(
+            U256::from_u64(7),
+            U256::from_u64(3),
+            3600,
+            1_700_000_000,
+            Pubkey::new_unique(),
+            [9; 32],
+            254,
+        )
+    }
+
+    #[test]
+    fn test_view_reads_the_same_config_as_read_versioned() {
+        let config = config();
+        // A resized config account
+        let (mut words, len) = account_words(&config, 64);
+
+        with_account(&mut words, len, |account| {
+            let data = account.try_borrow_data().unwrap();
+            let read = *GatewayConfig::read_versioned(&data).unwrap();
+            drop(data);
+
+            let view = GatewayConfig::view(account).unwrap();
+            assert_eq!(*view, read);
+            assert_eq!(*view, config);
+        });
+    }
+
+    #[test]
+    fn test_view_mut_writes_in_place() {
+        let session = SignatureVerificationSessionData::default();
+        let (mut words, len) = account_words(&session, 0);
+
+        with_account(&mut words, len, |account| {
+            let mut view = SignatureVerificationSessionData::view_mut(account).unwrap();
+            view.bump = 42;
+            view.approved_message_count = 3;
+            drop(view);
+
+            let data = account.try_borrow_data().unwrap();
+            let read = SignatureVerificationSessionData::read(&data).unwrap();
+            assert_eq!(read.bump, 42);
+            assert_eq!(read.approved_message_count, 3);
+        });
+    }
+
+    #[test]
+    fn test_view_holds_the_borrow_of_the_data() {
+        let message = IncomingMessage::new(255, 254, MessageStatus::approved(), [1; 32], [2; 32]);
+        let (mut words, len) = account_words(&message, size_of::<IncomingMessageExtension>());
+
+        with_account(&mut words, len, |account| {
+            let view = IncomingMessage::view(account).unwrap();
+            assert_eq!(*view, message);
+            assert_eq!(
+                IncomingMessage::view_mut(account).err(),
+                Some(ProgramError::AccountBorrowFailed)
+            );
+            drop(view);
+
+            assert!(IncomingMessage::view_mut(account).is_ok());
+        });
+    }
+
+    #[test]
+    fn test_view_rejects_other_accounts() {
+        let (mut words, len) = account_words(&config(), 0);
+
+        with_account(&mut words, len, |account| {
+            assert_eq!(
+                IncomingMessage::view(account).err(),
+                Some(GatewayError::BytemuckDataLenInvalid.into())
+            );
+            assert_eq!(
+                SignatureVerificationSessionData::view_mut(account).err(),
+                Some(GatewayError::BytemuckDataLenInvalid.into())
+            );
+        });
+    }
+}
@@ -8,7 +8,9 @@ use axelar_solana_encoding::types::payload::Payload;
 use axelar_solana_encoding::types::verifier_set::verifier_set_hash;
 use axelar_solana_encoding::LeafHash;
 use axelar_solana_gateway::error::GatewayError;
-use axelar_solana_gateway::events::MessageApprovedEvent;
+use axelar_solana_gateway::events::{
+    BatchApprovedEvent, MessageApprovalDetailsEvent, MessageApprovedEvent,
+};
 use axelar_solana_gateway::state::incoming_message::{command_id, IncomingMessage, MessageStatus};
 use axelar_solana_gateway::{get_incoming_message_pda, get_validate_message_signing_pda};
 use axelar_solana_gateway_test_fixtures::gateway::{
@@ -20,6 +22,7 @@ use itertools::Itertools;
 use pretty_assertions::assert_eq;
 use rand::Rng;
 use solana_program_test::tokio;
+use solana_sdk::clock::Clock;
 use solana_sdk::pubkey::Pubkey;
 
 #[tokio::test]
@@ -52,6 +55,7 @@ async fn successfully_approves_messages() {
             get_incoming_message_pda(&command_id);
 
         let message = message_info.leaf.clone().message;
+        let clock = metadata.get_sysvar::<Clock>().await;
         // First simulate to check events
         let simulation_result = metadata
             .simulate_approve_message(
@@ -84,6 +88,14 @@ async fn successfully_approves_messages() {
         };
 
         assert_event_cpi(&expected_event, &inner_ixs);
+        assert_event_cpi(
+            &MessageApprovalDetailsEvent {
+                command_id,
+                approved_at_slot: clock.slot,
+                approved_at: clock.unix_timestamp,
+            },
+            &inner_ixs,
+        );
 
         // Execute the transaction
         let _tx = metadata
@@ -114,6 +126,108 @@ async fn successfully_approves_messages() {
     assert_eq!(counter, message_count);
 }
 
+/// The approval time comes from the cluster clock, so destination programs
+/// can judge the freshness of a message on their own.
+#[tokio::test]
+async fn records_approval_slot_and_timestamp() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+    metadata.warp_to_slot(1_000);
+    metadata.forward_time(3_600).await;
+    let clock = metadata.get_sysvar::<Clock>().await;
+
+    // Action
+    let messages = make_messages(1);
+    metadata
+        .sign_session_and_approve_messages(&metadata.signers.clone(), &messages)
+        .await
+        .unwrap();
+
+    // Assert
+    let cc_id = &messages[0].cc_id;
+    let (incoming_message_pda, _) = get_incoming_message_pda(&command_id(&cc_id.chain, &cc_id.id));
+    let extension = metadata
+        .incoming_message_extension(incoming_message_pda)
+        .await
+        .unwrap();
+    assert_eq!(extension.approved_at_slot, clock.slot);
+    assert_eq!(extension.approved_at, clock.unix_timestamp);
+    assert!(extension.approved_at_slot >= 1_000);
+    assert!(!extension.has_execution_receipt());
+
+    let now = clock.unix_timestamp + 600;
+    assert_eq!(extension.approval_age(now), Some(600));
+    assert_eq!(extension.approval_age(clock.unix_timestamp - 1), None);
+}
+
+#[tokio::test]
+async fn emits_batch_approved_event_for_last_message_of_batch() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+    let message_count = 3;
+    let messages = make_messages(message_count);
+    let payload = Payload::Messages(Messages(messages));
+    let execute_data = metadata.construct_execute_data(&metadata.signers.clone(), payload);
+    let verification_session_pda = metadata
+        .init_payload_session_and_verify(&execute_data)
+        .await
+        .unwrap();
+    let MerkleisedPayload::NewMessages { messages } = execute_data.payload_items else {
+        unreachable!()
+    };
+    let expected_event = BatchApprovedEvent {
+        payload_merkle_root: execute_data.payload_merkle_root,
+        message_count: message_count.try_into().unwrap(),
+    };
+
+    for (approved, message_info) in messages.into_iter().enumerate() {
+        let simulation_result = metadata
+            .simulate_approve_message(
+                execute_data.payload_merkle_root,
+                message_info.clone(),
+                verification_session_pda,
+            )
+            .await
+            .unwrap();
+        let inner_ixs = simulation_result
+            .simulation_details
+            .unwrap()
+            .inner_instructions
+            .unwrap()
+            .first()
+            .cloned()
+            .unwrap();
+
+        // Only the last message of the batch completes it
+        assert_eq!(
+            contains_event_cpi(&expected_event, &inner_ixs),
+            approved + 1 == message_count
+        );
+
+        metadata
+            .approve_message(
+                execute_data.payload_merkle_root,
+                message_info,
+                verification_session_pda,
+            )
+            .await
+            .unwrap();
+
+        let session = metadata
+            .signature_verification_session(verification_session_pda)
+            .await;
+        assert_eq!(usize::from(session.approved_message_count), approved + 1);
+    }
+}
+
 #[tokio::test]
 #[allow(clippy::too_many_lines)]
 async fn fail_individual_approval_if_done_many_times() {
@@ -472,6 +586,102 @@ async fn fails_to_approve_message_with_invalid_domain_separator() {
     assert_eq!(gateway_error, GatewayError::InvalidDomainSeparator);
 }
 
+#[tokio::test]
+async fn successfully_approves_single_message_without_proof() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+    let payload = Payload::Messages(Messages(make_messages(1)));
+    let execute_data = metadata.construct_execute_data(&metadata.signers.clone(), payload);
+    let verification_session_pda = metadata
+        .init_payload_session_and_verify(&execute_data)
+        .await
+        .unwrap();
+    let MerkleisedPayload::NewMessages { messages } = execute_data.payload_items else {
+        unreachable!()
+    };
+    let leaf = messages.into_iter().next().unwrap().leaf;
+    assert_eq!(
+        leaf.hash::<SolanaSyscallHasher>(),
+        execute_data.payload_merkle_root
+    );
+
+    // Action
+    metadata
+        .approve_single_message(leaf.clone(), verification_session_pda)
+        .await
+        .unwrap();
+
+    // Assert
+    let command_id = command_id(&leaf.message.cc_id.chain, &leaf.message.cc_id.id);
+    let (incoming_message_pda, _) = get_incoming_message_pda(&command_id);
+    let incoming_message = metadata.incoming_message(incoming_message_pda).await;
+    assert_eq!(incoming_message.status, MessageStatus::approved());
+    assert_eq!(
+        incoming_message.message_hash,
+        leaf.message.hash::<SolanaSyscallHasher>()
+    );
+    let session = metadata
+        .signature_verification_session(verification_session_pda)
+        .await;
+    assert_eq!(session.approved_message_count, 1);
+
+    // The message can't be approved a second time
+    let tx_result = metadata
+        .approve_single_message(leaf, verification_session_pda)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        tx_result.get_gateway_error().unwrap(),
+        GatewayError::MessageAlreadyInitialised
+    );
+}
+
+#[tokio::test]
+async fn fails_to_approve_leaf_of_multi_message_payload_without_proof() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+    let payload = Payload::Messages(Messages(make_messages(2)));
+    let execute_data = metadata.construct_execute_data(&metadata.signers.clone(), payload);
+    let verification_session_pda = metadata
+        .init_payload_session_and_verify(&execute_data)
+        .await
+        .unwrap();
+    let MerkleisedPayload::NewMessages { messages } = execute_data.payload_items else {
+        unreachable!()
+    };
+
+    for message_info in messages {
+        // Action
+        let tx_result = metadata
+            .approve_single_message(message_info.leaf.clone(), verification_session_pda)
+            .await
+            .unwrap_err();
+
+        // Assert
+        assert_eq!(
+            tx_result.get_gateway_error().unwrap(),
+            GatewayError::LeafNodeNotPartOfMerkleRoot
+        );
+
+        // Claiming a single-leaf payload doesn't match the signed session either
+        let mut leaf = message_info.leaf;
+        leaf.set_size = 1;
+        leaf.position = 0;
+        metadata
+            .approve_single_message(leaf, verification_session_pda)
+            .await
+            .unwrap_err();
+    }
+}
+
 /// Test that old (but still active) verifier sets can fully process a message approval cycle
 #[tokio::test]
 #[rstest::rstest]
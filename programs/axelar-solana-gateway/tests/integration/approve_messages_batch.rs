@@ -0,0 +1,186 @@
+use axelar_solana_encoding::types::execute_data::{MerkleisedMessage, MerkleisedPayload};
+use axelar_solana_encoding::types::messages::Messages;
+use axelar_solana_encoding::types::payload::Payload;
+use axelar_solana_gateway::error::GatewayError;
+use axelar_solana_gateway::events::BatchApprovedEvent;
+use axelar_solana_gateway::get_incoming_message_pda;
+use axelar_solana_gateway::instructions::approve_messages_batch;
+use axelar_solana_gateway::instructions::relay::APPROVE_MESSAGE_COMPUTE_UNITS;
+use axelar_solana_gateway::state::incoming_message::{command_id, MessageStatus};
+use axelar_solana_gateway_test_fixtures::gateway::{make_messages, GetGatewayError};
+use axelar_solana_gateway_test_fixtures::{
+    SolanaAxelarIntegration, SolanaAxelarIntegrationMetadata,
+};
+use event_cpi_test_utils::assert_event_cpi;
+use solana_program_test::tokio;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+struct Batch {
+    metadata: SolanaAxelarIntegrationMetadata,
+    payload_merkle_root: [u8; 32],
+    verification_session_pda: Pubkey,
+    messages: Vec<MerkleisedMessage>,
+}
+
+async fn setup(message_count: usize) -> Batch {
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+    let payload = Payload::Messages(Messages(make_messages(message_count)));
+    let execute_data = metadata.construct_execute_data(&metadata.signers.clone(), payload);
+    let verification_session_pda = metadata
+        .init_payload_session_and_verify(&execute_data)
+        .await
+        .unwrap();
+    let MerkleisedPayload::NewMessages { messages } = execute_data.payload_items else {
+        unreachable!()
+    };
+
+    Batch {
+        metadata,
+        payload_merkle_root: execute_data.payload_merkle_root,
+        verification_session_pda,
+        messages,
+    }
+}
+
+fn incoming_message_pda(message: &MerkleisedMessage) -> Pubkey {
+    let cc_id = &message.leaf.message.cc_id;
+    get_incoming_message_pda(&command_id(&cc_id.chain, &cc_id.id)).0
+}
+
+impl Batch {
+    /// Builds the batch approval of `messages`, preceded by the compute
+    /// budget it needs.
+    fn approve_ixs(&self, messages: &[MerkleisedMessage]) -> [Instruction; 2] {
+        let compute_units = APPROVE_MESSAGE_COMPUTE_UNITS * u32::try_from(messages.len()).unwrap();
+        let approve_ix = approve_messages_batch(
+            messages
+                .iter()
+                .map(|message| (message.clone(), incoming_message_pda(message)))
+                .collect(),
+            self.payload_merkle_root,
+            self.metadata.gateway_root_pda,
+            self.metadata.payer.pubkey(),
+            self.verification_session_pda,
+        )
+        .unwrap();
+
+        [
+            ComputeBudgetInstruction::set_compute_unit_limit(compute_units.max(1)),
+            approve_ix,
+        ]
+    }
+}
+
+#[tokio::test]
+async fn successfully_approves_a_batch_of_messages() {
+    // Setup
+    let mut batch = setup(4).await;
+    let ixs = batch.approve_ixs(&batch.messages);
+
+    // Action
+    let simulation_result = batch.metadata.simulate_tx(&ixs).await.unwrap();
+    batch.metadata.send_tx(&ixs).await.unwrap();
+
+    // Assert
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .get(1)
+        .cloned()
+        .unwrap();
+    assert_event_cpi(
+        &BatchApprovedEvent {
+            payload_merkle_root: batch.payload_merkle_root,
+            message_count: 4,
+        },
+        &inner_ixs,
+    );
+    for message in &batch.messages {
+        let incoming_message = batch
+            .metadata
+            .incoming_message(incoming_message_pda(message))
+            .await;
+        assert_eq!(incoming_message.status, MessageStatus::approved());
+    }
+}
+
+#[tokio::test]
+async fn batches_of_the_same_payload_can_be_chunked() {
+    // Setup
+    let mut batch = setup(5).await;
+    let (first, second) = batch.messages.split_at(2);
+    let first_ixs = batch.approve_ixs(first);
+    let second_ixs = batch.approve_ixs(second);
+
+    // Action
+    batch.metadata.send_tx(&second_ixs).await.unwrap();
+    batch.metadata.send_tx(&first_ixs).await.unwrap();
+
+    // Assert
+    for message in &batch.messages {
+        let incoming_message = batch
+            .metadata
+            .incoming_message(incoming_message_pda(message))
+            .await;
+        assert_eq!(incoming_message.status, MessageStatus::approved());
+    }
+}
+
+#[tokio::test]
+async fn fails_atomically_when_a_message_is_already_approved() {
+    // Setup
+    let mut batch = setup(3).await;
+    let already_approved = batch.messages[1].clone();
+    batch
+        .metadata
+        .approve_message(
+            batch.payload_merkle_root,
+            already_approved,
+            batch.verification_session_pda,
+        )
+        .await
+        .unwrap();
+    let ixs = batch.approve_ixs(&batch.messages);
+
+    // Action
+    let tx = batch.metadata.send_tx(&ixs).await.unwrap_err();
+
+    // Assert
+    assert_eq!(
+        tx.get_gateway_error().unwrap(),
+        GatewayError::MessageAlreadyInitialised
+    );
+    let first_pda = incoming_message_pda(&batch.messages[0]);
+    assert!(batch
+        .metadata
+        .try_get_account_no_checks(&first_pda)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn fails_to_approve_an_empty_batch() {
+    // Setup
+    let mut batch = setup(1).await;
+    let ixs = batch.approve_ixs(&[]);
+
+    // Action
+    let tx = batch.metadata.send_tx(&ixs).await.unwrap_err();
+
+    // Assert
+    assert_eq!(
+        tx.result.unwrap_err(),
+        TransactionError::InstructionError(1, InstructionError::InvalidInstructionData)
+    );
+}
@@ -0,0 +1,123 @@
+use axelar_solana_encoding::types::execute_data::MerkleisedPayload;
+use axelar_solana_encoding::types::messages::Messages;
+use axelar_solana_encoding::types::payload::Payload;
+use axelar_solana_gateway::events::{MessageApprovalTraceEvent, SignatureVerifiedTraceEvent};
+use axelar_solana_gateway::state::incoming_message::command_id;
+use axelar_solana_gateway_test_fixtures::gateway::random_message;
+use axelar_solana_gateway_test_fixtures::SolanaAxelarIntegration;
+use event_cpi_test_utils::assert_event_cpi;
+use pretty_assertions::assert_eq;
+use solana_program_test::tokio;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+
+#[tokio::test]
+async fn signature_verification_and_approval_are_traced() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+    let message = random_message();
+    let payload = Payload::Messages(Messages(vec![message.clone()]));
+    let execute_data = metadata.construct_execute_data(&metadata.signers.clone(), payload);
+    metadata
+        .initialize_payload_verification_session(&execute_data)
+        .await
+        .unwrap();
+    let verifier_set_tracker_pda = metadata.signers.verifier_set_tracker().0;
+    let (verification_session_pda, _) = axelar_solana_gateway::get_signature_verification_pda(
+        &execute_data.payload_merkle_root,
+        &execute_data.signing_verifier_set_merkle_root,
+    );
+
+    // Action: verify signatures until the quorum is reached
+    let mut accumulated_weight = 0_u128;
+    for leaf_info in &execute_data.signing_verifier_set_leaves {
+        if accumulated_weight >= leaf_info.leaf.quorum {
+            break;
+        }
+        accumulated_weight += leaf_info.leaf.signer_weight;
+
+        let ixs = [
+            ComputeBudgetInstruction::set_compute_unit_limit(300_000),
+            axelar_solana_gateway::instructions::verify_signature(
+                metadata.gateway_root_pda,
+                verifier_set_tracker_pda,
+                verification_session_pda,
+                execute_data.payload_merkle_root,
+                leaf_info.clone(),
+            )
+            .unwrap(),
+        ];
+        let simulation_result = metadata.simulate_tx(&ixs).await.unwrap();
+        let inner_ixs = simulation_result
+            .simulation_details
+            .unwrap()
+            .inner_instructions
+            .unwrap()[1]
+            .clone();
+        metadata.send_tx(&ixs).await.unwrap();
+
+        // Assert
+        assert_event_cpi(
+            &SignatureVerifiedTraceEvent {
+                verification_session: verification_session_pda,
+                payload_merkle_root: execute_data.payload_merkle_root,
+                signing_verifier_set_hash: execute_data.signing_verifier_set_merkle_root,
+                signer_position: leaf_info.leaf.position,
+                signer_weight: leaf_info.leaf.signer_weight,
+                accumulated_weight,
+                quorum: leaf_info.leaf.quorum,
+            },
+            &inner_ixs,
+        );
+    }
+
+    // Action: approve the message
+    let MerkleisedPayload::NewMessages { messages } = execute_data.payload_items else {
+        unreachable!("we constructed a message batch");
+    };
+    let merkleised_message = messages[0].clone();
+    let simulation_result = metadata
+        .simulate_approve_message(
+            execute_data.payload_merkle_root,
+            merkleised_message.clone(),
+            verification_session_pda,
+        )
+        .await
+        .unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+
+    // Assert
+    let session = metadata
+        .signature_verification_session(verification_session_pda)
+        .await;
+    assert_event_cpi(
+        &MessageApprovalTraceEvent {
+            command_id: command_id(&message.cc_id.chain, &message.cc_id.id),
+            verification_session: verification_session_pda,
+            payload_merkle_root: execute_data.payload_merkle_root,
+            signing_verifier_set_hash: execute_data.signing_verifier_set_merkle_root,
+            leaf_index: merkleised_message.leaf.position,
+            set_size: 1,
+            signature_slots: session.signature_verification.signature_slots,
+        },
+        &inner_ixs,
+    );
+    assert_eq!(
+        session
+            .signature_verification
+            .slots_iter()
+            .filter(|slot| *slot)
+            .count(),
+        2
+    );
+}
@@ -0,0 +1,170 @@
+use axelar_solana_encoding::types::messages::Message;
+use axelar_solana_gateway::error::GatewayError;
+use axelar_solana_gateway::events::MessageCancelledEvent;
+use axelar_solana_gateway::get_incoming_message_pda;
+use axelar_solana_gateway::instructions::cancel_message;
+use axelar_solana_gateway::state::incoming_message::{
+    cancel_message_payload_hash, command_id, MessageStatus,
+};
+use axelar_solana_gateway_test_fixtures::gateway::{random_message, GetGatewayError};
+use axelar_solana_gateway_test_fixtures::{
+    SolanaAxelarIntegration, SolanaAxelarIntegrationMetadata,
+};
+use event_cpi_test_utils::assert_event_cpi;
+use pretty_assertions::assert_eq;
+use solana_program_test::tokio;
+
+/// Approves `message` along with a cancellation of it sent by its source, and
+/// returns the runner and the cancellation.
+async fn approved_message_and_cancellation(
+    message: &Message,
+) -> (SolanaAxelarIntegrationMetadata, Message) {
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+
+    let mut cancellation = random_message();
+    cancellation.cc_id.chain.clone_from(&message.cc_id.chain);
+    cancellation
+        .source_address
+        .clone_from(&message.source_address);
+    cancellation.destination_address = axelar_solana_gateway::ID.to_string();
+    cancellation.payload_hash =
+        cancel_message_payload_hash(&command_id(&message.cc_id.chain, &message.cc_id.id));
+
+    metadata
+        .sign_session_and_approve_messages(
+            &metadata.signers.clone(),
+            &[message.clone(), cancellation.clone()],
+        )
+        .await
+        .unwrap();
+
+    (metadata, cancellation)
+}
+
+async fn status(
+    metadata: &mut SolanaAxelarIntegrationMetadata,
+    message: &Message,
+) -> MessageStatus {
+    let (incoming_message_pda, _) =
+        get_incoming_message_pda(&command_id(&message.cc_id.chain, &message.cc_id.id));
+    metadata.incoming_message(incoming_message_pda).await.status
+}
+
+#[tokio::test]
+async fn source_cancels_approved_message() {
+    // Setup
+    let message = random_message();
+    let (mut metadata, cancellation) = approved_message_and_cancellation(&message).await;
+
+    // Action
+    let ix = cancel_message(cancellation.clone(), message.clone()).unwrap();
+    let simulation_result = metadata.simulate_tx(&[ix.clone()]).await.unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    metadata.send_tx(&[ix]).await.unwrap();
+
+    // Assert
+    assert_event_cpi(
+        &MessageCancelledEvent {
+            command_id: command_id(&message.cc_id.chain, &message.cc_id.id),
+            cancellation_command_id: command_id(&cancellation.cc_id.chain, &cancellation.cc_id.id),
+            source_chain: message.cc_id.chain.clone(),
+            cc_id: message.cc_id.id.clone(),
+            source_address: message.source_address.clone(),
+        },
+        &inner_ixs,
+    );
+    assert_eq!(
+        status(&mut metadata, &message).await,
+        MessageStatus::cancelled()
+    );
+    assert_eq!(
+        status(&mut metadata, &cancellation).await,
+        MessageStatus::executed()
+    );
+
+    // The cancelled message can be neither executed nor cancelled again
+    assert!(!status(&mut metadata, &message).await.is_executed());
+    let err = metadata
+        .send_tx(&[cancel_message(cancellation, message).unwrap()])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.get_gateway_error().unwrap(),
+        GatewayError::MessageNotApproved
+    );
+}
+
+#[tokio::test]
+async fn fail_if_cancellation_is_not_sent_by_the_source() {
+    // Setup
+    let message = random_message();
+    let (mut metadata, _) = approved_message_and_cancellation(&message).await;
+    let mut cancellation = random_message();
+    cancellation.cc_id.chain.clone_from(&message.cc_id.chain);
+    cancellation.destination_address = axelar_solana_gateway::ID.to_string();
+    cancellation.payload_hash =
+        cancel_message_payload_hash(&command_id(&message.cc_id.chain, &message.cc_id.id));
+    metadata
+        .sign_session_and_approve_messages(&metadata.signers.clone(), &[cancellation.clone()])
+        .await
+        .unwrap();
+
+    // Action
+    let err = metadata
+        .send_tx(&[cancel_message(cancellation, message.clone()).unwrap()])
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert_eq!(
+        err.get_gateway_error().unwrap(),
+        GatewayError::UnauthorizedMessageCancellation
+    );
+    assert_eq!(
+        status(&mut metadata, &message).await,
+        MessageStatus::approved()
+    );
+}
+
+#[tokio::test]
+async fn fail_if_cancellation_names_another_message() {
+    // Setup
+    let message = random_message();
+    let (mut metadata, cancellation) = approved_message_and_cancellation(&message).await;
+    let mut other_message = random_message();
+    other_message.cc_id.chain.clone_from(&message.cc_id.chain);
+    other_message
+        .source_address
+        .clone_from(&message.source_address);
+    metadata
+        .sign_session_and_approve_messages(&metadata.signers.clone(), &[other_message.clone()])
+        .await
+        .unwrap();
+
+    // Action
+    let err = metadata
+        .send_tx(&[cancel_message(cancellation, other_message.clone()).unwrap()])
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert_eq!(
+        err.get_gateway_error().unwrap(),
+        GatewayError::InvalidCancellationMessage
+    );
+    assert_eq!(
+        status(&mut metadata, &other_message).await,
+        MessageStatus::approved()
+    );
+}
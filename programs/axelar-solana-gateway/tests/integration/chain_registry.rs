@@ -0,0 +1,206 @@
+use axelar_solana_gateway::error::GatewayError;
+use axelar_solana_gateway::events::ChainRegistrationSetEvent;
+use axelar_solana_gateway::instructions::{
+    call_contract, call_contract_to_registered_chain, set_chain_registered,
+};
+use axelar_solana_gateway::state::ChainRegistry;
+use axelar_solana_gateway::{get_chain_registry_pda, BytemuckedPda};
+use axelar_solana_gateway_test_fixtures::gateway::GetGatewayError;
+use axelar_solana_gateway_test_fixtures::{
+    SolanaAxelarIntegration, SolanaAxelarIntegrationMetadata,
+};
+use event_cpi_test_utils::assert_event_cpi;
+use pretty_assertions::assert_eq;
+use solana_program_test::tokio;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+async fn register_chain(metadata: &mut SolanaAxelarIntegrationMetadata, chain_name: &str) {
+    let operator = metadata.operator.insecure_clone();
+    let ix = set_chain_registered(
+        metadata.payer.pubkey(),
+        operator.pubkey(),
+        chain_name.to_owned(),
+        true,
+    )
+    .unwrap();
+    metadata
+        .send_tx_with_custom_signers(&[ix], &[&operator, &metadata.payer.insecure_clone()])
+        .await
+        .unwrap();
+}
+
+fn call_contract_ix(
+    metadata: &SolanaAxelarIntegrationMetadata,
+    destination_chain: &str,
+) -> Instruction {
+    call_contract_to_registered_chain(
+        axelar_solana_gateway::id(),
+        metadata.gateway_root_pda,
+        metadata.payer.pubkey(),
+        None,
+        destination_chain.to_owned(),
+        "0xdestination".to_owned(),
+        b"payload".to_vec(),
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn successfully_register_chain() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![11, 42, 33])
+        .build()
+        .setup()
+        .await;
+    let operator = metadata.operator.insecure_clone();
+
+    // Action
+    let ix = set_chain_registered(
+        metadata.payer.pubkey(),
+        operator.pubkey(),
+        "ethereum".to_owned(),
+        true,
+    )
+    .unwrap();
+    let simulation_result = metadata
+        .simulate_tx_with_custom_signers(
+            &[ix.clone()],
+            &[&operator, &metadata.payer.insecure_clone()],
+        )
+        .await
+        .unwrap();
+    metadata
+        .send_tx_with_custom_signers(&[ix], &[&operator, &metadata.payer.insecure_clone()])
+        .await
+        .unwrap();
+
+    // Assert
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    assert_event_cpi(
+        &ChainRegistrationSetEvent {
+            chain_name: "ethereum".to_owned(),
+            registered: true,
+        },
+        &inner_ixs,
+    );
+
+    let account = metadata
+        .try_get_account_no_checks(&get_chain_registry_pda().0)
+        .await
+        .unwrap()
+        .unwrap();
+    let registry = ChainRegistry::read(&account.data).unwrap();
+    assert_eq!(
+        registry.registered(),
+        &[ChainRegistry::chain_hash("ethereum")]
+    );
+}
+
+#[tokio::test]
+async fn fail_if_signer_is_not_operator_or_upgrade_authority() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![11, 42, 33])
+        .build()
+        .setup()
+        .await;
+    let stranger_danger = Keypair::new();
+
+    // Action
+    let ix = set_chain_registered(
+        metadata.payer.pubkey(),
+        stranger_danger.pubkey(),
+        "ethereum".to_owned(),
+        true,
+    )
+    .unwrap();
+    let err = metadata
+        .send_tx_with_custom_signers(&[ix], &[&stranger_danger, &metadata.payer.insecure_clone()])
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert_eq!(
+        err.get_gateway_error().unwrap(),
+        GatewayError::InvalidOperatorOrAuthorityAccount
+    );
+}
+
+#[tokio::test]
+async fn call_contract_accepts_any_chain_while_registry_is_empty() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![11, 42, 33])
+        .build()
+        .setup()
+        .await;
+
+    // Action
+    let ix = call_contract_ix(&metadata, "Ethereum");
+    let res = metadata.send_tx(&[ix]).await;
+
+    // Assert
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn call_contract_to_registered_chain_succeeds() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![11, 42, 33])
+        .build()
+        .setup()
+        .await;
+    register_chain(&mut metadata, "ethereum").await;
+
+    // Action
+    let ix = call_contract_ix(&metadata, "ethereum");
+    let res = metadata.send_tx(&[ix]).await;
+
+    // Assert
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn fail_call_contract_to_unregistered_chain() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![11, 42, 33])
+        .build()
+        .setup()
+        .await;
+    register_chain(&mut metadata, "ethereum").await;
+
+    // Action
+    let ix = call_contract_ix(&metadata, "Ethereum");
+    let err = metadata.send_tx(&[ix]).await.unwrap_err();
+
+    // Assert
+    assert_eq!(
+        err.get_gateway_error().unwrap(),
+        GatewayError::DestinationChainNotRegistered
+    );
+
+    // Calls that don't pass the registry are not validated
+    let ix = call_contract(
+        axelar_solana_gateway::id(),
+        metadata.gateway_root_pda,
+        metadata.payer.pubkey(),
+        None,
+        "Ethereum".to_owned(),
+        "0xdestination".to_owned(),
+        b"payload".to_vec(),
+    )
+    .unwrap();
+    assert!(metadata.send_tx(&[ix]).await.is_ok());
+}
@@ -2,12 +2,17 @@ use crate::initialize_message_payload::{
     get_message_account, initialize_message_payload_pda, message_to_command_id,
 };
 use axelar_solana_encoding::types::messages::Message;
+use axelar_solana_gateway::get_incoming_message_pda;
+use axelar_solana_gateway::state::incoming_message::{IncomingMessage, MessageStatus};
 use axelar_solana_gateway_test_fixtures::gateway::random_message;
 use axelar_solana_gateway_test_fixtures::{
     SolanaAxelarIntegration, SolanaAxelarIntegrationMetadata,
 };
 use solana_program_test::tokio;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
 
 #[tokio::test]
 async fn successfully_close_message_payload_pda() {
@@ -54,6 +59,106 @@ async fn successfully_close_message_payload_pda() {
     );
 }
 
+#[tokio::test]
+async fn anyone_can_close_message_payload_pda_of_executed_message() {
+    // Setup
+    let mut runner = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+    let message = random_message();
+    initialize_message_payload_pda(&mut runner, &message, 128).await;
+    let command_id = message_to_command_id(&message);
+    mark_executed(&mut runner, &command_id).await;
+
+    let collector = Keypair::new();
+    runner
+        .fund_account(&collector.pubkey(), 1_000_000_000)
+        .await;
+
+    let previous_payer_account_balance = get_payer_account_balance(&mut runner).await;
+    let previous_message_account_balance =
+        get_message_payload_account_balance(&mut runner, &message).await;
+
+    // Action: the collector closes the account without the payer signing
+    let ix = axelar_solana_gateway::instructions::close_executed_message_payload(
+        runner.gateway_root_pda,
+        runner.payer.pubkey(),
+        command_id,
+    )
+    .unwrap();
+    runner
+        .send_tx_with_custom(&collector.pubkey(), &[ix], &[&collector])
+        .await
+        .unwrap();
+
+    // Assert: the rent went back to the payer, who paid no fees
+    assert!(get_message_account(&mut runner, &message).await.is_none());
+    assert_eq!(
+        get_payer_account_balance(&mut runner).await,
+        previous_payer_account_balance + previous_message_account_balance
+    );
+}
+
+#[tokio::test]
+async fn payer_must_sign_to_close_message_payload_pda_of_approved_message() {
+    // Setup
+    let mut runner = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+    let message = random_message();
+    initialize_message_payload_pda(&mut runner, &message, 128).await;
+    let command_id = message_to_command_id(&message);
+
+    let collector = Keypair::new();
+    runner
+        .fund_account(&collector.pubkey(), 1_000_000_000)
+        .await;
+
+    // Action: the collector closes the account without the payer signing
+    let ix = axelar_solana_gateway::instructions::close_executed_message_payload(
+        runner.gateway_root_pda,
+        runner.payer.pubkey(),
+        command_id,
+    )
+    .unwrap();
+    let (_, tx) = runner
+        .send_tx_with_custom(&collector.pubkey(), &[ix], &[&collector])
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert_eq!(
+        tx.result.unwrap_err(),
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+    assert!(get_message_account(&mut runner, &message).await.is_some());
+}
+
+async fn mark_executed(runner: &mut SolanaAxelarIntegrationMetadata, command_id: &[u8; 32]) {
+    let (incoming_message_pda, _) = get_incoming_message_pda(command_id);
+    let mut incoming_message = runner.incoming_message(incoming_message_pda).await;
+    incoming_message.status = MessageStatus::executed();
+
+    let mut raw_account = runner
+        .try_get_account_no_checks(&incoming_message_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    incoming_message
+        .write(
+            raw_account
+                .data
+                .get_mut(..IncomingMessage::pda_size())
+                .unwrap(),
+        )
+        .expect("must overwrite PDA");
+    runner.set_account_state(&incoming_message_pda, raw_account);
+}
+
 async fn get_payer_account_balance(runner: &mut SolanaAxelarIntegrationMetadata) -> u64 {
     let payer_pubkey = runner.payer.pubkey();
     let payer_account = runner
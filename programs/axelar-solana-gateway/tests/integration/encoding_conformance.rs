@@ -0,0 +1,371 @@
+//! Conformance of the gateway against the execute data produced by the
+//! `axelar-solana-encoding` pipeline.
+//!
+//! Every fixture describes a verifier set (its size, weights and threshold)
+//! and a message batch. The execute data is produced by the same
+//! `axelar_solana_encoding::encode` call the Amplifier prover uses and the
+//! gateway must verify and approve it end to end. Mutated variants of the
+//! same execute data must never get a tampered message approved, which guards
+//! against the encoding drifting away from what the gateway verifies.
+
+use axelar_solana_encoding::types::execute_data::{
+    ExecuteData, MerkleisedMessage, MerkleisedPayload, SigningVerifierSetInfo,
+};
+use axelar_solana_encoding::types::messages::Messages;
+use axelar_solana_encoding::types::payload::Payload;
+use axelar_solana_encoding::types::pubkey::{PublicKey, Signature};
+use axelar_solana_gateway::state::incoming_message::command_id;
+use axelar_solana_gateway::{get_incoming_message_pda, get_signature_verification_pda};
+use axelar_solana_gateway_test_fixtures::gateway::make_messages;
+use axelar_solana_gateway_test_fixtures::{
+    SolanaAxelarIntegration, SolanaAxelarIntegrationMetadata,
+};
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+use solana_program_test::tokio;
+
+/// Half of the `u128` range, so that two signers still sum up to a valid
+/// weight.
+const HALF_U128: u128 = u128::MAX >> 1;
+
+/// A verifier set and message batch the encoding pipeline is exercised with.
+#[derive(Debug, Clone, Copy)]
+struct Fixture {
+    name: &'static str,
+    weights: &'static [u128],
+    quorum: u128,
+    messages: usize,
+}
+
+const FIXTURES: &[Fixture] = &[
+    Fixture {
+        name: "single signer, single message",
+        weights: &[1],
+        quorum: 1,
+        messages: 1,
+    },
+    Fixture {
+        name: "threshold equal to the total weight",
+        weights: &[3, 3, 3],
+        quorum: 9,
+        messages: 2,
+    },
+    Fixture {
+        name: "bare majority",
+        weights: &[1, 1, 1, 1, 1],
+        quorum: 3,
+        messages: 3,
+    },
+    Fixture {
+        name: "single heavy signer reaching the threshold",
+        weights: &[1, 100, 1],
+        quorum: 100,
+        messages: 1,
+    },
+    Fixture {
+        name: "weights close to the u128 limit",
+        weights: &[HALF_U128, HALF_U128],
+        quorum: HALF_U128 + 1,
+        messages: 2,
+    },
+    Fixture {
+        name: "large verifier set",
+        weights: &[1; 32],
+        quorum: 22,
+        messages: 5,
+    },
+];
+
+/// A change applied to the leaf of a signer.
+#[derive(Debug, Clone, Copy)]
+enum SignerMutation {
+    SignatureByte(usize),
+    Weight(u128),
+    Quorum(u128),
+    Position,
+    PubkeyByte(usize),
+    DomainSeparator,
+}
+
+impl SignerMutation {
+    fn apply(self, info: &mut SigningVerifierSetInfo) {
+        match self {
+            Self::SignatureByte(byte) => match info.signature {
+                Signature::EcdsaRecoverable(ref mut signature) => {
+                    signature[byte % signature.len()] ^= 0xFF;
+                }
+                Signature::Ed25519(ref mut signature) => {
+                    signature[byte % signature.len()] ^= 0xFF;
+                }
+            },
+            Self::Weight(delta) => {
+                info.leaf.signer_weight = info.leaf.signer_weight.wrapping_add(delta);
+            }
+            Self::Quorum(delta) => {
+                info.leaf.quorum = info.leaf.quorum.wrapping_add(delta);
+            }
+            Self::Position => {
+                if info.leaf.set_size == 1 {
+                    info.leaf.set_size = 2;
+                    info.leaf.position = 1;
+                } else {
+                    info.leaf.position = (info.leaf.position + 1) % info.leaf.set_size;
+                }
+            }
+            Self::PubkeyByte(byte) => match info.leaf.signer_pubkey {
+                PublicKey::Secp256k1(ref mut pubkey) => {
+                    pubkey[byte % pubkey.len()] ^= 0xFF;
+                }
+                PublicKey::Ed25519(ref mut pubkey) => {
+                    pubkey[byte % pubkey.len()] ^= 0xFF;
+                }
+            },
+            Self::DomainSeparator => {
+                info.leaf.domain_separator[0] ^= 0xFF;
+            }
+        }
+    }
+}
+
+/// A change applied to the leaf of a message.
+#[derive(Debug, Clone, Copy)]
+enum MessageMutation {
+    PayloadHashByte(usize),
+    DestinationAddress,
+    Position,
+    SetSize,
+}
+
+impl MessageMutation {
+    fn apply(self, message: &mut MerkleisedMessage) {
+        let leaf = &mut message.leaf;
+        match self {
+            Self::PayloadHashByte(byte) => {
+                leaf.message.payload_hash[byte % 32] ^= 0xFF;
+            }
+            Self::DestinationAddress => leaf.message.destination_address.push('0'),
+            Self::Position => leaf.position = leaf.position.wrapping_add(1),
+            Self::SetSize => leaf.set_size = leaf.set_size.wrapping_add(1),
+        }
+    }
+}
+
+/// A change applied to valid execute data.
+#[derive(Debug, Clone, Copy)]
+enum Mutation {
+    Signer {
+        signer: usize,
+        mutation: SignerMutation,
+    },
+    Message {
+        message: usize,
+        mutation: MessageMutation,
+    },
+    PayloadRootByte(usize),
+}
+
+impl Mutation {
+    /// Applies the mutation. A mutated signer is moved to the front of the
+    /// leaves so that it's verified before the quorum is reached.
+    fn apply(self, execute_data: &mut ExecuteData) {
+        match self {
+            Self::Signer { signer, mutation } => {
+                let leaves = &mut execute_data.signing_verifier_set_leaves;
+                let signer = signer % leaves.len();
+                leaves.rotate_left(signer);
+                mutation.apply(&mut leaves[0]);
+            }
+            Self::Message { message, mutation } => {
+                let messages = messages_mut(execute_data);
+                let message = message % messages.len();
+                mutation.apply(&mut messages[message]);
+            }
+            Self::PayloadRootByte(byte) => {
+                execute_data.payload_merkle_root[byte % 32] ^= 0xFF;
+            }
+        }
+    }
+}
+
+fn mutation_strategy() -> impl Strategy<Value = Mutation> {
+    let signer_mutation = prop_oneof![
+        any::<usize>().prop_map(SignerMutation::SignatureByte),
+        (1..=u128::from(u8::MAX)).prop_map(SignerMutation::Weight),
+        (1..=u128::from(u8::MAX)).prop_map(SignerMutation::Quorum),
+        Just(SignerMutation::Position),
+        // The first byte of compressed keys is left alone, flipping it only
+        // makes the key unparsable
+        (1..33_usize).prop_map(SignerMutation::PubkeyByte),
+        Just(SignerMutation::DomainSeparator),
+    ];
+    let message_mutation = prop_oneof![
+        any::<usize>().prop_map(MessageMutation::PayloadHashByte),
+        Just(MessageMutation::DestinationAddress),
+        Just(MessageMutation::Position),
+        Just(MessageMutation::SetSize),
+    ];
+    prop_oneof![
+        (any::<usize>(), signer_mutation)
+            .prop_map(|(signer, mutation)| Mutation::Signer { signer, mutation }),
+        (any::<usize>(), message_mutation)
+            .prop_map(|(message, mutation)| Mutation::Message { message, mutation }),
+        any::<usize>().prop_map(Mutation::PayloadRootByte),
+    ]
+}
+
+fn messages(execute_data: &ExecuteData) -> &[MerkleisedMessage] {
+    let MerkleisedPayload::NewMessages { ref messages } = execute_data.payload_items else {
+        unreachable!("fixtures only encode message batches");
+    };
+    messages
+}
+
+fn messages_mut(execute_data: &mut ExecuteData) -> &mut [MerkleisedMessage] {
+    let MerkleisedPayload::NewMessages { ref mut messages } = execute_data.payload_items else {
+        unreachable!("fixtures only encode message batches");
+    };
+    messages
+}
+
+async fn setup(fixture: &Fixture) -> (SolanaAxelarIntegrationMetadata, ExecuteData) {
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(fixture.weights.to_vec())
+        .custom_quorum(fixture.quorum)
+        .build()
+        .setup()
+        .await;
+    let payload = Payload::Messages(Messages(make_messages(fixture.messages)));
+    let execute_data = metadata.construct_execute_data(&metadata.signers.clone(), payload);
+
+    (metadata, execute_data)
+}
+
+/// Verifies the signatures of `execute_data` until the quorum is reached and
+/// approves each of its messages, returning the approved ones.
+async fn relay(
+    metadata: &mut SolanaAxelarIntegrationMetadata,
+    execute_data: &ExecuteData,
+) -> Vec<MerkleisedMessage> {
+    let Ok(verification_session_pda) = metadata.init_payload_session_and_verify(execute_data).await
+    else {
+        return Vec::new();
+    };
+
+    let mut approved = Vec::new();
+    for message in messages(execute_data) {
+        let result = metadata
+            .approve_message(
+                execute_data.payload_merkle_root,
+                message.clone(),
+                verification_session_pda,
+            )
+            .await;
+        if result.is_ok() {
+            approved.push(message.clone());
+        }
+    }
+
+    approved
+}
+
+#[tokio::test]
+async fn gateway_approves_every_encoding_fixture() {
+    for fixture in FIXTURES {
+        // Setup
+        let (mut metadata, execute_data) = setup(fixture).await;
+
+        // The execute data survives a round trip through its wire format
+        let encoded = borsh::to_vec(&execute_data).unwrap();
+        assert_eq!(
+            borsh::from_slice::<ExecuteData>(&encoded).unwrap(),
+            execute_data,
+            "{}",
+            fixture.name
+        );
+        // The encoded signing verifier set is the one the gateway tracks
+        assert_eq!(
+            axelar_solana_gateway::get_verifier_set_tracker_pda(
+                execute_data.signing_verifier_set_merkle_root
+            )
+            .0,
+            metadata.signers.verifier_set_tracker().0,
+            "{}",
+            fixture.name
+        );
+
+        // Action
+        let approved = relay(&mut metadata, &execute_data).await;
+
+        // Assert
+        assert_eq!(approved, messages(&execute_data), "{}", fixture.name);
+        let (verification_session_pda, _) = get_signature_verification_pda(
+            &execute_data.payload_merkle_root,
+            &execute_data.signing_verifier_set_merkle_root,
+        );
+        let session = metadata
+            .signature_verification_session(verification_session_pda)
+            .await;
+        assert!(
+            session.signature_verification.is_valid(),
+            "{}",
+            fixture.name
+        );
+    }
+}
+
+async fn run(fixture: Fixture, mutation: Mutation) -> Result<(), TestCaseError> {
+    let (mut metadata, valid) = setup(&fixture).await;
+    let mut mutated = valid.clone();
+    mutation.apply(&mut mutated);
+
+    let approved = relay(&mut metadata, &mutated).await;
+
+    // A tampered message is rejected on its own, any other mutation keeps
+    // the whole batch from being approved
+    let expected = match mutation {
+        Mutation::Message { .. } => messages(&mutated)
+            .iter()
+            .filter(|message| messages(&valid).contains(message))
+            .cloned()
+            .collect::<Vec<_>>(),
+        Mutation::Signer { .. } | Mutation::PayloadRootByte(_) => Vec::new(),
+    };
+    prop_assert_eq!(
+        &approved,
+        &expected,
+        "{} with {:?} diverged",
+        fixture.name,
+        mutation
+    );
+
+    // Messages left unapproved have no incoming message PDA
+    for message in messages(&mutated)
+        .iter()
+        .filter(|message| !approved.contains(message))
+    {
+        let cc_id = &message.leaf.message.cc_id;
+        let (incoming_message_pda, _) =
+            get_incoming_message_pda(&command_id(&cc_id.chain, &cc_id.id));
+        let incoming_message = metadata
+            .try_get_account_no_checks(&incoming_message_pda)
+            .await
+            .unwrap();
+        prop_assert!(incoming_message.is_none());
+    }
+
+    Ok(())
+}
+
+proptest! {
+    // Every case boots a fresh program-test bank, keep the amount modest
+    #![proptest_config(ProptestConfig::with_cases(24))]
+
+    #[test]
+    fn gateway_rejects_mutated_encoding_fixtures(
+        fixture in prop::sample::select(FIXTURES),
+        mutation in mutation_strategy(),
+    ) {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(run(fixture, mutation))?;
+    }
+}
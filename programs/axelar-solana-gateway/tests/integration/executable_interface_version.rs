@@ -0,0 +1,31 @@
+use axelar_solana_gateway::executable::{ExecutableInterfaceVersion, EXECUTABLE_INTERFACE_VERSION};
+use axelar_solana_gateway::instructions::get_executable_interface_version;
+use axelar_solana_gateway_test_fixtures::SolanaAxelarIntegration;
+use borsh::BorshDeserialize;
+use pretty_assertions::assert_eq;
+use solana_program_test::tokio;
+
+#[tokio::test]
+async fn deployed_gateway_returns_its_executable_interface_version() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42])
+        .build()
+        .setup()
+        .await;
+
+    // Action
+    let ix = get_executable_interface_version().unwrap();
+    let simulation_result = metadata.simulate_tx(&[ix]).await.unwrap();
+
+    // Assert
+    let return_data = simulation_result
+        .simulation_details
+        .unwrap()
+        .return_data
+        .unwrap();
+    assert_eq!(return_data.program_id, axelar_solana_gateway::ID);
+    let deployed = ExecutableInterfaceVersion::try_from_slice(&return_data.data).unwrap();
+    assert_eq!(deployed, EXECUTABLE_INTERFACE_VERSION);
+    assert!(EXECUTABLE_INTERFACE_VERSION.is_compatible_with(deployed));
+}
@@ -1,5 +1,7 @@
 use axelar_message_primitives::U256;
 use axelar_solana_gateway::get_gateway_root_config_pda;
+use axelar_solana_gateway::instructions::InitializeConfig;
+use axelar_solana_gateway::state::verifier_set_tracker::SignatureScheme;
 use axelar_solana_gateway::state::GatewayConfig;
 use axelar_solana_gateway_test_fixtures::{
     SolanaAxelarIntegration, SolanaAxelarIntegrationMetadata,
@@ -94,6 +96,49 @@ async fn test_successfylly_initialize_config_with_single_initial_signer() {
     assert_verifier_sets(&mut metadata).await;
 }
 
+#[tokio::test]
+async fn test_successfully_initialize_config_with_signature_scheme() {
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42])
+        .build()
+        .setup_without_init_config()
+        .await;
+    let (gateway_config_pda, _bump) = get_gateway_root_config_pda();
+    let initial_sets = metadata.init_gateway_config_verifier_set_data();
+    let ix = axelar_solana_gateway::instructions::initialize_config_with_signature_scheme(
+        metadata.fixture.payer.pubkey(),
+        metadata.upgrade_authority.pubkey(),
+        InitializeConfig {
+            domain_separator: metadata.domain_separator,
+            initial_verifier_set: initial_sets.clone(),
+            minimum_rotation_delay: metadata.minimum_rotate_signers_delay_seconds,
+            operator: metadata.operator.pubkey(),
+            previous_verifier_retention: metadata.previous_signers_retention.into(),
+        },
+        SignatureScheme::Ed25519,
+        gateway_config_pda,
+    )
+    .unwrap();
+
+    let signers = &[
+        metadata.fixture.payer.insecure_clone(),
+        metadata.upgrade_authority.insecure_clone(),
+    ];
+    metadata
+        .send_tx_with_custom_signers(&[ix], signers)
+        .await
+        .unwrap();
+
+    // Assert -- config derived correctly
+    let root_pda_data = metadata.gateway_config(gateway_config_pda).await;
+    assert!(cmp_config(&metadata, &root_pda_data));
+
+    // Assert -- the initial verifier set signs with the given scheme
+    assert_verifier_sets(&mut metadata).await;
+    let vs_data = metadata.verifier_set_tracker(initial_sets.pda).await;
+    assert_eq!(vs_data.signature_scheme(), Ok(SignatureScheme::Ed25519));
+}
+
 #[tokio::test]
 async fn test_reverts_on_invalid_gateway_pda_pubkey() {
     let mut metadata = SolanaAxelarIntegration::builder()
@@ -3,7 +3,7 @@ use axelar_solana_encoding::types::execute_data::MerkleisedPayload;
 use axelar_solana_encoding::types::messages::{Message, Messages};
 use axelar_solana_encoding::types::payload::Payload;
 use axelar_solana_encoding::LeafHash;
-use axelar_solana_gateway::events::MessageApprovedEvent;
+use axelar_solana_gateway::events::{MessageApprovalDetailsEvent, MessageApprovedEvent};
 use axelar_solana_gateway::instructions;
 use axelar_solana_gateway::state::incoming_message::{command_id, IncomingMessage, MessageStatus};
 use axelar_solana_gateway::state::message_payload::ImmutMessagePayload;
@@ -19,6 +19,7 @@ use event_cpi_test_utils::assert_event_cpi;
 use pretty_assertions::assert_eq;
 use solana_program_test::tokio;
 use solana_sdk::account::Account;
+use solana_sdk::clock::Clock;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signer::Signer;
 
@@ -73,7 +74,7 @@ pub async fn approve_message(runner: &mut SolanaAxelarIntegrationMetadata, messa
     .unwrap();
 
     // Simulate transaction
-
+    let clock = runner.get_sysvar::<Clock>().await;
     let simulation_result = runner.simulate_tx(&[ix.clone()]).await.unwrap();
 
     // Assert event emitted
@@ -100,6 +101,14 @@ pub async fn approve_message(runner: &mut SolanaAxelarIntegrationMetadata, messa
     };
 
     assert_event_cpi(&expected_event, &inner_ixs);
+    assert_event_cpi(
+        &MessageApprovalDetailsEvent {
+            command_id,
+            approved_at_slot: clock.slot,
+            approved_at: clock.unix_timestamp,
+        },
+        &inner_ixs,
+    );
 
     // Execute the transaction
 
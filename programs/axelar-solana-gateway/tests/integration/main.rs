@@ -11,13 +11,27 @@
 #![allow(clippy::missing_panics_doc)]
 
 mod approve_message;
+mod approve_messages_batch;
+#[cfg(feature = "audit-trace")]
+mod audit_trace;
+mod cancel_message;
+mod chain_registry;
 mod close_message_payload;
 mod commit_message_payload;
+mod encoding_conformance;
+mod executable_interface_version;
 mod initialize_config;
 pub mod initialize_message_payload;
 mod initialize_signature_verification;
+mod prune_verifier_set_tracker;
+mod record_execution_receipt;
+mod recover_operatorship;
+mod resize_config;
 mod rotate_signers;
+mod state_digest;
+mod state_machine;
 mod transfer_operatorship;
 mod validate_message;
+mod verify_event_authority;
 mod verify_signature;
 mod write_message_payload;
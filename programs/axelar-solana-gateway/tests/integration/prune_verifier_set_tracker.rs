@@ -0,0 +1,158 @@
+use axelar_message_primitives::U256;
+use axelar_solana_gateway::error::GatewayError;
+use axelar_solana_gateway::events::VerifierSetTrackerPrunedEvent;
+use axelar_solana_gateway::instructions::prune_verifier_set_tracker;
+use axelar_solana_gateway_test_fixtures::gateway::{make_verifier_set, GetGatewayError};
+use axelar_solana_gateway_test_fixtures::test_signer::SigningVerifierSet;
+use axelar_solana_gateway_test_fixtures::{
+    SolanaAxelarIntegration, SolanaAxelarIntegrationMetadata,
+};
+use event_cpi_test_utils::assert_event_cpi;
+use solana_program_test::tokio;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+
+/// Rotates to a new verifier set, signed by the current one, and returns it.
+async fn rotate(metadata: &mut SolanaAxelarIntegrationMetadata) -> SigningVerifierSet {
+    let new_verifier_set = make_verifier_set(&[500, 200], 1, metadata.domain_separator);
+    metadata
+        .sign_session_and_rotate_signers(
+            &metadata.signers.clone(),
+            &new_verifier_set.verifier_set(),
+        )
+        .await
+        .unwrap()
+        .1
+        .unwrap();
+
+    new_verifier_set
+}
+
+#[tokio::test]
+async fn prunes_verifier_set_tracker_outside_of_retention() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .previous_signers_retention(1)
+        .build()
+        .setup()
+        .await;
+    let (tracker_pda, _) = metadata.signers.verifier_set_tracker();
+    let old_tracker = metadata.verifier_set_tracker(tracker_pda).await;
+    let tracker_rent = metadata
+        .try_get_account_no_checks(&tracker_pda)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    rotate(&mut metadata).await;
+    let operator = metadata.operator.pubkey();
+    let operator_balance = metadata
+        .try_get_account_no_checks(&operator)
+        .await
+        .unwrap()
+        .map_or(0, |account| account.lamports);
+
+    // Action
+    let ix = prune_verifier_set_tracker(tracker_pda, operator).unwrap();
+    let simulation_result = metadata.simulate_tx(&[ix.clone()]).await.unwrap();
+    metadata.send_tx(&[ix]).await.unwrap();
+
+    // Assert
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    assert_event_cpi(
+        &VerifierSetTrackerPrunedEvent {
+            verifier_set_hash: old_tracker.verifier_set_hash,
+            epoch: U256::ONE,
+            current_epoch: 2_u128.into(),
+            retention: U256::ONE,
+        },
+        &inner_ixs,
+    );
+    assert!(metadata
+        .try_get_account_no_checks(&tracker_pda)
+        .await
+        .unwrap()
+        .is_none());
+    let operator_balance_after = metadata
+        .try_get_account_no_checks(&operator)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(operator_balance_after, operator_balance + tracker_rent);
+}
+
+#[tokio::test]
+async fn fails_to_prune_verifier_set_tracker_within_retention() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .previous_signers_retention(2)
+        .build()
+        .setup()
+        .await;
+    let (tracker_pda, _) = metadata.signers.verifier_set_tracker();
+    rotate(&mut metadata).await;
+
+    // Action
+    let ix = prune_verifier_set_tracker(tracker_pda, metadata.operator.pubkey()).unwrap();
+    let err = metadata.send_tx(&[ix]).await.unwrap_err();
+
+    // Assert
+    assert_eq!(
+        err.get_gateway_error().unwrap(),
+        GatewayError::VerifierSetStillRetained
+    );
+    metadata.verifier_set_tracker(tracker_pda).await;
+}
+
+#[tokio::test]
+async fn fails_to_prune_active_verifier_set_tracker() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .previous_signers_retention(0)
+        .build()
+        .setup()
+        .await;
+    let (tracker_pda, _) = metadata.signers.verifier_set_tracker();
+
+    // Action
+    let ix = prune_verifier_set_tracker(tracker_pda, metadata.operator.pubkey()).unwrap();
+    let err = metadata.send_tx(&[ix]).await.unwrap_err();
+
+    // Assert
+    assert_eq!(
+        err.get_gateway_error().unwrap(),
+        GatewayError::VerifierSetStillRetained
+    );
+}
+
+#[tokio::test]
+async fn fails_to_prune_verifier_set_tracker_to_other_recipient() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .previous_signers_retention(1)
+        .build()
+        .setup()
+        .await;
+    let (tracker_pda, _) = metadata.signers.verifier_set_tracker();
+    rotate(&mut metadata).await;
+
+    // Action
+    let ix = prune_verifier_set_tracker(tracker_pda, Pubkey::new_unique()).unwrap();
+    let res = metadata.send_tx(&[ix]).await;
+
+    // Assert
+    assert!(res.is_err());
+    metadata.verifier_set_tracker(tracker_pda).await;
+}
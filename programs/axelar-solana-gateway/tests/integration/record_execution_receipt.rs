@@ -0,0 +1,280 @@
+use axelar_solana_encoding::types::messages::Message;
+use axelar_solana_gateway::error::GatewayError;
+use axelar_solana_gateway::events::MessageExecutedEventV2;
+use axelar_solana_gateway::get_incoming_message_pda;
+use axelar_solana_gateway::instructions::record_execution_receipt;
+use axelar_solana_gateway::state::incoming_message::{
+    command_id, IncomingMessage, MessageStatus, MAX_EXECUTION_COMPUTE_UNITS,
+};
+use axelar_solana_gateway_test_fixtures::gateway::{make_messages, random_bytes, GetGatewayError};
+use axelar_solana_gateway_test_fixtures::{
+    SolanaAxelarIntegration, SolanaAxelarIntegrationMetadata,
+};
+use event_cpi_test_utils::assert_event_cpi;
+use pretty_assertions::assert_eq;
+use program_utils::pda::BytemuckedPda;
+use solana_program_test::tokio;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+/// Approves a message, uploads its payload and flags it as executed, returning
+/// the runner and the message.
+async fn executed_message(mark_executed: bool) -> (SolanaAxelarIntegrationMetadata, Message) {
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+
+    let raw_payload = random_bytes::<64>();
+    let mut message = make_messages(1).remove(0);
+    message.payload_hash = solana_program::keccak::hash(&raw_payload).to_bytes();
+    metadata
+        .sign_session_and_approve_messages(&metadata.signers.clone(), &[message.clone()])
+        .await
+        .unwrap();
+    metadata
+        .upload_message_payload(&message, &raw_payload)
+        .await
+        .unwrap();
+
+    let command_id = command_id(&message.cc_id.chain, &message.cc_id.id);
+    if mark_executed {
+        let (incoming_message_pda, _) = get_incoming_message_pda(&command_id);
+        let mut incoming_message = metadata.incoming_message(incoming_message_pda).await;
+        incoming_message.status = MessageStatus::executed();
+
+        let mut raw_account = metadata
+            .try_get_account_no_checks(&incoming_message_pda)
+            .await
+            .unwrap()
+            .unwrap();
+        incoming_message
+            .write(
+                raw_account
+                    .data
+                    .get_mut(..IncomingMessage::pda_size())
+                    .unwrap(),
+            )
+            .expect("must overwrite PDA");
+        metadata.set_account_state(&incoming_message_pda, raw_account);
+    }
+
+    (metadata, message)
+}
+
+#[tokio::test]
+async fn successfully_records_execution_receipt() {
+    // Setup
+    let (mut metadata, message) = executed_message(true).await;
+    let relayer = metadata.payer.pubkey();
+    let compute_units = 123_456;
+
+    // Action
+    let ix = record_execution_receipt(
+        metadata.gateway_root_pda,
+        relayer,
+        message.clone(),
+        compute_units,
+    )
+    .unwrap();
+    let simulation_result = metadata.simulate_tx(&[ix.clone()]).await.unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    assert_event_cpi(
+        &MessageExecutedEventV2 {
+            command_id: command_id(&message.cc_id.chain, &message.cc_id.id),
+            destination_address: message.destination_address.parse().unwrap(),
+            payload_hash: message.payload_hash,
+            source_chain: message.cc_id.chain.clone(),
+            cc_id: message.cc_id.id.clone(),
+            source_address: message.source_address.clone(),
+            destination_chain: message.destination_chain.clone(),
+            relayer,
+            compute_units,
+        },
+        &inner_ixs,
+    );
+    metadata.send_tx(&[ix]).await.unwrap();
+
+    // Assert
+    let (incoming_message_pda, _) =
+        get_incoming_message_pda(&command_id(&message.cc_id.chain, &message.cc_id.id));
+    let extension = metadata
+        .incoming_message_extension(incoming_message_pda)
+        .await
+        .unwrap();
+    assert!(extension.has_execution_receipt());
+    assert_eq!(extension.relayer, relayer);
+    assert_eq!(extension.compute_units, compute_units);
+}
+
+#[tokio::test]
+async fn fail_if_message_not_executed() {
+    // Setup
+    let (mut metadata, message) = executed_message(false).await;
+
+    // Action
+    let ix = record_execution_receipt(
+        metadata.gateway_root_pda,
+        metadata.payer.pubkey(),
+        message.clone(),
+        1_000,
+    )
+    .unwrap();
+    let err = metadata.send_tx(&[ix]).await.unwrap_err();
+
+    // Assert
+    assert_eq!(
+        err.get_gateway_error().unwrap(),
+        GatewayError::MessageNotExecuted
+    );
+}
+
+#[tokio::test]
+async fn fail_if_receipt_already_recorded() {
+    // Setup
+    let (mut metadata, message) = executed_message(true).await;
+    let ix = record_execution_receipt(
+        metadata.gateway_root_pda,
+        metadata.payer.pubkey(),
+        message.clone(),
+        1_000,
+    )
+    .unwrap();
+    metadata.send_tx(&[ix]).await.unwrap();
+
+    // Action
+    let ix = record_execution_receipt(
+        metadata.gateway_root_pda,
+        metadata.payer.pubkey(),
+        message.clone(),
+        2_000,
+    )
+    .unwrap();
+    let err = metadata.send_tx(&[ix]).await.unwrap_err();
+
+    // Assert
+    assert_eq!(
+        err.get_gateway_error().unwrap(),
+        GatewayError::ExecutionReceiptAlreadyRecorded
+    );
+}
+
+#[tokio::test]
+async fn fail_if_compute_units_out_of_bounds() {
+    // Setup
+    let (mut metadata, message) = executed_message(true).await;
+
+    for compute_units in [0, MAX_EXECUTION_COMPUTE_UNITS + 1] {
+        // Action
+        let ix = record_execution_receipt(
+            metadata.gateway_root_pda,
+            metadata.payer.pubkey(),
+            message.clone(),
+            compute_units,
+        )
+        .unwrap();
+        let err = metadata.send_tx(&[ix]).await.unwrap_err();
+
+        // Assert
+        assert_eq!(
+            err.get_gateway_error().unwrap(),
+            GatewayError::InvalidExecutionComputeUnits
+        );
+    }
+}
+
+#[tokio::test]
+async fn fail_if_relayer_did_not_upload_the_payload() {
+    // Setup
+    let (mut metadata, message) = executed_message(true).await;
+    let (incoming_message_pda, _) =
+        get_incoming_message_pda(&command_id(&message.cc_id.chain, &message.cc_id.id));
+    let (message_payload_pda, _) = axelar_solana_gateway::find_message_payload_pda(
+        incoming_message_pda,
+        metadata.payer.pubkey(),
+    );
+
+    // Action: another relayer claims the receipt using the payer's payload
+    let impostor = Keypair::new();
+    let mut ix = record_execution_receipt(
+        metadata.gateway_root_pda,
+        impostor.pubkey(),
+        message.clone(),
+        1_000,
+    )
+    .unwrap();
+    ix.accounts[3].pubkey = message_payload_pda;
+    let res = metadata
+        .send_tx_with_custom_signers(&[ix], &[&metadata.payer.insecure_clone(), &impostor])
+        .await;
+
+    // Assert
+    assert!(res.is_err());
+    let extension = metadata
+        .incoming_message_extension(incoming_message_pda)
+        .await
+        .unwrap();
+    assert!(!extension.has_execution_receipt());
+}
+
+#[tokio::test]
+async fn fail_if_message_was_tampered_with() {
+    // Setup
+    let (mut metadata, mut message) = executed_message(true).await;
+    message.source_address = "tampered".to_owned();
+
+    // Action
+    let ix = record_execution_receipt(
+        metadata.gateway_root_pda,
+        metadata.payer.pubkey(),
+        message,
+        1_000,
+    )
+    .unwrap();
+    let err = metadata.send_tx(&[ix]).await.unwrap_err();
+
+    // Assert
+    assert_eq!(
+        err.get_gateway_error().unwrap(),
+        GatewayError::MessageHasBeenTamperedWith
+    );
+}
+
+#[tokio::test]
+async fn fail_if_message_was_approved_without_extension() {
+    // Setup: an incoming message approved before the extension was introduced
+    let (mut metadata, message) = executed_message(true).await;
+    let (incoming_message_pda, _) =
+        get_incoming_message_pda(&command_id(&message.cc_id.chain, &message.cc_id.id));
+    let mut raw_account = metadata
+        .try_get_account_no_checks(&incoming_message_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    raw_account.data.truncate(IncomingMessage::pda_size());
+    metadata.set_account_state(&incoming_message_pda, raw_account);
+
+    // Action
+    let ix = record_execution_receipt(
+        metadata.gateway_root_pda,
+        metadata.payer.pubkey(),
+        message,
+        1_000,
+    )
+    .unwrap();
+    let err = metadata.send_tx(&[ix]).await.unwrap_err();
+
+    // Assert
+    assert_eq!(
+        err.get_gateway_error().unwrap(),
+        GatewayError::ExecutionReceiptNotSupported
+    );
+}
@@ -0,0 +1,236 @@
+use axelar_solana_gateway::error::GatewayError;
+use axelar_solana_gateway::events::{
+    OperatorshipRecoveredEvent, OperatorshipRecoveryInitiatedEvent,
+};
+use axelar_solana_gateway::get_operatorship_recovery_pda;
+use axelar_solana_gateway::instructions::recover_operatorship;
+use axelar_solana_gateway::state::operatorship_recovery::OPERATORSHIP_RECOVERY_DELAY;
+use axelar_solana_gateway::state::{GatewayConfig, OperatorshipRecovery};
+use axelar_solana_gateway_test_fixtures::gateway::GetGatewayError;
+use axelar_solana_gateway_test_fixtures::{
+    SolanaAxelarIntegration, SolanaAxelarIntegrationMetadata,
+};
+use event_cpi_test_utils::assert_event_cpi;
+use program_utils::pda::BytemuckedPda;
+use solana_program_test::tokio;
+use solana_sdk::account::ReadableAccount;
+use solana_sdk::clock::Clock;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+/// Sends a `RecoverOperatorship` instruction signed by `authority` and
+/// returns the inner instructions of its simulation.
+async fn recover(
+    metadata: &mut SolanaAxelarIntegrationMetadata,
+    authority: &Keypair,
+    new_operator: Pubkey,
+) -> Result<
+    Vec<solana_sdk::inner_instruction::InnerInstruction>,
+    solana_program_test::BanksTransactionResultWithMetadata,
+> {
+    let ix = recover_operatorship(authority.pubkey(), new_operator).unwrap();
+    let signers = [authority, &metadata.payer.insecure_clone()];
+    let simulation_result = metadata
+        .simulate_tx_with_custom_signers(&[ix.clone()], &signers)
+        .await
+        .unwrap();
+    metadata
+        .send_tx_with_custom_signers(&[ix], &signers)
+        .await?;
+
+    Ok(simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap())
+}
+
+async fn operator(metadata: &mut SolanaAxelarIntegrationMetadata) -> Pubkey {
+    let gateway_root_pda = metadata.gateway_root_pda;
+    let config = metadata
+        .get_account(&gateway_root_pda, &axelar_solana_gateway::ID)
+        .await;
+    GatewayConfig::read(config.data()).unwrap().operator
+}
+
+async fn now(metadata: &mut SolanaAxelarIntegrationMetadata) -> u64 {
+    metadata
+        .get_sysvar::<Clock>()
+        .await
+        .unix_timestamp
+        .try_into()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn recovers_operatorship_after_the_timelock() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+    metadata
+        .fund_account(&metadata.upgrade_authority.pubkey(), 1_000_000_000)
+        .await;
+    let upgrade_authority = metadata.upgrade_authority.insecure_clone();
+    let previous_operator = operator(&mut metadata).await;
+    let new_operator = Pubkey::new_unique();
+    let (recovery_pda, _) = get_operatorship_recovery_pda(&metadata.gateway_root_pda);
+
+    // Action - initiate the recovery
+    let initiated_at = now(&mut metadata).await;
+    let inner_ixs = recover(&mut metadata, &upgrade_authority, new_operator)
+        .await
+        .unwrap();
+
+    // Assert
+    let eligible_at = initiated_at + OPERATORSHIP_RECOVERY_DELAY;
+    assert_event_cpi(
+        &OperatorshipRecoveryInitiatedEvent {
+            new_operator,
+            eligible_at,
+        },
+        &inner_ixs,
+    );
+    let recovery = metadata
+        .try_get_account_no_checks(&recovery_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let recovery = OperatorshipRecovery::read(recovery.data()).unwrap();
+    assert_eq!(recovery.new_operator, new_operator);
+    assert_eq!(recovery.eligible_at, eligible_at);
+    assert_eq!(operator(&mut metadata).await, previous_operator);
+
+    // Action - apply it once the timelock elapsed
+    metadata
+        .forward_time(OPERATORSHIP_RECOVERY_DELAY.try_into().unwrap())
+        .await;
+    let inner_ixs = recover(&mut metadata, &upgrade_authority, new_operator)
+        .await
+        .unwrap();
+
+    // Assert
+    assert_event_cpi(
+        &OperatorshipRecoveredEvent {
+            previous_operator,
+            new_operator,
+        },
+        &inner_ixs,
+    );
+    assert_eq!(operator(&mut metadata).await, new_operator);
+    assert!(metadata
+        .try_get_account_no_checks(&recovery_pda)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn fails_to_recover_operatorship_before_the_timelock() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+    metadata
+        .fund_account(&metadata.upgrade_authority.pubkey(), 1_000_000_000)
+        .await;
+    let upgrade_authority = metadata.upgrade_authority.insecure_clone();
+    let previous_operator = operator(&mut metadata).await;
+    let new_operator = Pubkey::new_unique();
+    recover(&mut metadata, &upgrade_authority, new_operator)
+        .await
+        .unwrap();
+    metadata
+        .forward_time((OPERATORSHIP_RECOVERY_DELAY - 60).try_into().unwrap())
+        .await;
+
+    // Action
+    let tx = recover(&mut metadata, &upgrade_authority, new_operator)
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert_eq!(
+        tx.get_gateway_error().unwrap(),
+        GatewayError::OperatorshipRecoveryTimelocked
+    );
+    assert_eq!(operator(&mut metadata).await, previous_operator);
+}
+
+#[tokio::test]
+async fn recovering_to_another_operator_restarts_the_timelock() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+    metadata
+        .fund_account(&metadata.upgrade_authority.pubkey(), 1_000_000_000)
+        .await;
+    let upgrade_authority = metadata.upgrade_authority.insecure_clone();
+    let typo_operator = Pubkey::new_unique();
+    let new_operator = Pubkey::new_unique();
+    recover(&mut metadata, &upgrade_authority, typo_operator)
+        .await
+        .unwrap();
+    metadata
+        .forward_time(OPERATORSHIP_RECOVERY_DELAY.try_into().unwrap())
+        .await;
+
+    // Action
+    let restarted_at = now(&mut metadata).await;
+    let inner_ixs = recover(&mut metadata, &upgrade_authority, new_operator)
+        .await
+        .unwrap();
+
+    // Assert
+    assert_event_cpi(
+        &OperatorshipRecoveryInitiatedEvent {
+            new_operator,
+            eligible_at: restarted_at + OPERATORSHIP_RECOVERY_DELAY,
+        },
+        &inner_ixs,
+    );
+    let tx = recover(&mut metadata, &upgrade_authority, new_operator)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        tx.get_gateway_error().unwrap(),
+        GatewayError::OperatorshipRecoveryTimelocked
+    );
+}
+
+#[tokio::test]
+async fn fails_to_recover_operatorship_when_signer_is_the_operator() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+    metadata
+        .fund_account(&metadata.upgrade_authority.pubkey(), 1_000_000_000)
+        .await;
+    let operator_keypair = metadata.operator.insecure_clone();
+    let new_operator = Pubkey::new_unique();
+
+    // Action
+    let tx = recover(&mut metadata, &operator_keypair, new_operator)
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert_eq!(
+        tx.get_gateway_error().unwrap(),
+        GatewayError::InvalidOperatorOrAuthorityAccount
+    );
+}
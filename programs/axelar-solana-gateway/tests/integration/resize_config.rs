@@ -0,0 +1,147 @@
+use axelar_solana_gateway::error::GatewayError;
+use axelar_solana_gateway::state::GatewayConfig;
+use axelar_solana_gateway_test_fixtures::{
+    SolanaAxelarIntegration, SolanaAxelarIntegrationMetadata,
+};
+use num_traits::ToPrimitive as _;
+use program_utils::pda::BytemuckedPda;
+use solana_program_test::tokio;
+use solana_sdk::account::ReadableAccount;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+#[tokio::test]
+async fn successfully_resize_config_and_keep_using_it() {
+    // Setup
+    let SolanaAxelarIntegrationMetadata {
+        mut fixture,
+        gateway_root_pda,
+        operator,
+        ..
+    } = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![11, 42, 33])
+        .build()
+        .setup()
+        .await;
+    let original_config_acc = fixture
+        .get_account(&gateway_root_pda, &axelar_solana_gateway::ID)
+        .await;
+    let original_config = *GatewayConfig::read(original_config_acc.data()).unwrap();
+    let new_size = GatewayConfig::pda_size() + 128;
+
+    // Action
+    let ix = axelar_solana_gateway::instructions::resize_config(
+        fixture.payer.pubkey(),
+        operator.pubkey(),
+        new_size as u64,
+    )
+    .unwrap();
+    let tx = fixture
+        .send_tx_with_custom_signers(&[ix], &[&operator, &fixture.payer.insecure_clone()])
+        .await
+        .unwrap();
+
+    // Assert
+    assert!(tx.result.is_ok());
+    let resized_config_acc = fixture
+        .get_account(&gateway_root_pda, &axelar_solana_gateway::ID)
+        .await;
+    assert_eq!(resized_config_acc.data().len(), new_size);
+    assert!(resized_config_acc.lamports() > original_config_acc.lamports());
+    assert!(resized_config_acc.data()[GatewayConfig::pda_size()..]
+        .iter()
+        .all(|byte| *byte == 0));
+    let resized_config = GatewayConfig::read_versioned(resized_config_acc.data()).unwrap();
+    assert_eq!(*resized_config, original_config);
+
+    // The resized config can still be used by the gateway instructions
+    let new_operator = Keypair::new();
+    let ix = axelar_solana_gateway::instructions::transfer_operatorship(
+        gateway_root_pda,
+        operator.pubkey(),
+        new_operator.pubkey(),
+    )
+    .unwrap();
+    fixture
+        .send_tx_with_custom_signers(&[ix], &[&operator, &fixture.payer.insecure_clone()])
+        .await
+        .unwrap();
+    let altered_config_acc = fixture
+        .get_account(&gateway_root_pda, &axelar_solana_gateway::ID)
+        .await;
+    let altered_config = GatewayConfig::read_versioned(altered_config_acc.data()).unwrap();
+    assert_eq!(altered_config.operator, new_operator.pubkey());
+}
+
+#[tokio::test]
+async fn fail_if_resize_shrinks_config() {
+    // Setup
+    let SolanaAxelarIntegrationMetadata {
+        mut fixture,
+        operator,
+        ..
+    } = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![11, 42, 33])
+        .build()
+        .setup()
+        .await;
+
+    // Action
+    let ix = axelar_solana_gateway::instructions::resize_config(
+        fixture.payer.pubkey(),
+        operator.pubkey(),
+        (GatewayConfig::pda_size() - 1) as u64,
+    )
+    .unwrap();
+    let tx = fixture
+        .send_tx_with_custom_signers(&[ix], &[&operator, &fixture.payer.insecure_clone()])
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert_eq!(
+        tx.result.unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(GatewayError::InvalidConfigSize.to_u32().unwrap())
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_if_signer_is_not_operator_or_upgrade_authority() {
+    // Setup
+    let SolanaAxelarIntegrationMetadata { mut fixture, .. } = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![11, 42, 33])
+        .build()
+        .setup()
+        .await;
+
+    // Action
+    let stranger_danger = Keypair::new();
+    let ix = axelar_solana_gateway::instructions::resize_config(
+        fixture.payer.pubkey(),
+        stranger_danger.pubkey(),
+        (GatewayConfig::pda_size() + 128) as u64,
+    )
+    .unwrap();
+    let tx = fixture
+        .send_tx_with_custom_signers(&[ix], &[&stranger_danger, &fixture.payer.insecure_clone()])
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert_eq!(
+        tx.result.unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                GatewayError::InvalidOperatorOrAuthorityAccount
+                    .to_u32()
+                    .unwrap()
+            )
+        )
+    );
+}
@@ -7,13 +7,17 @@ use axelar_solana_encoding::types::messages::Messages;
 use axelar_solana_encoding::types::payload::Payload;
 use axelar_solana_encoding::types::verifier_set::verifier_set_hash;
 use axelar_solana_gateway::error::GatewayError;
-use axelar_solana_gateway::events::VerifierSetRotatedEvent;
+use axelar_solana_gateway::events::{VerifierSetRotatedEvent, VerifierSetRotationDetailsEvent};
 use axelar_solana_gateway::get_verifier_set_tracker_pda;
-use axelar_solana_gateway::state::verifier_set_tracker::VerifierSetTracker;
+use axelar_solana_gateway::instructions::VerifierSetLeafProof;
+use axelar_solana_gateway::state::verifier_set_tracker::{SignatureScheme, VerifierSetTracker};
 use axelar_solana_gateway_test_fixtures::gateway::{
-    make_messages, make_verifier_set, random_bytes, random_message, GetGatewayError,
+    make_ed25519_verifier_set, make_messages, make_verifier_set, random_bytes, random_message,
+    GetGatewayError,
 };
+use axelar_solana_gateway_test_fixtures::test_signer::SigningVerifierSet;
 use axelar_solana_gateway_test_fixtures::SolanaAxelarIntegration;
+use axelar_solana_gateway_test_fixtures::SolanaAxelarIntegrationMetadata;
 use event_cpi_test_utils::assert_event_cpi;
 use solana_program_test::tokio;
 use solana_sdk::signature::Keypair;
@@ -101,6 +105,201 @@ async fn successfully_rotates_signers() {
     );
 }
 
+/// Returns the proof of the first leaf of `verifier_set`.
+fn first_leaf_proof(
+    metadata: &mut SolanaAxelarIntegrationMetadata,
+    verifier_set: &SigningVerifierSet,
+) -> VerifierSetLeafProof {
+    // The signing verifier set of any payload comes with its leaves and proofs
+    let payload = Payload::Messages(Messages(make_messages(1)));
+    let execute_data = metadata.construct_execute_data(verifier_set, payload);
+    let info = execute_data.signing_verifier_set_leaves.first().unwrap();
+    VerifierSetLeafProof {
+        leaf: info.leaf,
+        merkle_proof: info.merkle_proof.clone(),
+    }
+}
+
+/// Builds a `rotate_signers` instruction to `new_verifier_set`, signed by the
+/// initial verifier set, that proves `new_verifier_set_leaf`.
+async fn rotate_signers_with_leaf_proof_ix(
+    metadata: &mut SolanaAxelarIntegrationMetadata,
+    new_verifier_set: &SigningVerifierSet,
+    new_verifier_set_leaf: VerifierSetLeafProof,
+) -> solana_sdk::instruction::Instruction {
+    let payload = Payload::NewVerifierSet(new_verifier_set.verifier_set());
+    let execute_data = metadata.construct_execute_data(&metadata.signers.clone(), payload);
+    let verification_session_account = metadata
+        .init_payload_session_and_verify(&execute_data)
+        .await
+        .unwrap();
+
+    axelar_solana_gateway::instructions::rotate_signers_with_leaf_proof(
+        metadata.gateway_root_pda,
+        verification_session_account,
+        metadata.signers.verifier_set_tracker().0,
+        new_verifier_set.verifier_set_tracker().0,
+        metadata.payer.pubkey(),
+        None,
+        verifier_set_hash::<NativeHasher>(
+            &new_verifier_set.verifier_set(),
+            &metadata.domain_separator,
+        )
+        .unwrap(),
+        Some(new_verifier_set_leaf),
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn rotation_details_report_the_proven_leaf_of_the_new_verifier_set() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+    let new_verifier_set = make_verifier_set(&[500, 200, 100], 1, metadata.domain_separator);
+    let leaf_proof = first_leaf_proof(&mut metadata, &new_verifier_set);
+    let ix = rotate_signers_with_leaf_proof_ix(&mut metadata, &new_verifier_set, leaf_proof).await;
+
+    // Action
+    let simulation_result = metadata.simulate_tx(&[ix.clone()]).await.unwrap();
+    metadata.send_tx(&[ix]).await.unwrap();
+
+    // Assert
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    let expected_event = VerifierSetRotationDetailsEvent {
+        previous_epoch: U256::ONE,
+        epoch: 2_u128.into(),
+        previous_verifier_set_hash: verifier_set_hash::<NativeHasher>(
+            &metadata.signers.verifier_set(),
+            &metadata.domain_separator,
+        )
+        .unwrap(),
+        verifier_set_hash: verifier_set_hash::<NativeHasher>(
+            &new_verifier_set.verifier_set(),
+            &metadata.domain_separator,
+        )
+        .unwrap(),
+        threshold: Some(new_verifier_set.verifier_set().quorum),
+        signer_count: Some(3),
+        rotation_delay_enforced: true,
+    };
+    assert_event_cpi(&expected_event, &inner_ixs);
+}
+
+#[tokio::test]
+async fn rotates_to_an_ed25519_verifier_set_verified_by_the_ed25519_program() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+    let new_verifier_set = make_ed25519_verifier_set(&[500, 200], 1, metadata.domain_separator);
+    let leaf_proof = first_leaf_proof(&mut metadata, &new_verifier_set);
+    let ix = rotate_signers_with_leaf_proof_ix(&mut metadata, &new_verifier_set, leaf_proof).await;
+
+    // Action
+    metadata.send_tx(&[ix]).await.unwrap();
+
+    // Assert - the new verifier set signs with Ed25519 keys
+    let tracker = metadata
+        .verifier_set_tracker(new_verifier_set.verifier_set_tracker().0)
+        .await;
+    assert_eq!(tracker.signature_scheme(), Ok(SignatureScheme::Ed25519));
+
+    // Assert - its signatures are verified through the Ed25519 program
+    let payload = Payload::Messages(Messages(make_messages(1)));
+    let execute_data = metadata.construct_execute_data(&new_verifier_set, payload);
+    let verification_session_account = metadata
+        .init_payload_session_and_verify(&execute_data)
+        .await
+        .unwrap();
+    let session = metadata
+        .signature_verification_session(verification_session_account)
+        .await;
+    assert!(session.signature_verification.is_valid());
+}
+
+#[tokio::test]
+async fn fail_to_verify_ed25519_signature_without_the_ed25519_program() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+    let new_verifier_set = make_ed25519_verifier_set(&[500, 200], 1, metadata.domain_separator);
+    let leaf_proof = first_leaf_proof(&mut metadata, &new_verifier_set);
+    let ix = rotate_signers_with_leaf_proof_ix(&mut metadata, &new_verifier_set, leaf_proof).await;
+    metadata.send_tx(&[ix]).await.unwrap();
+
+    let payload = Payload::Messages(Messages(make_messages(1)));
+    let execute_data = metadata.construct_execute_data(&new_verifier_set, payload);
+    metadata
+        .initialize_payload_verification_session(&execute_data)
+        .await
+        .unwrap();
+    let (verification_session_account, _) = axelar_solana_gateway::get_signature_verification_pda(
+        &execute_data.payload_merkle_root,
+        &execute_data.signing_verifier_set_merkle_root,
+    );
+
+    // Action
+    let ix = axelar_solana_gateway::instructions::verify_signature(
+        metadata.gateway_root_pda,
+        new_verifier_set.verifier_set_tracker().0,
+        verification_session_account,
+        execute_data.payload_merkle_root,
+        execute_data.signing_verifier_set_leaves[0].clone(),
+    )
+    .unwrap();
+    let tx = metadata.send_tx(&[ix]).await.unwrap_err();
+
+    // Assert
+    assert!(tx.metadata.unwrap().log_messages.into_iter().any(
+        |log| log.contains("Ed25519 signatures must be verified by the preceding instruction")
+    ));
+    let session = metadata
+        .signature_verification_session(verification_session_account)
+        .await;
+    assert!(session
+        .signature_verification
+        .slots_iter()
+        .all(|slot| !slot));
+}
+
+#[tokio::test]
+async fn fail_if_new_verifier_set_leaf_is_not_part_of_the_new_verifier_set() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+    let new_verifier_set = make_verifier_set(&[500, 200], 1, metadata.domain_separator);
+    let leaf_proof = first_leaf_proof(&mut metadata, &metadata.signers.clone());
+    let ix = rotate_signers_with_leaf_proof_ix(&mut metadata, &new_verifier_set, leaf_proof).await;
+
+    // Action
+    let tx = metadata.send_tx(&[ix]).await.unwrap_err();
+
+    // Assert
+    assert_eq!(
+        tx.get_gateway_error().unwrap(),
+        GatewayError::InvalidMerkleProof
+    );
+}
+
 #[tokio::test]
 async fn fail_when_approve_messages_payload_hash_is_used() {
     // Setup
@@ -0,0 +1,132 @@
+use axelar_solana_encoding::hasher::NativeHasher;
+use axelar_solana_encoding::types::verifier_set::verifier_set_hash;
+use axelar_solana_gateway::instructions::state_digest;
+use axelar_solana_gateway::state::verifier_set_tracker::VerifierSetHash;
+use axelar_solana_gateway_test_fixtures::gateway::make_verifier_set;
+use axelar_solana_gateway_test_fixtures::test_signer::SigningVerifierSet;
+use axelar_solana_gateway_test_fixtures::{
+    SolanaAxelarIntegration, SolanaAxelarIntegrationMetadata,
+};
+use pretty_assertions::assert_eq;
+use solana_program_test::tokio;
+
+fn signers_hash(
+    metadata: &SolanaAxelarIntegrationMetadata,
+    signers: &SigningVerifierSet,
+) -> VerifierSetHash {
+    verifier_set_hash::<NativeHasher>(&signers.verifier_set(), &metadata.domain_separator).unwrap()
+}
+
+async fn query_state_digest(
+    metadata: &mut SolanaAxelarIntegrationMetadata,
+    current_verifier_set_hash: VerifierSetHash,
+    previous_verifier_set_hash: Option<VerifierSetHash>,
+) -> Option<[u8; 32]> {
+    let ix = state_digest(current_verifier_set_hash, previous_verifier_set_hash).unwrap();
+    let simulation_result = metadata.simulate_tx(&[ix]).await.unwrap();
+    if simulation_result
+        .result
+        .as_ref()
+        .is_some_and(Result::is_err)
+    {
+        return None;
+    }
+    let return_data = simulation_result
+        .simulation_details
+        .unwrap()
+        .return_data
+        .unwrap();
+    assert_eq!(return_data.program_id, axelar_solana_gateway::ID);
+
+    Some(return_data.data.try_into().unwrap())
+}
+
+#[tokio::test]
+async fn state_digest_matches_the_initial_state() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+    let initial_signers = metadata.signers.clone();
+    let initial_hash = signers_hash(&metadata, &initial_signers);
+
+    // Action
+    let digest = query_state_digest(&mut metadata, initial_hash, None).await;
+
+    // Assert
+    let config = metadata.gateway_config(metadata.gateway_root_pda).await;
+    let tracker = metadata
+        .verifier_set_tracker(initial_signers.verifier_set_tracker().0)
+        .await;
+    assert_eq!(digest, Some(config.state_digest(&tracker, None)));
+}
+
+#[tokio::test]
+async fn state_digest_covers_the_previous_verifier_set_after_rotation() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+    let initial_signers = metadata.signers.clone();
+    let initial_hash = signers_hash(&metadata, &initial_signers);
+    let digest_before_rotation = query_state_digest(&mut metadata, initial_hash, None)
+        .await
+        .unwrap();
+
+    let new_signers = make_verifier_set(&[500, 200], 1, metadata.domain_separator);
+    let new_hash = signers_hash(&metadata, &new_signers);
+    let (_, rotation) = metadata
+        .sign_session_and_rotate_signers(&initial_signers, &new_signers.verifier_set())
+        .await
+        .unwrap();
+    rotation.unwrap();
+
+    // Action
+    let digest = query_state_digest(&mut metadata, new_hash, Some(initial_hash)).await;
+
+    // Assert
+    let config = metadata.gateway_config(metadata.gateway_root_pda).await;
+    let current = metadata
+        .verifier_set_tracker(new_signers.verifier_set_tracker().0)
+        .await;
+    let previous = metadata
+        .verifier_set_tracker(initial_signers.verifier_set_tracker().0)
+        .await;
+    let expected = config.state_digest(&current, Some(&previous));
+    assert_eq!(digest, Some(expected));
+    assert_ne!(expected, digest_before_rotation);
+}
+
+#[tokio::test]
+async fn state_digest_rejects_stale_or_missing_verifier_sets() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 42])
+        .build()
+        .setup()
+        .await;
+    let initial_signers = metadata.signers.clone();
+    let initial_hash = signers_hash(&metadata, &initial_signers);
+    let new_signers = make_verifier_set(&[500, 200], 1, metadata.domain_separator);
+    let new_hash = signers_hash(&metadata, &new_signers);
+    let (_, rotation) = metadata
+        .sign_session_and_rotate_signers(&initial_signers, &new_signers.verifier_set())
+        .await
+        .unwrap();
+    rotation.unwrap();
+
+    // Action & Assert: the previous verifier set can't be passed as the current one
+    assert_eq!(
+        query_state_digest(&mut metadata, initial_hash, Some(new_hash)).await,
+        None
+    );
+    // Action & Assert: the previous verifier set is required after a rotation
+    assert_eq!(
+        query_state_digest(&mut metadata, new_hash, None).await,
+        None
+    );
+}
@@ -0,0 +1,288 @@
+//! Property-based model test for the approval / verification state machine.
+//!
+//! Random sequences of `initialize_payload_verification_session`,
+//! `verify_signature`, `approve_message` and `rotate_signers` are replayed
+//! against program-test while a simple model predicts which of them must
+//! succeed. After every step the on-chain state is checked against the model.
+
+use axelar_message_primitives::U256;
+use axelar_solana_encoding::hasher::NativeHasher;
+use axelar_solana_encoding::types::execute_data::{ExecuteData, MerkleisedPayload};
+use axelar_solana_encoding::types::messages::Messages;
+use axelar_solana_encoding::types::payload::Payload;
+use axelar_solana_encoding::types::verifier_set::verifier_set_hash;
+use axelar_solana_gateway::state::incoming_message::command_id;
+use axelar_solana_gateway::{
+    get_incoming_message_pda, get_signature_verification_pda, get_verifier_set_tracker_pda,
+};
+use axelar_solana_gateway_test_fixtures::gateway::{make_messages, make_verifier_set};
+use axelar_solana_gateway_test_fixtures::{
+    SolanaAxelarIntegration, SolanaAxelarIntegrationMetadata,
+};
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+use solana_program_test::tokio;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+
+const SIGNER_WEIGHTS: [u128; 3] = [10, 10, 10];
+const QUORUM: u128 = 20;
+const BATCHES: usize = 2;
+const MESSAGES_PER_BATCH: usize = 2;
+/// Message batches first, followed by the signer rotation payload
+const SESSIONS: usize = BATCHES + 1;
+const ROTATION_SESSION: usize = BATCHES;
+
+#[derive(Debug, Clone)]
+enum Op {
+    InitSession { session: usize },
+    VerifySignature { session: usize, signer: usize },
+    ApproveMessage { batch: usize, message: usize },
+    RotateSigners,
+}
+
+fn op_strategy() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        (0..SESSIONS).prop_map(|session| Op::InitSession { session }),
+        (0..SESSIONS, 0..SIGNER_WEIGHTS.len())
+            .prop_map(|(session, signer)| Op::VerifySignature { session, signer }),
+        (0..BATCHES, 0..MESSAGES_PER_BATCH)
+            .prop_map(|(batch, message)| Op::ApproveMessage { batch, message }),
+        Just(Op::RotateSigners),
+    ]
+}
+
+/// What the gateway is expected to hold after each successful operation.
+#[derive(Debug, Default)]
+struct Model {
+    initialized: [bool; SESSIONS],
+    verified: [[bool; SIGNER_WEIGHTS.len()]; SESSIONS],
+    approved: [[bool; MESSAGES_PER_BATCH]; BATCHES],
+    rotated: bool,
+}
+
+impl Model {
+    fn has_quorum(&self, session: usize) -> bool {
+        let weight: u128 = self.verified[session]
+            .iter()
+            .zip(SIGNER_WEIGHTS)
+            .filter_map(|(verified, weight)| verified.then_some(weight))
+            .sum();
+        weight >= QUORUM
+    }
+
+    /// The initial verifier set expires as soon as it gets rotated, because
+    /// the default retention only keeps the latest set.
+    fn expect_success(&self, op: &Op) -> bool {
+        match *op {
+            Op::InitSession { session } => !self.initialized[session] && !self.rotated,
+            Op::VerifySignature { session, signer } => {
+                self.initialized[session]
+                    && !self.has_quorum(session)
+                    && !self.verified[session][signer]
+                    && !self.rotated
+            }
+            Op::ApproveMessage { batch, message } => {
+                self.initialized[batch] && self.has_quorum(batch) && !self.approved[batch][message]
+            }
+            Op::RotateSigners => {
+                self.initialized[ROTATION_SESSION]
+                    && self.has_quorum(ROTATION_SESSION)
+                    && !self.rotated
+            }
+        }
+    }
+
+    fn apply(&mut self, op: &Op) {
+        match *op {
+            Op::InitSession { session } => self.initialized[session] = true,
+            Op::VerifySignature { session, signer } => self.verified[session][signer] = true,
+            Op::ApproveMessage { batch, message } => self.approved[batch][message] = true,
+            Op::RotateSigners => self.rotated = true,
+        }
+    }
+}
+
+struct Harness {
+    metadata: SolanaAxelarIntegrationMetadata,
+    sessions: Vec<ExecuteData>,
+    new_verifier_set_hash: [u8; 32],
+}
+
+impl Harness {
+    async fn new() -> Self {
+        let mut metadata = SolanaAxelarIntegration::builder()
+            .initial_signer_weights(SIGNER_WEIGHTS.to_vec())
+            .custom_quorum(QUORUM)
+            .build()
+            .setup()
+            .await;
+
+        let signers = metadata.signers.clone();
+        let mut sessions = (0..BATCHES)
+            .map(|_| {
+                let payload = Payload::Messages(Messages(make_messages(MESSAGES_PER_BATCH)));
+                metadata.construct_execute_data(&signers, payload)
+            })
+            .collect::<Vec<_>>();
+
+        let new_verifier_set = make_verifier_set(&[42], 1, metadata.domain_separator);
+        let new_verifier_set_hash = verifier_set_hash::<NativeHasher>(
+            &new_verifier_set.verifier_set(),
+            &metadata.domain_separator,
+        )
+        .unwrap();
+        let payload = Payload::NewVerifierSet(new_verifier_set.verifier_set());
+        sessions.push(metadata.construct_execute_data(&signers, payload));
+
+        Self {
+            metadata,
+            sessions,
+            new_verifier_set_hash,
+        }
+    }
+
+    fn session_pda(&self, session: usize) -> Pubkey {
+        let execute_data = &self.sessions[session];
+        get_signature_verification_pda(
+            &execute_data.payload_merkle_root,
+            &execute_data.signing_verifier_set_merkle_root,
+        )
+        .0
+    }
+
+    fn instruction(&self, op: &Op) -> Instruction {
+        let gateway_root_pda = self.metadata.gateway_root_pda;
+        let payer = self.metadata.payer.pubkey();
+        match *op {
+            Op::InitSession { session } => {
+                let execute_data = &self.sessions[session];
+                axelar_solana_gateway::instructions::initialize_payload_verification_session(
+                    payer,
+                    gateway_root_pda,
+                    execute_data.payload_merkle_root,
+                    execute_data.signing_verifier_set_merkle_root,
+                )
+                .unwrap()
+            }
+            Op::VerifySignature { session, signer } => {
+                let execute_data = &self.sessions[session];
+                axelar_solana_gateway::instructions::verify_signature(
+                    gateway_root_pda,
+                    self.metadata.signers.verifier_set_tracker().0,
+                    self.session_pda(session),
+                    execute_data.payload_merkle_root,
+                    execute_data.signing_verifier_set_leaves[signer].clone(),
+                )
+                .unwrap()
+            }
+            Op::ApproveMessage { batch, message } => {
+                let execute_data = &self.sessions[batch];
+                let MerkleisedPayload::NewMessages { ref messages } = execute_data.payload_items
+                else {
+                    unreachable!("message batches come first");
+                };
+                let message = messages[message].clone();
+                let cc_id = &message.leaf.message.cc_id;
+                let (incoming_message_pda, _) =
+                    get_incoming_message_pda(&command_id(&cc_id.chain, &cc_id.id));
+                axelar_solana_gateway::instructions::approve_message(
+                    message,
+                    execute_data.payload_merkle_root,
+                    gateway_root_pda,
+                    payer,
+                    self.session_pda(batch),
+                    incoming_message_pda,
+                )
+                .unwrap()
+            }
+            Op::RotateSigners => axelar_solana_gateway::instructions::rotate_signers(
+                gateway_root_pda,
+                self.session_pda(ROTATION_SESSION),
+                self.metadata.signers.verifier_set_tracker().0,
+                get_verifier_set_tracker_pda(self.new_verifier_set_hash).0,
+                payer,
+                None,
+                self.new_verifier_set_hash,
+            )
+            .unwrap(),
+        }
+    }
+
+    async fn current_epoch(&mut self) -> U256 {
+        let gateway_root_pda = self.metadata.gateway_root_pda;
+        self.metadata
+            .gateway_config(gateway_root_pda)
+            .await
+            .current_epoch
+    }
+}
+
+async fn run(ops: Vec<Op>) -> Result<(), TestCaseError> {
+    let mut harness = Harness::new().await;
+    let mut model = Model::default();
+    let mut previous_epoch = harness.current_epoch().await;
+
+    for (step, op) in (0_u32..).zip(&ops) {
+        let expected = model.expect_success(op);
+
+        // The step number keeps otherwise identical transactions apart, so a
+        // repeated operation is rejected by the gateway and not deduplicated
+        // by the bank. Signature verification needs the bumped budget anyway.
+        let ix = harness.instruction(op);
+        let result = harness
+            .metadata
+            .send_tx(&[
+                ComputeBudgetInstruction::set_compute_unit_limit(250_000_u32.saturating_add(step)),
+                ix,
+            ])
+            .await;
+        prop_assert_eq!(
+            result.is_ok(),
+            expected,
+            "step {} ({:?}) diverged from the model",
+            step,
+            op
+        );
+        if expected {
+            model.apply(op);
+        }
+
+        // Invariant: an approval is only possible with a quorum-backed session
+        if let (true, Op::ApproveMessage { batch, .. }) = (expected, op) {
+            let session_pda = harness.session_pda(*batch);
+            let session = harness
+                .metadata
+                .signature_verification_session(session_pda)
+                .await;
+            prop_assert!(session.signature_verification.is_valid());
+        }
+
+        // Invariant: the epoch never decreases and only moves on rotation
+        let epoch = harness.current_epoch().await;
+        let expected_epoch = if expected && matches!(op, Op::RotateSigners) {
+            previous_epoch.checked_add(U256::ONE).unwrap()
+        } else {
+            previous_epoch
+        };
+        prop_assert_eq!(epoch, expected_epoch);
+        previous_epoch = epoch;
+    }
+
+    Ok(())
+}
+
+proptest! {
+    // Every case boots a fresh program-test bank, keep the amount modest
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn approval_state_machine_upholds_invariants(
+        ops in prop::collection::vec(op_strategy(), 1..32)
+    ) {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(run(ops))?;
+    }
+}
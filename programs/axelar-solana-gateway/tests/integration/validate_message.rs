@@ -192,7 +192,12 @@ async fn set_existing_incoming_message_state(
         .unwrap();
 
     incoming_message
-        .write(&mut raw_account.data)
+        .write(
+            raw_account
+                .data
+                .get_mut(..IncomingMessage::pda_size())
+                .unwrap(),
+        )
         .expect("must overwrite PDA");
 
     metadata.set_account_state(&incoming_message_pda, raw_account);
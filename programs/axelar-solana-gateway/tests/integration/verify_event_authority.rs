@@ -0,0 +1,140 @@
+use axelar_solana_gateway::error::GatewayError;
+use axelar_solana_gateway::events::EventAuthorityVerifiedEvent;
+use axelar_solana_gateway::get_event_authority_pda;
+use axelar_solana_gateway::instructions::verify_event_authority;
+use axelar_solana_gateway_test_fixtures::gateway::GetGatewayError;
+use axelar_solana_gateway_test_fixtures::SolanaAxelarIntegration;
+use event_cpi_test_utils::assert_event_cpi;
+use pretty_assertions::assert_eq;
+use solana_program_test::tokio;
+use solana_sdk::account::Account;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+#[tokio::test]
+async fn successfully_verify_event_authority() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![11, 42, 33])
+        .build()
+        .setup()
+        .await;
+    let operator = metadata.operator.insecure_clone();
+    let (event_authority, bump) = get_event_authority_pda();
+
+    // Action
+    let ix = verify_event_authority(operator.pubkey()).unwrap();
+    let simulation_result = metadata
+        .simulate_tx_with_custom_signers(
+            &[ix.clone()],
+            &[&operator, &metadata.payer.insecure_clone()],
+        )
+        .await
+        .unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+
+    // Assert
+    assert_event_cpi(
+        &EventAuthorityVerifiedEvent {
+            event_authority,
+            bump,
+        },
+        &inner_ixs,
+    );
+    metadata
+        .send_tx_with_custom_signers(&[ix], &[&operator, &metadata.payer.insecure_clone()])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn fail_if_signer_is_not_operator_or_upgrade_authority() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![11, 42, 33])
+        .build()
+        .setup()
+        .await;
+    let stranger_danger = Keypair::new();
+
+    // Action
+    let ix = verify_event_authority(stranger_danger.pubkey()).unwrap();
+    let err = metadata
+        .send_tx_with_custom_signers(&[ix], &[&stranger_danger, &metadata.payer.insecure_clone()])
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert_eq!(
+        err.get_gateway_error().unwrap(),
+        GatewayError::InvalidOperatorOrAuthorityAccount
+    );
+}
+
+#[tokio::test]
+async fn fail_if_event_authority_is_not_derived_from_gateway() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![11, 42, 33])
+        .build()
+        .setup()
+        .await;
+    let operator = metadata.operator.insecure_clone();
+
+    // Action
+    let mut ix = verify_event_authority(operator.pubkey()).unwrap();
+    ix.accounts[3].pubkey = Pubkey::new_unique();
+    let err = metadata
+        .send_tx_with_custom_signers(&[ix], &[&operator, &metadata.payer.insecure_clone()])
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert_eq!(
+        err.get_gateway_error().unwrap(),
+        GatewayError::InvalidEventAuthority
+    );
+}
+
+#[tokio::test]
+async fn fail_if_event_authority_holds_data() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![11, 42, 33])
+        .build()
+        .setup()
+        .await;
+    let operator = metadata.operator.insecure_clone();
+    let (event_authority, _) = get_event_authority_pda();
+    metadata.set_account_state(
+        &event_authority,
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![1; 8],
+            owner: Pubkey::new_unique(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Action
+    let ix = verify_event_authority(operator.pubkey()).unwrap();
+    let err = metadata
+        .send_tx_with_custom_signers(&[ix], &[&operator, &metadata.payer.insecure_clone()])
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert_eq!(
+        err.get_gateway_error().unwrap(),
+        GatewayError::InvalidEventAuthority
+    );
+}
@@ -353,6 +353,78 @@ async fn test_large_weight_will_validate_whole_batch() {
     );
 }
 
+#[tokio::test]
+async fn test_signatures_after_quorum_are_rejected() {
+    // Setup
+    let mut metadata = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42, 43])
+        .custom_quorum(42)
+        .build()
+        .setup()
+        .await;
+    let payload = Payload::Messages(Messages(vec![random_message()]));
+    let execute_data = metadata.construct_execute_data(&metadata.signers.clone(), payload);
+    metadata
+        .initialize_payload_verification_session(&execute_data)
+        .await
+        .unwrap();
+    let verifier_set_tracker_pda = metadata.signers.verifier_set_tracker().0;
+    let (verification_session_pda, _) = axelar_solana_gateway::get_signature_verification_pda(
+        &execute_data.payload_merkle_root,
+        &execute_data.signing_verifier_set_merkle_root,
+    );
+    let mut ixs = execute_data
+        .signing_verifier_set_leaves
+        .iter()
+        .map(|leaf_info| {
+            axelar_solana_gateway::instructions::verify_signature(
+                metadata.gateway_root_pda,
+                verifier_set_tracker_pda,
+                verification_session_pda,
+                execute_data.payload_merkle_root,
+                leaf_info.clone(),
+            )
+            .unwrap()
+        });
+
+    // Any signature reaches the quorum
+    metadata
+        .send_tx(&[
+            ComputeBudgetInstruction::set_compute_unit_limit(260_000),
+            ixs.next().unwrap(),
+        ])
+        .await
+        .unwrap();
+
+    // Action
+    let tx_result = metadata
+        .send_tx(&[
+            ComputeBudgetInstruction::set_compute_unit_limit(260_000),
+            ixs.next().unwrap(),
+        ])
+        .await
+        .unwrap_err();
+
+    // Assert
+    let gateway_error = tx_result.get_gateway_error().unwrap();
+    assert_eq!(gateway_error, GatewayError::SigningSessionAlreadyValid);
+    assert!(gateway_error.should_relayer_proceed());
+
+    let session = metadata
+        .signature_verification_session(verification_session_pda)
+        .await;
+    assert!(session.signature_verification.is_valid());
+    assert_eq!(
+        session
+            .signature_verification
+            .slots_iter()
+            .filter(|slot| *slot)
+            .count(),
+        1,
+        "only the signature reaching the quorum should be verified"
+    );
+}
+
 #[tokio::test]
 async fn fail_verification_if_non_registered_verifier_set_signed_batch() {
     // Setup
@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use std::time::SystemTime;
 
 use axelar_solana_encoding::types::messages::{CrossChainId, Message};
@@ -27,6 +28,14 @@ use crate::fixtures::{
 };
 
 pub(crate) async fn setup_programs() -> (SolanaAxelarIntegrationMetadata, Pubkey, Pubkey) {
+    setup_programs_with(vec![]).await
+}
+
+/// Same as [`setup_programs`], also deploying `extra_programs` with the
+/// upgrade authority of the integration.
+pub(crate) async fn setup_programs_with(
+    extra_programs: Vec<(PathBuf, Pubkey)>,
+) -> (SolanaAxelarIntegrationMetadata, Pubkey, Pubkey) {
     let mut fixture = TestFixture::new(ProgramTest::default()).await;
     let upgrade_authority = Keypair::new();
 
@@ -41,10 +50,15 @@ pub(crate) async fn setup_programs() -> (SolanaAxelarIntegrationMetadata, Pubkey
     // Setup gateway
     let mut sol_integration = SolanaAxelarIntegration::builder()
         .initial_signer_weights(vec![555, 222])
-        .programs_to_deploy(vec![(
-            "axelar_solana_memo_program.so".into(),
-            axelar_solana_memo_program::id(),
-        )])
+        .programs_to_deploy(
+            [(
+                "axelar_solana_memo_program.so".into(),
+                axelar_solana_memo_program::id(),
+            )]
+            .into_iter()
+            .chain(extra_programs)
+            .collect(),
+        )
         .build()
         .setup_with_fixture_and_authority(fixture, upgrade_authority.insecure_clone())
         .await;
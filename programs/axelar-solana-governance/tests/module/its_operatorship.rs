@@ -0,0 +1,136 @@
+use axelar_solana_governance::instructions::builder::IxBuilder;
+use axelar_solana_governance::state::GovernanceConfig;
+use axelar_solana_its::Roles;
+use borsh::BorshDeserialize;
+use role_management::state::UserRoles;
+use solana_program_test::tokio;
+use solana_sdk::instruction::AccountMeta;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use solana_sdk::system_instruction;
+
+use crate::fixtures::operator_keypair;
+use crate::helpers::{
+    approve_ix_at_gateway, default_proposal_eta, gmp_sample_metadata, setup_programs_with,
+};
+
+async fn its_roles(
+    sol_integration: &mut axelar_solana_gateway_test_fixtures::SolanaAxelarIntegrationMetadata,
+    user: &Pubkey,
+) -> UserRoles<Roles> {
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (roles_pda, _) =
+        role_management::find_user_roles_pda(&axelar_solana_its::id(), &its_root_pda, user);
+    let data = sol_integration
+        .fixture
+        .get_account(&roles_pda, &axelar_solana_its::id())
+        .await
+        .data;
+
+    UserRoles::<Roles>::try_from_slice(&data).unwrap()
+}
+
+/// The ITS operator proposes operatorship to the governance config PDA, which
+/// accepts it through an operator proposal whose execution signs for the PDA.
+#[tokio::test]
+async fn test_governance_pda_accepts_its_operatorship_by_cpi() {
+    // Setup
+    let (mut sol_integration, config_pda, _) = Box::pin(setup_programs_with(vec![(
+        "axelar_solana_its.so".into(),
+        axelar_solana_its::id(),
+    )]))
+    .await;
+    assert_eq!(config_pda, GovernanceConfig::pda().0);
+
+    let its_operator = sol_integration.fixture.payer.pubkey();
+    let upgrade_authority = sol_integration.upgrade_authority.insecure_clone();
+    let payer = sol_integration.fixture.payer.insecure_clone();
+    let ixs = [
+        system_instruction::transfer(&its_operator, &upgrade_authority.pubkey(), u32::MAX.into()),
+        axelar_solana_its::instruction::initialize(
+            upgrade_authority.pubkey(),
+            its_operator,
+            "solana".to_owned(),
+            "axelar157hl7gpuknjmhtac2qnphuazv2yerfagva7lsu9vuj2pgn32z22qa26dk4".to_owned(),
+        )
+        .unwrap(),
+    ];
+    sol_integration
+        .fixture
+        .send_tx_with_custom_signers(&ixs, &[&upgrade_authority, &payer])
+        .await
+        .unwrap();
+
+    let propose_ix = axelar_solana_its::instruction::propose_operatorship(
+        its_operator,
+        its_operator,
+        config_pda,
+    )
+    .unwrap();
+    sol_integration
+        .fixture
+        .send_tx(&[propose_ix])
+        .await
+        .unwrap();
+
+    // Send the proposal accepting the operatorship via GMP, acting as Axelar
+    // governance infrastructure.
+    let accept_ix =
+        axelar_solana_its::instruction::accept_operatorship(its_operator, config_pda, its_operator)
+            .unwrap();
+    let mut its_accounts = accept_ix.accounts.clone();
+    its_accounts.push(AccountMeta::new_readonly(axelar_solana_its::id(), false));
+
+    let ix_builder = IxBuilder::new().with_proposal_data(
+        axelar_solana_its::id(),
+        0,
+        default_proposal_eta(),
+        None,
+        &its_accounts,
+        accept_ix.data,
+    );
+
+    let mut gmp_call_data = ix_builder
+        .clone()
+        .gmp_ix()
+        .with_msg_metadata(gmp_sample_metadata())
+        .schedule_time_lock_proposal(&its_operator, &config_pda)
+        .build();
+    approve_ix_at_gateway(&mut sol_integration, &mut gmp_call_data).await;
+    sol_integration
+        .fixture
+        .send_tx(&[gmp_call_data.ix])
+        .await
+        .unwrap();
+
+    let mut gmp_call_data = ix_builder
+        .clone()
+        .gmp_ix()
+        .with_msg_metadata(gmp_sample_metadata())
+        .approve_operator_proposal(&its_operator, &config_pda)
+        .build();
+    approve_ix_at_gateway(&mut sol_integration, &mut gmp_call_data).await;
+    sol_integration
+        .fixture
+        .send_tx(&[gmp_call_data.ix])
+        .await
+        .unwrap();
+
+    // Action: the governance program accepts with its config PDA as signer
+    let operator = operator_keypair();
+    let ix = ix_builder
+        .execute_operator_proposal(&config_pda, &operator.pubkey())
+        .build();
+    sol_integration
+        .fixture
+        .send_tx_with_custom_signers(&[ix], &[operator, payer])
+        .await
+        .unwrap();
+
+    // Assert
+    let governance_roles = its_roles(&mut sol_integration, &config_pda).await;
+    assert!(governance_roles.contains(Roles::OPERATOR));
+
+    let previous_operator_roles = its_roles(&mut sol_integration, &its_operator).await;
+    assert!(!previous_operator_roles.contains(Roles::OPERATOR));
+}
@@ -21,6 +21,7 @@ mod gateway_upgrade;
 mod gmp;
 mod helpers;
 mod initialize_config;
+mod its_operatorship;
 mod transfer_operatorship;
 mod update_config;
 mod withdraw_tokens;
@@ -15,6 +15,8 @@ use spl_token_2022::check_spl_token_program_account;
 use spl_token_2022::extension::StateWithExtensions;
 use spl_token_2022::state::Account as TokenAccount;
 
+use crate::error::ItsError;
+
 /// Checks if an account is a valid Token account for the given mint and owner.
 pub(crate) fn is_valid_token_account(
     account: &AccountInfo,
@@ -144,8 +146,9 @@ pub(crate) struct CallContractAccounts<'a> {
     pub(crate) gateway_event_authority: &'a AccountInfo<'a>,
     pub(crate) gateway_program: &'a AccountInfo<'a>,
     pub(crate) gas_service_root: &'a AccountInfo<'a>,
+    pub(crate) gas_service_paused_chains: &'a AccountInfo<'a>,
     pub(crate) gas_service_event_authority: &'a AccountInfo<'a>,
-    pub(crate) _gas_service_program: &'a AccountInfo<'a>,
+    pub(crate) gas_service_program: &'a AccountInfo<'a>,
     pub(crate) system_program: &'a AccountInfo<'a>,
     pub(crate) its_root: &'a AccountInfo<'a>,
     pub(crate) call_contract_signing: &'a AccountInfo<'a>,
@@ -155,7 +158,19 @@ pub(crate) struct CallContractAccounts<'a> {
 impl Validate for CallContractAccounts<'_> {
     fn validate(&self) -> Result<(), ProgramError> {
         validate_system_account_key(self.system_program.key)?;
-        axelar_solana_gateway::check_program_account(*self.gateway_program.key)?;
+
+        if *self.gateway_program.key != axelar_solana_gateway::ID {
+            msg!("Unexpected gateway program: {}", self.gateway_program.key);
+            return Err(ItsError::InvalidGatewayProgram.into());
+        }
+
+        if *self.gas_service_program.key != axelar_solana_gas_service::ID {
+            msg!(
+                "Unexpected gas service program: {}",
+                self.gas_service_program.key
+            );
+            return Err(ItsError::InvalidGasServiceProgram.into());
+        }
 
         Ok(())
     }
@@ -175,8 +190,9 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for CallContractAccounts<'a> {
             gateway_event_authority: next_account_info(accounts_iter)?,
             gateway_program: next_account_info(accounts_iter)?,
             gas_service_root: next_account_info(accounts_iter)?,
+            gas_service_paused_chains: next_account_info(accounts_iter)?,
             gas_service_event_authority: next_account_info(accounts_iter)?,
-            _gas_service_program: next_account_info(accounts_iter)?,
+            gas_service_program: next_account_info(accounts_iter)?,
             system_program: next_account_info(accounts_iter)?,
             its_root: next_account_info(accounts_iter)?,
             call_contract_signing: next_account_info(accounts_iter)?,
@@ -257,6 +273,8 @@ impl<'a> TryFrom<DeployCanonicalTokenAccounts<'a>> for DeployTokenManagerAccount
             rent_sysvar: value.rent_sysvar,
             operator: None,
             operator_roles: None,
+            flow_limiter: None,
+            flow_limiter_roles: None,
             __event_cpi_authority_info: value.__event_cpi_authority_info,
             __event_cpi_program_account: value.__event_cpi_program_account,
         };
@@ -341,6 +359,8 @@ impl<'a> TryFrom<DeployCustomTokenAccounts<'a>> for DeployTokenManagerAccounts<'
             rent_sysvar: value.rent_sysvar,
             operator: value.operator,
             operator_roles: value.operator_roles,
+            flow_limiter: None,
+            flow_limiter_roles: None,
             __event_cpi_authority_info: value.__event_cpi_authority_info,
             __event_cpi_program_account: value.__event_cpi_program_account,
         };
@@ -366,11 +386,15 @@ pub(crate) struct TakeTokenAccounts<'a> {
     pub(crate) gateway_event_authority: &'a AccountInfo<'a>,
     pub(crate) gateway_program: &'a AccountInfo<'a>,
     pub(crate) gas_service_root: &'a AccountInfo<'a>,
+    pub(crate) gas_service_paused_chains: &'a AccountInfo<'a>,
     pub(crate) gas_service_event_authority: &'a AccountInfo<'a>,
     pub(crate) gas_service_program: &'a AccountInfo<'a>,
     pub(crate) system_program: &'a AccountInfo<'a>,
     pub(crate) call_contract_signing: &'a AccountInfo<'a>,
     pub(crate) its_program: &'a AccountInfo<'a>,
+    pub(crate) outbound_nonce: &'a AccountInfo<'a>,
+    pub(crate) token_observer: &'a AccountInfo<'a>,
+    pub(crate) token_observer_program: Option<&'a AccountInfo<'a>>,
 }
 
 impl Validate for TakeTokenAccounts<'_> {
@@ -415,11 +439,15 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for TakeTokenAccounts<'a> {
             gateway_event_authority: next_account_info(accounts_iter)?,
             gateway_program: next_account_info(accounts_iter)?,
             gas_service_root: next_account_info(accounts_iter)?,
+            gas_service_paused_chains: next_account_info(accounts_iter)?,
             gas_service_event_authority: next_account_info(accounts_iter)?,
             gas_service_program: next_account_info(accounts_iter)?,
             system_program: next_account_info(accounts_iter)?,
             call_contract_signing: next_account_info(accounts_iter)?,
             its_program: next_account_info(accounts_iter)?,
+            outbound_nonce: next_account_info(accounts_iter)?,
+            token_observer: next_account_info(accounts_iter)?,
+            token_observer_program: next_optional_account_info(accounts_iter, &crate::ID)?,
             __event_cpi_authority_info: next_account_info(accounts_iter)?,
             __event_cpi_program_account: next_account_info(accounts_iter)?,
         };
@@ -440,8 +468,9 @@ impl<'a> TryFrom<TakeTokenAccounts<'a>> for CallContractAccounts<'a> {
             gateway_event_authority: value.gateway_event_authority,
             gateway_program: value.gateway_program,
             gas_service_root: value.gas_service_root,
+            gas_service_paused_chains: value.gas_service_paused_chains,
             gas_service_event_authority: value.gas_service_event_authority,
-            _gas_service_program: value.gas_service_program,
+            gas_service_program: value.gas_service_program,
             call_contract_signing: value.call_contract_signing,
             program: value.its_program,
             system_program: value.system_program,
@@ -471,6 +500,8 @@ pub(crate) struct GiveTokenAccounts<'a> {
     pub(crate) rent_sysvar: &'a AccountInfo<'a>,
     pub(crate) destination: &'a AccountInfo<'a>,
     pub(crate) destination_ata: &'a AccountInfo<'a>,
+    pub(crate) token_observer: &'a AccountInfo<'a>,
+    pub(crate) token_observer_program: Option<&'a AccountInfo<'a>>,
     pub(crate) interchain_transfer_execute: Option<&'a AccountInfo<'a>>,
     pub(crate) remaining_accounts: &'a [AccountInfo<'a>],
 }
@@ -508,6 +539,11 @@ impl<'a> TryFrom<ExecuteAccounts<'a>> for GiveTokenAccounts<'a> {
             rent_sysvar: value.rent_sysvar,
             destination: next_account_info(remaining_accounts_iter)?,
             destination_ata: next_account_info(remaining_accounts_iter)?,
+            token_observer: next_account_info(remaining_accounts_iter)?,
+            token_observer_program: next_optional_account_info(
+                remaining_accounts_iter,
+                &crate::ID,
+            )?,
             interchain_transfer_execute: next_optional_account_info(
                 remaining_accounts_iter,
                 &crate::ID,
@@ -517,21 +553,14 @@ impl<'a> TryFrom<ExecuteAccounts<'a>> for GiveTokenAccounts<'a> {
             remaining_accounts: remaining_accounts_iter.as_slice(),
         };
 
+        // Otherwise the destination associated token account is created, if
+        // needed, once the destination owner policy of the token is known.
         if is_valid_token_account(
             converted.destination,
             converted.token_program.key,
             converted.mint.key,
         ) {
             converted.destination_ata = converted.destination;
-        } else {
-            crate::create_associated_token_account_idempotent(
-                converted.payer,
-                converted.mint,
-                converted.destination_ata,
-                converted.destination,
-                converted.system_program,
-                converted.token_program,
-            )?;
         }
 
         converted.validate()?;
@@ -616,6 +645,8 @@ pub(crate) struct DeployTokenManagerAccounts<'a> {
     pub(crate) rent_sysvar: &'a AccountInfo<'a>,
     pub(crate) operator: Option<&'a AccountInfo<'a>>,
     pub(crate) operator_roles: Option<&'a AccountInfo<'a>>,
+    pub(crate) flow_limiter: Option<&'a AccountInfo<'a>>,
+    pub(crate) flow_limiter_roles: Option<&'a AccountInfo<'a>>,
 }
 
 impl Validate for DeployTokenManagerAccounts<'_> {
@@ -666,6 +697,14 @@ impl<'a> TryFrom<ExecuteAccounts<'a>> for DeployTokenManagerAccounts<'a> {
             rent_sysvar: value.rent_sysvar,
             operator: next_optional_account_info(accounts_iter, &crate::ID)?,
             operator_roles: next_optional_account_info(accounts_iter, &crate::ID)?,
+            // Absent from accounts derived before link params could name a
+            // flow limiter; the deployment fails if the message names one.
+            flow_limiter: next_optional_account_info(accounts_iter, &crate::ID)
+                .ok()
+                .flatten(),
+            flow_limiter_roles: next_optional_account_info(accounts_iter, &crate::ID)
+                .ok()
+                .flatten(),
             __event_cpi_authority_info: value.__event_cpi_authority_info,
             __event_cpi_program_account: value.__event_cpi_program_account,
         })
@@ -700,7 +739,7 @@ impl Validate for DeployInterchainTokenAccounts<'_> {
         validate_rent_key(self.rent_sysvar.key)?;
         validate_sysvar_instructions_key(self.sysvar_instructions.key)?;
         validate_mpl_token_metadata_key(self.mpl_token_metadata_program.key)?;
-        spl_token_2022::check_program_account(self.token_program.key)?;
+        check_spl_token_program_account(self.token_program.key)?;
 
         if !self.payer.is_signer {
             msg!("Payer should be a signer");
@@ -782,6 +821,8 @@ impl<'a> From<DeployInterchainTokenAccounts<'a>> for DeployTokenManagerAccounts<
             rent_sysvar: value.rent_sysvar,
             operator: value.minter,
             operator_roles: value.minter_roles,
+            flow_limiter: None,
+            flow_limiter_roles: None,
             __event_cpi_authority_info: value.__event_cpi_authority_info,
             __event_cpi_program_account: value.__event_cpi_program_account,
         }
@@ -817,6 +858,30 @@ impl<'a> TryFrom<ExecuteAccounts<'a>> for DeployInterchainTokenAccounts<'a> {
     }
 }
 
+/// The optional accounts trailing the remote deployment accounts, to check
+/// an operator-signed [`GasQuote`](crate::gas_quote::GasQuote).
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GasQuoteAccounts<'a> {
+    pub(crate) sysvar_instructions: &'a AccountInfo<'a>,
+    pub(crate) signer_roles: &'a AccountInfo<'a>,
+}
+
+impl<'a> GasQuoteAccounts<'a> {
+    fn next_optional(
+        accounts_iter: &mut core::slice::Iter<'a, AccountInfo<'a>>,
+    ) -> Result<Option<Self>, ProgramError> {
+        let Some(sysvar_instructions) = accounts_iter.next() else {
+            return Ok(None);
+        };
+        validate_sysvar_instructions_key(sysvar_instructions.key)?;
+
+        Ok(Some(Self {
+            sysvar_instructions,
+            signer_roles: next_account_info(accounts_iter)?,
+        }))
+    }
+}
+
 #[event_cpi]
 #[derive(Debug)]
 pub(crate) struct DeployRemoteInterchainTokenAccounts<'a> {
@@ -830,11 +895,13 @@ pub(crate) struct DeployRemoteInterchainTokenAccounts<'a> {
     pub(crate) gateway_event_authority: &'a AccountInfo<'a>,
     pub(crate) gateway_program: &'a AccountInfo<'a>,
     pub(crate) gas_service_root: &'a AccountInfo<'a>,
+    pub(crate) gas_service_paused_chains: &'a AccountInfo<'a>,
     pub(crate) gas_service_event_authority: &'a AccountInfo<'a>,
     pub(crate) gas_service_program: &'a AccountInfo<'a>,
     pub(crate) system_program: &'a AccountInfo<'a>,
     pub(crate) call_contract_signing: &'a AccountInfo<'a>,
     pub(crate) its_program: &'a AccountInfo<'a>,
+    pub(crate) gas_quote: Option<GasQuoteAccounts<'a>>,
 }
 
 impl Validate for DeployRemoteInterchainTokenAccounts<'_> {
@@ -872,6 +939,7 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for DeployRemoteInterchainTokenAccounts<
             gateway_event_authority: next_account_info(accounts_iter)?,
             gateway_program: next_account_info(accounts_iter)?,
             gas_service_root: next_account_info(accounts_iter)?,
+            gas_service_paused_chains: next_account_info(accounts_iter)?,
             gas_service_event_authority: next_account_info(accounts_iter)?,
             gas_service_program: next_account_info(accounts_iter)?,
             system_program: next_account_info(accounts_iter)?,
@@ -879,6 +947,7 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for DeployRemoteInterchainTokenAccounts<
             its_program: next_account_info(accounts_iter)?,
             __event_cpi_authority_info: next_account_info(accounts_iter)?,
             __event_cpi_program_account: next_account_info(accounts_iter)?,
+            gas_quote: GasQuoteAccounts::next_optional(accounts_iter)?,
         };
 
         converted.validate()?;
@@ -903,11 +972,13 @@ pub(crate) struct DeployRemoteInterchainTokenWithMinterAccounts<'a> {
     pub(crate) gateway_event_authority: &'a AccountInfo<'a>,
     pub(crate) gateway_program: &'a AccountInfo<'a>,
     pub(crate) gas_service_root: &'a AccountInfo<'a>,
+    pub(crate) gas_service_paused_chains: &'a AccountInfo<'a>,
     pub(crate) gas_service_event_authority: &'a AccountInfo<'a>,
     pub(crate) gas_service_program: &'a AccountInfo<'a>,
     pub(crate) system_program: &'a AccountInfo<'a>,
     pub(crate) call_contract_signing: &'a AccountInfo<'a>,
     pub(crate) its_program: &'a AccountInfo<'a>,
+    pub(crate) gas_quote: Option<GasQuoteAccounts<'a>>,
 }
 
 impl Validate for DeployRemoteInterchainTokenWithMinterAccounts<'_> {
@@ -949,6 +1020,7 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for DeployRemoteInterchainTokenWithMinte
             gateway_event_authority: next_account_info(accounts_iter)?,
             gateway_program: next_account_info(accounts_iter)?,
             gas_service_root: next_account_info(accounts_iter)?,
+            gas_service_paused_chains: next_account_info(accounts_iter)?,
             gas_service_event_authority: next_account_info(accounts_iter)?,
             gas_service_program: next_account_info(accounts_iter)?,
             system_program: next_account_info(accounts_iter)?,
@@ -956,6 +1028,7 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for DeployRemoteInterchainTokenWithMinte
             its_program: next_account_info(accounts_iter)?,
             __event_cpi_authority_info: next_account_info(accounts_iter)?,
             __event_cpi_program_account: next_account_info(accounts_iter)?,
+            gas_quote: GasQuoteAccounts::next_optional(accounts_iter)?,
         };
 
         converted.validate()?;
@@ -976,11 +1049,13 @@ pub(crate) struct DeployRemoteCanonicalInterchainTokenAccounts<'a> {
     pub(crate) gateway_event_authority: &'a AccountInfo<'a>,
     pub(crate) gateway_program: &'a AccountInfo<'a>,
     pub(crate) gas_service_config: &'a AccountInfo<'a>,
+    pub(crate) gas_service_paused_chains: &'a AccountInfo<'a>,
     pub(crate) gas_service_event_authority: &'a AccountInfo<'a>,
     pub(crate) gas_service_program: &'a AccountInfo<'a>,
     pub(crate) system_program: &'a AccountInfo<'a>,
     pub(crate) call_contract_signing: &'a AccountInfo<'a>,
     pub(crate) its_program: &'a AccountInfo<'a>,
+    pub(crate) gas_quote: Option<GasQuoteAccounts<'a>>,
 }
 
 impl Validate for DeployRemoteCanonicalInterchainTokenAccounts<'_> {
@@ -1013,6 +1088,7 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for DeployRemoteCanonicalInterchainToken
             gateway_event_authority: next_account_info(accounts_iter)?,
             gateway_program: next_account_info(accounts_iter)?,
             gas_service_config: next_account_info(accounts_iter)?,
+            gas_service_paused_chains: next_account_info(accounts_iter)?,
             gas_service_event_authority: next_account_info(accounts_iter)?,
             gas_service_program: next_account_info(accounts_iter)?,
             system_program: next_account_info(accounts_iter)?,
@@ -1020,6 +1096,7 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for DeployRemoteCanonicalInterchainToken
             its_program: next_account_info(accounts_iter)?,
             __event_cpi_authority_info: next_account_info(accounts_iter)?,
             __event_cpi_program_account: next_account_info(accounts_iter)?,
+            gas_quote: GasQuoteAccounts::next_optional(accounts_iter)?,
         };
 
         converted.validate()?;
@@ -1040,8 +1117,9 @@ impl<'a> TryFrom<DeployRemoteCanonicalInterchainTokenAccounts<'a>> for CallContr
             gateway_event_authority: value.gateway_event_authority,
             gateway_program: value.gateway_program,
             gas_service_root: value.gas_service_config,
+            gas_service_paused_chains: value.gas_service_paused_chains,
             gas_service_event_authority: value.gas_service_event_authority,
-            _gas_service_program: value.gas_service_program,
+            gas_service_program: value.gas_service_program,
             system_program: value.system_program,
             its_root: value.its_root,
             call_contract_signing: value.call_contract_signing,
@@ -1075,11 +1153,13 @@ impl<'a> TryFrom<DeployRemoteInterchainTokenAccounts<'a>>
             gateway_event_authority: value.gateway_event_authority,
             gateway_program: value.gateway_program,
             gas_service_config: value.gas_service_root,
+            gas_service_paused_chains: value.gas_service_paused_chains,
             gas_service_event_authority: value.gas_service_event_authority,
             gas_service_program: value.gas_service_program,
             system_program: value.system_program,
             call_contract_signing: value.call_contract_signing,
             its_program: value.its_program,
+            gas_quote: value.gas_quote,
             __event_cpi_authority_info: value.__event_cpi_authority_info,
             __event_cpi_program_account: value.__event_cpi_program_account,
         })
@@ -1104,11 +1184,13 @@ impl<'a> TryFrom<DeployRemoteInterchainTokenWithMinterAccounts<'a>>
             gateway_event_authority: value.gateway_event_authority,
             gateway_program: value.gateway_program,
             gas_service_config: value.gas_service_root,
+            gas_service_paused_chains: value.gas_service_paused_chains,
             gas_service_event_authority: value.gas_service_event_authority,
             gas_service_program: value.gas_service_program,
             system_program: value.system_program,
             call_contract_signing: value.call_contract_signing,
             its_program: value.its_program,
+            gas_quote: value.gas_quote,
             __event_cpi_authority_info: value.__event_cpi_authority_info,
             __event_cpi_program_account: value.__event_cpi_program_account,
         })
@@ -1126,11 +1208,13 @@ pub(crate) struct LinkTokenAccounts<'a> {
     pub(crate) gateway_event_authority: &'a AccountInfo<'a>,
     pub(crate) gateway_program: &'a AccountInfo<'a>,
     pub(crate) gas_service_root: &'a AccountInfo<'a>,
+    pub(crate) gas_service_paused_chains: &'a AccountInfo<'a>,
     pub(crate) gas_service_event_authority: &'a AccountInfo<'a>,
     pub(crate) gas_service_program: &'a AccountInfo<'a>,
     pub(crate) system_program: &'a AccountInfo<'a>,
     pub(crate) call_contract_signing: &'a AccountInfo<'a>,
     pub(crate) its_program: &'a AccountInfo<'a>,
+    pub(crate) outbound_nonce: &'a AccountInfo<'a>,
 }
 
 impl Validate for LinkTokenAccounts<'_> {
@@ -1167,11 +1251,13 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for LinkTokenAccounts<'a> {
             gateway_event_authority: next_account_info(accounts_iter)?,
             gateway_program: next_account_info(accounts_iter)?,
             gas_service_root: next_account_info(accounts_iter)?,
+            gas_service_paused_chains: next_account_info(accounts_iter)?,
             gas_service_event_authority: next_account_info(accounts_iter)?,
             gas_service_program: next_account_info(accounts_iter)?,
             system_program: next_account_info(accounts_iter)?,
             call_contract_signing: next_account_info(accounts_iter)?,
             its_program: next_account_info(accounts_iter)?,
+            outbound_nonce: next_account_info(accounts_iter)?,
             __event_cpi_authority_info: next_account_info(accounts_iter)?,
             __event_cpi_program_account: next_account_info(accounts_iter)?,
         };
@@ -1192,8 +1278,9 @@ impl<'a> TryFrom<LinkTokenAccounts<'a>> for CallContractAccounts<'a> {
             gateway_event_authority: value.gateway_event_authority,
             gateway_program: value.gateway_program,
             gas_service_root: value.gas_service_root,
+            gas_service_paused_chains: value.gas_service_paused_chains,
             gas_service_event_authority: value.gas_service_event_authority,
-            _gas_service_program: value.gas_service_program,
+            gas_service_program: value.gas_service_program,
             system_program: value.system_program,
             its_root: value.its_root,
             call_contract_signing: value.call_contract_signing,
@@ -1218,6 +1305,7 @@ pub(crate) struct RegisterTokenMetadataAccounts<'a> {
     pub(crate) gateway_event_authority: &'a AccountInfo<'a>,
     pub(crate) gateway_program: &'a AccountInfo<'a>,
     pub(crate) gas_service_root: &'a AccountInfo<'a>,
+    pub(crate) gas_service_paused_chains: &'a AccountInfo<'a>,
     pub(crate) gas_service_event_authority: &'a AccountInfo<'a>,
     pub(crate) gas_service_program: &'a AccountInfo<'a>,
     pub(crate) system_program: &'a AccountInfo<'a>,
@@ -1253,6 +1341,7 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for RegisterTokenMetadataAccounts<'a> {
             gateway_event_authority: next_account_info(accounts_iter)?,
             gateway_program: next_account_info(accounts_iter)?,
             gas_service_root: next_account_info(accounts_iter)?,
+            gas_service_paused_chains: next_account_info(accounts_iter)?,
             gas_service_event_authority: next_account_info(accounts_iter)?,
             gas_service_program: next_account_info(accounts_iter)?,
             system_program: next_account_info(accounts_iter)?,
@@ -1278,8 +1367,9 @@ impl<'a> TryFrom<RegisterTokenMetadataAccounts<'a>> for CallContractAccounts<'a>
             gateway_event_authority: value.gateway_event_authority,
             gateway_program: value.gateway_program,
             gas_service_root: value.gas_service_root,
+            gas_service_paused_chains: value.gas_service_paused_chains,
             gas_service_event_authority: value.gas_service_event_authority,
-            _gas_service_program: value.gas_service_program,
+            gas_service_program: value.gas_service_program,
             system_program: value.system_program,
             its_root: value.its_root,
             call_contract_signing: value.call_contract_signing,
@@ -1294,6 +1384,176 @@ impl<'a> TryFrom<RegisterTokenMetadataAccounts<'a>> for CallContractAccounts<'a>
     }
 }
 
+#[event_cpi]
+#[derive(Debug)]
+pub(crate) struct RegisterAndLinkTokenAccounts<'a> {
+    pub(crate) payer: &'a AccountInfo<'a>,
+    pub(crate) deployer: &'a AccountInfo<'a>,
+    pub(crate) system_program: &'a AccountInfo<'a>,
+    pub(crate) its_root: &'a AccountInfo<'a>,
+    pub(crate) token_manager: &'a AccountInfo<'a>,
+    pub(crate) mint: &'a AccountInfo<'a>,
+    pub(crate) token_manager_ata: &'a AccountInfo<'a>,
+    pub(crate) token_program: &'a AccountInfo<'a>,
+    pub(crate) ata_program: &'a AccountInfo<'a>,
+    pub(crate) rent_sysvar: &'a AccountInfo<'a>,
+    pub(crate) operator: Option<&'a AccountInfo<'a>>,
+    pub(crate) operator_roles: Option<&'a AccountInfo<'a>>,
+    pub(crate) gateway_root: &'a AccountInfo<'a>,
+    pub(crate) gateway_event_authority: &'a AccountInfo<'a>,
+    pub(crate) gateway_program: &'a AccountInfo<'a>,
+    pub(crate) gas_service_root: &'a AccountInfo<'a>,
+    pub(crate) gas_service_paused_chains: &'a AccountInfo<'a>,
+    pub(crate) gas_service_event_authority: &'a AccountInfo<'a>,
+    pub(crate) gas_service_program: &'a AccountInfo<'a>,
+    pub(crate) call_contract_signing: &'a AccountInfo<'a>,
+    pub(crate) its_program: &'a AccountInfo<'a>,
+    pub(crate) outbound_nonce: &'a AccountInfo<'a>,
+}
+
+impl Validate for RegisterAndLinkTokenAccounts<'_> {
+    fn validate(&self) -> Result<(), ProgramError> {
+        if !self.payer.is_signer {
+            msg!("Payer should be a signer");
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if !self.deployer.is_signer {
+            msg!("Deployer should be signer");
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo<'a>]> for RegisterAndLinkTokenAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(value: &'a [AccountInfo<'a>]) -> Result<Self, Self::Error>
+    where
+        Self: Sized + Validate,
+    {
+        let accounts_iter = &mut value.iter();
+
+        let converted = Self {
+            payer: next_account_info(accounts_iter)?,
+            deployer: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+            its_root: next_account_info(accounts_iter)?,
+            token_manager: next_account_info(accounts_iter)?,
+            mint: next_account_info(accounts_iter)?,
+            token_manager_ata: next_account_info(accounts_iter)?,
+            token_program: next_account_info(accounts_iter)?,
+            ata_program: next_account_info(accounts_iter)?,
+            rent_sysvar: next_account_info(accounts_iter)?,
+            operator: next_optional_account_info(accounts_iter, &crate::ID)?,
+            operator_roles: next_optional_account_info(accounts_iter, &crate::ID)?,
+            gateway_root: next_account_info(accounts_iter)?,
+            gateway_event_authority: next_account_info(accounts_iter)?,
+            gateway_program: next_account_info(accounts_iter)?,
+            gas_service_root: next_account_info(accounts_iter)?,
+            gas_service_paused_chains: next_account_info(accounts_iter)?,
+            gas_service_event_authority: next_account_info(accounts_iter)?,
+            gas_service_program: next_account_info(accounts_iter)?,
+            call_contract_signing: next_account_info(accounts_iter)?,
+            its_program: next_account_info(accounts_iter)?,
+            outbound_nonce: next_account_info(accounts_iter)?,
+            __event_cpi_authority_info: next_account_info(accounts_iter)?,
+            __event_cpi_program_account: next_account_info(accounts_iter)?,
+        };
+
+        converted.validate()?;
+
+        Ok(converted)
+    }
+}
+
+impl<'a> TryFrom<&RegisterAndLinkTokenAccounts<'a>> for DeployCustomTokenAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(value: &RegisterAndLinkTokenAccounts<'a>) -> Result<Self, Self::Error> {
+        let converted = Self {
+            payer: value.payer,
+            deployer: value.deployer,
+            system_program: value.system_program,
+            its_root: value.its_root,
+            token_manager: value.token_manager,
+            mint: value.mint,
+            token_manager_ata: value.token_manager_ata,
+            token_program: value.token_program,
+            ata_program: value.ata_program,
+            rent_sysvar: value.rent_sysvar,
+            operator: value.operator,
+            operator_roles: value.operator_roles,
+            __event_cpi_authority_info: value.__event_cpi_authority_info,
+            __event_cpi_program_account: value.__event_cpi_program_account,
+        };
+
+        converted.validate()?;
+
+        Ok(converted)
+    }
+}
+
+impl<'a> TryFrom<&RegisterAndLinkTokenAccounts<'a>> for RegisterTokenMetadataAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(value: &RegisterAndLinkTokenAccounts<'a>) -> Result<Self, Self::Error> {
+        let converted = Self {
+            payer: value.payer,
+            mint: value.mint,
+            its_root: value.its_root,
+            gateway_root: value.gateway_root,
+            gateway_event_authority: value.gateway_event_authority,
+            gateway_program: value.gateway_program,
+            gas_service_root: value.gas_service_root,
+            gas_service_paused_chains: value.gas_service_paused_chains,
+            gas_service_event_authority: value.gas_service_event_authority,
+            gas_service_program: value.gas_service_program,
+            system_program: value.system_program,
+            call_contract_signing: value.call_contract_signing,
+            its_program: value.its_program,
+            __event_cpi_authority_info: value.__event_cpi_authority_info,
+            __event_cpi_program_account: value.__event_cpi_program_account,
+        };
+
+        converted.validate()?;
+
+        Ok(converted)
+    }
+}
+
+impl<'a> TryFrom<&RegisterAndLinkTokenAccounts<'a>> for LinkTokenAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(value: &RegisterAndLinkTokenAccounts<'a>) -> Result<Self, Self::Error> {
+        let converted = Self {
+            payer: value.payer,
+            deployer: value.deployer,
+            its_root: value.its_root,
+            token_manager: value.token_manager,
+            gateway_root: value.gateway_root,
+            gateway_event_authority: value.gateway_event_authority,
+            gateway_program: value.gateway_program,
+            gas_service_root: value.gas_service_root,
+            gas_service_paused_chains: value.gas_service_paused_chains,
+            gas_service_event_authority: value.gas_service_event_authority,
+            gas_service_program: value.gas_service_program,
+            system_program: value.system_program,
+            call_contract_signing: value.call_contract_signing,
+            its_program: value.its_program,
+            outbound_nonce: value.outbound_nonce,
+            __event_cpi_authority_info: value.__event_cpi_authority_info,
+            __event_cpi_program_account: value.__event_cpi_program_account,
+        };
+
+        converted.validate()?;
+
+        Ok(converted)
+    }
+}
+
 #[event_cpi]
 #[derive(Debug)]
 pub(crate) struct SetTrustedChainAccounts<'a> {
@@ -1336,3 +1596,11 @@ impl<'a> TryFrom<&'a [AccountInfo<'a>]> for SetTrustedChainAccounts<'a> {
 }
 
 pub(crate) type RemoveTrustedChainAccounts<'a> = SetTrustedChainAccounts<'a>;
+
+pub(crate) type RenameTrustedChainAccounts<'a> = SetTrustedChainAccounts<'a>;
+
+pub(crate) type SetMaxPayloadSizeAccounts<'a> = SetTrustedChainAccounts<'a>;
+
+pub(crate) type SetPauseFlagsAccounts<'a> = SetTrustedChainAccounts<'a>;
+
+pub(crate) type SetDestinationExecutionPolicyAccounts<'a> = SetTrustedChainAccounts<'a>;
@@ -0,0 +1,486 @@
+//! Generates the Anchor IDL of the ITS program.
+//!
+//! The instructions and their arguments come from
+//! [`InterchainTokenServiceInstruction`], the accounts of every instruction
+//! from the numbered account list documented on its variant, and the account
+//! and type layouts from the structs and enums of the crate.
+//!
+//! ```sh
+//! cargo run -p axelar-solana-its --features idl-build --bin its-idl -- \
+//!     bindings/generated/axelar-solana-its/idl.json
+//! ```
+//!
+//! [`InterchainTokenServiceInstruction`]: axelar_solana_its::instruction::InterchainTokenServiceInstruction
+
+// Reason: the syntax tree enums of syn are non-exhaustive
+#![allow(clippy::wildcard_enum_match_arm)]
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+use serde_json::{json, Value};
+use syn::{
+    Attribute, Expr, ExprLit, Fields, GenericArgument, Item, Lit, LitStr, Meta, PathArguments, Type,
+};
+
+type Result<T> = core::result::Result<T, Box<dyn Error>>;
+
+const INSTRUCTION_ENUM: &str = "InterchainTokenServiceInstruction";
+const ERROR_ENUM: &str = "ItsError";
+
+/// Words a generated account name stops at, as the rest of the description
+/// only qualifies the account.
+const NAME_STOP_WORDS: &[&str] = &[
+    "which", "that", "for", "used", "owned", "derived", "either", "with", "to", "from", "on", "in",
+    "if", "when", "as", "is", "being",
+];
+
+/// Words dropped from generated account names.
+const NAME_FILLER_WORDS: &[&str] = &["the", "a", "an"];
+
+fn main() -> Result<()> {
+    let src = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src");
+    let output = std::env::args()
+        .nth(1)
+        .map_or_else(|| PathBuf::from("idl.json"), PathBuf::from);
+
+    let mut items = BTreeMap::new();
+    collect_items(&src, &mut items)?;
+
+    let mut generator = Generator {
+        items: &items,
+        referenced: BTreeSet::new(),
+    };
+    let idl = generator.idl()?;
+
+    std::fs::write(output, format!("{}\n", serde_json::to_string_pretty(&idl)?))?;
+
+    Ok(())
+}
+
+/// Collects the structs and enums of every source file under `dir`, skipping
+/// test modules.
+fn collect_items(dir: &Path, items: &mut BTreeMap<String, Item>) -> Result<()> {
+    let mut entries = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<core::result::Result<Vec<_>, _>>()?;
+    entries.sort();
+
+    for path in entries {
+        if path.is_dir() {
+            if path.file_name() != Some(OsStr::new("bin")) {
+                collect_items(&path, items)?;
+            }
+        } else if path.extension() == Some(OsStr::new("rs")) {
+            let file = syn::parse_file(&std::fs::read_to_string(&path)?)?;
+            collect_module_items(file.items, items);
+        }
+    }
+
+    Ok(())
+}
+
+fn collect_module_items(module_items: Vec<Item>, items: &mut BTreeMap<String, Item>) {
+    for item in module_items {
+        match item {
+            Item::Struct(ref item_struct) => {
+                items.entry(item_struct.ident.to_string()).or_insert(item);
+            }
+            Item::Enum(ref item_enum) => {
+                items.entry(item_enum.ident.to_string()).or_insert(item);
+            }
+            Item::Mod(item_mod) => {
+                let is_test = item_mod
+                    .attrs
+                    .iter()
+                    .any(|attr| attr.path().is_ident("cfg"));
+                if let (false, Some((_, content))) = (is_test, item_mod.content) {
+                    collect_module_items(content, items);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+struct Generator<'a> {
+    items: &'a BTreeMap<String, Item>,
+    /// Types referenced by the instructions and accounts, to be described.
+    referenced: BTreeSet<String>,
+}
+
+impl Generator<'_> {
+    fn idl(&mut self) -> Result<Value> {
+        let items = self.items;
+        let Some(Item::Enum(instruction_enum)) = items.get(INSTRUCTION_ENUM) else {
+            return Err(format!("{INSTRUCTION_ENUM} not found").into());
+        };
+
+        let mut instructions = Vec::new();
+        for variant in &instruction_enum.variants {
+            let args = match variant.fields {
+                Fields::Named(ref fields) => fields
+                    .named
+                    .iter()
+                    .map(|field| self.field(field.ident.as_ref(), &field.ty))
+                    .collect::<Result<Vec<_>>>()?,
+                Fields::Unnamed(_) => {
+                    return Err(format!("{} has unnamed arguments", variant.ident).into());
+                }
+                Fields::Unit => Vec::new(),
+            };
+
+            instructions.push(json!({
+                "name": camel_case(&variant.ident.to_string()),
+                "docs": summary(&variant.attrs),
+                "accounts": accounts(&variant.attrs),
+                "args": args,
+            }));
+        }
+
+        let accounts = items
+            .iter()
+            .filter_map(|(name, item)| match *item {
+                Item::Struct(ref item_struct)
+                    if item_struct
+                        .attrs
+                        .iter()
+                        .any(|attr| attr.path().is_ident("account")) =>
+                {
+                    Some(name.clone())
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let accounts = accounts
+            .iter()
+            .map(|name| self.type_definition(name))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(json!({
+            "version": env!("CARGO_PKG_VERSION"),
+            "name": "axelar_solana_its",
+            "instructions": instructions,
+            "accounts": accounts,
+            "types": self.types(&accounts)?,
+            "errors": self.errors()?,
+            "metadata": {
+                "address": axelar_solana_its::ID.to_string(),
+            },
+        }))
+    }
+
+    /// Describes every referenced type that isn't an account, including the
+    /// types they reference in turn.
+    fn types(&mut self, accounts: &[Value]) -> Result<Vec<Value>> {
+        let account_names = accounts
+            .iter()
+            .filter_map(|account| account.get("name").and_then(Value::as_str))
+            .map(ToOwned::to_owned)
+            .collect::<BTreeSet<_>>();
+
+        let mut described = BTreeMap::new();
+        while let Some(name) = self
+            .referenced
+            .iter()
+            .find(|name| !described.contains_key(*name))
+            .cloned()
+        {
+            let definition = if self.items.contains_key(&name) {
+                Some(self.type_definition(&name)?)
+            } else {
+                // Types from other crates are left for the client to provide
+                None
+            };
+            described.insert(name, definition);
+        }
+
+        Ok(described
+            .into_iter()
+            .filter(|(name, _)| !account_names.contains(name))
+            .filter_map(|(_, definition)| definition)
+            .collect())
+    }
+
+    fn type_definition(&mut self, name: &str) -> Result<Value> {
+        let items = self.items;
+        let ty = match items.get(name) {
+            Some(Item::Struct(item_struct)) => json!({
+                "kind": "struct",
+                "fields": self.fields(&item_struct.fields)?,
+            }),
+            Some(Item::Enum(item_enum)) => {
+                let mut variants = Vec::new();
+                for variant in &item_enum.variants {
+                    let mut definition = json!({ "name": variant.ident.to_string() });
+                    if !matches!(variant.fields, Fields::Unit) {
+                        definition["fields"] = Value::Array(self.fields(&variant.fields)?);
+                    }
+                    variants.push(definition);
+                }
+                json!({ "kind": "enum", "variants": variants })
+            }
+            _ => return Err(format!("{name} not found").into()),
+        };
+
+        Ok(json!({ "name": name, "type": ty }))
+    }
+
+    fn fields(&mut self, fields: &Fields) -> Result<Vec<Value>> {
+        match *fields {
+            Fields::Named(ref fields) => fields
+                .named
+                .iter()
+                .map(|field| self.field(field.ident.as_ref(), &field.ty))
+                .collect(),
+            Fields::Unnamed(ref fields) => fields
+                .unnamed
+                .iter()
+                .map(|field| self.idl_type(&field.ty))
+                .collect(),
+            Fields::Unit => Ok(Vec::new()),
+        }
+    }
+
+    fn field(&mut self, ident: Option<&syn::Ident>, ty: &Type) -> Result<Value> {
+        let name = ident.ok_or("named field without a name")?.to_string();
+
+        Ok(json!({
+            "name": camel_case(&name),
+            "type": self.idl_type(ty)?,
+        }))
+    }
+
+    fn idl_type(&mut self, ty: &Type) -> Result<Value> {
+        if let Type::Array(ref array) = *ty {
+            let Expr::Lit(ExprLit {
+                lit: Lit::Int(ref len),
+                ..
+            }) = array.len
+            else {
+                return Err("array lengths must be integer literals".into());
+            };
+            return Ok(json!({
+                "array": [self.idl_type(&array.elem)?, len.base10_parse::<usize>()?],
+            }));
+        }
+
+        let Type::Path(ref type_path) = *ty else {
+            return Err("unsupported argument type".into());
+        };
+        let segment = type_path.path.segments.last().ok_or("empty type path")?;
+        let ident = segment.ident.to_string();
+        let generic = match segment.arguments {
+            PathArguments::AngleBracketed(ref arguments) => {
+                arguments.args.first().and_then(|argument| match *argument {
+                    GenericArgument::Type(ref generic) => Some(generic),
+                    _ => None,
+                })
+            }
+            _ => None,
+        };
+
+        let idl_type = match (ident.as_str(), generic) {
+            (
+                "bool" | "u8" | "i8" | "u16" | "i16" | "u32" | "i32" | "u64" | "i64" | "u128"
+                | "i128",
+                None,
+            ) => Value::String(ident),
+            ("String", None) => json!("string"),
+            ("Pubkey", None) => json!("publicKey"),
+            ("Vec", Some(Type::Path(inner))) if inner.path.is_ident("u8") => json!("bytes"),
+            ("Vec", Some(inner)) => json!({ "vec": self.idl_type(inner)? }),
+            ("Option", Some(inner)) => json!({ "option": self.idl_type(inner)? }),
+            ("Box", Some(inner)) => self.idl_type(inner)?,
+            (_, None) => {
+                self.referenced.insert(ident.clone());
+                json!({ "defined": ident })
+            }
+            (_, Some(_)) => return Err(format!("unsupported generic type {ident}").into()),
+        };
+
+        Ok(idl_type)
+    }
+
+    fn errors(&self) -> Result<Vec<Value>> {
+        let Some(Item::Enum(error_enum)) = self.items.get(ERROR_ENUM) else {
+            return Err(format!("{ERROR_ENUM} not found").into());
+        };
+
+        let mut code = 0_u32;
+        let mut errors = Vec::new();
+        for variant in &error_enum.variants {
+            if let Some((
+                _,
+                Expr::Lit(ExprLit {
+                    lit: Lit::Int(ref discriminant),
+                    ..
+                }),
+            )) = variant.discriminant
+            {
+                code = discriminant.base10_parse()?;
+            }
+
+            let msg = variant
+                .attrs
+                .iter()
+                .find(|attr| attr.path().is_ident("error"))
+                .map(|attr| attr.parse_args::<LitStr>())
+                .transpose()?
+                .map(|msg| msg.value());
+            errors.push(json!({
+                "code": code,
+                "name": variant.ident.to_string(),
+                "msg": msg,
+            }));
+            code += 1;
+        }
+
+        Ok(errors)
+    }
+}
+
+fn doc_lines(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter_map(|attr| match attr.meta {
+            Meta::NameValue(ref name_value) if name_value.path.is_ident("doc") => {
+                match name_value.value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(ref doc),
+                        ..
+                    }) => Some(doc.value().trim().to_owned()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// The first paragraph of the doc comment.
+fn summary(attrs: &[Attribute]) -> Vec<String> {
+    doc_lines(attrs)
+        .into_iter()
+        .take_while(|line| !line.is_empty())
+        .collect()
+}
+
+/// Parses the numbered account list of an instruction doc comment, such as
+/// `0. [writable,signer] Payer account.`. Trailing variable accounts
+/// (`3..N`) are left out, Anchor can't describe them.
+fn accounts(attrs: &[Attribute]) -> Vec<Value> {
+    let mut names = BTreeMap::<String, usize>::new();
+    let mut accounts = Vec::new();
+
+    for line in doc_lines(attrs) {
+        let Some((index, rest)) = line.split_once(". ") else {
+            continue;
+        };
+        if index.is_empty() || !index.chars().all(|character| character.is_ascii_digit()) {
+            continue;
+        }
+        let Some((flags, description)) =
+            rest.strip_prefix('[').and_then(|rest| rest.split_once(']'))
+        else {
+            continue;
+        };
+        let flags = flags
+            .split(',')
+            .map(str::trim)
+            .filter(|flag| !flag.is_empty())
+            .collect::<Vec<_>>();
+        if !flags
+            .iter()
+            .all(|flag| matches!(*flag, "writable" | "signer"))
+        {
+            continue;
+        }
+
+        let mut name = account_name(description);
+        let count = names.entry(name.clone()).or_insert(0);
+        *count += 1;
+        if *count > 1 {
+            name = format!("{name}{count}");
+        }
+
+        accounts.push(json!({
+            "name": name,
+            "isMut": flags.contains(&"writable"),
+            "isSigner": flags.contains(&"signer"),
+            "docs": [description.trim()],
+        }));
+    }
+
+    accounts
+}
+
+/// Derives an account name from the leading words of its description, e.g.
+/// `The token manager PDA account.` becomes `tokenManagerPda`.
+fn account_name(description: &str) -> String {
+    let mut words = Vec::new();
+    for word in description.split_whitespace() {
+        let clean = word
+            .chars()
+            .filter(|character| character.is_ascii_alphanumeric() || *character == '_')
+            .collect::<String>();
+        let lowercase = clean.to_lowercase();
+        if NAME_STOP_WORDS.contains(&lowercase.as_str()) {
+            break;
+        }
+        if !clean.is_empty() && !NAME_FILLER_WORDS.contains(&lowercase.as_str()) {
+            // Acronyms such as `PDA` become `Pda`
+            let word = if clean
+                .chars()
+                .all(|character| !character.is_ascii_lowercase())
+            {
+                let mut chars = lowercase.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default()
+            } else {
+                clean
+            };
+            words.push(word);
+        }
+        if word.ends_with([',', '.', ':', ';']) || word.contains('(') {
+            break;
+        }
+    }
+    if words.len() > 1
+        && words
+            .last()
+            .is_some_and(|word| word.eq_ignore_ascii_case("account"))
+    {
+        words.pop();
+    }
+
+    if words.is_empty() {
+        return "account".to_owned();
+    }
+    camel_case(&words.join(" "))
+}
+
+/// Converts `snake_case`, `PascalCase` or space separated words to
+/// `camelCase`.
+fn camel_case(input: &str) -> String {
+    let mut output = String::new();
+    let mut upper_next = false;
+    for character in input.chars() {
+        if character == '_' || character == ' ' {
+            upper_next = !output.is_empty();
+        } else if output.is_empty() {
+            output.extend(character.to_lowercase());
+        } else if upper_next {
+            output.extend(character.to_uppercase());
+            upper_next = false;
+        } else {
+            output.push(character);
+        }
+    }
+
+    output
+}
@@ -0,0 +1,138 @@
+//! Error types
+
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+
+/// Errors that may be returned by the Interchain Token Service program.
+#[repr(u32)]
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum ItsError {
+    /// The outbound GMP payload exceeds the maximum size configured for the
+    /// destination chain. See [`PayloadTooLarge`].
+    #[error("Payload too large")]
+    PayloadTooLarge = 0,
+
+    /// An interchain transfer with data forwards more accounts to the
+    /// destination program than the destination execution policy allows.
+    #[error("Too many destination accounts")]
+    TooManyDestinationAccounts,
+
+    /// Not enough compute units remain to call the destination program of
+    /// an interchain transfer with data, as required by the destination
+    /// execution policy.
+    #[error("Insufficient destination compute units")]
+    InsufficientDestinationComputeUnits,
+
+    /// The destination program of an interchain transfer with data failed.
+    /// See [`DestinationExecutionFailed`](crate::events::DestinationExecutionFailed).
+    #[error("Destination execution failed")]
+    DestinationExecutionFailed,
+
+    /// The Metaplex metadata update authority of the token was already handed
+    /// over by the [`TokenManager`](crate::state::token_manager::TokenManager).
+    #[error("Token metadata authority already delegated")]
+    TokenMetadataAuthorityAlreadyDelegated,
+
+    /// The maximum supply of the
+    /// [`TokenManager`](crate::state::token_manager::TokenManager) was
+    /// already set.
+    #[error("Max supply already set")]
+    MaxSupplyAlreadySet,
+
+    /// Minting would push the token supply above the maximum supply of the
+    /// [`TokenManager`](crate::state::token_manager::TokenManager).
+    #[error("Max supply exceeded")]
+    MaxSupplyExceeded,
+
+    /// The `link_params` of an inbound `LinkToken` message don't follow the
+    /// [`LinkParams`](crate::link_params::LinkParams) encoding.
+    #[error("Invalid link params")]
+    InvalidLinkParams,
+
+    /// The gateway program account of an outbound call isn't the Axelar
+    /// gateway program.
+    #[error("Invalid gateway program")]
+    InvalidGatewayProgram,
+
+    /// The gas service program account of an outbound call isn't the Axelar
+    /// gas service program.
+    #[error("Invalid gas service program")]
+    InvalidGasServiceProgram,
+
+    /// Withheld fees are harvested to a token account other than the fee
+    /// collector set on the token manager.
+    #[error("Fee collector mismatch")]
+    FeeCollectorMismatch,
+
+    /// The grace period of an ITS operatorship transfer is zero or exceeds
+    /// [`MAX_OPERATOR_GRACE_PERIOD_SLOTS`](crate::state::MAX_OPERATOR_GRACE_PERIOD_SLOTS).
+    #[error("Invalid operator grace period")]
+    InvalidOperatorGracePeriod,
+
+    /// The [`TokenManager`](crate::state::token_manager::TokenManager) of a
+    /// token ITS mints is no longer its mint authority.
+    /// See [`TokenManagerAuthorityMismatch`](crate::events::TokenManagerAuthorityMismatch).
+    #[error("TokenManager authority mismatch")]
+    TokenManagerAuthorityMismatch,
+
+    /// A [`TokenManager`](crate::state::token_manager::TokenManager) can't be
+    /// decommissioned while its associated token account still holds tokens.
+    #[error("Locked tokens remain")]
+    LockedTokensRemain,
+
+    /// The [`GasQuote`](crate::gas_quote::GasQuote) accompanying a remote
+    /// deployment isn't verified by the preceding instruction, isn't signed by
+    /// the ITS operator or is for another destination chain.
+    #[error("Invalid gas quote")]
+    InvalidGasQuote,
+
+    /// The [`GasQuote`](crate::gas_quote::GasQuote) accompanying a remote
+    /// deployment expired.
+    #[error("Gas quote expired")]
+    GasQuoteExpired,
+
+    /// A remote deployment pays less gas than its
+    /// [`GasQuote`](crate::gas_quote::GasQuote).
+    #[error("Gas below quote")]
+    GasBelowQuote,
+
+    /// An interchain transfer targets the chain ITS is running on, which
+    /// takes a regular SPL token transfer instead.
+    #[error("Destination is the local chain")]
+    DestinationIsLocalChain,
+
+    /// A [`TokenManager`](crate::state::token_manager::TokenManager) can't be
+    /// decommissioned while roles PDAs over it are left open.
+    #[error("Role holders remain")]
+    RoleHoldersRemain,
+}
+
+impl From<ItsError> for ProgramError {
+    fn from(error: ItsError) -> Self {
+        // ItsError's memory representation is an u32, so this is safe
+        Self::Custom(error as u32)
+    }
+}
+
+/// An outbound GMP payload rejected for exceeding the maximum size configured
+/// for its destination chain.
+///
+/// The sizes are logged when converting into a [`ProgramError`], which maps
+/// to [`ItsError::PayloadTooLarge`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[error("Payload of {actual} bytes towards {destination_chain} exceeds the maximum of {max} bytes")]
+pub struct PayloadTooLarge {
+    /// The chain the payload is sent to.
+    pub destination_chain: String,
+    /// The size of the encoded payload, in bytes.
+    pub actual: usize,
+    /// The maximum payload size configured for the destination chain.
+    pub max: u32,
+}
+
+impl From<PayloadTooLarge> for ProgramError {
+    fn from(error: PayloadTooLarge) -> Self {
+        msg!("{}", error);
+        ItsError::PayloadTooLarge.into()
+    }
+}
@@ -1,8 +1,15 @@
 #![allow(missing_docs)]
 use anchor_discriminators::Discriminator;
+use borsh::{BorshDeserialize, BorshSerialize};
 use event_cpi_macros::event;
 use solana_program::pubkey::Pubkey;
 
+use crate::state::token_manager::DestinationOwnerPolicy;
+use crate::state::DestinationExecutionPolicy;
+
+/// Emitted by earlier versions of the program for outbound transfers,
+/// superseded by [`InterchainTransferV2`] and kept to decode past
+/// transactions.
 #[event]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct InterchainTransfer {
@@ -15,6 +22,34 @@ pub struct InterchainTransfer {
     pub data_hash: [u8; 32],
 }
 
+/// Emitted for outbound transfers, replacing the [`InterchainTransfer`] of
+/// earlier versions with the outbound `nonce` of the sender.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct InterchainTransferV2 {
+    pub token_id: [u8; 32],
+    pub source_address: Pubkey,
+    pub source_token_account: Pubkey,
+    pub destination_chain: String,
+    pub destination_address: Vec<u8>,
+    pub amount: u64,
+    pub data_hash: [u8; 32],
+    pub nonce: u64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct InterchainTransferPartiallyFilled {
+    pub token_id: [u8; 32],
+    pub source_address: Pubkey,
+    pub requested_amount: u64,
+    pub filled_amount: u64,
+    pub unfilled_amount: u64,
+}
+
+/// Emitted by earlier versions of the program for inbound transfers,
+/// superseded by [`InterchainTransferReceivedV2`] and kept to decode past
+/// transactions.
 #[event]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct InterchainTransferReceived {
@@ -28,6 +63,32 @@ pub struct InterchainTransferReceived {
     pub data_hash: [u8; 32],
 }
 
+/// Emitted for inbound transfers, replacing the
+/// [`InterchainTransferReceived`] of earlier versions with the rent debited
+/// for the destination associated token account, if it was created.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct InterchainTransferReceivedV2 {
+    pub command_id: [u8; 32],
+    pub token_id: [u8; 32],
+    pub source_chain: String,
+    pub source_address: Vec<u8>,
+    pub destination_address: Pubkey,
+    pub destination_token_account: Pubkey,
+    pub amount: u64,
+    pub data_hash: [u8; 32],
+    pub ata_rent: Option<AtaRentDebit>,
+}
+
+/// Lamports debited from the payer of an inbound transfer to create the
+/// associated token account of the destination, recorded so that relayers
+/// can bill it to the token operator. The token is the one of the transfer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BorshSerialize, BorshDeserialize)]
+pub struct AtaRentDebit {
+    pub payer: Pubkey,
+    pub lamports: u64,
+}
+
 #[event]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct TokenMetadataRegistered {
@@ -35,6 +96,8 @@ pub struct TokenMetadataRegistered {
     pub decimals: u8,
 }
 
+/// Emitted by earlier versions of the program when linking a token,
+/// superseded by [`LinkTokenStartedV2`] and kept to decode past transactions.
 #[event]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct LinkTokenStarted {
@@ -46,6 +109,20 @@ pub struct LinkTokenStarted {
     pub params: Vec<u8>,
 }
 
+/// Emitted when linking a token, replacing the [`LinkTokenStarted`] of
+/// earlier versions with the outbound `nonce` of the deployer.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LinkTokenStartedV2 {
+    pub token_id: [u8; 32],
+    pub destination_chain: String,
+    pub source_token_address: Pubkey,
+    pub destination_token_address: Vec<u8>,
+    pub token_manager_type: u8,
+    pub params: Vec<u8>,
+    pub nonce: u64,
+}
+
 #[event]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct InterchainTokenDeploymentStarted {
@@ -112,14 +189,231 @@ pub struct FlowLimitSet {
     pub flow_limit: Option<u64>,
 }
 
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TokenObserverSet {
+    pub token_id: [u8; 32],
+    pub operator: Pubkey,
+    pub observer: Option<Pubkey>,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DestinationOwnerPolicySet {
+    pub token_id: [u8; 32],
+    pub operator: Pubkey,
+    pub policy: DestinationOwnerPolicy,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TokenManagerMaxSupplySet {
+    pub token_id: [u8; 32],
+    pub operator: Pubkey,
+    pub max_supply: u64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TokenManagerFeeCollectorSet {
+    pub token_id: [u8; 32],
+    pub operator: Pubkey,
+    pub fee_collector: Option<Pubkey>,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FlowSlotMigrated {
+    pub token_id: [u8; 32],
+    pub flow_slot: Pubkey,
+    pub epoch: u64,
+    pub flow_in: u64,
+    pub flow_out: u64,
+    pub folded: bool,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WithheldFeesHarvested {
+    pub token_id: [u8; 32],
+    pub operator: Pubkey,
+    pub destination_token_account: Pubkey,
+    pub amount: u64,
+}
+
+/// A binding recorded in a token manager that doesn't match the one derived
+/// from its token id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BorshSerialize, BorshDeserialize)]
+pub enum TokenManagerMismatch {
+    /// The mint of a native interchain token isn't the interchain token PDA.
+    TokenAddress { expected: Pubkey },
+    /// The mint isn't owned by the recorded token program.
+    TokenProgram { mint_owner: Pubkey },
+    /// The recorded associated token account isn't the one derived for the
+    /// token manager, the mint and the token program.
+    AssociatedTokenAccount { expected: Pubkey },
+    /// The associated token account isn't an initialized token account.
+    AssociatedTokenAccountNotInitialized,
+    /// The associated token account holds another mint.
+    AssociatedTokenAccountMint { mint: Pubkey },
+    /// The associated token account isn't owned by the token manager.
+    AssociatedTokenAccountOwner { owner: Pubkey },
+}
+
+/// Status of a token manager, consistent when `mismatches` is empty.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TokenManagerVerified {
+    pub token_id: [u8; 32],
+    pub token_manager: Pubkey,
+    pub mismatches: Vec<TokenManagerMismatch>,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TokenMetadataAuthoritySet {
+    pub token_id: [u8; 32],
+    pub minter: Pubkey,
+    pub operator: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DestinationOwnerMismatch {
+    pub token_id: [u8; 32],
+    pub destination_address: Pubkey,
+    pub destination_token_account: Pubkey,
+    pub token_account_owner: Pubkey,
+}
+
+/// Emitted by earlier versions of the program when a chain is trusted,
+/// superseded by [`TrustedChainSetV2`] and kept to decode past transactions.
 #[event]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct TrustedChainSet {
     pub chain_name: String,
 }
 
+/// Emitted when a chain is trusted, replacing the [`TrustedChainSet`] of
+/// earlier versions with the acting `authority`.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TrustedChainSetV2 {
+    pub chain_name: String,
+    pub authority: Pubkey,
+}
+
+/// Emitted by earlier versions of the program when a chain is no longer
+/// trusted, superseded by [`TrustedChainRemovedV2`] and kept to decode past
+/// transactions.
 #[event]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct TrustedChainRemoved {
     pub chain_name: String,
 }
+
+/// Emitted when a chain is no longer trusted, replacing the
+/// [`TrustedChainRemoved`] of earlier versions with the acting `authority`.
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TrustedChainRemovedV2 {
+    pub chain_name: String,
+    pub authority: Pubkey,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TrustedChainRenamed {
+    pub old_chain_name: String,
+    pub new_chain_name: String,
+    pub authority: Pubkey,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PauseStatusChanged {
+    pub paused: bool,
+    pub authority: Pubkey,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PauseFlagsSet {
+    pub pause_flags: u8,
+    pub authority: Pubkey,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MaxPayloadSizeSet {
+    pub chain_name: String,
+    pub max_size: Option<u32>,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DestinationExecutionPolicySet {
+    pub policy: Option<DestinationExecutionPolicy>,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DestinationExecutionFailed {
+    pub command_id: [u8; 32],
+    pub token_id: [u8; 32],
+    pub destination_program: Pubkey,
+    pub error_code: u64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct OperatorshipTransferredWithGracePeriod {
+    pub previous_operator: Pubkey,
+    pub new_operator: Pubkey,
+    pub expires_at_slot: u64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GracePeriodOperatorUsed {
+    pub operator: Pubkey,
+    pub expires_at_slot: u64,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TokenManagerAuthorityMismatch {
+    pub command_id: [u8; 32],
+    pub token_id: [u8; 32],
+    pub mint: Pubkey,
+    pub token_manager: Pubkey,
+    pub mint_authority: Option<Pubkey>,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HubMessageSent {
+    pub destination_chain: String,
+    pub hub_address: String,
+    pub payload_hash: [u8; 32],
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TokenManagerDecommissioned {
+    pub token_id: [u8; 32],
+    pub token_manager: Pubkey,
+    pub operator: Pubkey,
+    pub mint_authority: Option<Pubkey>,
+}
+
+#[event]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GasQuoteUsed {
+    pub signer: Pubkey,
+    pub destination_chain: String,
+    pub quoted_gas_value: u64,
+    pub gas_value: u64,
+    pub expires_at: i64,
+}
@@ -4,23 +4,57 @@ use axelar_solana_gateway::executable::AxelarMessagePayload;
 use axelar_solana_gateway::state::message_payload::ImmutMessagePayload;
 use borsh::{BorshDeserialize, BorshSerialize};
 use interchain_token_transfer_gmp::GMPPayload;
-use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
 use solana_program::msg;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 
 use crate::assert_valid_interchain_transfer_execute_pda;
 
+/// Index of the Interchain Transfer Execute PDA of the destination program,
+/// signing the call.
+pub const INTERCHAIN_TRANSFER_EXECUTE_ACCOUNT_INDEX: usize = 0;
+
+/// Index of the gateway Message Payload PDA of the transfer.
+pub const MESSAGE_PAYLOAD_ACCOUNT_INDEX: usize = 1;
+
+/// Index of the token program (spl-token or spl-token-2022).
+pub const TOKEN_PROGRAM_ACCOUNT_INDEX: usize = 2;
+
+/// Index of the token mint.
+pub const TOKEN_MINT_ACCOUNT_INDEX: usize = 3;
+
+/// Index of the associated token account of the destination program, which
+/// received the transferred tokens.
+pub const DESTINATION_PROGRAM_ATA_ACCOUNT_INDEX: usize = 4;
+
 /// The index of the first account that is expected to be passed to the
-/// destination program. The prepended accounts are:
+/// destination program, i.e. the number of accounts prepended by ITS. The
+/// prepended accounts are:
 ///
-/// 0. [signer] The Interchain Token Service Root PDA.
-/// 1. [] The Message Payload PDA.
-/// 2. [] The token program (spl-token or spl-token-2022).
-/// 3. [writable] The token mint.
-/// 4. [writable] The Destination Program Associated Token Account.
+/// 0. [signer] The Interchain Transfer Execute PDA
+///    ([`INTERCHAIN_TRANSFER_EXECUTE_ACCOUNT_INDEX`]).
+/// 1. [] The Message Payload PDA ([`MESSAGE_PAYLOAD_ACCOUNT_INDEX`]).
+/// 2. [] The token program (spl-token or spl-token-2022)
+///    ([`TOKEN_PROGRAM_ACCOUNT_INDEX`]).
+/// 3. [writable] The token mint ([`TOKEN_MINT_ACCOUNT_INDEX`]).
+/// 4. [writable] The Destination Program Associated Token Account
+///    ([`DESTINATION_PROGRAM_ATA_ACCOUNT_INDEX`]).
 pub const PROGRAM_ACCOUNTS_START_INDEX: usize = 5;
 
+// The prepended accounts are numbered consecutively, followed by the
+// destination program accounts.
+const _: () = assert!(
+    INTERCHAIN_TRANSFER_EXECUTE_ACCOUNT_INDEX == 0
+        && MESSAGE_PAYLOAD_ACCOUNT_INDEX == INTERCHAIN_TRANSFER_EXECUTE_ACCOUNT_INDEX + 1
+        && TOKEN_PROGRAM_ACCOUNT_INDEX == MESSAGE_PAYLOAD_ACCOUNT_INDEX + 1
+        && TOKEN_MINT_ACCOUNT_INDEX == TOKEN_PROGRAM_ACCOUNT_INDEX + 1
+        && DESTINATION_PROGRAM_ATA_ACCOUNT_INDEX == TOKEN_MINT_ACCOUNT_INDEX + 1
+        && PROGRAM_ACCOUNTS_START_INDEX == DESTINATION_PROGRAM_ATA_ACCOUNT_INDEX + 1,
+    "the prepended accounts must be numbered consecutively"
+);
+
 /// This is the payload that the `executeWithInterchainToken` processor on the destinatoin program
 /// must expect
 #[derive(Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
@@ -91,14 +125,53 @@ impl MaybeAxelarInterchainTokenExecutablePayload for &[u8] {
     }
 }
 
+/// Verifies that ITS signed the call to `program_id`, i.e. that the account
+/// at [`INTERCHAIN_TRANSFER_EXECUTE_ACCOUNT_INDEX`] is the Interchain Transfer
+/// Execute PDA of `program_id` and is a signer.
+///
+/// Only ITS can sign with that PDA, so programs handling
+/// `executeWithInterchainToken` must run this check before trusting the
+/// transferred amount or the call data. It is part of
+/// [`MaybeAxelarInterchainTokenExecutablePayload::try_get_axelar_interchain_token_executable_payload`],
+/// programs decoding the payload themselves must call it explicitly.
+///
+/// # Errors
+///
+/// - [`ProgramError::NotEnoughAccountKeys`] if `accounts` doesn't hold the
+///   signing PDA.
+/// - [`ProgramError::MissingRequiredSignature`] if the signing PDA didn't
+///   sign the call.
+/// - [`ProgramError::InvalidArgument`] if the account isn't the Interchain
+///   Transfer Execute PDA of `program_id`.
+pub fn verify_its_signer(program_id: &Pubkey, accounts: &[AccountInfo<'_>]) -> ProgramResult {
+    let signing_pda_account = accounts
+        .get(INTERCHAIN_TRANSFER_EXECUTE_ACCOUNT_INDEX)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    if !signing_pda_account.is_signer {
+        msg!(
+            "Signing PDA account must be a signer: {}",
+            signing_pda_account.key
+        );
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    assert_valid_interchain_transfer_execute_pda(signing_pda_account, program_id)?;
+
+    Ok(())
+}
+
 /// Validates accounts and extract extracts the call data associated with the [`AxelarInterchainTokenExecuteInfo`]
 fn extract_interchain_token_execute_call_data<'a>(
     accounts: &'a [AccountInfo<'a>],
 ) -> Result<Vec<u8>, ProgramError> {
     let (protocol_accounts, program_accounts) = accounts.split_at(PROGRAM_ACCOUNTS_START_INDEX);
-    let account_iter = &mut protocol_accounts.iter();
-    let signing_pda_account = next_account_info(account_iter)?;
-    let message_payload_account = next_account_info(account_iter)?;
+    let signing_pda_account = protocol_accounts
+        .get(INTERCHAIN_TRANSFER_EXECUTE_ACCOUNT_INDEX)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let message_payload_account = protocol_accounts
+        .get(MESSAGE_PAYLOAD_ACCOUNT_INDEX)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
     let message_payload_account_data = message_payload_account.try_borrow_data()?;
     let message_payload: ImmutMessagePayload<'_> = (**message_payload_account_data).try_into()?;
 
@@ -141,3 +214,51 @@ fn extract_interchain_token_execute_call_data<'a>(
 
     Ok(inner_payload.payload_without_accounts().to_vec())
 }
+
+#[cfg(test)]
+mod tests {
+    use solana_program::system_program;
+
+    use super::*;
+
+    fn verify(key: Pubkey, is_signer: bool, program_id: &Pubkey) -> ProgramResult {
+        let mut lamports = 0;
+        let mut data = [];
+        let account = AccountInfo::new(
+            &key,
+            is_signer,
+            false,
+            &mut lamports,
+            &mut data,
+            &system_program::ID,
+            false,
+            0,
+        );
+
+        verify_its_signer(program_id, &[account])
+    }
+
+    #[test]
+    fn test_verify_its_signer() {
+        let program_id = Pubkey::new_unique();
+        let (signing_pda, _) = crate::find_interchain_transfer_execute_pda(&program_id);
+
+        assert_eq!(verify(signing_pda, true, &program_id), Ok(()));
+        assert_eq!(
+            verify(signing_pda, false, &program_id),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+        assert_eq!(
+            verify(Pubkey::new_unique(), true, &program_id),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            verify(signing_pda, true, &Pubkey::new_unique()),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            verify_its_signer(&program_id, &[]),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+    }
+}
@@ -0,0 +1,258 @@
+//! Operator-signed quotes of the gas paid for remote deployments.
+//!
+//! The gas a remote deployment has to pay on its destination chain varies
+//! with the chain and over time, which leaves clients guessing. The ITS
+//! operator can instead quote it off-chain: a [`GasQuote`] signed with the
+//! operator key is verified by an Ed25519 program instruction placed right
+//! before the deployment, which checks it through instruction introspection.
+//! Anyone can submit a deployment carrying a quote, the operator only signs
+//! the price.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use program_utils::pda::BorshPda;
+use role_management::state::UserRoles;
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::ed25519_program;
+use solana_program::instruction::Instruction;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::{Pubkey, PUBKEY_BYTES};
+use solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use solana_program::sysvar::Sysvar;
+
+use crate::error::ItsError;
+use crate::Roles;
+
+/// Prefix of the signed message, so that a quote signature can't be taken for
+/// the signature of anything else.
+pub const GAS_QUOTE_DOMAIN: &[u8] = b"axelar-its-gas-quote";
+
+/// Size of an Ed25519 signature.
+const SIGNATURE_BYTES: usize = 64;
+
+/// Offset of the signature offsets in the Ed25519 program instruction data,
+/// after the signature count and a padding byte.
+const OFFSETS_START: usize = 2;
+
+/// Size of the signature offsets: seven `u16`.
+const OFFSETS_BYTES: usize = 14;
+
+/// Instruction index telling the Ed25519 program that the data lives in its
+/// own instruction.
+const OWN_INSTRUCTION: u16 = u16::MAX;
+
+/// The price of a remote deployment, as quoted by the ITS operator.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct GasQuote {
+    /// The chain the token is deployed to.
+    pub destination_chain: String,
+    /// The minimum gas value, in lamports, the deployment pays.
+    pub gas_value: u64,
+    /// The unix timestamp after which the quote can no longer be used.
+    pub expires_at: i64,
+}
+
+impl GasQuote {
+    /// The message the operator signs.
+    ///
+    /// # Errors
+    ///
+    /// If serialization fails.
+    pub fn message(&self) -> Result<Vec<u8>, ProgramError> {
+        let mut message = GAS_QUOTE_DOMAIN.to_vec();
+        self.serialize(&mut message)?;
+
+        Ok(message)
+    }
+}
+
+/// Creates the Ed25519 program instruction verifying `signature` of `quote` by
+/// `signer`, to be placed right before the remote deployment.
+///
+/// # Errors
+///
+/// If serialization fails or the quote is too large for the instruction.
+// The Ed25519 program reads its offsets as little-endian `u16`.
+#[allow(clippy::little_endian_bytes)]
+pub fn verify_gas_quote_instruction(
+    signer: &Pubkey,
+    signature: &[u8; SIGNATURE_BYTES],
+    quote: &GasQuote,
+) -> Result<Instruction, ProgramError> {
+    let message = quote.message()?;
+    let offset =
+        |offset: usize| u16::try_from(offset).map_err(|_err| ProgramError::InvalidInstructionData);
+
+    let public_key_offset = OFFSETS_START + OFFSETS_BYTES;
+    let signature_offset = public_key_offset + PUBKEY_BYTES;
+    let message_data_offset = signature_offset + SIGNATURE_BYTES;
+    let offsets = [
+        offset(signature_offset)?,
+        OWN_INSTRUCTION,
+        offset(public_key_offset)?,
+        OWN_INSTRUCTION,
+        offset(message_data_offset)?,
+        offset(message.len())?,
+        OWN_INSTRUCTION,
+    ];
+
+    let mut data = Vec::with_capacity(message_data_offset + message.len());
+    // A single signature, and a padding byte
+    data.extend_from_slice(&[1, 0]);
+    for value in offsets {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(&message);
+
+    Ok(Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    })
+}
+
+/// Reads the signer and the quote out of the data of an Ed25519 program
+/// instruction verifying a single signature of a [`GasQuote`], with the data
+/// in the instruction itself. The runtime rejects the transaction if the
+/// Ed25519 program fails to verify the signature.
+#[allow(clippy::little_endian_bytes)]
+fn signed_gas_quote(data: &[u8]) -> Option<(Pubkey, GasQuote)> {
+    if data.first() != Some(&1) {
+        msg!("The Ed25519 instruction must verify a single signature");
+        return None;
+    }
+
+    let offsets = data.get(OFFSETS_START..OFFSETS_START.checked_add(OFFSETS_BYTES)?)?;
+    let offset = |index: usize| {
+        let start = index.checked_mul(2)?;
+        let bytes = offsets.get(start..start.checked_add(2)?)?;
+        Some(u16::from_le_bytes(bytes.try_into().ok()?))
+    };
+    if [offset(1)?, offset(3)?, offset(6)?] != [OWN_INSTRUCTION; 3] {
+        msg!("The Ed25519 instruction must carry the quote and its signature");
+        return None;
+    }
+
+    let bytes_at = |offset: u16, len: usize| {
+        let offset = usize::from(offset);
+        data.get(offset..offset.checked_add(len)?)
+    };
+    let signer = Pubkey::try_from(bytes_at(offset(2)?, PUBKEY_BYTES)?).ok()?;
+    let message = bytes_at(offset(4)?, usize::from(offset(5)?))?;
+    let quote = GasQuote::try_from_slice(message.strip_prefix(GAS_QUOTE_DOMAIN)?).ok()?;
+
+    Some((signer, quote))
+}
+
+/// Checks the [`GasQuote`] verified by the instruction preceding the current
+/// one against a remote deployment to `destination_chain` paying
+/// `gas_value`, and returns it along with its signer.
+///
+/// # Errors
+///
+/// - [`ItsError::InvalidGasQuote`] if the preceding instruction doesn't
+///   verify a quote for `destination_chain`, or the quote isn't signed by the
+///   ITS operator, whose roles are `signer_roles`.
+/// - [`ItsError::GasQuoteExpired`] if the quote expired.
+/// - [`ItsError::GasBelowQuote`] if `gas_value` is below the quoted value.
+pub(crate) fn validate_gas_quote(
+    sysvar_instructions: &AccountInfo<'_>,
+    signer_roles: &AccountInfo<'_>,
+    its_root: &AccountInfo<'_>,
+    destination_chain: &str,
+    gas_value: u64,
+) -> Result<(Pubkey, GasQuote), ProgramError> {
+    let current_index = load_current_index_checked(sysvar_instructions)?;
+    let Some(preceding_index) = current_index.checked_sub(1) else {
+        msg!("Gas quotes must be verified by the preceding instruction");
+        return Err(ItsError::InvalidGasQuote.into());
+    };
+    let instruction =
+        load_instruction_at_checked(usize::from(preceding_index), sysvar_instructions)?;
+    if instruction.program_id != ed25519_program::ID {
+        msg!("Gas quotes must be verified by the preceding instruction");
+        return Err(ItsError::InvalidGasQuote.into());
+    }
+
+    let (signer, quote) = signed_gas_quote(&instruction.data).ok_or(ItsError::InvalidGasQuote)?;
+
+    let roles = UserRoles::<Roles>::load(signer_roles)?;
+    let (expected_roles, _) =
+        role_management::user_roles_pda(&crate::ID, its_root.key, &signer, Some(roles.bump()));
+    if *signer_roles.key != expected_roles || !roles.contains(Roles::OPERATOR) {
+        msg!("Gas quote signer {} is not the ITS operator", signer);
+        return Err(ItsError::InvalidGasQuote.into());
+    }
+
+    if quote.destination_chain != destination_chain {
+        msg!(
+            "Gas quote is for {}, not {}",
+            quote.destination_chain,
+            destination_chain
+        );
+        return Err(ItsError::InvalidGasQuote.into());
+    }
+
+    if Clock::get()?.unix_timestamp > quote.expires_at {
+        msg!("Gas quote expired at {}", quote.expires_at);
+        return Err(ItsError::GasQuoteExpired.into());
+    }
+
+    if gas_value < quote.gas_value {
+        msg!(
+            "Gas value {} is below the quoted {}",
+            gas_value,
+            quote.gas_value
+        );
+        return Err(ItsError::GasBelowQuote.into());
+    }
+
+    Ok((signer, quote))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quote() -> GasQuote {
+        GasQuote {
+            destination_chain: "ethereum".to_owned(),
+            gas_value: 1_500_000,
+            expires_at: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn test_signed_gas_quote_round_trips() {
+        let signer = Pubkey::new_unique();
+        let instruction = verify_gas_quote_instruction(&signer, &[7; 64], &quote()).unwrap();
+
+        assert_eq!(instruction.program_id, ed25519_program::ID);
+        assert_eq!(signed_gas_quote(&instruction.data), Some((signer, quote())));
+    }
+
+    #[test]
+    fn test_other_messages_are_not_quotes() {
+        let signer = Pubkey::new_unique();
+        let mut instruction = verify_gas_quote_instruction(&signer, &[7; 64], &quote()).unwrap();
+        let domain_start = instruction.data.len() - quote().message().unwrap().len();
+        instruction.data[domain_start] ^= 0xff;
+
+        assert_eq!(signed_gas_quote(&instruction.data), None);
+    }
+
+    #[test]
+    fn test_quotes_must_live_in_the_ed25519_instruction() {
+        let signer = Pubkey::new_unique();
+        let mut instruction = verify_gas_quote_instruction(&signer, &[7; 64], &quote()).unwrap();
+        // The message instruction index
+        instruction.data[14..16].copy_from_slice(&0_u16.to_le_bytes());
+
+        assert_eq!(signed_gas_quote(&instruction.data), None);
+    }
+}
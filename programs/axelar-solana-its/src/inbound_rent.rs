@@ -0,0 +1,290 @@
+//! Rent paid by the payer of an inbound interchain transfer.
+//!
+//! Executing an inbound transfer creates the destination associated token
+//! account when it doesn't exist yet, and the [`InterchainTransferExecute`]
+//! PDA of the destination program the first time it's called with data. Both
+//! are paid by the relayer executing the message, which can't tell the cost
+//! from the message alone. [`estimate_inbound_transfer_rent`] reports which
+//! of these accounts would be created and the lamports they require, both
+//! on-chain through
+//! [`EstimateInboundRent`](crate::instruction::InterchainTokenServiceInstruction::EstimateInboundRent)
+//! and off-chain from fetched accounts.
+//!
+//! The flow of a token is tracked in its [`TokenManager`], so no flow
+//! account is ever created by an inbound transfer.
+//!
+//! [`TokenManager`]: crate::state::token_manager::TokenManager
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use interchain_token_transfer_gmp::{GMPPayload, InterchainTransfer};
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions};
+use spl_token_2022::state::{Account as TokenAccount, Mint};
+
+use crate::state::interchain_transfer_execute::InterchainTransferExecute;
+
+/// An account the execution of an inbound transfer would create.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct RentedAccount {
+    /// The address of the account.
+    pub address: Pubkey,
+    /// The size of the account data, in bytes.
+    pub space: u64,
+    /// The lamports the payer transfers to make the account rent exempt.
+    pub lamports: u64,
+}
+
+/// The accounts the execution of an inbound transfer would create, as
+/// returned by
+/// [`EstimateInboundRent`](crate::instruction::InterchainTokenServiceInstruction::EstimateInboundRent).
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct InboundRentEstimate {
+    /// The accounts that would be created, empty when the payer pays no
+    /// rent.
+    pub accounts: Vec<RentedAccount>,
+}
+
+impl InboundRentEstimate {
+    /// The lamports the payer of the execution needs on top of the
+    /// transaction fees.
+    #[must_use]
+    pub fn total_lamports(&self) -> u64 {
+        self.accounts
+            .iter()
+            .fold(0, |total, account| total.saturating_add(account.lamports))
+    }
+
+    fn push(&mut self, rent: &Rent, address: Pubkey, space: usize) -> Result<(), ProgramError> {
+        self.accounts.push(RentedAccount {
+            address,
+            space: u64::try_from(space).map_err(|_err| ProgramError::ArithmeticOverflow)?,
+            lamports: rent.minimum_balance(space),
+        });
+
+        Ok(())
+    }
+}
+
+/// The state of an account at the time of the estimate. Accounts that don't
+/// exist are owned by the system program and have no data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountSnapshot<'a> {
+    /// The address of the account.
+    pub address: Pubkey,
+    /// The program owning the account.
+    pub owner: Pubkey,
+    /// The data of the account.
+    pub data: &'a [u8],
+}
+
+impl AccountSnapshot<'_> {
+    fn is_token_account_of(&self, token_program: &Pubkey, mint: &Pubkey) -> bool {
+        self.owner == *token_program
+            && StateWithExtensions::<TokenAccount>::unpack(self.data)
+                .is_ok_and(|token_account| token_account.base.mint == *mint)
+    }
+
+    fn is_initialized_pda(&self) -> bool {
+        self.owner == crate::ID && self.data.iter().any(|byte| *byte != 0)
+    }
+}
+
+/// Decodes the interchain transfer carried by the payload of an inbound
+/// message, either as received from the hub or already unwrapped.
+///
+/// # Errors
+///
+/// [`ProgramError::InvalidInstructionData`] if the payload can't be decoded
+/// or isn't an interchain transfer.
+pub fn decode_inbound_transfer(payload: &[u8]) -> Result<InterchainTransfer, ProgramError> {
+    let decoded =
+        GMPPayload::decode(payload).map_err(|_err| ProgramError::InvalidInstructionData)?;
+    let payload = if let GMPPayload::ReceiveFromHub(inner) = decoded {
+        GMPPayload::decode(&inner.payload).map_err(|_err| ProgramError::InvalidInstructionData)?
+    } else {
+        decoded
+    };
+
+    match payload {
+        GMPPayload::InterchainTransfer(transfer) => Ok(transfer),
+        GMPPayload::DeployInterchainToken(_)
+        | GMPPayload::SendToHub(_)
+        | GMPPayload::ReceiveFromHub(_)
+        | GMPPayload::LinkToken(_)
+        | GMPPayload::RegisterTokenMetadata(_) => {
+            msg!("Rent can only be estimated for interchain transfers");
+            Err(ProgramError::InvalidInstructionData)
+        }
+    }
+}
+
+/// Estimates the rent paid by the payer of an inbound transfer of `mint`,
+/// following the same rules as the execution: the destination associated
+/// token account is only created if neither the destination address nor the
+/// associated token account is a token account of the mint.
+///
+/// `transfer_execute` is the [`InterchainTransferExecute`] PDA of the
+/// destination program, for transfers carrying data.
+///
+/// # Errors
+///
+/// If the mint can't be decoded or the size of the associated token account
+/// can't be computed.
+pub fn estimate_inbound_transfer_rent(
+    rent: &Rent,
+    token_program: &Pubkey,
+    mint: &AccountSnapshot<'_>,
+    destination: &AccountSnapshot<'_>,
+    destination_ata: &AccountSnapshot<'_>,
+    transfer_execute: Option<&AccountSnapshot<'_>>,
+) -> Result<InboundRentEstimate, ProgramError> {
+    let mut estimate = InboundRentEstimate::default();
+
+    if !destination.is_token_account_of(token_program, &mint.address)
+        && !destination_ata.is_token_account_of(token_program, &mint.address)
+    {
+        estimate.push(
+            rent,
+            destination_ata.address,
+            associated_token_account_space(token_program, mint.data)?,
+        )?;
+    }
+
+    if let Some(transfer_execute) = transfer_execute.filter(|pda| !pda.is_initialized_pda()) {
+        let space = borsh::to_vec(&InterchainTransferExecute::new(0))?.len();
+        estimate.push(rent, transfer_execute.address, space)?;
+    }
+
+    Ok(estimate)
+}
+
+/// The size of the associated token account of a mint, which for Token 2022
+/// depends on the extensions of the mint.
+fn associated_token_account_space(
+    token_program: &Pubkey,
+    mint_data: &[u8],
+) -> Result<usize, ProgramError> {
+    if *token_program != spl_token_2022::ID {
+        return Ok(TokenAccount::LEN);
+    }
+
+    let mint = StateWithExtensions::<Mint>::unpack(mint_data)?;
+    let mut extensions =
+        ExtensionType::get_required_init_account_extensions(&mint.get_extension_types()?);
+    if !extensions.contains(&ExtensionType::ImmutableOwner) {
+        extensions.push(ExtensionType::ImmutableOwner);
+    }
+
+    ExtensionType::try_calculate_account_len::<TokenAccount>(&extensions)
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::program_option::COption;
+    use solana_program::system_program;
+
+    use super::*;
+
+    fn mint_data() -> Vec<u8> {
+        let mut data = vec![0; Mint::LEN];
+        Mint {
+            mint_authority: COption::None,
+            supply: 0,
+            decimals: 9,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }
+        .pack_into_slice(&mut data);
+        data
+    }
+
+    fn token_account_data(mint: Pubkey, owner: Pubkey) -> Vec<u8> {
+        let mut data = vec![0; TokenAccount::LEN];
+        TokenAccount {
+            mint,
+            owner,
+            state: spl_token_2022::state::AccountState::Initialized,
+            ..TokenAccount::default()
+        }
+        .pack_into_slice(&mut data);
+        data
+    }
+
+    const fn missing(address: Pubkey) -> AccountSnapshot<'static> {
+        AccountSnapshot {
+            address,
+            owner: system_program::ID,
+            data: &[],
+        }
+    }
+
+    #[test]
+    fn test_missing_accounts_are_reported() {
+        let rent = Rent::default();
+        let mint_data = mint_data();
+        let mint = AccountSnapshot {
+            address: Pubkey::new_unique(),
+            owner: spl_token_2022::ID,
+            data: &mint_data,
+        };
+        let destination_ata = Pubkey::new_unique();
+        let transfer_execute = Pubkey::new_unique();
+
+        let estimate = estimate_inbound_transfer_rent(
+            &rent,
+            &spl_token_2022::ID,
+            &mint,
+            &missing(Pubkey::new_unique()),
+            &missing(destination_ata),
+            Some(&missing(transfer_execute)),
+        )
+        .unwrap();
+
+        // The account type and the empty `ImmutableOwner` extension
+        let ata_space = 170;
+        assert_eq!(estimate.accounts.len(), 2);
+        assert_eq!(estimate.accounts[0].address, destination_ata);
+        assert_eq!(estimate.accounts[0].space, ata_space as u64);
+        assert_eq!(estimate.accounts[1].address, transfer_execute);
+        assert_eq!(
+            estimate.total_lamports(),
+            rent.minimum_balance(ata_space)
+                + rent.minimum_balance(estimate.accounts[1].space as usize)
+        );
+    }
+
+    #[test]
+    fn test_existing_token_account_is_not_reported() {
+        let rent = Rent::default();
+        let mint_data = mint_data();
+        let mint = AccountSnapshot {
+            address: Pubkey::new_unique(),
+            owner: spl_token_2022::ID,
+            data: &mint_data,
+        };
+        let wallet = Pubkey::new_unique();
+        let token_account_data = token_account_data(mint.address, wallet);
+        let destination_ata = AccountSnapshot {
+            address: Pubkey::new_unique(),
+            owner: spl_token_2022::ID,
+            data: &token_account_data,
+        };
+
+        let estimate = estimate_inbound_transfer_rent(
+            &rent,
+            &spl_token_2022::ID,
+            &mint,
+            &missing(wallet),
+            &destination_ata,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(estimate, InboundRentEstimate::default());
+        assert_eq!(estimate.total_lamports(), 0);
+    }
+}
@@ -17,9 +17,12 @@ use solana_program::{system_program, sysvar};
 use spl_associated_token_account::get_associated_token_address_with_program_id;
 use typed_builder::TypedBuilder;
 
+use crate::link_params::LinkParams;
 use crate::state;
 
+pub mod address;
 pub mod interchain_token;
+pub mod lookup_table;
 pub mod token_manager;
 
 /// Instructions supported by the ITS program.
@@ -51,6 +54,8 @@ pub enum InterchainTokenServiceInstruction {
     /// 1. [] The program data account.
     /// 2. [writable] ITS root PDA.
     /// 3. [] System program account
+    /// 4. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 5. [] The ITS program account.
     SetPauseStatus {
         /// The new pause status.
         paused: bool,
@@ -163,13 +168,18 @@ pub enum InterchainTokenServiceInstruction {
     /// 6. [] The gateway event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
     /// 7. [] The GMP gateway program account
     /// 8. [writable] The GMP gas configuration account
-    /// 9. [] The gas service event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gas service program ID).
-    /// 10. [] The GMP gas service program account
-    /// 11. [] The system program account
-    /// 12. [] The GMP call contract signing account
-    /// 13. [] The ITS program account
-    /// 14. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
-    /// 15. [] The ITS program account.
+    /// 9. [] The GMP gas service paused chains PDA
+    /// 10. [] The gas service event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gas service program ID).
+    /// 11. [] The GMP gas service program account
+    /// 12. [] The system program account
+    /// 13. [] The GMP call contract signing account
+    /// 14. [] The ITS program account
+    /// 15. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 16. [] The ITS program account.
+    /// 17. [] Optional: the instructions sysvar, to check a
+    ///    [`GasQuote`](crate::gas_quote::GasQuote) verified by the preceding
+    ///    instruction. See [`with_gas_quote`].
+    /// 18. [] Optional: the account holding the roles of the quote signer on the ITS root
     DeployRemoteCanonicalInterchainToken {
         /// The remote chain where the `InterchainToken` should be deployed.
         destination_chain: String,
@@ -181,12 +191,16 @@ pub enum InterchainTokenServiceInstruction {
 
     /// Transfers interchain tokens.
     ///
+    /// For `MintBurnFrom` token managers the tokens are burnt by the `TokenManager`
+    /// PDA using an allowance, thus the owner of the source account must first
+    /// approve the `TokenManager` PDA as delegate for at least `amount` tokens.
+    ///
     /// Accounts expected by this instruction:
     ///
     /// 0. [writable,signer] The account which is paying for the transaction
     /// 1. [signer] The address of the owner or delegate of the source account of the
     ///    transfer. In case it's the `TokenManager`, it shouldn't be set as signer as the signing
-    ///    happens on chain.
+    ///    happens on chain. For `MintBurnFrom` token managers, this must be the owner.
     /// 2. [] The ITS root account
     /// 3. [writable] The source account from which the tokens are being transferred
     /// 4. [writable] The mint account (token address)
@@ -200,9 +214,13 @@ pub enum InterchainTokenServiceInstruction {
     /// 12. [] The gateway event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
     /// 13. [] The GMP gateway program account
     /// 14. [writable] The GMP gas configuration account
-    /// 15. [] The gas service event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gas service program ID).
-    /// 16. [] The GMP gas service program account
-    /// 17. [] The GMP call contract signing account
+    /// 15. [] The GMP gas service paused chains PDA
+    /// 16. [] The gas service event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gas service program ID).
+    /// 17. [] The GMP gas service program account
+    /// 18. [] The GMP call contract signing account
+    /// 19. [writable] The outbound nonce PDA of the sender, created on first use
+    /// 20. [] The token observer PDA of the token
+    /// 21. [] The observer program registered for the token, or the ITS program ID if none
     InterchainTransfer {
         /// The token id associated with the token
         token_id: [u8; 32],
@@ -246,9 +264,13 @@ pub enum InterchainTokenServiceInstruction {
     /// 12. [] The gateway event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
     /// 13. [] The GMP gateway program account
     /// 14. [writable] The GMP gas configuration account
-    /// 15. [] The gas service event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gas service program ID).
-    /// 16. [] The GMP gas service program account
-    /// 17. [] The GMP call contract signing account
+    /// 15. [] The GMP gas service paused chains PDA
+    /// 16. [] The gas service event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gas service program ID).
+    /// 17. [] The GMP gas service program account
+    /// 18. [] The GMP call contract signing account
+    /// 19. [writable] The outbound nonce PDA of the sender, created on first use
+    /// 20. [] The token observer PDA of the token
+    /// 21. [] The observer program registered for the token, or the ITS program ID if none
     CpiInterchainTransfer {
         /// The token id associated with the token
         token_id: [u8; 32],
@@ -288,7 +310,7 @@ pub enum InterchainTokenServiceInstruction {
     /// 4. [writable] The token manager account associated with the interchain token
     /// 5. [writable] The mint account (token address) to deploy
     /// 6. [writable] The token manager Associated Token Account associated with the mint
-    /// 7. [] The token program account (`spl_token_2022`)
+    /// 7. [] The token program account (`spl_token` or `spl_token_2022`)
     /// 8. [] The Associated Token Account program account (`spl_associated_token_account`)
     /// 9. [] The rent sysvar account
     /// 10. [] The instructions sysvar account
@@ -330,13 +352,18 @@ pub enum InterchainTokenServiceInstruction {
     /// 7. [] The gateway event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
     /// 8. [] The GMP gateway program account
     /// 9. [writable] The GMP gas configuration account
-    /// 10. [] The gas service event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gas service program ID).
-    /// 11. [] The GMP gas service program account
-    /// 12. [] The system program account
-    /// 13. [] The GMP call contract signing account
-    /// 14. [] The ITS program account
-    /// 15. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
-    /// 16. [] The ITS program account.
+    /// 10. [] The GMP gas service paused chains PDA
+    /// 11. [] The gas service event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gas service program ID).
+    /// 12. [] The GMP gas service program account
+    /// 13. [] The system program account
+    /// 14. [] The GMP call contract signing account
+    /// 15. [] The ITS program account
+    /// 16. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 17. [] The ITS program account.
+    /// 18. [] Optional: the instructions sysvar, to check a
+    ///    [`GasQuote`](crate::gas_quote::GasQuote) verified by the preceding
+    ///    instruction. See [`with_gas_quote`].
+    /// 19. [] Optional: the account holding the roles of the quote signer on the ITS root
     DeployRemoteInterchainToken {
         /// The salt used to derive the tokenId associated with the token
         salt: [u8; 32],
@@ -368,13 +395,18 @@ pub enum InterchainTokenServiceInstruction {
     /// 10. [] The gateway event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
     /// 11. [] The GMP gateway program account
     /// 12. [writable] The GMP gas configuration account
-    /// 13. [] The gas service event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gas service program ID).
-    /// 14. [] The GMP gas service program account
-    /// 15. [] The system program account
-    /// 16. [] The GMP call contract signing account
-    /// 17. [] The ITS program account
-    /// 18. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
-    /// 19. [] The ITS program account.
+    /// 13. [] The GMP gas service paused chains PDA
+    /// 14. [] The gas service event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gas service program ID).
+    /// 15. [] The GMP gas service program account
+    /// 16. [] The system program account
+    /// 17. [] The GMP call contract signing account
+    /// 18. [] The ITS program account
+    /// 19. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 20. [] The ITS program account.
+    /// 21. [] Optional: the instructions sysvar, to check a
+    ///    [`GasQuote`](crate::gas_quote::GasQuote) verified by the preceding
+    ///    instruction. See [`with_gas_quote`].
+    /// 22. [] Optional: the account holding the roles of the quote signer on the ITS root
     DeployRemoteInterchainTokenWithMinter {
         /// The salt used to derive the tokenId associated with the token
         salt: [u8; 32],
@@ -403,13 +435,14 @@ pub enum InterchainTokenServiceInstruction {
     /// 4. [] The gateway event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
     /// 5. [] The GMP gateway program account
     /// 6. [writable] The GMP gas configuration account
-    /// 7. [] The gas service event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gas service program ID).
-    /// 8. [] The GMP gas service program account
-    /// 9. [] The system program account
-    /// 10. [] The GMP call contract signing account
-    /// 11. [] The ITS program account
-    /// 12. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
-    /// 13. [] The ITS program account.
+    /// 7. [] The GMP gas service paused chains PDA
+    /// 8. [] The gas service event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gas service program ID).
+    /// 9. [] The GMP gas service program account
+    /// 10. [] The system program account
+    /// 11. [] The GMP call contract signing account
+    /// 12. [] The ITS program account
+    /// 13. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 14. [] The ITS program account.
     RegisterTokenMetadata {
         /// The gas value to be paid for the GMP transaction
         gas_value: u64,
@@ -457,13 +490,15 @@ pub enum InterchainTokenServiceInstruction {
     /// 5. [] The gateway event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
     /// 6. [] The GMP gateway program account
     /// 7. [writable] The GMP gas configuration account
-    /// 8. [] The gas service event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gas service program ID).
-    /// 9. [] The GMP gas service program account
-    /// 10. [] The system program account
-    /// 11. [] The GMP call contract signing account
-    /// 12. [] The ITS program account
-    /// 13. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
-    /// 14. [] The ITS program account.
+    /// 8. [] The GMP gas service paused chains PDA
+    /// 9. [] The gas service event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gas service program ID).
+    /// 10. [] The GMP gas service program account
+    /// 11. [] The system program account
+    /// 12. [] The GMP call contract signing account
+    /// 13. [] The ITS program account
+    /// 14. [writable] The outbound nonce PDA of the deployer, created on first use
+    /// 15. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 16. [] The ITS program account.
     LinkToken {
         /// Salt used to derive the `token_id` associated with the token.
         salt: [u8; 32],
@@ -473,7 +508,8 @@ pub enum InterchainTokenServiceInstruction {
         destination_token_address: Vec<u8>,
         /// The type of token manager used on the destination chain.
         token_manager_type: state::token_manager::Type,
-        /// The params required on the destination chain.
+        /// The params required on the destination chain. Links towards
+        /// another Solana chain use the [`LinkParams`] encoding.
         link_params: Vec<u8>,
         /// The gas value to be paid for the GMP transaction
         gas_value: u64,
@@ -504,9 +540,13 @@ pub enum InterchainTokenServiceInstruction {
     /// 12. [] The gateway event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
     /// 13. [] The GMP gateway program account
     /// 14. [writable] The GMP gas configuration account
-    /// 15. [] The gas service event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gas service program ID).
-    /// 16. [] The GMP gas service program account
-    /// 17. [] The GMP call contract signing account
+    /// 15. [] The GMP gas service paused chains PDA
+    /// 16. [] The gas service event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gas service program ID).
+    /// 17. [] The GMP gas service program account
+    /// 18. [] The GMP call contract signing account
+    /// 19. [writable] The outbound nonce PDA of the sender, created on first use
+    /// 20. [] The token observer PDA of the token
+    /// 21. [] The observer program registered for the token, or the ITS program ID if none
     CallContractWithInterchainToken {
         /// The token id associated with the token
         token_id: [u8; 32],
@@ -553,9 +593,13 @@ pub enum InterchainTokenServiceInstruction {
     /// 12. [] The gateway event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
     /// 13. [] The GMP gateway program account
     /// 14. [writable] The GMP gas configuration account
-    /// 15. [] The gas service event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gas service program ID).
-    /// 16. [] The GMP gas service program account
-    /// 17. [] The GMP call contract signing account
+    /// 15. [] The GMP gas service paused chains PDA
+    /// 16. [] The gas service event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gas service program ID).
+    /// 17. [] The GMP gas service program account
+    /// 18. [] The GMP call contract signing account
+    /// 19. [writable] The outbound nonce PDA of the sender, created on first use
+    /// 20. [] The token observer PDA of the token
+    /// 21. [] The observer program registered for the token, or the ITS program ID if none
     CpiCallContractWithInterchainToken {
         /// The token id associated with the token
         token_id: [u8; 32],
@@ -604,13 +648,14 @@ pub enum InterchainTokenServiceInstruction {
         flow_limit: Option<u64>,
     },
 
-    /// Transfers operatorship to another account.
+    /// Transfers operatorship to another account, ending the grace period of
+    /// a previous [`InterchainTokenServiceInstruction::TransferOperatorshipWithGracePeriod`].
     ///
     /// 0. [] System program account.
     /// 1. [writable, signer] Payer account.
     /// 2. [] PDA for the payer roles on the resource which the operatorship is being transferred
     ///    from.
-    /// 3. [] PDA for the resource.
+    /// 3. [writable] PDA for the resource.
     /// 4. [] Account to transfer operatorship to.
     /// 5. [writable] PDA with the roles on the resource the
     ///    operatorship is being transferred to.
@@ -618,32 +663,38 @@ pub enum InterchainTokenServiceInstruction {
 
     /// Proposes operatorship transfer to another account.
     ///
+    /// The account operatorship is proposed to can be a PDA, e.g. of a
+    /// governance program. Its roles account is created here, so that the
+    /// program owning the PDA can accept with `invoke_signed` without paying
+    /// for it.
+    ///
     /// 0. [] System program account.
     /// 1. [writable, signer] Payer account.
-    /// 2. [] PDA for the payer roles on the resource.
-    /// 3. [] PDA for the resource.
-    /// 4. [] Account to transfer operatorship to.
-    /// 5. [writable] PDA with the roles on the resource for the accounts the
+    /// 2. [signer] Account proposing the transfer, holding the operator role.
+    /// 3. [] PDA for the proposer roles on the resource.
+    /// 4. [] PDA for the resource.
+    /// 5. [] Account to transfer operatorship to.
+    /// 6. [writable] PDA with the roles on the resource for the account the
     ///    operatorship is being transferred to.
-    /// 6. [] Account which the operatorship is being transferred from.
-    /// 7. [writable] PDA with the roles on the resource for the account the
-    ///    operatorship is being transferred from.
-    /// 8. [writable] PDA for the proposal
+    /// 7. [writable] PDA for the proposal
     ProposeOperatorship,
 
     /// Accepts operatorship transfer from another account.
     ///
+    /// When operatorship was proposed to a PDA, the program owning it accepts
+    /// by invoking this instruction with `invoke_signed`.
+    ///
     /// 0. [] System program account.
     /// 1. [writable, signer] Payer account.
-    /// 2. [] PDA for the payer roles on the resource.
-    /// 3. [] PDA for the resource.
-    /// 4. [] Account to transfer operatorship to.
-    /// 5. [writable] PDA with the roles on the resource for the accounts the
+    /// 2. [signer] Account the operatorship is being transferred to.
+    /// 3. [writable] PDA with the roles on the resource for the account the
     ///    operatorship is being transferred to.
-    /// 6. [] Account which the operatorship is being transferred from.
-    /// 7. [writable] PDA with the roles on the resource for the account the
+    /// 4. [writable] PDA for the resource.
+    /// 5. [writable] Account which the operatorship is being transferred from,
+    ///    receiving the rent of the proposal.
+    /// 6. [writable] PDA with the roles on the resource for the account the
     ///    operatorship is being transferred from.
-    /// 8. [writable] PDA for the proposal
+    /// 7. [writable] PDA for the proposal
     AcceptOperatorship,
 
     /// Adds a flow limiter to a [`TokenManager`].
@@ -653,7 +704,7 @@ pub enum InterchainTokenServiceInstruction {
     /// 2. [writable,signer] Payer account.
     /// 3. [signer] Account with operator role on the token manager.
     /// 4. [] PDA for the operator's roles on the token manager.
-    /// 5. [] PDA for the token manager.
+    /// 5. [writable] PDA for the token manager.
     /// 6. [] Account to add as flow limiter.
     /// 7. [writable] PDA with the roles on the token manager for the flow limiter being added.
     AddTokenManagerFlowLimiter,
@@ -692,7 +743,7 @@ pub enum InterchainTokenServiceInstruction {
     /// 2. [writable,signer] Payer account.
     /// 3. [signer] Sender account with operator role.
     /// 4. [writable] PDA for the sender's roles on the token manager.
-    /// 5. [] PDA for the token manager.
+    /// 5. [writable] PDA for the token manager.
     /// 6. [] Account to transfer operatorship to.
     /// 7. [writable] PDA with the roles on the token manager for the destination account.
     TransferTokenManagerOperatorship,
@@ -717,7 +768,7 @@ pub enum InterchainTokenServiceInstruction {
     /// 2. [writable,signer] Payer account.
     /// 3. [signer] Accepter account.
     /// 4. [writable] PDA for the accepter's roles on the token manager.
-    /// 5. [] PDA for the token manager.
+    /// 5. [writable] PDA for the token manager.
     /// 6. [] Account that operatorship is being transferred from.
     /// 7. [writable] PDA with the roles on the token manager for the origin account.
     /// 8. [writable] PDA for the proposal
@@ -731,10 +782,13 @@ pub enum InterchainTokenServiceInstruction {
     /// 1. [signer] The current mint authority
     /// 2. [writable] The mint for which the authority is being handed over
     /// 3. [] ITS root account
-    /// 4. [] The [`TokenManager`] account associated with the mint
+    /// 4. [writable] The [`TokenManager`] account associated with the mint
     /// 5. [writable] The account that will hold the roles of the former authority on the [`TokenManager`]
     /// 6. [] The token program used to create the mint
     /// 7. [] The system program account
+    /// 8..8+M. [signer] When the current mint authority is a SPL multisig, the M signer accounts
+    ///    approving the handover. In this case the authority (1) is the multisig account and is not
+    ///    a signer, and the minter role is granted to the payer, which must be one of the signers.
     HandoverMintAuthority {
         /// The id of the token registered with ITS for which the authority is being handed over.
         token_id: [u8; 32],
@@ -763,7 +817,7 @@ pub enum InterchainTokenServiceInstruction {
     /// 2. [writable,signer] Payer account.
     /// 3. [signer] Account with minter role on the token manager.
     /// 4. [writable] PDA for the sender's roles on the token manager.
-    /// 5. [] PDA for the token manager.
+    /// 5. [writable] PDA for the token manager.
     /// 6. [] Account to transfer mintership to.
     /// 7. [writable] PDA with the roles on the token manager for the destination account.
     TransferInterchainTokenMintership,
@@ -788,7 +842,7 @@ pub enum InterchainTokenServiceInstruction {
     /// 2. [writable,signer] Payer account.
     /// 3. [signer] Accepter account.
     /// 4. [writable] PDA for the accepter's roles on the token manager.
-    /// 5. [] PDA for the token manager.
+    /// 5. [writable] PDA for the token manager.
     /// 6. [writable] Account which the mintership is being transferred from.
     /// 7. [writable] PDA with the roles on the token manager for the origin account.
     /// 8. [writable] PDA for the proposal
@@ -805,137 +859,712 @@ pub enum InterchainTokenServiceInstruction {
         /// The GMP metadata
         message: Message,
     },
-}
-
-/// Inputs for the [`execute`] function.
-///
-/// To construct this type, use its builder API.
-///
-/// # Example
-///
-/// ```ignore
-/// use axelar_solana_its::instructions::ExecuteInstructionInputs;
-///
-/// let inputs = ExecuteInstructionInputs::builder()
-///   .payer(payer_pubkey)
-///   .incoming_message_pda(gateway_approved_message_pda)
-///   .message(message)
-///   .payload(payload)
-///   .token_program(spl_token_2022::ID)
-///   .mint(mint_pubkey)
-///   .bumps(bumps)
-///   .build();
-/// ```
-#[derive(Debug, Clone, TypedBuilder)]
-pub struct ExecuteInstructionInputs {
-    /// The payer account.
-    pub(crate) payer: Pubkey,
 
-    /// The PDA used to track the message status by the gateway program.
-    pub(crate) incoming_message_pda: Pubkey,
+    /// Computes the `token_id` of the interchain token deployed by `deployer`
+    /// with `salt` and sets it as the return data.
+    ///
+    /// No accounts are expected by this instruction.
+    PreviewInterchainTokenId {
+        /// The account deploying the interchain token.
+        deployer: Pubkey,
+        /// Salt used to derive the `token_id`.
+        salt: [u8; 32],
+    },
 
-    /// The PDA used to store the message payload.
-    pub(crate) message_payload_pda: Pubkey,
+    /// Computes the `token_id` of the canonical interchain token registered
+    /// for `mint` and sets it as the return data.
+    ///
+    /// No accounts are expected by this instruction.
+    PreviewCanonicalTokenId {
+        /// The mint of the canonical token.
+        mint: Pubkey,
+    },
 
-    /// The Axelar GMP metadata.
-    pub(crate) message: Message,
+    /// Computes the `token_id` of the custom token registered by `deployer`
+    /// with `salt` and sets it as the return data.
+    ///
+    /// No accounts are expected by this instruction.
+    PreviewLinkedTokenId {
+        /// The account registering the custom token.
+        deployer: Pubkey,
+        /// Salt used to derive the `token_id`.
+        salt: [u8; 32],
+    },
 
-    /// The ITS GMP payload.
-    pub(crate) payload: GMPPayload,
+    /// Transfers the ITS operatorship to another account, like
+    /// [`InterchainTokenServiceInstruction::TransferOperatorship`], while the
+    /// previous operator keeps being honored for ITS root operations during
+    /// `grace_period_slots` slots. Every use of the previous operator during
+    /// the grace period emits a
+    /// [`GracePeriodOperatorUsed`](crate::events::GracePeriodOperatorUsed)
+    /// event. A later operatorship transfer ends the grace period.
+    ///
+    /// 0. [] System program account.
+    /// 1. [writable, signer] Payer account.
+    /// 2. [writable, signer] Account holding the operator role.
+    /// 3. [writable] PDA for the operator roles on the ITS root PDA.
+    /// 4. [writable] ITS root PDA.
+    /// 5. [] Account to transfer operatorship to.
+    /// 6. [writable] PDA with the roles on the ITS root PDA for the account the
+    ///    operatorship is being transferred to.
+    /// 7. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 8. [] The ITS program account.
+    TransferOperatorshipWithGracePeriod {
+        /// Number of slots the previous operator is honored for, at most
+        /// [`MAX_OPERATOR_GRACE_PERIOD_SLOTS`](crate::state::MAX_OPERATOR_GRACE_PERIOD_SLOTS).
+        grace_period_slots: u64,
+    },
 
-    /// The token program required by the instruction (spl-token or
-    /// spl-token-2022).
-    pub(crate) token_program: Pubkey,
+    /// Logs the flow of a token in the current epoch and sets it as the
+    /// return data, as a borsh serialized
+    /// [`FlowStatus`](state::flow_limit::FlowStatus).
+    ///
+    /// 0. [] ITS root PDA account.
+    /// 1. [] The [`TokenManager`] PDA account.
+    GetFlowStatus,
 
-    /// The mint account required by the instruction. Hard requirement for
-    /// `InterchainTransfer` instruction. Optional for `DeployTokenManager` and
-    /// ignored by `DeployInterchainToken`.
-    #[builder(default, setter(strip_option(fallback = mint_opt)))]
-    pub(crate) mint: Option<Pubkey>,
-}
+    /// Replaces the set of paused instruction groups, so that a single path
+    /// of the ITS can be halted without pausing the whole service.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. [writable,signer] The address of the payer.
+    /// 1. [signer] The address of the authority: either ITS operator or upgrade authority (owner).
+    /// 2. [] The account that holds the authority roles on the ITS root account.
+    /// 3. [] The program data account.
+    /// 4. [writable] ITS root PDA.
+    /// 5. [] The system program account.
+    /// 6. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 7. [] The ITS program account.
+    SetPauseFlags {
+        /// The bits of the [`PauseFlags`](state::PauseFlags) to set, unknown
+        /// bits are rejected.
+        pause_flags: u8,
+    },
 
-/// Creates an [`InterchainTokenServiceInstruction::Initialize`] instruction.
-///
-/// # Errors
-///
-/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
-pub fn initialize(
-    payer: Pubkey,
-    operator: Pubkey,
-    chain_name: String,
-    its_hub_address: String,
-) -> Result<Instruction, ProgramError> {
-    let (its_root_pda, _) = crate::find_its_root_pda();
-    let (program_data_address, _) =
-        Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID);
-    let (user_roles_pda, _) =
-        role_management::find_user_roles_pda(&crate::ID, &its_root_pda, &operator);
+    /// Logs the accounts the execution of an inbound interchain transfer
+    /// would create and the rent they require, and sets them as the return
+    /// data, as a borsh serialized
+    /// [`InboundRentEstimate`](crate::inbound_rent::InboundRentEstimate).
+    /// Meant to be simulated by relayers before executing the message.
+    ///
+    /// 0. [] The mint of the transferred token.
+    /// 1. [] The destination address of the transfer.
+    /// 2. [] The associated token account of the destination address.
+    /// 3. [] The token program of the mint.
+    /// 4. [] The [`InterchainTransferExecute`](state::interchain_transfer_execute::InterchainTransferExecute)
+    ///    PDA of the destination program for transfers with data, the ITS
+    ///    program account otherwise.
+    EstimateInboundRent {
+        /// The payload of the inbound message, either as received from the
+        /// ITS Hub or unwrapped.
+        payload: Vec<u8>,
+    },
 
-    let data = to_vec(&InterchainTokenServiceInstruction::Initialize {
-        chain_name,
-        its_hub_address,
-    })?;
+    /// Retires a [`TokenManager`], closing its PDA, its associated token
+    /// account, its [`TokenObserver`](state::token_observer::TokenObserver)
+    /// PDA and the roles PDAs of its role holders, and refunding their rent
+    /// to the payer. Inbound and outbound transfers of the token fail from
+    /// then on, until it's registered again.
+    ///
+    /// Every authority the token manager holds over the mint (minting,
+    /// freezing, the metadata pointer, the transfer fee config and the
+    /// on-mint metadata) goes to `mint_authority`. Metaplex metadata keeps
+    /// the closed token manager as its update authority unless it was handed
+    /// over with [`InterchainTokenServiceInstruction::SetTokenMetadataAuthority`]
+    /// beforehand. The token manager is refused while its associated token
+    /// account still holds tokens, and withheld transfer fees have to be
+    /// harvested first.
+    ///
+    /// The roles PDA of every operator, minter and flow limiter of the token
+    /// manager must be passed, as roles left open would be granted again if
+    /// the token were registered again. The token manager counts its role
+    /// holders and is refused unless all of them are closed, token managers
+    /// deployed before the count was kept can't be decommissioned.
+    ///
+    /// Only the ITS operator can decommission token managers.
+    ///
+    /// 0. [writable,signer] The address of the payer, receiving the rent of the closed accounts.
+    /// 1. [signer] The address of the ITS operator.
+    /// 2. [] The account that holds the operator role on the ITS root account.
+    /// 3. [] ITS root PDA account.
+    /// 4. [writable] The [`TokenManager`] PDA account.
+    /// 5. [writable] The mint account.
+    /// 6. [writable] The [`TokenManager`] associated token account.
+    /// 7. [] The token program account.
+    /// 8. [writable] The [`TokenObserver`](state::token_observer::TokenObserver) PDA account, closed if initialized.
+    /// 9. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 10. [] The ITS program account.
+    ///
+    /// Followed by a pair of accounts for every role holder of the [`TokenManager`]:
+    ///
+    /// 0. [] The role holder.
+    /// 1. [writable] The roles PDA of the holder on the [`TokenManager`].
+    DecommissionTokenManager {
+        /// The account the authorities over the mint are handed back to,
+        /// required when the [`TokenManager`] holds any and rejected
+        /// otherwise.
+        mint_authority: Option<Pubkey>,
+    },
 
-    let accounts = vec![
-        AccountMeta::new(payer, true),
-        AccountMeta::new_readonly(program_data_address, false),
-        AccountMeta::new(its_root_pda, false),
-        AccountMeta::new_readonly(system_program::ID, false),
-        AccountMeta::new_readonly(operator, false),
-        AccountMeta::new(user_roles_pda, false),
-    ];
+    /// Transfers interchain tokens paying the gas with a slice of the
+    /// transferred tokens instead of lamports.
+    ///
+    /// `gas_amount` tokens are moved from the source account to the gas
+    /// service and `amount - gas_amount` tokens are bridged. The gas service
+    /// token account for the mint must already exist.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. [writable,signer] The account which is paying for the transaction
+    /// 1. [signer] The address of the owner or delegate of the source account of the
+    ///    transfer. For `MintBurnFrom` token managers, this must be the owner.
+    /// 2. [] The ITS root account
+    /// 3. [writable] The source account from which the tokens are being transferred
+    /// 4. [writable] The mint account (token address)
+    /// 5. [writable] The token manager account associated with the interchain token
+    /// 6. [writable] The token manager Associated Token Account associated with the mint
+    /// 7. [] The token program account that was used to create the mint (`spl_token` vs `spl_token_2022`)
+    /// 8. [] The GMP gateway root account
+    /// 9. [] The gateway event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
+    /// 10. [] The GMP gateway program account
+    /// 11. [writable] The GMP gas configuration account
+    /// 12. [] The GMP gas service paused chains PDA
+    /// 13. [] The gas service event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gas service program ID).
+    /// 14. [] The GMP gas service program account
+    /// 15. [] The system program account
+    /// 16. [] The GMP call contract signing account
+    /// 17. [] The ITS program account.
+    /// 18. [writable] The outbound nonce PDA of the sender, created on first use
+    /// 19. [] The token observer PDA of the token
+    /// 20. [] The observer program registered for the token, or the ITS program ID if none
+    /// 21. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 22. [] The ITS program account.
+    /// 23. [writable] The GMP gas configuration Associated Token Account associated with the mint
+    InterchainTransferWithTokenGas {
+        /// The token id associated with the token
+        token_id: [u8; 32],
 
-    Ok(Instruction {
-        program_id: crate::ID,
-        accounts,
-        data,
-    })
-}
+        /// The chain where the tokens are being transferred to.
+        destination_chain: String,
 
-/// Creates an [`InterchainTokenServiceInstruction::SetPauseStatus`] instruction.
-///
-/// # Errors
-///
-/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
-pub fn set_pause_status(owner: Pubkey, paused: bool) -> Result<Instruction, ProgramError> {
-    let (program_data_address, _) =
-        Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID);
-    let (its_root_pda, _) = crate::find_its_root_pda();
+        /// The address on the destination chain to send the tokens to.
+        destination_address: Vec<u8>,
 
-    let data = to_vec(&InterchainTokenServiceInstruction::SetPauseStatus { paused })?;
+        /// Amount of tokens taken from the source account, including the gas.
+        amount: u64,
 
-    let accounts = vec![
-        AccountMeta::new_readonly(owner, true),
-        AccountMeta::new_readonly(program_data_address, false),
-        AccountMeta::new(its_root_pda, false),
-        AccountMeta::new_readonly(system_program::ID, false),
-    ];
+        /// Amount of tokens paid to the gas service.
+        gas_amount: u64,
 
-    Ok(Instruction {
-        program_id: crate::ID,
-        accounts,
-        data,
-    })
-}
+        /// The bump from the call contract signing account PDA derivation
+        signing_pda_bump: u8,
+    },
 
-/// Creates an [`InterchainTokenServiceInstruction::SetTrustedChain`] instruction.
-///
-/// # Errors
-///
-/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
-pub fn set_trusted_chain(
-    payer: Pubkey,
-    authority: Pubkey,
-    chain_name: String,
-) -> Result<Instruction, ProgramError> {
-    let (program_data_address, _) =
-        Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID);
+    /// Registers the program notified about every inbound and outbound
+    /// interchain transfer of the token, or removes it. See
+    /// [`crate::observer`] for the notification sent to the program.
+    ///
+    /// An observer failing the notification aborts the transfer, including
+    /// inbound ones, which can't be executed until the observer is fixed or
+    /// removed.
+    ///
+    /// 0. [writable,signer] Payer account, refunded when the observer is removed.
+    /// 1. [signer] Account with operator role on the token manager.
+    /// 2. [] ITS root PDA account.
+    /// 3. [] The [`TokenManager`] PDA account.
+    /// 4. [] The PDA account with the operator's roles on the [`TokenManager`].
+    /// 5. [writable] The token observer PDA account.
+    /// 6. [] System program account.
+    /// 7. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 8. [] The ITS program account.
+    SetTokenObserver {
+        /// The observer program, `None` to remove the current one.
+        observer: Option<Pubkey>,
+    },
 
-    let (its_root_pda, _) = crate::find_its_root_pda();
-    let (authority_roles_pda, _) =
-        role_management::find_user_roles_pda(&crate::ID, &its_root_pda, &authority);
-    let (event_authority, _bump) =
-        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+    /// Sets how inbound transfers of the token handle a destination
+    /// associated token account that is no longer owned by the destination
+    /// address.
+    ///
+    /// 0. [writable,signer] Payer account.
+    /// 1. [signer] Account with operator role on the token manager.
+    /// 2. [] ITS root PDA account.
+    /// 3. [writable] The [`TokenManager`] PDA account.
+    /// 4. [] The PDA account with the operator's roles on the [`TokenManager`].
+    /// 5. [] System program account.
+    /// 6. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 7. [] The ITS program account.
+    SetTokenManagerDestinationOwnerPolicy {
+        /// The new policy.
+        policy: state::token_manager::DestinationOwnerPolicy,
+    },
+
+    /// Hands the Metaplex metadata update authority of an interchain token
+    /// over from the [`TokenManager`] to `new_authority`, letting the project
+    /// manage the metadata directly. This is a one-way operation recorded in
+    /// the [`TokenManager`], ITS can't get the authority back.
+    ///
+    /// Requires both the minter and the operator of the token manager to sign.
+    ///
+    /// 0. [writable,signer] Payer account.
+    /// 1. [signer] Account with minter role on the token manager.
+    /// 2. [] PDA for the minter's roles on the token manager.
+    /// 3. [signer] Account with operator role on the token manager.
+    /// 4. [] PDA for the operator's roles on the token manager.
+    /// 5. [] ITS root PDA.
+    /// 6. [writable] The [`TokenManager`] PDA account.
+    /// 7. [] The mint account.
+    /// 8. [writable] The Metaplex metadata account of the mint.
+    /// 9. [] The Metaplex metadata program account (`mpl_token_metadata`).
+    /// 10. [] System program account.
+    /// 11. [] Instructions sysvar account.
+    /// 12. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 13. [] The ITS program account.
+    SetTokenMetadataAuthority {
+        /// The new update authority of the metadata.
+        new_authority: Pubkey,
+    },
+
+    /// Sets a hard cap on the supply of the token, enforced whenever ITS
+    /// mints it, either through [`MintInterchainToken`] or inbound transfers.
+    /// The cap can only be set once and not below the current supply. Only
+    /// tokens ITS mints ([`NativeInterchainToken`], [`MintBurn`] and
+    /// [`MintBurnFrom`]) can be capped.
+    ///
+    /// [`MintInterchainToken`]: InterchainTokenServiceInstruction::MintInterchainToken
+    /// [`NativeInterchainToken`]: state::token_manager::Type::NativeInterchainToken
+    /// [`MintBurn`]: state::token_manager::Type::MintBurn
+    /// [`MintBurnFrom`]: state::token_manager::Type::MintBurnFrom
+    ///
+    /// 0. [writable,signer] Payer account.
+    /// 1. [signer] Account with operator role on the token manager.
+    /// 2. [] ITS root PDA account.
+    /// 3. [writable] The [`TokenManager`] PDA account.
+    /// 4. [] The PDA account with the operator's roles on the [`TokenManager`].
+    /// 5. [] The mint account.
+    /// 6. [] System program account.
+    /// 7. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 8. [] The ITS program account.
+    SetTokenManagerMaxSupply {
+        /// The maximum supply of the token.
+        max_supply: u64,
+    },
+
+    /// Sets the token account the withheld transfer fees of a
+    /// [`LockUnlockFee`] token are harvested to. Once set,
+    /// [`HarvestWithheldFees`] only pays out to the fee collector.
+    ///
+    /// [`LockUnlockFee`]: state::token_manager::Type::LockUnlockFee
+    /// [`HarvestWithheldFees`]: InterchainTokenServiceInstruction::HarvestWithheldFees
+    ///
+    /// 0. [writable,signer] Payer account.
+    /// 1. [signer] Account with operator role on the token manager.
+    /// 2. [] ITS root PDA account.
+    /// 3. [writable] The [`TokenManager`] PDA account.
+    /// 4. [] The PDA account with the operator's roles on the [`TokenManager`].
+    /// 5. [] System program account.
+    /// 6. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 7. [] The ITS program account.
+    SetTokenManagerFeeCollector {
+        /// The token account receiving the fees, `None` to let the operator
+        /// pick the destination on every harvest.
+        fee_collector: Option<Pubkey>,
+    },
+
+    /// Grows a [`TokenManager`] PDA account ahead of a migration, making room
+    /// for fields added in future versions and topping up its rent from the
+    /// payer.
+    ///
+    /// Only the ITS operator or the upgrade authority can resize token managers.
+    ///
+    /// 0. [writable,signer] The address of the payer, funding the rent top-up.
+    /// 1. [signer] The address of the authority: either ITS operator or upgrade authority (owner).
+    /// 2. [] The account that holds the authority roles on the ITS root account.
+    /// 3. [] The program data account.
+    /// 4. [] ITS root PDA account.
+    /// 5. [writable] The [`TokenManager`] PDA account.
+    /// 6. [] System program account.
+    ResizeTokenManager {
+        /// The new size of the token manager account, in bytes. Must not be
+        /// smaller than the current size.
+        new_size: u64,
+    },
+
+    /// Withdraws the transfer fees withheld in the token manager associated
+    /// token account of a [`LockUnlockFee`] token, and in any other token
+    /// account of the mint passed along, to a token account of the operator's
+    /// choice, or to the fee collector of the [`TokenManager`] when set. The
+    /// mint's withdraw withheld authority must be the [`TokenManager`].
+    ///
+    /// [`LockUnlockFee`]: state::token_manager::Type::LockUnlockFee
+    ///
+    /// 0. [signer] Account with operator role on the token manager.
+    /// 1. [] ITS root PDA account.
+    /// 2. [] The [`TokenManager`] PDA account.
+    /// 3. [] The PDA account with the operator's roles on the [`TokenManager`].
+    /// 4. [writable] The mint account.
+    /// 5. [writable] The token account receiving the fees, the fee collector when set.
+    /// 6. [writable] The [`TokenManager`] associated token account.
+    /// 7. [] The token program account (Token-2022).
+    /// 8. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 9. [] The ITS program account.
+    /// 10..N. [writable] Other token accounts of the mint to harvest.
+    HarvestWithheldFees,
+
+    /// Registers a custom token with ITS like [`RegisterCustomToken`], then
+    /// sends its metadata to the ITS Hub like [`RegisterTokenMetadata`] and
+    /// links it to the token on `destination_chain` like [`LinkToken`], all
+    /// in one transaction. Both GMP messages are sent through the gateway, so
+    /// the transaction needs a larger compute budget than the default.
+    ///
+    /// [`RegisterCustomToken`]: InterchainTokenServiceInstruction::RegisterCustomToken
+    /// [`RegisterTokenMetadata`]: InterchainTokenServiceInstruction::RegisterTokenMetadata
+    /// [`LinkToken`]: InterchainTokenServiceInstruction::LinkToken
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. [writable,signer] The account which is paying for the transaction
+    /// 1. [signer] The account of the deployer
+    /// 2. [] The system program account
+    /// 3. [] The ITS root account
+    /// 4. [writable] The token manager account associated with the interchain token
+    /// 5. [writable] The mint account (token address) to register
+    /// 6. [writable] The token manager Associated Token Account associated with the mint
+    /// 7. [] The token program account that was used to create the mint (`spl_token` vs `spl_token_2022`)
+    /// 8. [] The Associated Token Account program account (`spl_associated_token_account`)
+    /// 9. [] The rent sysvar account
+    /// 10. [] Optional: Account to set as operator on the `TokenManager`
+    /// 11. [writable] Optional: The account holding the roles of the operator on the `TokenManager`
+    /// 12. [] The GMP gateway root account
+    /// 13. [] The gateway event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gateway program ID).
+    /// 14. [] The GMP gateway program account
+    /// 15. [writable] The GMP gas configuration account
+    /// 16. [] The GMP gas service paused chains PDA
+    /// 17. [] The gas service event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and gas service program ID).
+    /// 18. [] The GMP gas service program account
+    /// 19. [] The GMP call contract signing account
+    /// 20. [] The ITS program account
+    /// 21. [writable] The outbound nonce PDA of the deployer, created on first use
+    /// 22. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 23. [] The ITS program account.
+    RegisterAndLinkToken {
+        /// Salt used to derive the `token_id` associated with the token.
+        salt: [u8; 32],
+        /// The type of the local token manager.
+        token_manager_type: state::token_manager::Type,
+        /// The operator account
+        operator: Option<Pubkey>,
+        /// The chain where the token is being linked to.
+        destination_chain: String,
+        /// The address of the token on the destination chain.
+        destination_token_address: Vec<u8>,
+        /// The type of token manager used on the destination chain.
+        destination_token_manager_type: state::token_manager::Type,
+        /// The params required on the destination chain. Links towards
+        /// another Solana chain use the [`LinkParams`] encoding.
+        link_params: Vec<u8>,
+        /// The gas value to be paid for the metadata registration GMP transaction
+        metadata_gas_value: u64,
+        /// The gas value to be paid for the link GMP transaction
+        link_gas_value: u64,
+        /// The signing PDA bump
+        signing_pda_bump: u8,
+    },
+
+    /// Migrates a legacy [`FlowSlot`] PDA into the flow state embedded in the
+    /// [`TokenManager`] and closes it, refunding its rent to the payer.
+    ///
+    /// The counters of a slot for the current epoch are added to the
+    /// [`TokenManager`] flow state so no accounting of the epoch is lost. A
+    /// slot of a past epoch no longer limits any transfer and is only closed.
+    ///
+    /// [`FlowSlot`]: state::flow_limit::FlowSlot
+    ///
+    /// 0. [writable,signer] Payer account, receiving the rent of the closed slot.
+    /// 1. [signer] Account with operator role on the token manager.
+    /// 2. [] ITS root PDA account.
+    /// 3. [writable] The [`TokenManager`] PDA account.
+    /// 4. [] The PDA account with the operator's roles on the [`TokenManager`].
+    /// 5. [writable] The legacy [`FlowSlot`] PDA account of `epoch`.
+    /// 6. [] System program account.
+    /// 7. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 8. [] The ITS program account.
+    MigrateFlowSlot {
+        /// The flow epoch the legacy slot was derived for.
+        epoch: u64,
+    },
+
+    /// Renames a trusted chain, moving the configuration kept under the old
+    /// name, such as the maximum payload size, to the new one. Deployment
+    /// approvals towards the old name aren't migrated and need to be approved
+    /// again.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. [writable,signer] The address of the payer.
+    /// 1. [signer] The address of the authority: either ITS operator or upgrade authority (owner).
+    /// 2. [] The account that holds the authority roles on the ITS root account.
+    /// 3. [] The program data account.
+    /// 4. [writable] ITS root PDA.
+    /// 5. [] The system program account.
+    /// 6. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 7. [] The ITS program account.
+    RenameTrustedChain {
+        /// The current name of the trusted chain.
+        old_chain_name: String,
+        /// The name the chain is trusted under from now on.
+        new_chain_name: String,
+    },
+
+    /// Checks the bindings recorded in a [`TokenManager`] against the ones
+    /// derived from its token id, and emits a
+    /// [`TokenManagerVerified`](crate::events::TokenManagerVerified) event
+    /// listing every mismatch found. Meant for automated health checks, anyone
+    /// can verify a token manager.
+    ///
+    /// 0. [] ITS root PDA account.
+    /// 1. [] The [`TokenManager`] PDA account.
+    /// 2. [] The mint account recorded in the [`TokenManager`].
+    /// 3. [] The associated token account recorded in the [`TokenManager`].
+    /// 4. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 5. [] The ITS program account.
+    VerifyTokenManager,
+
+    /// Same as [`InterchainTokenServiceInstruction::InterchainTransfer`], but
+    /// transfers only up to the remaining outbound flow capacity of the token
+    /// instead of failing when `amount` exceeds it. The unfilled remainder is
+    /// never taken from the source account and is returned as borsh encoded
+    /// `u64` return data.
+    ///
+    /// Accounts expected by this instruction are the same as for
+    /// [`InterchainTokenServiceInstruction::InterchainTransfer`].
+    InterchainTransferAllowPartial {
+        /// The token id associated with the token
+        token_id: [u8; 32],
+
+        /// The chain where the tokens are being transferred to.
+        destination_chain: String,
+
+        /// The address on the destination chain to send the tokens to.
+        destination_address: Vec<u8>,
+
+        /// Amount of tokens being transferred, at most.
+        amount: u64,
+
+        /// The gas value to be paid for the deploy transaction
+        gas_value: u64,
+
+        /// The bump from the call contract signing account PDA derivation
+        signing_pda_bump: u8,
+    },
+
+    /// Sets the limits applied when ITS calls the destination program of an
+    /// interchain transfer with data.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. [writable,signer] The address of the payer.
+    /// 1. [signer] The address of the authority: either ITS operator or upgrade authority (owner).
+    /// 2. [] The account that holds the authority roles on the ITS root account.
+    /// 3. [] The program data account.
+    /// 4. [writable] ITS root PDA.
+    /// 5. [] The system program account.
+    /// 6. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 7. [] The ITS program account.
+    SetDestinationExecutionPolicy {
+        /// The policy to apply, `None` to remove the limits.
+        policy: Option<state::DestinationExecutionPolicy>,
+    },
+
+    /// Sets the maximum size of the GMP payload ITS sends towards a chain.
+    /// Outbound messages exceeding it are rejected with
+    /// [`ItsError::PayloadTooLarge`](crate::error::ItsError::PayloadTooLarge).
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. [writable,signer] The address of the payer.
+    /// 1. [signer] The address of the authority: either ITS operator or upgrade authority (owner).
+    /// 2. [] The account that holds the authority roles on the ITS root account.
+    /// 3. [] The program data account.
+    /// 4. [writable] ITS root PDA.
+    /// 5. [] The system program account.
+    /// 6. [] The event authority PDA (derived from event_cpi::EVENT_AUTHORITY_SEED and ITS program ID).
+    /// 7. [] The ITS program account.
+    SetMaxPayloadSize {
+        /// The name of the destination chain.
+        chain_name: String,
+        /// The maximum payload size in bytes, `None` to remove the limit.
+        max_size: Option<u32>,
+    },
+
+    /// Deploys an interchain token like [`DeployInterchainToken`], also
+    /// storing its name and symbol on the mint through the Token-2022
+    /// `MetadataPointer` and `TokenMetadata` extensions, so that programs can
+    /// read them without Metaplex. Requires the mint to be deployed under
+    /// `spl_token_2022`.
+    ///
+    /// Accounts expected by this instruction are the same as for
+    /// [`DeployInterchainToken`].
+    ///
+    /// [`DeployInterchainToken`]: InterchainTokenServiceInstruction::DeployInterchainToken
+    DeployInterchainTokenWithOnMintMetadata {
+        /// The salt used to derive the tokenId associated with the token
+        salt: [u8; 32],
+
+        /// Token name
+        name: String,
+
+        /// Token symbol
+        symbol: String,
+
+        /// Token decimals
+        decimals: u8,
+
+        /// Initial supply
+        initial_supply: u64,
+    },
+}
+
+/// Inputs for the [`execute`] function.
+///
+/// To construct this type, use its builder API.
+///
+/// # Example
+///
+/// ```ignore
+/// use axelar_solana_its::instructions::ExecuteInstructionInputs;
+///
+/// let inputs = ExecuteInstructionInputs::builder()
+///   .payer(payer_pubkey)
+///   .incoming_message_pda(gateway_approved_message_pda)
+///   .message(message)
+///   .payload(payload)
+///   .token_program(spl_token_2022::ID)
+///   .mint(mint_pubkey)
+///   .bumps(bumps)
+///   .build();
+/// ```
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct ExecuteInstructionInputs {
+    /// The payer account.
+    pub(crate) payer: Pubkey,
+
+    /// The PDA used to track the message status by the gateway program.
+    pub(crate) incoming_message_pda: Pubkey,
+
+    /// The PDA used to store the message payload.
+    pub(crate) message_payload_pda: Pubkey,
+
+    /// The Axelar GMP metadata.
+    pub(crate) message: Message,
+
+    /// The ITS GMP payload.
+    pub(crate) payload: GMPPayload,
+
+    /// The token program required by the instruction (spl-token or
+    /// spl-token-2022).
+    pub(crate) token_program: Pubkey,
+
+    /// The mint account required by the instruction. Hard requirement for
+    /// `InterchainTransfer` instruction. Optional for `DeployTokenManager` and
+    /// ignored by `DeployInterchainToken`.
+    #[builder(default, setter(strip_option(fallback = mint_opt)))]
+    pub(crate) mint: Option<Pubkey>,
+
+    /// The observer program registered for the token, see
+    /// [`InterchainTokenServiceInstruction::SetTokenObserver`]. Only used by
+    /// the `InterchainTransfer` instruction.
+    #[builder(default, setter(strip_option(fallback = token_observer_opt)))]
+    pub(crate) token_observer: Option<Pubkey>,
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::Initialize`] instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn initialize(
+    payer: Pubkey,
+    operator: Pubkey,
+    chain_name: String,
+    its_hub_address: String,
+) -> Result<Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (program_data_address, _) =
+        Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID);
+    let (user_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::ID, &its_root_pda, &operator);
+
+    let data = to_vec(&InterchainTokenServiceInstruction::Initialize {
+        chain_name,
+        its_hub_address,
+    })?;
+
+    let accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(program_data_address, false),
+        AccountMeta::new(its_root_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(operator, false),
+        AccountMeta::new(user_roles_pda, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::SetPauseStatus`] instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn set_pause_status(owner: Pubkey, paused: bool) -> Result<Instruction, ProgramError> {
+    let (program_data_address, _) =
+        Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID);
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let data = to_vec(&InterchainTokenServiceInstruction::SetPauseStatus { paused })?;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(owner, true),
+        AccountMeta::new_readonly(program_data_address, false),
+        AccountMeta::new(its_root_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::SetTrustedChain`] instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn set_trusted_chain(
+    payer: Pubkey,
+    authority: Pubkey,
+    chain_name: String,
+) -> Result<Instruction, ProgramError> {
+    let (program_data_address, _) =
+        Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID);
+
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (authority_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::ID, &its_root_pda, &authority);
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
 
     let data = to_vec(&InterchainTokenServiceInstruction::SetTrustedChain { chain_name })?;
 
@@ -965,7 +1594,169 @@ pub fn set_trusted_chain(
 pub fn remove_trusted_chain(
     payer: Pubkey,
     authority: Pubkey,
-    chain_name: String,
+    chain_name: String,
+) -> Result<Instruction, ProgramError> {
+    let (program_data_address, _) =
+        Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID);
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (authority_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::ID, &its_root_pda, &authority);
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let data = to_vec(&InterchainTokenServiceInstruction::RemoveTrustedChain { chain_name })?;
+
+    let accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(authority, true),
+        AccountMeta::new_readonly(authority_roles_pda, false),
+        AccountMeta::new_readonly(program_data_address, false),
+        AccountMeta::new(its_root_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::RenameTrustedChain`] instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn rename_trusted_chain(
+    payer: Pubkey,
+    authority: Pubkey,
+    old_chain_name: String,
+    new_chain_name: String,
+) -> Result<Instruction, ProgramError> {
+    let (program_data_address, _) =
+        Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID);
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (authority_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::ID, &its_root_pda, &authority);
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let data = to_vec(&InterchainTokenServiceInstruction::RenameTrustedChain {
+        old_chain_name,
+        new_chain_name,
+    })?;
+
+    let accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(authority, true),
+        AccountMeta::new_readonly(authority_roles_pda, false),
+        AccountMeta::new_readonly(program_data_address, false),
+        AccountMeta::new(its_root_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::SetPauseFlags`] instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn set_pause_flags(
+    payer: Pubkey,
+    authority: Pubkey,
+    pause_flags: state::PauseFlags,
+) -> Result<Instruction, ProgramError> {
+    let (program_data_address, _) =
+        Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID);
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (authority_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::ID, &its_root_pda, &authority);
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let data = to_vec(&InterchainTokenServiceInstruction::SetPauseFlags {
+        pause_flags: pause_flags.bits(),
+    })?;
+
+    let accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(authority, true),
+        AccountMeta::new_readonly(authority_roles_pda, false),
+        AccountMeta::new_readonly(program_data_address, false),
+        AccountMeta::new(its_root_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::SetMaxPayloadSize`] instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn set_max_payload_size(
+    payer: Pubkey,
+    authority: Pubkey,
+    chain_name: String,
+    max_size: Option<u32>,
+) -> Result<Instruction, ProgramError> {
+    let (program_data_address, _) =
+        Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID);
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (authority_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::ID, &its_root_pda, &authority);
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let data = to_vec(&InterchainTokenServiceInstruction::SetMaxPayloadSize {
+        chain_name,
+        max_size,
+    })?;
+
+    let accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(authority, true),
+        AccountMeta::new_readonly(authority_roles_pda, false),
+        AccountMeta::new_readonly(program_data_address, false),
+        AccountMeta::new(its_root_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::SetDestinationExecutionPolicy`] instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn set_destination_execution_policy(
+    payer: Pubkey,
+    authority: Pubkey,
+    policy: Option<state::DestinationExecutionPolicy>,
 ) -> Result<Instruction, ProgramError> {
     let (program_data_address, _) =
         Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID);
@@ -975,7 +1766,8 @@ pub fn remove_trusted_chain(
     let (event_authority, _bump) =
         Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
 
-    let data = to_vec(&InterchainTokenServiceInstruction::RemoveTrustedChain { chain_name })?;
+    let data =
+        to_vec(&InterchainTokenServiceInstruction::SetDestinationExecutionPolicy { policy })?;
 
     let accounts = vec![
         AccountMeta::new(payer, true),
@@ -1156,6 +1948,27 @@ pub fn register_canonical_interchain_token(
     })
 }
 
+/// Creates the instructions registering wSOL as a canonical interchain token
+/// and registering its metadata with the ITS Hub.
+///
+/// The [`crate::native_mint`] is registered under the classic SPL Token
+/// program and is always described as [`crate::native_mint::NAME`] /
+/// [`crate::native_mint::SYMBOL`] with [`crate::native_mint::DECIMALS`]
+/// decimals. Both instructions fit in a single transaction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn register_native_mint_canonical_token(
+    payer: Pubkey,
+    metadata_gas_value: u64,
+) -> Result<Vec<Instruction>, ProgramError> {
+    Ok(vec![
+        register_canonical_interchain_token(payer, crate::native_mint::ID, spl_token::ID)?,
+        register_token_metadata(payer, crate::native_mint::ID, metadata_gas_value)?,
+    ])
+}
+
 /// Creates an [`InterchainTokenServiceInstruction::DeployRemoteInterchainToken`]
 /// instruction.
 ///
@@ -1174,6 +1987,7 @@ pub fn deploy_remote_canonical_interchain_token(
         axelar_solana_gateway::get_call_contract_signing_pda(crate::ID);
     let (metadata_account_key, _) = mpl_token_metadata::accounts::Metadata::find_pda(&mint);
     let (gas_config_pda, _bump) = axelar_solana_gas_service::get_config_pda();
+    let (gas_paused_chains_pda, _bump) = axelar_solana_gas_service::get_paused_chains_pda();
     let token_id = crate::canonical_interchain_token_id(&mint);
     let (token_manager, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
     let (gateway_event_authority, _bump) = Pubkey::find_program_address(
@@ -1197,6 +2011,7 @@ pub fn deploy_remote_canonical_interchain_token(
         AccountMeta::new_readonly(gateway_event_authority, false),
         AccountMeta::new_readonly(axelar_solana_gateway::ID, false),
         AccountMeta::new(gas_config_pda, false),
+        AccountMeta::new_readonly(gas_paused_chains_pda, false),
         AccountMeta::new_readonly(gas_service_event_authority, false),
         AccountMeta::new_readonly(axelar_solana_gas_service::ID, false),
         AccountMeta::new_readonly(system_program::ID, false),
@@ -1222,7 +2037,7 @@ pub fn deploy_remote_canonical_interchain_token(
 }
 
 /// Creates an [`InterchainTokenServiceInstruction::DeployInterchainToken`]
-/// instruction.
+/// instruction, deploying the mint under `spl_token_2022`.
 ///
 /// # Errors
 ///
@@ -1236,18 +2051,109 @@ pub fn deploy_interchain_token(
     decimals: u8,
     initial_supply: u64,
     minter: Option<Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    deploy_interchain_token_with_token_program(
+        payer,
+        deployer,
+        salt,
+        name,
+        symbol,
+        decimals,
+        initial_supply,
+        minter,
+        spl_token_2022::ID,
+    )
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::DeployInterchainToken`]
+/// instruction, deploying the mint under `token_program`.
+///
+/// Passing `spl_token::ID` deploys a classic SPL Token mint, for integrations
+/// that don't support Token-2022. The token program is recorded in the
+/// [`TokenManager`](crate::state::token_manager::TokenManager) and has to be
+/// used for every transfer of the token. Tokens deployed from other chains
+/// always use `spl_token_2022`.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn deploy_interchain_token_with_token_program(
+    payer: Pubkey,
+    deployer: Pubkey,
+    salt: [u8; 32],
+    name: String,
+    symbol: String,
+    decimals: u8,
+    initial_supply: u64,
+    minter: Option<Pubkey>,
+    token_program: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    deploy_interchain_token_instruction(
+        payer,
+        deployer,
+        salt,
+        name,
+        symbol,
+        decimals,
+        initial_supply,
+        minter,
+        token_program,
+        false,
+    )
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::DeployInterchainTokenWithOnMintMetadata`]
+/// instruction, deploying the mint under `spl_token_2022` with its name and
+/// symbol stored on the mint through the `MetadataPointer` and
+/// `TokenMetadata` extensions, in addition to the Metaplex metadata.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn deploy_interchain_token_with_on_mint_metadata(
+    payer: Pubkey,
+    deployer: Pubkey,
+    salt: [u8; 32],
+    name: String,
+    symbol: String,
+    decimals: u8,
+    initial_supply: u64,
+    minter: Option<Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    deploy_interchain_token_instruction(
+        payer,
+        deployer,
+        salt,
+        name,
+        symbol,
+        decimals,
+        initial_supply,
+        minter,
+        spl_token_2022::ID,
+        true,
+    )
+}
+
+fn deploy_interchain_token_instruction(
+    payer: Pubkey,
+    deployer: Pubkey,
+    salt: [u8; 32],
+    name: String,
+    symbol: String,
+    decimals: u8,
+    initial_supply: u64,
+    minter: Option<Pubkey>,
+    token_program: Pubkey,
+    on_mint_metadata: bool,
 ) -> Result<Instruction, ProgramError> {
     let (its_root_pda, _) = crate::find_its_root_pda();
     let token_id = crate::interchain_token_id(&deployer, &salt);
     let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
     let (mint, _) = crate::find_interchain_token_pda(&its_root_pda, &token_id);
-    let token_manager_ata = get_associated_token_address_with_program_id(
-        &token_manager_pda,
-        &mint,
-        &spl_token_2022::ID,
-    );
+    let token_manager_ata =
+        get_associated_token_address_with_program_id(&token_manager_pda, &mint, &token_program);
     let deployer_ata =
-        get_associated_token_address_with_program_id(&deployer, &mint, &spl_token_2022::ID);
+        get_associated_token_address_with_program_id(&deployer, &mint, &token_program);
     let (metadata_account_key, _) = mpl_token_metadata::accounts::Metadata::find_pda(&mint);
     let (event_authority, _bump) =
         Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
@@ -1260,7 +2166,7 @@ pub fn deploy_interchain_token(
         AccountMeta::new(token_manager_pda, false),
         AccountMeta::new(mint, false),
         AccountMeta::new(token_manager_ata, false),
-        AccountMeta::new_readonly(spl_token_2022::ID, false),
+        AccountMeta::new_readonly(token_program, false),
         AccountMeta::new_readonly(spl_associated_token_account::ID, false),
         AccountMeta::new_readonly(sysvar::rent::ID, false),
         AccountMeta::new_readonly(sysvar::instructions::ID, false),
@@ -1279,13 +2185,25 @@ pub fn deploy_interchain_token(
         AccountMeta::new_readonly(crate::ID, false),
     ];
 
-    let data = to_vec(&InterchainTokenServiceInstruction::DeployInterchainToken {
-        salt,
-        name,
-        symbol,
-        decimals,
-        initial_supply,
-    })?;
+    let data = if on_mint_metadata {
+        to_vec(
+            &InterchainTokenServiceInstruction::DeployInterchainTokenWithOnMintMetadata {
+                salt,
+                name,
+                symbol,
+                decimals,
+                initial_supply,
+            },
+        )?
+    } else {
+        to_vec(&InterchainTokenServiceInstruction::DeployInterchainToken {
+            salt,
+            name,
+            symbol,
+            decimals,
+            initial_supply,
+        })?
+    };
 
     Ok(Instruction {
         program_id: crate::ID,
@@ -1315,6 +2233,7 @@ pub fn deploy_remote_interchain_token(
         axelar_solana_gateway::get_call_contract_signing_pda(crate::ID);
     let (metadata_account_key, _) = mpl_token_metadata::accounts::Metadata::find_pda(&mint);
     let (gas_config_pda, _bump) = axelar_solana_gas_service::get_config_pda();
+    let (gas_paused_chains_pda, _bump) = axelar_solana_gas_service::get_paused_chains_pda();
     let (token_manager, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
     let (gateway_event_authority, _bump) = Pubkey::find_program_address(
         &[event_cpi::EVENT_AUTHORITY_SEED],
@@ -1338,6 +2257,7 @@ pub fn deploy_remote_interchain_token(
         AccountMeta::new_readonly(gateway_event_authority, false),
         AccountMeta::new_readonly(axelar_solana_gateway::ID, false),
         AccountMeta::new(gas_config_pda, false),
+        AccountMeta::new_readonly(gas_paused_chains_pda, false),
         AccountMeta::new_readonly(gas_service_event_authority, false),
         AccountMeta::new_readonly(axelar_solana_gas_service::ID, false),
         AccountMeta::new_readonly(system_program::ID, false),
@@ -1391,6 +2311,7 @@ pub fn deploy_remote_interchain_token_with_minter(
     let (minter_roles_pda, _) =
         role_management::find_user_roles_pda(&crate::ID, &token_manager_pda, &minter);
     let (gas_config_pda, _bump) = axelar_solana_gas_service::get_config_pda();
+    let (gas_paused_chains_pda, _bump) = axelar_solana_gas_service::get_paused_chains_pda();
     let (gateway_event_authority, _bump) = Pubkey::find_program_address(
         &[event_cpi::EVENT_AUTHORITY_SEED],
         &axelar_solana_gateway::ID,
@@ -1416,6 +2337,7 @@ pub fn deploy_remote_interchain_token_with_minter(
         AccountMeta::new_readonly(gateway_event_authority, false),
         AccountMeta::new_readonly(axelar_solana_gateway::ID, false),
         AccountMeta::new(gas_config_pda, false),
+        AccountMeta::new_readonly(gas_paused_chains_pda, false),
         AccountMeta::new_readonly(gas_service_event_authority, false),
         AccountMeta::new_readonly(axelar_solana_gas_service::ID, false),
         AccountMeta::new_readonly(system_program::ID, false),
@@ -1442,6 +2364,30 @@ pub fn deploy_remote_interchain_token_with_minter(
     })
 }
 
+/// Has a remote deployment instruction created by
+/// [`deploy_remote_interchain_token`],
+/// [`deploy_remote_interchain_token_with_minter`] or
+/// [`deploy_remote_canonical_interchain_token`] check a
+/// [`GasQuote`](crate::gas_quote::GasQuote) signed by `quote_signer`, the ITS
+/// operator.
+///
+/// The instruction must immediately follow the one created by
+/// [`verify_gas_quote_instruction`](crate::gas_quote::verify_gas_quote_instruction)
+/// for the quote, and pay at least the quoted gas value.
+#[must_use]
+pub fn with_gas_quote(mut instruction: Instruction, quote_signer: Pubkey) -> Instruction {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (signer_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::ID, &its_root_pda, &quote_signer);
+
+    instruction.accounts.extend([
+        AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        AccountMeta::new_readonly(signer_roles_pda, false),
+    ]);
+
+    instruction
+}
+
 /// Creates [`InterchainTokenServiceInstruction::RegisterTokenMetadata`] instruction.
 ///
 /// # Errors
@@ -1457,6 +2403,7 @@ pub fn register_token_metadata(
     let (call_contract_signing_pda, signing_pda_bump) =
         axelar_solana_gateway::get_call_contract_signing_pda(crate::ID);
     let (gas_config_pda, _bump) = axelar_solana_gas_service::get_config_pda();
+    let (gas_paused_chains_pda, _bump) = axelar_solana_gas_service::get_paused_chains_pda();
     let (gateway_event_authority, _bump) = Pubkey::find_program_address(
         &[event_cpi::EVENT_AUTHORITY_SEED],
         &axelar_solana_gateway::ID,
@@ -1476,6 +2423,7 @@ pub fn register_token_metadata(
         AccountMeta::new_readonly(gateway_event_authority, false),
         AccountMeta::new_readonly(axelar_solana_gateway::ID, false),
         AccountMeta::new(gas_config_pda, false),
+        AccountMeta::new_readonly(gas_paused_chains_pda, false),
         AccountMeta::new_readonly(gas_service_event_authority, false),
         AccountMeta::new_readonly(axelar_solana_gas_service::ID, false),
         AccountMeta::new_readonly(system_program::ID, false),
@@ -1583,6 +2531,7 @@ pub fn link_token(
     let token_id = crate::linked_token_id(&deployer, &salt);
     let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
     let (gas_config_pda, _bump) = axelar_solana_gas_service::get_config_pda();
+    let (gas_paused_chains_pda, _bump) = axelar_solana_gas_service::get_paused_chains_pda();
     let (gateway_event_authority, _bump) = Pubkey::find_program_address(
         &[event_cpi::EVENT_AUTHORITY_SEED],
         &axelar_solana_gateway::ID,
@@ -1593,6 +2542,7 @@ pub fn link_token(
     );
     let (event_authority, _bump) =
         Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+    let (outbound_nonce_pda, _) = crate::find_outbound_nonce_pda(&deployer);
 
     let accounts = vec![
         AccountMeta::new(payer, true),
@@ -1603,11 +2553,13 @@ pub fn link_token(
         AccountMeta::new_readonly(gateway_event_authority, false),
         AccountMeta::new_readonly(axelar_solana_gateway::ID, false),
         AccountMeta::new(gas_config_pda, false),
+        AccountMeta::new_readonly(gas_paused_chains_pda, false),
         AccountMeta::new_readonly(gas_service_event_authority, false),
         AccountMeta::new_readonly(axelar_solana_gas_service::ID, false),
         AccountMeta::new_readonly(system_program::ID, false),
         AccountMeta::new_readonly(call_contract_signing_pda, false),
         AccountMeta::new_readonly(crate::ID, false),
+        AccountMeta::new(outbound_nonce_pda, false),
         AccountMeta::new_readonly(event_authority, false),
         AccountMeta::new_readonly(crate::ID, false),
     ];
@@ -1629,6 +2581,108 @@ pub fn link_token(
     })
 }
 
+/// Creates an [`InterchainTokenServiceInstruction::RegisterAndLinkToken`]
+/// instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn register_and_link_token(
+    payer: Pubkey,
+    deployer: Pubkey,
+    salt: [u8; 32],
+    mint: Pubkey,
+    token_manager_type: state::token_manager::Type,
+    token_program: Pubkey,
+    operator: Option<Pubkey>,
+    destination_chain: String,
+    destination_token_address: Vec<u8>,
+    destination_token_manager_type: state::token_manager::Type,
+    link_params: Vec<u8>,
+    metadata_gas_value: u64,
+    link_gas_value: u64,
+) -> Result<Instruction, ProgramError> {
+    let (gateway_root_pda, _) = axelar_solana_gateway::get_gateway_root_config_pda();
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (call_contract_signing_pda, signing_pda_bump) =
+        axelar_solana_gateway::get_call_contract_signing_pda(crate::ID);
+    let token_id = crate::linked_token_id(&deployer, &salt);
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+    let token_manager_ata =
+        get_associated_token_address_with_program_id(&token_manager_pda, &mint, &token_program);
+    let (gas_config_pda, _bump) = axelar_solana_gas_service::get_config_pda();
+    let (gas_paused_chains_pda, _bump) = axelar_solana_gas_service::get_paused_chains_pda();
+    let (gateway_event_authority, _bump) = Pubkey::find_program_address(
+        &[event_cpi::EVENT_AUTHORITY_SEED],
+        &axelar_solana_gateway::ID,
+    );
+    let (gas_service_event_authority, _bump) = Pubkey::find_program_address(
+        &[event_cpi::EVENT_AUTHORITY_SEED],
+        &axelar_solana_gas_service::ID,
+    );
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+    let (outbound_nonce_pda, _) = crate::find_outbound_nonce_pda(&deployer);
+
+    let mut accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(deployer, true),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new(token_manager_pda, false),
+        AccountMeta::new(mint, false),
+        AccountMeta::new(token_manager_ata, false),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        AccountMeta::new_readonly(sysvar::rent::ID, false),
+    ];
+
+    // Add optional operator accounts (uses program_id as sentinel for None)
+    if let Some(operator) = operator {
+        let (operator_roles_pda, _) =
+            role_management::find_user_roles_pda(&crate::ID, &token_manager_pda, &operator);
+        accounts.push(AccountMeta::new_readonly(operator, false));
+        accounts.push(AccountMeta::new(operator_roles_pda, false));
+    } else {
+        accounts.push(AccountMeta::new_readonly(crate::ID, false));
+        accounts.push(AccountMeta::new_readonly(crate::ID, false));
+    }
+
+    accounts.extend([
+        AccountMeta::new_readonly(gateway_root_pda, false),
+        AccountMeta::new_readonly(gateway_event_authority, false),
+        AccountMeta::new_readonly(axelar_solana_gateway::ID, false),
+        AccountMeta::new(gas_config_pda, false),
+        AccountMeta::new_readonly(gas_paused_chains_pda, false),
+        AccountMeta::new_readonly(gas_service_event_authority, false),
+        AccountMeta::new_readonly(axelar_solana_gas_service::ID, false),
+        AccountMeta::new_readonly(call_contract_signing_pda, false),
+        AccountMeta::new_readonly(crate::ID, false),
+        AccountMeta::new(outbound_nonce_pda, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ]);
+
+    let data = to_vec(&InterchainTokenServiceInstruction::RegisterAndLinkToken {
+        salt,
+        token_manager_type,
+        operator,
+        destination_chain,
+        destination_token_address,
+        destination_token_manager_type,
+        link_params,
+        metadata_gas_value,
+        link_gas_value,
+        signing_pda_bump,
+    })?;
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
 /// Creates an [`InterchainTokenServiceInstruction::InterchainTransfer`]
 /// instruction.
 ///
@@ -1655,6 +2709,7 @@ pub fn interchain_transfer(
     let (call_contract_signing_pda, signing_pda_bump) =
         axelar_solana_gateway::get_call_contract_signing_pda(crate::ID);
     let (gas_config_pda, _bump) = axelar_solana_gas_service::get_config_pda();
+    let (gas_paused_chains_pda, _bump) = axelar_solana_gas_service::get_paused_chains_pda();
     let (gateway_event_authority, _bump) = Pubkey::find_program_address(
         &[event_cpi::EVENT_AUTHORITY_SEED],
         &axelar_solana_gateway::ID,
@@ -1665,43 +2720,175 @@ pub fn interchain_transfer(
     );
     let (event_authority, _bump) =
         Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+    let (outbound_nonce_pda, _) = crate::find_outbound_nonce_pda(&authority);
+    let (token_observer_pda, _) = crate::find_token_observer_pda(&token_manager_pda);
+
+    let accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(authority, true),
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new(source_account, false),
+        AccountMeta::new(mint, false),
+        AccountMeta::new(token_manager_pda, false),
+        AccountMeta::new(token_manager_ata, false),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(gateway_root_pda, false),
+        AccountMeta::new_readonly(gateway_event_authority, false),
+        AccountMeta::new_readonly(axelar_solana_gateway::ID, false),
+        AccountMeta::new(gas_config_pda, false),
+        AccountMeta::new_readonly(gas_paused_chains_pda, false),
+        AccountMeta::new_readonly(gas_service_event_authority, false),
+        AccountMeta::new_readonly(axelar_solana_gas_service::ID, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(call_contract_signing_pda, false),
+        AccountMeta::new_readonly(crate::ID, false),
+        AccountMeta::new(outbound_nonce_pda, false),
+        AccountMeta::new_readonly(token_observer_pda, false),
+        AccountMeta::new_readonly(crate::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    let data = to_vec(&InterchainTokenServiceInstruction::InterchainTransfer {
+        token_id,
+        destination_chain,
+        destination_address,
+        amount,
+        gas_value,
+        signing_pda_bump,
+    })?;
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::InterchainTransferAllowPartial`]
+/// instruction that transfers up to the remaining outbound flow capacity of
+/// the token when `amount` exceeds it.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn interchain_transfer_allow_partial(
+    payer: Pubkey,
+    authority: Pubkey,
+    source_account: Pubkey,
+    token_id: [u8; 32],
+    destination_chain: String,
+    destination_address: Vec<u8>,
+    amount: u64,
+    mint: Pubkey,
+    token_program: Pubkey,
+    gas_value: u64,
+) -> Result<Instruction, ProgramError> {
+    let mut instruction = interchain_transfer(
+        payer,
+        authority,
+        source_account,
+        token_id,
+        destination_chain.clone(),
+        destination_address.clone(),
+        amount,
+        mint,
+        token_program,
+        gas_value,
+    )?;
+
+    let (_, signing_pda_bump) = axelar_solana_gateway::get_call_contract_signing_pda(crate::ID);
+    instruction.data = to_vec(
+        &InterchainTokenServiceInstruction::InterchainTransferAllowPartial {
+            token_id,
+            destination_chain,
+            destination_address,
+            amount,
+            gas_value,
+            signing_pda_bump,
+        },
+    )?;
+
+    Ok(instruction)
+}
+
+/// Sets the observer program registered for the token, see
+/// [`InterchainTokenServiceInstruction::SetTokenObserver`], on an outbound
+/// transfer instruction created by [`interchain_transfer`],
+/// [`interchain_transfer_allow_partial`], [`interchain_transfer_with_token_gas`],
+/// [`cpi_interchain_transfer`], [`call_contract_with_interchain_token`] or
+/// [`cpi_call_contract_with_interchain_token`].
+///
+/// # Errors
+///
+/// [`ProgramError::NotEnoughAccountKeys`]: When the instruction is not an
+/// outbound transfer.
+pub fn with_token_observer(
+    mut instruction: Instruction,
+    token_observer: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    const TOKEN_OBSERVER_PROGRAM_INDEX: usize = 19;
 
-    let accounts = vec![
-        AccountMeta::new(payer, true),
-        AccountMeta::new_readonly(authority, true),
-        AccountMeta::new_readonly(its_root_pda, false),
-        AccountMeta::new(source_account, false),
-        AccountMeta::new(mint, false),
-        AccountMeta::new(token_manager_pda, false),
-        AccountMeta::new(token_manager_ata, false),
-        AccountMeta::new_readonly(token_program, false),
-        AccountMeta::new_readonly(gateway_root_pda, false),
-        AccountMeta::new_readonly(gateway_event_authority, false),
-        AccountMeta::new_readonly(axelar_solana_gateway::ID, false),
-        AccountMeta::new(gas_config_pda, false),
-        AccountMeta::new_readonly(gas_service_event_authority, false),
-        AccountMeta::new_readonly(axelar_solana_gas_service::ID, false),
-        AccountMeta::new_readonly(system_program::ID, false),
-        AccountMeta::new_readonly(call_contract_signing_pda, false),
-        AccountMeta::new_readonly(crate::ID, false),
-        AccountMeta::new_readonly(event_authority, false),
-        AccountMeta::new_readonly(crate::ID, false),
-    ];
+    let observer_program = instruction
+        .accounts
+        .get_mut(TOKEN_OBSERVER_PROGRAM_INDEX)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    *observer_program = AccountMeta::new_readonly(token_observer, false);
 
-    let data = to_vec(&InterchainTokenServiceInstruction::InterchainTransfer {
+    Ok(instruction)
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::InterchainTransferWithTokenGas`]
+/// instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn interchain_transfer_with_token_gas(
+    payer: Pubkey,
+    authority: Pubkey,
+    source_account: Pubkey,
+    token_id: [u8; 32],
+    destination_chain: String,
+    destination_address: Vec<u8>,
+    amount: u64,
+    mint: Pubkey,
+    token_program: Pubkey,
+    gas_amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let mut instruction = interchain_transfer(
+        payer,
+        authority,
+        source_account,
         token_id,
-        destination_chain,
-        destination_address,
+        destination_chain.clone(),
+        destination_address.clone(),
         amount,
-        gas_value,
-        signing_pda_bump,
-    })?;
+        mint,
+        token_program,
+        0,
+    )?;
 
-    Ok(Instruction {
-        program_id: crate::ID,
-        accounts,
-        data,
-    })
+    let (gas_config_pda, _bump) = axelar_solana_gas_service::get_config_pda();
+    let gas_config_ata =
+        get_associated_token_address_with_program_id(&gas_config_pda, &mint, &token_program);
+    instruction
+        .accounts
+        .push(AccountMeta::new(gas_config_ata, false));
+
+    let (_, signing_pda_bump) = axelar_solana_gateway::get_call_contract_signing_pda(crate::ID);
+    instruction.data = to_vec(
+        &InterchainTokenServiceInstruction::InterchainTransferWithTokenGas {
+            token_id,
+            destination_chain,
+            destination_address,
+            amount,
+            gas_amount,
+            signing_pda_bump,
+        },
+    )?;
+
+    Ok(instruction)
 }
 
 /// Creates an [`InterchainTokenServiceInstruction::CpiInterchainTransfer`] instruction.
@@ -1733,6 +2920,7 @@ pub fn cpi_interchain_transfer(
     let (call_contract_signing_pda, signing_pda_bump) =
         axelar_solana_gateway::get_call_contract_signing_pda(crate::ID);
     let (gas_config_pda, _bump) = axelar_solana_gas_service::get_config_pda();
+    let (gas_paused_chains_pda, _bump) = axelar_solana_gas_service::get_paused_chains_pda();
     let (gateway_event_authority, _bump) = Pubkey::find_program_address(
         &[event_cpi::EVENT_AUTHORITY_SEED],
         &axelar_solana_gateway::ID,
@@ -1743,6 +2931,8 @@ pub fn cpi_interchain_transfer(
     );
     let (event_authority, _bump) =
         Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+    let (outbound_nonce_pda, _) = crate::find_outbound_nonce_pda(&source_program_id);
+    let (token_observer_pda, _) = crate::find_token_observer_pda(&token_manager_pda);
 
     let accounts = vec![
         AccountMeta::new(payer, true),
@@ -1757,11 +2947,15 @@ pub fn cpi_interchain_transfer(
         AccountMeta::new_readonly(gateway_event_authority, false),
         AccountMeta::new_readonly(axelar_solana_gateway::ID, false),
         AccountMeta::new(gas_config_pda, false),
+        AccountMeta::new_readonly(gas_paused_chains_pda, false),
         AccountMeta::new_readonly(gas_service_event_authority, false),
         AccountMeta::new_readonly(axelar_solana_gas_service::ID, false),
         AccountMeta::new_readonly(system_program::ID, false),
         AccountMeta::new_readonly(call_contract_signing_pda, false),
         AccountMeta::new_readonly(crate::ID, false),
+        AccountMeta::new(outbound_nonce_pda, false),
+        AccountMeta::new_readonly(token_observer_pda, false),
+        AccountMeta::new_readonly(crate::ID, false),
         AccountMeta::new_readonly(event_authority, false),
         AccountMeta::new_readonly(crate::ID, false),
     ];
@@ -1821,6 +3015,9 @@ pub fn call_contract_with_interchain_token(
     let (event_authority, _bump) =
         Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
     let (gas_config_pda, _bump) = axelar_solana_gas_service::get_config_pda();
+    let (gas_paused_chains_pda, _bump) = axelar_solana_gas_service::get_paused_chains_pda();
+    let (outbound_nonce_pda, _) = crate::find_outbound_nonce_pda(&authority);
+    let (token_observer_pda, _) = crate::find_token_observer_pda(&token_manager_pda);
 
     let accounts = vec![
         AccountMeta::new(payer, true),
@@ -1835,11 +3032,15 @@ pub fn call_contract_with_interchain_token(
         AccountMeta::new_readonly(gateway_event_authority, false),
         AccountMeta::new_readonly(axelar_solana_gateway::ID, false),
         AccountMeta::new(gas_config_pda, false),
+        AccountMeta::new_readonly(gas_paused_chains_pda, false),
         AccountMeta::new_readonly(gas_service_event_authority, false),
         AccountMeta::new_readonly(axelar_solana_gas_service::ID, false),
         AccountMeta::new_readonly(system_program::ID, false),
         AccountMeta::new_readonly(call_contract_signing_pda, false),
         AccountMeta::new_readonly(crate::ID, false),
+        AccountMeta::new(outbound_nonce_pda, false),
+        AccountMeta::new_readonly(token_observer_pda, false),
+        AccountMeta::new_readonly(crate::ID, false),
         AccountMeta::new_readonly(event_authority, false),
         AccountMeta::new_readonly(crate::ID, false),
     ];
@@ -1903,6 +3104,9 @@ pub fn cpi_call_contract_with_interchain_token(
     let (event_authority, _bump) =
         Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
     let (gas_config_pda, _bump) = axelar_solana_gas_service::get_config_pda();
+    let (gas_paused_chains_pda, _bump) = axelar_solana_gas_service::get_paused_chains_pda();
+    let (outbound_nonce_pda, _) = crate::find_outbound_nonce_pda(&source_program_id);
+    let (token_observer_pda, _) = crate::find_token_observer_pda(&token_manager_pda);
 
     let accounts = vec![
         AccountMeta::new(payer, true),
@@ -1917,11 +3121,15 @@ pub fn cpi_call_contract_with_interchain_token(
         AccountMeta::new_readonly(gateway_event_authority, false),
         AccountMeta::new_readonly(axelar_solana_gateway::ID, false),
         AccountMeta::new(gas_config_pda, false),
+        AccountMeta::new_readonly(gas_paused_chains_pda, false),
         AccountMeta::new_readonly(gas_service_event_authority, false),
         AccountMeta::new_readonly(axelar_solana_gas_service::ID, false),
         AccountMeta::new_readonly(system_program::ID, false),
         AccountMeta::new_readonly(call_contract_signing_pda, false),
         AccountMeta::new_readonly(crate::ID, false),
+        AccountMeta::new(outbound_nonce_pda, false),
+        AccountMeta::new_readonly(token_observer_pda, false),
+        AccountMeta::new_readonly(crate::ID, false),
         AccountMeta::new_readonly(event_authority, false),
         AccountMeta::new_readonly(crate::ID, false),
     ];
@@ -1997,19 +3205,12 @@ pub fn execute(inputs: ExecuteInstructionInputs) -> Result<Instruction, ProgramE
         &inputs.message,
     );
 
-    let unwrapped_payload = match inputs.payload {
-        GMPPayload::InterchainTransfer(_)
-        | GMPPayload::DeployInterchainToken(_)
-        | GMPPayload::LinkToken(_)
-        | GMPPayload::RegisterTokenMetadata(_) => inputs.payload,
-        GMPPayload::SendToHub(inner) => GMPPayload::decode(&inner.payload)
-            .map_err(|_err| ProgramError::InvalidInstructionData)?,
-        GMPPayload::ReceiveFromHub(inner) => GMPPayload::decode(&inner.payload)
-            .map_err(|_err| ProgramError::InvalidInstructionData)?,
-    };
-
-    let mut its_accounts =
-        derive_its_accounts(&unwrapped_payload, inputs.token_program, inputs.mint)?;
+    let mut its_accounts = derive_its_accounts(
+        unwrap_hub_payload(&inputs.payload)?.as_ref(),
+        inputs.token_program,
+        inputs.mint,
+        inputs.token_observer,
+    )?;
 
     accounts.append(&mut its_accounts);
 
@@ -2024,6 +3225,97 @@ pub fn execute(inputs: ExecuteInstructionInputs) -> Result<Instruction, ProgramE
     })
 }
 
+/// Creates an [`InterchainTokenServiceInstruction::EstimateInboundRent`]
+/// instruction for the inbound transfer of `mint` carried by `payload`.
+///
+/// # Errors
+///
+/// [`ProgramError::InvalidInstructionData`]: When the payload is not an
+/// interchain transfer or carries a malformed destination address.
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn estimate_inbound_rent(
+    payload: Vec<u8>,
+    mint: Pubkey,
+    token_program: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let transfer = crate::inbound_rent::decode_inbound_transfer(&payload)?;
+    let destination_address: &[u8] = transfer.destination_address.as_ref();
+    let destination = Pubkey::try_from(destination_address)
+        .map_err(|_err| ProgramError::InvalidInstructionData)?;
+    let destination_ata =
+        get_associated_token_address_with_program_id(&destination, &mint, &token_program);
+    let transfer_execute = if transfer.data.is_empty() {
+        crate::ID
+    } else {
+        crate::find_interchain_transfer_execute_pda(&destination).0
+    };
+
+    let data = to_vec(&InterchainTokenServiceInstruction::EstimateInboundRent { payload })?;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(mint, false),
+        AccountMeta::new_readonly(destination, false),
+        AccountMeta::new_readonly(destination_ata, false),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(transfer_execute, false),
+    ];
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
+/// Derives the full account list of an
+/// [`InterchainTokenServiceInstruction::Execute`] instruction processing
+/// `payload`, in the exact order the processor expects.
+///
+/// `payload` can either be the ITS Hub wrapped payload or the inner ITS
+/// message. The gateway incoming message and message payload PDAs are derived
+/// from `message`, with `payer` being the account that uploaded the payload
+/// and pays for the execution.
+///
+/// `mint` is required for `InterchainTransfer` payloads as it cannot be
+/// derived from the payload, `token_observer` is the observer program
+/// registered for the token, if any. Both are ignored otherwise.
+///
+/// # Errors
+///
+/// [`ProgramError::InvalidInstructionData`]: When the payload is not an
+/// inbound ITS message or carries malformed addresses.
+pub fn derive_inbound_accounts(
+    payload: &GMPPayload,
+    message: &Message,
+    payer: Pubkey,
+    token_program: Pubkey,
+    mint: Option<Pubkey>,
+    token_observer: Option<Pubkey>,
+) -> Result<Vec<AccountMeta>, ProgramError> {
+    let (incoming_message_pda, _) = axelar_solana_gateway::get_incoming_message_pda(&command_id(
+        &message.cc_id.chain,
+        &message.cc_id.id,
+    ));
+    let (message_payload_pda, _) =
+        axelar_solana_gateway::find_message_payload_pda(incoming_message_pda, payer);
+
+    let payload = unwrap_hub_payload(payload)?;
+    let (mint, token_observer) = if let GMPPayload::InterchainTransfer(_) = payload.as_ref() {
+        (mint, token_observer)
+    } else {
+        (None, None)
+    };
+
+    let mut accounts =
+        prefix_accounts(&payer, &incoming_message_pda, &message_payload_pda, message);
+    let mut its_accounts =
+        derive_its_accounts(payload.as_ref(), token_program, mint, token_observer)?;
+
+    accounts.append(&mut its_accounts);
+
+    Ok(accounts)
+}
+
 /// Creates an [`InterchainTokenServiceInstruction::OperatorInstruction`]
 /// instruction with the [`operator::Instruction::TransferOperatorship`]
 /// variant.
@@ -2047,7 +3339,7 @@ pub fn transfer_operatorship(
         AccountMeta::new(payer, true),
         AccountMeta::new(sender, true),
         AccountMeta::new(sender_roles_pda, false),
-        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new(its_root_pda, false),
         AccountMeta::new_readonly(to, false),
         AccountMeta::new(destination_roles_pda, false),
     ];
@@ -2061,6 +3353,50 @@ pub fn transfer_operatorship(
     })
 }
 
+/// Creates an [`InterchainTokenServiceInstruction::TransferOperatorshipWithGracePeriod`] instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn transfer_operatorship_with_grace_period(
+    payer: Pubkey,
+    sender: Pubkey,
+    to: Pubkey,
+    grace_period_slots: u64,
+) -> Result<Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (sender_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::id(), &its_root_pda, &sender);
+    let (destination_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::id(), &its_root_pda, &to);
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(payer, true),
+        AccountMeta::new(sender, true),
+        AccountMeta::new(sender_roles_pda, false),
+        AccountMeta::new(its_root_pda, false),
+        AccountMeta::new_readonly(to, false),
+        AccountMeta::new(destination_roles_pda, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    let data = to_vec(
+        &InterchainTokenServiceInstruction::TransferOperatorshipWithGracePeriod {
+            grace_period_slots,
+        },
+    )?;
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
 /// Creates an [`InterchainTokenServiceInstruction::ProposeOperatorship`] instruction.
 ///
 /// # Errors
@@ -2087,11 +3423,11 @@ pub fn propose_operatorship(
     let accounts = vec![
         AccountMeta::new_readonly(solana_program::system_program::id(), false),
         AccountMeta::new(payer, true),
-        AccountMeta::new_readonly(proposer, false),
+        AccountMeta::new_readonly(proposer, true),
         AccountMeta::new_readonly(proposer_roles_pda, false),
         AccountMeta::new_readonly(its_root_pda, false),
         AccountMeta::new_readonly(to, false),
-        AccountMeta::new_readonly(destination_roles_pda, false),
+        AccountMeta::new(destination_roles_pda, false),
         AccountMeta::new(proposal_pda, false),
     ];
 
@@ -2132,8 +3468,8 @@ pub fn accept_operatorship(
         AccountMeta::new(payer, true),
         AccountMeta::new_readonly(role_receiver, true),
         AccountMeta::new(role_receiver_roles_pda, false),
-        AccountMeta::new_readonly(its_root_pda, false),
-        AccountMeta::new_readonly(from, false),
+        AccountMeta::new(its_root_pda, false),
+        AccountMeta::new(from, false),
         AccountMeta::new(origin_roles_pda, false),
         AccountMeta::new(proposal_pda, false),
     ];
@@ -2147,6 +3483,73 @@ pub fn accept_operatorship(
     })
 }
 
+/// Creates an [`InterchainTokenServiceInstruction::PreviewInterchainTokenId`] instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn preview_interchain_token_id(
+    deployer: Pubkey,
+    salt: [u8; 32],
+) -> Result<Instruction, ProgramError> {
+    let data =
+        to_vec(&InterchainTokenServiceInstruction::PreviewInterchainTokenId { deployer, salt })?;
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: vec![],
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::PreviewCanonicalTokenId`] instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn preview_canonical_token_id(mint: Pubkey) -> Result<Instruction, ProgramError> {
+    let data = to_vec(&InterchainTokenServiceInstruction::PreviewCanonicalTokenId { mint })?;
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: vec![],
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::PreviewLinkedTokenId`] instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn preview_linked_token_id(
+    deployer: Pubkey,
+    salt: [u8; 32],
+) -> Result<Instruction, ProgramError> {
+    let data = to_vec(&InterchainTokenServiceInstruction::PreviewLinkedTokenId { deployer, salt })?;
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: vec![],
+        data,
+    })
+}
+
+fn unwrap_hub_payload(payload: &GMPPayload) -> Result<Cow<'_, GMPPayload>, ProgramError> {
+    match payload {
+        GMPPayload::InterchainTransfer(_)
+        | GMPPayload::DeployInterchainToken(_)
+        | GMPPayload::LinkToken(_)
+        | GMPPayload::RegisterTokenMetadata(_) => Ok(Cow::Borrowed(payload)),
+        GMPPayload::SendToHub(inner) => GMPPayload::decode(&inner.payload)
+            .map(Cow::Owned)
+            .map_err(|_err| ProgramError::InvalidInstructionData),
+        GMPPayload::ReceiveFromHub(inner) => GMPPayload::decode(&inner.payload)
+            .map(Cow::Owned)
+            .map_err(|_err| ProgramError::InvalidInstructionData),
+    }
+}
+
 fn prefix_accounts(
     payer: &Pubkey,
     gateway_incoming_message_pda: &Pubkey,
@@ -2178,6 +3581,7 @@ pub(crate) fn derive_its_accounts<'a, T>(
     payload: T,
     token_program: Pubkey,
     maybe_mint: Option<Pubkey>,
+    token_observer: Option<Pubkey>,
 ) -> Result<Vec<AccountMeta>, ProgramError>
 where
     T: TryInto<ItsMessageRef<'a>>,
@@ -2194,8 +3598,13 @@ where
     let (mut accounts, mint, token_manager_pda) =
         derive_common_its_accounts(token_program, &message, maybe_mint)?;
 
-    let mut message_specific_accounts =
-        derive_specific_its_accounts(&message, mint, token_manager_pda, token_program)?;
+    let mut message_specific_accounts = derive_specific_its_accounts(
+        &message,
+        mint,
+        token_manager_pda,
+        token_program,
+        token_observer,
+    )?;
 
     accounts.append(&mut message_specific_accounts);
 
@@ -2207,6 +3616,7 @@ fn derive_specific_its_accounts(
     mint_account: Pubkey,
     token_manager_pda: Pubkey,
     token_program: Pubkey,
+    token_observer: Option<Pubkey>,
 ) -> Result<Vec<AccountMeta>, ProgramError> {
     let mut specific_accounts = Vec::new();
 
@@ -2231,6 +3641,13 @@ fn derive_specific_its_accounts(
             specific_accounts.push(AccountMeta::new(wallet, false));
             specific_accounts.push(AccountMeta::new(destination_ata, false));
 
+            let (token_observer_pda, _) = crate::find_token_observer_pda(&token_manager_pda);
+            specific_accounts.push(AccountMeta::new_readonly(token_observer_pda, false));
+            specific_accounts.push(AccountMeta::new_readonly(
+                token_observer.unwrap_or(crate::ID),
+                false,
+            ));
+
             if data.is_empty() {
                 specific_accounts.push(AccountMeta::new_readonly(crate::ID, false));
             } else {
@@ -2273,15 +3690,22 @@ fn derive_specific_its_accounts(
             }
         }
         ItsMessageRef::LinkToken { link_params, .. } => {
-            if let Ok(operator) = Pubkey::try_from(*link_params) {
-                let (operator_roles_pda, _) =
-                    role_management::find_user_roles_pda(&crate::ID, &token_manager_pda, &operator);
-
-                specific_accounts.push(AccountMeta::new_readonly(operator, false));
-                specific_accounts.push(AccountMeta::new(operator_roles_pda, false));
-            } else {
-                specific_accounts.push(AccountMeta::new_readonly(crate::ID, false));
-                specific_accounts.push(AccountMeta::new_readonly(crate::ID, false));
+            let link_params = LinkParams::decode(link_params)?;
+
+            for role_holder in [link_params.operator, link_params.flow_limiter] {
+                if let Some(role_holder) = role_holder {
+                    let (roles_pda, _) = role_management::find_user_roles_pda(
+                        &crate::ID,
+                        &token_manager_pda,
+                        &role_holder,
+                    );
+
+                    specific_accounts.push(AccountMeta::new_readonly(role_holder, false));
+                    specific_accounts.push(AccountMeta::new(roles_pda, false));
+                } else {
+                    specific_accounts.push(AccountMeta::new_readonly(crate::ID, false));
+                    specific_accounts.push(AccountMeta::new_readonly(crate::ID, false));
+                }
             }
         }
     };
@@ -2427,9 +3851,173 @@ impl<'a> TryFrom<&'a GMPPayload> for ItsMessageRef<'a> {
 mod tests {
     use std::borrow::Cow;
 
+    use axelar_solana_encoding::types::messages::{CrossChainId, Message};
+    use interchain_token_transfer_gmp::alloy_primitives::{Bytes, U256};
+    use interchain_token_transfer_gmp::{
+        DeployInterchainToken, GMPPayload, InterchainTransfer, LinkToken, ReceiveFromHub,
+    };
     use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 
-    use super::{derive_specific_its_accounts, ItsMessageRef};
+    use super::{
+        derive_inbound_accounts, derive_its_accounts, derive_specific_its_accounts, execute,
+        ExecuteInstructionInputs, ItsMessageRef,
+    };
+
+    /// Number of gateway accounts preceding the ITS accounts, as parsed by
+    /// `ExecuteAccounts`.
+    const GATEWAY_ACCOUNTS_LEN: usize = 7;
+
+    fn inbound_message() -> Message {
+        Message {
+            cc_id: CrossChainId {
+                chain: "axelar".to_owned(),
+                id: "inbound-1".to_owned(),
+            },
+            source_address: "its-hub".to_owned(),
+            destination_chain: "solana".to_owned(),
+            destination_address: crate::ID.to_string(),
+            payload_hash: [1; 32],
+        }
+    }
+
+    fn from_hub(payload: &GMPPayload) -> GMPPayload {
+        GMPPayload::ReceiveFromHub(ReceiveFromHub {
+            selector: U256::from(ReceiveFromHub::MESSAGE_TYPE_ID),
+            source_chain: "ethereum".to_owned(),
+            payload: payload.encode().into(),
+        })
+    }
+
+    fn inbound_payloads(wallet: Pubkey, link_target: Pubkey) -> Vec<GMPPayload> {
+        vec![
+            GMPPayload::InterchainTransfer(InterchainTransfer {
+                selector: U256::from(InterchainTransfer::MESSAGE_TYPE_ID),
+                token_id: [7; 32].into(),
+                source_address: Bytes::from(vec![0; 20]),
+                destination_address: Bytes::from(wallet.to_bytes().to_vec()),
+                amount: U256::from(100_u64),
+                data: Bytes::new(),
+            }),
+            GMPPayload::DeployInterchainToken(DeployInterchainToken {
+                selector: U256::from(DeployInterchainToken::MESSAGE_TYPE_ID),
+                token_id: [8; 32].into(),
+                name: "Test Token".to_owned(),
+                symbol: "TST".to_owned(),
+                decimals: 9,
+                minter: Bytes::from(wallet.to_bytes().to_vec()),
+            }),
+            GMPPayload::LinkToken(LinkToken {
+                selector: U256::from(LinkToken::MESSAGE_TYPE_ID),
+                token_id: [9; 32].into(),
+                token_manager_type: U256::from(2_u8),
+                source_token_address: Bytes::from(vec![0; 20]),
+                destination_token_address: Bytes::from(link_target.to_bytes().to_vec()),
+                link_params: Bytes::new(),
+            }),
+        ]
+    }
+
+    #[test]
+    fn test_derive_inbound_accounts_matches_execute_instruction() {
+        let payer = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let message = inbound_message();
+        let (incoming_message_pda, _) = axelar_solana_gateway::get_incoming_message_pda(
+            &axelar_solana_gateway::state::incoming_message::command_id(
+                &message.cc_id.chain,
+                &message.cc_id.id,
+            ),
+        );
+        let (message_payload_pda, _) =
+            axelar_solana_gateway::find_message_payload_pda(incoming_message_pda, payer);
+
+        for payload in inbound_payloads(Pubkey::new_unique(), Pubkey::new_unique()) {
+            let is_transfer = matches!(payload, GMPPayload::InterchainTransfer(_));
+            let payload = from_hub(&payload);
+            let derived = derive_inbound_accounts(
+                &payload,
+                &message,
+                payer,
+                spl_token_2022::ID,
+                Some(mint),
+                None,
+            )
+            .unwrap();
+
+            let inputs = ExecuteInstructionInputs::builder()
+                .payer(payer)
+                .incoming_message_pda(incoming_message_pda)
+                .message_payload_pda(message_payload_pda)
+                .message(message.clone())
+                .token_program(spl_token_2022::ID)
+                .mint_opt(is_transfer.then_some(mint))
+                .payload(payload)
+                .build();
+
+            assert_eq!(derived, execute(inputs).unwrap().accounts);
+        }
+    }
+
+    #[test]
+    fn test_derive_inbound_accounts_matches_processor_validation() {
+        let payer = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let observer = Pubkey::new_unique();
+        let message = inbound_message();
+
+        for payload in inbound_payloads(Pubkey::new_unique(), mint) {
+            let derived = derive_inbound_accounts(
+                &from_hub(&payload),
+                &message,
+                payer,
+                spl_token_2022::ID,
+                Some(mint),
+                Some(observer),
+            )
+            .unwrap();
+
+            // The processor checks everything after the gateway accounts
+            // against `derive_its_accounts` for the unwrapped payload, taking
+            // the mint and observer from the accounts only for transfers.
+            let is_transfer = matches!(payload, GMPPayload::InterchainTransfer(_));
+            let expected = derive_its_accounts(
+                &payload,
+                spl_token_2022::ID,
+                is_transfer.then_some(mint),
+                is_transfer.then_some(observer),
+            )
+            .unwrap();
+            assert_eq!(derived[GATEWAY_ACCOUNTS_LEN..], expected[..]);
+
+            assert_eq!(derived[0].pubkey, payer);
+            assert!(derived[0].is_signer);
+            assert_eq!(derived[GATEWAY_ACCOUNTS_LEN + 5].pubkey, spl_token_2022::ID);
+
+            if is_transfer {
+                assert_eq!(derived[GATEWAY_ACCOUNTS_LEN + 3].pubkey, mint);
+                assert_eq!(derived[GATEWAY_ACCOUNTS_LEN + 13].pubkey, observer);
+            }
+        }
+    }
+
+    #[test]
+    fn test_derive_inbound_accounts_requires_mint_for_transfers() {
+        let payload = inbound_payloads(Pubkey::new_unique(), Pubkey::new_unique())
+            .into_iter()
+            .next()
+            .unwrap();
+
+        let result = derive_inbound_accounts(
+            &payload,
+            &inbound_message(),
+            Pubkey::new_unique(),
+            spl_token_2022::ID,
+            None,
+            None,
+        );
+
+        assert_eq!(result.unwrap_err(), ProgramError::InvalidInstructionData);
+    }
 
     #[test]
     fn test_deploy_interchain_token_with_invalid_minter_pubkey() {
@@ -2454,8 +4042,13 @@ mod tests {
         let token_program = spl_token_2022::ID;
 
         // This should fail with InvalidInstructionData because minter is not empty but also not 32 bytes
-        let result =
-            derive_specific_its_accounts(&message, mint_account, token_manager_pda, token_program);
+        let result = derive_specific_its_accounts(
+            &message,
+            mint_account,
+            token_manager_pda,
+            token_program,
+            None,
+        );
 
         assert_eq!(result.unwrap_err(), ProgramError::InvalidInstructionData);
     }
@@ -2483,8 +4076,13 @@ mod tests {
         let token_program = spl_token_2022::ID;
 
         // This should succeed because empty minter is allowed
-        let result =
-            derive_specific_its_accounts(&message, mint_account, token_manager_pda, token_program);
+        let result = derive_specific_its_accounts(
+            &message,
+            mint_account,
+            token_manager_pda,
+            token_program,
+            None,
+        );
 
         assert!(result.is_ok());
         let accounts = result.unwrap();
@@ -2518,8 +4116,13 @@ mod tests {
         let token_program = spl_token_2022::ID;
 
         // This should succeed because minter is exactly 32 bytes
-        let result =
-            derive_specific_its_accounts(&message, mint_account, token_manager_pda, token_program);
+        let result = derive_specific_its_accounts(
+            &message,
+            mint_account,
+            token_manager_pda,
+            token_program,
+            None,
+        );
 
         assert!(result.is_ok());
         let accounts = result.unwrap();
@@ -2527,4 +4130,56 @@ mod tests {
         // Should have 6 accounts (4 base accounts + minter_key + minter_roles_pda)
         assert_eq!(accounts.len(), 6);
     }
+
+    fn link_token_message(link_params: &[u8]) -> ItsMessageRef<'_> {
+        ItsMessageRef::LinkToken {
+            token_id: Cow::Owned([0; 32]),
+            source_token_address: &[6; 20],
+            destination_token_address: &[7; 32],
+            token_manager_type: crate::state::token_manager::Type::LockUnlock,
+            link_params,
+        }
+    }
+
+    #[test]
+    fn test_link_token_with_operator_and_flow_limiter() {
+        let operator = Pubkey::new_unique();
+        let flow_limiter = Pubkey::new_unique();
+        let link_params = crate::link_params::LinkParams {
+            operator: Some(operator),
+            flow_limiter: Some(flow_limiter),
+        }
+        .encode();
+
+        let accounts = derive_specific_its_accounts(
+            &link_token_message(&link_params),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            spl_token_2022::ID,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(accounts.len(), 4);
+        assert_eq!(accounts[0].pubkey, operator);
+        assert_eq!(accounts[2].pubkey, flow_limiter);
+        assert!(accounts[3].is_writable);
+    }
+
+    #[test]
+    fn test_link_token_with_invalid_link_params() {
+        // An EVM address is not a valid Solana operator
+        let result = derive_specific_its_accounts(
+            &link_token_message(&[1; 20]),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            spl_token_2022::ID,
+            None,
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            crate::error::ItsError::InvalidLinkParams.into()
+        );
+    }
 }
@@ -0,0 +1,342 @@
+//! Address codec helpers for the ITS instructions.
+//!
+//! ITS messages carry addresses as raw bytes and every chain interprets them
+//! its own way. The helpers here convert between the human readable address
+//! of a chain and the bytes its ITS contract expects, so that
+//! `destination_address` values are built the same way everywhere:
+//!
+//! | Chain   | Human readable                | Bytes in ITS messages         |
+//! |---------|-------------------------------|-------------------------------|
+//! | Solana  | base58 public key             | the 32 bytes of the key       |
+//! | EVM     | `0x` + 40 hex digits (EIP-55) | the 20 bytes of the address   |
+//! | Sui     | `0x` + up to 64 hex digits    | the 32 bytes of the address   |
+//! | Aptos   | `0x` + up to 64 hex digits    | the 32 bytes of the address   |
+//! | Stellar | `G...` / `C...` strkey        | the UTF-8 bytes of the strkey |
+
+use core::str::FromStr;
+
+use alloy_primitives::{hex, Address};
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+/// Length in bytes of Sui and Aptos addresses.
+const MOVE_ADDRESS_LEN: usize = 32;
+
+/// Length in characters of a Stellar strkey for a 32-byte payload.
+const STELLAR_STRKEY_LEN: usize = 56;
+
+/// Strkey version byte of Stellar account IDs (`G...`).
+const STELLAR_ACCOUNT_VERSION: u8 = 6 << 3;
+
+/// Strkey version byte of Stellar contract IDs (`C...`).
+const STELLAR_CONTRACT_VERSION: u8 = 2 << 3;
+
+/// The address formats of the chains ITS connects to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddressFormat {
+    /// Solana public keys.
+    Solana,
+    /// Ethereum and other EVM chains.
+    Evm,
+    /// Sui.
+    Sui,
+    /// Aptos.
+    Aptos,
+    /// Stellar account and contract IDs.
+    Stellar,
+}
+
+/// An address that is invalid for its [`AddressFormat`].
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("Invalid {format:?} address: {reason}")]
+pub struct InvalidAddress {
+    /// The format the address was checked against.
+    pub format: AddressFormat,
+    /// Why the address was rejected.
+    pub reason: &'static str,
+}
+
+impl From<InvalidAddress> for ProgramError {
+    fn from(error: InvalidAddress) -> Self {
+        msg!("{}", error);
+        Self::InvalidArgument
+    }
+}
+
+const fn invalid(format: AddressFormat, reason: &'static str) -> InvalidAddress {
+    InvalidAddress { format, reason }
+}
+
+/// Returns the bytes representing `pubkey` in ITS messages sent to Solana.
+#[must_use]
+pub fn pubkey_to_address_bytes(pubkey: &Pubkey) -> Vec<u8> {
+    pubkey.to_bytes().to_vec()
+}
+
+/// Reads a Solana public key from the bytes of an ITS message.
+///
+/// # Errors
+///
+/// If `bytes` is not exactly 32 bytes long.
+pub fn pubkey_from_address_bytes(bytes: &[u8]) -> Result<Pubkey, InvalidAddress> {
+    Pubkey::try_from(bytes).map_err(|_err| invalid(AddressFormat::Solana, "expected 32 bytes"))
+}
+
+/// Converts a human readable `address` to the bytes the ITS contract on a
+/// chain using `format` expects, validating it on the way.
+///
+/// # Errors
+///
+/// If `address` isn't a valid address in `format`. Mixed-case EVM addresses
+/// must carry a valid EIP-55 checksum.
+pub fn encode_address(format: AddressFormat, address: &str) -> Result<Vec<u8>, InvalidAddress> {
+    match format {
+        AddressFormat::Solana => Pubkey::from_str(address)
+            .map(|pubkey| pubkey_to_address_bytes(&pubkey))
+            .map_err(|_err| invalid(format, "expected a base58 encoded public key")),
+        AddressFormat::Evm => parse_evm_address(address).map(|address| address.to_vec()),
+        AddressFormat::Sui | AddressFormat::Aptos => {
+            parse_move_address(format, address).map(|address| address.to_vec())
+        }
+        AddressFormat::Stellar => {
+            validate_stellar_strkey(address)?;
+            Ok(address.as_bytes().to_vec())
+        }
+    }
+}
+
+/// Formats the `bytes` of an ITS message as the human readable address of a
+/// chain using `format`.
+///
+/// EVM addresses are checksummed, Sui addresses are zero padded to 64 hex
+/// digits and Aptos addresses use the short form for special addresses
+/// (`0x0` to `0xf`), as defined in AIP-40.
+///
+/// # Errors
+///
+/// If `bytes` doesn't hold a valid address in `format`.
+pub fn format_address(format: AddressFormat, bytes: &[u8]) -> Result<String, InvalidAddress> {
+    match format {
+        AddressFormat::Solana => pubkey_from_address_bytes(bytes).map(|pubkey| pubkey.to_string()),
+        AddressFormat::Evm => {
+            let address: [u8; 20] = bytes
+                .try_into()
+                .map_err(|_err| invalid(format, "expected 20 bytes"))?;
+            Ok(Address::from(address).to_checksum(None))
+        }
+        AddressFormat::Sui | AddressFormat::Aptos => {
+            let address: [u8; MOVE_ADDRESS_LEN] = bytes
+                .try_into()
+                .map_err(|_err| invalid(format, "expected 32 bytes"))?;
+            Ok(format_move_address(format, &address))
+        }
+        AddressFormat::Stellar => {
+            let address = core::str::from_utf8(bytes)
+                .map_err(|_err| invalid(format, "expected an UTF-8 encoded strkey"))?;
+            validate_stellar_strkey(address)?;
+            Ok(address.to_owned())
+        }
+    }
+}
+
+fn parse_evm_address(address: &str) -> Result<Address, InvalidAddress> {
+    let format = AddressFormat::Evm;
+    let digits = address
+        .strip_prefix("0x")
+        .ok_or_else(|| invalid(format, "expected a 0x prefix"))?;
+    if digits.len() != 40 {
+        return Err(invalid(format, "expected 40 hex digits"));
+    }
+
+    let is_mixed_case = digits.chars().any(|c| c.is_ascii_lowercase())
+        && digits.chars().any(|c| c.is_ascii_uppercase());
+    if is_mixed_case {
+        Address::parse_checksummed(address, None)
+            .map_err(|_err| invalid(format, "invalid EIP-55 checksum"))
+    } else {
+        Address::from_str(address).map_err(|_err| invalid(format, "expected hex digits"))
+    }
+}
+
+fn parse_move_address(
+    format: AddressFormat,
+    address: &str,
+) -> Result<[u8; MOVE_ADDRESS_LEN], InvalidAddress> {
+    let digits = address
+        .strip_prefix("0x")
+        .ok_or_else(|| invalid(format, "expected a 0x prefix"))?;
+    if digits.is_empty() || digits.len() > MOVE_ADDRESS_LEN * 2 {
+        return Err(invalid(format, "expected 1 to 64 hex digits"));
+    }
+
+    let padded = format!("{digits:0>64}");
+    let mut bytes = [0_u8; MOVE_ADDRESS_LEN];
+    hex::decode_to_slice(padded, &mut bytes)
+        .map_err(|_err| invalid(format, "expected hex digits"))?;
+    Ok(bytes)
+}
+
+fn format_move_address(format: AddressFormat, address: &[u8; MOVE_ADDRESS_LEN]) -> String {
+    if format == AddressFormat::Aptos {
+        if let Some((last, rest)) = address.split_last() {
+            if *last < 0x10 && rest.iter().all(|byte| *byte == 0) {
+                return format!("0x{last:x}");
+            }
+        }
+    }
+
+    format!("0x{}", hex::encode(address))
+}
+
+/// Checks that `address` is a Stellar account (`G...`) or contract (`C...`)
+/// strkey: base32 encoded version byte, 32-byte key and CRC16-XModem
+/// checksum.
+fn validate_stellar_strkey(address: &str) -> Result<(), InvalidAddress> {
+    let format = AddressFormat::Stellar;
+    if address.len() != STELLAR_STRKEY_LEN {
+        return Err(invalid(format, "expected 56 characters"));
+    }
+
+    let decoded = base32_decode(address).ok_or_else(|| invalid(format, "expected base32"))?;
+    let Some((payload, checksum)) = decoded.split_last_chunk::<2>() else {
+        return Err(invalid(format, "expected a checksum"));
+    };
+    let Some((version, _key)) = payload.split_first() else {
+        return Err(invalid(format, "expected a version byte"));
+    };
+
+    if *version != STELLAR_ACCOUNT_VERSION && *version != STELLAR_CONTRACT_VERSION {
+        return Err(invalid(format, "expected an account or contract ID"));
+    }
+
+    let [low, high] = *checksum;
+    let expected = u16::from(low) | (u16::from(high) << 8);
+    if crc16_xmodem(payload) != expected {
+        return Err(invalid(format, "invalid checksum"));
+    }
+
+    Ok(())
+}
+
+/// Decodes unpadded RFC 4648 base32, rejecting non-zero trailing bits.
+fn base32_decode(input: &str) -> Option<Vec<u8>> {
+    let mut output = Vec::with_capacity(input.len());
+    let mut buffer: u32 = 0;
+    let mut bits: u32 = 0;
+
+    for c in input.bytes() {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'2'..=b'7' => c - b'2' + 26,
+            _ => return None,
+        };
+        buffer = (buffer << 5) | u32::from(value);
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            output.push(u8::try_from(buffer >> bits).ok()?);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    (buffer == 0).then_some(output)
+}
+
+fn crc16_xmodem(data: &[u8]) -> u16 {
+    data.iter().fold(0_u16, |crc, byte| {
+        (0..8).fold(crc ^ (u16::from(*byte) << 8), |crc, _| {
+            if crc & 0x8000 == 0 {
+                crc << 1
+            } else {
+                (crc << 1) ^ 0x1021
+            }
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EVM_ADDRESS: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+    const STELLAR_ACCOUNT: &str = "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ";
+    const STELLAR_CONTRACT: &str = "CA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAXE";
+
+    #[test]
+    fn test_solana_round_trip() {
+        let pubkey = Pubkey::new_unique();
+        let bytes = encode_address(AddressFormat::Solana, &pubkey.to_string()).unwrap();
+
+        assert_eq!(bytes, pubkey_to_address_bytes(&pubkey));
+        assert_eq!(pubkey_from_address_bytes(&bytes).unwrap(), pubkey);
+        assert_eq!(
+            format_address(AddressFormat::Solana, &bytes).unwrap(),
+            pubkey.to_string()
+        );
+        assert!(pubkey_from_address_bytes(&[0; 20]).is_err());
+    }
+
+    #[test]
+    fn test_evm_addresses_are_checksummed() {
+        let bytes = encode_address(AddressFormat::Evm, EVM_ADDRESS).unwrap();
+        assert_eq!(bytes.len(), 20);
+        assert_eq!(
+            format_address(AddressFormat::Evm, &bytes).unwrap(),
+            EVM_ADDRESS
+        );
+
+        let lowercase = EVM_ADDRESS.to_lowercase();
+        assert_eq!(
+            encode_address(AddressFormat::Evm, &lowercase).unwrap(),
+            bytes
+        );
+
+        let bad_checksum = EVM_ADDRESS.replace("aA", "Aa");
+        assert!(encode_address(AddressFormat::Evm, &bad_checksum).is_err());
+        assert!(encode_address(
+            AddressFormat::Evm,
+            "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"
+        )
+        .is_err());
+        assert!(encode_address(AddressFormat::Evm, "0x5aaeb6053f").is_err());
+        assert!(format_address(AddressFormat::Evm, &[0; 32]).is_err());
+    }
+
+    #[test]
+    fn test_move_addresses_are_zero_padded() {
+        let bytes = encode_address(AddressFormat::Sui, "0x2").unwrap();
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(bytes.last(), Some(&2));
+        assert_eq!(
+            format_address(AddressFormat::Sui, &bytes).unwrap(),
+            format!("0x{:0>64}", "2")
+        );
+        assert_eq!(format_address(AddressFormat::Aptos, &bytes).unwrap(), "0x2");
+
+        let full = format!("0x{}", "ab".repeat(32));
+        let bytes = encode_address(AddressFormat::Aptos, &full).unwrap();
+        assert_eq!(format_address(AddressFormat::Aptos, &bytes).unwrap(), full);
+
+        assert!(encode_address(AddressFormat::Sui, "0x").is_err());
+        assert!(encode_address(AddressFormat::Sui, &format!("0x{}", "a".repeat(65))).is_err());
+        assert!(encode_address(AddressFormat::Aptos, "0xzz").is_err());
+    }
+
+    #[test]
+    fn test_stellar_strkeys_are_validated() {
+        for address in [STELLAR_ACCOUNT, STELLAR_CONTRACT] {
+            let bytes = encode_address(AddressFormat::Stellar, address).unwrap();
+            assert_eq!(bytes, address.as_bytes());
+            assert_eq!(
+                format_address(AddressFormat::Stellar, &bytes).unwrap(),
+                address
+            );
+        }
+
+        let corrupted = STELLAR_ACCOUNT.replacen('A', "B", 1);
+        assert!(encode_address(AddressFormat::Stellar, &corrupted).is_err());
+        assert!(encode_address(AddressFormat::Stellar, &STELLAR_ACCOUNT.to_lowercase()).is_err());
+        assert!(encode_address(AddressFormat::Stellar, "GA7QYNF7").is_err());
+    }
+}
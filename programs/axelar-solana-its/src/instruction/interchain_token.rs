@@ -4,6 +4,7 @@ use borsh::to_vec;
 use solana_program::instruction::AccountMeta;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
+use solana_program::{system_program, sysvar};
 
 use super::InterchainTokenServiceInstruction;
 
@@ -65,7 +66,7 @@ pub fn transfer_mintership(
         AccountMeta::new(payer, true),
         AccountMeta::new_readonly(sender, true),
         AccountMeta::new(sender_roles_pda, false),
-        AccountMeta::new_readonly(token_manager_pda, false),
+        AccountMeta::new(token_manager_pda, false),
         AccountMeta::new_readonly(to, false),
         AccountMeta::new(destination_roles_pda, false),
     ];
@@ -157,7 +158,7 @@ pub fn accept_mintership(
         AccountMeta::new(payer, true),
         AccountMeta::new_readonly(accepter, true),
         AccountMeta::new(accepter_roles_pda, false),
-        AccountMeta::new_readonly(token_manager_pda, false),
+        AccountMeta::new(token_manager_pda, false),
         AccountMeta::new(from, false),
         AccountMeta::new(origin_roles_pda, false),
         AccountMeta::new(proposal_pda, false),
@@ -171,3 +172,54 @@ pub fn accept_mintership(
         data,
     })
 }
+
+/// Creates an [`InterchainTokenServiceInstruction::SetTokenMetadataAuthority`]
+/// instruction.
+///
+/// # Errors
+///
+/// If serialization fails.
+pub fn set_metadata_authority(
+    payer: Pubkey,
+    minter: Pubkey,
+    operator: Pubkey,
+    token_id: [u8; 32],
+    mint: Pubkey,
+    new_authority: Pubkey,
+) -> Result<solana_program::instruction::Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+    let (minter_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &minter);
+    let (operator_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &operator);
+    let (metadata_account, _) = mpl_token_metadata::accounts::Metadata::find_pda(&mint);
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let data =
+        to_vec(&InterchainTokenServiceInstruction::SetTokenMetadataAuthority { new_authority })?;
+
+    let accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(minter, true),
+        AccountMeta::new_readonly(minter_roles_pda, false),
+        AccountMeta::new_readonly(operator, true),
+        AccountMeta::new_readonly(operator_roles_pda, false),
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new(token_manager_pda, false),
+        AccountMeta::new_readonly(mint, false),
+        AccountMeta::new(metadata_account, false),
+        AccountMeta::new_readonly(mpl_token_metadata::ID, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(solana_program::instruction::Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
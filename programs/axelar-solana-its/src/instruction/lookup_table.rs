@@ -0,0 +1,193 @@
+//! Address lookup table helpers for the ITS instructions.
+//!
+//! The ITS instructions that reach the gateway and the gas service easily
+//! exceed the account limit of legacy transactions once compute budget and ATA
+//! creation instructions are added. Clients can store the accounts returned
+//! here in an address lookup table and send the instructions as v0 messages.
+
+use solana_program::address_lookup_table::instruction as lookup_table_instruction;
+use solana_program::clock::Slot;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_program::{system_program, sysvar};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+/// Maximum number of addresses added by a single `ExtendLookupTable`
+/// instruction, chosen so that the instruction fits in a transaction together
+/// with a `CreateLookupTable` instruction.
+pub const MAX_ADDRESSES_PER_EXTEND: usize = 20;
+
+/// Returns the accounts shared by all the ITS instructions, regardless of the
+/// token or the user: programs, sysvars and the singleton PDAs of ITS, the
+/// gateway and the gas service.
+#[must_use]
+pub fn static_addresses() -> Vec<Pubkey> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (its_event_authority, _) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+    let (gateway_root_pda, _) = axelar_solana_gateway::get_gateway_root_config_pda();
+    let (gateway_event_authority, _) = axelar_solana_gateway::get_event_authority_pda();
+    let (call_contract_signing_pda, _) =
+        axelar_solana_gateway::get_call_contract_signing_pda(crate::ID);
+    let (gas_config_pda, _) = axelar_solana_gas_service::get_config_pda();
+    let (gas_paused_chains_pda, _) = axelar_solana_gas_service::get_paused_chains_pda();
+    let (gas_service_event_authority, _) = Pubkey::find_program_address(
+        &[event_cpi::EVENT_AUTHORITY_SEED],
+        &axelar_solana_gas_service::ID,
+    );
+
+    vec![
+        crate::ID,
+        its_root_pda,
+        its_event_authority,
+        axelar_solana_gateway::ID,
+        gateway_root_pda,
+        gateway_event_authority,
+        call_contract_signing_pda,
+        axelar_solana_gas_service::ID,
+        gas_config_pda,
+        gas_paused_chains_pda,
+        gas_service_event_authority,
+        system_program::ID,
+        spl_token_2022::ID,
+        spl_associated_token_account::ID,
+        mpl_token_metadata::ID,
+        sysvar::rent::ID,
+        sysvar::instructions::ID,
+    ]
+}
+
+/// Returns the accounts specific to the token with the given `token_id`: its
+/// token manager PDA, the mint, the token manager ATA and the token observer
+/// PDA.
+#[must_use]
+pub fn token_addresses(token_id: &[u8; 32], mint: Pubkey, token_program: Pubkey) -> Vec<Pubkey> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, token_id);
+    let token_manager_ata =
+        get_associated_token_address_with_program_id(&token_manager_pda, &mint, &token_program);
+
+    let (token_observer_pda, _) = crate::find_token_observer_pda(&token_manager_pda);
+
+    vec![
+        token_manager_pda,
+        mint,
+        token_manager_ata,
+        token_program,
+        token_observer_pda,
+    ]
+}
+
+/// Creates the instructions to create an address lookup table owned by
+/// `authority` and fill it with `addresses`, returning the lookup table
+/// address along with the instructions.
+///
+/// The first instruction creates the table and must be sent with the first
+/// `ExtendLookupTable` instruction or before it. The table can only be used in
+/// a transaction from the slot after its last extension.
+#[must_use]
+pub fn create_lookup_table(
+    authority: Pubkey,
+    payer: Pubkey,
+    recent_slot: Slot,
+    addresses: &[Pubkey],
+) -> (Pubkey, Vec<Instruction>) {
+    let (create_ix, lookup_table) =
+        lookup_table_instruction::create_lookup_table(authority, payer, recent_slot);
+
+    let mut instructions = vec![create_ix];
+    instructions.extend(extend_lookup_table(
+        lookup_table,
+        authority,
+        payer,
+        addresses,
+    ));
+
+    (lookup_table, instructions)
+}
+
+/// Creates the instructions to add `addresses` to an existing address lookup
+/// table, at most [`MAX_ADDRESSES_PER_EXTEND`] addresses per instruction.
+#[must_use]
+pub fn extend_lookup_table(
+    lookup_table: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    addresses: &[Pubkey],
+) -> Vec<Instruction> {
+    addresses
+        .chunks(MAX_ADDRESSES_PER_EXTEND)
+        .map(|chunk| {
+            lookup_table_instruction::extend_lookup_table(
+                lookup_table,
+                authority,
+                Some(payer),
+                chunk.to_vec(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+
+    #[test]
+    fn test_lookup_table_covers_interchain_transfer_accounts() {
+        let payer = Pubkey::new_unique();
+        let source_account = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let token_id = [1; 32];
+        let ix = crate::instruction::interchain_transfer(
+            payer,
+            payer,
+            source_account,
+            token_id,
+            "ethereum".to_owned(),
+            vec![1; 20],
+            100,
+            mint,
+            spl_token_2022::ID,
+            0,
+        )
+        .unwrap();
+
+        let mut addresses = static_addresses();
+        addresses.extend(token_addresses(&token_id, mint, spl_token_2022::ID));
+        let (outbound_nonce_pda, _) = crate::find_outbound_nonce_pda(&payer);
+
+        // Only the user specific accounts are left out of the lookup table
+        for meta in ix.accounts {
+            if meta.pubkey == payer
+                || meta.pubkey == source_account
+                || meta.pubkey == outbound_nonce_pda
+            {
+                continue;
+            }
+            assert!(
+                addresses.contains(&meta.pubkey),
+                "{} is missing from the lookup table",
+                meta.pubkey
+            );
+        }
+    }
+
+    #[test]
+    fn test_create_lookup_table_chunks_extensions() {
+        let authority = Pubkey::new_unique();
+        let addresses: Vec<Pubkey> = (0..=MAX_ADDRESSES_PER_EXTEND)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+
+        let (lookup_table, instructions) = create_lookup_table(authority, authority, 1, &addresses);
+
+        // One create instruction and two extensions
+        assert_eq!(instructions.len(), 3);
+        assert!(instructions
+            .iter()
+            .skip(1)
+            .all(|ix| ix.accounts.first().map(|meta| meta.pubkey) == Some(lookup_table)));
+    }
+}
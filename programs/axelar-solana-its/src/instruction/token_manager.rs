@@ -1,12 +1,15 @@
 //! Instructions for the token manager.
 
 use borsh::to_vec;
+use solana_program::bpf_loader_upgradeable;
 use solana_program::instruction::AccountMeta;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 use solana_program::system_program;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
 
 use super::InterchainTokenServiceInstruction;
+use crate::state::token_manager::DestinationOwnerPolicy;
 
 /// Creates an [`TokenManagerInstructions::SetFlowLimit`] wrapped in an
 /// [`InterchainTokenServiceInstruction::TokenManagerInstruction`].
@@ -47,6 +50,419 @@ pub fn set_flow_limit(
     })
 }
 
+/// Creates an [`InterchainTokenServiceInstruction::SetTokenObserver`]
+/// instruction.
+///
+/// # Errors
+///
+/// If serialization fails.
+pub fn set_token_observer(
+    payer: Pubkey,
+    operator: Pubkey,
+    token_id: [u8; 32],
+    observer: Option<Pubkey>,
+) -> Result<solana_program::instruction::Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+    let (operator_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &operator);
+    let (token_observer_pda, _) = crate::find_token_observer_pda(&token_manager_pda);
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let data = to_vec(&InterchainTokenServiceInstruction::SetTokenObserver { observer })?;
+
+    let accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(operator, true),
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new_readonly(token_manager_pda, false),
+        AccountMeta::new_readonly(operator_roles_pda, false),
+        AccountMeta::new(token_observer_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(solana_program::instruction::Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
+/// Creates an
+/// [`InterchainTokenServiceInstruction::SetTokenManagerDestinationOwnerPolicy`]
+/// instruction.
+///
+/// # Errors
+///
+/// If serialization fails.
+pub fn set_destination_owner_policy(
+    payer: Pubkey,
+    operator: Pubkey,
+    token_id: [u8; 32],
+    policy: DestinationOwnerPolicy,
+) -> Result<solana_program::instruction::Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+    let (operator_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &operator);
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let data = to_vec(
+        &InterchainTokenServiceInstruction::SetTokenManagerDestinationOwnerPolicy { policy },
+    )?;
+
+    let accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(operator, true),
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new(token_manager_pda, false),
+        AccountMeta::new_readonly(operator_roles_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(solana_program::instruction::Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::SetTokenManagerMaxSupply`]
+/// instruction.
+///
+/// # Errors
+///
+/// If serialization fails.
+pub fn set_max_supply(
+    payer: Pubkey,
+    operator: Pubkey,
+    token_id: [u8; 32],
+    mint: Pubkey,
+    max_supply: u64,
+) -> Result<solana_program::instruction::Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+    let (operator_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &operator);
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let data = to_vec(&InterchainTokenServiceInstruction::SetTokenManagerMaxSupply { max_supply })?;
+
+    let accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(operator, true),
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new(token_manager_pda, false),
+        AccountMeta::new_readonly(operator_roles_pda, false),
+        AccountMeta::new_readonly(mint, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(solana_program::instruction::Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
+/// Creates an
+/// [`InterchainTokenServiceInstruction::SetTokenManagerFeeCollector`]
+/// instruction.
+///
+/// # Errors
+///
+/// If serialization fails.
+pub fn set_fee_collector(
+    payer: Pubkey,
+    operator: Pubkey,
+    token_id: [u8; 32],
+    fee_collector: Option<Pubkey>,
+) -> Result<solana_program::instruction::Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+    let (operator_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &operator);
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let data =
+        to_vec(&InterchainTokenServiceInstruction::SetTokenManagerFeeCollector { fee_collector })?;
+
+    let accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(operator, true),
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new(token_manager_pda, false),
+        AccountMeta::new_readonly(operator_roles_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(solana_program::instruction::Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::HarvestWithheldFees`]
+/// instruction, harvesting the token manager associated token account and
+/// `additional_sources`.
+///
+/// # Errors
+///
+/// If serialization fails.
+pub fn harvest_withheld_fees(
+    operator: Pubkey,
+    token_id: [u8; 32],
+    mint: Pubkey,
+    destination: Pubkey,
+    token_program: Pubkey,
+    additional_sources: &[Pubkey],
+) -> Result<solana_program::instruction::Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+    let (operator_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &operator);
+    let token_manager_ata =
+        get_associated_token_address_with_program_id(&token_manager_pda, &mint, &token_program);
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let data = to_vec(&InterchainTokenServiceInstruction::HarvestWithheldFees)?;
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(operator, true),
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new_readonly(token_manager_pda, false),
+        AccountMeta::new_readonly(operator_roles_pda, false),
+        AccountMeta::new(mint, false),
+        AccountMeta::new(destination, false),
+        AccountMeta::new(token_manager_ata, false),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+    accounts.extend(
+        additional_sources
+            .iter()
+            .map(|source| AccountMeta::new(*source, false)),
+    );
+
+    Ok(solana_program::instruction::Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::VerifyTokenManager`]
+/// instruction. `mint` and `token_manager_ata` are the accounts recorded in
+/// the token manager.
+///
+/// # Errors
+///
+/// If serialization fails.
+pub fn verify_token_manager(
+    token_id: [u8; 32],
+    mint: Pubkey,
+    token_manager_ata: Pubkey,
+) -> Result<solana_program::instruction::Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let data = to_vec(&InterchainTokenServiceInstruction::VerifyTokenManager)?;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new_readonly(token_manager_pda, false),
+        AccountMeta::new_readonly(mint, false),
+        AccountMeta::new_readonly(token_manager_ata, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(solana_program::instruction::Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::GetFlowStatus`]
+/// instruction.
+///
+/// # Errors
+///
+/// If serialization fails.
+pub fn get_flow_status(
+    token_id: [u8; 32],
+) -> Result<solana_program::instruction::Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+
+    let data = to_vec(&InterchainTokenServiceInstruction::GetFlowStatus)?;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new_readonly(token_manager_pda, false),
+    ];
+
+    Ok(solana_program::instruction::Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::ResizeTokenManager`]
+/// instruction.
+///
+/// # Errors
+///
+/// If serialization fails.
+pub fn resize(
+    payer: Pubkey,
+    authority: Pubkey,
+    token_id: [u8; 32],
+    new_size: u64,
+) -> Result<solana_program::instruction::Instruction, ProgramError> {
+    let (program_data_address, _) =
+        Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID);
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+    let (authority_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::id(), &its_root_pda, &authority);
+
+    let data = to_vec(&InterchainTokenServiceInstruction::ResizeTokenManager { new_size })?;
+
+    let accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(authority, true),
+        AccountMeta::new_readonly(authority_roles_pda, false),
+        AccountMeta::new_readonly(program_data_address, false),
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new(token_manager_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+
+    Ok(solana_program::instruction::Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::MigrateFlowSlot`]
+/// instruction for the legacy flow slot of `epoch`.
+///
+/// # Errors
+///
+/// If serialization fails.
+pub fn migrate_flow_slot(
+    payer: Pubkey,
+    operator: Pubkey,
+    token_id: [u8; 32],
+    epoch: u64,
+) -> Result<solana_program::instruction::Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+    let (operator_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &operator);
+    let (flow_slot_pda, _) = crate::find_flow_slot_pda(&token_manager_pda, epoch);
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let data = to_vec(&InterchainTokenServiceInstruction::MigrateFlowSlot { epoch })?;
+
+    let accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(operator, true),
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new(token_manager_pda, false),
+        AccountMeta::new_readonly(operator_roles_pda, false),
+        AccountMeta::new(flow_slot_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    Ok(solana_program::instruction::Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::DecommissionTokenManager`]
+/// instruction, handing the authorities over the mint back to
+/// `mint_authority` and closing the roles PDAs of the `role_holders`.
+///
+/// # Errors
+///
+/// If serialization fails.
+pub fn decommission(
+    payer: Pubkey,
+    operator: Pubkey,
+    token_id: [u8; 32],
+    mint: Pubkey,
+    token_program: Pubkey,
+    mint_authority: Option<Pubkey>,
+    role_holders: &[Pubkey],
+) -> Result<solana_program::instruction::Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+    let (token_observer_pda, _) = crate::find_token_observer_pda(&token_manager_pda);
+    let (operator_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::id(), &its_root_pda, &operator);
+    let token_manager_ata =
+        get_associated_token_address_with_program_id(&token_manager_pda, &mint, &token_program);
+    let (event_authority, _bump) =
+        Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &crate::ID);
+
+    let data =
+        to_vec(&InterchainTokenServiceInstruction::DecommissionTokenManager { mint_authority })?;
+
+    let mut accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(operator, true),
+        AccountMeta::new_readonly(operator_roles_pda, false),
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new(token_manager_pda, false),
+        AccountMeta::new(mint, false),
+        AccountMeta::new(token_manager_ata, false),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new(token_observer_pda, false),
+        AccountMeta::new_readonly(event_authority, false),
+        AccountMeta::new_readonly(crate::ID, false),
+    ];
+
+    for holder in role_holders {
+        let (holder_roles_pda, _) =
+            role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, holder);
+        accounts.push(AccountMeta::new_readonly(*holder, false));
+        accounts.push(AccountMeta::new(holder_roles_pda, false));
+    }
+
+    Ok(solana_program::instruction::Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
 /// Creates a [`TokenManagerInstructions::AddFlowLimiter`] instruction.
 ///
 /// # Errors
@@ -71,7 +487,7 @@ pub fn add_flow_limiter(
         AccountMeta::new(payer, true),
         AccountMeta::new(adder, true),
         AccountMeta::new_readonly(adder_roles_pda, false),
-        AccountMeta::new_readonly(token_manager_pda, false),
+        AccountMeta::new(token_manager_pda, false),
         AccountMeta::new_readonly(flow_limiter, false),
         AccountMeta::new(flow_limiter_roles_pda, false),
     ];
@@ -147,7 +563,7 @@ pub fn transfer_operatorship(
         AccountMeta::new(payer, true),
         AccountMeta::new(sender, true),
         AccountMeta::new(sender_roles_pda, false),
-        AccountMeta::new_readonly(token_manager_pda, false),
+        AccountMeta::new(token_manager_pda, false),
         AccountMeta::new_readonly(to, false),
         AccountMeta::new(destination_roles_pda, false),
     ];
@@ -238,7 +654,7 @@ pub fn accept_operatorship(
         AccountMeta::new(payer, true),
         AccountMeta::new(accepter, true),
         AccountMeta::new(accepter_roles_pda, false),
-        AccountMeta::new_readonly(token_manager_pda, false),
+        AccountMeta::new(token_manager_pda, false),
         AccountMeta::new_readonly(from, false),
         AccountMeta::new(origin_roles_pda, false),
         AccountMeta::new(proposal_pda, false),
@@ -275,11 +691,58 @@ pub fn handover_mint_authority(
         AccountMeta::new_readonly(authority, true),
         AccountMeta::new(mint, false),
         AccountMeta::new_readonly(its_root_pda, false),
-        AccountMeta::new_readonly(token_manager_pda, false),
+        AccountMeta::new(token_manager_pda, false),
+        AccountMeta::new(minter_roles_pda, false),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+
+    let data = to_vec(&InterchainTokenServiceInstruction::HandoverMintAuthority { token_id })?;
+
+    Ok(solana_program::instruction::Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::HandoverMintAuthority`] instruction for a mint
+/// whose authority is a SPL multisig account.
+///
+/// The `payer` must be one of the `signers` and is granted the minter role on the
+/// [`TokenManager`](crate::state::token_manager::TokenManager).
+///
+/// # Errors
+///
+/// If serialization fails.
+pub fn handover_multisig_mint_authority(
+    payer: Pubkey,
+    multisig: Pubkey,
+    signers: &[Pubkey],
+    token_id: [u8; 32],
+    mint: Pubkey,
+    token_program: Pubkey,
+) -> Result<solana_program::instruction::Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+    let (minter_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::ID, &token_manager_pda, &payer);
+
+    let mut accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(multisig, false),
+        AccountMeta::new(mint, false),
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new(token_manager_pda, false),
         AccountMeta::new(minter_roles_pda, false),
         AccountMeta::new_readonly(token_program, false),
         AccountMeta::new_readonly(system_program::ID, false),
     ];
+    accounts.extend(
+        signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true)),
+    );
 
     let data = to_vec(&InterchainTokenServiceInstruction::HandoverMintAuthority { token_id })?;
 
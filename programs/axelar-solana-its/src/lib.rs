@@ -12,13 +12,20 @@ use solana_program::program::invoke;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 use state::interchain_transfer_execute::InterchainTransferExecute;
-use state::InterchainTokenService;
+use state::outbound_nonce::OutboundNonce;
+use state::token_observer::TokenObserver;
+use state::{InterchainTokenService, PauseFlags};
 
 mod accounts;
 mod entrypoint;
+pub mod error;
 pub mod events;
 pub mod executable;
+pub mod gas_quote;
+pub mod inbound_rent;
 pub mod instruction;
+pub mod link_params;
+pub mod observer;
 pub mod processor;
 pub mod state;
 
@@ -94,6 +101,27 @@ pub mod seed_prefixes {
 
     /// The seed prefix for deriving the interchain transfer execute signing PDA
     pub const INTERCHAIN_TRANSFER_EXECUTE_SEED: &[u8] = b"interchain-transfer-execute";
+
+    /// The seed prefix for deriving the per-sender outbound nonce PDA
+    pub const OUTBOUND_NONCE_SEED: &[u8] = b"outbound-nonce";
+
+    /// The seed prefix for deriving the token observer PDA
+    pub const TOKEN_OBSERVER_SEED: &[u8] = b"token-observer";
+}
+
+/// The wrapped SOL mint of the classic SPL Token program.
+///
+/// ITS always describes wSOL with [`native_mint::NAME`] and
+/// [`native_mint::SYMBOL`], whatever metadata the cluster has for it, so its
+/// canonical registration looks the same across environments.
+pub mod native_mint {
+    pub use spl_token::native_mint::{check_id, id, DECIMALS, ID};
+
+    /// The name ITS uses for wSOL
+    pub const NAME: &str = "Wrapped SOL";
+
+    /// The symbol ITS uses for wSOL
+    pub const SYMBOL: &str = "SOL";
 }
 
 bitflags! {
@@ -184,8 +212,11 @@ pub(crate) fn assert_valid_its_root_pda(
     Ok(())
 }
 
-pub(crate) fn assert_its_not_paused(its_config: &InterchainTokenService) -> ProgramResult {
-    if its_config.paused {
+pub(crate) fn assert_its_not_paused(
+    its_config: &InterchainTokenService,
+    path: PauseFlags,
+) -> ProgramResult {
+    if its_config.is_paused(path) {
         msg!("The Interchain Token Service is currently paused.");
         return Err(ProgramError::Immutable);
     }
@@ -467,6 +498,169 @@ pub(crate) fn initiate_interchain_execute_pda_if_empty<'a>(
     Ok(())
 }
 
+/// Tries to create the PDA for an [`OutboundNonce`] using the provided bump.
+///
+/// # Errors
+///
+/// If the bump is invalid.
+pub fn create_outbound_nonce_pda(sender: &Pubkey, bump: u8) -> Result<Pubkey, ProgramError> {
+    Ok(Pubkey::create_program_address(
+        &[seed_prefixes::OUTBOUND_NONCE_SEED, sender.as_ref(), &[bump]],
+        &crate::id(),
+    )?)
+}
+
+/// Derives the PDA for an [`OutboundNonce`].
+#[inline]
+#[must_use]
+pub fn find_outbound_nonce_pda(sender: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[seed_prefixes::OUTBOUND_NONCE_SEED, sender.as_ref()],
+        &crate::id(),
+    )
+}
+
+/// Tries to create the PDA for a legacy [`FlowSlot`] using the provided bump.
+///
+/// # Errors
+///
+/// If the bump is invalid.
+///
+/// [`FlowSlot`]: state::flow_limit::FlowSlot
+#[allow(clippy::little_endian_bytes)]
+pub fn create_flow_slot_pda(
+    token_manager_pda: &Pubkey,
+    epoch: u64,
+    bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    Ok(Pubkey::create_program_address(
+        &[
+            seed_prefixes::FLOW_SLOT_SEED,
+            token_manager_pda.as_ref(),
+            &epoch.to_le_bytes(),
+            &[bump],
+        ],
+        &crate::id(),
+    )?)
+}
+
+/// Derives the PDA for a legacy [`FlowSlot`].
+///
+/// [`FlowSlot`]: state::flow_limit::FlowSlot
+#[inline]
+#[must_use]
+#[allow(clippy::little_endian_bytes)]
+pub fn find_flow_slot_pda(token_manager_pda: &Pubkey, epoch: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seed_prefixes::FLOW_SLOT_SEED,
+            token_manager_pda.as_ref(),
+            &epoch.to_le_bytes(),
+        ],
+        &crate::id(),
+    )
+}
+
+/// Tries to create the PDA for a [`TokenObserver`] using the provided bump.
+///
+/// # Errors
+///
+/// If the bump is invalid.
+pub fn create_token_observer_pda(
+    token_manager_pda: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    Ok(Pubkey::create_program_address(
+        &[
+            seed_prefixes::TOKEN_OBSERVER_SEED,
+            token_manager_pda.as_ref(),
+            &[bump],
+        ],
+        &crate::id(),
+    )?)
+}
+
+/// Derives the PDA for a [`TokenObserver`].
+#[inline]
+#[must_use]
+pub fn find_token_observer_pda(token_manager_pda: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seed_prefixes::TOKEN_OBSERVER_SEED,
+            token_manager_pda.as_ref(),
+        ],
+        &crate::id(),
+    )
+}
+
+/// Validates the [`TokenObserver`] PDA of the given [`TokenManager`] and
+/// returns the registered observer, if any.
+pub(crate) fn load_token_observer(
+    token_observer_pda_account: &AccountInfo<'_>,
+    token_manager_pda: &Pubkey,
+) -> Result<Option<TokenObserver>, ProgramError> {
+    if !token_observer_pda_account.is_initialized_pda(&crate::id()) {
+        let (expected_pda, _) = find_token_observer_pda(token_manager_pda);
+        if expected_pda.ne(token_observer_pda_account.key) {
+            msg!("Invalid TokenObserver PDA provided");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        return Ok(None);
+    }
+
+    let token_observer = TokenObserver::load(token_observer_pda_account)?;
+    let expected_pda = create_token_observer_pda(token_manager_pda, token_observer.bump)?;
+    if expected_pda.ne(token_observer_pda_account.key) {
+        msg!("Invalid TokenObserver PDA provided");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(Some(token_observer))
+}
+
+/// Returns the nonce to assign to the next outbound message of `sender` and
+/// advances the counter, creating the [`OutboundNonce`] PDA on first use.
+pub(crate) fn next_outbound_nonce<'a>(
+    outbound_nonce_pda_account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_account: &AccountInfo<'a>,
+    sender: &Pubkey,
+) -> Result<u64, ProgramError> {
+    let mut outbound_nonce = if outbound_nonce_pda_account.is_initialized_pda(&crate::id()) {
+        let outbound_nonce = OutboundNonce::load(outbound_nonce_pda_account)?;
+        let expected_pda = create_outbound_nonce_pda(sender, outbound_nonce.bump)?;
+        if expected_pda.ne(outbound_nonce_pda_account.key) {
+            msg!("Invalid OutboundNonce PDA provided");
+            return Err(ProgramError::InvalidArgument);
+        }
+        outbound_nonce
+    } else {
+        let (expected_pda, bump) = find_outbound_nonce_pda(sender);
+        if expected_pda.ne(outbound_nonce_pda_account.key) {
+            msg!("Invalid OutboundNonce PDA provided");
+            return Err(ProgramError::InvalidArgument);
+        }
+        let outbound_nonce = OutboundNonce::new(bump);
+        outbound_nonce.init(
+            &crate::id(),
+            system_account,
+            payer,
+            outbound_nonce_pda_account,
+            &[seed_prefixes::OUTBOUND_NONCE_SEED, sender.as_ref(), &[bump]],
+        )?;
+        outbound_nonce
+    };
+
+    let nonce = outbound_nonce.next_nonce;
+    outbound_nonce.next_nonce = nonce
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    outbound_nonce.store(payer, outbound_nonce_pda_account, system_account)?;
+
+    Ok(nonce)
+}
+
 /// Asserts the given ATA is associated with the given token program, mint and wallet
 ///
 /// # Errors
@@ -0,0 +1,105 @@
+//! Encoding of the `link_params` of a `LinkToken` message towards Solana.
+//!
+//! The params configure the roles of the token manager deployed on Solana:
+//!
+//! | Length   | Content                        | Roles                                               |
+//! |----------|--------------------------------|-----------------------------------------------------|
+//! | 0 bytes  | nothing                        | none                                                |
+//! | 32 bytes | `operator`                     | `operator`: operator and flow limiter               |
+//! | 64 bytes | `operator` then `flow_limiter` | `operator`: operator, `flow_limiter`: flow limiter  |
+//!
+//! Any other length is rejected with [`ItsError::InvalidLinkParams`], failing
+//! the execution of the message instead of deploying a token manager without
+//! the roles the source intended.
+
+use solana_program::pubkey::{Pubkey, PUBKEY_BYTES};
+
+use crate::error::ItsError;
+
+/// Roles of the token manager deployed by an inbound `LinkToken` message.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LinkParams {
+    /// Receives the operator role, and the flow limiter role unless
+    /// `flow_limiter` is set.
+    pub operator: Option<Pubkey>,
+    /// Receives the flow limiter role. Can only be set along with `operator`.
+    pub flow_limiter: Option<Pubkey>,
+}
+
+impl LinkParams {
+    /// Decodes the `link_params` of an inbound `LinkToken` message.
+    ///
+    /// # Errors
+    ///
+    /// [`ItsError::InvalidLinkParams`] if `params` is not 0, 32 or 64 bytes
+    /// long.
+    pub fn decode(params: &[u8]) -> Result<Self, ItsError> {
+        let pubkey =
+            |bytes: &[u8]| Pubkey::try_from(bytes).map_err(|_err| ItsError::InvalidLinkParams);
+
+        match params.len() {
+            0 => Ok(Self::default()),
+            PUBKEY_BYTES => Ok(Self {
+                operator: Some(pubkey(params)?),
+                flow_limiter: None,
+            }),
+            len if len == 2 * PUBKEY_BYTES => {
+                let (operator, flow_limiter) = params.split_at(PUBKEY_BYTES);
+                Ok(Self {
+                    operator: Some(pubkey(operator)?),
+                    flow_limiter: Some(pubkey(flow_limiter)?),
+                })
+            }
+            _ => Err(ItsError::InvalidLinkParams),
+        }
+    }
+
+    /// Encodes the params, to be passed as the `link_params` of a
+    /// `LinkToken` message towards Solana. A `flow_limiter` without an
+    /// `operator` is dropped, as the encoding can't represent it.
+    #[must_use]
+    pub fn encode(&self) -> Vec<u8> {
+        match (self.operator, self.flow_limiter) {
+            (None, _) => Vec::new(),
+            (Some(operator), None) => operator.to_bytes().to_vec(),
+            (Some(operator), Some(flow_limiter)) => {
+                [operator.to_bytes(), flow_limiter.to_bytes()].concat()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_link_params_roundtrip() {
+        let operator = Pubkey::new_unique();
+        let flow_limiter = Pubkey::new_unique();
+
+        for params in [
+            LinkParams::default(),
+            LinkParams {
+                operator: Some(operator),
+                flow_limiter: None,
+            },
+            LinkParams {
+                operator: Some(operator),
+                flow_limiter: Some(flow_limiter),
+            },
+        ] {
+            assert_eq!(LinkParams::decode(&params.encode()), Ok(params));
+        }
+    }
+
+    #[test]
+    fn test_link_params_reject_other_lengths() {
+        for len in [1, 20, 31, 33, 63, 65, 96] {
+            assert_eq!(
+                LinkParams::decode(&vec![1; len]),
+                Err(ItsError::InvalidLinkParams)
+            );
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! Module that defines the notification sent to the observer program a token
+//! operator can register with
+//! [`SetTokenObserver`](crate::instruction::InterchainTokenServiceInstruction::SetTokenObserver).
+//!
+//! On every inbound and outbound interchain transfer of the token, ITS calls
+//! the observer program with a [`TransferNotification`] prefixed by
+//! [`TOKEN_OBSERVER_NOTIFY`]. The only account passed is the
+//! [`TokenObserver`] PDA of the token, as a signer, which observers must check
+//! with [`validate_transfer_notification`] to make sure the call comes from
+//! ITS. Returning an error from the observer aborts the transfer.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::msg;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::token_observer::TokenObserver;
+use crate::{find_its_root_pda, find_token_manager_pda, find_token_observer_pda, seed_prefixes};
+
+/// Token observer notification prefix
+pub const TOKEN_OBSERVER_NOTIFY: &[u8; 16] = b"axelar-its-obsv_";
+
+/// The direction of a transfer, from the point of view of Solana.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum TransferDirection {
+    /// Tokens received from another chain.
+    Inbound,
+    /// Tokens sent to another chain.
+    Outbound,
+}
+
+/// The payload the observer program of a token is called with.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct TransferNotification {
+    /// The token ID.
+    pub token_id: [u8; 32],
+
+    /// Whether the tokens are entering or leaving Solana.
+    pub direction: TransferDirection,
+
+    /// The amount of tokens given to the destination or taken from the
+    /// source, after token fees.
+    pub amount: u64,
+
+    /// The source chain of an inbound transfer or the destination chain of an
+    /// outbound one.
+    pub counterpart_chain: String,
+}
+
+/// Tries to parse a [`TransferNotification`] out of the instruction data of
+/// an observer program.
+///
+/// Returns `None` if the data does not start with [`TOKEN_OBSERVER_NOTIFY`].
+///
+/// # Errors
+///
+/// If the notification cannot be deserialized.
+#[must_use]
+pub fn parse_transfer_notification(
+    input: &[u8],
+) -> Option<Result<TransferNotification, ProgramError>> {
+    let payload = input.strip_prefix(TOKEN_OBSERVER_NOTIFY)?;
+
+    Some(
+        borsh::from_slice(payload)
+            .map_err(|borsh_error| ProgramError::BorshIoError(borsh_error.to_string())),
+    )
+}
+
+/// Checks that the notification was sent by ITS: the first account must be
+/// the [`TokenObserver`] PDA of the notified token, as a signer.
+///
+/// # Errors
+///
+/// If the accounts are missing, the PDA is not a signer or does not belong to
+/// the token.
+pub fn validate_transfer_notification(
+    accounts: &[AccountInfo<'_>],
+    notification: &TransferNotification,
+) -> ProgramResult {
+    let token_observer_pda = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    if !token_observer_pda.is_signer {
+        msg!("Token observer PDA must be a signer");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let (its_root_pda, _) = find_its_root_pda();
+    let (token_manager_pda, _) = find_token_manager_pda(&its_root_pda, &notification.token_id);
+    let (expected_pda, _) = find_token_observer_pda(&token_manager_pda);
+    if expected_pda != *token_observer_pda.key {
+        msg!("Invalid token observer PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
+/// Calls the observer registered for the token, if any.
+///
+/// `token_observer_program` must be the registered program, or `None` when
+/// no observer is registered.
+pub(crate) fn notify_token_observer<'a>(
+    token_observer_pda: &AccountInfo<'a>,
+    token_observer_program: Option<&AccountInfo<'a>>,
+    token_manager_pda: &Pubkey,
+    notification: &TransferNotification,
+) -> ProgramResult {
+    let Some(token_observer) = crate::load_token_observer(token_observer_pda, token_manager_pda)?
+    else {
+        if token_observer_program.is_some() {
+            msg!("No token observer is registered for the token");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        return Ok(());
+    };
+
+    let Some(observer_program) =
+        token_observer_program.filter(|program| *program.key == token_observer.program_id)
+    else {
+        msg!(
+            "The registered token observer {} must be provided",
+            token_observer.program_id
+        );
+        return Err(ProgramError::IncorrectProgramId);
+    };
+
+    invoke_observer(
+        token_observer_pda,
+        observer_program,
+        token_manager_pda,
+        &token_observer,
+        notification,
+    )
+}
+
+fn invoke_observer<'a>(
+    token_observer_pda: &AccountInfo<'a>,
+    observer_program: &AccountInfo<'a>,
+    token_manager_pda: &Pubkey,
+    token_observer: &TokenObserver,
+    notification: &TransferNotification,
+) -> ProgramResult {
+    let mut data = TOKEN_OBSERVER_NOTIFY.to_vec();
+    data.extend_from_slice(&borsh::to_vec(notification)?);
+
+    invoke_signed(
+        &Instruction {
+            program_id: token_observer.program_id,
+            accounts: vec![AccountMeta::new_readonly(*token_observer_pda.key, true)],
+            data,
+        },
+        &[token_observer_pda.clone(), observer_program.clone()],
+        &[&[
+            seed_prefixes::TOKEN_OBSERVER_SEED,
+            token_manager_pda.as_ref(),
+            &[token_observer.bump],
+        ]],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_transfer_notification() {
+        let notification = TransferNotification {
+            token_id: [7; 32],
+            direction: TransferDirection::Outbound,
+            amount: 42,
+            counterpart_chain: "ethereum".to_owned(),
+        };
+        let mut data = TOKEN_OBSERVER_NOTIFY.to_vec();
+        data.extend_from_slice(&borsh::to_vec(&notification).unwrap());
+
+        assert_eq!(
+            parse_transfer_notification(&data).unwrap().unwrap(),
+            notification
+        );
+        assert!(parse_transfer_notification(b"not a notification").is_none());
+        assert!(parse_transfer_notification(TOKEN_OBSERVER_NOTIFY)
+            .unwrap()
+            .is_err());
+    }
+}
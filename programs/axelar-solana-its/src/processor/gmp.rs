@@ -2,6 +2,8 @@
 use axelar_solana_encoding::types::messages::Message;
 use axelar_solana_gateway::executable::validate_with_gmp_metadata;
 use axelar_solana_gateway::state::message_payload::ImmutMessagePayload;
+use event_cpi::EventAccounts;
+use event_cpi_macros::{emit_cpi, event_cpi_accounts};
 use interchain_token_transfer_gmp::{GMPPayload, SendToHub};
 use itertools::{self, Itertools};
 use program_utils::pda::BorshPda;
@@ -18,10 +20,10 @@ use crate::instruction;
 use crate::processor::interchain_token;
 use crate::processor::interchain_transfer::process_inbound_transfer;
 use crate::processor::link_token;
-use crate::state::token_manager::TokenManager;
-use crate::state::InterchainTokenService;
+use crate::state::{InterchainTokenService, PauseFlags};
 use crate::{
-    assert_its_not_paused, assert_valid_its_root_pda, check_program_account, ITS_HUB_CHAIN_NAME,
+    assert_its_not_paused, assert_valid_its_root_pda, check_program_account, events,
+    ITS_HUB_CHAIN_NAME,
 };
 
 pub(crate) fn process_execute(accounts: ExecuteAccounts, message: Message) -> ProgramResult {
@@ -29,7 +31,6 @@ pub(crate) fn process_execute(accounts: ExecuteAccounts, message: Message) -> Pr
 
     let its_root_config = InterchainTokenService::load(accounts.its_root)?;
     assert_valid_its_root_pda(accounts.its_root, its_root_config.bump)?;
-    assert_its_not_paused(&its_root_config)?;
 
     if message.source_address != its_root_config.its_hub_address {
         msg!("Untrusted source address: {}", message.source_address);
@@ -53,13 +54,18 @@ pub(crate) fn process_execute(accounts: ExecuteAccounts, message: Message) -> Pr
 
     let payload =
         GMPPayload::decode(&inner.payload).map_err(|_err| ProgramError::InvalidInstructionData)?;
+    assert_its_not_paused(&its_root_config, pause_path(&payload, true))?;
 
     validate_its_accounts(&accounts.its_accounts(), &payload)?;
 
     match payload {
-        GMPPayload::InterchainTransfer(transfer) => {
-            process_inbound_transfer(accounts.try_into()?, message, &transfer, inner.source_chain)
-        }
+        GMPPayload::InterchainTransfer(transfer) => process_inbound_transfer(
+            accounts.try_into()?,
+            &its_root_config,
+            message,
+            &transfer,
+            inner.source_chain,
+        ),
         GMPPayload::DeployInterchainToken(deploy) => interchain_token::process_inbound_deploy(
             accounts.try_into()?,
             deploy.token_id.0,
@@ -67,6 +73,7 @@ pub(crate) fn process_execute(accounts: ExecuteAccounts, message: Message) -> Pr
             deploy.symbol,
             deploy.decimals,
             0,
+            false,
         ),
         GMPPayload::LinkToken(payload) => {
             link_token::process_inbound(accounts.try_into()?, &payload)
@@ -77,17 +84,107 @@ pub(crate) fn process_execute(accounts: ExecuteAccounts, message: Message) -> Pr
     }
 }
 
-pub(crate) fn process_call_contract(
-    accounts: &CallContractAccounts,
+/// The group of instructions a GMP payload received from or sent to the hub
+/// belongs to, when it comes to pausing.
+const fn pause_path(payload: &GMPPayload, inbound: bool) -> PauseFlags {
+    match *payload {
+        GMPPayload::InterchainTransfer(_) if inbound => PauseFlags::INBOUND_TRANSFERS,
+        GMPPayload::InterchainTransfer(_) => PauseFlags::OUTBOUND_TRANSFERS,
+        GMPPayload::DeployInterchainToken(_) => PauseFlags::DEPLOYMENTS,
+        GMPPayload::LinkToken(_) | GMPPayload::RegisterTokenMetadata(_) => {
+            PauseFlags::LINK_OPERATIONS
+        }
+        GMPPayload::SendToHub(_) | GMPPayload::ReceiveFromHub(_) => PauseFlags::GLOBAL,
+    }
+}
+
+/// How the gas for an outbound call is paid to the gas service.
+pub(crate) enum GasPayment<'a> {
+    /// Lamports paid by the transaction payer.
+    Native(u64),
+    /// Tokens paid by `authority` out of `token_account` into the gas service
+    /// `gas_token_account`.
+    Token {
+        amount: u64,
+        authority: &'a AccountInfo<'a>,
+        token_account: &'a AccountInfo<'a>,
+        gas_token_account: &'a AccountInfo<'a>,
+        mint: &'a AccountInfo<'a>,
+        token_program: &'a AccountInfo<'a>,
+    },
+}
+
+impl<'a> GasPayment<'a> {
+    /// Pays the gas of an outbound call, whose payload hashes to
+    /// `payload_hash`, to the gas service.
+    fn pay(
+        self,
+        accounts: &CallContractAccounts<'a>,
+        payload_hash: [u8; 32],
+        its_hub_address: String,
+    ) -> ProgramResult {
+        match self {
+            Self::Native(0) => Ok(()),
+            Self::Native(gas_value) => pay_gas(
+                accounts.payer,
+                accounts.gas_service_root,
+                accounts.gas_service_paused_chains,
+                accounts.gas_service_event_authority,
+                accounts.system_program,
+                payload_hash,
+                its_hub_address,
+                gas_value,
+            ),
+            Self::Token {
+                amount,
+                authority,
+                token_account,
+                gas_token_account,
+                mint,
+                token_program,
+            } => {
+                let gas_payment_ix =
+                    axelar_solana_gas_service::instructions::pay_spl_gas_instruction(
+                        authority.key,
+                        token_account.key,
+                        mint.key,
+                        token_program.key,
+                        crate::ITS_HUB_CHAIN_NAME.to_owned(),
+                        its_hub_address,
+                        payload_hash,
+                        *authority.key,
+                        amount,
+                    )?;
+
+                invoke(
+                    &gas_payment_ix,
+                    &[
+                        authority.clone(),
+                        token_account.clone(),
+                        accounts.gas_service_root.clone(),
+                        accounts.gas_service_paused_chains.clone(),
+                        gas_token_account.clone(),
+                        mint.clone(),
+                        token_program.clone(),
+                        accounts.gas_service_event_authority.clone(),
+                    ],
+                )
+            }
+        }
+    }
+}
+
+pub(crate) fn process_call_contract<'a>(
+    accounts: &CallContractAccounts<'a>,
     payload: &GMPPayload,
     destination_chain: String,
-    gas_value: u64,
+    gas: GasPayment<'a>,
     signing_pda_bump: u8,
     wrapped: bool,
 ) -> ProgramResult {
     let its_root_config = InterchainTokenService::load(accounts.its_root)?;
     assert_valid_its_root_pda(accounts.its_root, its_root_config.bump)?;
-    assert_its_not_paused(&its_root_config)?;
+    assert_its_not_paused(&its_root_config, pause_path(payload, false))?;
 
     check_program_account(*accounts.program.key)?;
 
@@ -111,7 +208,7 @@ pub(crate) fn process_call_contract(
             selector: SendToHub::MESSAGE_TYPE_ID
                 .try_into()
                 .map_err(|_err| ProgramError::ArithmeticOverflow)?,
-            destination_chain,
+            destination_chain: destination_chain.clone(),
             payload: payload.encode().into(),
         })
         .encode()
@@ -119,6 +216,10 @@ pub(crate) fn process_call_contract(
         payload.encode()
     };
 
+    // Reject oversized payloads here, where the user can see why, rather than
+    // further down the Axelar pipeline.
+    its_root_config.check_payload_size(&destination_chain, payload.len())?;
+
     let payload_hash = solana_program::keccak::hashv(&[&payload]).to_bytes();
     let call_contract_ix = axelar_solana_gateway::instructions::call_contract(
         axelar_solana_gateway::id(),
@@ -130,17 +231,11 @@ pub(crate) fn process_call_contract(
         payload,
     )?;
 
-    if gas_value > 0 {
-        pay_gas(
-            accounts.payer,
-            accounts.gas_service_root,
-            accounts.gas_service_event_authority,
-            accounts.system_program,
-            payload_hash,
-            its_root_config.its_hub_address,
-            gas_value,
-        )?;
-    }
+    gas.pay(
+        accounts,
+        payload_hash,
+        its_root_config.its_hub_address.clone(),
+    )?;
 
     invoke_signed(
         &call_contract_ix,
@@ -156,12 +251,23 @@ pub(crate) fn process_call_contract(
         ]],
     )?;
 
+    // Lets off-chain systems pair the ITS event of this instruction with the
+    // message the hub receives, without re-encoding the payload.
+    let event_accounts_iter = &mut accounts.event_accounts().into_iter();
+    event_cpi_accounts!(event_accounts_iter);
+    emit_cpi!(events::HubMessageSent {
+        destination_chain,
+        hub_address: its_root_config.its_hub_address,
+        payload_hash,
+    });
+
     Ok(())
 }
 
 fn pay_gas<'a>(
     payer: &'a AccountInfo<'a>,
     gas_service_config: &'a AccountInfo<'a>,
+    gas_service_paused_chains: &'a AccountInfo<'a>,
     gas_service_event_authority: &'a AccountInfo<'a>,
     system_program: &'a AccountInfo<'a>,
     payload_hash: [u8; 32],
@@ -182,6 +288,7 @@ fn pay_gas<'a>(
         &[
             payer.clone(),
             gas_service_config.clone(),
+            gas_service_paused_chains.clone(),
             system_program.clone(),
             gas_service_event_authority.clone(),
         ],
@@ -189,12 +296,13 @@ fn pay_gas<'a>(
 }
 
 fn validate_its_accounts(accounts: &[AccountInfo<'_>], payload: &GMPPayload) -> ProgramResult {
-    const TOKEN_MANAGER_PDA_INDEX: usize = 2;
     const TOKEN_MINT_INDEX: usize = 3;
     const TOKEN_PROGRAM_INDEX: usize = 5;
+    const TOKEN_OBSERVER_PROGRAM_INDEX: usize = 13;
 
     // In this case we cannot derive the mint account, so we just use what we got
-    // and check later against the mint within the `TokenManager` PDA.
+    // and check later against the mint within the `TokenManager` PDA, when the
+    // transfer is processed.
     let maybe_mint = if let GMPPayload::InterchainTransfer(_) = payload {
         accounts.get(TOKEN_MINT_INDEX).map(|account| *account.key)
     } else {
@@ -206,8 +314,19 @@ fn validate_its_accounts(accounts: &[AccountInfo<'_>], payload: &GMPPayload) ->
         .map(|account| *account.key)
         .ok_or(ProgramError::InvalidAccountData)?;
 
+    // The observer program is checked against the `TokenObserver` PDA when
+    // the transfer is processed.
+    let maybe_token_observer = if let GMPPayload::InterchainTransfer(_) = payload {
+        accounts
+            .get(TOKEN_OBSERVER_PROGRAM_INDEX)
+            .map(|account| *account.key)
+            .filter(|key| *key != crate::ID)
+    } else {
+        None
+    };
+
     let derived_its_accounts =
-        instruction::derive_its_accounts(payload, token_program, maybe_mint)?;
+        instruction::derive_its_accounts(payload, token_program, maybe_mint, maybe_token_observer)?;
 
     for element in accounts.iter().zip_longest(derived_its_accounts.iter()) {
         match element {
@@ -222,18 +341,5 @@ fn validate_its_accounts(accounts: &[AccountInfo<'_>], payload: &GMPPayload) ->
         }
     }
 
-    // Now we validate the mint account passed for `InterchainTransfer`
-    if let Some(mint) = maybe_mint {
-        let token_manager_pda = accounts
-            .get(TOKEN_MANAGER_PDA_INDEX)
-            .ok_or(ProgramError::InvalidAccountData)?;
-
-        let token_manager = TokenManager::load(token_manager_pda)?;
-
-        if token_manager.token_address.as_ref() != mint.as_ref() {
-            return Err(ProgramError::InvalidAccountData);
-        }
-    }
-
     Ok(())
 }
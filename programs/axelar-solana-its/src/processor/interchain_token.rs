@@ -4,11 +4,11 @@ use axelar_solana_gateway::num_traits::Zero;
 use event_cpi_macros::{emit_cpi, event_cpi_accounts};
 use interchain_token_transfer_gmp::{DeployInterchainToken, GMPPayload};
 use mpl_token_metadata::accounts::Metadata;
-use mpl_token_metadata::instructions::CreateV1CpiBuilder;
+use mpl_token_metadata::instructions::{CreateV1CpiBuilder, UpdateV1CpiBuilder};
 use mpl_token_metadata::types::TokenStandard;
 use program_utils::pda::init_pda_raw;
 use program_utils::pda::BorshPda;
-use program_utils::validate_system_account_key;
+use program_utils::{validate_mpl_token_metadata_key, validate_system_account_key};
 use role_management::processor::{
     ensure_roles, ensure_signer_roles, RoleAddAccounts, RoleRemoveAccounts,
     RoleTransferWithProposalAccounts,
@@ -20,14 +20,15 @@ use solana_program::program::{invoke, invoke_signed, set_return_data};
 use solana_program::program_error::ProgramError;
 use solana_program::program_pack::Pack as _;
 use solana_program::pubkey::Pubkey;
-use spl_token_2022::extension::metadata_pointer::MetadataPointer;
-use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use spl_token_2022::extension::metadata_pointer::{self, MetadataPointer};
+use spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions};
 use spl_token_2022::instruction::initialize_mint;
 use spl_token_2022::state::Mint;
+use spl_token_metadata_interface::instruction::initialize as initialize_token_metadata;
 use spl_token_metadata_interface::state::TokenMetadata;
 
 use super::gmp;
-use super::token_manager::DeployTokenManagerInternal;
+use super::token_manager::{record_role_holder, DeployTokenManagerInternal};
 use crate::accounts::CommonDeployRemoteInterchainTokenAccounts;
 use crate::accounts::DeployRemoteCanonicalInterchainTokenAccounts;
 use crate::accounts::DeployRemoteInterchainTokenAccounts;
@@ -35,9 +36,11 @@ use crate::accounts::DeployRemoteInterchainTokenWithMinterAccounts;
 use crate::accounts::{
     CallContractAccounts, DeployInterchainTokenAccounts, DeployTokenManagerAccounts,
 };
+use crate::error::ItsError;
+use crate::gas_quote::validate_gas_quote;
 use crate::state::deploy_approval::DeployApproval;
-use crate::state::token_manager::{self, TokenManager};
-use crate::state::InterchainTokenService;
+use crate::state::token_manager::{self, top_up_rent, TokenManager};
+use crate::state::{InterchainTokenService, PauseFlags};
 use crate::{assert_its_not_paused, assert_valid_deploy_approval_pda, events, find_its_root_pda};
 use crate::{assert_valid_its_root_pda, assert_valid_token_manager_pda, seed_prefixes, Roles};
 use event_cpi::EventAccounts;
@@ -49,6 +52,7 @@ pub(crate) fn process_deploy(
     symbol: String,
     decimals: u8,
     initial_supply: u64,
+    on_mint_metadata: bool,
 ) -> ProgramResult {
     let event_accounts_iter = &mut accounts.event_accounts().into_iter();
     event_cpi_accounts!(event_accounts_iter);
@@ -67,13 +71,26 @@ pub(crate) fn process_deploy(
         return Err(ProgramError::InvalidArgument);
     }
 
+    if on_mint_metadata && *accounts.token_program.key != spl_token_2022::ID {
+        msg!("On-mint metadata requires the Token-2022 program");
+        return Err(ProgramError::InvalidArgument);
+    }
+
     emit_cpi!(events::InterchainTokenIdClaimed {
         token_id,
         deployer: *accounts.deployer.key,
         salt: deploy_salt,
     });
 
-    process_inbound_deploy(accounts, token_id, name, symbol, decimals, initial_supply)?;
+    process_inbound_deploy(
+        accounts,
+        token_id,
+        name,
+        symbol,
+        decimals,
+        initial_supply,
+        on_mint_metadata,
+    )?;
 
     set_return_data(&token_id);
 
@@ -87,6 +104,7 @@ pub(crate) fn process_inbound_deploy(
     symbol: String,
     decimals: u8,
     initial_supply: u64,
+    on_mint_metadata: bool,
 ) -> ProgramResult {
     msg!("Instruction: InboundDeploy");
 
@@ -95,7 +113,7 @@ pub(crate) fn process_inbound_deploy(
 
     let its_config = InterchainTokenService::load(accounts.its_root)?;
     assert_valid_its_root_pda(accounts.its_root, its_config.bump)?;
-    assert_its_not_paused(&its_config)?;
+    assert_its_not_paused(&its_config, PauseFlags::DEPLOYMENTS)?;
 
     let (interchain_token_pda, interchain_token_pda_bump) =
         crate::find_interchain_token_pda(accounts.its_root.key, &token_id);
@@ -118,6 +136,7 @@ pub(crate) fn process_inbound_deploy(
         interchain_token_pda_bump,
         token_manager_pda_bump,
         initial_supply,
+        on_mint_metadata,
     )?;
 
     let mut truncated_name = name;
@@ -125,6 +144,16 @@ pub(crate) fn process_inbound_deploy(
     truncated_name.truncate(mpl_token_metadata::MAX_NAME_LENGTH);
     truncated_symbol.truncate(mpl_token_metadata::MAX_SYMBOL_LENGTH);
 
+    if on_mint_metadata {
+        setup_on_mint_metadata(
+            &accounts,
+            &token_id,
+            truncated_name.clone(),
+            truncated_symbol.clone(),
+            token_manager_pda_bump,
+        )?;
+    }
+
     setup_metadata(
         &accounts,
         &token_id,
@@ -168,6 +197,8 @@ pub(crate) fn process_inbound_deploy(
 }
 
 /// Retrieves token metadata with fallback logic:
+/// 0. The wSOL native mint always uses [`crate::native_mint::NAME`] and
+///    [`crate::native_mint::SYMBOL`]
 /// 1. First, try to get metadata from Token 2022 extensions
 ///     - If the metadata pointer points to the mint itself, we try to deserialize it using
 ///     `TokenMetadata`
@@ -177,6 +208,13 @@ pub(crate) fn get_token_metadata(
     mint: &AccountInfo,
     maybe_metadata_account: Option<&AccountInfo>,
 ) -> Result<(String, String), ProgramError> {
+    if crate::native_mint::check_id(mint.key) {
+        return Ok((
+            crate::native_mint::NAME.to_owned(),
+            crate::native_mint::SYMBOL.to_owned(),
+        ));
+    }
+
     let mint_data = mint.try_borrow_data()?;
 
     if let Ok(mint_with_extensions) = StateWithExtensions::<Mint>::unpack(&mint_data) {
@@ -265,6 +303,7 @@ pub(crate) fn process_outbound_deploy(
         minter: maybe_destination_minter.unwrap_or_default().into(),
     });
 
+    let gas_quote = accounts.gas_quote;
     let gmp_accounts = CallContractAccounts::try_from(accounts)?;
     let its_root_config = InterchainTokenService::load(gmp_accounts.its_root)?;
     assert_valid_its_root_pda(gmp_accounts.its_root, its_root_config.bump)?;
@@ -273,11 +312,28 @@ pub(crate) fn process_outbound_deploy(
         return Err(ProgramError::InvalidInstructionData);
     }
 
+    if let Some(gas_quote) = gas_quote {
+        let (signer, quote) = validate_gas_quote(
+            gas_quote.sysvar_instructions,
+            gas_quote.signer_roles,
+            gmp_accounts.its_root,
+            &destination_chain,
+            gas_value,
+        )?;
+        emit_cpi!(events::GasQuoteUsed {
+            signer,
+            destination_chain: quote.destination_chain,
+            quoted_gas_value: quote.gas_value,
+            gas_value,
+            expires_at: quote.expires_at,
+        });
+    }
+
     gmp::process_call_contract(
         &gmp_accounts,
         &message,
         destination_chain.clone(),
-        gas_value,
+        gmp::GasPayment::Native(gas_value),
         signing_pda_bump,
         true,
     )?;
@@ -394,6 +450,7 @@ pub(crate) fn process_mint<'a>(accounts: &'a [AccountInfo<'a>], amount: u64) ->
     }
 
     spl_token_2022::check_spl_token_program_account(token_program_account.key)?;
+    token_manager.assert_token_program(mint_account, token_program_account.key)?;
 
     if mint_account.owner != token_program_account.key {
         return Err(ProgramError::IncorrectProgramId);
@@ -407,6 +464,8 @@ pub(crate) fn process_mint<'a>(accounts: &'a [AccountInfo<'a>], amount: u64) ->
         Roles::MINTER,
     )?;
 
+    token_manager.ensure_within_max_supply(mint_account, amount)?;
+
     invoke_signed(
         &spl_token_2022::instruction::mint_to(
             token_program_account.key,
@@ -439,13 +498,20 @@ fn setup_mint(
     interchain_token_pda_bump: u8,
     token_manager_pda_bump: u8,
     initial_supply: u64,
+    on_mint_metadata: bool,
 ) -> ProgramResult {
+    let mint_len = if on_mint_metadata {
+        ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::MetadataPointer])?
+    } else {
+        Mint::LEN
+    };
+
     init_pda_raw(
         accounts.payer,
         accounts.mint,
         accounts.token_program.key,
         accounts.system_program,
-        spl_token_2022::state::Mint::LEN
+        mint_len
             .try_into()
             .map_err(|_err| ProgramError::ArithmeticOverflow)?,
         &[
@@ -456,9 +522,22 @@ fn setup_mint(
         ],
     )?;
 
+    // The metadata pointer has to be initialized before the mint
+    if on_mint_metadata {
+        invoke(
+            &metadata_pointer::instruction::initialize(
+                accounts.token_program.key,
+                accounts.mint.key,
+                Some(*accounts.token_manager.key),
+                Some(*accounts.mint.key),
+            )?,
+            &[accounts.mint.clone(), accounts.token_program.clone()],
+        )?;
+    }
+
     invoke(
         &initialize_mint(
-            &spl_token_2022::id(),
+            accounts.token_program.key,
             accounts.mint.key,
             accounts.token_manager.key,
             Some(accounts.token_manager.key),
@@ -511,6 +590,65 @@ fn setup_mint(
     Ok(())
 }
 
+/// Stores the name and symbol of the token on the mint itself, through the
+/// `TokenMetadata` extension its `MetadataPointer` points to. The token
+/// program grows the mint to fit the metadata, the payer tops up its rent
+/// beforehand.
+fn setup_on_mint_metadata(
+    accounts: &DeployInterchainTokenAccounts<'_>,
+    token_id: &[u8],
+    name: String,
+    symbol: String,
+    token_manager_pda_bump: u8,
+) -> ProgramResult {
+    let token_metadata = TokenMetadata {
+        update_authority: Some(*accounts.token_manager.key).try_into()?,
+        mint: *accounts.mint.key,
+        name,
+        symbol,
+        ..TokenMetadata::default()
+    };
+    let mint_len = accounts
+        .mint
+        .data_len()
+        .checked_add(token_metadata.tlv_size_of()?)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    top_up_rent(
+        accounts.payer,
+        accounts.mint,
+        accounts.system_program,
+        mint_len,
+    )?;
+
+    invoke_signed(
+        &initialize_token_metadata(
+            accounts.token_program.key,
+            accounts.mint.key,
+            accounts.token_manager.key,
+            accounts.mint.key,
+            accounts.token_manager.key,
+            token_metadata.name,
+            token_metadata.symbol,
+            token_metadata.uri,
+        ),
+        &[
+            accounts.mint.clone(),
+            accounts.token_manager.clone(),
+            accounts.token_program.clone(),
+        ],
+        &[&[
+            seed_prefixes::TOKEN_MANAGER_SEED,
+            accounts.its_root.key.as_ref(),
+            token_id,
+            &[token_manager_pda_bump],
+        ]],
+    )?;
+
+    Ok(())
+}
+
+/// Creates the Metaplex metadata of the token, or verifies the metadata left
+/// by an earlier deployment attempt matches instead of failing on it.
 fn setup_metadata(
     accounts: &DeployInterchainTokenAccounts<'_>,
     token_id: &[u8],
@@ -519,6 +657,12 @@ fn setup_metadata(
     uri: String,
     token_manager_pda_bump: u8,
 ) -> ProgramResult {
+    if *accounts.mpl_token_metadata.owner == mpl_token_metadata::ID
+        && !accounts.mpl_token_metadata.data_is_empty()
+    {
+        return verify_existing_metadata(accounts, &name, &symbol);
+    }
+
     CreateV1CpiBuilder::new(accounts.mpl_token_metadata_program)
         .metadata(accounts.mpl_token_metadata)
         .token_standard(TokenStandard::Fungible)
@@ -543,6 +687,27 @@ fn setup_metadata(
     Ok(())
 }
 
+fn verify_existing_metadata(
+    accounts: &DeployInterchainTokenAccounts<'_>,
+    name: &str,
+    symbol: &str,
+) -> ProgramResult {
+    let metadata = Metadata::from_bytes(&accounts.mpl_token_metadata.try_borrow_data()?)?;
+
+    if metadata.mint != *accounts.mint.key
+        || metadata.update_authority != *accounts.token_manager.key
+        || metadata.name.trim_end_matches('\0') != name
+        || metadata.symbol.trim_end_matches('\0') != symbol
+    {
+        msg!("Existing token metadata doesn't match the deployed token");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    msg!("Token metadata already exists, skipping its creation");
+
+    Ok(())
+}
+
 pub(crate) fn approve_deploy_remote_interchain_token(
     accounts: &[AccountInfo<'_>],
     deployer: Pubkey,
@@ -742,12 +907,16 @@ pub(crate) fn process_transfer_mintership<'a>(accounts: &'a [AccountInfo<'a>]) -
         target_roles_account: sender_roles_account,
     };
 
+    let new_holder = destination_roles_account.data_is_empty();
     role_management::processor::add(
         &crate::id(),
         role_add_accounts,
         Roles::MINTER,
         Roles::MINTER,
     )?;
+    if new_holder {
+        record_role_holder(payer_account, token_manager_account, system_program_account)?;
+    }
 
     role_management::processor::remove(
         &crate::id(),
@@ -835,5 +1004,106 @@ pub(crate) fn process_accept_mintership<'a>(accounts: &'a [AccountInfo<'a>]) ->
         proposal_account,
     };
 
-    role_management::processor::accept(&crate::id(), role_management_accounts, Roles::MINTER)
+    let new_holder = destination_roles_account.data_is_empty();
+    role_management::processor::accept(&crate::id(), role_management_accounts, Roles::MINTER)?;
+    if new_holder {
+        record_role_holder(payer_account, token_manager_account, system_program_account)?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn process_set_metadata_authority<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    new_authority: Pubkey,
+) -> ProgramResult {
+    msg!("Instruction: SetTokenMetadataAuthority");
+
+    let accounts_iter = &mut accounts.iter();
+    let payer = next_account_info(accounts_iter)?;
+    let minter = next_account_info(accounts_iter)?;
+    let minter_roles_pda = next_account_info(accounts_iter)?;
+    let operator = next_account_info(accounts_iter)?;
+    let operator_roles_pda = next_account_info(accounts_iter)?;
+    let its_root_pda = next_account_info(accounts_iter)?;
+    let token_manager_pda = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let metadata_account = next_account_info(accounts_iter)?;
+    let mpl_token_metadata_program = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+    let sysvar_instructions = next_account_info(accounts_iter)?;
+
+    event_cpi_accounts!(accounts_iter);
+
+    validate_system_account_key(system_account.key)?;
+    validate_mpl_token_metadata_key(mpl_token_metadata_program.key)?;
+
+    let its_config = InterchainTokenService::load(its_root_pda)?;
+    assert_valid_its_root_pda(its_root_pda, its_config.bump)?;
+
+    ensure_signer_roles(
+        &crate::id(),
+        token_manager_pda,
+        minter,
+        minter_roles_pda,
+        Roles::MINTER,
+    )?;
+    ensure_signer_roles(
+        &crate::id(),
+        token_manager_pda,
+        operator,
+        operator_roles_pda,
+        Roles::OPERATOR,
+    )?;
+
+    let mut token_manager = TokenManager::load(token_manager_pda)?;
+    assert_valid_token_manager_pda(
+        token_manager_pda,
+        its_root_pda.key,
+        &token_manager.token_id,
+        token_manager.bump,
+    )?;
+
+    if token_manager.metadata_authority_delegated {
+        return Err(ItsError::TokenMetadataAuthorityAlreadyDelegated.into());
+    }
+
+    if token_manager.token_address != *mint.key {
+        msg!("Mint and token ID don't match");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if Metadata::find_pda(mint.key).0 != *metadata_account.key {
+        msg!("Invalid metadata account for the mint");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Metaplex only checks that the token manager is the current update
+    // authority, the update authority can change even on immutable metadata
+    UpdateV1CpiBuilder::new(mpl_token_metadata_program)
+        .authority(token_manager_pda)
+        .mint(mint)
+        .metadata(metadata_account)
+        .payer(payer)
+        .system_program(system_account)
+        .sysvar_instructions(sysvar_instructions)
+        .new_update_authority(new_authority)
+        .invoke_signed(&[&[
+            seed_prefixes::TOKEN_MANAGER_SEED,
+            its_root_pda.key.as_ref(),
+            &token_manager.token_id,
+            &[token_manager.bump],
+        ]])?;
+
+    token_manager.metadata_authority_delegated = true;
+    token_manager.store(payer, token_manager_pda, system_account)?;
+
+    emit_cpi!(events::TokenMetadataAuthoritySet {
+        token_id: token_manager.token_id,
+        minter: *minter.key,
+        operator: *operator.key,
+        new_authority,
+    });
+
+    Ok(())
 }
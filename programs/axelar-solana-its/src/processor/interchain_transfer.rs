@@ -6,35 +6,46 @@ use axelar_solana_gateway::state::incoming_message::command_id;
 use event_cpi_macros::{emit_cpi, event_cpi_accounts};
 use interchain_token_transfer_gmp::{GMPPayload, InterchainTransfer};
 use program_utils::pda::BorshPda;
-use solana_program::account_info::AccountInfo;
+use solana_program::account_info::{next_account_info, AccountInfo};
 use solana_program::clock::Clock;
+use solana_program::compute_units::sol_remaining_compute_units;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::instruction::AccountMeta;
 use solana_program::instruction::Instruction;
 use solana_program::msg;
-use solana_program::program::invoke_signed;
+use solana_program::program::{invoke_signed, set_return_data};
 use solana_program::program_error::ProgramError;
+use solana_program::program_option::COption;
 use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
 use solana_program::sysvar::Sysvar;
+use spl_token_2022::extension::default_account_state::DefaultAccountState;
 use spl_token_2022::extension::transfer_fee::TransferFeeConfig;
 use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
-use spl_token_2022::state::Mint;
+use spl_token_2022::state::{Account as TokenAccount, AccountState, Mint};
 
 use crate::accounts::{
-    AxelarInterchainTokenExecutableAccounts, FlowTrackingAccounts, GiveTokenAccounts,
-    TakeTokenAccounts,
+    is_valid_token_account, AxelarInterchainTokenExecutableAccounts, FlowTrackingAccounts,
+    GiveTokenAccounts, TakeTokenAccounts,
 };
-use crate::executable::{AxelarInterchainTokenExecuteInfo, AXELAR_INTERCHAIN_TOKEN_EXECUTE};
+use crate::error::ItsError;
+use crate::executable::{
+    AxelarInterchainTokenExecuteInfo, AXELAR_INTERCHAIN_TOKEN_EXECUTE, PROGRAM_ACCOUNTS_START_INDEX,
+};
+use crate::inbound_rent::{self, AccountSnapshot};
+use crate::observer::{notify_token_observer, TransferDirection, TransferNotification};
 use crate::processor::token_manager as token_manager_processor;
-use crate::state::flow_limit::FlowDirection;
-use crate::state::token_manager::{self, TokenManager};
+use crate::state::flow_limit::{FlowDirection, FlowState};
+use crate::state::token_manager::{self, DestinationOwnerPolicy, TokenManager};
+use crate::state::InterchainTokenService;
 use crate::{
-    assert_valid_interchain_transfer_execute_pda, assert_valid_token_manager_pda, events,
-    initiate_interchain_execute_pda_if_empty, seed_prefixes,
+    assert_valid_interchain_transfer_execute_pda, assert_valid_its_root_pda,
+    assert_valid_token_manager_pda, events, initiate_interchain_execute_pda_if_empty,
+    next_outbound_nonce, seed_prefixes,
 };
 use event_cpi::EventAccounts;
 
-use super::gmp;
+use super::gmp::{self, GasPayment};
 
 /// Processes an incoming [`InterchainTransfer`] GMP message.
 ///
@@ -43,6 +54,10 @@ use super::gmp;
 /// For incoming `InterchainTransfer` messages, the behaviour of the
 /// [`NativeInterchainToken`], [`MintBurn`] and [`MintBurnFrom`]
 /// [`TokenManager`]s are the same: the token is minted to the destination token account.
+/// If the [`TokenManager`] is no longer the mint authority of the token, the
+/// transfer is rejected with [`ItsError::TokenManagerAuthorityMismatch`] and a
+/// [`events::TokenManagerAuthorityMismatch`] event is emitted, so that it can
+/// be told apart from other failures of the mint.
 ///
 /// As for [`LockUnlock`] and [`LockUnlockFee`] [`TokenManager`]s, they are
 /// typically used in the home chain of the token, thus, if we're getting an
@@ -75,39 +90,113 @@ use super::gmp;
 ///    - **SPL Token ATAs**: Can have ownership transferred, creating a security risk
 ///    
 ///    If ownership verification fails, the transaction is rejected to prevent funds being sent to
-///    accounts controlled by unexpected parties./
+///    accounts controlled by unexpected parties, unless the [`DestinationOwnerPolicy`] of the
+///    token is [`DestinationOwnerPolicy::Permissive`]. In that case the tokens are transferred to
+///    the existing ATA and a [`events::DestinationOwnerMismatch`] event is emitted.
+///
+/// When the destination associated token account has to be created, its rent
+/// is paid by the payer of the transfer. The debit is recorded in the
+/// [`events::InterchainTransferReceivedV2`] event so that relayers can get
+/// reimbursed.
+///
+/// # Frozen Destination Accounts
+///
+/// Token accounts of mints with the [`DefaultAccountState`] extension set to
+/// frozen start out frozen and can't receive tokens. When the [`TokenManager`]
+/// is the freeze authority of such a mint, the destination token account is
+/// thawed before the tokens are given. Mints frozen by default whose freeze
+/// authority is another account must have their destination accounts thawed
+/// beforehand, otherwise the transfer fails.
+///
+/// # Compute Units
+///
+/// This is the hot path of the relayer, so the accounts are expected to have
+/// already been checked against [`crate::instruction::derive_its_accounts`] by
+/// the caller: the [`TokenManager`] PDA and the associated token accounts are
+/// not derived again here. The [`TokenManager`] is loaded once and updated in
+/// place when tracking the flow, and the [`InterchainTokenService`] config is
+/// the one loaded by the caller.
 ///
 /// # Errors
 ///
 /// An error occurred when processing the message. The reason can be derived
 /// from the logs.
+#[allow(clippy::too_many_lines)]
 pub(crate) fn process_inbound_transfer(
     accounts: GiveTokenAccounts,
+    its_root_config: &InterchainTokenService,
     message: Message,
     payload: &InterchainTransfer,
     source_chain: String,
 ) -> ProgramResult {
-    let token_manager = TokenManager::load(accounts.token_manager)?;
-    assert_valid_token_manager_pda(
-        accounts.token_manager,
-        accounts.its_root.key,
-        &token_manager.token_id,
-        token_manager.bump,
-    )?;
+    let mut token_manager = TokenManager::load(accounts.token_manager)?;
+    if token_manager.token_address != *accounts.mint.key {
+        msg!("Mint and token ID don't match");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    token_manager.assert_token_program(accounts.mint, accounts.token_program.key)?;
+
+    let message_command_id = command_id(&message.cc_id.chain, &message.cc_id.id);
+    let event_accounts_iter = &mut accounts.event_accounts().into_iter();
+    event_cpi_accounts!(event_accounts_iter);
+
+    if let Some(mint_authority) = mint_authority_mismatch(&accounts, &token_manager)? {
+        msg!(
+            "TokenManager is no longer the mint authority of {}",
+            accounts.mint.key
+        );
+        emit_cpi!(events::TokenManagerAuthorityMismatch {
+            command_id: message_command_id,
+            token_id: token_manager.token_id,
+            mint: *accounts.mint.key,
+            token_manager: *accounts.token_manager.key,
+            mint_authority,
+        });
+        return Err(ItsError::TokenManagerAuthorityMismatch.into());
+    }
 
     let Ok(converted_amount) = payload.amount.try_into() else {
         msg!("Failed to convert amount");
         return Err(ProgramError::InvalidInstructionData);
     };
 
+    let payer_lamports = accounts.payer.lamports();
+    let destination_owner_mismatch = prepare_destination_token_account(&accounts, &token_manager)?;
+    let ata_rent = payer_lamports
+        .checked_sub(accounts.payer.lamports())
+        .filter(|lamports| *lamports > 0)
+        .map(|lamports| events::AtaRentDebit {
+            payer: *accounts.payer.key,
+            lamports,
+        });
+    thaw_frozen_destination_token_account(&accounts, &token_manager)?;
+
     // Check if source is already a valid token account for this mint
-    let transferred_amount = give_token(&accounts, &token_manager, converted_amount)?;
+    let transferred_amount = give_token(&accounts, &mut token_manager, converted_amount)?;
+
+    notify_token_observer(
+        accounts.token_observer,
+        accounts.token_observer_program,
+        accounts.token_manager.key,
+        &TransferNotification {
+            token_id: token_manager.token_id,
+            direction: TransferDirection::Inbound,
+            amount: transferred_amount,
+            counterpart_chain: source_chain.clone(),
+        },
+    )?;
 
-    let event_accounts_iter = &mut accounts.event_accounts().into_iter();
-    event_cpi_accounts!(event_accounts_iter);
+    if let Some(token_account_owner) = destination_owner_mismatch {
+        emit_cpi!(events::DestinationOwnerMismatch {
+            token_id: token_manager.token_id,
+            destination_address: *accounts.destination.key,
+            destination_token_account: *accounts.destination_ata.key,
+            token_account_owner,
+        });
+    }
 
-    emit_cpi!(events::InterchainTransferReceived {
-        command_id: command_id(&message.cc_id.chain, &message.cc_id.id),
+    emit_cpi!(events::InterchainTransferReceivedV2 {
+        command_id: message_command_id,
         token_id: token_manager.token_id,
         source_chain,
         source_address: payload.source_address.to_vec(),
@@ -119,6 +208,7 @@ pub(crate) fn process_inbound_transfer(
         } else {
             solana_program::keccak::hash(payload.data.as_ref()).0
         },
+        ata_rent,
     });
 
     if !payload.data.is_empty() {
@@ -128,10 +218,11 @@ pub(crate) fn process_inbound_transfer(
 
         let destination_payload = AxelarMessagePayload::decode(payload.data.as_ref())?;
         let destination_accounts = destination_payload.account_meta();
+        let destination_accounts_len = destination_accounts.len();
         let axelar_executable_accounts =
             AxelarInterchainTokenExecutableAccounts::try_from(accounts)?;
 
-        if destination_accounts.len()
+        if destination_accounts_len
             > axelar_executable_accounts
                 .destination_program_accounts
                 .len()
@@ -144,30 +235,35 @@ pub(crate) fn process_inbound_transfer(
             program_account.key,
         )?;
 
-        let account_infos = [
-            &[
-                axelar_executable_accounts
-                    .interchain_transfer_execute
-                    .clone(),
-                axelar_executable_accounts.gateway_message_payload.clone(),
-                axelar_executable_accounts.token_program.clone(),
-                axelar_executable_accounts.mint.clone(),
-                axelar_executable_accounts.destination_program_ata.clone(),
-            ],
-            axelar_executable_accounts.destination_program_accounts,
-        ]
-        .concat();
+        let mut account_infos =
+            Vec::with_capacity(PROGRAM_ACCOUNTS_START_INDEX + destination_accounts_len);
+        account_infos.extend_from_slice(&[
+            axelar_executable_accounts
+                .interchain_transfer_execute
+                .clone(),
+            axelar_executable_accounts.gateway_message_payload.clone(),
+            axelar_executable_accounts.token_program.clone(),
+            axelar_executable_accounts.mint.clone(),
+            axelar_executable_accounts.destination_program_ata.clone(),
+        ]);
+        account_infos.extend_from_slice(axelar_executable_accounts.destination_program_accounts);
 
         let its_execute_instruction = build_axelar_interchain_token_execute(
-            message,
+            message_command_id,
+            message.cc_id.chain,
             &axelar_executable_accounts,
             *program_account.key,
-            destination_payload.account_meta(),
+            destination_accounts,
             payload,
             transferred_amount,
         )?;
 
-        invoke_signed(
+        its_root_config
+            .check_destination_execution(destination_accounts_len, sol_remaining_compute_units())?;
+
+        // Most failures of the destination program abort the transaction
+        // in the runtime, the ones handed back are attributed to it here.
+        if let Err(error) = invoke_signed(
             &its_execute_instruction,
             &account_infos,
             &[&[
@@ -175,7 +271,20 @@ pub(crate) fn process_inbound_transfer(
                 program_account.key.as_ref(),
                 &[axelar_transfer_execute_bump],
             ]],
-        )?;
+        ) {
+            msg!(
+                "Destination program {} failed: {}",
+                program_account.key,
+                error
+            );
+            emit_cpi!(events::DestinationExecutionFailed {
+                command_id: message_command_id,
+                token_id: token_manager.token_id,
+                destination_program: *program_account.key,
+                error_code: error.into(),
+            });
+            return Err(ItsError::DestinationExecutionFailed.into());
+        }
 
         initiate_interchain_execute_pda_if_empty(
             axelar_executable_accounts.interchain_transfer_execute,
@@ -189,21 +298,278 @@ pub(crate) fn process_inbound_transfer(
     Ok(())
 }
 
+/// Estimates the rent the execution of an inbound transfer would cost.
+///
+/// Logs the accounts the execution of the inbound transfer carried by
+/// `payload` would create, and sets them as the return data.
+pub(crate) fn process_estimate_inbound_rent(
+    accounts: &[AccountInfo<'_>],
+    payload: &[u8],
+) -> ProgramResult {
+    msg!("Instruction: EstimateInboundRent");
+
+    let accounts_iter = &mut accounts.iter();
+    let mint = next_account_info(accounts_iter)?;
+    let destination = next_account_info(accounts_iter)?;
+    let destination_ata = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+    let transfer_execute = next_account_info(accounts_iter)?;
+
+    let transfer = inbound_rent::decode_inbound_transfer(payload)?;
+    let destination_address: &[u8] = transfer.destination_address.as_ref();
+    if destination.key.to_bytes().as_slice() != destination_address {
+        msg!("Destination address doesn't match the payload");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    spl_token_2022::check_spl_token_program_account(token_program.key)?;
+    if mint.owner != token_program.key {
+        msg!("Mint isn't owned by the token program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let expected_destination_ata =
+        spl_associated_token_account::get_associated_token_address_with_program_id(
+            destination.key,
+            mint.key,
+            token_program.key,
+        );
+    if expected_destination_ata != *destination_ata.key {
+        msg!("Invalid destination associated token account provided");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let transfer_execute = if transfer.data.is_empty() {
+        None
+    } else {
+        let (expected_transfer_execute, _) =
+            crate::find_interchain_transfer_execute_pda(destination.key);
+        if expected_transfer_execute != *transfer_execute.key {
+            msg!("Invalid InterchainTransferExecute PDA provided");
+            return Err(ProgramError::InvalidArgument);
+        }
+        Some(transfer_execute)
+    };
+
+    let mint_data = mint.try_borrow_data()?;
+    let destination_data = destination.try_borrow_data()?;
+    let destination_ata_data = destination_ata.try_borrow_data()?;
+    let transfer_execute_data = transfer_execute
+        .map(AccountInfo::try_borrow_data)
+        .transpose()?;
+
+    let estimate = inbound_rent::estimate_inbound_transfer_rent(
+        &Rent::get()?,
+        token_program.key,
+        &AccountSnapshot {
+            address: *mint.key,
+            owner: *mint.owner,
+            data: &mint_data,
+        },
+        &AccountSnapshot {
+            address: *destination.key,
+            owner: *destination.owner,
+            data: &destination_data,
+        },
+        &AccountSnapshot {
+            address: *destination_ata.key,
+            owner: *destination_ata.owner,
+            data: &destination_ata_data,
+        },
+        transfer_execute
+            .zip(transfer_execute_data.as_ref())
+            .map(|(account, data)| AccountSnapshot {
+                address: *account.key,
+                owner: *account.owner,
+                data,
+            })
+            .as_ref(),
+    )?;
+
+    for account in &estimate.accounts {
+        msg!(
+            "Creates {} with {} bytes for {} lamports",
+            account.address,
+            account.space,
+            account.lamports
+        );
+    }
+    msg!("Total rent: {} lamports", estimate.total_lamports());
+    set_return_data(&borsh::to_vec(&estimate)?);
+
+    Ok(())
+}
+
+/// Checks that the [`TokenManager`] of a token ITS mints is still the mint
+/// authority of the token.
+///
+/// Returns the current mint authority of the token, `None` when it was
+/// revoked, if the [`TokenManager`] lost it outside of ITS. Minting would
+/// otherwise fail in the token program with an opaque error.
+#[allow(clippy::option_option)]
+fn mint_authority_mismatch(
+    accounts: &GiveTokenAccounts,
+    token_manager: &TokenManager,
+) -> Result<Option<Option<Pubkey>>, ProgramError> {
+    use token_manager::Type::{MintBurn, MintBurnFrom, NativeInterchainToken};
+
+    if !matches!(
+        token_manager.ty,
+        NativeInterchainToken | MintBurn | MintBurnFrom
+    ) {
+        return Ok(None);
+    }
+
+    let mint_authority = {
+        let data = accounts.mint.try_borrow_data()?;
+        StateWithExtensions::<Mint>::unpack(&data)?
+            .base
+            .mint_authority
+    };
+
+    Ok(match mint_authority {
+        COption::Some(key) if key == *accounts.token_manager.key => None,
+        COption::Some(key) => Some(Some(key)),
+        COption::None => Some(None),
+    })
+}
+
+/// Makes sure the destination token account of an inbound transfer exists
+/// and is owned by the destination address, creating its associated token
+/// account if needed.
+///
+/// Returns the owner of the destination associated token account when it
+/// differs from the destination address and the token allows it through
+/// [`DestinationOwnerPolicy::Permissive`].
+fn prepare_destination_token_account(
+    accounts: &GiveTokenAccounts,
+    token_manager: &TokenManager,
+) -> Result<Option<Pubkey>, ProgramError> {
+    if is_valid_token_account(
+        accounts.destination,
+        accounts.token_program.key,
+        accounts.mint.key,
+    ) {
+        return Ok(None);
+    }
+
+    // The destination associated token account was checked against its
+    // derivation along with the other accounts of the message.
+    if is_valid_token_account(
+        accounts.destination_ata,
+        accounts.token_program.key,
+        accounts.mint.key,
+    ) {
+        let token_account_owner = {
+            let data = accounts.destination_ata.try_borrow_data()?;
+            StateWithExtensions::<TokenAccount>::unpack(&data)?
+                .base
+                .owner
+        };
+
+        if token_account_owner != *accounts.destination.key {
+            match token_manager.destination_owner_policy {
+                DestinationOwnerPolicy::Strict => {
+                    msg!(
+                        "Destination token account is owned by {}, not by the destination address",
+                        token_account_owner
+                    );
+                    return Err(ProgramError::IllegalOwner);
+                }
+                DestinationOwnerPolicy::Permissive => {
+                    msg!(
+                        "Destination token account is owned by {}, transferring anyway",
+                        token_account_owner
+                    );
+                    return Ok(Some(token_account_owner));
+                }
+            }
+        }
+    }
+
+    crate::create_associated_token_account_idempotent(
+        accounts.payer,
+        accounts.mint,
+        accounts.destination_ata,
+        accounts.destination,
+        accounts.system_program,
+        accounts.token_program,
+    )?;
+
+    Ok(None)
+}
+
+/// Thaws the destination token account of an inbound transfer if it was
+/// frozen by the [`DefaultAccountState`] extension of a mint whose freeze
+/// authority is the token manager.
+fn thaw_frozen_destination_token_account(
+    accounts: &GiveTokenAccounts,
+    token_manager: &TokenManager,
+) -> ProgramResult {
+    let is_frozen = {
+        let data = accounts.destination_ata.try_borrow_data()?;
+        StateWithExtensions::<TokenAccount>::unpack(&data)?
+            .base
+            .is_frozen()
+    };
+
+    if !is_frozen {
+        return Ok(());
+    }
+
+    let thawable_by_token_manager = {
+        let data = accounts.mint.try_borrow_data()?;
+        let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+        let frozen_by_default = mint
+            .get_extension::<DefaultAccountState>()
+            .is_ok_and(|extension| extension.state == AccountState::Frozen as u8);
+
+        frozen_by_default
+            && mint.base.freeze_authority == COption::Some(*accounts.token_manager.key)
+    };
+
+    if !thawable_by_token_manager {
+        msg!("Destination token account is frozen");
+        return Ok(());
+    }
+
+    invoke_signed(
+        &spl_token_2022::instruction::thaw_account(
+            accounts.token_program.key,
+            accounts.destination_ata.key,
+            accounts.mint.key,
+            accounts.token_manager.key,
+            &[],
+        )?,
+        &[
+            accounts.destination_ata.clone(),
+            accounts.mint.clone(),
+            accounts.token_manager.clone(),
+        ],
+        &[&[
+            seed_prefixes::TOKEN_MANAGER_SEED,
+            accounts.its_root.key.as_ref(),
+            &token_manager.token_id,
+            &[token_manager.bump],
+        ]],
+    )
+}
+
 fn build_axelar_interchain_token_execute(
-    message: Message,
+    command_id: [u8; 32],
+    source_chain: String,
     axelar_its_executable_accounts: &AxelarInterchainTokenExecutableAccounts,
     program_id: Pubkey,
     mut program_accounts: Vec<AccountMeta>,
     payload: &InterchainTransfer,
     amount: u64,
 ) -> Result<Instruction, ProgramError> {
-    let command_id = command_id(&message.cc_id.chain, &message.cc_id.id);
     let source_address = payload.source_address.to_vec();
-    let source_chain = message.cc_id.chain;
     let token = axelar_its_executable_accounts.mint.key.to_bytes();
     let token_id = payload.token_id.0;
 
-    let mut accounts = vec![
+    // Laid out as documented by the index constants of the executable module
+    let protocol_accounts: [AccountMeta; PROGRAM_ACCOUNTS_START_INDEX] = [
         AccountMeta::new(
             *axelar_its_executable_accounts
                 .interchain_transfer_execute
@@ -221,6 +587,8 @@ fn build_axelar_interchain_token_execute(
             false,
         ),
     ];
+    let mut accounts = Vec::with_capacity(PROGRAM_ACCOUNTS_START_INDEX + program_accounts.len());
+    accounts.extend_from_slice(&protocol_accounts);
     accounts.append(&mut program_accounts);
 
     let executable_payload = AxelarInterchainTokenExecuteInfo {
@@ -248,6 +616,9 @@ fn build_axelar_interchain_token_execute(
 /// This function handles transfers where the source address should be the sender
 /// (user account). It validates that the sender is a user account and not a
 /// program or PDA to ensure proper source attribution in the transfer events.
+///
+/// With `allow_partial` set, the transfer is capped to the remaining outbound
+/// flow capacity of the token, see [`process_outbound_transfer`].
 pub(crate) fn process_user_interchain_transfer(
     accounts: TakeTokenAccounts,
     token_id: [u8; 32],
@@ -257,16 +628,9 @@ pub(crate) fn process_user_interchain_transfer(
     gas_value: u64,
     signing_pda_bump: u8,
     data: Option<Vec<u8>>,
+    allow_partial: bool,
 ) -> ProgramResult {
-    // Check that the sender is a user account, not a program or PDA
-    // User accounts should be owned by the System Program
-    if accounts.authority.owner != &solana_program::system_program::ID {
-        msg!(
-            "Sender is not owned by System Program, owner: {}",
-            accounts.authority.owner
-        );
-        return Err(ProgramError::InvalidAccountData);
-    }
+    ensure_user_account(accounts.authority)?;
 
     let source_address = *accounts.authority.key;
     process_outbound_transfer(
@@ -275,13 +639,74 @@ pub(crate) fn process_user_interchain_transfer(
         destination_chain,
         destination_address,
         amount,
-        gas_value,
+        GasPayment::Native(gas_value),
         signing_pda_bump,
         data,
         source_address,
+        allow_partial,
     )
 }
 
+/// Processes a user interchain transfer where the gas is paid with
+/// `gas_amount` of the transferred tokens instead of lamports.
+///
+/// The tokens are paid to the gas service from the same source account, so
+/// only `amount - gas_amount` tokens are bridged.
+pub(crate) fn process_user_interchain_transfer_with_token_gas<'a>(
+    accounts: TakeTokenAccounts<'a>,
+    gas_token_account: &'a AccountInfo<'a>,
+    token_id: [u8; 32],
+    destination_chain: String,
+    destination_address: Vec<u8>,
+    amount: u64,
+    gas_amount: u64,
+    signing_pda_bump: u8,
+) -> ProgramResult {
+    ensure_user_account(accounts.authority)?;
+
+    if gas_amount == 0 {
+        msg!("Gas amount cannot be zero");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let source_address = *accounts.authority.key;
+    let gas = GasPayment::Token {
+        amount: gas_amount,
+        authority: accounts.authority,
+        token_account: accounts.source_ata,
+        gas_token_account,
+        mint: accounts.mint,
+        token_program: accounts.token_program,
+    };
+
+    process_outbound_transfer(
+        accounts,
+        token_id,
+        destination_chain,
+        destination_address,
+        amount,
+        gas,
+        signing_pda_bump,
+        None,
+        source_address,
+        false,
+    )
+}
+
+/// Checks that the sender is a user account, not a program or PDA. User
+/// accounts should be owned by the System Program.
+fn ensure_user_account(authority: &AccountInfo<'_>) -> ProgramResult {
+    if authority.owner != &solana_program::system_program::ID {
+        msg!(
+            "Sender is not owned by System Program, owner: {}",
+            authority.owner
+        );
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
 /// Processes an interchain transfer initiated via Cross-Program Invocation (CPI) by a PDA.
 pub(crate) fn process_cpi_interchain_transfer(
     accounts: TakeTokenAccounts,
@@ -325,27 +750,48 @@ pub(crate) fn process_cpi_interchain_transfer(
         destination_chain,
         destination_address,
         amount,
-        gas_value,
+        GasPayment::Native(gas_value),
         signing_pda_bump,
         data,
         source_id,
+        false,
     )
 }
 
-pub(crate) fn process_outbound_transfer(
-    accounts: TakeTokenAccounts,
+/// Takes the tokens from the source account and sends the
+/// [`InterchainTransfer`] GMP message.
+///
+/// When `allow_partial` is set and `amount` exceeds the remaining outbound
+/// flow capacity of the token, only the remaining capacity is transferred. The
+/// unfilled remainder stays in the source account, is reported through an
+/// [`events::InterchainTransferPartiallyFilled`] event and is set as
+/// borsh encoded `u64` return data.
+#[allow(clippy::too_many_lines)]
+pub(crate) fn process_outbound_transfer<'a>(
+    accounts: TakeTokenAccounts<'a>,
     token_id: [u8; 32],
     destination_chain: String,
     destination_address: Vec<u8>,
     mut amount: u64,
-    gas_value: u64,
+    gas: GasPayment<'a>,
     signing_pda_bump: u8,
     data: Option<Vec<u8>>,
     source_address: Pubkey,
+    allow_partial: bool,
 ) -> ProgramResult {
     msg!("Instruction: OutboundTransfer");
 
-    let token_manager = TokenManager::load(accounts.token_manager)?;
+    let its_root_config = InterchainTokenService::load(accounts.its_root)?;
+    assert_valid_its_root_pda(accounts.its_root, its_root_config.bump)?;
+    if destination_chain == its_root_config.chain_name {
+        msg!(
+            "Cannot transfer to {}, the chain ITS is running on. Use a regular SPL token transfer instead",
+            destination_chain
+        );
+        return Err(ItsError::DestinationIsLocalChain.into());
+    }
+
+    let mut token_manager = TokenManager::load(accounts.token_manager)?;
 
     assert_valid_token_manager_pda(
         accounts.token_manager,
@@ -369,11 +815,57 @@ pub(crate) fn process_outbound_transfer(
         msg!("Mint and token ID don't match");
         return Err(ProgramError::InvalidAccountData);
     }
+    token_manager.assert_token_program(accounts.mint, accounts.token_program.key)?;
+
+    if let GasPayment::Token {
+        amount: gas_amount, ..
+    } = gas
+    {
+        amount = amount
+            .checked_sub(gas_amount)
+            .filter(|bridged| *bridged > 0)
+            .ok_or_else(|| {
+                msg!("Gas amount must be lower than the transferred amount");
+                ProgramError::InvalidInstructionData
+            })?;
+    }
+
+    let requested_amount = amount;
+    if allow_partial {
+        if let Some(remaining) = remaining_outbound_flow(&token_manager)? {
+            amount = amount.min(remaining);
+        }
+
+        if amount == 0 {
+            msg!("No outbound flow capacity left for the token");
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
+    let filled_amount = amount;
 
-    let amount_minus_fees = take_token(&accounts, &token_manager, amount)?;
+    let amount_minus_fees = take_token(&accounts, &mut token_manager, amount)?;
     amount = amount_minus_fees;
 
-    let transfer_event = events::InterchainTransfer {
+    notify_token_observer(
+        accounts.token_observer,
+        accounts.token_observer_program,
+        accounts.token_manager.key,
+        &TransferNotification {
+            token_id,
+            direction: TransferDirection::Outbound,
+            amount,
+            counterpart_chain: destination_chain.clone(),
+        },
+    )?;
+
+    let nonce = next_outbound_nonce(
+        accounts.outbound_nonce,
+        accounts.payer,
+        accounts.system_program,
+        &source_address,
+    )?;
+
+    let transfer_event = events::InterchainTransferV2 {
         token_id,
         source_address,
         source_token_account: *accounts.source_ata.key,
@@ -389,9 +881,20 @@ pub(crate) fn process_outbound_transfer(
         } else {
             [0; 32]
         },
+        nonce,
     };
+    let unfilled_amount = requested_amount.saturating_sub(filled_amount);
     let event_accounts_iter = &mut accounts.event_accounts().into_iter();
     event_cpi_accounts!(event_accounts_iter);
+    if unfilled_amount > 0 {
+        emit_cpi!(events::InterchainTransferPartiallyFilled {
+            token_id,
+            source_address,
+            requested_amount,
+            filled_amount,
+            unfilled_amount,
+        });
+    }
     emit_cpi!(transfer_event);
 
     let payload = GMPPayload::InterchainTransfer(InterchainTransfer {
@@ -409,15 +912,21 @@ pub(crate) fn process_outbound_transfer(
         &accounts.try_into()?,
         &payload,
         transfer_event.destination_chain,
-        gas_value,
+        gas,
         signing_pda_bump,
         true,
-    )
+    )?;
+
+    if allow_partial {
+        set_return_data(&borsh::to_vec(&unfilled_amount)?);
+    }
+
+    Ok(())
 }
 
 pub(crate) fn take_token(
     accounts: &TakeTokenAccounts,
-    token_manager: &TokenManager,
+    token_manager: &mut TokenManager,
     amount: u64,
 ) -> Result<u64, ProgramError> {
     token_manager_processor::validate_token_manager_type(
@@ -429,29 +938,41 @@ pub(crate) fn take_token(
     handle_take_token_transfer(accounts, token_manager, amount)
 }
 
+/// Gives `amount` tokens to the destination of an inbound transfer. The mint
+/// authority of the [`TokenManager`] was checked beforehand through
+/// [`mint_authority_mismatch`].
 fn give_token(
     accounts: &GiveTokenAccounts,
-    token_manager: &TokenManager,
+    token_manager: &mut TokenManager,
     amount: u64,
 ) -> Result<u64, ProgramError> {
-    token_manager_processor::validate_token_manager_type(
-        token_manager.ty,
-        accounts.mint,
-        accounts.token_manager,
-    )?;
-
     let transferred_amount = handle_give_token_transfer(accounts, token_manager, amount)?;
 
     Ok(transferred_amount)
 }
 
+/// Returns the outbound flow capacity left for the token in the current flow
+/// epoch, or `None` if the token has no flow limit.
+fn remaining_outbound_flow(token_manager: &TokenManager) -> Result<Option<u64>, ProgramError> {
+    let mut flow_slot = token_manager.flow_slot.clone();
+
+    // A stale slot gets reset when the flow is tracked
+    let current_epoch = crate::state::flow_limit::current_flow_epoch()?;
+    if flow_slot.epoch != current_epoch {
+        flow_slot = FlowState::new(flow_slot.flow_limit, current_epoch);
+    }
+
+    Ok(flow_slot.remaining_flow(FlowDirection::Out))
+}
+
+/// Tracks the flow of `amount` tokens on the already loaded `token_manager`,
+/// storing it back only when the token has a flow limit.
 fn track_token_flow(
     accounts: &FlowTrackingAccounts,
+    token_manager: &mut TokenManager,
     amount: u64,
     direction: FlowDirection,
 ) -> ProgramResult {
-    let mut token_manager = TokenManager::load(accounts.token_manager)?;
-
     if token_manager.flow_slot.flow_limit.is_none() {
         return Ok(());
     }
@@ -477,14 +998,14 @@ fn track_token_flow(
 
 fn handle_give_token_transfer(
     accounts: &GiveTokenAccounts,
-    token_manager: &TokenManager,
+    token_manager: &mut TokenManager,
     amount: u64,
 ) -> Result<u64, ProgramError> {
     use token_manager::Type::{
         LockUnlock, LockUnlockFee, MintBurn, MintBurnFrom, NativeInterchainToken,
     };
 
-    track_token_flow(&accounts.into(), amount, FlowDirection::In)?;
+    track_token_flow(&accounts.into(), token_manager, amount, FlowDirection::In)?;
     let token_id = token_manager.token_id;
     let token_manager_pda_bump = token_manager.bump;
 
@@ -536,17 +1057,17 @@ fn handle_give_token_transfer(
 
 fn handle_take_token_transfer(
     accounts: &TakeTokenAccounts,
-    token_manager: &TokenManager,
+    token_manager: &mut TokenManager,
     amount: u64,
 ) -> Result<u64, ProgramError> {
     use token_manager::Type::{
         LockUnlock, LockUnlockFee, MintBurn, MintBurnFrom, NativeInterchainToken,
     };
 
-    track_token_flow(&accounts.into(), amount, FlowDirection::Out)?;
+    track_token_flow(&accounts.into(), token_manager, amount, FlowDirection::Out)?;
 
     let transferred = match token_manager.ty {
-        NativeInterchainToken | MintBurn | MintBurnFrom => {
+        NativeInterchainToken | MintBurn => {
             burn(
                 accounts.authority,
                 accounts.token_program,
@@ -557,6 +1078,23 @@ fn handle_take_token_transfer(
             )?;
             amount
         }
+        MintBurnFrom => {
+            ensure_burn_from_allowance(accounts, amount)?;
+            burn(
+                accounts.token_manager,
+                accounts.token_program,
+                accounts.mint,
+                accounts.source_ata,
+                amount,
+                &[
+                    seed_prefixes::TOKEN_MANAGER_SEED,
+                    accounts.its_root.key.as_ref(),
+                    &token_manager.token_id,
+                    &[token_manager.bump],
+                ],
+            )?;
+            amount
+        }
         LockUnlock => {
             let decimals = get_mint_decimals(accounts.mint)?;
             let transfer_info =
@@ -578,6 +1116,40 @@ fn handle_take_token_transfer(
     Ok(transferred)
 }
 
+/// Ensures the source token account can be burnt from by the [`TokenManager`]
+/// PDA acting as a delegate.
+///
+/// With [`MintBurnFrom`] the tokens are burnt by the [`TokenManager`] through
+/// an allowance previously granted by the owner of the source account (via
+/// the SPL `Approve` instruction), mirroring `burnFrom` semantics on EVM. The
+/// authority initiating the transfer must be the owner of the source account,
+/// otherwise anyone could spend allowances granted to the [`TokenManager`].
+fn ensure_burn_from_allowance(accounts: &TakeTokenAccounts, amount: u64) -> ProgramResult {
+    let source_data = accounts.source_ata.try_borrow_data()?;
+    let source = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&source_data)?;
+
+    if source.base.owner != *accounts.authority.key {
+        msg!("Authority must be the owner of the source token account");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if source.base.delegate != COption::Some(*accounts.token_manager.key) {
+        msg!("TokenManager is not an approved delegate of the source token account");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if source.base.delegated_amount < amount {
+        msg!(
+            "Insufficient allowance for TokenManager: approved {}, requested {}",
+            source.base.delegated_amount,
+            amount
+        );
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    Ok(())
+}
+
 fn get_mint_decimals(token_mint: &AccountInfo) -> Result<u8, ProgramError> {
     let mint_data = token_mint.try_borrow_data()?;
     let mint_state = StateWithExtensions::<Mint>::unpack(&mint_data)?;
@@ -645,6 +1217,8 @@ fn mint_to<'a>(
     token_manager: &TokenManager,
     amount: u64,
 ) -> ProgramResult {
+    token_manager.ensure_within_max_supply(token_mint, amount)?;
+
     invoke_signed(
         &spl_token_2022::instruction::mint_to(
             token_program.key,
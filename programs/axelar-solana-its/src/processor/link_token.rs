@@ -13,13 +13,14 @@ use spl_token_2022::state::Mint;
 
 use crate::accounts::{
     DeployCanonicalTokenAccounts, DeployCustomTokenAccounts, DeployTokenManagerAccounts,
-    LinkTokenAccounts, RegisterTokenMetadataAccounts,
+    LinkTokenAccounts, RegisterAndLinkTokenAccounts, RegisterTokenMetadataAccounts,
 };
+use crate::link_params::LinkParams;
 use crate::processor::gmp;
 use crate::processor::interchain_token;
 use crate::processor::token_manager::DeployTokenManagerInternal;
 use crate::state::token_manager::TokenManager;
-use crate::state::{token_manager, InterchainTokenService};
+use crate::state::{token_manager, InterchainTokenService, PauseFlags};
 use crate::{
     assert_its_not_paused, assert_valid_its_root_pda, assert_valid_token_manager_pda, events,
 };
@@ -41,18 +42,18 @@ pub(crate) fn process_inbound(
             .try_into()
             .map_err(|_err| ProgramError::InvalidAccountData)?,
     );
-    let operator = match payload.link_params.as_ref().try_into() {
-        Ok(operator_bytes) => Some(Pubkey::new_from_array(operator_bytes)),
-        Err(_err) => None,
-    };
+    let link_params = LinkParams::decode(payload.link_params.as_ref()).inspect_err(|_err| {
+        msg!("Invalid link params");
+    })?;
 
     let deploy_token_manager = DeployTokenManagerInternal::new(
         payload.token_manager_type.try_into()?,
         payload.token_id.0,
         token_address,
-        operator,
+        link_params.operator,
         None,
-    );
+    )
+    .with_flow_limiter(link_params.flow_limiter);
 
     let its_root_pda_bump = InterchainTokenService::load(accounts.its_root)?.bump;
 
@@ -107,13 +108,21 @@ pub(crate) fn process_outbound(
         token_manager.bump,
     )?;
 
-    let link_started_events = events::LinkTokenStarted {
+    let nonce = crate::next_outbound_nonce(
+        accounts.outbound_nonce,
+        accounts.payer,
+        accounts.system_program,
+        accounts.deployer.key,
+    )?;
+
+    let link_started_events = events::LinkTokenStartedV2 {
         token_id,
         destination_chain,
         source_token_address: token_manager.token_address,
         destination_token_address,
         token_manager_type: token_manager_type.into(),
         params: link_params,
+        nonce,
     };
     emit_cpi!(link_started_events);
 
@@ -132,7 +141,7 @@ pub(crate) fn process_outbound(
         &accounts.try_into()?,
         &message,
         link_started_events.destination_chain,
-        gas_value,
+        gmp::GasPayment::Native(gas_value),
         signing_pda_bump,
         true,
     )?;
@@ -170,7 +179,7 @@ pub(crate) fn register_token_metadata(
         &accounts.try_into()?,
         &payload,
         crate::ITS_HUB_CHAIN_NAME.to_owned(),
-        gas_value,
+        gmp::GasPayment::Native(gas_value),
         signing_pda_bump,
         false,
     )
@@ -190,7 +199,7 @@ pub(crate) fn register_custom_token(
 
     let its_config = InterchainTokenService::load(accounts.its_root)?;
     assert_valid_its_root_pda(accounts.its_root, its_config.bump)?;
-    assert_its_not_paused(&its_config)?;
+    assert_its_not_paused(&its_config, PauseFlags::LINK_OPERATIONS)?;
 
     let deployer = *accounts.deployer.key;
     let deploy_salt = crate::linked_token_deployer_salt(&deployer, &salt);
@@ -204,6 +213,42 @@ pub(crate) fn register_custom_token(
     )
 }
 
+/// Registers a custom token, registers its metadata with the ITS Hub and links
+/// it to the token on `destination_chain`, in that order, so that the Hub
+/// knows the decimals of the token by the time it processes the link.
+pub(crate) fn register_and_link_token(
+    accounts: RegisterAndLinkTokenAccounts,
+    salt: [u8; 32],
+    token_manager_type: token_manager::Type,
+    operator: Option<Pubkey>,
+    destination_chain: String,
+    destination_token_address: Vec<u8>,
+    destination_token_manager_type: token_manager::Type,
+    link_params: Vec<u8>,
+    metadata_gas_value: u64,
+    link_gas_value: u64,
+    signing_pda_bump: u8,
+) -> ProgramResult {
+    msg!("Instruction: RegisterAndLinkToken");
+
+    register_custom_token((&accounts).try_into()?, salt, token_manager_type, operator)?;
+    register_token_metadata(
+        (&accounts).try_into()?,
+        metadata_gas_value,
+        signing_pda_bump,
+    )?;
+    process_outbound(
+        (&accounts).try_into()?,
+        salt,
+        destination_chain,
+        destination_token_address,
+        destination_token_manager_type,
+        link_params,
+        link_gas_value,
+        signing_pda_bump,
+    )
+}
+
 pub(crate) fn register_canonical_interchain_token(
     accounts: DeployCanonicalTokenAccounts,
 ) -> ProgramResult {
@@ -211,7 +256,7 @@ pub(crate) fn register_canonical_interchain_token(
 
     let its_config = InterchainTokenService::load(accounts.its_root)?;
     assert_valid_its_root_pda(accounts.its_root, its_config.bump)?;
-    assert_its_not_paused(&its_config)?;
+    assert_its_not_paused(&its_config, PauseFlags::DEPLOYMENTS)?;
 
     if let Err(_err) =
         interchain_token::get_token_metadata(accounts.mint, Some(accounts.token_metadata))
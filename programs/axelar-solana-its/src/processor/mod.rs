@@ -13,13 +13,24 @@ use role_management::processor::{
 };
 use role_management::state::UserRoles;
 use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::msg;
+use solana_program::program::set_return_data;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
 use token_manager::handover_mint_authority;
 
-use crate::state::InterchainTokenService;
+use crate::accounts::{
+    RenameTrustedChainAccounts, SetDestinationExecutionPolicyAccounts, SetMaxPayloadSizeAccounts,
+    SetPauseFlagsAccounts,
+};
+use crate::error::ItsError;
+use crate::state::{
+    DestinationExecutionPolicy, InterchainTokenService, OperatorGracePeriod, PauseFlags,
+    MAX_OPERATOR_GRACE_PERIOD_SLOTS,
+};
 use crate::{accounts::RemoveTrustedChainAccounts, state::token_manager::TokenManager};
 use crate::{accounts::SetTrustedChainAccounts, instruction::InterchainTokenServiceInstruction};
 use crate::{assert_valid_its_root_pda, check_program_account, events, Roles};
@@ -71,6 +82,17 @@ pub fn process_instruction<'a>(
         InterchainTokenServiceInstruction::RemoveTrustedChain { chain_name } => {
             process_remove_trusted_chain(accounts.try_into()?, &chain_name)
         }
+        InterchainTokenServiceInstruction::RenameTrustedChain {
+            old_chain_name,
+            new_chain_name,
+        } => process_rename_trusted_chain(accounts.try_into()?, &old_chain_name, new_chain_name),
+        InterchainTokenServiceInstruction::SetMaxPayloadSize {
+            chain_name,
+            max_size,
+        } => process_set_max_payload_size(accounts.try_into()?, chain_name, max_size),
+        InterchainTokenServiceInstruction::SetDestinationExecutionPolicy { policy } => {
+            process_set_destination_execution_policy(accounts.try_into()?, policy)
+        }
         InterchainTokenServiceInstruction::ApproveDeployRemoteInterchainToken {
             deployer,
             salt,
@@ -119,6 +141,22 @@ pub fn process_instruction<'a>(
             symbol,
             decimals,
             initial_supply,
+            false,
+        ),
+        InterchainTokenServiceInstruction::DeployInterchainTokenWithOnMintMetadata {
+            salt,
+            name,
+            symbol,
+            decimals,
+            initial_supply,
+        } => interchain_token::process_deploy(
+            accounts.try_into()?,
+            salt,
+            name,
+            symbol,
+            decimals,
+            initial_supply,
+            true,
         ),
         InterchainTokenServiceInstruction::DeployRemoteInterchainToken {
             salt,
@@ -162,6 +200,7 @@ pub fn process_instruction<'a>(
             gas_value,
             signing_pda_bump,
             None,
+            false,
         ),
         InterchainTokenServiceInstruction::CpiInterchainTransfer {
             token_id,
@@ -184,6 +223,29 @@ pub fn process_instruction<'a>(
             pda_seeds,
             None,
         ),
+        InterchainTokenServiceInstruction::InterchainTransferWithTokenGas {
+            token_id,
+            destination_chain,
+            destination_address,
+            amount,
+            gas_amount,
+            signing_pda_bump,
+        } => {
+            let (gas_token_account, transfer_accounts) = accounts
+                .split_last()
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+            interchain_transfer::process_user_interchain_transfer_with_token_gas(
+                transfer_accounts.try_into()?,
+                gas_token_account,
+                token_id,
+                destination_chain,
+                destination_address,
+                amount,
+                gas_amount,
+                signing_pda_bump,
+            )
+        }
         InterchainTokenServiceInstruction::RegisterTokenMetadata {
             gas_value,
             signing_pda_bump,
@@ -216,6 +278,30 @@ pub fn process_instruction<'a>(
             gas_value,
             signing_pda_bump,
         ),
+        InterchainTokenServiceInstruction::RegisterAndLinkToken {
+            salt,
+            token_manager_type,
+            operator,
+            destination_chain,
+            destination_token_address,
+            destination_token_manager_type,
+            link_params,
+            metadata_gas_value,
+            link_gas_value,
+            signing_pda_bump,
+        } => link_token::register_and_link_token(
+            accounts.try_into()?,
+            salt,
+            token_manager_type,
+            operator,
+            destination_chain,
+            destination_token_address,
+            destination_token_manager_type,
+            link_params,
+            metadata_gas_value,
+            link_gas_value,
+            signing_pda_bump,
+        ),
         InterchainTokenServiceInstruction::SetFlowLimit { flow_limit } => {
             let accounts_iter = &mut accounts.iter();
             let payer_account = next_account_info(accounts_iter)?;
@@ -234,13 +320,17 @@ pub fn process_instruction<'a>(
 
             validate_system_account_key(system_program_account.key)?;
 
-            ensure_signer_roles(
-                &crate::id(),
-                its_root_account,
+            if let Some(expires_at_slot) = ensure_operator(
                 operator_account,
                 its_roles_account,
-                Roles::OPERATOR,
-            )?;
+                its_root_account,
+                &its_config_pda,
+            )? {
+                emit_cpi!(events::GracePeriodOperatorUsed {
+                    operator: *operator_account.key,
+                    expires_at_slot,
+                });
+            }
 
             let token_manager = TokenManager::load(token_manager_account)?;
 
@@ -287,6 +377,33 @@ pub fn process_instruction<'a>(
         InterchainTokenServiceInstruction::AcceptTokenManagerOperatorship => {
             token_manager::process_accept_operatorship(accounts)
         }
+        InterchainTokenServiceInstruction::SetTokenObserver { observer } => {
+            token_manager::process_set_token_observer(accounts, observer)
+        }
+        InterchainTokenServiceInstruction::SetTokenManagerDestinationOwnerPolicy { policy } => {
+            token_manager::process_set_destination_owner_policy(accounts, policy)
+        }
+        InterchainTokenServiceInstruction::SetTokenManagerMaxSupply { max_supply } => {
+            token_manager::process_set_max_supply(accounts, max_supply)
+        }
+        InterchainTokenServiceInstruction::SetTokenManagerFeeCollector { fee_collector } => {
+            token_manager::process_set_fee_collector(accounts, fee_collector)
+        }
+        InterchainTokenServiceInstruction::HarvestWithheldFees => {
+            token_manager::process_harvest_withheld_fees(accounts)
+        }
+        InterchainTokenServiceInstruction::VerifyTokenManager => {
+            token_manager::process_verify(accounts)
+        }
+        InterchainTokenServiceInstruction::ResizeTokenManager { new_size } => {
+            token_manager::process_resize(accounts, new_size)
+        }
+        InterchainTokenServiceInstruction::MigrateFlowSlot { epoch } => {
+            token_manager::process_migrate_flow_slot(accounts, epoch)
+        }
+        InterchainTokenServiceInstruction::DecommissionTokenManager { mint_authority } => {
+            token_manager::process_decommission(accounts, mint_authority)
+        }
         InterchainTokenServiceInstruction::HandoverMintAuthority { token_id } => {
             handover_mint_authority(accounts, token_id)
         }
@@ -302,6 +419,9 @@ pub fn process_instruction<'a>(
         InterchainTokenServiceInstruction::AcceptInterchainTokenMintership => {
             interchain_token::process_accept_mintership(accounts)
         }
+        InterchainTokenServiceInstruction::SetTokenMetadataAuthority { new_authority } => {
+            interchain_token::process_set_metadata_authority(accounts, new_authority)
+        }
         InterchainTokenServiceInstruction::CallContractWithInterchainToken {
             token_id,
             destination_chain,
@@ -319,6 +439,7 @@ pub fn process_instruction<'a>(
             gas_value,
             signing_pda_bump,
             Some(data),
+            false,
         ),
         InterchainTokenServiceInstruction::CpiCallContractWithInterchainToken {
             token_id,
@@ -342,6 +463,51 @@ pub fn process_instruction<'a>(
             pda_seeds,
             Some(data),
         ),
+        InterchainTokenServiceInstruction::PreviewInterchainTokenId { deployer, salt } => {
+            msg!("Instruction: PreviewInterchainTokenId");
+            set_return_data(&crate::interchain_token_id(&deployer, &salt));
+            Ok(())
+        }
+        InterchainTokenServiceInstruction::PreviewCanonicalTokenId { mint } => {
+            msg!("Instruction: PreviewCanonicalTokenId");
+            set_return_data(&crate::canonical_interchain_token_id(&mint));
+            Ok(())
+        }
+        InterchainTokenServiceInstruction::PreviewLinkedTokenId { deployer, salt } => {
+            msg!("Instruction: PreviewLinkedTokenId");
+            set_return_data(&crate::linked_token_id(&deployer, &salt));
+            Ok(())
+        }
+        InterchainTokenServiceInstruction::TransferOperatorshipWithGracePeriod {
+            grace_period_slots,
+        } => process_transfer_operatorship_with_grace_period(accounts, grace_period_slots),
+        InterchainTokenServiceInstruction::GetFlowStatus => {
+            token_manager::process_get_flow_status(accounts)
+        }
+        InterchainTokenServiceInstruction::SetPauseFlags { pause_flags } => {
+            process_set_pause_flags(accounts.try_into()?, pause_flags)
+        }
+        InterchainTokenServiceInstruction::EstimateInboundRent { payload } => {
+            interchain_transfer::process_estimate_inbound_rent(accounts, &payload)
+        }
+        InterchainTokenServiceInstruction::InterchainTransferAllowPartial {
+            token_id,
+            destination_chain,
+            destination_address,
+            amount,
+            gas_value,
+            signing_pda_bump,
+        } => interchain_transfer::process_user_interchain_transfer(
+            accounts.try_into()?,
+            token_id,
+            destination_chain,
+            destination_address,
+            amount,
+            gas_value,
+            signing_pda_bump,
+            None,
+            true,
+        ),
     }
 }
 
@@ -410,7 +576,11 @@ fn process_initialize(
     Ok(())
 }
 
-fn process_transfer_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+/// Moves the operator role on the ITS root from the origin to the destination
+/// account of a `TransferOperatorship*` instruction and returns the ITS root.
+fn transfer_operator_role<'a>(
+    accounts: &'a [AccountInfo<'a>],
+) -> Result<InterchainTokenService, ProgramError> {
     let accounts_iter = &mut accounts.iter();
 
     let system_program_account = next_account_info(accounts_iter)?;
@@ -428,8 +598,6 @@ fn process_transfer_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> Program
         return Err(ProgramError::InvalidArgument);
     }
 
-    msg!("Instruction: TransferOperatorship");
-
     let its_config = InterchainTokenService::load(resource_account)?;
     assert_valid_its_root_pda(resource_account, its_config.bump)?;
 
@@ -465,7 +633,128 @@ fn process_transfer_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> Program
         role_remove_accounts,
         Roles::OPERATOR,
         Roles::OPERATOR,
-    )
+    )?;
+
+    Ok(its_config)
+}
+
+fn process_transfer_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    msg!("Instruction: TransferOperatorship");
+
+    let mut its_config = transfer_operator_role(accounts)?;
+
+    // A new transfer ends the grace period of the operator before
+    if its_config.operator_grace_period.take().is_some() {
+        let accounts_iter = &mut accounts.iter();
+        let system_program_account = next_account_info(accounts_iter)?;
+        let payer_account = next_account_info(accounts_iter)?;
+        let resource_account = accounts_iter
+            .nth(2)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        its_config.store(payer_account, resource_account, system_program_account)?;
+    }
+
+    Ok(())
+}
+
+fn process_transfer_operatorship_with_grace_period<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    grace_period_slots: u64,
+) -> ProgramResult {
+    msg!("Instruction: TransferOperatorshipWithGracePeriod");
+
+    let accounts_iter = &mut accounts.iter();
+    let system_program_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let origin_user_account = next_account_info(accounts_iter)?;
+    let _origin_roles_account = next_account_info(accounts_iter)?;
+    let resource_account = next_account_info(accounts_iter)?;
+    let destination_user_account = next_account_info(accounts_iter)?;
+    let _destination_roles_account = next_account_info(accounts_iter)?;
+
+    event_cpi_accounts!(accounts_iter);
+
+    if grace_period_slots == 0 || grace_period_slots > MAX_OPERATOR_GRACE_PERIOD_SLOTS {
+        msg!(
+            "The grace period must be between 1 and {} slots",
+            MAX_OPERATOR_GRACE_PERIOD_SLOTS
+        );
+        return Err(ItsError::InvalidOperatorGracePeriod.into());
+    }
+
+    let expires_at_slot = Clock::get()?
+        .slot
+        .checked_add(grace_period_slots)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let mut its_config = transfer_operator_role(accounts)?;
+    its_config.operator_grace_period = Some(OperatorGracePeriod {
+        previous_operator: *origin_user_account.key,
+        expires_at_slot,
+    });
+    its_config.store(payer_account, resource_account, system_program_account)?;
+
+    emit_cpi!(events::OperatorshipTransferredWithGracePeriod {
+        previous_operator: *origin_user_account.key,
+        new_operator: *destination_user_account.key,
+        expires_at_slot,
+    });
+
+    Ok(())
+}
+
+/// Ensures `authority` holds the operator role on the ITS root, or is the
+/// previous operator during its grace period, in which case the slot the grace
+/// period expires at is returned so the caller can emit a
+/// [`events::GracePeriodOperatorUsed`] warning.
+fn ensure_operator(
+    authority: &AccountInfo<'_>,
+    authority_roles: &AccountInfo<'_>,
+    its_root_account: &AccountInfo<'_>,
+    its_root: &InterchainTokenService,
+) -> Result<Option<u64>, ProgramError> {
+    let Err(err) = ensure_signer_roles(
+        &crate::id(),
+        its_root_account,
+        authority,
+        authority_roles,
+        Roles::OPERATOR,
+    ) else {
+        return Ok(None);
+    };
+
+    let Some(grace_period) = its_root.operator_grace_period else {
+        return Err(err);
+    };
+    if !authority.is_signer || !grace_period.honors(authority.key, Clock::get()?.slot) {
+        return Err(err);
+    }
+
+    msg!(
+        "Warning: {} is honored as operator until its grace period expires at slot {}",
+        authority.key,
+        grace_period.expires_at_slot
+    );
+
+    Ok(Some(grace_period.expires_at_slot))
+}
+
+/// Like [`ensure_operator`], also accepting the upgrade authority of ITS.
+fn ensure_upgrade_authority_or_operator(
+    authority: &AccountInfo<'_>,
+    authority_roles: &AccountInfo<'_>,
+    program_data: &AccountInfo<'_>,
+    its_root_account: &AccountInfo<'_>,
+    its_root: &InterchainTokenService,
+) -> Result<Option<u64>, ProgramError> {
+    if ensure_upgrade_authority(&crate::id(), authority, program_data).is_ok() {
+        return Ok(None);
+    }
+
+    ensure_operator(authority, authority_roles, its_root_account, its_root).map_err(|_err| {
+        msg!("Account passed as authority is neither upgrade authority nor operator");
+        ProgramError::MissingRequiredSignature
+    })
 }
 
 fn process_propose_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
@@ -498,7 +787,24 @@ fn process_propose_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramR
         proposal_account,
     };
 
-    role_management::processor::propose(&crate::id(), role_management_accounts, Roles::OPERATOR)
+    role_management::processor::propose(&crate::id(), role_management_accounts, Roles::OPERATOR)?;
+
+    // Create the destination roles account up front so accepting doesn't
+    // need funds, e.g. when the destination is a PDA accepting through CPI.
+    role_management::processor::add(
+        &crate::id(),
+        RoleAddAccounts {
+            system_account: system_program_account,
+            payer: payer_account,
+            authority_user_account: proposer_user_account,
+            authority_roles_account: proposer_roles_account,
+            resource: resource_account,
+            target_user_account: destination_user_account,
+            target_roles_account: destination_roles_account,
+        },
+        Roles::empty(),
+        Roles::OPERATOR,
+    )
 }
 
 fn process_accept_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
@@ -516,6 +822,9 @@ fn process_accept_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramRe
 
     validate_system_account_key(system_program_account.key)?;
 
+    let mut its_config = InterchainTokenService::load(resource_account)?;
+    assert_valid_its_root_pda(resource_account, its_config.bump)?;
+
     let role_management_accounts = RoleTransferWithProposalAccounts {
         system_account: system_program_account,
         payer: payer_account,
@@ -527,7 +836,14 @@ fn process_accept_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramRe
         proposal_account,
     };
 
-    role_management::processor::accept(&crate::id(), role_management_accounts, Roles::OPERATOR)
+    role_management::processor::accept(&crate::id(), role_management_accounts, Roles::OPERATOR)?;
+
+    // Accepting the operatorship ends the grace period of the operator before
+    if its_config.operator_grace_period.take().is_some() {
+        its_config.store(payer_account, resource_account, system_program_account)?;
+    }
+
+    Ok(())
 }
 
 fn process_set_pause_status<'a>(accounts: &'a [AccountInfo<'a>], paused: bool) -> ProgramResult {
@@ -537,6 +853,8 @@ fn process_set_pause_status<'a>(accounts: &'a [AccountInfo<'a>], paused: bool) -
     let its_root_account = next_account_info(accounts_iter)?;
     let system_program_account = next_account_info(accounts_iter)?;
 
+    event_cpi_accounts!(accounts_iter);
+
     validate_system_account_key(system_program_account.key)?;
 
     msg!("Instruction: SetPauseStatus");
@@ -546,9 +864,18 @@ fn process_set_pause_status<'a>(accounts: &'a [AccountInfo<'a>], paused: bool) -
     let mut its_root_config = InterchainTokenService::load(its_root_account)?;
     assert_valid_its_root_pda(its_root_account, its_root_config.bump)?;
 
-    its_root_config.paused = paused;
+    if paused {
+        its_root_config.pause();
+    } else {
+        its_root_config.unpause();
+    }
     its_root_config.store(owner_account, its_root_account, system_program_account)?;
 
+    emit_cpi!(events::PauseStatusChanged {
+        paused,
+        authority: *owner_account.key,
+    });
+
     Ok(())
 }
 
@@ -561,24 +888,26 @@ fn process_set_trusted_chain(
     let event_accounts = &mut accounts.event_accounts().into_iter();
     event_cpi_accounts!(event_accounts);
 
-    if ensure_upgrade_authority(&crate::id(), accounts.authority, accounts.program_data).is_err()
-        && ensure_signer_roles(
-            &crate::id(),
-            accounts.its_root,
-            accounts.authority,
-            accounts.authority_roles,
-            Roles::OPERATOR,
-        )
-        .is_err()
-    {
-        msg!("Account passed as authority is neither upgrade authority nor operator");
-        return Err(ProgramError::MissingRequiredSignature);
-    }
-
     let mut its_root = InterchainTokenService::load(accounts.its_root)?;
     assert_valid_its_root_pda(accounts.its_root, its_root.bump)?;
 
-    let trusted_chain_event = events::TrustedChainSet { chain_name };
+    if let Some(expires_at_slot) = ensure_upgrade_authority_or_operator(
+        accounts.authority,
+        accounts.authority_roles,
+        accounts.program_data,
+        accounts.its_root,
+        &its_root,
+    )? {
+        emit_cpi!(events::GracePeriodOperatorUsed {
+            operator: *accounts.authority.key,
+            expires_at_slot,
+        });
+    }
+
+    let trusted_chain_event = events::TrustedChainSetV2 {
+        chain_name,
+        authority: *accounts.authority.key,
+    };
     emit_cpi!(trusted_chain_event);
     its_root.add_trusted_chain(trusted_chain_event.chain_name);
     its_root.store(accounts.payer, accounts.its_root, accounts.system_program)?;
@@ -595,24 +924,25 @@ fn process_remove_trusted_chain(
     let event_accounts = &mut accounts.event_accounts().into_iter();
     event_cpi_accounts!(event_accounts);
 
-    if ensure_upgrade_authority(&crate::id(), accounts.authority, accounts.program_data).is_err()
-        && ensure_signer_roles(
-            &crate::id(),
-            accounts.its_root,
-            accounts.authority,
-            accounts.authority_roles,
-            Roles::OPERATOR,
-        )
-        .is_err()
-    {
-        msg!("Account passed as authority is neither upgrade authority nor operator");
-        return Err(ProgramError::MissingRequiredSignature);
-    }
     let mut its_root = InterchainTokenService::load(accounts.its_root)?;
     assert_valid_its_root_pda(accounts.its_root, its_root.bump)?;
 
-    emit_cpi!(events::TrustedChainRemoved {
+    if let Some(expires_at_slot) = ensure_upgrade_authority_or_operator(
+        accounts.authority,
+        accounts.authority_roles,
+        accounts.program_data,
+        accounts.its_root,
+        &its_root,
+    )? {
+        emit_cpi!(events::GracePeriodOperatorUsed {
+            operator: *accounts.authority.key,
+            expires_at_slot,
+        });
+    }
+
+    emit_cpi!(events::TrustedChainRemovedV2 {
         chain_name: chain_name.to_owned(),
+        authority: *accounts.authority.key,
     });
 
     its_root.remove_trusted_chain(chain_name)?;
@@ -620,3 +950,147 @@ fn process_remove_trusted_chain(
 
     Ok(())
 }
+
+fn process_rename_trusted_chain(
+    accounts: RenameTrustedChainAccounts,
+    old_chain_name: &str,
+    new_chain_name: String,
+) -> ProgramResult {
+    msg!("Instruction: RenameTrustedChain");
+
+    let event_accounts = &mut accounts.event_accounts().into_iter();
+    event_cpi_accounts!(event_accounts);
+
+    let mut its_root = InterchainTokenService::load(accounts.its_root)?;
+    assert_valid_its_root_pda(accounts.its_root, its_root.bump)?;
+
+    if let Some(expires_at_slot) = ensure_upgrade_authority_or_operator(
+        accounts.authority,
+        accounts.authority_roles,
+        accounts.program_data,
+        accounts.its_root,
+        &its_root,
+    )? {
+        emit_cpi!(events::GracePeriodOperatorUsed {
+            operator: *accounts.authority.key,
+            expires_at_slot,
+        });
+    }
+
+    its_root.rename_trusted_chain(old_chain_name, new_chain_name.clone())?;
+    its_root.store(accounts.payer, accounts.its_root, accounts.system_program)?;
+
+    emit_cpi!(events::TrustedChainRenamed {
+        old_chain_name: old_chain_name.to_owned(),
+        new_chain_name,
+        authority: *accounts.authority.key,
+    });
+
+    Ok(())
+}
+
+fn process_set_max_payload_size(
+    accounts: SetMaxPayloadSizeAccounts,
+    chain_name: String,
+    max_size: Option<u32>,
+) -> ProgramResult {
+    msg!("Instruction: SetMaxPayloadSize");
+
+    let event_accounts = &mut accounts.event_accounts().into_iter();
+    event_cpi_accounts!(event_accounts);
+
+    let mut its_root = InterchainTokenService::load(accounts.its_root)?;
+    assert_valid_its_root_pda(accounts.its_root, its_root.bump)?;
+
+    if let Some(expires_at_slot) = ensure_upgrade_authority_or_operator(
+        accounts.authority,
+        accounts.authority_roles,
+        accounts.program_data,
+        accounts.its_root,
+        &its_root,
+    )? {
+        emit_cpi!(events::GracePeriodOperatorUsed {
+            operator: *accounts.authority.key,
+            expires_at_slot,
+        });
+    }
+
+    let max_payload_size_event = events::MaxPayloadSizeSet {
+        chain_name,
+        max_size,
+    };
+    emit_cpi!(max_payload_size_event);
+    its_root.set_max_payload_size(max_payload_size_event.chain_name, max_size);
+    its_root.store(accounts.payer, accounts.its_root, accounts.system_program)?;
+
+    Ok(())
+}
+
+fn process_set_pause_flags(accounts: SetPauseFlagsAccounts, pause_flags: u8) -> ProgramResult {
+    msg!("Instruction: SetPauseFlags");
+
+    let event_accounts = &mut accounts.event_accounts().into_iter();
+    event_cpi_accounts!(event_accounts);
+
+    let mut its_root = InterchainTokenService::load(accounts.its_root)?;
+    assert_valid_its_root_pda(accounts.its_root, its_root.bump)?;
+
+    if let Some(expires_at_slot) = ensure_upgrade_authority_or_operator(
+        accounts.authority,
+        accounts.authority_roles,
+        accounts.program_data,
+        accounts.its_root,
+        &its_root,
+    )? {
+        emit_cpi!(events::GracePeriodOperatorUsed {
+            operator: *accounts.authority.key,
+            expires_at_slot,
+        });
+    }
+
+    let Some(pause_flags) = PauseFlags::from_bits(pause_flags) else {
+        msg!("Unknown pause flags: {:#010b}", pause_flags);
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    emit_cpi!(events::PauseFlagsSet {
+        pause_flags: pause_flags.bits(),
+        authority: *accounts.authority.key,
+    });
+    its_root.set_pause_flags(pause_flags);
+    its_root.store(accounts.payer, accounts.its_root, accounts.system_program)?;
+
+    Ok(())
+}
+
+fn process_set_destination_execution_policy(
+    accounts: SetDestinationExecutionPolicyAccounts,
+    policy: Option<DestinationExecutionPolicy>,
+) -> ProgramResult {
+    msg!("Instruction: SetDestinationExecutionPolicy");
+
+    let event_accounts = &mut accounts.event_accounts().into_iter();
+    event_cpi_accounts!(event_accounts);
+
+    let mut its_root = InterchainTokenService::load(accounts.its_root)?;
+    assert_valid_its_root_pda(accounts.its_root, its_root.bump)?;
+
+    if let Some(expires_at_slot) = ensure_upgrade_authority_or_operator(
+        accounts.authority,
+        accounts.authority_roles,
+        accounts.program_data,
+        accounts.its_root,
+        &its_root,
+    )? {
+        emit_cpi!(events::GracePeriodOperatorUsed {
+            operator: *accounts.authority.key,
+            expires_at_slot,
+        });
+    }
+
+    emit_cpi!(events::DestinationExecutionPolicySet { policy });
+    its_root.destination_execution_policy = policy;
+    its_root.store(accounts.payer, accounts.its_root, accounts.system_program)?;
+
+    Ok(())
+}
@@ -1,28 +1,43 @@
 //! Processor for [`TokenManager`] related requests.
 
 use event_cpi_macros::{emit_cpi, event_cpi_accounts};
-use program_utils::{pda::BorshPda, validate_system_account_key};
+use program_utils::pda::{close_pda, BorshPda};
+use program_utils::validate_system_account_key;
 use role_management::processor::{
-    ensure_signer_roles, RoleAddAccounts, RoleRemoveAccounts, RoleTransferWithProposalAccounts,
+    ensure_proper_account, ensure_signer_roles, RoleAddAccounts, RoleRemoveAccounts,
+    RoleTransferWithProposalAccounts,
 };
 use role_management::state::UserRoles;
 use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::msg;
-use solana_program::program::invoke;
+use solana_program::program::{invoke, invoke_signed, set_return_data};
 use solana_program::program_error::ProgramError;
 use solana_program::program_option::COption;
 use solana_program::program_pack::Pack;
 use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::extension::metadata_pointer::MetadataPointer;
+use spl_token_2022::extension::transfer_fee::instruction::withdraw_withheld_tokens_from_accounts;
+use spl_token_2022::extension::transfer_fee::TransferFeeConfig;
 use spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions};
 use spl_token_2022::instruction::AuthorityType;
-use spl_token_2022::state::Mint;
+use spl_token_2022::state::{Mint, Multisig};
+use spl_token_metadata_interface::instruction::update_authority as update_token_metadata_authority;
+use spl_token_metadata_interface::state::TokenMetadata;
 
 use crate::accounts::DeployTokenManagerAccounts;
-use crate::state::token_manager::{self, TokenManager};
+use crate::error::ItsError;
+use crate::link_params::LinkParams;
+use crate::state::flow_limit::{current_flow_epoch, FlowSlot};
+use crate::state::token_manager::{self, DestinationOwnerPolicy, TokenManager};
+use crate::state::token_observer::TokenObserver;
 use crate::state::InterchainTokenService;
 use crate::{assert_valid_its_root_pda, events};
 use crate::{assert_valid_token_manager_pda, seed_prefixes, Roles};
+use crate::{find_token_observer_pda, load_token_observer};
 use event_cpi::EventAccounts;
 
 pub(crate) fn set_flow_limit<'a>(
@@ -50,6 +65,7 @@ pub(crate) struct DeployTokenManagerInternal {
     token_id: [u8; 32],
     token_address: Pubkey,
     operator: Option<Pubkey>,
+    flow_limiter: Option<Pubkey>,
     minter: Option<Pubkey>,
 }
 
@@ -66,9 +82,17 @@ impl DeployTokenManagerInternal {
             token_id,
             token_address,
             operator,
+            flow_limiter: None,
             minter,
         }
     }
+
+    /// Grants the flow limiter role to `flow_limiter` instead of the
+    /// operator.
+    pub(crate) const fn with_flow_limiter(mut self, flow_limiter: Option<Pubkey>) -> Self {
+        self.flow_limiter = flow_limiter;
+        self
+    }
 }
 
 /// Deploys a new [`TokenManager`] PDA.
@@ -96,6 +120,10 @@ pub(crate) fn deploy(
         accounts.token_program,
     )?;
 
+    // Every roles PDA set up over the token manager has to be closed when it's
+    // decommissioned
+    let mut role_holders = 0;
+
     if let Some(operator_from_message) = deploy_token_manager.operator {
         let (Some(operator), Some(operator_roles_pda)) =
             (accounts.operator, accounts.operator_roles)
@@ -108,7 +136,10 @@ pub(crate) fn deploy(
             return Err(ProgramError::InvalidAccountData);
         }
 
-        let mut roles = Roles::OPERATOR | Roles::FLOW_LIMITER;
+        let mut roles = Roles::OPERATOR;
+        if deploy_token_manager.flow_limiter.is_none() {
+            roles |= Roles::FLOW_LIMITER;
+        }
         if deploy_token_manager.minter.is_some()
             && deploy_token_manager.manager_type == token_manager::Type::NativeInterchainToken
         {
@@ -123,15 +154,43 @@ pub(crate) fn deploy(
             accounts.system_program,
             roles,
         )?;
+        role_holders += 1;
+    }
+
+    if let Some(flow_limiter_from_message) = deploy_token_manager.flow_limiter {
+        let (Some(flow_limiter), Some(flow_limiter_roles_pda)) =
+            (accounts.flow_limiter, accounts.flow_limiter_roles)
+        else {
+            return Err(ProgramError::InvalidArgument);
+        };
+
+        if flow_limiter_from_message.ne(flow_limiter.key) {
+            msg!("Invalid flow limiter provided");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        setup_roles(
+            accounts.payer,
+            accounts.token_manager,
+            flow_limiter.key,
+            flow_limiter_roles_pda,
+            accounts.system_program,
+            Roles::FLOW_LIMITER,
+        )?;
+        if deploy_token_manager.operator != Some(flow_limiter_from_message) {
+            role_holders += 1;
+        }
     }
 
-    let token_manager = TokenManager::new(
+    let mut token_manager = TokenManager::new(
         deploy_token_manager.manager_type,
         deploy_token_manager.token_id,
         deploy_token_manager.token_address,
         *accounts.token_manager_ata.key,
+        *accounts.token_program.key,
         token_manager_pda_bump,
     );
+    token_manager.role_holders = Some(role_holders);
     token_manager.init(
         &crate::id(),
         accounts.system_program,
@@ -149,10 +208,11 @@ pub(crate) fn deploy(
         token_id: deploy_token_manager.token_id,
         token_manager: *accounts.token_manager.key,
         token_manager_type: deploy_token_manager.manager_type.into(),
-        params: deploy_token_manager
-            .operator
-            .map(|op| op.to_bytes().to_vec())
-            .unwrap_or_default(),
+        params: LinkParams {
+            operator: deploy_token_manager.operator,
+            flow_limiter: deploy_token_manager.flow_limiter,
+        }
+        .encode(),
     });
 
     Ok(())
@@ -196,6 +256,27 @@ fn setup_roles<'a>(
     Ok(())
 }
 
+/// Counts a roles PDA newly created over the token manager towards
+/// [`TokenManager::role_holders`]. Token managers that don't count their
+/// holders are left untouched.
+pub(crate) fn record_role_holder<'a>(
+    payer: &AccountInfo<'a>,
+    token_manager_pda: &AccountInfo<'a>,
+    system_account: &AccountInfo<'a>,
+) -> ProgramResult {
+    let mut token_manager = TokenManager::load(token_manager_pda)?;
+    let Some(role_holders) = token_manager.role_holders else {
+        return Ok(());
+    };
+
+    token_manager.role_holders = Some(
+        role_holders
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+    );
+    token_manager.store(payer, token_manager_pda, system_account)
+}
+
 pub(crate) fn validate_mint_extensions(
     ty: token_manager::Type,
     token_mint: &AccountInfo<'_>,
@@ -262,6 +343,7 @@ pub(crate) fn handover_mint_authority(
     let minter_roles = next_account_info(accounts_iter)?;
     let token_program = next_account_info(accounts_iter)?;
     let system_account = next_account_info(accounts_iter)?;
+    let multisig_signers = accounts_iter.as_slice();
 
     msg!("Instruction: HandoverMintAuthority");
 
@@ -272,7 +354,12 @@ pub(crate) fn handover_mint_authority(
         return Err(ProgramError::MissingRequiredSignature);
     }
 
-    if !authority.is_signer {
+    let is_multisig_authority = is_multisig(authority, token_program)?;
+    if is_multisig_authority {
+        ensure_multisig_signers(payer, multisig_signers)?;
+    }
+
+    if !is_multisig_authority && !authority.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
     }
 
@@ -321,25 +408,46 @@ pub(crate) fn handover_mint_authority(
             // to the `TokenManager` and the `minter` role is added to the payer
             // on the `TokenManager`. Future minting by the user needs to go
             // through ITS.
+            //
+            // When the authority is a SPL multisig, the token program checks the
+            // signer set against the multisig threshold. As a multisig cannot sign
+            // ITS instructions, the `minter` role goes to the payer, which is
+            // required to be one of the signers.
+            let signer_pubkeys = multisig_signers
+                .iter()
+                .map(|signer| signer.key)
+                .collect::<Vec<_>>();
             let authority_transfer_ix = spl_token_2022::instruction::set_authority(
                 token_program.key,
                 mint.key,
                 Some(token_manager.key),
                 AuthorityType::MintTokens,
                 authority.key,
-                &[],
+                &signer_pubkeys,
             )?;
 
-            invoke(&authority_transfer_ix, &[mint.clone(), authority.clone()])?;
+            let mut cpi_accounts = vec![mint.clone(), authority.clone()];
+            cpi_accounts.extend(multisig_signers.iter().cloned());
+            invoke(&authority_transfer_ix, &cpi_accounts)?;
 
+            let minter = if is_multisig_authority {
+                payer.key
+            } else {
+                authority.key
+            };
+
+            let new_holder = minter_roles.data_is_empty();
             setup_roles(
                 payer,
                 token_manager,
-                authority.key,
+                minter,
                 minter_roles,
                 system_account,
                 Roles::MINTER,
             )?;
+            if new_holder {
+                record_role_holder(payer, token_manager, system_account)?;
+            }
 
             Ok(())
         }
@@ -352,6 +460,33 @@ pub(crate) fn handover_mint_authority(
     Ok(())
 }
 
+fn is_multisig(authority: &AccountInfo, token_program: &AccountInfo) -> Result<bool, ProgramError> {
+    if authority.owner != token_program.key || authority.data_len() != Multisig::LEN {
+        return Ok(false);
+    }
+
+    let multisig = Multisig::unpack(&authority.try_borrow_data()?)?;
+    Ok(multisig.is_initialized)
+}
+
+fn ensure_multisig_signers(payer: &AccountInfo, signers: &[AccountInfo]) -> ProgramResult {
+    if signers.is_empty() {
+        msg!("Multisig mint authority requires the signer accounts to be provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    if signers.iter().any(|signer| !signer.is_signer) {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !signers.iter().any(|signer| signer.key == payer.key) {
+        msg!("Payer must be one of the multisig signers");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
 pub(crate) fn process_add_flow_limiter<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
     msg!("Instruction: AddTokenManagerFlowLimiter");
 
@@ -391,12 +526,18 @@ pub(crate) fn process_add_flow_limiter<'a>(accounts: &'a [AccountInfo<'a>]) -> P
         target_roles_account: destination_roles_account,
     };
 
+    let new_holder = destination_roles_account.data_is_empty();
     role_management::processor::add(
         &crate::id(),
         role_management_accounts,
         Roles::FLOW_LIMITER,
         Roles::OPERATOR,
-    )
+    )?;
+    if new_holder {
+        record_role_holder(payer, resource, system_account)?;
+    }
+
+    Ok(())
 }
 
 pub(crate) fn process_remove_flow_limiter<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
@@ -494,147 +635,1057 @@ pub(crate) fn process_set_flow_limit<'a>(
     Ok(())
 }
 
-pub(crate) fn process_transfer_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
-    msg!("Instruction: TransferTokenManagerOperatorship");
+pub(crate) fn process_set_token_observer<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    observer: Option<Pubkey>,
+) -> ProgramResult {
+    msg!("Instruction: SetTokenObserver");
 
     let accounts_iter = &mut accounts.iter();
-    let its_config_pda = next_account_info(accounts_iter)?;
-    let system_account = next_account_info(accounts_iter)?;
     let payer = next_account_info(accounts_iter)?;
-    let origin_user_account = next_account_info(accounts_iter)?;
-    let origin_roles_account = next_account_info(accounts_iter)?;
-    let token_manager_account = next_account_info(accounts_iter)?;
-    let destination_user_account = next_account_info(accounts_iter)?;
-    let destination_roles_account = next_account_info(accounts_iter)?;
+    let operator = next_account_info(accounts_iter)?;
+    let its_root_pda = next_account_info(accounts_iter)?;
+    let token_manager_pda = next_account_info(accounts_iter)?;
+    let token_manager_user_roles_pda = next_account_info(accounts_iter)?;
+    let token_observer_pda = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
 
-    validate_system_account_key(system_account.key)?;
+    event_cpi_accounts!(accounts_iter);
 
-    if origin_user_account.key == destination_user_account.key {
-        msg!("Source and destination accounts are the same");
-        return Err(ProgramError::InvalidArgument);
-    }
+    let its_config_pda = InterchainTokenService::load(its_root_pda)?;
+    assert_valid_its_root_pda(its_root_pda, its_config_pda.bump)?;
 
-    let its_config = InterchainTokenService::load(its_config_pda)?;
-    let token_manager = TokenManager::load(token_manager_account)?;
+    validate_system_account_key(system_account.key)?;
 
-    assert_valid_its_root_pda(its_config_pda, its_config.bump)?;
+    ensure_signer_roles(
+        &crate::id(),
+        token_manager_pda,
+        operator,
+        token_manager_user_roles_pda,
+        Roles::OPERATOR,
+    )?;
+
+    let token_manager = TokenManager::load(token_manager_pda)?;
     assert_valid_token_manager_pda(
-        token_manager_account,
-        its_config_pda.key,
+        token_manager_pda,
+        its_root_pda.key,
         &token_manager.token_id,
         token_manager.bump,
     )?;
 
-    let role_add_accounts = RoleAddAccounts {
-        system_account,
-        payer,
-        authority_user_account: origin_user_account,
-        authority_roles_account: origin_roles_account,
-        resource: token_manager_account,
-        target_user_account: destination_user_account,
-        target_roles_account: destination_roles_account,
-    };
-    let role_remove_accounts = RoleRemoveAccounts {
-        system_account,
-        payer,
-        authority_user_account: origin_user_account,
-        authority_roles_account: origin_roles_account,
-        resource: token_manager_account,
-        target_user_account: origin_user_account,
-        target_roles_account: origin_roles_account,
-    };
+    let current_observer = load_token_observer(token_observer_pda, token_manager_pda.key)?;
 
-    role_management::processor::add(
-        &crate::id(),
-        role_add_accounts,
-        Roles::OPERATOR,
-        Roles::OPERATOR,
-    )?;
+    match (current_observer, observer) {
+        (Some(mut token_observer), Some(program_id)) => {
+            token_observer.program_id = program_id;
+            token_observer.store(payer, token_observer_pda, system_account)?;
+        }
+        (None, Some(program_id)) => {
+            let (_, bump) = find_token_observer_pda(token_manager_pda.key);
+            TokenObserver::new(program_id, bump).init(
+                &crate::id(),
+                system_account,
+                payer,
+                token_observer_pda,
+                &[
+                    seed_prefixes::TOKEN_OBSERVER_SEED,
+                    token_manager_pda.key.as_ref(),
+                    &[bump],
+                ],
+            )?;
+        }
+        (Some(_), None) => {
+            program_utils::pda::close_pda(payer, token_observer_pda, &crate::id())?;
+        }
+        (None, None) => {
+            msg!("No token observer is registered for the token");
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
 
-    role_management::processor::remove(
-        &crate::id(),
-        role_remove_accounts,
-        Roles::OPERATOR,
-        Roles::OPERATOR,
-    )
+    emit_cpi!(events::TokenObserverSet {
+        token_id: token_manager.token_id,
+        operator: *operator.key,
+        observer,
+    });
+
+    Ok(())
 }
 
-pub(crate) fn process_propose_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
-    msg!("Instruction: ProposeTokenManagerOperatorship");
+pub(crate) fn process_set_destination_owner_policy<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    policy: DestinationOwnerPolicy,
+) -> ProgramResult {
+    msg!("Instruction: SetTokenManagerDestinationOwnerPolicy");
 
     let accounts_iter = &mut accounts.iter();
-    let its_config_pda = next_account_info(accounts_iter)?;
-    let system_account = next_account_info(accounts_iter)?;
     let payer = next_account_info(accounts_iter)?;
-    let origin_user_account = next_account_info(accounts_iter)?;
-    let origin_roles_account = next_account_info(accounts_iter)?;
-    let token_manager_account = next_account_info(accounts_iter)?;
-    let destination_user_account = next_account_info(accounts_iter)?;
-    let destination_roles_account = next_account_info(accounts_iter)?;
-    let proposal_account = next_account_info(accounts_iter)?;
+    let operator = next_account_info(accounts_iter)?;
+    let its_root_pda = next_account_info(accounts_iter)?;
+    let token_manager_pda = next_account_info(accounts_iter)?;
+    let token_manager_user_roles_pda = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+
+    event_cpi_accounts!(accounts_iter);
+
+    let its_config_pda = InterchainTokenService::load(its_root_pda)?;
+    assert_valid_its_root_pda(its_root_pda, its_config_pda.bump)?;
 
     validate_system_account_key(system_account.key)?;
 
-    let role_management_accounts = RoleTransferWithProposalAccounts {
-        system_account,
-        payer,
-        resource: token_manager_account,
-        destination_user_account,
-        destination_roles_account,
-        origin_user_account,
-        origin_roles_account,
-        proposal_account,
-    };
+    ensure_signer_roles(
+        &crate::id(),
+        token_manager_pda,
+        operator,
+        token_manager_user_roles_pda,
+        Roles::OPERATOR,
+    )?;
 
-    let its_config = InterchainTokenService::load(its_config_pda)?;
-    assert_valid_its_root_pda(its_config_pda, its_config.bump)?;
-    let token_manager = TokenManager::load(role_management_accounts.resource)?;
+    let mut token_manager = TokenManager::load(token_manager_pda)?;
     assert_valid_token_manager_pda(
-        role_management_accounts.resource,
-        its_config_pda.key,
+        token_manager_pda,
+        its_root_pda.key,
         &token_manager.token_id,
         token_manager.bump,
     )?;
 
-    role_management::processor::propose(&crate::id(), role_management_accounts, Roles::OPERATOR)
+    token_manager.destination_owner_policy = policy;
+    token_manager.store(payer, token_manager_pda, system_account)?;
+
+    emit_cpi!(events::DestinationOwnerPolicySet {
+        token_id: token_manager.token_id,
+        operator: *operator.key,
+        policy,
+    });
+
+    Ok(())
 }
 
-pub(crate) fn process_accept_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
-    msg!("Instruction: AcceptTokenManagerOperatorship");
+/// Caps the supply of a token ITS mints. The cap can't be lowered below the
+/// current supply, nor changed once set.
+pub(crate) fn process_set_max_supply<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    max_supply: u64,
+) -> ProgramResult {
+    msg!("Instruction: SetTokenManagerMaxSupply");
 
     let accounts_iter = &mut accounts.iter();
-    let its_config_pda = next_account_info(accounts_iter)?;
-    let system_account = next_account_info(accounts_iter)?;
     let payer = next_account_info(accounts_iter)?;
-    let destination_user_account = next_account_info(accounts_iter)?;
-    let destination_roles_account = next_account_info(accounts_iter)?;
-    let token_manager_account = next_account_info(accounts_iter)?;
-    let origin_user_account = next_account_info(accounts_iter)?;
-    let origin_roles_account = next_account_info(accounts_iter)?;
-    let proposal_account = next_account_info(accounts_iter)?;
+    let operator = next_account_info(accounts_iter)?;
+    let its_root_pda = next_account_info(accounts_iter)?;
+    let token_manager_pda = next_account_info(accounts_iter)?;
+    let token_manager_user_roles_pda = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+
+    event_cpi_accounts!(accounts_iter);
+
+    let its_config_pda = InterchainTokenService::load(its_root_pda)?;
+    assert_valid_its_root_pda(its_root_pda, its_config_pda.bump)?;
 
     validate_system_account_key(system_account.key)?;
 
-    let role_management_accounts = RoleTransferWithProposalAccounts {
-        system_account,
-        payer,
-        resource: token_manager_account,
-        destination_user_account,
-        destination_roles_account,
-        origin_user_account,
-        origin_roles_account,
-        proposal_account,
-    };
+    ensure_signer_roles(
+        &crate::id(),
+        token_manager_pda,
+        operator,
+        token_manager_user_roles_pda,
+        Roles::OPERATOR,
+    )?;
 
-    let its_config = InterchainTokenService::load(its_config_pda)?;
-    assert_valid_its_root_pda(its_config_pda, its_config.bump)?;
-    let token_manager = TokenManager::load(role_management_accounts.resource)?;
+    let mut token_manager = TokenManager::load(token_manager_pda)?;
     assert_valid_token_manager_pda(
-        role_management_accounts.resource,
-        its_config_pda.key,
+        token_manager_pda,
+        its_root_pda.key,
         &token_manager.token_id,
         token_manager.bump,
     )?;
 
-    role_management::processor::accept(&crate::id(), role_management_accounts, Roles::OPERATOR)
+    if !matches!(
+        token_manager.ty,
+        token_manager::Type::NativeInterchainToken
+            | token_manager::Type::MintBurn
+            | token_manager::Type::MintBurnFrom
+    ) {
+        msg!("Only tokens minted by ITS can have a maximum supply");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if token_manager.max_supply.is_some() {
+        return Err(ItsError::MaxSupplyAlreadySet.into());
+    }
+
+    if token_manager.token_address != *mint.key {
+        msg!("Mint and token ID don't match");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // The current supply must already be within the cap
+    token_manager.max_supply = Some(max_supply);
+    token_manager.ensure_within_max_supply(mint, 0)?;
+    token_manager.store(payer, token_manager_pda, system_account)?;
+
+    emit_cpi!(events::TokenManagerMaxSupplySet {
+        token_id: token_manager.token_id,
+        operator: *operator.key,
+        max_supply,
+    });
+
+    Ok(())
+}
+
+/// Records the token account the withheld fees of a
+/// [`token_manager::Type::LockUnlockFee`] token are harvested to.
+pub(crate) fn process_set_fee_collector<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    fee_collector: Option<Pubkey>,
+) -> ProgramResult {
+    msg!("Instruction: SetTokenManagerFeeCollector");
+
+    let accounts_iter = &mut accounts.iter();
+    let payer = next_account_info(accounts_iter)?;
+    let operator = next_account_info(accounts_iter)?;
+    let its_root_pda = next_account_info(accounts_iter)?;
+    let token_manager_pda = next_account_info(accounts_iter)?;
+    let token_manager_user_roles_pda = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+
+    event_cpi_accounts!(accounts_iter);
+
+    let its_config_pda = InterchainTokenService::load(its_root_pda)?;
+    assert_valid_its_root_pda(its_root_pda, its_config_pda.bump)?;
+
+    validate_system_account_key(system_account.key)?;
+
+    ensure_signer_roles(
+        &crate::id(),
+        token_manager_pda,
+        operator,
+        token_manager_user_roles_pda,
+        Roles::OPERATOR,
+    )?;
+
+    let mut token_manager = TokenManager::load(token_manager_pda)?;
+    assert_valid_token_manager_pda(
+        token_manager_pda,
+        its_root_pda.key,
+        &token_manager.token_id,
+        token_manager.bump,
+    )?;
+
+    if token_manager.ty != token_manager::Type::LockUnlockFee {
+        msg!("Only LockUnlockFee tokens can have a fee collector");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    token_manager.fee_collector = fee_collector;
+    token_manager.store(payer, token_manager_pda, system_account)?;
+
+    emit_cpi!(events::TokenManagerFeeCollectorSet {
+        token_id: token_manager.token_id,
+        operator: *operator.key,
+        fee_collector,
+    });
+
+    Ok(())
+}
+
+/// Withdraws the transfer fees withheld in the [`TokenManager`] associated
+/// token account, and in any other token account of the mint passed along,
+/// to `destination`, which must be the fee collector of the [`TokenManager`]
+/// when one is set.
+///
+/// Only [`token_manager::Type::LockUnlockFee`] tokens whose withdraw withheld
+/// authority is the [`TokenManager`] can be harvested through ITS, the
+/// authority of other mints can withdraw the fees directly.
+pub(crate) fn process_harvest_withheld_fees<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    msg!("Instruction: HarvestWithheldFees");
+
+    let accounts_iter = &mut accounts.iter();
+    let operator = next_account_info(accounts_iter)?;
+    let its_root_pda = next_account_info(accounts_iter)?;
+    let token_manager_pda = next_account_info(accounts_iter)?;
+    let token_manager_user_roles_pda = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let destination = next_account_info(accounts_iter)?;
+    let token_manager_ata = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+
+    event_cpi_accounts!(accounts_iter);
+    let additional_sources = accounts_iter.as_slice();
+
+    let its_config_pda = InterchainTokenService::load(its_root_pda)?;
+    assert_valid_its_root_pda(its_root_pda, its_config_pda.bump)?;
+
+    ensure_signer_roles(
+        &crate::id(),
+        token_manager_pda,
+        operator,
+        token_manager_user_roles_pda,
+        Roles::OPERATOR,
+    )?;
+
+    let token_manager = TokenManager::load(token_manager_pda)?;
+    assert_valid_token_manager_pda(
+        token_manager_pda,
+        its_root_pda.key,
+        &token_manager.token_id,
+        token_manager.bump,
+    )?;
+    token_manager.assert_token_program(mint, token_program.key)?;
+
+    if token_manager.ty != token_manager::Type::LockUnlockFee {
+        msg!("Only fees of LockUnlockFee tokens can be harvested");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if token_manager.token_address != *mint.key {
+        msg!("Mint and token ID don't match");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if token_manager.associated_token_account != *token_manager_ata.key {
+        msg!("Wrong TokenManager associated token account");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if let Some(fee_collector) = token_manager.fee_collector {
+        if fee_collector != *destination.key {
+            msg!(
+                "Withheld fees must be harvested to the fee collector {}",
+                fee_collector
+            );
+            return Err(ItsError::FeeCollectorMismatch.into());
+        }
+    }
+
+    ensure_withdraw_withheld_authority(mint, token_manager_pda.key)?;
+
+    let balance_before = token_account_balance(destination)?;
+
+    let sources = core::iter::once(token_manager_ata)
+        .chain(additional_sources)
+        .collect::<Vec<_>>();
+    let source_keys = sources.iter().map(|source| source.key).collect::<Vec<_>>();
+    let withdraw_ix = withdraw_withheld_tokens_from_accounts(
+        token_program.key,
+        mint.key,
+        destination.key,
+        token_manager_pda.key,
+        &[],
+        &source_keys,
+    )?;
+
+    let mut cpi_accounts = vec![
+        mint.clone(),
+        destination.clone(),
+        token_manager_pda.clone(),
+        token_program.clone(),
+    ];
+    cpi_accounts.extend(sources.into_iter().cloned());
+
+    invoke_signed(
+        &withdraw_ix,
+        &cpi_accounts,
+        &[&[
+            seed_prefixes::TOKEN_MANAGER_SEED,
+            its_root_pda.key.as_ref(),
+            &token_manager.token_id,
+            &[token_manager.bump],
+        ]],
+    )?;
+
+    let amount = token_account_balance(destination)?
+        .checked_sub(balance_before)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    emit_cpi!(events::WithheldFeesHarvested {
+        token_id: token_manager.token_id,
+        operator: *operator.key,
+        destination_token_account: *destination.key,
+        amount,
+    });
+
+    Ok(())
+}
+
+fn ensure_withdraw_withheld_authority(
+    mint: &AccountInfo<'_>,
+    token_manager_pda: &Pubkey,
+) -> ProgramResult {
+    let mint_data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+    let fee_config = mint_state.get_extension::<TransferFeeConfig>()?;
+    if Option::<Pubkey>::from(fee_config.withdraw_withheld_authority) != Some(*token_manager_pda) {
+        msg!("The TokenManager is not the withdraw withheld authority of the mint");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
+fn token_account_balance(token_account: &AccountInfo<'_>) -> Result<u64, ProgramError> {
+    let data = token_account.try_borrow_data()?;
+    let account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+
+    Ok(account.base.amount)
+}
+
+/// Logs the flow of the token in the current epoch and sets it as the return
+/// data, for wallets and programs that can't decode the [`TokenManager`].
+pub(crate) fn process_get_flow_status(accounts: &[AccountInfo<'_>]) -> ProgramResult {
+    msg!("Instruction: GetFlowStatus");
+
+    let accounts_iter = &mut accounts.iter();
+    let its_root_pda = next_account_info(accounts_iter)?;
+    let token_manager_pda = next_account_info(accounts_iter)?;
+
+    let its_config_pda = InterchainTokenService::load(its_root_pda)?;
+    assert_valid_its_root_pda(its_root_pda, its_config_pda.bump)?;
+
+    let token_manager = TokenManager::load(token_manager_pda)?;
+    assert_valid_token_manager_pda(
+        token_manager_pda,
+        its_root_pda.key,
+        &token_manager.token_id,
+        token_manager.bump,
+    )?;
+
+    let status = token_manager
+        .flow_slot
+        .status(Clock::get()?.unix_timestamp)?;
+
+    msg!(
+        "Flow limit: {:?}, flow in: {}, flow out: {}, epoch: {}, resets at: {}",
+        status.limit,
+        status.flow_in,
+        status.flow_out,
+        status.epoch,
+        status.resets_at
+    );
+    set_return_data(&borsh::to_vec(&status)?);
+
+    Ok(())
+}
+
+/// Checks the bindings recorded in the [`TokenManager`] against the ones
+/// derived from its token id and emits them as a
+/// [`events::TokenManagerVerified`] event. Mismatches are reported rather
+/// than failing the instruction, only accounts other than the recorded ones
+/// are rejected.
+pub(crate) fn process_verify<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    msg!("Instruction: VerifyTokenManager");
+
+    let accounts_iter = &mut accounts.iter();
+    let its_root_pda = next_account_info(accounts_iter)?;
+    let token_manager_pda = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let token_manager_ata = next_account_info(accounts_iter)?;
+
+    event_cpi_accounts!(accounts_iter);
+
+    let its_config_pda = InterchainTokenService::load(its_root_pda)?;
+    assert_valid_its_root_pda(its_root_pda, its_config_pda.bump)?;
+
+    let token_manager = TokenManager::load(token_manager_pda)?;
+    assert_valid_token_manager_pda(
+        token_manager_pda,
+        its_root_pda.key,
+        &token_manager.token_id,
+        token_manager.bump,
+    )?;
+
+    if token_manager.token_address != *mint.key {
+        msg!("Mint and token ID don't match");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if token_manager.associated_token_account != *token_manager_ata.key {
+        msg!("Wrong TokenManager associated token account");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let mut mismatches = Vec::new();
+
+    if token_manager.ty == token_manager::Type::NativeInterchainToken {
+        let (expected, _) =
+            crate::find_interchain_token_pda(its_root_pda.key, &token_manager.token_id);
+        if expected != token_manager.token_address {
+            mismatches.push(events::TokenManagerMismatch::TokenAddress { expected });
+        }
+    }
+
+    let token_program = token_manager.resolve_token_program(mint)?;
+    if *mint.owner != token_program {
+        mismatches.push(events::TokenManagerMismatch::TokenProgram {
+            mint_owner: *mint.owner,
+        });
+    }
+
+    let expected_ata = get_associated_token_address_with_program_id(
+        token_manager_pda.key,
+        &token_manager.token_address,
+        &token_program,
+    );
+    if expected_ata != token_manager.associated_token_account {
+        mismatches.push(events::TokenManagerMismatch::AssociatedTokenAccount {
+            expected: expected_ata,
+        });
+    }
+
+    let ata_data = token_manager_ata.try_borrow_data()?;
+    match StateWithExtensions::<spl_token_2022::state::Account>::unpack(&ata_data) {
+        Ok(ata) if *token_manager_ata.owner == token_program => {
+            if ata.base.mint != token_manager.token_address {
+                mismatches.push(events::TokenManagerMismatch::AssociatedTokenAccountMint {
+                    mint: ata.base.mint,
+                });
+            }
+            if ata.base.owner != *token_manager_pda.key {
+                mismatches.push(events::TokenManagerMismatch::AssociatedTokenAccountOwner {
+                    owner: ata.base.owner,
+                });
+            }
+        }
+        Ok(_) | Err(_) => {
+            mismatches.push(events::TokenManagerMismatch::AssociatedTokenAccountNotInitialized);
+        }
+    }
+
+    if !mismatches.is_empty() {
+        msg!("TokenManager has {} mismatching bindings", mismatches.len());
+    }
+
+    emit_cpi!(events::TokenManagerVerified {
+        token_id: token_manager.token_id,
+        token_manager: *token_manager_pda.key,
+        mismatches,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn process_resize<'a>(accounts: &'a [AccountInfo<'a>], new_size: u64) -> ProgramResult {
+    msg!("Instruction: ResizeTokenManager");
+
+    let accounts_iter = &mut accounts.iter();
+    let payer = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let authority_roles = next_account_info(accounts_iter)?;
+    let program_data = next_account_info(accounts_iter)?;
+    let its_root_pda = next_account_info(accounts_iter)?;
+    let token_manager_pda = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+
+    validate_system_account_key(system_account.key)?;
+
+    let its_config = InterchainTokenService::load(its_root_pda)?;
+    assert_valid_its_root_pda(its_root_pda, its_config.bump)?;
+
+    // No event accounts are passed, a previous operator is only logged
+    super::ensure_upgrade_authority_or_operator(
+        authority,
+        authority_roles,
+        program_data,
+        its_root_pda,
+        &its_config,
+    )?;
+
+    let token_manager = TokenManager::load(token_manager_pda)?;
+    assert_valid_token_manager_pda(
+        token_manager_pda,
+        its_root_pda.key,
+        &token_manager.token_id,
+        token_manager.bump,
+    )?;
+
+    let new_size: usize = new_size
+        .try_into()
+        .map_err(|_err| ProgramError::ArithmeticOverflow)?;
+
+    // Shrinking could drop data
+    if new_size < token_manager_pda.data_len() {
+        msg!(
+            "New token manager size {} is smaller than the current size {}",
+            new_size,
+            token_manager_pda.data_len()
+        );
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    token_manager::top_up_rent(payer, token_manager_pda, system_account, new_size)?;
+    token_manager_pda.realloc(new_size, true)?;
+
+    Ok(())
+}
+
+/// Folds a legacy [`FlowSlot`] PDA into the [`TokenManager`] flow state and
+/// closes it. Slots of past epochs are closed without being folded, as they no
+/// longer count towards the flow limit.
+pub(crate) fn process_migrate_flow_slot<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    epoch: u64,
+) -> ProgramResult {
+    msg!("Instruction: MigrateFlowSlot");
+
+    let accounts_iter = &mut accounts.iter();
+    let payer = next_account_info(accounts_iter)?;
+    let operator = next_account_info(accounts_iter)?;
+    let its_root_pda = next_account_info(accounts_iter)?;
+    let token_manager_pda = next_account_info(accounts_iter)?;
+    let token_manager_user_roles_pda = next_account_info(accounts_iter)?;
+    let flow_slot_pda = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+
+    event_cpi_accounts!(accounts_iter);
+
+    let its_config_pda = InterchainTokenService::load(its_root_pda)?;
+    assert_valid_its_root_pda(its_root_pda, its_config_pda.bump)?;
+
+    validate_system_account_key(system_account.key)?;
+
+    ensure_signer_roles(
+        &crate::id(),
+        token_manager_pda,
+        operator,
+        token_manager_user_roles_pda,
+        Roles::OPERATOR,
+    )?;
+
+    let mut token_manager = TokenManager::load(token_manager_pda)?;
+    assert_valid_token_manager_pda(
+        token_manager_pda,
+        its_root_pda.key,
+        &token_manager.token_id,
+        token_manager.bump,
+    )?;
+
+    let flow_slot = FlowSlot::load(flow_slot_pda)?;
+    let expected_flow_slot_pda =
+        crate::create_flow_slot_pda(token_manager_pda.key, epoch, flow_slot.bump)?;
+    if expected_flow_slot_pda.ne(flow_slot_pda.key) {
+        msg!("Invalid FlowSlot PDA provided");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let folded = epoch == current_flow_epoch()?;
+    if folded {
+        token_manager.flow_slot.fold_legacy(&flow_slot, epoch)?;
+        token_manager.store(payer, token_manager_pda, system_account)?;
+    }
+
+    close_pda(payer, flow_slot_pda, &crate::id())?;
+
+    emit_cpi!(events::FlowSlotMigrated {
+        token_id: token_manager.token_id,
+        flow_slot: *flow_slot_pda.key,
+        epoch,
+        flow_in: flow_slot.flow_in,
+        flow_out: flow_slot.flow_out,
+        folded,
+    });
+
+    Ok(())
+}
+
+/// Closes the [`TokenManager`] PDA, its associated token account, its
+/// [`TokenObserver`] PDA and the roles PDAs of the passed holders, handing the
+/// authorities the [`TokenManager`] holds over the mint back to
+/// `mint_authority`. Refused while the associated token account holds tokens,
+/// as they would be locked for good, or while roles PDAs over the token
+/// manager are left open.
+pub(crate) fn process_decommission<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    mint_authority: Option<Pubkey>,
+) -> ProgramResult {
+    msg!("Instruction: DecommissionTokenManager");
+
+    let accounts_iter = &mut accounts.iter();
+    let payer = next_account_info(accounts_iter)?;
+    let operator = next_account_info(accounts_iter)?;
+    let operator_roles = next_account_info(accounts_iter)?;
+    let its_root_pda = next_account_info(accounts_iter)?;
+    let token_manager_pda = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let token_manager_ata = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+    let token_observer_pda = next_account_info(accounts_iter)?;
+
+    event_cpi_accounts!(accounts_iter);
+
+    let its_config = InterchainTokenService::load(its_root_pda)?;
+    assert_valid_its_root_pda(its_root_pda, its_config.bump)?;
+
+    if let Some(expires_at_slot) =
+        super::ensure_operator(operator, operator_roles, its_root_pda, &its_config)?
+    {
+        emit_cpi!(events::GracePeriodOperatorUsed {
+            operator: *operator.key,
+            expires_at_slot,
+        });
+    }
+
+    let token_manager = TokenManager::load(token_manager_pda)?;
+    assert_valid_token_manager_pda(
+        token_manager_pda,
+        its_root_pda.key,
+        &token_manager.token_id,
+        token_manager.bump,
+    )?;
+    token_manager.assert_token_program(mint, token_program.key)?;
+
+    if token_manager.token_address != *mint.key {
+        msg!("Mint and token ID don't match");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if token_manager.associated_token_account != *token_manager_ata.key {
+        msg!("Wrong TokenManager associated token account");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let locked = token_account_balance(token_manager_ata)?;
+    if locked > 0 {
+        msg!("{} tokens are still locked in the TokenManager", locked);
+        return Err(ItsError::LockedTokensRemain.into());
+    }
+
+    let signer_seeds: &[&[u8]] = &[
+        seed_prefixes::TOKEN_MANAGER_SEED,
+        its_root_pda.key.as_ref(),
+        &token_manager.token_id,
+        &[token_manager.bump],
+    ];
+
+    hand_back_mint_authorities(
+        mint,
+        token_manager_pda,
+        token_program,
+        mint_authority,
+        signer_seeds,
+    )?;
+
+    let close_ata_ix = spl_token_2022::instruction::close_account(
+        token_program.key,
+        token_manager_ata.key,
+        payer.key,
+        token_manager_pda.key,
+        &[],
+    )?;
+    invoke_signed(
+        &close_ata_ix,
+        &[
+            token_manager_ata.clone(),
+            payer.clone(),
+            token_manager_pda.clone(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    if load_token_observer(token_observer_pda, token_manager_pda.key)?.is_some() {
+        close_pda(payer, token_observer_pda, &crate::id())?;
+    }
+
+    // Roles left behind would be granted again if the token were registered
+    // again, the holders are passed as (user, roles PDA) pairs
+    close_role_holders(payer, token_manager_pda, &token_manager, accounts_iter)?;
+
+    close_pda(payer, token_manager_pda, &crate::id())?;
+
+    emit_cpi!(events::TokenManagerDecommissioned {
+        token_id: token_manager.token_id,
+        token_manager: *token_manager_pda.key,
+        operator: *operator.key,
+        mint_authority,
+    });
+
+    Ok(())
+}
+
+/// Closes the roles PDAs passed as (user, roles PDA) pairs, refusing unless
+/// they are all of the [`TokenManager::role_holders`].
+fn close_role_holders<'a>(
+    payer: &AccountInfo<'a>,
+    token_manager_pda: &AccountInfo<'a>,
+    token_manager: &TokenManager,
+    accounts_iter: &mut core::slice::Iter<'a, AccountInfo<'a>>,
+) -> ProgramResult {
+    let mut closed_roles: u32 = 0;
+    while let Ok(user) = next_account_info(accounts_iter) {
+        let user_roles = next_account_info(accounts_iter)?;
+        ensure_proper_account::<Roles>(&crate::id(), token_manager_pda, user, user_roles)?;
+        close_pda(payer, user_roles, &crate::id())?;
+        closed_roles = closed_roles
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
+
+    match token_manager.role_holders {
+        Some(role_holders) if role_holders == closed_roles => Ok(()),
+        Some(role_holders) => {
+            msg!(
+                "{} roles PDAs were closed out of the {} held over the TokenManager",
+                closed_roles,
+                role_holders
+            );
+            Err(ItsError::RoleHoldersRemain.into())
+        }
+        None => {
+            msg!("The role holders of the TokenManager weren't counted");
+            Err(ItsError::RoleHoldersRemain.into())
+        }
+    }
+}
+
+/// Hands every authority the [`TokenManager`] holds over the mint back to
+/// `new_authority`, which is required when it holds any and rejected
+/// otherwise.
+///
+/// Metaplex metadata is immutable and keeps the closed [`TokenManager`] PDA
+/// as its update authority, unless it was handed over through
+/// `SetTokenMetadataAuthority` beforehand.
+fn hand_back_mint_authorities<'a>(
+    mint: &AccountInfo<'a>,
+    token_manager_pda: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    new_authority: Option<Pubkey>,
+    signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    let (held, holds_metadata) = held_mint_authorities(mint, token_manager_pda.key)?;
+    let new_authority = match (new_authority, held.is_empty() && !holds_metadata) {
+        (Some(new_authority), false) => new_authority,
+        (None, true) => return Ok(()),
+        (None, false) => {
+            msg!("The authorities of the token must be handed back to a designated account");
+            return Err(ProgramError::InvalidArgument);
+        }
+        (Some(_), true) => {
+            msg!("The TokenManager holds no authority over the token");
+            return Err(ProgramError::InvalidArgument);
+        }
+    };
+
+    for authority_type in held {
+        let authority_transfer_ix = spl_token_2022::instruction::set_authority(
+            token_program.key,
+            mint.key,
+            Some(&new_authority),
+            authority_type,
+            token_manager_pda.key,
+            &[],
+        )?;
+        invoke_signed(
+            &authority_transfer_ix,
+            &[mint.clone(), token_manager_pda.clone()],
+            &[signer_seeds],
+        )?;
+    }
+
+    if holds_metadata {
+        invoke_signed(
+            &update_token_metadata_authority(
+                token_program.key,
+                mint.key,
+                token_manager_pda.key,
+                Some(new_authority).try_into()?,
+            ),
+            &[mint.clone(), token_manager_pda.clone()],
+            &[signer_seeds],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Lists the authorities over the mint held by the [`TokenManager`], and
+/// whether it's the update authority of the on-mint `TokenMetadata`.
+fn held_mint_authorities(
+    mint: &AccountInfo<'_>,
+    token_manager_pda: &Pubkey,
+) -> Result<(Vec<AuthorityType>, bool), ProgramError> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+    let held_by_token_manager = |authority: Option<Pubkey>| authority == Some(*token_manager_pda);
+
+    let mut held = Vec::new();
+    if held_by_token_manager(mint_state.base.mint_authority.into()) {
+        held.push(AuthorityType::MintTokens);
+    }
+    if held_by_token_manager(mint_state.base.freeze_authority.into()) {
+        held.push(AuthorityType::FreezeAccount);
+    }
+    if let Ok(metadata_pointer) = mint_state.get_extension::<MetadataPointer>() {
+        if held_by_token_manager(metadata_pointer.authority.into()) {
+            held.push(AuthorityType::MetadataPointer);
+        }
+    }
+    if let Ok(fee_config) = mint_state.get_extension::<TransferFeeConfig>() {
+        if held_by_token_manager(fee_config.transfer_fee_config_authority.into()) {
+            held.push(AuthorityType::TransferFeeConfig);
+        }
+        if held_by_token_manager(fee_config.withdraw_withheld_authority.into()) {
+            held.push(AuthorityType::WithheldWithdraw);
+        }
+    }
+
+    let holds_metadata = mint_state
+        .get_variable_len_extension::<TokenMetadata>()
+        .is_ok_and(|metadata| held_by_token_manager(metadata.update_authority.into()));
+
+    Ok((held, holds_metadata))
+}
+
+pub(crate) fn process_transfer_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    msg!("Instruction: TransferTokenManagerOperatorship");
+
+    let accounts_iter = &mut accounts.iter();
+    let its_config_pda = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let origin_user_account = next_account_info(accounts_iter)?;
+    let origin_roles_account = next_account_info(accounts_iter)?;
+    let token_manager_account = next_account_info(accounts_iter)?;
+    let destination_user_account = next_account_info(accounts_iter)?;
+    let destination_roles_account = next_account_info(accounts_iter)?;
+
+    validate_system_account_key(system_account.key)?;
+
+    if origin_user_account.key == destination_user_account.key {
+        msg!("Source and destination accounts are the same");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let its_config = InterchainTokenService::load(its_config_pda)?;
+    let token_manager = TokenManager::load(token_manager_account)?;
+
+    assert_valid_its_root_pda(its_config_pda, its_config.bump)?;
+    assert_valid_token_manager_pda(
+        token_manager_account,
+        its_config_pda.key,
+        &token_manager.token_id,
+        token_manager.bump,
+    )?;
+
+    let role_add_accounts = RoleAddAccounts {
+        system_account,
+        payer,
+        authority_user_account: origin_user_account,
+        authority_roles_account: origin_roles_account,
+        resource: token_manager_account,
+        target_user_account: destination_user_account,
+        target_roles_account: destination_roles_account,
+    };
+    let role_remove_accounts = RoleRemoveAccounts {
+        system_account,
+        payer,
+        authority_user_account: origin_user_account,
+        authority_roles_account: origin_roles_account,
+        resource: token_manager_account,
+        target_user_account: origin_user_account,
+        target_roles_account: origin_roles_account,
+    };
+
+    let new_holder = destination_roles_account.data_is_empty();
+    role_management::processor::add(
+        &crate::id(),
+        role_add_accounts,
+        Roles::OPERATOR,
+        Roles::OPERATOR,
+    )?;
+    if new_holder {
+        record_role_holder(payer, token_manager_account, system_account)?;
+    }
+
+    role_management::processor::remove(
+        &crate::id(),
+        role_remove_accounts,
+        Roles::OPERATOR,
+        Roles::OPERATOR,
+    )
+}
+
+pub(crate) fn process_propose_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    msg!("Instruction: ProposeTokenManagerOperatorship");
+
+    let accounts_iter = &mut accounts.iter();
+    let its_config_pda = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let origin_user_account = next_account_info(accounts_iter)?;
+    let origin_roles_account = next_account_info(accounts_iter)?;
+    let token_manager_account = next_account_info(accounts_iter)?;
+    let destination_user_account = next_account_info(accounts_iter)?;
+    let destination_roles_account = next_account_info(accounts_iter)?;
+    let proposal_account = next_account_info(accounts_iter)?;
+
+    validate_system_account_key(system_account.key)?;
+
+    let role_management_accounts = RoleTransferWithProposalAccounts {
+        system_account,
+        payer,
+        resource: token_manager_account,
+        destination_user_account,
+        destination_roles_account,
+        origin_user_account,
+        origin_roles_account,
+        proposal_account,
+    };
+
+    let its_config = InterchainTokenService::load(its_config_pda)?;
+    assert_valid_its_root_pda(its_config_pda, its_config.bump)?;
+    let token_manager = TokenManager::load(role_management_accounts.resource)?;
+    assert_valid_token_manager_pda(
+        role_management_accounts.resource,
+        its_config_pda.key,
+        &token_manager.token_id,
+        token_manager.bump,
+    )?;
+
+    role_management::processor::propose(&crate::id(), role_management_accounts, Roles::OPERATOR)
+}
+
+pub(crate) fn process_accept_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    msg!("Instruction: AcceptTokenManagerOperatorship");
+
+    let accounts_iter = &mut accounts.iter();
+    let its_config_pda = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let destination_user_account = next_account_info(accounts_iter)?;
+    let destination_roles_account = next_account_info(accounts_iter)?;
+    let token_manager_account = next_account_info(accounts_iter)?;
+    let origin_user_account = next_account_info(accounts_iter)?;
+    let origin_roles_account = next_account_info(accounts_iter)?;
+    let proposal_account = next_account_info(accounts_iter)?;
+
+    validate_system_account_key(system_account.key)?;
+
+    let role_management_accounts = RoleTransferWithProposalAccounts {
+        system_account,
+        payer,
+        resource: token_manager_account,
+        destination_user_account,
+        destination_roles_account,
+        origin_user_account,
+        origin_roles_account,
+        proposal_account,
+    };
+
+    let its_config = InterchainTokenService::load(its_config_pda)?;
+    assert_valid_its_root_pda(its_config_pda, its_config.bump)?;
+    let token_manager = TokenManager::load(role_management_accounts.resource)?;
+    assert_valid_token_manager_pda(
+        role_management_accounts.resource,
+        its_config_pda.key,
+        &token_manager.token_id,
+        token_manager.bump,
+    )?;
+
+    let new_holder = destination_roles_account.data_is_empty();
+    role_management::processor::accept(&crate::id(), role_management_accounts, Roles::OPERATOR)?;
+    if new_holder {
+        record_role_holder(payer, token_manager_account, system_account)?;
+    }
+
+    Ok(())
 }
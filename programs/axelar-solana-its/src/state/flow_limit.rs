@@ -5,6 +5,7 @@ use core::time::Duration;
 
 use anchor_discriminators::Discriminator;
 use anchor_discriminators_macros::account;
+use borsh::{BorshDeserialize, BorshSerialize};
 use program_utils::pda::BorshPda;
 use solana_program::clock::Clock;
 use solana_program::entrypoint::ProgramResult;
@@ -48,6 +49,72 @@ impl FlowState {
         Self::update_flow(flow_limit, to_add, to_compare, amount)
     }
 
+    /// Returns the largest amount that can still flow in the given direction
+    /// without exceeding the flow limit, or `None` if there's no limit.
+    pub(crate) fn remaining_flow(&self, direction: FlowDirection) -> Option<u64> {
+        let flow_limit = self.flow_limit?;
+
+        let (current, opposite) = match direction {
+            FlowDirection::In => (self.flow_in, self.flow_out),
+            FlowDirection::Out => (self.flow_out, self.flow_in),
+        };
+
+        Some(
+            opposite
+                .saturating_add(flow_limit)
+                .saturating_sub(current)
+                .min(flow_limit),
+        )
+    }
+
+    /// Folds the counters of the legacy [`FlowSlot`] of `epoch` into the
+    /// state. Counters of a previous epoch are replaced, while the flow limit
+    /// is kept.
+    pub(crate) fn fold_legacy(&mut self, slot: &FlowSlot, epoch: u64) -> ProgramResult {
+        if self.epoch != epoch {
+            *self = Self::new(self.flow_limit, epoch);
+        }
+
+        self.flow_in = self
+            .flow_in
+            .checked_add(slot.flow_in)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.flow_out = self
+            .flow_out
+            .checked_add(slot.flow_out)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+
+    /// Returns the flow at `timestamp`. Counters recorded for a past epoch
+    /// are reported as zero, as the next transfer resets them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the timestamp can't be converted to a flow epoch.
+    pub fn status(&self, timestamp: i64) -> Result<FlowStatus, ProgramError> {
+        let epoch = flow_epoch_with_timestamp(timestamp)?;
+        let (flow_in, flow_out) = if self.epoch == epoch {
+            (self.flow_in, self.flow_out)
+        } else {
+            (0, 0)
+        };
+        let resets_at = epoch
+            .checked_add(1)
+            .and_then(|next_epoch| next_epoch.checked_mul(EPOCH_TIME.as_secs()))
+            .and_then(|secs| i64::try_from(secs).ok())
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        Ok(FlowStatus {
+            limit: self.flow_limit,
+            flow_in,
+            flow_out,
+            epoch,
+            resets_at,
+        })
+    }
+
     fn update_flow(
         flow_limit: u64,
         to_add: &mut u64,
@@ -88,6 +155,48 @@ impl FlowState {
 
 impl BorshPda for FlowState {}
 
+/// Legacy per-epoch flow accounting, stored in a dedicated PDA derived from
+/// the [`TokenManager`] and the epoch before it was embedded into the
+/// [`TokenManager`] as [`FlowState`].
+///
+/// Only read by [`MigrateFlowSlot`], which folds it into the
+/// [`TokenManager`] and closes it.
+///
+/// [`TokenManager`]: crate::state::token_manager::TokenManager
+/// [`MigrateFlowSlot`]: crate::instruction::InterchainTokenServiceInstruction::MigrateFlowSlot
+#[account]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct FlowSlot {
+    /// The flow limit at the time the slot was written.
+    pub flow_limit: Option<u64>,
+    /// Amount of tokens that flowed in during the epoch.
+    pub flow_in: u64,
+    /// Amount of tokens that flowed out during the epoch.
+    pub flow_out: u64,
+    /// The flow slot PDA bump seed.
+    pub bump: u8,
+}
+
+impl BorshPda for FlowSlot {}
+
+/// The flow of a token in the current epoch, as set in the return data of
+/// [`GetFlowStatus`].
+///
+/// [`GetFlowStatus`]: crate::instruction::InterchainTokenServiceInstruction::GetFlowStatus
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct FlowStatus {
+    /// The flow limit, `None` if the token isn't flow limited.
+    pub limit: Option<u64>,
+    /// Amount of tokens that flowed in during the epoch.
+    pub flow_in: u64,
+    /// Amount of tokens that flowed out during the epoch.
+    pub flow_out: u64,
+    /// The current flow epoch.
+    pub epoch: u64,
+    /// Unix timestamp at which the epoch ends and the counters are reset.
+    pub resets_at: i64,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum FlowDirection {
     In,
@@ -117,6 +226,36 @@ pub fn flow_epoch_with_timestamp(timestamp: i64) -> Result<u64, ProgramError> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_fold_legacy_flow_slot() {
+        let slot = FlowSlot {
+            flow_limit: Some(1),
+            flow_in: 20,
+            flow_out: 5,
+            bump: 255,
+        };
+
+        // Same epoch: counters add up
+        let mut state = FlowState::new(Some(100), 3);
+        state.add_flow(10, FlowDirection::In).unwrap();
+        state.fold_legacy(&slot, 3).unwrap();
+        assert_eq!((state.flow_in, state.flow_out, state.epoch), (30, 5, 3));
+
+        // Stale state: counters are replaced, limit is kept
+        let mut state = FlowState::new(Some(100), 2);
+        state.add_flow(10, FlowDirection::In).unwrap();
+        state.fold_legacy(&slot, 3).unwrap();
+        assert_eq!(
+            state,
+            FlowState {
+                flow_limit: Some(100),
+                flow_in: 20,
+                flow_out: 5,
+                epoch: 3,
+            }
+        );
+    }
+
     #[test]
     fn test_flow_slot_new_valid() {
         // Test valid creation of FlowSlot
@@ -278,6 +417,56 @@ mod tests {
         assert_eq!(slot.flow_in, 100); // Ensure flow_in hasn't changed
     }
 
+    #[test]
+    fn test_remaining_flow() {
+        let flow_limit = 100;
+        let mut slot = FlowState::new(Some(flow_limit), 0);
+        assert_eq!(slot.remaining_flow(FlowDirection::Out), Some(100));
+
+        slot.add_flow(70, FlowDirection::Out).unwrap();
+        assert_eq!(slot.remaining_flow(FlowDirection::Out), Some(30));
+        assert_eq!(slot.remaining_flow(FlowDirection::In), Some(100));
+
+        // Incoming flow frees up outgoing capacity, capped by the limit
+        slot.add_flow(50, FlowDirection::In).unwrap();
+        assert_eq!(slot.remaining_flow(FlowDirection::Out), Some(80));
+
+        // The remaining flow can always be added
+        slot.add_flow(80, FlowDirection::Out).unwrap();
+        assert_eq!(slot.remaining_flow(FlowDirection::Out), Some(0));
+
+        let unlimited = FlowState::new(None, 0);
+        assert_eq!(unlimited.remaining_flow(FlowDirection::Out), None);
+    }
+
+    #[test]
+    fn test_flow_status() {
+        let epoch_secs = i64::try_from(EPOCH_TIME.as_secs()).unwrap();
+        let mut state = FlowState::new(Some(100), 3);
+        state.add_flow(40, FlowDirection::In).unwrap();
+        state.add_flow(10, FlowDirection::Out).unwrap();
+
+        // Same epoch: counters are reported as stored
+        let status = state.status(3 * epoch_secs + 1).unwrap();
+        assert_eq!(
+            status,
+            FlowStatus {
+                limit: Some(100),
+                flow_in: 40,
+                flow_out: 10,
+                epoch: 3,
+                resets_at: 4 * epoch_secs,
+            }
+        );
+
+        // Next epoch: counters are about to be reset
+        let status = state.status(4 * epoch_secs).unwrap();
+        assert_eq!((status.flow_in, status.flow_out), (0, 0));
+        assert_eq!((status.epoch, status.resets_at), (4, 5 * epoch_secs));
+
+        assert_eq!(state.status(-1), Err(ProgramError::ArithmeticOverflow));
+    }
+
     #[test]
     fn test_flow_slot_initialization_with_direction() {
         // Test that FlowSlot initializes correctly based on transfer direction
@@ -1,19 +1,30 @@
 //! State module contains data structures that keep state within the ITS
 //! program.
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use anchor_discriminators::Discriminator;
 use anchor_discriminators_macros::account;
+use bitflags::bitflags;
+use borsh::{BorshDeserialize, BorshSerialize};
 use program_utils::pda::BorshPda;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::msg;
 use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::error::{ItsError, PayloadTooLarge};
 
 pub mod deploy_approval;
 pub mod flow_limit;
 pub mod interchain_transfer_execute;
+pub mod outbound_nonce;
 pub mod token_manager;
+pub mod token_observer;
+
+/// Maximum number of slots the previous ITS operator can keep being honored
+/// for after transferring the operatorship, roughly a day.
+pub const MAX_OPERATOR_GRACE_PERIOD_SLOTS: u64 = 216_000;
 
 /// Struct containing state of the ITS program.
 #[account]
@@ -24,14 +35,37 @@ pub struct InterchainTokenService {
     /// Name of the chain ITS is running on.
     pub chain_name: String,
 
-    /// Whether the ITS is paused.
-    pub paused: bool,
+    /// The paths of the ITS that are currently paused.
+    ///
+    /// Takes the place of the former `paused` flag: `true` was stored as the
+    /// [`PauseFlags::GLOBAL`] bit, so accounts written before load as they
+    /// are.
+    pub pause_flags: PauseFlags,
 
     /// Trusted chains
-    pub trusted_chains: HashSet<String>,
+    ///
+    /// Kept sorted so that the same set of chains always serializes to the
+    /// same bytes. Borsh encodes sets as a length prefixed ascending list,
+    /// so accounts written while this was a `HashSet` load as they are.
+    pub trusted_chains: BTreeSet<String>,
 
     /// Bump used to derive the ITS PDA.
     pub bump: u8,
+
+    /// Maximum size, in bytes, of the GMP payload sent towards a destination
+    /// chain. Chains without an entry are not limited.
+    #[appended]
+    pub max_payload_sizes: BTreeMap<String, u32>,
+
+    /// Limits applied when calling the destination program of an interchain
+    /// transfer with data. `None` applies no limits.
+    #[appended]
+    pub destination_execution_policy: Option<DestinationExecutionPolicy>,
+
+    /// The previous operator, still honored for ITS root operations until
+    /// its grace period expires. `None` when no grace period is running.
+    #[appended]
+    pub operator_grace_period: Option<OperatorGracePeriod>,
 }
 
 impl InterchainTokenService {
@@ -41,20 +75,36 @@ impl InterchainTokenService {
         Self {
             its_hub_address,
             chain_name,
-            paused: false,
-            trusted_chains: HashSet::new(),
+            pause_flags: PauseFlags::empty(),
+            trusted_chains: BTreeSet::new(),
+            max_payload_sizes: BTreeMap::new(),
+            destination_execution_policy: None,
+            operator_grace_period: None,
             bump,
         }
     }
 
     /// Pauses the Interchain Token Service.
     pub fn pause(&mut self) {
-        self.paused = true;
+        self.pause_flags.insert(PauseFlags::GLOBAL);
     }
 
-    /// Unpauses the Interchain Token Service.
+    /// Unpauses the Interchain Token Service. Paths paused individually
+    /// stay paused.
     pub fn unpause(&mut self) {
-        self.paused = false;
+        self.pause_flags.remove(PauseFlags::GLOBAL);
+    }
+
+    /// Replaces the set of paused paths.
+    pub fn set_pause_flags(&mut self, pause_flags: PauseFlags) {
+        self.pause_flags = pause_flags;
+    }
+
+    /// Whether the instructions of the `path` group are paused, either
+    /// individually or because the whole service is.
+    #[must_use]
+    pub const fn is_paused(&self, path: PauseFlags) -> bool {
+        self.pause_flags.intersects(PauseFlags::GLOBAL.union(path))
     }
 
     /// Returns the bump used to derive the ITS PDA.
@@ -78,11 +128,350 @@ impl InterchainTokenService {
         Ok(())
     }
 
+    /// Renames a trusted chain, carrying over the configuration kept for it
+    /// under the old name.
+    ///
+    /// # Errors
+    ///
+    /// [`ProgramError::InvalidArgument`] if the old chain isn't trusted or the
+    /// new chain already is.
+    pub fn rename_trusted_chain(
+        &mut self,
+        old_chain_id: &str,
+        new_chain_id: String,
+    ) -> ProgramResult {
+        if self.trusted_chains.contains(&new_chain_id) {
+            msg!(
+                "Chain '{}' is already in the trusted chains list",
+                new_chain_id
+            );
+            return Err(ProgramError::InvalidArgument);
+        }
+        self.remove_trusted_chain(old_chain_id)?;
+
+        if let Some(max_size) = self.max_payload_sizes.remove(old_chain_id) {
+            self.max_payload_sizes
+                .insert(new_chain_id.clone(), max_size);
+        }
+        self.trusted_chains.insert(new_chain_id);
+
+        Ok(())
+    }
+
     /// Checks whether or not a given chain is trusted
     #[must_use]
     pub fn is_trusted_chain(&self, chain_id: &str) -> bool {
         self.trusted_chains.contains(chain_id)
     }
+
+    /// Sets the maximum payload size towards a chain, `None` removes the limit.
+    pub fn set_max_payload_size(&mut self, chain_id: String, max_size: Option<u32>) {
+        match max_size {
+            Some(max_size) => {
+                self.max_payload_sizes.insert(chain_id, max_size);
+            }
+            None => {
+                self.max_payload_sizes.remove(&chain_id);
+            }
+        }
+    }
+
+    /// Checks a payload of `size` bytes against the limit configured for the
+    /// destination chain.
+    ///
+    /// # Errors
+    ///
+    /// [`PayloadTooLarge`] carrying the actual and maximum sizes when the
+    /// payload exceeds the limit.
+    pub fn check_payload_size(&self, chain_id: &str, size: usize) -> Result<(), PayloadTooLarge> {
+        let Some(&max) = self.max_payload_sizes.get(chain_id) else {
+            return Ok(());
+        };
+
+        if u32::try_from(size).map_or(true, |size| size > max) {
+            return Err(PayloadTooLarge {
+                destination_chain: chain_id.to_owned(),
+                actual: size,
+                max,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Checks a call to the destination program of an interchain transfer
+    /// with data against the [`DestinationExecutionPolicy`], if any.
+    ///
+    /// # Errors
+    ///
+    /// * [`ItsError::TooManyDestinationAccounts`] when more than
+    ///   [`DestinationExecutionPolicy::max_accounts`] accounts are forwarded.
+    /// * [`ItsError::InsufficientDestinationComputeUnits`] when less than
+    ///   [`DestinationExecutionPolicy::min_compute_units`] remain.
+    pub fn check_destination_execution(
+        &self,
+        forwarded_accounts: usize,
+        remaining_compute_units: u64,
+    ) -> Result<(), ItsError> {
+        let Some(policy) = self.destination_execution_policy else {
+            return Ok(());
+        };
+
+        if forwarded_accounts > usize::from(policy.max_accounts) {
+            msg!(
+                "Forwarding {} accounts to the destination program, the maximum is {}",
+                forwarded_accounts,
+                policy.max_accounts
+            );
+            return Err(ItsError::TooManyDestinationAccounts);
+        }
+
+        if remaining_compute_units < policy.min_compute_units {
+            msg!(
+                "{} compute units left for the destination program, at least {} are required",
+                remaining_compute_units,
+                policy.min_compute_units
+            );
+            return Err(ItsError::InsufficientDestinationComputeUnits);
+        }
+
+        Ok(())
+    }
+}
+
+bitflags! {
+    /// Groups of ITS instructions that can be paused independently.
+    #[derive(Debug, Eq, PartialEq, Clone, Copy)]
+    pub struct PauseFlags: u8 {
+        /// Pauses every group.
+        const GLOBAL = 0b0000_0001;
+
+        /// Interchain transfers received from other chains.
+        const INBOUND_TRANSFERS = 0b0000_0010;
+
+        /// Interchain transfers sent to other chains.
+        const OUTBOUND_TRANSFERS = 0b0000_0100;
+
+        /// Local, remote and inbound interchain token deployments, and the
+        /// registration of canonical tokens.
+        const DEPLOYMENTS = 0b0000_1000;
+
+        /// Custom token registration, token metadata registration and token
+        /// linking, in both directions.
+        const LINK_OPERATIONS = 0b0001_0000;
+    }
+}
+
+impl BorshSerialize for PauseFlags {
+    fn serialize<W: std::io::prelude::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.bits().serialize(writer)
+    }
+}
+
+impl BorshDeserialize for PauseFlags {
+    fn deserialize_reader<R: std::io::prelude::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let byte = u8::deserialize_reader(reader)?;
+        Ok(Self::from_bits_truncate(byte))
+    }
+}
+
+/// Limits applied when ITS calls the destination program of an interchain
+/// transfer with data.
+///
+/// The runtime doesn't allow to bound the compute units consumed by a CPI,
+/// so the compute limit is a reserve: the destination program is only
+/// called if at least `min_compute_units` remain, and relayers can tell an
+/// under-provisioned transaction from a faulty destination program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BorshSerialize, BorshDeserialize)]
+pub struct DestinationExecutionPolicy {
+    /// Maximum number of accounts forwarded to the destination program, on
+    /// top of the ones ITS always passes.
+    pub max_accounts: u8,
+    /// Compute units that must remain when the destination program is
+    /// called.
+    pub min_compute_units: u64,
+}
+
+/// An operator that keeps being honored for ITS root operations for a few
+/// slots after transferring the operatorship, so that automation still
+/// signing with the old key doesn't fail while it's being rotated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BorshSerialize, BorshDeserialize)]
+pub struct OperatorGracePeriod {
+    /// The account the operatorship was transferred from.
+    pub previous_operator: Pubkey,
+    /// The last slot the previous operator is honored at.
+    pub expires_at_slot: u64,
+}
+
+impl OperatorGracePeriod {
+    /// Whether `account` is the previous operator and its grace period is
+    /// still running at `slot`.
+    #[must_use]
+    pub fn honors(&self, account: &Pubkey, slot: u64) -> bool {
+        self.previous_operator == *account && slot <= self.expires_at_slot
+    }
 }
 
 impl BorshPda for InterchainTokenService {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use borsh::to_vec;
+
+    use super::*;
+
+    const CHAINS: [&str; 5] = ["ethereum", "avalanche", "sui", "fantom", "polygon"];
+
+    fn its_root<'a>(chains: impl IntoIterator<Item = &'a str>) -> InterchainTokenService {
+        let mut its_root = InterchainTokenService::new(255, "solana".to_owned(), "hub".to_owned());
+        for (max_size, chain) in (1_u32..).zip(chains) {
+            its_root.add_trusted_chain(chain.to_owned());
+            its_root.set_max_payload_size(chain.to_owned(), Some(max_size * 100));
+        }
+        its_root
+    }
+
+    /// Layout of [`InterchainTokenService`] before the chain collections
+    /// were sorted and the `#[appended]` fields were added.
+    #[derive(BorshSerialize)]
+    struct HashedInterchainTokenService {
+        its_hub_address: String,
+        chain_name: String,
+        paused: bool,
+        trusted_chains: HashSet<String>,
+        bump: u8,
+    }
+
+    /// An ITS root holding only what the hashed layout can store.
+    fn legacy_its_root() -> InterchainTokenService {
+        let mut its_root = InterchainTokenService::new(255, "solana".to_owned(), "hub".to_owned());
+        for chain in CHAINS {
+            its_root.add_trusted_chain(chain.to_owned());
+        }
+        its_root
+    }
+
+    fn hashed_bytes(its_root: &InterchainTokenService, paused: bool) -> Vec<u8> {
+        let hashed = HashedInterchainTokenService {
+            its_hub_address: its_root.its_hub_address.clone(),
+            chain_name: its_root.chain_name.clone(),
+            paused,
+            trusted_chains: its_root.trusted_chains.iter().cloned().collect(),
+            bump: its_root.bump,
+        };
+
+        [
+            InterchainTokenService::DISCRIMINATOR,
+            &to_vec(&hashed).unwrap(),
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn test_serialization_is_independent_of_insertion_order() {
+        let forward = to_vec(&its_root(CHAINS)).unwrap();
+        let reversed = {
+            let mut its_root = its_root(CHAINS.into_iter().rev());
+            // Sizes are assigned by insertion order, align them
+            for (max_size, chain) in (1_u32..).zip(CHAINS) {
+                its_root.set_max_payload_size(chain.to_owned(), Some(max_size * 100));
+            }
+            to_vec(&its_root).unwrap()
+        };
+
+        assert_eq!(forward, reversed);
+    }
+
+    #[test]
+    fn test_hashed_layout_loads_with_defaults() {
+        let its_root = legacy_its_root();
+        let hashed_bytes = hashed_bytes(&its_root, false);
+
+        let loaded = InterchainTokenService::try_from_slice(&hashed_bytes).unwrap();
+
+        assert_eq!(loaded, its_root);
+        assert!(loaded.max_payload_sizes.is_empty());
+        assert_eq!(loaded.destination_execution_policy, None);
+        assert_eq!(loaded.operator_grace_period, None);
+        // The appended fields are written after the legacy bytes
+        assert!(to_vec(&loaded).unwrap().starts_with(&hashed_bytes));
+    }
+
+    #[test]
+    fn test_legacy_paused_flag_loads_as_global_pause() {
+        let mut its_root = legacy_its_root();
+        its_root.pause();
+        let hashed_bytes = hashed_bytes(&its_root, true);
+
+        let loaded = InterchainTokenService::try_from_slice(&hashed_bytes).unwrap();
+
+        assert_eq!(loaded, its_root);
+        assert!(loaded.is_paused(PauseFlags::INBOUND_TRANSFERS));
+        assert!(loaded.is_paused(PauseFlags::LINK_OPERATIONS));
+    }
+
+    #[test]
+    fn test_pause_flags_pause_only_their_group() {
+        let mut its_root = its_root(CHAINS);
+        its_root.set_pause_flags(PauseFlags::OUTBOUND_TRANSFERS | PauseFlags::DEPLOYMENTS);
+
+        assert!(its_root.is_paused(PauseFlags::OUTBOUND_TRANSFERS));
+        assert!(its_root.is_paused(PauseFlags::DEPLOYMENTS));
+        assert!(!its_root.is_paused(PauseFlags::INBOUND_TRANSFERS));
+        assert!(!its_root.is_paused(PauseFlags::LINK_OPERATIONS));
+
+        its_root.pause();
+        assert!(its_root.is_paused(PauseFlags::INBOUND_TRANSFERS));
+
+        its_root.unpause();
+        assert!(!its_root.is_paused(PauseFlags::INBOUND_TRANSFERS));
+        assert!(its_root.is_paused(PauseFlags::OUTBOUND_TRANSFERS));
+    }
+
+    #[test]
+    fn test_rename_trusted_chain_moves_configuration() {
+        let mut its_root = its_root(["polygon", "ethereum"]);
+
+        its_root
+            .rename_trusted_chain("polygon", "polygon-amoy".to_owned())
+            .unwrap();
+
+        assert!(!its_root.is_trusted_chain("polygon"));
+        assert!(its_root.is_trusted_chain("polygon-amoy"));
+        assert_eq!(its_root.max_payload_sizes.get("polygon"), None);
+        assert_eq!(its_root.max_payload_sizes.get("polygon-amoy"), Some(&100));
+        assert_eq!(its_root.max_payload_sizes.get("ethereum"), Some(&200));
+    }
+
+    #[test]
+    fn test_rename_trusted_chain_rejects_unknown_or_taken_names() {
+        let mut its_root = its_root(["polygon", "ethereum"]);
+        let before = its_root.clone();
+
+        assert_eq!(
+            its_root.rename_trusted_chain("sui", "sui-testnet".to_owned()),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            its_root.rename_trusted_chain("polygon", "ethereum".to_owned()),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(its_root, before);
+    }
+
+    #[test]
+    fn test_operator_grace_period_honors_previous_operator_until_expiry() {
+        let previous_operator = Pubkey::new_unique();
+        let grace_period = OperatorGracePeriod {
+            previous_operator,
+            expires_at_slot: 100,
+        };
+
+        assert!(grace_period.honors(&previous_operator, 99));
+        assert!(grace_period.honors(&previous_operator, 100));
+        assert!(!grace_period.honors(&previous_operator, 101));
+        assert!(!grace_period.honors(&Pubkey::new_unique(), 100));
+    }
+}
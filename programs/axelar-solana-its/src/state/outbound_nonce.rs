@@ -0,0 +1,30 @@
+//! State module contains data structures that keep state within the ITS
+//! program.
+
+use anchor_discriminators::Discriminator;
+use anchor_discriminators_macros::account;
+use program_utils::pda::BorshPda;
+
+/// Per-sender counter of outbound messages (`InterchainTransfer` and
+/// `LinkToken`), used to order the events emitted on behalf of a sender.
+#[account]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct OutboundNonce {
+    /// The nonce that will be assigned to the next outbound message.
+    pub next_nonce: u64,
+    /// The outbound nonce PDA bump seed.
+    pub bump: u8,
+}
+
+impl OutboundNonce {
+    /// Creates a new `OutboundNonce` struct.
+    #[must_use]
+    pub const fn new(bump: u8) -> Self {
+        Self {
+            next_nonce: 0,
+            bump,
+        }
+    }
+}
+
+impl BorshPda for OutboundNonce {}
@@ -4,11 +4,21 @@
 use alloy_primitives::U256;
 use anchor_discriminators::Discriminator;
 use anchor_discriminators_macros::account;
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::{to_vec, BorshDeserialize, BorshSerialize};
 use program_utils::pda::BorshPda;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program::invoke;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+use spl_token_2022::extension::StateWithExtensions;
+use spl_token_2022::state::Mint;
 
+use crate::error::ItsError;
 use crate::state::flow_limit::FlowState;
 
 /// There are different types of token managers available for developers to
@@ -134,6 +144,26 @@ impl TryFrom<u8> for Type {
     }
 }
 
+/// How inbound transfers handle a destination associated token account that
+/// exists but is no longer owned by the destination address.
+///
+/// SPL Token accounts, unlike Token-2022 ones, don't have the
+/// `ImmutableOwner` extension and their owner can be changed after creation.
+#[derive(
+    Debug, Default, Eq, PartialEq, PartialOrd, Ord, Clone, Copy, BorshSerialize, BorshDeserialize,
+)]
+pub enum DestinationOwnerPolicy {
+    /// Reject the transfer, tokens must reach an account owned by the
+    /// destination address.
+    #[default]
+    Strict,
+
+    /// Transfer to the associated token account anyway and emit a
+    /// [`crate::events::DestinationOwnerMismatch`] event. Meant for legacy
+    /// integrations relying on associated token accounts that changed owners.
+    Permissive,
+}
+
 /// Struct containing state of a `TokenManager`
 #[account]
 #[derive(Debug, Eq, PartialEq, Clone)]
@@ -155,6 +185,38 @@ pub struct TokenManager {
 
     /// The token manager PDA bump seed.
     pub bump: u8,
+
+    /// How inbound transfers handle destination accounts that changed owners.
+    #[appended]
+    pub destination_owner_policy: DestinationOwnerPolicy,
+
+    /// The token program owning the mint, either `spl_token` or
+    /// `spl_token_2022`. Token managers created before it was recorded load
+    /// `Pubkey::default()`, see [`TokenManager::resolve_token_program`].
+    #[appended]
+    pub token_program: Pubkey,
+
+    /// Whether the Metaplex metadata update authority was handed over to an
+    /// external key. Once set, ITS no longer controls the token metadata.
+    #[appended]
+    pub metadata_authority_delegated: bool,
+
+    /// Hard cap on the supply of the mint, enforced whenever ITS mints the
+    /// token. Set once by the operator, `None` if uncapped.
+    #[appended]
+    pub max_supply: Option<u64>,
+
+    /// The token account withheld transfer fees of a
+    /// [`Type::LockUnlockFee`] token are harvested to. Set by the operator,
+    /// `None` if the operator picks the destination on every harvest.
+    #[appended]
+    pub fee_collector: Option<Pubkey>,
+
+    /// The number of roles PDAs held over this token manager, all of which
+    /// must be closed to decommission it. `None` for token managers created
+    /// before the holders were counted.
+    #[appended]
+    pub role_holders: Option<u32>,
 }
 
 impl TokenManager {
@@ -165,6 +227,7 @@ impl TokenManager {
         token_id: [u8; 32],
         token_address: Pubkey,
         associated_token_account: Pubkey,
+        token_program: Pubkey,
         bump: u8,
     ) -> Self {
         Self {
@@ -173,9 +236,267 @@ impl TokenManager {
             token_address,
             associated_token_account,
             flow_slot: FlowState::new(None, 0),
+            destination_owner_policy: DestinationOwnerPolicy::Strict,
+            token_program,
+            metadata_authority_delegated: false,
+            max_supply: None,
+            fee_collector: None,
+            role_holders: Some(0),
             bump,
         }
     }
+
+    /// The token program owning the mint managed by this token manager.
+    /// Token managers that didn't record it fall back to the owner of `mint`.
+    ///
+    /// # Errors
+    ///
+    /// [`ProgramError::InvalidAccountData`] if `mint` isn't the managed mint.
+    pub fn resolve_token_program(&self, mint: &AccountInfo<'_>) -> Result<Pubkey, ProgramError> {
+        if *mint.key != self.token_address {
+            msg!("Mint and token ID don't match");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if self.token_program == Pubkey::default() {
+            return Ok(*mint.owner);
+        }
+
+        Ok(self.token_program)
+    }
+
+    /// Ensures `token_program` is the token program owning `mint`, the mint
+    /// managed by this token manager.
+    ///
+    /// # Errors
+    ///
+    /// - [`ProgramError::IncorrectProgramId`] if the programs don't match.
+    /// - [`ProgramError::InvalidAccountData`] if `mint` isn't the managed mint.
+    pub fn assert_token_program(
+        &self,
+        mint: &AccountInfo<'_>,
+        token_program: &Pubkey,
+    ) -> ProgramResult {
+        let expected = self.resolve_token_program(mint)?;
+        if expected != *token_program {
+            msg!(
+                "Token manager expects token program {}, got {}",
+                expected,
+                token_program
+            );
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        Ok(())
+    }
+
+    /// Ensures minting `amount` more tokens of `mint` keeps its supply within
+    /// [`TokenManager::max_supply`].
+    ///
+    /// # Errors
+    ///
+    /// - [`ItsError::MaxSupplyExceeded`] if the supply would exceed the cap.
+    /// - [`ProgramError`] if `mint` can't be unpacked.
+    pub fn ensure_within_max_supply(&self, mint: &AccountInfo<'_>, amount: u64) -> ProgramResult {
+        let Some(max_supply) = self.max_supply else {
+            return Ok(());
+        };
+
+        let mint_data = mint.try_borrow_data()?;
+        let supply = StateWithExtensions::<Mint>::unpack(&mint_data)?.base.supply;
+
+        if supply
+            .checked_add(amount)
+            .is_none_or(|new_supply| new_supply > max_supply)
+        {
+            msg!(
+                "Minting {} tokens on top of a supply of {} exceeds the maximum of {}",
+                amount,
+                supply,
+                max_supply
+            );
+            return Err(ItsError::MaxSupplyExceeded.into());
+        }
+
+        Ok(())
+    }
 }
 
-impl BorshPda for TokenManager {}
+impl BorshPda for TokenManager {
+    /// Stores the token manager, topping up the rent of the account from
+    /// `payer`.
+    ///
+    /// The account grows when needed but never shrinks, keeping the room
+    /// reserved with
+    /// [`crate::instruction::InterchainTokenServiceInstruction::ResizeTokenManager`].
+    fn store<'a>(
+        &self,
+        payer: &AccountInfo<'a>,
+        destination: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        let serialized_data = to_vec(self)?;
+        let data_len = serialized_data.len().max(destination.data_len());
+
+        top_up_rent(payer, destination, system_program, data_len)?;
+        if data_len > destination.data_len() {
+            destination.realloc(data_len, true)?;
+        }
+
+        let mut account_data = destination.try_borrow_mut_data()?;
+        let (data, reserved) = account_data.split_at_mut(serialized_data.len());
+        data.copy_from_slice(&serialized_data);
+        reserved.fill(0);
+
+        Ok(())
+    }
+
+    /// Loads the token manager, ignoring the reserved bytes after it.
+    ///
+    /// Accounts created before the `#[appended]` fields existed end right
+    /// after `bump` and load with those fields defaulted, so they can still
+    /// be resized with
+    /// [`crate::instruction::InterchainTokenServiceInstruction::ResizeTokenManager`].
+    fn load(source_account: &AccountInfo<'_>) -> Result<Self, ProgramError> {
+        let account_data = source_account.try_borrow_data()?;
+        let mut data: &[u8] = &account_data;
+
+        Self::deserialize(&mut data).map_err(|err| {
+            msg!(
+                "Warning: failed to deserialize account as TokenManager: {}. The account might not have been initialized.",
+                err,
+            );
+            ProgramError::from(err)
+        })
+    }
+}
+
+/// Transfers lamports from `payer` to `account` so that it's rent exempt with
+/// `data_len` bytes of data.
+///
+/// # Errors
+///
+/// - [`ProgramError::MissingRequiredSignature`] if a top-up is needed and
+///   `payer` isn't a writable signer.
+/// - [`ProgramError::InsufficientFunds`] if `payer` can't cover the top-up.
+pub(crate) fn top_up_rent<'a>(
+    payer: &AccountInfo<'a>,
+    account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    data_len: usize,
+) -> ProgramResult {
+    let lamports_needed = Rent::get()?
+        .minimum_balance(data_len)
+        .saturating_sub(account.lamports());
+
+    if lamports_needed == 0 {
+        return Ok(());
+    }
+
+    if !payer.is_signer || !payer.is_writable {
+        msg!("Rent payer must be a writable signer");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if payer.lamports() < lamports_needed {
+        msg!(
+            "Rent payer can't cover the {} lamports needed by {}",
+            lamports_needed,
+            account.key
+        );
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    invoke(
+        &system_instruction::transfer(payer.key, account.key, lamports_needed),
+        &[payer.clone(), account.clone(), system_program.clone()],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Layout of [`TokenManager`] before the `#[appended]` fields.
+    #[derive(BorshSerialize)]
+    struct LegacyTokenManager {
+        ty: Type,
+        token_id: [u8; 32],
+        token_address: Pubkey,
+        associated_token_account: Pubkey,
+        flow_slot: FlowState,
+        bump: u8,
+    }
+
+    fn legacy_bytes(reserved: usize) -> (TokenManager, Vec<u8>) {
+        let legacy = LegacyTokenManager {
+            ty: Type::LockUnlock,
+            token_id: [7; 32],
+            token_address: Pubkey::new_unique(),
+            associated_token_account: Pubkey::new_unique(),
+            flow_slot: FlowState::new(Some(1_000), 42),
+            bump: 254,
+        };
+        let expected = TokenManager {
+            ty: legacy.ty,
+            token_id: legacy.token_id,
+            token_address: legacy.token_address,
+            associated_token_account: legacy.associated_token_account,
+            flow_slot: legacy.flow_slot.clone(),
+            bump: legacy.bump,
+            destination_owner_policy: DestinationOwnerPolicy::Strict,
+            token_program: Pubkey::default(),
+            metadata_authority_delegated: false,
+            max_supply: None,
+            fee_collector: None,
+            role_holders: None,
+        };
+        let bytes = [
+            TokenManager::DISCRIMINATOR,
+            &to_vec(&legacy).unwrap(),
+            &vec![0; reserved],
+        ]
+        .concat();
+
+        (expected, bytes)
+    }
+
+    #[test]
+    fn test_legacy_layout_loads_with_defaults() {
+        let (expected, bytes) = legacy_bytes(0);
+
+        let loaded = TokenManager::deserialize(&mut bytes.as_slice()).unwrap();
+
+        assert_eq!(loaded, expected);
+    }
+
+    #[test]
+    fn test_reserved_bytes_of_legacy_layout_load_as_defaults() {
+        let (expected, bytes) = legacy_bytes(128);
+
+        let loaded = TokenManager::deserialize(&mut bytes.as_slice()).unwrap();
+
+        assert_eq!(loaded, expected);
+    }
+
+    #[test]
+    fn test_current_layout_round_trips() {
+        let mut token_manager = TokenManager::new(
+            Type::LockUnlockFee,
+            [9; 32],
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            spl_token_2022::ID,
+            253,
+        );
+        token_manager.destination_owner_policy = DestinationOwnerPolicy::Permissive;
+        token_manager.max_supply = Some(1_000_000);
+        token_manager.fee_collector = Some(Pubkey::new_unique());
+        token_manager.role_holders = Some(2);
+
+        let bytes = to_vec(&token_manager).unwrap();
+        let loaded = TokenManager::deserialize(&mut bytes.as_slice()).unwrap();
+
+        assert_eq!(loaded, token_manager);
+    }
+}
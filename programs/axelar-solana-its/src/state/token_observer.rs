@@ -0,0 +1,32 @@
+//! State module contains data structures that keep state within the ITS
+//! program.
+
+use anchor_discriminators::Discriminator;
+use anchor_discriminators_macros::account;
+use program_utils::pda::BorshPda;
+use solana_program::pubkey::Pubkey;
+
+/// The program registered by the operator of a [`TokenManager`] to be
+/// notified about every interchain transfer of the token.
+///
+/// [`TokenManager`]: crate::state::token_manager::TokenManager
+#[account]
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct TokenObserver {
+    /// The observer program, called with a
+    /// [`TransferNotification`](crate::observer::TransferNotification) on
+    /// each transfer.
+    pub program_id: Pubkey,
+    /// The token observer PDA bump seed.
+    pub bump: u8,
+}
+
+impl TokenObserver {
+    /// Creates a new `TokenObserver` struct.
+    #[must_use]
+    pub const fn new(program_id: Pubkey, bump: u8) -> Self {
+        Self { program_id, bump }
+    }
+}
+
+impl BorshPda for TokenObserver {}
@@ -0,0 +1,154 @@
+use axelar_solana_its::events::{AtaRentDebit, InterchainTransferReceivedV2};
+use axelar_solana_its::inbound_rent::InboundRentEstimate;
+use axelar_solana_its_test_fixtures::gmp::receive_from_hub;
+use axelar_solana_its_test_fixtures::SolanaItsIntegration;
+use axelar_solana_its_test_fixtures::{SolanaItsIntegrationMetadata, TestToken};
+use borsh::BorshDeserialize;
+use event_cpi_test_utils::get_first_event_cpi_occurrence;
+use interchain_token_transfer_gmp::alloy_primitives::{Bytes, U256};
+use interchain_token_transfer_gmp::{GMPPayload, InterchainTransfer};
+use solana_program_test::tokio;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer as _;
+
+/// The hub payload of a transfer of `token` to `destination`.
+fn transfer_payload(token: &TestToken, destination: Pubkey) -> GMPPayload {
+    receive_from_hub(
+        "ethereum",
+        &GMPPayload::InterchainTransfer(InterchainTransfer {
+            selector: U256::from(InterchainTransfer::MESSAGE_TYPE_ID),
+            token_id: token.token_id.into(),
+            source_address: Bytes::from(vec![0xAB; 20]),
+            destination_address: Bytes::from(destination.to_bytes().to_vec()),
+            amount: U256::from(100_u64),
+            data: Bytes::new(),
+        }),
+    )
+}
+
+/// Simulates an `EstimateInboundRent` for a transfer of `token` to
+/// `destination`.
+async fn estimate_rent(
+    its: &mut SolanaItsIntegrationMetadata,
+    token: &TestToken,
+    destination: Pubkey,
+) -> InboundRentEstimate {
+    let ix = axelar_solana_its::instruction::estimate_inbound_rent(
+        transfer_payload(token, destination).encode(),
+        token.mint,
+        token.token_program,
+    )
+    .unwrap();
+    let return_data = its
+        .simulate_tx(&[ix])
+        .await
+        .unwrap()
+        .simulation_details
+        .unwrap()
+        .return_data
+        .unwrap();
+    assert_eq!(return_data.program_id, axelar_solana_its::id());
+
+    InboundRentEstimate::try_from_slice(&return_data.data).unwrap()
+}
+
+/// Relays a transfer of `token` to `destination` and returns the
+/// `InterchainTransferReceivedV2` event it emitted.
+async fn relay_transfer(
+    its: &mut SolanaItsIntegrationMetadata,
+    token: &TestToken,
+    destination: Pubkey,
+) -> InterchainTransferReceivedV2 {
+    let payload = transfer_payload(token, destination);
+    let ix = its
+        .approve_its_message(payload, Some(token.mint), token.token_program)
+        .await
+        .unwrap();
+
+    let simulation_result = its.simulate_tx(&[ix.clone()]).await.unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    its.send_tx(&[ix]).await.unwrap();
+
+    get_first_event_cpi_occurrence::<InterchainTransferReceivedV2>(&inner_ixs).unwrap()
+}
+
+#[tokio::test]
+async fn test_ata_creation_rent_is_recorded_for_reimbursement() {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let token = its
+        .deploy_interchain_token(
+            solana_sdk::keccak::hash(b"ata-rent-reimbursement").to_bytes(),
+            "Test Token",
+            "TT",
+            9,
+            1_000,
+        )
+        .await;
+    let destination = Pubkey::new_unique();
+
+    let event = relay_transfer(&mut its, &token, destination).await;
+
+    let destination_ata = its
+        .try_get_account_no_checks(&token.associated_token_account(&destination))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        event.ata_rent,
+        Some(AtaRentDebit {
+            payer: its.fixture.payer.pubkey(),
+            lamports: destination_ata.lamports,
+        })
+    );
+
+    // The destination token account exists now, nothing to reimburse
+    let event = relay_transfer(&mut its, &token, destination).await;
+    assert_eq!(event.ata_rent, None);
+}
+
+#[tokio::test]
+async fn test_inbound_rent_estimate_matches_the_rent_paid() {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let token = its
+        .deploy_interchain_token(
+            solana_sdk::keccak::hash(b"inbound-rent-estimate").to_bytes(),
+            "Test Token",
+            "TT",
+            9,
+            1_000,
+        )
+        .await;
+    let destination = Pubkey::new_unique();
+    let destination_ata = token.associated_token_account(&destination);
+
+    let estimate = estimate_rent(&mut its, &token, destination).await;
+    assert_eq!(estimate.accounts.len(), 1);
+    assert_eq!(estimate.accounts[0].address, destination_ata);
+
+    let event = relay_transfer(&mut its, &token, destination).await;
+    let destination_ata_account = its
+        .try_get_account_no_checks(&destination_ata)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        estimate.accounts[0].space,
+        destination_ata_account.data.len() as u64
+    );
+    assert_eq!(estimate.total_lamports(), destination_ata_account.lamports);
+    assert_eq!(
+        event.ata_rent.map(|debit| debit.lamports),
+        Some(estimate.total_lamports())
+    );
+
+    // Nothing left to create once the destination token account exists
+    let estimate = estimate_rent(&mut its, &token, destination).await;
+    assert_eq!(estimate, InboundRentEstimate::default());
+}
@@ -0,0 +1,127 @@
+use axelar_solana_its_test_fixtures::token::TestToken;
+use evm_contracts_test_suite::ethers::signers::Signer;
+use solana_program_test::tokio;
+use solana_sdk::program_pack::Pack;
+use test_context::test_context;
+
+use crate::ItsTestContext;
+
+const INITIAL_SUPPLY: u64 = 1_000_000;
+
+async fn deploy_classic_interchain_token(ctx: &mut ItsTestContext, salt: &[u8]) -> TestToken {
+    ctx.solana_chain
+        .deploy_interchain_token_with_token_program(
+            solana_sdk::keccak::hash(salt).0,
+            "Classic Token",
+            "CLT",
+            6,
+            INITIAL_SUPPLY,
+            spl_token::id(),
+        )
+        .await
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_deploy_interchain_token_with_classic_token_program(ctx: &mut ItsTestContext) {
+    let token = deploy_classic_interchain_token(ctx, b"ClassicToken").await;
+
+    let mint = ctx
+        .solana_chain
+        .try_get_account_no_checks(&token.mint)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(mint.owner, spl_token::id());
+    let mint = spl_token::state::Mint::unpack(&mint.data).unwrap();
+    assert_eq!(mint.supply, INITIAL_SUPPLY);
+    assert_eq!(mint.decimals, 6);
+
+    let token_manager = ctx.solana_chain.token_manager(&token.token_id).await;
+    assert_eq!(token_manager.token_program, spl_token::id());
+    assert_eq!(
+        token_manager.associated_token_account,
+        token.associated_token_account(&token.token_manager_pda())
+    );
+
+    let operator = ctx.solana_chain.operator;
+    let operator_ata = ctx
+        .solana_chain
+        .try_get_account_no_checks(&token.associated_token_account(&operator))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(operator_ata.owner, spl_token::id());
+    let operator_ata = spl_token::state::Account::unpack(&operator_ata.data).unwrap();
+    assert_eq!(operator_ata.amount, INITIAL_SUPPLY);
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_classic_interchain_token_transfers_use_recorded_token_program(
+    ctx: &mut ItsTestContext,
+) {
+    let token = deploy_classic_interchain_token(ctx, b"ClassicTransferToken").await;
+    let operator = ctx.solana_chain.operator;
+    let operator_ata = token.associated_token_account(&operator);
+    let destination_address = ctx.evm_signer.wallet.address().as_bytes().to_vec();
+
+    let wrong_program_ix = axelar_solana_its::instruction::interchain_transfer(
+        operator,
+        operator,
+        operator_ata,
+        token.token_id,
+        ctx.evm_chain_name.clone(),
+        destination_address.clone(),
+        100,
+        token.mint,
+        spl_token_2022::id(),
+        0,
+    )
+    .unwrap();
+    assert!(ctx.send_solana_tx(&[wrong_program_ix]).await.is_err());
+
+    let transfer_ix = axelar_solana_its::instruction::interchain_transfer(
+        operator,
+        operator,
+        operator_ata,
+        token.token_id,
+        ctx.evm_chain_name.clone(),
+        destination_address,
+        100,
+        token.mint,
+        spl_token::id(),
+        0,
+    )
+    .unwrap();
+    ctx.send_solana_tx(&[transfer_ix]).await.unwrap();
+
+    let operator_ata = ctx
+        .solana_chain
+        .try_get_account_no_checks(&operator_ata)
+        .await
+        .unwrap()
+        .unwrap();
+    let operator_ata = spl_token::state::Account::unpack(&operator_ata.data).unwrap();
+    assert_eq!(operator_ata.amount, INITIAL_SUPPLY - 100);
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_deploy_interchain_token_rejects_non_token_program(ctx: &mut ItsTestContext) {
+    let payer = ctx.solana_wallet;
+    let ix = axelar_solana_its::instruction::deploy_interchain_token_with_token_program(
+        payer,
+        payer,
+        solana_sdk::keccak::hash(b"NotATokenProgram").0,
+        "Invalid Token".to_owned(),
+        "INV".to_owned(),
+        6,
+        INITIAL_SUPPLY,
+        None,
+        solana_sdk::system_program::id(),
+    )
+    .unwrap();
+
+    assert!(ctx.send_solana_tx(&[ix]).await.is_err());
+}
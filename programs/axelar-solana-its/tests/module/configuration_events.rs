@@ -0,0 +1,121 @@
+use axelar_solana_its::events::{PauseStatusChanged, TrustedChainRemovedV2, TrustedChainSetV2};
+use axelar_solana_its_test_fixtures::{SolanaItsIntegration, SolanaItsIntegrationMetadata};
+use event_cpi_test_utils::assert_event_cpi;
+use solana_program_test::tokio;
+use solana_sdk::inner_instruction::InnerInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+async fn simulate_and_send(
+    its: &mut SolanaItsIntegrationMetadata,
+    ix: Instruction,
+    signers: &[Keypair],
+) -> Vec<InnerInstruction> {
+    let signers = signers.iter().collect::<Vec<_>>();
+    let simulation_result = its
+        .simulate_tx_with_custom_signers(&[ix.clone()], signers.as_slice())
+        .await
+        .unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    its.send_tx_with_custom_signers(&[ix], signers.as_slice())
+        .await
+        .unwrap();
+
+    inner_ixs
+}
+
+#[tokio::test]
+async fn test_trusted_chain_events_carry_authority() {
+    // Setup
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let operator = its.operator;
+    let payer = its.payer.insecure_clone();
+
+    // Action
+    let set_ix = axelar_solana_its::instruction::set_trusted_chain(
+        operator,
+        operator,
+        "avalanche".to_owned(),
+    )
+    .unwrap();
+    let set_inner_ixs = simulate_and_send(&mut its, set_ix, &[payer.insecure_clone()]).await;
+    let remove_ix = axelar_solana_its::instruction::remove_trusted_chain(
+        operator,
+        operator,
+        "avalanche".to_owned(),
+    )
+    .unwrap();
+    let remove_inner_ixs = simulate_and_send(&mut its, remove_ix, &[payer]).await;
+
+    // Assert
+    assert_event_cpi(
+        &TrustedChainSetV2 {
+            chain_name: "avalanche".to_owned(),
+            authority: operator,
+        },
+        &set_inner_ixs,
+    );
+    assert_event_cpi(
+        &TrustedChainRemovedV2 {
+            chain_name: "avalanche".to_owned(),
+            authority: operator,
+        },
+        &remove_inner_ixs,
+    );
+}
+
+#[tokio::test]
+async fn test_pause_status_changed_event() {
+    // Setup
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let payer = its.payer.insecure_clone();
+    let upgrade_authority = its.upgrade_authority.insecure_clone();
+
+    for paused in [true, false] {
+        // Action
+        let ix =
+            axelar_solana_its::instruction::set_pause_status(upgrade_authority.pubkey(), paused)
+                .unwrap();
+        let inner_ixs = simulate_and_send(
+            &mut its,
+            ix,
+            &[payer.insecure_clone(), upgrade_authority.insecure_clone()],
+        )
+        .await;
+
+        // Assert
+        assert_event_cpi(
+            &PauseStatusChanged {
+                paused,
+                authority: upgrade_authority.pubkey(),
+            },
+            &inner_ixs,
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_pause_status_requires_upgrade_authority() {
+    // Setup
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let operator = its.operator;
+
+    // Action
+    let ix = axelar_solana_its::instruction::set_pause_status(operator, true).unwrap();
+    let res = its.send_tx(&[ix]).await;
+
+    // Assert
+    assert!(matches!(
+        res.unwrap_err().result,
+        Err(TransactionError::InstructionError(0, _))
+    ));
+}
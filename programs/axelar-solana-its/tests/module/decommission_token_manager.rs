@@ -0,0 +1,264 @@
+use axelar_solana_its::error::ItsError;
+use axelar_solana_its::events::TokenManagerDecommissioned;
+use axelar_solana_its::state::token_manager::Type as TokenManagerType;
+use axelar_solana_its_test_fixtures::{
+    SolanaItsIntegration, SolanaItsIntegrationMetadata, TestToken,
+};
+use event_cpi_test_utils::assert_event_cpi;
+use solana_program_test::{tokio, BanksTransactionResultWithMetadata};
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::program_option::COption;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer as _;
+use solana_sdk::transaction::TransactionError;
+use spl_token_2022::extension::StateWithExtensions;
+use spl_token_2022::state::Mint;
+
+async fn setup(token_manager_type: TokenManagerType) -> (SolanaItsIntegrationMetadata, TestToken) {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let token = its
+        .create_token(
+            token_manager_type,
+            spl_token_2022::id(),
+            solana_sdk::keccak::hash(b"decommission-token-manager").to_bytes(),
+            9,
+        )
+        .await;
+
+    (its, token)
+}
+
+async fn decommission(
+    its: &mut SolanaItsIntegrationMetadata,
+    token: &TestToken,
+    mint_authority: Option<Pubkey>,
+) -> Result<BanksTransactionResultWithMetadata, BanksTransactionResultWithMetadata> {
+    let operator = its.operator;
+    let ix = axelar_solana_its::instruction::token_manager::decommission(
+        operator,
+        operator,
+        token.token_id,
+        token.mint,
+        token.token_program,
+        mint_authority,
+        &[operator],
+    )
+    .unwrap();
+
+    its.send_tx(&[ix]).await
+}
+
+async fn account_exists(its: &mut SolanaItsIntegrationMetadata, address: &Pubkey) -> bool {
+    its.try_get_account_no_checks(address)
+        .await
+        .unwrap()
+        .is_some()
+}
+
+async fn mint(its: &mut SolanaItsIntegrationMetadata, mint: &Pubkey) -> Mint {
+    let account = its.try_get_account_no_checks(mint).await.unwrap().unwrap();
+    StateWithExtensions::<Mint>::unpack(&account.data)
+        .unwrap()
+        .base
+}
+
+async fn mint_authority(
+    its: &mut SolanaItsIntegrationMetadata,
+    mint_address: &Pubkey,
+) -> COption<Pubkey> {
+    mint(its, mint_address).await.mint_authority
+}
+
+fn token_manager_roles_pda(token: &TestToken, user: &Pubkey) -> Pubkey {
+    role_management::find_user_roles_pda(&axelar_solana_its::id(), &token.token_manager_pda(), user)
+        .0
+}
+
+fn instruction_error(res: BanksTransactionResultWithMetadata, error: InstructionError) {
+    assert_eq!(
+        res.result.unwrap_err(),
+        TransactionError::InstructionError(0, error)
+    );
+}
+
+#[tokio::test]
+async fn test_operator_decommissions_mint_burn_token_manager() {
+    let (mut its, token) = setup(TokenManagerType::MintBurn).await;
+    let operator = its.operator;
+    let new_authority = Pubkey::new_unique();
+    let token_manager_pda = token.token_manager_pda();
+    let token_manager_ata = token.associated_token_account(&token_manager_pda);
+
+    let ix = axelar_solana_its::instruction::token_manager::decommission(
+        operator,
+        operator,
+        token.token_id,
+        token.mint,
+        token.token_program,
+        Some(new_authority),
+        &[operator],
+    )
+    .unwrap();
+    let simulation_result = its.simulate_tx(&[ix.clone()]).await.unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    assert_event_cpi(
+        &TokenManagerDecommissioned {
+            token_id: token.token_id,
+            token_manager: token_manager_pda,
+            operator,
+            mint_authority: Some(new_authority),
+        },
+        &inner_ixs,
+    );
+
+    its.send_tx(&[ix]).await.unwrap();
+
+    assert_eq!(
+        mint_authority(&mut its, &token.mint).await,
+        COption::Some(new_authority)
+    );
+    assert!(!account_exists(&mut its, &token_manager_pda).await);
+    assert!(!account_exists(&mut its, &token_manager_ata).await);
+    assert!(!account_exists(&mut its, &token_manager_roles_pda(&token, &operator)).await);
+
+    // The token can no longer be minted through ITS
+    assert!(its.mint_test_tokens(&token, &operator, 1).await.is_err());
+}
+
+#[tokio::test]
+async fn test_mint_authority_must_be_designated() {
+    let (mut its, token) = setup(TokenManagerType::NativeInterchainToken).await;
+
+    let res = decommission(&mut its, &token, None).await.unwrap_err();
+
+    instruction_error(res, InstructionError::InvalidArgument);
+    assert!(account_exists(&mut its, &token.token_manager_pda()).await);
+    assert_eq!(
+        mint_authority(&mut its, &token.mint).await,
+        COption::Some(token.token_manager_pda())
+    );
+}
+
+#[tokio::test]
+async fn test_interchain_token_authorities_are_handed_back() {
+    let (mut its, token) = setup(TokenManagerType::NativeInterchainToken).await;
+    let new_authority = Pubkey::new_unique();
+
+    decommission(&mut its, &token, Some(new_authority))
+        .await
+        .unwrap();
+
+    let mint = mint(&mut its, &token.mint).await;
+    assert_eq!(mint.mint_authority, COption::Some(new_authority));
+    assert_eq!(mint.freeze_authority, COption::Some(new_authority));
+    assert!(!account_exists(&mut its, &token.token_manager_pda()).await);
+    assert!(!account_exists(&mut its, &token_manager_roles_pda(&token, &its.operator)).await);
+}
+
+#[tokio::test]
+async fn test_lock_unlock_token_manager_with_locked_tokens_is_refused() {
+    let (mut its, token) = setup(TokenManagerType::LockUnlock).await;
+    let token_manager_pda = token.token_manager_pda();
+    its.mint_test_tokens(&token, &token_manager_pda, 100)
+        .await
+        .unwrap();
+
+    let res = decommission(&mut its, &token, None).await.unwrap_err();
+
+    instruction_error(
+        res,
+        InstructionError::Custom(ItsError::LockedTokensRemain as u32),
+    );
+    assert!(account_exists(&mut its, &token_manager_pda).await);
+}
+
+#[tokio::test]
+async fn test_empty_lock_unlock_token_manager_is_decommissioned() {
+    let (mut its, token) = setup(TokenManagerType::LockUnlock).await;
+    let operator = its.operator;
+
+    // The mint authority of a lock/unlock token isn't held by ITS
+    let res = decommission(&mut its, &token, Some(operator))
+        .await
+        .unwrap_err();
+    instruction_error(res, InstructionError::InvalidArgument);
+
+    decommission(&mut its, &token, None).await.unwrap();
+
+    assert!(!account_exists(&mut its, &token.token_manager_pda()).await);
+    assert_eq!(
+        mint_authority(&mut its, &token.mint).await,
+        COption::Some(operator)
+    );
+}
+
+#[tokio::test]
+async fn test_only_operator_can_decommission_token_manager() {
+    let (mut its, token) = setup(TokenManagerType::MintBurn).await;
+    let stranger = Keypair::new();
+
+    let ix = axelar_solana_its::instruction::token_manager::decommission(
+        its.operator,
+        stranger.pubkey(),
+        token.token_id,
+        token.mint,
+        token.token_program,
+        Some(stranger.pubkey()),
+        &[],
+    )
+    .unwrap();
+    let payer = its.fixture.payer.insecure_clone();
+    let res = its
+        .send_tx_with_custom_signers(&[ix], &[&payer, &stranger])
+        .await;
+
+    assert!(res.is_err());
+    assert!(account_exists(&mut its, &token.token_manager_pda()).await);
+}
+
+#[tokio::test]
+async fn test_decommission_leaving_a_role_holder_out_is_refused() {
+    let (mut its, token) = setup(TokenManagerType::LockUnlock).await;
+    let operator = its.operator;
+    let flow_limiter = Pubkey::new_unique();
+
+    let ix = axelar_solana_its::instruction::token_manager::add_flow_limiter(
+        operator,
+        operator,
+        token.token_id,
+        flow_limiter,
+    )
+    .unwrap();
+    its.send_tx(&[ix]).await.unwrap();
+
+    let res = decommission(&mut its, &token, None).await.unwrap_err();
+    instruction_error(
+        res,
+        InstructionError::Custom(ItsError::RoleHoldersRemain as u32),
+    );
+    assert!(account_exists(&mut its, &token.token_manager_pda()).await);
+
+    let ix = axelar_solana_its::instruction::token_manager::decommission(
+        operator,
+        operator,
+        token.token_id,
+        token.mint,
+        token.token_program,
+        None,
+        &[operator, flow_limiter],
+    )
+    .unwrap();
+    its.send_tx(&[ix]).await.unwrap();
+
+    assert!(!account_exists(&mut its, &token.token_manager_pda()).await);
+    assert!(!account_exists(&mut its, &token_manager_roles_pda(&token, &operator)).await);
+    assert!(!account_exists(&mut its, &token_manager_roles_pda(&token, &flow_limiter)).await);
+}
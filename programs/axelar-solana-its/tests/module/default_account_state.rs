@@ -0,0 +1,161 @@
+use axelar_solana_its::state::token_manager::Type as TokenManagerType;
+use interchain_token_transfer_gmp::{GMPPayload, InterchainTransfer, SendToHub};
+use solana_program_test::tokio;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer as _;
+use solana_sdk::system_instruction;
+use spl_token_2022::extension::default_account_state::instruction::initialize_default_account_state;
+use spl_token_2022::extension::{ExtensionType, StateWithExtensions};
+use spl_token_2022::state::{Account as TokenAccount, AccountState, Mint};
+use test_context::test_context;
+
+use crate::ItsTestContext;
+
+/// Creates a Token-2022 mint whose accounts are frozen by default, registers
+/// it as a [`TokenManagerType::MintBurn`] token and returns its token id and
+/// mint.
+async fn setup_frozen_by_default_token(
+    ctx: &mut ItsTestContext,
+    salt: [u8; 32],
+    freeze_authority: Option<Pubkey>,
+) -> ([u8; 32], Pubkey) {
+    let operator = ctx.solana_chain.operator;
+    let token_id = axelar_solana_its::linked_token_id(&operator, &salt);
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+    let freeze_authority = freeze_authority.unwrap_or(token_manager_pda);
+
+    let mint = Keypair::new();
+    let space =
+        ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::DefaultAccountState])
+            .unwrap();
+    let rent = ctx.solana_chain.get_rent(space).await;
+    let payer = ctx.solana_chain.fixture.payer.insecure_clone();
+
+    ctx.solana_chain
+        .fixture
+        .send_tx_with_custom_signers(
+            &[
+                system_instruction::create_account(
+                    &payer.pubkey(),
+                    &mint.pubkey(),
+                    rent,
+                    space.try_into().unwrap(),
+                    &spl_token_2022::id(),
+                ),
+                initialize_default_account_state(
+                    &spl_token_2022::id(),
+                    &mint.pubkey(),
+                    &AccountState::Frozen,
+                )
+                .unwrap(),
+                spl_token_2022::instruction::initialize_mint(
+                    &spl_token_2022::id(),
+                    &mint.pubkey(),
+                    &operator,
+                    Some(&freeze_authority),
+                    9,
+                )
+                .unwrap(),
+            ],
+            &[&payer, &mint],
+        )
+        .await
+        .unwrap();
+
+    ctx.solana_chain
+        .register_custom_token(
+            salt,
+            mint.pubkey(),
+            TokenManagerType::MintBurn,
+            spl_token_2022::id(),
+        )
+        .await;
+
+    let handover_ix = axelar_solana_its::instruction::token_manager::handover_mint_authority(
+        operator,
+        operator,
+        token_id,
+        mint.pubkey(),
+        spl_token_2022::id(),
+    )
+    .unwrap();
+    ctx.send_solana_tx(&[handover_ix]).await.unwrap();
+
+    (token_id, mint.pubkey())
+}
+
+fn transfer_payload(
+    ctx: &ItsTestContext,
+    token_id: [u8; 32],
+    destination: Pubkey,
+    amount: u64,
+) -> Vec<u8> {
+    GMPPayload::SendToHub(SendToHub {
+        selector: SendToHub::MESSAGE_TYPE_ID.try_into().unwrap(),
+        destination_chain: ctx.solana_chain_name.clone(),
+        payload: GMPPayload::InterchainTransfer(InterchainTransfer {
+            selector: InterchainTransfer::MESSAGE_TYPE_ID.try_into().unwrap(),
+            token_id: token_id.into(),
+            source_address: [5; 32].into(),
+            destination_address: destination.to_bytes().into(),
+            amount: alloy_primitives::U256::from(amount),
+            data: vec![].into(),
+        })
+        .encode()
+        .into(),
+    })
+    .encode()
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_inbound_transfer_thaws_frozen_by_default_account(ctx: &mut ItsTestContext) {
+    let salt = solana_sdk::keccak::hash(b"default-account-state-thaw").to_bytes();
+    let (token_id, mint) = setup_frozen_by_default_token(ctx, salt, None).await;
+    let destination = Pubkey::new_unique();
+    let payload = transfer_payload(ctx, token_id, destination, 300);
+
+    let (_inner_ixs, tx) = ctx
+        .relay_to_solana(&payload, Some(mint), spl_token_2022::id())
+        .await;
+    assert!(tx.result.is_ok(), "inbound transfer failed: {tx:?}");
+
+    let destination_ata =
+        spl_associated_token_account::get_associated_token_address_with_program_id(
+            &destination,
+            &mint,
+            &spl_token_2022::id(),
+        );
+    let account = ctx
+        .solana_chain
+        .try_get_account_no_checks(&destination_ata)
+        .await
+        .unwrap()
+        .unwrap();
+    let token_account = StateWithExtensions::<TokenAccount>::unpack(&account.data)
+        .unwrap()
+        .base;
+
+    assert_eq!(token_account.state, AccountState::Initialized);
+    assert_eq!(token_account.amount, 300);
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_inbound_transfer_to_frozen_account_fails_without_freeze_authority(
+    ctx: &mut ItsTestContext,
+) {
+    let salt = solana_sdk::keccak::hash(b"default-account-state-foreign").to_bytes();
+    let foreign_authority = Pubkey::new_unique();
+    let (token_id, mint) = setup_frozen_by_default_token(ctx, salt, Some(foreign_authority)).await;
+    let payload = transfer_payload(ctx, token_id, Pubkey::new_unique(), 300);
+
+    let (_inner_ixs, tx) = ctx
+        .relay_to_solana(&payload, Some(mint), spl_token_2022::id())
+        .await;
+
+    assert!(tx.result.is_err());
+}
@@ -0,0 +1,165 @@
+use axelar_solana_its::error::ItsError;
+use axelar_solana_its::events::GasQuoteUsed;
+use axelar_solana_its::gas_quote::{verify_gas_quote_instruction, GasQuote};
+use event_cpi_test_utils::assert_event_cpi;
+use solana_program_test::{tokio, BanksTransactionResultWithMetadata};
+use solana_sdk::clock::Clock;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer as _;
+use solana_sdk::transaction::TransactionError;
+use test_context::test_context;
+
+use crate::ItsTestContext;
+
+const DESTINATION_CHAIN: &str = "ethereum";
+const QUOTED_GAS_VALUE: u64 = 5_000;
+
+async fn deploy_local_token(ctx: &mut ItsTestContext, salt: [u8; 32]) {
+    let ix = axelar_solana_its::instruction::deploy_interchain_token(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        salt,
+        "Quoted Token".to_owned(),
+        "QTT".to_owned(),
+        9,
+        1000,
+        Some(ctx.solana_wallet),
+    )
+    .unwrap();
+
+    ctx.send_solana_tx(&[ix]).await.unwrap();
+}
+
+async fn quote(ctx: &mut ItsTestContext, destination_chain: &str, expires_in: i64) -> GasQuote {
+    let clock = ctx.solana_chain.get_sysvar::<Clock>().await;
+
+    GasQuote {
+        destination_chain: destination_chain.to_owned(),
+        gas_value: QUOTED_GAS_VALUE,
+        expires_at: clock.unix_timestamp + expires_in,
+    }
+}
+
+fn quoted_deploy_remote(
+    ctx: &ItsTestContext,
+    salt: [u8; 32],
+    quote: &GasQuote,
+    signer: &Keypair,
+    gas_value: u64,
+) -> Vec<Instruction> {
+    let signature = signer.sign_message(&quote.message().unwrap());
+    let verify_ix = verify_gas_quote_instruction(
+        &signer.pubkey(),
+        signature.as_ref().try_into().unwrap(),
+        quote,
+    )
+    .unwrap();
+    let deploy_ix = axelar_solana_its::instruction::deploy_remote_interchain_token(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        salt,
+        DESTINATION_CHAIN.to_owned(),
+        gas_value,
+    )
+    .unwrap();
+
+    vec![
+        verify_ix,
+        axelar_solana_its::instruction::with_gas_quote(deploy_ix, signer.pubkey()),
+    ]
+}
+
+fn its_error(res: BanksTransactionResultWithMetadata, error: ItsError) {
+    assert_eq!(
+        res.result.unwrap_err(),
+        TransactionError::InstructionError(1, InstructionError::Custom(error as u32))
+    );
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_deploy_remote_with_operator_gas_quote(ctx: &mut ItsTestContext) {
+    let salt = solana_sdk::keccak::hash(b"QuotedToken").0;
+    deploy_local_token(ctx, salt).await;
+    let quote = quote(ctx, DESTINATION_CHAIN, 60).await;
+    let operator = ctx.solana_chain.fixture.payer.insecure_clone();
+    let ixs = quoted_deploy_remote(ctx, salt, &quote, &operator, QUOTED_GAS_VALUE + 1);
+
+    let simulation_result = ctx.simulate_solana_tx(&ixs).await;
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .get(1)
+        .cloned()
+        .unwrap();
+    assert_event_cpi(
+        &GasQuoteUsed {
+            signer: operator.pubkey(),
+            destination_chain: DESTINATION_CHAIN.to_owned(),
+            quoted_gas_value: QUOTED_GAS_VALUE,
+            gas_value: QUOTED_GAS_VALUE + 1,
+            expires_at: quote.expires_at,
+        },
+        &inner_ixs,
+    );
+
+    ctx.send_solana_tx(&ixs).await.unwrap();
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_deploy_remote_below_gas_quote_fails(ctx: &mut ItsTestContext) {
+    let salt = solana_sdk::keccak::hash(b"UnderpaidQuotedToken").0;
+    deploy_local_token(ctx, salt).await;
+    let quote = quote(ctx, DESTINATION_CHAIN, 60).await;
+    let operator = ctx.solana_chain.fixture.payer.insecure_clone();
+    let ixs = quoted_deploy_remote(ctx, salt, &quote, &operator, QUOTED_GAS_VALUE - 1);
+
+    let res = ctx.send_solana_tx(&ixs).await.unwrap_err();
+
+    its_error(res, ItsError::GasBelowQuote);
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_deploy_remote_with_expired_gas_quote_fails(ctx: &mut ItsTestContext) {
+    let salt = solana_sdk::keccak::hash(b"ExpiredQuotedToken").0;
+    deploy_local_token(ctx, salt).await;
+    let quote = quote(ctx, DESTINATION_CHAIN, -1).await;
+    let operator = ctx.solana_chain.fixture.payer.insecure_clone();
+    let ixs = quoted_deploy_remote(ctx, salt, &quote, &operator, QUOTED_GAS_VALUE);
+
+    let res = ctx.send_solana_tx(&ixs).await.unwrap_err();
+
+    its_error(res, ItsError::GasQuoteExpired);
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_deploy_remote_with_gas_quote_for_other_chain_fails(ctx: &mut ItsTestContext) {
+    let salt = solana_sdk::keccak::hash(b"MisdirectedQuotedToken").0;
+    deploy_local_token(ctx, salt).await;
+    let quote = quote(ctx, "avalanche", 60).await;
+    let operator = ctx.solana_chain.fixture.payer.insecure_clone();
+    let ixs = quoted_deploy_remote(ctx, salt, &quote, &operator, QUOTED_GAS_VALUE);
+
+    let res = ctx.send_solana_tx(&ixs).await.unwrap_err();
+
+    its_error(res, ItsError::InvalidGasQuote);
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_deploy_remote_with_gas_quote_not_signed_by_operator_fails(ctx: &mut ItsTestContext) {
+    let salt = solana_sdk::keccak::hash(b"ForgedQuotedToken").0;
+    deploy_local_token(ctx, salt).await;
+    let quote = quote(ctx, DESTINATION_CHAIN, 60).await;
+    let ixs = quoted_deploy_remote(ctx, salt, &quote, &Keypair::new(), QUOTED_GAS_VALUE);
+
+    let res = ctx.send_solana_tx(&ixs).await;
+
+    assert!(res.is_err());
+}
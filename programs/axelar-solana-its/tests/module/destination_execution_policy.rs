@@ -0,0 +1,162 @@
+use axelar_message_primitives::{DataPayload, EncodingScheme, SolanaAccountRepr};
+use axelar_solana_its::error::ItsError;
+use axelar_solana_its::events::DestinationExecutionPolicySet;
+use axelar_solana_its::state::{DestinationExecutionPolicy, InterchainTokenService};
+use axelar_solana_its_test_fixtures::{SolanaItsIntegration, SolanaItsIntegrationMetadata};
+use event_cpi_test_utils::assert_event_cpi;
+use solana_program_test::tokio;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer as _;
+use solana_sdk::transaction::TransactionError;
+
+async fn set_policy(
+    its: &mut SolanaItsIntegrationMetadata,
+    policy: Option<DestinationExecutionPolicy>,
+) {
+    let operator = its.operator;
+    let ix = axelar_solana_its::instruction::set_destination_execution_policy(
+        operator, operator, policy,
+    )
+    .unwrap();
+    let simulation_result = its.simulate_tx(&[ix.clone()]).await.unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    assert_event_cpi(&DestinationExecutionPolicySet { policy }, &inner_ixs);
+
+    its.send_tx(&[ix]).await.unwrap();
+}
+
+async fn its_root(its: &mut SolanaItsIntegrationMetadata) -> InterchainTokenService {
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    its.load_borsh_pda(&its_root_pda).await
+}
+
+/// Relays a transfer with data to a destination program, forwarding
+/// `forwarded_accounts` accounts to it, and returns the ITS error code.
+async fn relay_transfer_with_data(
+    its: &mut SolanaItsIntegrationMetadata,
+    forwarded_accounts: usize,
+) -> u32 {
+    let token = its
+        .deploy_interchain_token(
+            solana_sdk::keccak::hash(b"destination-execution-policy").to_bytes(),
+            "Test Token",
+            "TT",
+            9,
+            1_000,
+        )
+        .await;
+    let accounts = (0..forwarded_accounts)
+        .map(|_| SolanaAccountRepr {
+            pubkey: Pubkey::new_unique().to_bytes().into(),
+            is_signer: false,
+            is_writable: false,
+        })
+        .collect::<Vec<_>>();
+    let data = DataPayload::new(b"memo", &accounts, EncodingScheme::Borsh)
+        .encode()
+        .unwrap();
+
+    let err = its
+        .relay_interchain_transfer(
+            "ethereum",
+            &token,
+            axelar_solana_memo_program::id(),
+            100,
+            data,
+        )
+        .await
+        .unwrap_err();
+    let TransactionError::InstructionError(_, InstructionError::Custom(code)) =
+        err.result.unwrap_err()
+    else {
+        panic!("expected an ITS error");
+    };
+
+    code
+}
+
+#[tokio::test]
+async fn test_operator_sets_destination_execution_policy() {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    assert_eq!(its_root(&mut its).await.destination_execution_policy, None);
+
+    let policy = DestinationExecutionPolicy {
+        max_accounts: 4,
+        min_compute_units: 50_000,
+    };
+    set_policy(&mut its, Some(policy)).await;
+    assert_eq!(
+        its_root(&mut its).await.destination_execution_policy,
+        Some(policy)
+    );
+
+    set_policy(&mut its, None).await;
+    assert_eq!(its_root(&mut its).await.destination_execution_policy, None);
+}
+
+#[tokio::test]
+async fn test_only_operator_can_set_destination_execution_policy() {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let impostor = Keypair::new();
+    let ix = axelar_solana_its::instruction::set_destination_execution_policy(
+        its.fixture.payer.pubkey(),
+        impostor.pubkey(),
+        Some(DestinationExecutionPolicy {
+            max_accounts: 0,
+            min_compute_units: 0,
+        }),
+    )
+    .unwrap();
+    let payer = its.fixture.payer.insecure_clone();
+
+    let res = its
+        .send_tx_with_custom_signers(&[ix], &[&payer, &impostor])
+        .await;
+
+    assert!(res.is_err());
+    assert_eq!(its_root(&mut its).await.destination_execution_policy, None);
+}
+
+#[tokio::test]
+async fn test_transfer_forwarding_too_many_accounts_is_rejected() {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    set_policy(
+        &mut its,
+        Some(DestinationExecutionPolicy {
+            max_accounts: 1,
+            min_compute_units: 0,
+        }),
+    )
+    .await;
+
+    let code = relay_transfer_with_data(&mut its, 2).await;
+
+    assert_eq!(code, ItsError::TooManyDestinationAccounts as u32);
+}
+
+#[tokio::test]
+async fn test_transfer_without_compute_reserve_is_rejected() {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    set_policy(
+        &mut its,
+        Some(DestinationExecutionPolicy {
+            max_accounts: 8,
+            // More than a transaction can ever be granted
+            min_compute_units: 10_000_000,
+        }),
+    )
+    .await;
+
+    let code = relay_transfer_with_data(&mut its, 1).await;
+
+    assert_eq!(code, ItsError::InsufficientDestinationComputeUnits as u32);
+}
@@ -0,0 +1,179 @@
+use axelar_solana_gateway_test_fixtures::assert_msg_present_in_logs;
+use axelar_solana_its::events::{DestinationOwnerMismatch, DestinationOwnerPolicySet};
+use axelar_solana_its::state::token_manager::{DestinationOwnerPolicy, Type as TokenManagerType};
+use axelar_solana_its_test_fixtures::TestToken;
+use event_cpi_test_utils::get_first_event_cpi_occurrence;
+use interchain_token_transfer_gmp::{GMPPayload, InterchainTransfer, SendToHub};
+use solana_program_test::tokio;
+use solana_sdk::program_pack::Pack as _;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer as _;
+use spl_token_2022::instruction::AuthorityType;
+use test_context::test_context;
+
+use crate::ItsTestContext;
+
+/// Creates a SPL Token (non-2022) token and an associated token account of
+/// `destination` whose owner was then changed to another account.
+async fn setup_token_with_transferred_ata(
+    ctx: &mut ItsTestContext,
+    destination: &Keypair,
+) -> (TestToken, Pubkey) {
+    let salt = solana_sdk::keccak::hash(b"destination-owner-policy-test").to_bytes();
+    let token = ctx
+        .solana_chain
+        .create_token(TokenManagerType::MintBurn, spl_token::id(), salt, 9)
+        .await;
+
+    let destination_ata = token.associated_token_account(&destination.pubkey());
+    let new_owner = Pubkey::new_unique();
+    let create_ata_ix =
+        spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+            &ctx.solana_wallet,
+            &destination.pubkey(),
+            &token.mint,
+            &token.token_program,
+        );
+    let set_owner_ix = spl_token_2022::instruction::set_authority(
+        &token.token_program,
+        &destination_ata,
+        Some(&new_owner),
+        AuthorityType::AccountOwner,
+        &destination.pubkey(),
+        &[],
+    )
+    .unwrap();
+
+    ctx.solana_chain
+        .fixture
+        .send_tx_with_custom_signers(
+            &[create_ata_ix, set_owner_ix],
+            &[
+                &ctx.solana_chain.fixture.payer.insecure_clone(),
+                destination,
+            ],
+        )
+        .await
+        .unwrap();
+
+    (token, new_owner)
+}
+
+fn transfer_payload(ctx: &ItsTestContext, token: &TestToken, destination: Pubkey) -> Vec<u8> {
+    GMPPayload::SendToHub(SendToHub {
+        selector: SendToHub::MESSAGE_TYPE_ID.try_into().unwrap(),
+        destination_chain: ctx.solana_chain_name.clone(),
+        payload: GMPPayload::InterchainTransfer(InterchainTransfer {
+            selector: InterchainTransfer::MESSAGE_TYPE_ID.try_into().unwrap(),
+            token_id: token.token_id.into(),
+            source_address: [5; 32].into(),
+            destination_address: destination.to_bytes().into(),
+            amount: alloy_primitives::U256::from(300_u64),
+            data: vec![].into(),
+        })
+        .encode()
+        .into(),
+    })
+    .encode()
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_destination_owner_policy_for_transferred_ata(ctx: &mut ItsTestContext) {
+    let destination = Keypair::new();
+    let (token, new_owner) = setup_token_with_transferred_ata(ctx, &destination).await;
+    let payload = transfer_payload(ctx, &token, destination.pubkey());
+
+    // Strict by default
+    let (_inner_ixs, tx) = ctx
+        .relay_to_solana(&payload, Some(token.mint), token.token_program)
+        .await;
+    assert!(tx.result.is_err());
+    assert_msg_present_in_logs(tx, "not by the destination address");
+
+    let set_policy_ix =
+        axelar_solana_its::instruction::token_manager::set_destination_owner_policy(
+            ctx.solana_wallet,
+            ctx.solana_wallet,
+            token.token_id,
+            DestinationOwnerPolicy::Permissive,
+        )
+        .unwrap();
+    let simulation_result = ctx.simulate_solana_tx(&[set_policy_ix.clone()]).await;
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    let event = get_first_event_cpi_occurrence::<DestinationOwnerPolicySet>(&inner_ixs).unwrap();
+    assert_eq!(event.token_id, token.token_id);
+    assert_eq!(event.policy, DestinationOwnerPolicy::Permissive);
+
+    ctx.send_solana_tx(&[set_policy_ix]).await.unwrap();
+
+    let token_manager = ctx.solana_chain.token_manager(&token.token_id).await;
+    assert_eq!(
+        token_manager.destination_owner_policy,
+        DestinationOwnerPolicy::Permissive
+    );
+
+    // Permissive transfers to the ATA and reports the mismatch
+    let payload = transfer_payload(ctx, &token, destination.pubkey());
+    let (inner_ixs, tx) = ctx
+        .relay_to_solana(&payload, Some(token.mint), token.token_program)
+        .await;
+    assert!(tx.result.is_ok());
+
+    let destination_ata = token.associated_token_account(&destination.pubkey());
+    let event = get_first_event_cpi_occurrence::<DestinationOwnerMismatch>(&inner_ixs).unwrap();
+    assert_eq!(event.destination_address, destination.pubkey());
+    assert_eq!(event.destination_token_account, destination_ata);
+    assert_eq!(event.token_account_owner, new_owner);
+
+    let data = ctx
+        .solana_chain
+        .try_get_account_no_checks(&destination_ata)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let account = spl_token_2022::state::Account::unpack_from_slice(&data).unwrap();
+    assert_eq!(account.amount, 300);
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_only_operator_can_set_destination_owner_policy(ctx: &mut ItsTestContext) {
+    let stranger = Keypair::new();
+    let set_policy_ix =
+        axelar_solana_its::instruction::token_manager::set_destination_owner_policy(
+            ctx.solana_wallet,
+            stranger.pubkey(),
+            ctx.deployed_interchain_token,
+            DestinationOwnerPolicy::Permissive,
+        )
+        .unwrap();
+
+    let result = ctx
+        .solana_chain
+        .fixture
+        .send_tx_with_custom_signers(
+            &[set_policy_ix],
+            &[&ctx.solana_chain.fixture.payer.insecure_clone(), &stranger],
+        )
+        .await;
+    assert!(result.is_err());
+
+    let token_manager = ctx
+        .solana_chain
+        .token_manager(&ctx.deployed_interchain_token)
+        .await;
+    assert_eq!(
+        token_manager.destination_owner_policy,
+        DestinationOwnerPolicy::Strict
+    );
+}
@@ -0,0 +1,84 @@
+use axelar_solana_its_test_fixtures::{SolanaItsIntegration, SolanaItsIntegrationMetadata};
+use mpl_token_metadata::accounts::Metadata;
+use solana_program_test::tokio;
+use solana_sdk::account::Account;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+const SALT: [u8; 32] = [7; 32];
+
+async fn deploy(
+    its: &mut SolanaItsIntegrationMetadata,
+    deployer: &Keypair,
+    name: &str,
+) -> Result<Pubkey, ()> {
+    let payer = its.payer.insecure_clone();
+    let ix = axelar_solana_its::instruction::deploy_interchain_token(
+        payer.pubkey(),
+        deployer.pubkey(),
+        SALT,
+        name.to_owned(),
+        "TT".to_owned(),
+        9,
+        1_000,
+        None,
+    )
+    .unwrap();
+    its.send_tx_with_custom_signers(&[ix], &[&payer, deployer])
+        .await
+        .map_err(|_err| ())?;
+
+    let token_id = axelar_solana_its::interchain_token_id(&deployer.pubkey(), &SALT);
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (mint, _) = axelar_solana_its::find_interchain_token_pda(&its_root_pda, &token_id);
+
+    Ok(mint)
+}
+
+/// Deploys the token of `deployer` on a separate chain and returns its
+/// metadata account, as left behind by an earlier deployment attempt.
+async fn leftover_metadata(deployer: &Keypair, name: &str) -> (Pubkey, Account) {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let mint = deploy(&mut its, deployer, name).await.unwrap();
+    let (metadata_pda, _) = Metadata::find_pda(&mint);
+    let metadata = its
+        .get_account(&metadata_pda, &mpl_token_metadata::ID)
+        .await;
+
+    (metadata_pda, metadata)
+}
+
+#[tokio::test]
+async fn test_deploy_reuses_matching_existing_metadata() {
+    // Setup
+    let deployer = Keypair::new();
+    let (metadata_pda, metadata) = leftover_metadata(&deployer, "Test Token").await;
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    its.set_account_state(&metadata_pda, metadata.clone());
+
+    // Action
+    let mint = deploy(&mut its, &deployer, "Test Token").await.unwrap();
+
+    // Assert
+    assert_eq!(Metadata::find_pda(&mint).0, metadata_pda);
+    let metadata_after = its
+        .get_account(&metadata_pda, &mpl_token_metadata::ID)
+        .await;
+    assert_eq!(metadata_after.data, metadata.data);
+}
+
+#[tokio::test]
+async fn test_deploy_rejects_mismatching_existing_metadata() {
+    // Setup
+    let deployer = Keypair::new();
+    let (metadata_pda, metadata) = leftover_metadata(&deployer, "Other Token").await;
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    its.set_account_state(&metadata_pda, metadata);
+
+    // Action
+    let res = deploy(&mut its, &deployer, "Test Token").await;
+
+    // Assert
+    assert!(res.is_err());
+}
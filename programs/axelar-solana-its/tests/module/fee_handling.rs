@@ -1,5 +1,6 @@
 use anyhow::anyhow;
 use axelar_solana_gateway_test_fixtures::assert_msg_present_in_logs;
+use axelar_solana_its::events::InterchainTransferV2;
 use axelar_solana_its::state::token_manager::TokenManager;
 use borsh::BorshDeserialize;
 use evm_contracts_test_suite::ethers::signers::Signer;
@@ -174,10 +175,9 @@ async fn test_canonical_token_with_fee_lock_unlock(ctx: &mut ItsTestContext) ->
         .first()
         .cloned()
         .unwrap();
-    let transfer_event =
-        get_first_event_cpi_occurrence::<axelar_solana_its::events::InterchainTransfer>(&inner_ixs)
-            .ok_or_else(|| anyhow!("InterchainTransfer not found"))
-            .unwrap();
+    let transfer_event = get_first_event_cpi_occurrence::<InterchainTransferV2>(&inner_ixs)
+        .ok_or_else(|| anyhow!("InterchainTransfer not found"))
+        .unwrap();
 
     ctx.send_solana_tx(&[transfer_ix]).await.unwrap();
 
@@ -340,10 +340,9 @@ async fn test_canonical_token_various_fee_configs(ctx: &mut ItsTestContext) -> a
         .first()
         .cloned()
         .unwrap();
-    let transfer_event =
-        get_first_event_cpi_occurrence::<axelar_solana_its::events::InterchainTransfer>(&inner_ixs)
-            .ok_or_else(|| anyhow!("InterchainTransfer not found"))
-            .unwrap();
+    let transfer_event = get_first_event_cpi_occurrence::<InterchainTransferV2>(&inner_ixs)
+        .ok_or_else(|| anyhow!("InterchainTransfer not found"))
+        .unwrap();
 
     ctx.send_solana_tx(&[transfer_ix]).await.unwrap();
 
@@ -504,10 +503,9 @@ async fn test_canonical_token_maximum_fee_cap(ctx: &mut ItsTestContext) -> anyho
         .first()
         .cloned()
         .unwrap();
-    let transfer_event =
-        get_first_event_cpi_occurrence::<axelar_solana_its::events::InterchainTransfer>(&inner_ixs)
-            .ok_or_else(|| anyhow!("InterchainTransfer not found"))
-            .unwrap();
+    let transfer_event = get_first_event_cpi_occurrence::<InterchainTransferV2>(&inner_ixs)
+        .ok_or_else(|| anyhow!("InterchainTransfer not found"))
+        .unwrap();
 
     ctx.send_solana_tx(&[transfer_ix]).await.unwrap();
 
@@ -753,10 +751,9 @@ async fn test_custom_token_with_fee_lock_unlock_fee(
         .first()
         .cloned()
         .unwrap();
-    let outbound_event =
-        get_first_event_cpi_occurrence::<axelar_solana_its::events::InterchainTransfer>(&inner_ixs)
-            .ok_or_else(|| anyhow!("InterchainTransfer not found"))
-            .unwrap();
+    let outbound_event = get_first_event_cpi_occurrence::<InterchainTransferV2>(&inner_ixs)
+        .ok_or_else(|| anyhow!("InterchainTransfer not found"))
+        .unwrap();
     let call_contract_event = get_first_event_cpi_occurrence::<
         axelar_solana_gateway::events::CallContractEvent,
     >(&inner_ixs)
@@ -1,6 +1,8 @@
 use alloy_primitives::Bytes;
 use anyhow::anyhow;
 use axelar_solana_gateway_test_fixtures::assert_msg_present_in_logs;
+use axelar_solana_its::events::{InterchainTransferPartiallyFilled, InterchainTransferV2};
+use axelar_solana_its::state::flow_limit::FlowStatus;
 use axelar_solana_its::state::token_manager::TokenManager;
 use borsh::BorshDeserialize;
 use interchain_token_transfer_gmp::SendToHub;
@@ -446,6 +448,123 @@ async fn test_outgoing_interchain_transfer_outside_limit(ctx: &mut ItsTestContex
     assert_msg_present_in_logs(tx, "Flow limit exceeded");
 }
 
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_outgoing_interchain_transfer_partially_filled(
+    ctx: &mut ItsTestContext,
+) -> anyhow::Result<()> {
+    let token_id = ctx.deployed_interchain_token;
+    let flow_limit = 800;
+    let requested_amount = 900;
+
+    let flow_limit_ix = axelar_solana_its::instruction::set_flow_limit(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        token_id,
+        Some(flow_limit),
+    )?;
+
+    ctx.send_solana_tx(&[flow_limit_ix]).await;
+
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (interchain_token_pda, _) =
+        axelar_solana_its::find_interchain_token_pda(&its_root_pda, &token_id);
+
+    let associated_account_address = get_associated_token_address_with_program_id(
+        &ctx.solana_wallet,
+        &interchain_token_pda,
+        &spl_token_2022::id(),
+    );
+
+    let create_token_account_ix = create_associated_token_account(
+        &ctx.solana_wallet,
+        &ctx.solana_wallet,
+        &interchain_token_pda,
+        &spl_token_2022::id(),
+    );
+
+    ctx.send_solana_tx(&[create_token_account_ix]).await;
+
+    let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
+        token_id,
+        interchain_token_pda,
+        associated_account_address,
+        ctx.solana_wallet,
+        spl_token_2022::id(),
+        requested_amount,
+    )?;
+
+    ctx.send_solana_tx(&[mint_ix]).await;
+
+    let transfer_ix = axelar_solana_its::instruction::interchain_transfer_allow_partial(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        associated_account_address,
+        token_id,
+        ctx.evm_chain_name.clone(),
+        ctx.evm_signer.wallet.address().as_bytes().to_vec(),
+        requested_amount,
+        interchain_token_pda,
+        spl_token_2022::id(),
+        0,
+    )?;
+
+    // Simulate first to get the events and the unfilled remainder
+    let simulation_details = ctx
+        .simulate_solana_tx(&[transfer_ix.clone()])
+        .await
+        .simulation_details
+        .unwrap();
+    let return_data = simulation_details.return_data.unwrap();
+    assert_eq!(return_data.program_id, axelar_solana_its::id());
+    assert_eq!(u64::try_from_slice(&return_data.data)?, 100);
+
+    let inner_ixs = simulation_details
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    let partial_fill_event =
+        get_first_event_cpi_occurrence::<InterchainTransferPartiallyFilled>(&inner_ixs)
+            .ok_or_else(|| anyhow!("InterchainTransferPartiallyFilled not found"))?;
+    assert_eq!(partial_fill_event.requested_amount, requested_amount);
+    assert_eq!(partial_fill_event.filled_amount, flow_limit);
+    assert_eq!(partial_fill_event.unfilled_amount, 100);
+
+    let transfer_event = get_first_event_cpi_occurrence::<InterchainTransferV2>(&inner_ixs)
+        .ok_or_else(|| anyhow!("InterchainTransfer event not found"))?;
+    assert_eq!(transfer_event.amount, flow_limit);
+
+    let emitted_event = get_first_event_cpi_occurrence::<CallContractEvent>(&inner_ixs)
+        .ok_or_else(|| anyhow!("CallContractEvent not found"))?;
+
+    // Then execute the transaction
+    ctx.send_solana_tx(&[transfer_ix]).await.unwrap();
+
+    // The unfilled remainder never leaves the source account
+    let source_raw_account = ctx
+        .solana_chain
+        .try_get_account_no_checks(&associated_account_address)
+        .await?
+        .ok_or_else(|| anyhow!("source account not found"))?;
+    let source_account = Account::unpack_from_slice(&source_raw_account.data).unwrap();
+    assert_eq!(source_account.amount, 100);
+
+    ctx.relay_to_evm(&emitted_event.payload).await;
+
+    let log = retrieve_evm_log_with_filter(
+        ctx.evm_its_contracts
+            .interchain_token_service
+            .interchain_transfer_received_filter(),
+    )
+    .await;
+
+    assert_eq!(log.amount, U256::from(800_u32));
+
+    Ok(())
+}
+
 #[test_context(ItsTestContext)]
 #[tokio::test]
 async fn test_flow_slot_initialization_incoming_transfer(
@@ -928,3 +1047,89 @@ async fn test_net_flow_calculation_bidirectional(ctx: &mut ItsTestContext) -> an
 
     Ok(())
 }
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_get_flow_status(ctx: &mut ItsTestContext) -> anyhow::Result<()> {
+    let token_id = ctx.deployed_interchain_token;
+    let flow_limit = 800;
+    let amount = 300;
+
+    let flow_limit_ix = axelar_solana_its::instruction::set_flow_limit(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        token_id,
+        Some(flow_limit),
+    )?;
+
+    ctx.send_solana_tx(&[flow_limit_ix]).await;
+
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (interchain_token_pda, _) =
+        axelar_solana_its::find_interchain_token_pda(&its_root_pda, &token_id);
+
+    let associated_account_address = get_associated_token_address_with_program_id(
+        &ctx.solana_wallet,
+        &interchain_token_pda,
+        &spl_token_2022::id(),
+    );
+
+    let create_token_account_ix = create_associated_token_account(
+        &ctx.solana_wallet,
+        &ctx.solana_wallet,
+        &interchain_token_pda,
+        &spl_token_2022::id(),
+    );
+
+    ctx.send_solana_tx(&[create_token_account_ix]).await;
+
+    let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
+        token_id,
+        interchain_token_pda,
+        associated_account_address,
+        ctx.solana_wallet,
+        spl_token_2022::id(),
+        amount,
+    )?;
+
+    ctx.send_solana_tx(&[mint_ix]).await;
+
+    let transfer_ix = axelar_solana_its::instruction::interchain_transfer(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        associated_account_address,
+        token_id,
+        ctx.evm_chain_name.clone(),
+        ctx.evm_signer.wallet.address().as_bytes().to_vec(),
+        amount,
+        interchain_token_pda,
+        spl_token_2022::id(),
+        0,
+    )?;
+
+    ctx.send_solana_tx(&[transfer_ix]).await.unwrap();
+
+    let status_ix = axelar_solana_its::instruction::token_manager::get_flow_status(token_id)?;
+    let simulation_details = ctx
+        .simulate_solana_tx(&[status_ix])
+        .await
+        .simulation_details
+        .unwrap();
+    let return_data = simulation_details.return_data.unwrap();
+    assert_eq!(return_data.program_id, axelar_solana_its::id());
+
+    let current_timestamp = ctx.solana_chain.get_sysvar::<Clock>().await.unix_timestamp;
+    let current_epoch =
+        axelar_solana_its::state::flow_limit::flow_epoch_with_timestamp(current_timestamp)?;
+    let status = FlowStatus::try_from_slice(&return_data.data)?;
+    assert_eq!(status.limit, Some(flow_limit));
+    assert_eq!((status.flow_in, status.flow_out), (0, amount));
+    assert_eq!(status.epoch, current_epoch);
+    assert!(status.resets_at > current_timestamp);
+    assert!(simulation_details
+        .logs
+        .iter()
+        .any(|log| log.contains(&format!("flow out: {amount}"))));
+
+    Ok(())
+}
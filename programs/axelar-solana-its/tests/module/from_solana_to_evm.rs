@@ -1,6 +1,7 @@
 use anyhow::anyhow;
 use axelar_solana_gateway_test_fixtures::base::FindLog;
-use axelar_solana_its::events::InterchainTransfer;
+use axelar_solana_its::error::ItsError;
+use axelar_solana_its::events::{HubMessageSent, InterchainTransferV2};
 use borsh::BorshDeserialize;
 use evm_contracts_test_suite::ethers::signers::Signer;
 use mpl_token_metadata::accounts::Metadata;
@@ -8,15 +9,18 @@ use mpl_token_metadata::instructions::CreateV1Builder;
 use mpl_token_metadata::types::TokenStandard;
 use solana_program_test::tokio;
 use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::InstructionError;
 use solana_sdk::program_pack::Pack as _;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer as _;
+use solana_sdk::transaction::TransactionError;
 use spl_associated_token_account::get_associated_token_address_with_program_id;
 use spl_associated_token_account::instruction::create_associated_token_account;
 use test_context::test_context;
 
 use axelar_solana_gateway_test_fixtures::assert_msg_present_in_logs;
+use axelar_solana_its::state::outbound_nonce::OutboundNonce;
 use axelar_solana_its::state::token_manager::{TokenManager, Type as TokenManagerType};
 use evm_contracts_test_suite::evm_contracts_rs::contracts::{
     custom_test_token::CustomTestToken, interchain_token::InterchainToken,
@@ -26,7 +30,7 @@ use interchain_token_transfer_gmp::GMPPayload;
 
 use event_cpi_test_utils::get_first_event_cpi_occurrence;
 
-use crate::{retrieve_evm_log_with_filter, ItsTestContext};
+use crate::{retrieve_evm_log_with_filter, BorshPdaAccount, ItsTestContext};
 
 async fn custom_token(
     ctx: &mut ItsTestContext,
@@ -573,7 +577,7 @@ async fn transfer_fails_with_wrong_gas_service(ctx: &mut ItsTestContext) -> anyh
         1000, // gas_value needs to be greater than 0 for pay_gas to be called
     )
     .unwrap();
-    transfer_ix.accounts[12].pubkey = Pubkey::new_unique(); // invalid gas service
+    transfer_ix.accounts[13].pubkey = Pubkey::new_unique(); // invalid gas service
 
     assert!(ctx
         .send_solana_tx(&[transfer_ix])
@@ -739,7 +743,7 @@ async fn test_mint_burn_from_interchain_transfer_with_approval(
     // Check that an invalid program account leads to a failure
     {
         let mut link_token_ix = link_token_ix.clone();
-        link_token_ix.accounts[12].pubkey = Pubkey::new_unique();
+        link_token_ix.accounts[13].pubkey = Pubkey::new_unique();
         let result = ctx.send_solana_tx(&[link_token_ix]).await;
         assert!(result.is_err());
 
@@ -832,33 +836,10 @@ async fn test_mint_burn_from_interchain_transfer_with_approval(
         .await
         .unwrap();
 
-    // Have bob approve solana_wallet for a certain amount
-    let approve_amount = 500;
-    let approve_ix = spl_token_2022::instruction::approve(
-        &spl_token_2022::id(),
-        &bob_token_account,
-        &ctx.solana_wallet,
-        &bob.pubkey(),
-        &[],
-        approve_amount,
-    )?;
-
-    ctx.solana_chain
-        .fixture
-        .send_tx_with_custom_signers(
-            &[approve_ix],
-            &[
-                bob.insecure_clone(),
-                ctx.solana_chain.fixture.payer.insecure_clone(),
-            ],
-        )
-        .await;
-
-    // Make solana_wallet perform an interchain transfer from bob's account using approved amount
     let transfer_amount = 300;
     let interchain_transfer_ix = axelar_solana_its::instruction::interchain_transfer(
         ctx.solana_wallet,
-        ctx.solana_wallet,
+        bob.pubkey(),
         bob_token_account,
         token_id,
         ctx.evm_chain_name.clone(),
@@ -868,11 +849,71 @@ async fn test_mint_burn_from_interchain_transfer_with_approval(
         spl_token_2022::id(),
         0,
     )?;
+    let signers = &[
+        bob.insecure_clone(),
+        ctx.solana_chain.fixture.payer.insecure_clone(),
+    ];
+
+    // Without an allowance granted to the TokenManager, the burn must fail even
+    // though the owner of the source account signs the transfer (unlike MintBurn).
+    let result = ctx
+        .solana_chain
+        .fixture
+        .send_tx_with_custom_signers(&[interchain_transfer_ix.clone()], signers)
+        .await;
+    assert!(result.is_err());
+    assert_msg_present_in_logs(
+        result.unwrap_err(),
+        "TokenManager is not an approved delegate of the source token account",
+    );
+
+    // Approving an account other than the TokenManager is not enough either
+    let approve_wallet_ix = spl_token_2022::instruction::approve(
+        &spl_token_2022::id(),
+        &bob_token_account,
+        &ctx.solana_wallet,
+        &bob.pubkey(),
+        &[],
+        transfer_amount,
+    )?;
+    ctx.solana_chain
+        .fixture
+        .send_tx_with_custom_signers(&[approve_wallet_ix], signers)
+        .await
+        .unwrap();
+    let result = ctx
+        .solana_chain
+        .fixture
+        .send_tx_with_custom_signers(&[interchain_transfer_ix.clone()], signers)
+        .await;
+    assert!(result.is_err());
+
+    // Have bob approve the TokenManager for a certain amount
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+    let approve_amount = 500;
+    let approve_ix = spl_token_2022::instruction::approve(
+        &spl_token_2022::id(),
+        &bob_token_account,
+        &token_manager_pda,
+        &bob.pubkey(),
+        &[],
+        approve_amount,
+    )?;
+    ctx.solana_chain
+        .fixture
+        .send_tx_with_custom_signers(&[approve_ix], signers)
+        .await
+        .unwrap();
 
     // Simulate first to get the event
     let simulation_result = ctx
-        .simulate_solana_tx(&[interchain_transfer_ix.clone()])
-        .await;
+        .solana_chain
+        .fixture
+        .simulate_tx_with_custom_signers(&[interchain_transfer_ix.clone()], signers)
+        .await
+        .unwrap();
     let inner_ixs = simulation_result
         .simulation_details
         .unwrap()
@@ -886,8 +927,12 @@ async fn test_mint_burn_from_interchain_transfer_with_approval(
     >(&inner_ixs)
     .expect("CallContractEvent not found");
 
-    // Then execute the transaction
-    ctx.send_solana_tx(&[interchain_transfer_ix]).await.unwrap();
+    // Then execute the transaction, the TokenManager burns using the allowance
+    ctx.solana_chain
+        .fixture
+        .send_tx_with_custom_signers(&[interchain_transfer_ix], signers)
+        .await
+        .unwrap();
 
     // Verify the transfer was successful by checking bob's balance
     let bob_account_data = ctx
@@ -902,8 +947,8 @@ async fn test_mint_burn_from_interchain_transfer_with_approval(
         spl_token_2022::state::Account::unpack_from_slice(&bob_account_data)?;
     assert_eq!(bob_token_account_info.amount, mint_amount - transfer_amount);
 
-    // Verify bob still has delegate set to solana_wallet with remaining allowance
-    assert_eq!(bob_token_account_info.delegate.unwrap(), ctx.solana_wallet);
+    // Verify the TokenManager allowance was consumed
+    assert_eq!(bob_token_account_info.delegate.unwrap(), token_manager_pda);
     assert_eq!(
         bob_token_account_info.delegated_amount,
         approve_amount - transfer_amount
@@ -1115,7 +1160,7 @@ async fn test_source_address_stays_consistent_through_the_transfer(
         .first()
         .cloned()
         .unwrap();
-    let transfer_event = get_first_event_cpi_occurrence::<InterchainTransfer>(&inner_ixs)
+    let transfer_event = get_first_event_cpi_occurrence::<InterchainTransferV2>(&inner_ixs)
         .ok_or_else(|| anyhow!("InterchainTransfer not found"))
         .unwrap();
     let call_contract_event = get_first_event_cpi_occurrence::<
@@ -1180,3 +1225,332 @@ async fn test_source_address_stays_consistent_through_the_transfer(
 
     Ok(())
 }
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_outbound_transfer_pairs_with_hub_message(
+    ctx: &mut ItsTestContext,
+) -> anyhow::Result<()> {
+    let salt = solana_sdk::keccak::hash(b"HubMessagePairingToken").0;
+    let deploy_local_ix = axelar_solana_its::instruction::deploy_interchain_token(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        salt,
+        "Pairing Token".to_owned(),
+        "PTK".to_owned(),
+        9,
+        1000,
+        Some(ctx.solana_wallet),
+    )?;
+
+    ctx.send_solana_tx(&[
+        ComputeBudgetInstruction::set_compute_unit_limit(260_000),
+        deploy_local_ix,
+    ])
+    .await
+    .unwrap();
+
+    let token_id = axelar_solana_its::interchain_token_id(&ctx.solana_wallet, &salt);
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (interchain_token_mint, _) =
+        axelar_solana_its::find_interchain_token_pda(&its_root_pda, &token_id);
+    let user_token_account = get_associated_token_address_with_program_id(
+        &ctx.solana_wallet,
+        &interchain_token_mint,
+        &spl_token_2022::id(),
+    );
+
+    let transfer_ix = axelar_solana_its::instruction::interchain_transfer(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        user_token_account,
+        token_id,
+        ctx.evm_chain_name.clone(),
+        b"0x1234567890123456789012345678901234567890".to_vec(),
+        50,
+        interchain_token_mint,
+        spl_token_2022::id(),
+        0,
+    )?;
+
+    let simulation_result = ctx.simulate_solana_tx(&[transfer_ix]).await;
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    let hub_message_event = get_first_event_cpi_occurrence::<HubMessageSent>(&inner_ixs)
+        .expect("HubMessageSent not found");
+    let call_contract_event = get_first_event_cpi_occurrence::<
+        axelar_solana_gateway::events::CallContractEvent,
+    >(&inner_ixs)
+    .expect("CallContractEvent not found");
+
+    assert_eq!(
+        hub_message_event.payload_hash,
+        call_contract_event.payload_hash
+    );
+    assert_eq!(
+        hub_message_event.payload_hash,
+        solana_sdk::keccak::hash(&call_contract_event.payload).0
+    );
+    assert_eq!(
+        hub_message_event.hub_address,
+        call_contract_event.destination_contract_address
+    );
+    assert_eq!(hub_message_event.destination_chain, ctx.evm_chain_name);
+
+    Ok(())
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_interchain_transfer_paying_gas_with_token(
+    ctx: &mut ItsTestContext,
+) -> anyhow::Result<()> {
+    let salt = solana_sdk::keccak::hash(b"TokenGasTestToken").0;
+    let deploy_local_ix = axelar_solana_its::instruction::deploy_interchain_token(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        salt,
+        "Token Gas Test Token".to_owned(),
+        "TGT".to_owned(),
+        9,
+        1000,
+        Some(ctx.solana_wallet),
+    )?;
+
+    ctx.send_solana_tx(&[
+        ComputeBudgetInstruction::set_compute_unit_limit(260_000),
+        deploy_local_ix,
+    ])
+    .await
+    .unwrap();
+
+    let token_id = axelar_solana_its::interchain_token_id(&ctx.solana_wallet, &salt);
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (interchain_token_mint, _) =
+        axelar_solana_its::find_interchain_token_pda(&its_root_pda, &token_id);
+    let user_token_account = get_associated_token_address_with_program_id(
+        &ctx.solana_wallet,
+        &interchain_token_mint,
+        &spl_token_2022::id(),
+    );
+
+    // The gas service token account must exist before it can receive tokens
+    let (gas_config_pda, _) = axelar_solana_gas_service::get_config_pda();
+    let gas_token_account = get_associated_token_address_with_program_id(
+        &gas_config_pda,
+        &interchain_token_mint,
+        &spl_token_2022::id(),
+    );
+    ctx.send_solana_tx(&[create_associated_token_account(
+        &ctx.solana_wallet,
+        &gas_config_pda,
+        &interchain_token_mint,
+        &spl_token_2022::id(),
+    )])
+    .await
+    .unwrap();
+
+    let amount = 500;
+    let gas_amount = 100;
+    let transfer_ix = axelar_solana_its::instruction::interchain_transfer_with_token_gas(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        user_token_account,
+        token_id,
+        ctx.evm_chain_name.clone(),
+        ctx.evm_signer.wallet.address().as_bytes().to_vec(),
+        amount,
+        interchain_token_mint,
+        spl_token_2022::id(),
+        gas_amount,
+    )?;
+
+    let simulation_result = ctx.simulate_solana_tx(&[transfer_ix.clone()]).await;
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    let transfer_event = get_first_event_cpi_occurrence::<InterchainTransferV2>(&inner_ixs)
+        .ok_or_else(|| anyhow!("InterchainTransfer not found"))?;
+    let gas_paid_event = get_first_event_cpi_occurrence::<
+        axelar_solana_gas_service::events::GasPaidEventV2,
+    >(&inner_ixs)
+    .ok_or_else(|| anyhow!("GasPaidEventV2 not found"))?;
+
+    assert_eq!(transfer_event.amount, amount - gas_amount);
+    assert_eq!(gas_paid_event.amount, gas_amount);
+    assert_eq!(gas_paid_event.sender, ctx.solana_wallet);
+    assert_eq!(gas_paid_event.spl_token_account, Some(user_token_account));
+    assert_eq!(gas_paid_event.mint, Some(interchain_token_mint));
+
+    ctx.send_solana_tx(&[transfer_ix]).await.unwrap();
+
+    let user_account = ctx
+        .solana_chain
+        .try_get_account_no_checks(&user_token_account)
+        .await?
+        .unwrap();
+    let user_account = spl_token_2022::extension::StateWithExtensions::<
+        spl_token_2022::state::Account,
+    >::unpack(&user_account.data)?;
+    assert_eq!(user_account.base.amount, 1000 - amount);
+
+    let gas_account = ctx
+        .solana_chain
+        .try_get_account_no_checks(&gas_token_account)
+        .await?
+        .unwrap();
+    let gas_account = spl_token_2022::extension::StateWithExtensions::<
+        spl_token_2022::state::Account,
+    >::unpack(&gas_account.data)?;
+    assert_eq!(gas_account.base.amount, gas_amount);
+
+    Ok(())
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_outbound_transfers_carry_sequential_nonces(
+    ctx: &mut ItsTestContext,
+) -> anyhow::Result<()> {
+    let salt = solana_sdk::keccak::hash(b"OutboundNonceTestToken").0;
+    let deploy_local_ix = axelar_solana_its::instruction::deploy_interchain_token(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        salt,
+        "Outbound Nonce Test Token".to_owned(),
+        "ONT".to_owned(),
+        9,
+        1000,
+        Some(ctx.solana_wallet),
+    )?;
+
+    ctx.send_solana_tx(&[
+        ComputeBudgetInstruction::set_compute_unit_limit(260_000),
+        deploy_local_ix,
+    ])
+    .await
+    .unwrap();
+
+    let token_id = axelar_solana_its::interchain_token_id(&ctx.solana_wallet, &salt);
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (interchain_token_mint, _) =
+        axelar_solana_its::find_interchain_token_pda(&its_root_pda, &token_id);
+    let user_token_account = get_associated_token_address_with_program_id(
+        &ctx.solana_wallet,
+        &interchain_token_mint,
+        &spl_token_2022::id(),
+    );
+
+    let mut nonces = Vec::new();
+    for amount in [10, 20] {
+        let transfer_ix = axelar_solana_its::instruction::interchain_transfer(
+            ctx.solana_wallet,
+            ctx.solana_wallet,
+            user_token_account,
+            token_id,
+            ctx.evm_chain_name.clone(),
+            ctx.evm_signer.wallet.address().as_bytes().to_vec(),
+            amount,
+            interchain_token_mint,
+            spl_token_2022::id(),
+            0,
+        )?;
+
+        let simulation_result = ctx.simulate_solana_tx(&[transfer_ix.clone()]).await;
+        let inner_ixs = simulation_result
+            .simulation_details
+            .unwrap()
+            .inner_instructions
+            .unwrap()
+            .first()
+            .cloned()
+            .unwrap();
+        let transfer_event = get_first_event_cpi_occurrence::<InterchainTransferV2>(&inner_ixs)
+            .ok_or_else(|| anyhow!("InterchainTransfer not found"))?;
+        nonces.push(transfer_event.nonce);
+
+        ctx.send_solana_tx(&[transfer_ix]).await.unwrap();
+    }
+
+    assert_eq!(nonces[1], nonces[0] + 1, "nonces must be sequential");
+
+    let (outbound_nonce_pda, _) = axelar_solana_its::find_outbound_nonce_pda(&ctx.solana_wallet);
+    let outbound_nonce = ctx
+        .solana_chain
+        .fixture
+        .get_account(&outbound_nonce_pda, &axelar_solana_its::id())
+        .await
+        .deserialize::<OutboundNonce>(&outbound_nonce_pda)?;
+    assert_eq!(outbound_nonce.next_nonce, nonces[1] + 1);
+
+    Ok(())
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn transfer_to_local_chain_fails(ctx: &mut ItsTestContext) -> anyhow::Result<()> {
+    let salt = solana_sdk::keccak::hash(b"SelfTransferTestToken").0;
+    let deploy_local_ix = axelar_solana_its::instruction::deploy_interchain_token(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        salt,
+        "Self Transfer Test Token".to_owned(),
+        "STT".to_owned(),
+        9,
+        1000,
+        Some(ctx.solana_wallet),
+    )?;
+
+    ctx.send_solana_tx(&[
+        ComputeBudgetInstruction::set_compute_unit_limit(260_000),
+        deploy_local_ix,
+    ])
+    .await
+    .unwrap();
+
+    let token_id = axelar_solana_its::interchain_token_id(&ctx.solana_wallet, &salt);
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (interchain_token_mint, _) =
+        axelar_solana_its::find_interchain_token_pda(&its_root_pda, &token_id);
+    let user_token_account = get_associated_token_address_with_program_id(
+        &ctx.solana_wallet,
+        &interchain_token_mint,
+        &spl_token_2022::id(),
+    );
+
+    let transfer_ix = axelar_solana_its::instruction::interchain_transfer(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        user_token_account,
+        token_id,
+        ctx.solana_chain_name.clone(),
+        Pubkey::new_unique().to_bytes().to_vec(),
+        10,
+        interchain_token_mint,
+        spl_token_2022::id(),
+        0,
+    )?;
+
+    let tx = ctx.send_solana_tx(&[transfer_ix]).await.unwrap_err();
+    assert_eq!(
+        tx.result.clone().unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ItsError::DestinationIsLocalChain as u32)
+        )
+    );
+    assert_msg_present_in_logs(tx, "Use a regular SPL token transfer instead");
+
+    Ok(())
+}
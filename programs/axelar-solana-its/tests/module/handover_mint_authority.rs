@@ -567,6 +567,171 @@ async fn test_successful_handover_mint_authority(ctx: &mut ItsTestContext) {
     );
 }
 
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_successful_handover_multisig_mint_authority(ctx: &mut ItsTestContext) {
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    let carol = Keypair::new();
+
+    ctx.send_solana_tx(&[system_instruction::transfer(
+        &ctx.solana_chain.fixture.payer.pubkey(),
+        &alice.pubkey(),
+        u32::MAX.into(),
+    )])
+    .await
+    .unwrap();
+
+    // Create a 2-of-3 multisig to act as the mint authority
+    let multisig = Keypair::new();
+    let multisig_rent = ctx
+        .solana_chain
+        .fixture
+        .get_rent(spl_token_2022::state::Multisig::LEN)
+        .await;
+    let create_multisig_ix = system_instruction::create_account(
+        &alice.pubkey(),
+        &multisig.pubkey(),
+        multisig_rent,
+        spl_token_2022::state::Multisig::LEN as u64,
+        &spl_token_2022::id(),
+    );
+    let init_multisig_ix = spl_token_2022::instruction::initialize_multisig(
+        &spl_token_2022::id(),
+        &multisig.pubkey(),
+        &[&alice.pubkey(), &bob.pubkey(), &carol.pubkey()],
+        2,
+    )
+    .unwrap();
+
+    ctx.solana_chain
+        .fixture
+        .send_tx_with_custom_signers(
+            &[create_multisig_ix, init_multisig_ix],
+            &[
+                &alice.insecure_clone(),
+                &multisig.insecure_clone(),
+                &ctx.solana_chain.fixture.payer.insecure_clone(),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let multisig_token_mint = ctx
+        .solana_chain
+        .fixture
+        .init_new_mint(multisig.pubkey(), spl_token_2022::id(), 9)
+        .await;
+
+    let salt = solana_sdk::keccak::hash(b"MultisigToken").0;
+    let register_ix = axelar_solana_its::instruction::register_custom_token(
+        alice.pubkey(),
+        alice.pubkey(),
+        salt,
+        multisig_token_mint,
+        token_manager::Type::MintBurn,
+        spl_token_2022::id(),
+        None,
+    )
+    .unwrap();
+
+    ctx.solana_chain
+        .fixture
+        .send_tx_with_custom_signers(
+            &[register_ix],
+            &[
+                &alice.insecure_clone(),
+                &ctx.solana_chain.fixture.payer.insecure_clone(),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let token_id = axelar_solana_its::linked_token_id(&alice.pubkey(), &salt);
+
+    // Below the multisig threshold, the handover must fail
+    let handover_ix =
+        axelar_solana_its::instruction::token_manager::handover_multisig_mint_authority(
+            alice.pubkey(),
+            multisig.pubkey(),
+            &[alice.pubkey()],
+            token_id,
+            multisig_token_mint,
+            spl_token_2022::id(),
+        )
+        .unwrap();
+
+    let result = ctx
+        .solana_chain
+        .fixture
+        .send_tx_with_custom_signers(
+            &[handover_ix],
+            &[
+                &alice.insecure_clone(),
+                &ctx.solana_chain.fixture.payer.insecure_clone(),
+            ],
+        )
+        .await;
+    assert!(result.is_err());
+
+    let handover_ix =
+        axelar_solana_its::instruction::token_manager::handover_multisig_mint_authority(
+            alice.pubkey(),
+            multisig.pubkey(),
+            &[alice.pubkey(), bob.pubkey()],
+            token_id,
+            multisig_token_mint,
+            spl_token_2022::id(),
+        )
+        .unwrap();
+
+    ctx.solana_chain
+        .fixture
+        .send_tx_with_custom_signers(
+            &[handover_ix],
+            &[
+                &alice.insecure_clone(),
+                &bob.insecure_clone(),
+                &ctx.solana_chain.fixture.payer.insecure_clone(),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+
+    let mint_data = ctx
+        .solana_chain
+        .fixture
+        .get_account(&multisig_token_mint, &spl_token_2022::id())
+        .await
+        .data;
+    let mint_state = spl_token_2022::state::Mint::unpack(&mint_data).unwrap();
+    assert_eq!(
+        mint_state.mint_authority.unwrap(),
+        token_manager_pda,
+        "Token manager should be the mint authority after handover"
+    );
+
+    let (alice_roles_pda, _) = role_management::find_user_roles_pda(
+        &axelar_solana_its::id(),
+        &token_manager_pda,
+        &alice.pubkey(),
+    );
+    let alice_roles_data = ctx
+        .solana_chain
+        .fixture
+        .get_account(&alice_roles_pda, &axelar_solana_its::id())
+        .await;
+    let alice_roles = UserRoles::<Roles>::try_from_slice(&alice_roles_data.data).unwrap();
+    assert!(
+        alice_roles.contains(Roles::MINTER),
+        "The paying multisig signer should have minter role after handover"
+    );
+}
+
 #[test_context(ItsTestContext)]
 #[tokio::test]
 async fn test_fail_handover_mint_authority_for_lock_unlock_token(ctx: &mut ItsTestContext) {
@@ -0,0 +1,294 @@
+use axelar_solana_its::error::ItsError;
+use axelar_solana_its::events::{TokenManagerFeeCollectorSet, WithheldFeesHarvested};
+use axelar_solana_its::state::token_manager::Type as TokenManagerType;
+use axelar_solana_its_test_fixtures::{
+    SolanaItsIntegration, SolanaItsIntegrationMetadata, TestToken,
+};
+use event_cpi_test_utils::get_first_event_cpi_occurrence;
+use solana_program_test::tokio;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer as _;
+use solana_sdk::transaction::TransactionError;
+use spl_associated_token_account::instruction::create_associated_token_account;
+use spl_token_2022::extension::transfer_fee::TransferFeeAmount;
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use spl_token_2022::state::Account;
+
+const FEE_BASIS_POINTS: u16 = 1_000;
+const TRANSFER_AMOUNT: u64 = 10_000;
+const EXPECTED_FEE: u64 = 1_000;
+
+/// Registers a `LockUnlockFee` token whose withdraw withheld authority is
+/// `withdraw_withheld_authority`, or its token manager when `None`, and
+/// transfers [`TRANSFER_AMOUNT`] out so that fees are withheld in the token
+/// manager ATA.
+async fn setup(
+    withdraw_withheld_authority: Option<Pubkey>,
+) -> (SolanaItsIntegrationMetadata, TestToken) {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let operator = its.operator;
+    let salt = solana_sdk::keccak::hash(b"harvest-withheld-fees").to_bytes();
+    let token_id = axelar_solana_its::linked_token_id(&operator, &salt);
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+    let withdraw_withheld_authority = withdraw_withheld_authority.unwrap_or(token_manager_pda);
+
+    let mint = its
+        .init_new_mint_with_fee(
+            &operator,
+            &spl_token_2022::id(),
+            FEE_BASIS_POINTS,
+            u64::MAX,
+            9,
+            None,
+            Some(&withdraw_withheld_authority),
+        )
+        .await;
+    let token = its
+        .register_custom_token(
+            salt,
+            mint,
+            TokenManagerType::LockUnlockFee,
+            spl_token_2022::id(),
+        )
+        .await;
+    its.mint_test_tokens(&token, &operator, TRANSFER_AMOUNT)
+        .await
+        .unwrap();
+
+    let transfer_ix = axelar_solana_its::instruction::interchain_transfer(
+        operator,
+        operator,
+        token.associated_token_account(&operator),
+        token.token_id,
+        "ethereum".to_owned(),
+        vec![0xAB; 20],
+        TRANSFER_AMOUNT,
+        token.mint,
+        token.token_program,
+        0,
+    )
+    .unwrap();
+    its.send_tx(&[transfer_ix]).await.unwrap();
+
+    (its, token)
+}
+
+async fn token_account(
+    its: &mut SolanaItsIntegrationMetadata,
+    token_account: &Pubkey,
+) -> (u64, u64) {
+    let account = its
+        .try_get_account_no_checks(token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let account = StateWithExtensions::<Account>::unpack(&account.data).unwrap();
+    let withheld = account
+        .get_extension::<TransferFeeAmount>()
+        .unwrap()
+        .withheld_amount
+        .into();
+
+    (account.base.amount, withheld)
+}
+
+#[tokio::test]
+async fn test_operator_can_harvest_withheld_fees() {
+    let (mut its, token) = setup(None).await;
+    let operator = its.operator;
+    let destination = token.associated_token_account(&operator);
+    let token_manager_ata = token.associated_token_account(&token.token_manager_pda());
+    assert_eq!(
+        token_account(&mut its, &token_manager_ata).await,
+        (TRANSFER_AMOUNT - EXPECTED_FEE, EXPECTED_FEE)
+    );
+
+    let harvest_ix = axelar_solana_its::instruction::token_manager::harvest_withheld_fees(
+        operator,
+        token.token_id,
+        token.mint,
+        destination,
+        token.token_program,
+        &[],
+    )
+    .unwrap();
+    let simulation_result = its.simulate_tx(&[harvest_ix.clone()]).await.unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    let event = get_first_event_cpi_occurrence::<WithheldFeesHarvested>(&inner_ixs).unwrap();
+    assert_eq!(
+        event,
+        WithheldFeesHarvested {
+            token_id: token.token_id,
+            operator,
+            destination_token_account: destination,
+            amount: EXPECTED_FEE,
+        }
+    );
+
+    its.send_tx(&[harvest_ix]).await.unwrap();
+
+    assert_eq!(
+        token_account(&mut its, &token_manager_ata).await,
+        (TRANSFER_AMOUNT - EXPECTED_FEE, 0)
+    );
+    assert_eq!(token_account(&mut its, &destination).await.0, EXPECTED_FEE);
+}
+
+#[tokio::test]
+async fn test_only_operator_can_harvest_withheld_fees() {
+    let (mut its, token) = setup(None).await;
+    let impostor = Keypair::new();
+    let token_manager_ata = token.associated_token_account(&token.token_manager_pda());
+
+    let harvest_ix = axelar_solana_its::instruction::token_manager::harvest_withheld_fees(
+        impostor.pubkey(),
+        token.token_id,
+        token.mint,
+        token.associated_token_account(&its.operator),
+        token.token_program,
+        &[],
+    )
+    .unwrap();
+    let payer = its.fixture.payer.insecure_clone();
+    let res = its
+        .send_tx_with_custom_signers(&[harvest_ix], &[&payer, &impostor])
+        .await;
+
+    assert!(res.is_err());
+    assert_eq!(
+        token_account(&mut its, &token_manager_ata).await.1,
+        EXPECTED_FEE
+    );
+}
+
+#[tokio::test]
+async fn test_harvest_requires_token_manager_withdraw_authority() {
+    let (mut its, token) = setup(Some(Pubkey::new_unique())).await;
+    let operator = its.operator;
+
+    let harvest_ix = axelar_solana_its::instruction::token_manager::harvest_withheld_fees(
+        operator,
+        token.token_id,
+        token.mint,
+        token.associated_token_account(&operator),
+        token.token_program,
+        &[],
+    )
+    .unwrap();
+    let res = its.send_tx(&[harvest_ix]).await;
+
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_harvest_pays_out_to_fee_collector() {
+    let (mut its, token) = setup(None).await;
+    let operator = its.operator;
+    let collector_owner = Pubkey::new_unique();
+    let fee_collector = token.associated_token_account(&collector_owner);
+    let create_ata_ix = create_associated_token_account(
+        &operator,
+        &collector_owner,
+        &token.mint,
+        &token.token_program,
+    );
+    its.send_tx(&[create_ata_ix]).await.unwrap();
+
+    let set_collector_ix = axelar_solana_its::instruction::token_manager::set_fee_collector(
+        operator,
+        operator,
+        token.token_id,
+        Some(fee_collector),
+    )
+    .unwrap();
+    let simulation_result = its.simulate_tx(&[set_collector_ix.clone()]).await.unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    let event = get_first_event_cpi_occurrence::<TokenManagerFeeCollectorSet>(&inner_ixs).unwrap();
+    assert_eq!(
+        event,
+        TokenManagerFeeCollectorSet {
+            token_id: token.token_id,
+            operator,
+            fee_collector: Some(fee_collector),
+        }
+    );
+    its.send_tx(&[set_collector_ix]).await.unwrap();
+    assert_eq!(
+        its.token_manager(&token.token_id).await.fee_collector,
+        Some(fee_collector)
+    );
+
+    let harvest_to_operator_ix =
+        axelar_solana_its::instruction::token_manager::harvest_withheld_fees(
+            operator,
+            token.token_id,
+            token.mint,
+            token.associated_token_account(&operator),
+            token.token_program,
+            &[],
+        )
+        .unwrap();
+    let res = its.send_tx(&[harvest_to_operator_ix]).await;
+    assert_eq!(
+        res.unwrap_err().result.unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ItsError::FeeCollectorMismatch as u32)
+        )
+    );
+
+    let harvest_ix = axelar_solana_its::instruction::token_manager::harvest_withheld_fees(
+        operator,
+        token.token_id,
+        token.mint,
+        fee_collector,
+        token.token_program,
+        &[],
+    )
+    .unwrap();
+    its.send_tx(&[harvest_ix]).await.unwrap();
+
+    assert_eq!(
+        token_account(&mut its, &fee_collector).await.0,
+        EXPECTED_FEE
+    );
+}
+
+#[tokio::test]
+async fn test_only_operator_can_set_fee_collector() {
+    let (mut its, token) = setup(None).await;
+    let impostor = Keypair::new();
+
+    let set_collector_ix = axelar_solana_its::instruction::token_manager::set_fee_collector(
+        its.operator,
+        impostor.pubkey(),
+        token.token_id,
+        Some(token.associated_token_account(&impostor.pubkey())),
+    )
+    .unwrap();
+    let payer = its.fixture.payer.insecure_clone();
+    let res = its
+        .send_tx_with_custom_signers(&[set_collector_ix], &[&payer, &impostor])
+        .await;
+
+    assert!(res.is_err());
+    assert_eq!(its.token_manager(&token.token_id).await.fee_collector, None);
+}
@@ -0,0 +1,158 @@
+//! Compute unit benchmarks for the inbound transfer hot path.
+//!
+//! Every scenario relays an `InterchainTransfer` to ITS and records the
+//! compute units consumed by the `Execute` transaction in
+//! `tests/goldens/inbound_transfer_compute_units.golden`. A scenario fails
+//! when it consumes more than [`TOLERANCE_PERCENT`] above its recorded
+//! figure, which absorbs the bump search of PDAs derived from the random
+//! fixture keys. Improvements and accepted regressions show up as a diff in
+//! that file once regenerated with
+//! `UPDATE_GOLDENS=1 cargo test inbound_transfer_compute_units`, the figures
+//! are recorded the same way when the file doesn't exist yet.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use axelar_message_primitives::{DataPayload, EncodingScheme, SolanaAccountRepr};
+use axelar_solana_its_test_fixtures::{SolanaItsIntegration, SolanaItsIntegrationMetadata};
+use axelar_solana_memo_program::instruction::AxelarMemoInstruction;
+use solana_program_test::tokio;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer as _;
+
+const GOLDEN_PATH: &str = "tests/goldens/inbound_transfer_compute_units.golden";
+const TOLERANCE_PERCENT: u64 = 5;
+const AMOUNT: u64 = 100;
+
+/// Relays a transfer to `destination` and returns the compute units its
+/// execution consumed.
+async fn relay_transfer(
+    its: &mut SolanaItsIntegrationMetadata,
+    token: &axelar_solana_its_test_fixtures::TestToken,
+    destination: Pubkey,
+    data: Vec<u8>,
+) -> u64 {
+    its.relay_interchain_transfer("ethereum", token, destination, AMOUNT, data)
+        .await
+        .unwrap()
+        .metadata
+        .unwrap()
+        .compute_units_consumed
+}
+
+async fn measure() -> BTreeMap<&'static str, u64> {
+    let mut its = SolanaItsIntegration::builder()
+        .programs_to_deploy(vec![(
+            "axelar_solana_memo_program.so".into(),
+            axelar_solana_memo_program::id(),
+        )])
+        .build()
+        .setup()
+        .await;
+
+    let counter = axelar_solana_memo_program::get_counter_pda();
+    let payer = its.fixture.payer.pubkey();
+    its.send_tx(&[axelar_solana_memo_program::instruction::initialize(&payer, &counter).unwrap()])
+        .await
+        .unwrap();
+    let token = its
+        .deploy_interchain_token(
+            solana_sdk::keccak::hash(b"inbound-transfer-compute-units").to_bytes(),
+            "Test Token",
+            "TT",
+            9,
+            0,
+        )
+        .await;
+    let mut measurements = BTreeMap::new();
+
+    let destination = Pubkey::new_unique();
+    measurements.insert(
+        "transfer_creating_destination_ata",
+        relay_transfer(&mut its, &token, destination, vec![]).await,
+    );
+    measurements.insert(
+        "transfer_to_existing_destination_ata",
+        relay_transfer(&mut its, &token, destination, vec![]).await,
+    );
+
+    let (metadata, _) = mpl_token_metadata::accounts::Metadata::find_pda(&token.mint);
+    let (counter, _) = axelar_solana_memo_program::get_counter_pda();
+    let data = DataPayload::new(
+        &borsh::to_vec(&AxelarMemoInstruction::ProcessMemo {
+            memo: "compute units".to_owned(),
+        })
+        .unwrap(),
+        &[
+            SolanaAccountRepr {
+                pubkey: metadata.to_bytes().into(),
+                is_signer: false,
+                is_writable: false,
+            },
+            SolanaAccountRepr {
+                pubkey: counter.to_bytes().into(),
+                is_signer: false,
+                is_writable: true,
+            },
+        ],
+        EncodingScheme::Borsh,
+    )
+    .encode()
+    .unwrap();
+    measurements.insert(
+        "transfer_with_data",
+        relay_transfer(
+            &mut its,
+            &token,
+            axelar_solana_memo_program::id(),
+            data.clone(),
+        )
+        .await,
+    );
+    measurements.insert(
+        "transfer_with_data_to_existing_execute_pda",
+        relay_transfer(&mut its, &token, axelar_solana_memo_program::id(), data).await,
+    );
+
+    measurements
+}
+
+fn render(measurements: &BTreeMap<&'static str, u64>) -> String {
+    let mut out = String::new();
+    for (name, compute_units) in measurements {
+        writeln!(out, "{name}: {compute_units}").unwrap();
+    }
+    out
+}
+
+fn parse(golden: &str) -> BTreeMap<String, u64> {
+    golden
+        .lines()
+        .filter_map(|line| line.split_once(": "))
+        .map(|(name, compute_units)| (name.to_owned(), compute_units.parse().unwrap()))
+        .collect()
+}
+
+#[allow(clippy::panic)]
+#[tokio::test]
+async fn test_inbound_transfer_compute_units_within_goldens() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(GOLDEN_PATH);
+    let measurements = measure().await;
+
+    if std::env::var_os("UPDATE_GOLDENS").is_some() || !path.exists() {
+        std::fs::write(&path, render(&measurements)).unwrap();
+        return;
+    }
+
+    let recorded = parse(&std::fs::read_to_string(&path).unwrap());
+    for (name, compute_units) in &measurements {
+        let budget = recorded.get(*name).unwrap_or_else(|| {
+            panic!("no recorded compute units for {name}, rerun with UPDATE_GOLDENS=1")
+        });
+        assert!(
+            compute_units * 100 <= budget * (100 + TOLERANCE_PERCENT),
+            "{name} consumed {compute_units} compute units, recorded {budget}; review the regression and rerun with UPDATE_GOLDENS=1"
+        );
+    }
+}
@@ -0,0 +1,828 @@
+//! Account-order goldens for the public instruction builders.
+//!
+//! Every builder is invoked with deterministic inputs and the resulting
+//! `AccountMeta` list is rendered (index, signer/writable flags and a label
+//! for well-known keys) and compared against
+//! `tests/goldens/instruction_accounts.golden`. Any change to account order
+//! or flags shows up as a diff in that file, which must be reviewed and
+//! regenerated with `UPDATE_GOLDENS=1 cargo test instruction_goldens`.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use alloy_primitives::Bytes;
+use axelar_solana_encoding::types::messages::{CrossChainId, Message};
+use axelar_solana_gateway::state::incoming_message::command_id;
+use axelar_solana_its::instruction::{self, ExecuteInstructionInputs};
+use axelar_solana_its::state::token_manager::Type;
+use axelar_solana_its::state::{DestinationExecutionPolicy, PauseFlags};
+use axelar_solana_its::Roles;
+use interchain_token_transfer_gmp::{
+    DeployInterchainToken, GMPPayload, InterchainTransfer, LinkToken,
+};
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+const GOLDEN_PATH: &str = "tests/goldens/instruction_accounts.golden";
+
+const PAYER: Pubkey = Pubkey::new_from_array([1; 32]);
+const AUTHORITY: Pubkey = Pubkey::new_from_array([2; 32]);
+const OTHER: Pubkey = Pubkey::new_from_array([3; 32]);
+const MINT: Pubkey = Pubkey::new_from_array([4; 32]);
+const SOURCE_ACCOUNT: Pubkey = Pubkey::new_from_array([5; 32]);
+const SOURCE_PROGRAM: Pubkey = Pubkey::new_from_array([6; 32]);
+const INCOMING_MESSAGE: Pubkey = Pubkey::new_from_array([7; 32]);
+const MESSAGE_PAYLOAD: Pubkey = Pubkey::new_from_array([8; 32]);
+const MULTISIG: Pubkey = Pubkey::new_from_array([9; 32]);
+const MULTISIG_SIGNER_A: Pubkey = Pubkey::new_from_array([10; 32]);
+const MULTISIG_SIGNER_B: Pubkey = Pubkey::new_from_array([11; 32]);
+const SALT: [u8; 32] = [42; 32];
+const TOKEN_ID: [u8; 32] = [43; 32];
+const CHAIN: &str = "ethereum";
+const FLOW_EPOCH: u64 = 20_000;
+
+/// Maps well-known keys to stable labels; anything else is rendered as its
+/// base58 address, which is just as deterministic.
+struct Labels(HashMap<Pubkey, String>);
+
+impl Labels {
+    fn new() -> Self {
+        let mut labels = HashMap::new();
+        let mut add = |key: Pubkey, label: &str| {
+            labels.insert(key, label.to_owned());
+        };
+
+        add(PAYER, "payer");
+        add(AUTHORITY, "authority");
+        add(OTHER, "other");
+        add(MINT, "mint");
+        add(SOURCE_ACCOUNT, "source_account");
+        add(SOURCE_PROGRAM, "source_program");
+        add(INCOMING_MESSAGE, "incoming_message_pda");
+        add(MESSAGE_PAYLOAD, "message_payload_pda");
+        add(MULTISIG, "multisig");
+        add(MULTISIG_SIGNER_A, "multisig_signer_a");
+        add(MULTISIG_SIGNER_B, "multisig_signer_b");
+
+        add(axelar_solana_its::ID, "its_program");
+        add(axelar_solana_gateway::ID, "gateway_program");
+        add(axelar_solana_gas_service::ID, "gas_service_program");
+        add(solana_program::system_program::ID, "system_program");
+        add(
+            solana_program::sysvar::instructions::ID,
+            "sysvar_instructions",
+        );
+        add(solana_program::sysvar::rent::ID, "sysvar_rent");
+        add(spl_token_2022::ID, "token_program");
+        add(spl_associated_token_account::ID, "ata_program");
+        add(mpl_token_metadata::ID, "metadata_program");
+
+        let (its_root, _) = axelar_solana_its::find_its_root_pda();
+        add(its_root, "its_root_pda");
+        let (gateway_root, _) = axelar_solana_gateway::get_gateway_root_config_pda();
+        add(gateway_root, "gateway_root_pda");
+
+        let token_ids = [
+            ("token_id", TOKEN_ID),
+            (
+                "interchain_token_id",
+                axelar_solana_its::interchain_token_id(&AUTHORITY, &SALT),
+            ),
+            (
+                "canonical_token_id",
+                axelar_solana_its::canonical_interchain_token_id(&MINT),
+            ),
+            (
+                "linked_token_id",
+                axelar_solana_its::linked_token_id(&AUTHORITY, &SALT),
+            ),
+        ];
+        let mut resources = vec![("its_root_pda".to_owned(), its_root)];
+        let mut mints = vec![("mint".to_owned(), MINT)];
+        for (name, token_id) in token_ids {
+            let (token_manager, _) =
+                axelar_solana_its::find_token_manager_pda(&its_root, &token_id);
+            let (interchain_token, _) =
+                axelar_solana_its::find_interchain_token_pda(&its_root, &token_id);
+            add(token_manager, &format!("token_manager_pda({name})"));
+            add(interchain_token, &format!("interchain_token_pda({name})"));
+            let (token_observer, _) = axelar_solana_its::find_token_observer_pda(&token_manager);
+            add(token_observer, &format!("token_observer_pda({name})"));
+            let (flow_slot, _) = axelar_solana_its::find_flow_slot_pda(&token_manager, FLOW_EPOCH);
+            add(flow_slot, &format!("flow_slot_pda({name})"));
+            resources.push((format!("token_manager_pda({name})"), token_manager));
+            mints.push((format!("interchain_token_pda({name})"), interchain_token));
+
+            let (metadata, _) = mpl_token_metadata::accounts::Metadata::find_pda(&interchain_token);
+            add(
+                metadata,
+                &format!("metadata_pda(interchain_token_pda({name}))"),
+            );
+
+            let (approval, _) =
+                axelar_solana_its::find_deployment_approval_pda(&OTHER, &token_id, CHAIN);
+            add(approval, &format!("deployment_approval_pda({name})"));
+        }
+
+        let (program_data, _) = Pubkey::find_program_address(
+            &[axelar_solana_its::ID.as_ref()],
+            &solana_program::bpf_loader_upgradeable::ID,
+        );
+        add(program_data, "its_program_data");
+
+        for (name, program_id) in [
+            ("its", axelar_solana_its::ID),
+            ("gateway", axelar_solana_gateway::ID),
+            ("gas_service", axelar_solana_gas_service::ID),
+        ] {
+            let (event_authority, _) =
+                Pubkey::find_program_address(&[event_cpi::EVENT_AUTHORITY_SEED], &program_id);
+            add(event_authority, &format!("{name}_event_authority"));
+        }
+
+        let (gas_config, _) = axelar_solana_gas_service::get_config_pda();
+        add(gas_config, "gas_config_pda");
+        let (gas_paused_chains, _) = axelar_solana_gas_service::get_paused_chains_pda();
+        add(gas_paused_chains, "gas_paused_chains_pda");
+        let (gas_config_ata, _) = Pubkey::find_program_address(
+            &[
+                gas_config.as_ref(),
+                spl_token_2022::ID.as_ref(),
+                MINT.as_ref(),
+            ],
+            &spl_associated_token_account::ID,
+        );
+        add(gas_config_ata, "ata(gas_config_pda, mint)");
+        let (call_contract_signing, _) =
+            axelar_solana_gateway::get_call_contract_signing_pda(axelar_solana_its::ID);
+        add(call_contract_signing, "call_contract_signing_pda(its)");
+        let (cpi_call_contract_signing, _) =
+            axelar_solana_gateway::get_call_contract_signing_pda(SOURCE_PROGRAM);
+        add(
+            cpi_call_contract_signing,
+            "call_contract_signing_pda(source_program)",
+        );
+        let (metadata, _) = mpl_token_metadata::accounts::Metadata::find_pda(&MINT);
+        add(metadata, "metadata_pda(mint)");
+
+        let message = message();
+        let command_id = command_id(&message.cc_id.chain, &message.cc_id.id);
+        let (validate_signing, _) = axelar_solana_gateway::get_validate_message_signing_pda(
+            axelar_solana_its::ID,
+            command_id,
+        );
+        add(validate_signing, "validate_message_signing_pda(its)");
+
+        let users = [
+            ("payer", PAYER),
+            ("authority", AUTHORITY),
+            ("other", OTHER),
+            ("multisig", MULTISIG),
+        ];
+        for (user_name, user) in users
+            .iter()
+            .copied()
+            .chain([("source_program", SOURCE_PROGRAM)])
+        {
+            let (outbound_nonce, _) = axelar_solana_its::find_outbound_nonce_pda(&user);
+            add(outbound_nonce, &format!("outbound_nonce_pda({user_name})"));
+        }
+        for (resource_name, resource) in &resources {
+            for (user_name, user) in users {
+                let (roles, _) =
+                    role_management::find_user_roles_pda(&axelar_solana_its::ID, resource, &user);
+                add(
+                    roles,
+                    &format!("user_roles_pda({resource_name}, {user_name})"),
+                );
+
+                for (mint_name, mint) in &mints {
+                    let (ata, _) = Pubkey::find_program_address(
+                        &[user.as_ref(), spl_token_2022::ID.as_ref(), mint.as_ref()],
+                        &spl_associated_token_account::ID,
+                    );
+                    add(ata, &format!("ata({user_name}, {mint_name})"));
+                }
+
+                for (to_name, to) in users {
+                    for (role_name, role) in
+                        [("operator", Roles::OPERATOR), ("minter", Roles::MINTER)]
+                    {
+                        let (proposal, _) = role_management::find_roles_proposal_pda(
+                            &axelar_solana_its::ID,
+                            resource,
+                            &user,
+                            &to,
+                            role,
+                        );
+                        add(
+                            proposal,
+                            &format!(
+                                "roles_proposal_pda({resource_name}, {user_name} -> {to_name}, {role_name})"
+                            ),
+                        );
+                    }
+                }
+            }
+
+            for (mint_name, mint) in &mints {
+                let (ata, _) = Pubkey::find_program_address(
+                    &[
+                        resource.as_ref(),
+                        spl_token_2022::ID.as_ref(),
+                        mint.as_ref(),
+                    ],
+                    &spl_associated_token_account::ID,
+                );
+                add(ata, &format!("ata({resource_name}, {mint_name})"));
+            }
+        }
+
+        Self(labels)
+    }
+
+    fn label(&self, key: &Pubkey) -> String {
+        self.0.get(key).cloned().unwrap_or_else(|| key.to_string())
+    }
+}
+
+fn render(labels: &Labels, name: &str, ix: &Instruction, out: &mut String) {
+    writeln!(out, "== {name} ({})", labels.label(&ix.program_id)).unwrap();
+    for (index, meta) in ix.accounts.iter().enumerate() {
+        let signer = if meta.is_signer { 's' } else { '-' };
+        let writable = if meta.is_writable { 'w' } else { '-' };
+        writeln!(
+            out,
+            "{index:>3} {signer}{writable} {}",
+            labels.label(&meta.pubkey)
+        )
+        .unwrap();
+    }
+    out.push('\n');
+}
+
+fn message() -> Message {
+    Message {
+        cc_id: CrossChainId {
+            chain: "axelar".to_owned(),
+            id: "message-id".to_owned(),
+        },
+        source_address: "its-hub".to_owned(),
+        destination_chain: "solana".to_owned(),
+        destination_address: axelar_solana_its::ID.to_string(),
+        payload_hash: [0; 32],
+    }
+}
+
+fn execute(payload: GMPPayload, mint: Option<Pubkey>) -> Instruction {
+    let inputs = ExecuteInstructionInputs::builder()
+        .payer(PAYER)
+        .incoming_message_pda(INCOMING_MESSAGE)
+        .message_payload_pda(MESSAGE_PAYLOAD)
+        .message(message())
+        .payload(payload)
+        .token_program(spl_token_2022::ID)
+        .mint_opt(mint)
+        .build();
+
+    instruction::execute(inputs).unwrap()
+}
+
+fn cases() -> Vec<(&'static str, Instruction)> {
+    let token_program = spl_token_2022::ID;
+    let interchain_token_id = axelar_solana_its::interchain_token_id(&AUTHORITY, &SALT);
+    let linked_token_id = axelar_solana_its::linked_token_id(&AUTHORITY, &SALT);
+
+    vec![
+        (
+            "initialize",
+            instruction::initialize(PAYER, AUTHORITY, "solana".to_owned(), "hub".to_owned())
+                .unwrap(),
+        ),
+        (
+            "set_pause_status",
+            instruction::set_pause_status(AUTHORITY, true).unwrap(),
+        ),
+        (
+            "set_trusted_chain",
+            instruction::set_trusted_chain(PAYER, AUTHORITY, CHAIN.to_owned()).unwrap(),
+        ),
+        (
+            "remove_trusted_chain",
+            instruction::remove_trusted_chain(PAYER, AUTHORITY, CHAIN.to_owned()).unwrap(),
+        ),
+        (
+            "rename_trusted_chain",
+            instruction::rename_trusted_chain(
+                PAYER,
+                AUTHORITY,
+                CHAIN.to_owned(),
+                format!("{CHAIN}-renamed"),
+            )
+            .unwrap(),
+        ),
+        (
+            "set_pause_flags",
+            instruction::set_pause_flags(PAYER, AUTHORITY, PauseFlags::OUTBOUND_TRANSFERS).unwrap(),
+        ),
+        (
+            "set_max_payload_size",
+            instruction::set_max_payload_size(PAYER, AUTHORITY, CHAIN.to_owned(), Some(1024))
+                .unwrap(),
+        ),
+        (
+            "set_destination_execution_policy",
+            instruction::set_destination_execution_policy(
+                PAYER,
+                AUTHORITY,
+                Some(DestinationExecutionPolicy {
+                    max_accounts: 8,
+                    min_compute_units: 100_000,
+                }),
+            )
+            .unwrap(),
+        ),
+        (
+            "approve_deploy_remote_interchain_token",
+            instruction::approve_deploy_remote_interchain_token(
+                PAYER,
+                OTHER,
+                AUTHORITY,
+                SALT,
+                CHAIN.to_owned(),
+                vec![1; 20],
+            )
+            .unwrap(),
+        ),
+        (
+            "revoke_deploy_remote_interchain_token",
+            instruction::revoke_deploy_remote_interchain_token(
+                PAYER,
+                OTHER,
+                AUTHORITY,
+                SALT,
+                CHAIN.to_owned(),
+            )
+            .unwrap(),
+        ),
+        (
+            "register_canonical_interchain_token",
+            instruction::register_canonical_interchain_token(PAYER, MINT, token_program).unwrap(),
+        ),
+        (
+            "deploy_remote_canonical_interchain_token",
+            instruction::deploy_remote_canonical_interchain_token(PAYER, MINT, CHAIN.to_owned(), 0)
+                .unwrap(),
+        ),
+        (
+            "deploy_remote_canonical_interchain_token_with_gas_quote",
+            instruction::with_gas_quote(
+                instruction::deploy_remote_canonical_interchain_token(
+                    PAYER,
+                    MINT,
+                    CHAIN.to_owned(),
+                    0,
+                )
+                .unwrap(),
+                AUTHORITY,
+            ),
+        ),
+        (
+            "deploy_interchain_token",
+            instruction::deploy_interchain_token(
+                PAYER,
+                AUTHORITY,
+                SALT,
+                "Token".to_owned(),
+                "TKN".to_owned(),
+                9,
+                100,
+                Some(OTHER),
+            )
+            .unwrap(),
+        ),
+        (
+            "deploy_interchain_token (no minter)",
+            instruction::deploy_interchain_token(
+                PAYER,
+                AUTHORITY,
+                SALT,
+                "Token".to_owned(),
+                "TKN".to_owned(),
+                9,
+                100,
+                None,
+            )
+            .unwrap(),
+        ),
+        (
+            "deploy_interchain_token_with_on_mint_metadata",
+            instruction::deploy_interchain_token_with_on_mint_metadata(
+                PAYER,
+                AUTHORITY,
+                SALT,
+                "Token".to_owned(),
+                "TKN".to_owned(),
+                9,
+                100,
+                Some(OTHER),
+            )
+            .unwrap(),
+        ),
+        (
+            "deploy_remote_interchain_token",
+            instruction::deploy_remote_interchain_token(
+                PAYER,
+                AUTHORITY,
+                SALT,
+                CHAIN.to_owned(),
+                0,
+            )
+            .unwrap(),
+        ),
+        (
+            "deploy_remote_interchain_token_with_minter",
+            instruction::deploy_remote_interchain_token_with_minter(
+                PAYER,
+                AUTHORITY,
+                SALT,
+                OTHER,
+                CHAIN.to_owned(),
+                vec![1; 20],
+                0,
+            )
+            .unwrap(),
+        ),
+        (
+            "register_token_metadata",
+            instruction::register_token_metadata(PAYER, MINT, 0).unwrap(),
+        ),
+        (
+            "register_custom_token",
+            instruction::register_custom_token(
+                PAYER,
+                AUTHORITY,
+                SALT,
+                MINT,
+                Type::LockUnlock,
+                token_program,
+                Some(OTHER),
+            )
+            .unwrap(),
+        ),
+        (
+            "link_token",
+            instruction::link_token(
+                PAYER,
+                AUTHORITY,
+                SALT,
+                CHAIN.to_owned(),
+                vec![2; 20],
+                Type::MintBurn,
+                vec![],
+                0,
+            )
+            .unwrap(),
+        ),
+        (
+            "register_and_link_token",
+            instruction::register_and_link_token(
+                PAYER,
+                AUTHORITY,
+                SALT,
+                MINT,
+                Type::LockUnlock,
+                token_program,
+                Some(OTHER),
+                CHAIN.to_owned(),
+                vec![2; 20],
+                Type::MintBurn,
+                vec![],
+                0,
+                0,
+            )
+            .unwrap(),
+        ),
+        (
+            "interchain_transfer",
+            instruction::interchain_transfer(
+                PAYER,
+                AUTHORITY,
+                SOURCE_ACCOUNT,
+                TOKEN_ID,
+                CHAIN.to_owned(),
+                vec![3; 20],
+                1,
+                MINT,
+                token_program,
+                0,
+            )
+            .unwrap(),
+        ),
+        (
+            "interchain_transfer_with_token_gas",
+            instruction::interchain_transfer_with_token_gas(
+                PAYER,
+                AUTHORITY,
+                SOURCE_ACCOUNT,
+                TOKEN_ID,
+                CHAIN.to_owned(),
+                vec![3; 20],
+                10,
+                MINT,
+                token_program,
+                1,
+            )
+            .unwrap(),
+        ),
+        (
+            "cpi_interchain_transfer",
+            instruction::cpi_interchain_transfer(
+                PAYER,
+                AUTHORITY,
+                SOURCE_ACCOUNT,
+                TOKEN_ID,
+                CHAIN.to_owned(),
+                vec![3; 20],
+                1,
+                MINT,
+                token_program,
+                0,
+                SOURCE_PROGRAM,
+                vec![b"seed".to_vec()],
+            )
+            .unwrap(),
+        ),
+        (
+            "call_contract_with_interchain_token",
+            instruction::call_contract_with_interchain_token(
+                PAYER,
+                AUTHORITY,
+                SOURCE_ACCOUNT,
+                TOKEN_ID,
+                CHAIN.to_owned(),
+                vec![3; 20],
+                1,
+                MINT,
+                vec![4; 8],
+                token_program,
+                0,
+            )
+            .unwrap(),
+        ),
+        (
+            "cpi_call_contract_with_interchain_token",
+            instruction::cpi_call_contract_with_interchain_token(
+                PAYER,
+                AUTHORITY,
+                SOURCE_ACCOUNT,
+                TOKEN_ID,
+                CHAIN.to_owned(),
+                vec![3; 20],
+                1,
+                MINT,
+                vec![4; 8],
+                token_program,
+                0,
+                SOURCE_PROGRAM,
+                vec![b"seed".to_vec()],
+            )
+            .unwrap(),
+        ),
+        (
+            "set_flow_limit",
+            instruction::set_flow_limit(PAYER, AUTHORITY, TOKEN_ID, Some(10)).unwrap(),
+        ),
+        (
+            "execute (InterchainTransfer)",
+            execute(
+                GMPPayload::InterchainTransfer(InterchainTransfer {
+                    selector: InterchainTransfer::MESSAGE_TYPE_ID.try_into().unwrap(),
+                    token_id: TOKEN_ID.into(),
+                    source_address: [5; 20].into(),
+                    destination_address: OTHER.to_bytes().into(),
+                    amount: 1_u64.try_into().unwrap(),
+                    data: Bytes::new(),
+                }),
+                Some(MINT),
+            ),
+        ),
+        (
+            "estimate_inbound_rent",
+            instruction::estimate_inbound_rent(
+                GMPPayload::InterchainTransfer(InterchainTransfer {
+                    selector: InterchainTransfer::MESSAGE_TYPE_ID.try_into().unwrap(),
+                    token_id: TOKEN_ID.into(),
+                    source_address: [5; 20].into(),
+                    destination_address: OTHER.to_bytes().into(),
+                    amount: 1_u64.try_into().unwrap(),
+                    data: Bytes::new(),
+                })
+                .encode(),
+                MINT,
+                token_program,
+            )
+            .unwrap(),
+        ),
+        (
+            "execute (DeployInterchainToken)",
+            execute(
+                GMPPayload::DeployInterchainToken(DeployInterchainToken {
+                    selector: DeployInterchainToken::MESSAGE_TYPE_ID.try_into().unwrap(),
+                    token_id: interchain_token_id.into(),
+                    name: "Token".to_owned(),
+                    symbol: "TKN".to_owned(),
+                    decimals: 9,
+                    minter: OTHER.to_bytes().into(),
+                }),
+                None,
+            ),
+        ),
+        (
+            "execute (LinkToken)",
+            execute(
+                GMPPayload::LinkToken(LinkToken {
+                    selector: LinkToken::MESSAGE_TYPE_ID.try_into().unwrap(),
+                    token_id: linked_token_id.into(),
+                    token_manager_type: Type::LockUnlock.into(),
+                    source_token_address: vec![6; 20].into(),
+                    destination_token_address: MINT.to_bytes().into(),
+                    link_params: OTHER.to_bytes().into(),
+                }),
+                Some(MINT),
+            ),
+        ),
+        (
+            "transfer_operatorship",
+            instruction::transfer_operatorship(PAYER, AUTHORITY, OTHER).unwrap(),
+        ),
+        (
+            "transfer_operatorship_with_grace_period",
+            instruction::transfer_operatorship_with_grace_period(PAYER, AUTHORITY, OTHER, 100)
+                .unwrap(),
+        ),
+        (
+            "propose_operatorship",
+            instruction::propose_operatorship(PAYER, AUTHORITY, OTHER).unwrap(),
+        ),
+        (
+            "accept_operatorship",
+            instruction::accept_operatorship(PAYER, OTHER, AUTHORITY).unwrap(),
+        ),
+        (
+            "interchain_token::mint",
+            instruction::interchain_token::mint(TOKEN_ID, MINT, OTHER, AUTHORITY, token_program, 1)
+                .unwrap(),
+        ),
+        (
+            "interchain_token::transfer_mintership",
+            instruction::interchain_token::transfer_mintership(PAYER, AUTHORITY, TOKEN_ID, OTHER)
+                .unwrap(),
+        ),
+        (
+            "interchain_token::propose_mintership",
+            instruction::interchain_token::propose_mintership(PAYER, AUTHORITY, TOKEN_ID, OTHER)
+                .unwrap(),
+        ),
+        (
+            "interchain_token::accept_mintership",
+            instruction::interchain_token::accept_mintership(PAYER, OTHER, TOKEN_ID, AUTHORITY)
+                .unwrap(),
+        ),
+        (
+            "interchain_token::set_metadata_authority",
+            instruction::interchain_token::set_metadata_authority(
+                PAYER, AUTHORITY, OTHER, TOKEN_ID, MINT, OTHER,
+            )
+            .unwrap(),
+        ),
+        (
+            "token_manager::set_flow_limit",
+            instruction::token_manager::set_flow_limit(PAYER, AUTHORITY, TOKEN_ID, None).unwrap(),
+        ),
+        (
+            "token_manager::add_flow_limiter",
+            instruction::token_manager::add_flow_limiter(PAYER, AUTHORITY, TOKEN_ID, OTHER)
+                .unwrap(),
+        ),
+        (
+            "token_manager::remove_flow_limiter",
+            instruction::token_manager::remove_flow_limiter(PAYER, AUTHORITY, TOKEN_ID, OTHER)
+                .unwrap(),
+        ),
+        (
+            "token_manager::transfer_operatorship",
+            instruction::token_manager::transfer_operatorship(PAYER, AUTHORITY, TOKEN_ID, OTHER)
+                .unwrap(),
+        ),
+        (
+            "token_manager::propose_operatorship",
+            instruction::token_manager::propose_operatorship(PAYER, AUTHORITY, TOKEN_ID, OTHER)
+                .unwrap(),
+        ),
+        (
+            "token_manager::accept_operatorship",
+            instruction::token_manager::accept_operatorship(PAYER, OTHER, TOKEN_ID, AUTHORITY)
+                .unwrap(),
+        ),
+        (
+            "token_manager::handover_mint_authority",
+            instruction::token_manager::handover_mint_authority(
+                PAYER,
+                AUTHORITY,
+                TOKEN_ID,
+                MINT,
+                token_program,
+            )
+            .unwrap(),
+        ),
+        (
+            "token_manager::handover_multisig_mint_authority",
+            instruction::token_manager::handover_multisig_mint_authority(
+                PAYER,
+                MULTISIG,
+                &[MULTISIG_SIGNER_A, MULTISIG_SIGNER_B],
+                TOKEN_ID,
+                MINT,
+                token_program,
+            )
+            .unwrap(),
+        ),
+        (
+            "token_manager::set_max_supply",
+            instruction::token_manager::set_max_supply(PAYER, AUTHORITY, TOKEN_ID, MINT, 1_000)
+                .unwrap(),
+        ),
+        (
+            "token_manager::set_fee_collector",
+            instruction::token_manager::set_fee_collector(PAYER, AUTHORITY, TOKEN_ID, Some(MINT))
+                .unwrap(),
+        ),
+        (
+            "token_manager::verify_token_manager",
+            instruction::token_manager::verify_token_manager(
+                TOKEN_ID,
+                MINT,
+                spl_associated_token_account::get_associated_token_address_with_program_id(
+                    &axelar_solana_its::find_token_manager_pda(
+                        &axelar_solana_its::find_its_root_pda().0,
+                        &TOKEN_ID,
+                    )
+                    .0,
+                    &MINT,
+                    &spl_token_2022::ID,
+                ),
+            )
+            .unwrap(),
+        ),
+        (
+            "token_manager::migrate_flow_slot",
+            instruction::token_manager::migrate_flow_slot(PAYER, AUTHORITY, TOKEN_ID, FLOW_EPOCH)
+                .unwrap(),
+        ),
+        (
+            "token_manager::get_flow_status",
+            instruction::token_manager::get_flow_status(TOKEN_ID).unwrap(),
+        ),
+        (
+            "token_manager::decommission",
+            instruction::token_manager::decommission(
+                PAYER,
+                AUTHORITY,
+                TOKEN_ID,
+                MINT,
+                spl_token_2022::ID,
+                Some(OTHER),
+                &[AUTHORITY, OTHER],
+            )
+            .unwrap(),
+        ),
+    ]
+}
+
+pub(crate) fn render_goldens() -> String {
+    let labels = Labels::new();
+    let mut out = String::new();
+    for (name, ix) in cases() {
+        render(&labels, name, &ix, &mut out);
+    }
+    out
+}
+
+#[test]
+fn instruction_account_order_matches_goldens() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(GOLDEN_PATH);
+    let actual = render_goldens();
+
+    if std::env::var_os("UPDATE_GOLDENS").is_some() {
+        std::fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(
+        expected, actual,
+        "instruction account layout changed; review the diff and rerun with UPDATE_GOLDENS=1"
+    );
+}
@@ -0,0 +1,81 @@
+use axelar_solana_its::link_params::LinkParams;
+use axelar_solana_its::state::token_manager::Type as TokenManagerType;
+use axelar_solana_its::Roles;
+use axelar_solana_its_test_fixtures::{SolanaItsIntegration, SolanaItsIntegrationMetadata};
+use interchain_token_transfer_gmp::alloy_primitives::U256;
+use interchain_token_transfer_gmp::{GMPPayload, LinkToken};
+use role_management::state::UserRoles;
+use solana_program_test::tokio;
+use solana_sdk::pubkey::Pubkey;
+
+const TOKEN_ID: [u8; 32] = [7; 32];
+
+fn link_token(mint: Pubkey, link_params: Vec<u8>) -> GMPPayload {
+    GMPPayload::LinkToken(LinkToken {
+        selector: U256::from(LinkToken::MESSAGE_TYPE_ID),
+        token_id: TOKEN_ID.into(),
+        token_manager_type: TokenManagerType::LockUnlock.into(),
+        source_token_address: vec![6; 20].into(),
+        destination_token_address: mint.to_bytes().to_vec().into(),
+        link_params: link_params.into(),
+    })
+}
+
+async fn roles(its: &mut SolanaItsIntegrationMetadata, user: &Pubkey) -> UserRoles<Roles> {
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &TOKEN_ID);
+    let (roles_pda, _) =
+        role_management::find_user_roles_pda(&axelar_solana_its::ID, &token_manager_pda, user);
+
+    its.load_borsh_pda(&roles_pda).await
+}
+
+#[tokio::test]
+async fn test_link_params_set_operator_and_flow_limiter() {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let operator = its.operator;
+    let mint = its.init_new_mint(operator, spl_token_2022::ID, 9).await;
+    let operator = Pubkey::new_unique();
+    let flow_limiter = Pubkey::new_unique();
+    let link_params = LinkParams {
+        operator: Some(operator),
+        flow_limiter: Some(flow_limiter),
+    };
+
+    its.relay_to_its(
+        "ethereum",
+        &link_token(mint, link_params.encode()),
+        Some(mint),
+        spl_token_2022::ID,
+    )
+    .await
+    .unwrap();
+
+    let operator_roles = roles(&mut its, &operator).await;
+    assert!(operator_roles.contains(Roles::OPERATOR));
+    assert!(!operator_roles.contains(Roles::FLOW_LIMITER));
+    let flow_limiter_roles = roles(&mut its, &flow_limiter).await;
+    assert!(flow_limiter_roles.contains(Roles::FLOW_LIMITER));
+    assert!(!flow_limiter_roles.contains(Roles::OPERATOR));
+}
+
+#[tokio::test]
+async fn test_link_params_operator_is_also_flow_limiter() {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let operator = its.operator;
+    let mint = its.init_new_mint(operator, spl_token_2022::ID, 9).await;
+    let operator = Pubkey::new_unique();
+
+    its.relay_to_its(
+        "ethereum",
+        &link_token(mint, operator.to_bytes().to_vec()),
+        Some(mint),
+        spl_token_2022::ID,
+    )
+    .await
+    .unwrap();
+
+    let operator_roles = roles(&mut its, &operator).await;
+    assert!(operator_roles.contains(Roles::OPERATOR | Roles::FLOW_LIMITER));
+}
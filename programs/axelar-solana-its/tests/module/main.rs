@@ -19,22 +19,51 @@
     unused_must_use
 )]
 
+mod ata_rent_reimbursement;
+mod classic_token_program;
+mod configuration_events;
+mod decommission_token_manager;
+mod default_account_state;
 mod deploy_interchain_token;
 mod deploy_manager_mismatch;
 mod deploy_remote;
+mod deploy_remote_gas_quote;
+mod destination_execution_policy;
+mod destination_owner_policy;
+mod existing_token_metadata;
 mod fee_handling;
 mod flow_limits;
 mod from_evm_to_solana;
 mod from_solana_to_evm;
 mod handover_mint_authority;
+mod harvest_withheld_fees;
 mod idempotent_ata_test;
+mod inbound_transfer_compute_units;
+mod instruction_goldens;
+mod link_params;
+mod max_supply;
 mod memo_cpi_transfer;
 mod metadata_length_validation;
 mod metadata_retrieval;
+mod migrate_flow_slot;
+mod native_mint;
+mod on_mint_metadata;
+mod operator_grace_period;
+mod outbound_program_accounts;
 mod pause_unpause;
+mod payload_size_limit;
+mod register_and_link_token;
+mod rename_trusted_chain;
+mod resize_token_manager;
 mod role_management;
+mod token_id_preview;
 mod token_id_validation;
+mod token_manager_authority_mismatch;
+mod token_metadata_authority;
+mod token_observer;
 mod transfer_destination;
+mod transfer_properties;
+mod verify_token_manager;
 
 use solana_banks_interface::BanksTransactionResultWithSimulation;
 use solana_program_test::BanksTransactionResultWithMetadata;
@@ -47,19 +76,13 @@ use solana_sdk::program_pack::Pack as _;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
-use solana_sdk::system_instruction;
 use test_context::AsyncTestContext;
 
-use axelar_solana_encoding::types::messages::Message;
 use axelar_solana_gateway::events::CallContractEvent;
-use axelar_solana_gateway::state::incoming_message::command_id;
-use axelar_solana_gateway_test_fixtures::base::workspace_root_dir;
-use axelar_solana_gateway_test_fixtures::gas_service::GasServiceUtils;
-use axelar_solana_gateway_test_fixtures::gateway::random_message;
-use axelar_solana_gateway_test_fixtures::{
-    SolanaAxelarIntegration, SolanaAxelarIntegrationMetadata,
+use axelar_solana_its_test_fixtures::{
+    SolanaItsIntegration, SolanaItsIntegrationMetadata, ITS_HUB_CHAIN_NAME,
+    ITS_HUB_TRUSTED_CONTRACT_ADDRESS,
 };
-use axelar_solana_its::instruction::ExecuteInstructionInputs;
 use event_cpi_test_utils::get_first_event_cpi_occurrence;
 use evm_contracts_test_suite::chain::TestBlockchain;
 use evm_contracts_test_suite::ethers::abi::Detokenize;
@@ -79,14 +102,10 @@ use program_utils::pda::BorshPda;
 
 const SOLANA_CHAIN_NAME: &str = "solana-localnet";
 const EVM_CHAIN_NAME: &str = "ethereum";
-const ITS_HUB_TRUSTED_CHAIN_NAME: &str = "axelar";
-const ITS_HUB_TRUSTED_CONTRACT_ADDRESS: &str =
-    "axelar157hl7gpuknjmhtac2qnphuazv2yerfagva7lsu9vuj2pgn32z22qa26dk4";
 
 pub struct ItsTestContext {
-    pub solana_chain: SolanaAxelarIntegrationMetadata,
+    pub solana_chain: SolanaItsIntegrationMetadata,
     pub solana_wallet: Pubkey,
-    pub solana_gas_utils: GasServiceUtils,
     pub evm_chain: TestBlockchain,
     pub solana_chain_name: String,
     pub evm_chain_name: String,
@@ -100,23 +119,15 @@ pub struct ItsTestContext {
 
 impl AsyncTestContext for ItsTestContext {
     async fn setup() -> Self {
-        let (mut solana_chain, counter_pda) = axelar_solana_setup().await;
+        let (solana_chain, counter_pda) = axelar_solana_setup().await;
         let (evm_chain, evm_signer, evm_its_contracts, evm_weighted_signers, evm_domain_separator) =
             axelar_evm_setup().await;
-        let solana_gas_utils = solana_chain.fixture.deploy_gas_service().await;
-
-        solana_chain
-            .fixture
-            .init_gas_config(&solana_gas_utils)
-            .await
-            .unwrap();
 
         let solana_wallet = solana_chain.fixture.payer.pubkey();
 
         let mut this = Self {
             solana_chain,
             solana_wallet,
-            solana_gas_utils,
             evm_chain,
             solana_chain_name: SOLANA_CHAIN_NAME.to_string(),
             evm_chain_name: EVM_CHAIN_NAME.to_string(),
@@ -150,52 +161,13 @@ impl ItsTestContext {
             self.evm_chain_name.clone(),
         );
 
-        let encoded_payload = payload.encode();
-        let payload_hash = solana_sdk::keccak::hash(&encoded_payload).to_bytes();
-        let message = random_hub_message_with_destination_and_payload(
-            axelar_solana_its::id().to_string(),
-            payload_hash,
-        );
-
-        let message_from_multisig_prover = self
-            .solana_chain
-            .sign_session_and_approve_messages(
-                &self.solana_chain.signers.clone(),
-                &[message.clone()],
-            )
-            .await
-            .unwrap();
-
-        let message_payload_pda = self
+        // Action: set message status as executed by calling the destination program
+        let instruction = self
             .solana_chain
-            .upload_message_payload(&message, &encoded_payload)
+            .approve_its_message(payload, maybe_mint, token_program)
             .await
             .unwrap();
 
-        // Action: set message status as executed by calling the destination program
-        let (incoming_message_pda, ..) = axelar_solana_gateway::get_incoming_message_pda(
-            &command_id(&message.cc_id.chain, &message.cc_id.id),
-        );
-
-        let merkelised_message = message_from_multisig_prover
-            .iter()
-            .find(|x| x.leaf.message.cc_id == message.cc_id)
-            .unwrap()
-            .clone();
-
-        let its_ix_inputs = ExecuteInstructionInputs::builder()
-            .payer(self.solana_chain.fixture.payer.pubkey())
-            .incoming_message_pda(incoming_message_pda)
-            .message_payload_pda(message_payload_pda)
-            .message(merkelised_message.leaf.message)
-            .payload(payload)
-            .token_program(token_program)
-            .mint_opt(maybe_mint)
-            .build();
-
-        let instruction = axelar_solana_its::instruction::execute(its_ix_inputs)
-            .expect("failed to create instruction");
-
         // Simulate first to get inner_ixs for event extraction
         let simulation_result = self.simulate_solana_tx(&[instruction.clone()]).await;
         let inner_ixs = simulation_result
@@ -252,7 +224,7 @@ impl ItsTestContext {
         let encoded_payload = payload.encode();
         let payload_hash = solana_sdk::keccak::hash(&encoded_payload).to_bytes();
         let message = EvmAxelarMessage {
-            source_chain: ITS_HUB_TRUSTED_CHAIN_NAME.to_owned(),
+            source_chain: ITS_HUB_CHAIN_NAME.to_owned(),
             message_id: String::from_utf8_lossy(&payload_hash).to_string(),
             source_address: ITS_HUB_TRUSTED_CONTRACT_ADDRESS.to_string(),
             contract_address: self.evm_its_contracts.interchain_token_service.address(),
@@ -279,7 +251,7 @@ impl ItsTestContext {
             .evm_its_contracts
             .gateway
             .is_message_approved(
-                ITS_HUB_TRUSTED_CHAIN_NAME.to_owned(),
+                ITS_HUB_CHAIN_NAME.to_owned(),
                 message.message_id.clone(),
                 message.source_address.clone(),
                 message.contract_address,
@@ -293,10 +265,7 @@ impl ItsTestContext {
         let command_id = self
             .evm_its_contracts
             .gateway
-            .message_to_command_id(
-                ITS_HUB_TRUSTED_CHAIN_NAME.to_owned(),
-                message.message_id.clone(),
-            )
+            .message_to_command_id(ITS_HUB_CHAIN_NAME.to_owned(), message.message_id.clone())
             .await
             .unwrap();
 
@@ -443,7 +412,7 @@ impl ItsTestContext {
 
         // Find the InterchainTransfer event
         let transfer_event = get_first_event_cpi_occurrence::<
-            axelar_solana_its::events::InterchainTransfer,
+            axelar_solana_its::events::InterchainTransferV2,
         >(&inner_ixs)
         .expect("InterchainTransfer event not found");
 
@@ -509,7 +478,7 @@ impl ItsTestContext {
             )
             .await;
         let transfer_received_event = get_first_event_cpi_occurrence::<
-            axelar_solana_its::events::InterchainTransferReceived,
+            axelar_solana_its::events::InterchainTransferReceivedV2,
         >(&inner_ixs)
         .unwrap();
         assert_eq!(transfer_received_event.amount, amount_back);
@@ -533,26 +502,15 @@ impl ItsTestContext {
     }
 }
 
-async fn axelar_solana_setup() -> (SolanaAxelarIntegrationMetadata, Pubkey) {
-    let programs = vec![
-        ("axelar_solana_its.so".into(), axelar_solana_its::id()),
-        (
-            workspace_root_dir()
-                .join("programs")
-                .join("axelar-solana-its")
-                .join("tests")
-                .join("mpl_token_metadata.so"),
-            mpl_token_metadata::ID,
-        ),
-        (
+async fn axelar_solana_setup() -> (SolanaItsIntegrationMetadata, Pubkey) {
+    let mut solana_chain = SolanaItsIntegration::builder()
+        .chain_name(SOLANA_CHAIN_NAME.to_owned())
+        .trusted_chains(vec![EVM_CHAIN_NAME.to_owned()])
+        .initial_signer_weights(vec![555, 222])
+        .programs_to_deploy(vec![(
             "axelar_solana_memo_program.so".into(),
             axelar_solana_memo_program::id(),
-        ),
-    ];
-
-    let mut solana_chain = SolanaAxelarIntegration::builder()
-        .initial_signer_weights(vec![555, 222])
-        .programs_to_deploy(programs)
+        )])
         .build()
         .setup()
         .await;
@@ -568,36 +526,6 @@ async fn axelar_solana_setup() -> (SolanaAxelarIntegrationMetadata, Pubkey) {
         .unwrap()])
         .await;
 
-    let _metadata = solana_chain
-        .fixture
-        .send_tx_with_custom_signers(
-            &[
-                system_instruction::transfer(
-                    &solana_chain.fixture.payer.pubkey(),
-                    &solana_chain.upgrade_authority.pubkey(),
-                    u32::MAX.into(),
-                ),
-                axelar_solana_its::instruction::initialize(
-                    solana_chain.upgrade_authority.pubkey(),
-                    solana_chain.fixture.payer.pubkey(),
-                    SOLANA_CHAIN_NAME.to_owned(),
-                    ITS_HUB_TRUSTED_CONTRACT_ADDRESS.to_owned(),
-                )
-                .unwrap(),
-                axelar_solana_its::instruction::set_trusted_chain(
-                    solana_chain.fixture.payer.pubkey(),
-                    solana_chain.upgrade_authority.pubkey(),
-                    EVM_CHAIN_NAME.to_owned(),
-                )
-                .unwrap(),
-            ],
-            &[
-                &solana_chain.upgrade_authority.insecure_clone(),
-                &solana_chain.fixture.payer.insecure_clone(),
-            ],
-        )
-        .await;
-
     (solana_chain, counter_pda)
 }
 
@@ -634,7 +562,7 @@ async fn axelar_evm_setup() -> (
     its_contracts
         .interchain_token_service
         .set_trusted_address(
-            ITS_HUB_TRUSTED_CHAIN_NAME.to_owned(),
+            ITS_HUB_CHAIN_NAME.to_owned(),
             ITS_HUB_TRUSTED_CONTRACT_ADDRESS.to_owned(),
         )
         .send()
@@ -652,18 +580,6 @@ async fn axelar_evm_setup() -> (
     )
 }
 
-#[must_use]
-pub fn random_hub_message_with_destination_and_payload(
-    destination_address: String,
-    payload_hash: [u8; 32],
-) -> Message {
-    let mut message = random_message();
-    message.destination_address = destination_address;
-    message.payload_hash = payload_hash;
-    message.source_address = ITS_HUB_TRUSTED_CONTRACT_ADDRESS.to_string();
-    message
-}
-
 #[allow(clippy::panic)]
 fn route_its_hub(payload: GMPPayload, source_chain: String) -> GMPPayload {
     let GMPPayload::SendToHub(inner) = payload else {
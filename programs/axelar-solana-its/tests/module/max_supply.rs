@@ -0,0 +1,182 @@
+use axelar_solana_its::error::ItsError;
+use axelar_solana_its::events::TokenManagerMaxSupplySet;
+use axelar_solana_its_test_fixtures::{
+    SolanaItsIntegration, SolanaItsIntegrationMetadata, TestToken,
+};
+use event_cpi_test_utils::assert_event_cpi;
+use solana_program_test::{tokio, BanksTransactionResultWithMetadata};
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::TransactionError;
+
+async fn set_max_supply(
+    its: &mut SolanaItsIntegrationMetadata,
+    token: &TestToken,
+    max_supply: u64,
+) -> Result<BanksTransactionResultWithMetadata, BanksTransactionResultWithMetadata> {
+    let operator = its.operator;
+    let ix = axelar_solana_its::instruction::token_manager::set_max_supply(
+        operator,
+        operator,
+        token.token_id,
+        token.mint,
+        max_supply,
+    )
+    .unwrap();
+
+    its.send_tx(&[ix]).await
+}
+
+async fn mint(
+    its: &mut SolanaItsIntegrationMetadata,
+    token: &TestToken,
+    amount: u64,
+) -> Result<BanksTransactionResultWithMetadata, BanksTransactionResultWithMetadata> {
+    let operator = its.operator;
+    let ix = axelar_solana_its::instruction::interchain_token::mint(
+        token.token_id,
+        token.mint,
+        token.associated_token_account(&operator),
+        operator,
+        token.token_program,
+        amount,
+    )
+    .unwrap();
+
+    its.send_tx(&[ix]).await
+}
+
+fn transaction_error(res: BanksTransactionResultWithMetadata) -> TransactionError {
+    res.result.unwrap_err()
+}
+
+fn instruction_error(index: u8, error: ItsError) -> TransactionError {
+    TransactionError::InstructionError(index, InstructionError::Custom(error as u32))
+}
+
+#[tokio::test]
+async fn test_operator_sets_max_supply_once() {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let token = its
+        .deploy_interchain_token(
+            solana_sdk::keccak::hash(b"max-supply").to_bytes(),
+            "Test Token",
+            "TT",
+            9,
+            1_000,
+        )
+        .await;
+    let operator = its.operator;
+    assert_eq!(its.token_manager(&token.token_id).await.max_supply, None);
+
+    let ix = axelar_solana_its::instruction::token_manager::set_max_supply(
+        operator,
+        operator,
+        token.token_id,
+        token.mint,
+        1_500,
+    )
+    .unwrap();
+    let simulation_result = its.simulate_tx(&[ix.clone()]).await.unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    assert_event_cpi(
+        &TokenManagerMaxSupplySet {
+            token_id: token.token_id,
+            operator,
+            max_supply: 1_500,
+        },
+        &inner_ixs,
+    );
+
+    its.send_tx(&[ix]).await.unwrap();
+    assert_eq!(
+        its.token_manager(&token.token_id).await.max_supply,
+        Some(1_500)
+    );
+
+    let err = set_max_supply(&mut its, &token, 2_000).await.unwrap_err();
+    assert_eq!(
+        transaction_error(err),
+        instruction_error(0, ItsError::MaxSupplyAlreadySet)
+    );
+}
+
+#[tokio::test]
+async fn test_max_supply_cannot_be_below_current_supply() {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let token = its
+        .deploy_interchain_token(
+            solana_sdk::keccak::hash(b"max-supply").to_bytes(),
+            "Test Token",
+            "TT",
+            9,
+            1_000,
+        )
+        .await;
+
+    let err = set_max_supply(&mut its, &token, 999).await.unwrap_err();
+
+    assert_eq!(
+        transaction_error(err),
+        instruction_error(0, ItsError::MaxSupplyExceeded)
+    );
+    assert_eq!(its.token_manager(&token.token_id).await.max_supply, None);
+}
+
+#[tokio::test]
+async fn test_proxy_mint_is_capped_by_max_supply() {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let token = its
+        .deploy_interchain_token(
+            solana_sdk::keccak::hash(b"max-supply").to_bytes(),
+            "Test Token",
+            "TT",
+            9,
+            1_000,
+        )
+        .await;
+    set_max_supply(&mut its, &token, 1_500).await.unwrap();
+
+    mint(&mut its, &token, 500).await.unwrap();
+    let err = mint(&mut its, &token, 1).await.unwrap_err();
+
+    assert_eq!(
+        transaction_error(err),
+        instruction_error(0, ItsError::MaxSupplyExceeded)
+    );
+}
+
+#[tokio::test]
+async fn test_inbound_transfer_is_capped_by_max_supply() {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let token = its
+        .deploy_interchain_token(
+            solana_sdk::keccak::hash(b"max-supply").to_bytes(),
+            "Test Token",
+            "TT",
+            9,
+            1_000,
+        )
+        .await;
+    set_max_supply(&mut its, &token, 1_500).await.unwrap();
+
+    let err = its
+        .relay_interchain_transfer("ethereum", &token, Pubkey::new_unique(), 501, vec![])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        transaction_error(err),
+        instruction_error(0, ItsError::MaxSupplyExceeded)
+    );
+
+    its.relay_interchain_transfer("ethereum", &token, Pubkey::new_unique(), 500, vec![])
+        .await
+        .unwrap();
+}
@@ -59,7 +59,7 @@ fn setup_test_environment(ctx: &ItsTestContext) -> TestSetup {
         token_manager_pda,
         token_manager_ata,
         gateway_root_pda: ctx.solana_chain.gateway_root_pda,
-        gas_service_root_pda: ctx.solana_gas_utils.config_pda,
+        gas_service_root_pda: ctx.solana_chain.gas_utils.config_pda,
     }
 }
 
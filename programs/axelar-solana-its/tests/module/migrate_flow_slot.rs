@@ -0,0 +1,185 @@
+use axelar_solana_its::events::FlowSlotMigrated;
+use axelar_solana_its::state::flow_limit::{flow_epoch_with_timestamp, FlowSlot};
+use axelar_solana_its_test_fixtures::{
+    SolanaItsIntegration, SolanaItsIntegrationMetadata, TestToken,
+};
+use event_cpi_test_utils::assert_event_cpi;
+use solana_program_test::tokio;
+use solana_sdk::account::Account;
+use solana_sdk::clock::Clock;
+use solana_sdk::pubkey::Pubkey;
+
+/// Writes a legacy flow slot PDA, as created by previous program versions.
+fn write_legacy_flow_slot(
+    its: &mut SolanaItsIntegrationMetadata,
+    token: &TestToken,
+    epoch: u64,
+    flow_in: u64,
+    flow_out: u64,
+) -> Pubkey {
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token.token_id);
+    let (flow_slot_pda, bump) = axelar_solana_its::find_flow_slot_pda(&token_manager_pda, epoch);
+    let flow_slot = FlowSlot {
+        flow_limit: Some(1_000),
+        flow_in,
+        flow_out,
+        bump,
+    };
+
+    its.set_account_state(
+        &flow_slot_pda,
+        Account {
+            lamports: 10_000_000,
+            data: borsh::to_vec(&flow_slot).unwrap(),
+            owner: axelar_solana_its::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    flow_slot_pda
+}
+
+#[tokio::test]
+async fn test_migrate_current_epoch_flow_slot() {
+    // Setup
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let token = its
+        .deploy_interchain_token(
+            solana_sdk::keccak::hash(b"migrate-flow-slot").to_bytes(),
+            "Test Token",
+            "TT",
+            9,
+            1_000,
+        )
+        .await;
+    let timestamp = its.get_sysvar::<Clock>().await.unix_timestamp;
+    let epoch = flow_epoch_with_timestamp(timestamp).unwrap();
+    let operator = its.operator;
+    let flow_slot_pda = write_legacy_flow_slot(&mut its, &token, epoch, 300, 100);
+
+    // Action
+    let ix = axelar_solana_its::instruction::token_manager::migrate_flow_slot(
+        operator,
+        operator,
+        token.token_id,
+        epoch,
+    )
+    .unwrap();
+    let simulation_result = its.simulate_tx(&[ix.clone()]).await.unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    its.send_tx(&[ix]).await.unwrap();
+
+    // Assert
+    assert_event_cpi(
+        &FlowSlotMigrated {
+            token_id: token.token_id,
+            flow_slot: flow_slot_pda,
+            epoch,
+            flow_in: 300,
+            flow_out: 100,
+            folded: true,
+        },
+        &inner_ixs,
+    );
+    let flow_state = its.token_manager(&token.token_id).await.flow_slot;
+    assert_eq!(
+        (flow_state.flow_in, flow_state.flow_out, flow_state.epoch),
+        (300, 100, epoch)
+    );
+    assert!(its
+        .try_get_account_no_checks(&flow_slot_pda)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_migrate_stale_flow_slot_only_closes_it() {
+    // Setup
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let token = its
+        .deploy_interchain_token(
+            solana_sdk::keccak::hash(b"migrate-flow-slot").to_bytes(),
+            "Test Token",
+            "TT",
+            9,
+            1_000,
+        )
+        .await;
+    let timestamp = its.get_sysvar::<Clock>().await.unix_timestamp;
+    let epoch = flow_epoch_with_timestamp(timestamp).unwrap();
+    let operator = its.operator;
+    let flow_state_before = its.token_manager(&token.token_id).await.flow_slot;
+    let flow_slot_pda = write_legacy_flow_slot(&mut its, &token, epoch - 1, 300, 100);
+
+    // Action
+    let ix = axelar_solana_its::instruction::token_manager::migrate_flow_slot(
+        operator,
+        operator,
+        token.token_id,
+        epoch - 1,
+    )
+    .unwrap();
+    its.send_tx(&[ix]).await.unwrap();
+
+    // Assert
+    assert_eq!(
+        its.token_manager(&token.token_id).await.flow_slot,
+        flow_state_before
+    );
+    assert!(its
+        .try_get_account_no_checks(&flow_slot_pda)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_migrate_flow_slot_requires_operator() {
+    // Setup
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let token = its
+        .deploy_interchain_token(
+            solana_sdk::keccak::hash(b"migrate-flow-slot").to_bytes(),
+            "Test Token",
+            "TT",
+            9,
+            1_000,
+        )
+        .await;
+    let timestamp = its.get_sysvar::<Clock>().await.unix_timestamp;
+    let epoch = flow_epoch_with_timestamp(timestamp).unwrap();
+    let payer = its.operator;
+    let flow_slot_pda = write_legacy_flow_slot(&mut its, &token, epoch, 300, 100);
+
+    // Action: the payer signs, but the operator account isn't the operator
+    let mut ix = axelar_solana_its::instruction::token_manager::migrate_flow_slot(
+        payer,
+        payer,
+        token.token_id,
+        epoch,
+    )
+    .unwrap();
+    let not_operator = Pubkey::new_unique();
+    ix.accounts[1].pubkey = not_operator;
+    ix.accounts[1].is_signer = false;
+    let res = its.send_tx(&[ix]).await;
+
+    // Assert
+    assert!(res.is_err());
+    assert!(its
+        .try_get_account_no_checks(&flow_slot_pda)
+        .await
+        .unwrap()
+        .is_some());
+}
@@ -0,0 +1,79 @@
+use axelar_solana_its::events::{InterchainTokenDeploymentStarted, TokenMetadataRegistered};
+use axelar_solana_its::state::token_manager;
+use axelar_solana_its_test_fixtures::SolanaItsIntegration;
+use event_cpi_test_utils::assert_event_cpi;
+use solana_program_test::tokio;
+
+#[tokio::test]
+async fn test_register_native_mint_canonical_token() {
+    // Setup
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let operator = its.operator;
+    let token_id = axelar_solana_its::canonical_interchain_token_id(&spl_token::native_mint::ID);
+
+    // Action
+    let ixs =
+        axelar_solana_its::instruction::register_native_mint_canonical_token(operator, 0).unwrap();
+    let simulation_result = its.simulate_tx(&ixs).await.unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()[1]
+        .clone();
+    its.send_tx(&ixs).await.unwrap();
+
+    // Assert
+    assert_event_cpi(
+        &TokenMetadataRegistered {
+            token_address: spl_token::native_mint::ID,
+            decimals: spl_token::native_mint::DECIMALS,
+        },
+        &inner_ixs,
+    );
+    let token_manager = its.token_manager(&token_id).await;
+    assert_eq!(token_manager.ty, token_manager::Type::LockUnlock);
+    assert_eq!(token_manager.token_address, spl_token::native_mint::ID);
+    assert_eq!(token_manager.token_program, spl_token::ID);
+}
+
+#[tokio::test]
+async fn test_native_mint_deploys_remotely_with_fixed_metadata() {
+    // Setup
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let operator = its.operator;
+    let ixs =
+        axelar_solana_its::instruction::register_native_mint_canonical_token(operator, 0).unwrap();
+    its.send_tx(&ixs).await.unwrap();
+
+    // Action
+    let ix = axelar_solana_its::instruction::deploy_remote_canonical_interchain_token(
+        operator,
+        spl_token::native_mint::ID,
+        "ethereum".to_owned(),
+        0,
+    )
+    .unwrap();
+    let simulation_result = its.simulate_tx(&[ix]).await.unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+
+    // Assert
+    assert_event_cpi(
+        &InterchainTokenDeploymentStarted {
+            token_id: axelar_solana_its::canonical_interchain_token_id(&spl_token::native_mint::ID),
+            token_name: axelar_solana_its::native_mint::NAME.to_owned(),
+            token_symbol: axelar_solana_its::native_mint::SYMBOL.to_owned(),
+            token_decimals: axelar_solana_its::native_mint::DECIMALS,
+            minter: vec![],
+            destination_chain: "ethereum".to_owned(),
+        },
+        &inner_ixs,
+    );
+}
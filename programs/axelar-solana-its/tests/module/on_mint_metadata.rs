@@ -0,0 +1,137 @@
+use axelar_solana_its::instruction::InterchainTokenServiceInstruction;
+use axelar_solana_its_test_fixtures::{SolanaItsIntegration, SolanaItsIntegrationMetadata};
+use borsh::to_vec;
+use solana_program_test::tokio;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::TransactionError;
+use spl_token_2022::extension::metadata_pointer::MetadataPointer;
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use spl_token_2022::state::{Account, Mint};
+use spl_token_metadata_interface::state::TokenMetadata;
+
+const SALT: [u8; 32] = [11; 32];
+const NAME: &str = "On Mint Token";
+const SYMBOL: &str = "OMT";
+const DECIMALS: u8 = 9;
+const INITIAL_SUPPLY: u64 = 1_000;
+
+fn token_accounts(its: &SolanaItsIntegrationMetadata) -> (Pubkey, Pubkey) {
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let token_id = axelar_solana_its::interchain_token_id(&its.operator, &SALT);
+    let (mint, _) = axelar_solana_its::find_interchain_token_pda(&its_root_pda, &token_id);
+    let (token_manager, _) = axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+
+    (mint, token_manager)
+}
+
+#[tokio::test]
+async fn test_deploy_interchain_token_with_on_mint_metadata() {
+    // Setup
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let operator = its.operator;
+    let (mint, token_manager) = token_accounts(&its);
+
+    // Action
+    let ix = axelar_solana_its::instruction::deploy_interchain_token_with_on_mint_metadata(
+        operator,
+        operator,
+        SALT,
+        NAME.to_owned(),
+        SYMBOL.to_owned(),
+        DECIMALS,
+        INITIAL_SUPPLY,
+        None,
+    )
+    .unwrap();
+    its.send_tx(&[ix]).await.unwrap();
+
+    // Assert
+    let mint_account = its.try_get_account_no_checks(&mint).await.unwrap().unwrap();
+    let state = StateWithExtensions::<Mint>::unpack(&mint_account.data).unwrap();
+    assert_eq!(state.base.decimals, DECIMALS);
+
+    let metadata_pointer = state.get_extension::<MetadataPointer>().unwrap();
+    assert_eq!(
+        Option::<Pubkey>::from(metadata_pointer.metadata_address),
+        Some(mint)
+    );
+    assert_eq!(
+        Option::<Pubkey>::from(metadata_pointer.authority),
+        Some(token_manager)
+    );
+
+    let token_metadata = state.get_variable_len_extension::<TokenMetadata>().unwrap();
+    assert_eq!(token_metadata.mint, mint);
+    assert_eq!(token_metadata.name, NAME);
+    assert_eq!(token_metadata.symbol, SYMBOL);
+    assert_eq!(
+        Option::<Pubkey>::from(token_metadata.update_authority),
+        Some(token_manager)
+    );
+
+    // The Metaplex metadata is still created
+    let (metaplex_metadata, _) = mpl_token_metadata::accounts::Metadata::find_pda(&mint);
+    assert!(its
+        .try_get_account_no_checks(&metaplex_metadata)
+        .await
+        .unwrap()
+        .is_some());
+
+    let operator_ata = spl_associated_token_account::get_associated_token_address_with_program_id(
+        &operator,
+        &mint,
+        &spl_token_2022::id(),
+    );
+    let operator_ata = its
+        .try_get_account_no_checks(&operator_ata)
+        .await
+        .unwrap()
+        .unwrap();
+    let operator_ata = StateWithExtensions::<Account>::unpack(&operator_ata.data).unwrap();
+    assert_eq!(operator_ata.base.amount, INITIAL_SUPPLY);
+}
+
+#[tokio::test]
+async fn test_on_mint_metadata_requires_token_2022() {
+    // Setup
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let operator = its.operator;
+    let mut ix = axelar_solana_its::instruction::deploy_interchain_token_with_token_program(
+        operator,
+        operator,
+        SALT,
+        NAME.to_owned(),
+        SYMBOL.to_owned(),
+        DECIMALS,
+        INITIAL_SUPPLY,
+        None,
+        spl_token::id(),
+    )
+    .unwrap();
+    ix.data = to_vec(
+        &InterchainTokenServiceInstruction::DeployInterchainTokenWithOnMintMetadata {
+            salt: SALT,
+            name: NAME.to_owned(),
+            symbol: SYMBOL.to_owned(),
+            decimals: DECIMALS,
+            initial_supply: INITIAL_SUPPLY,
+        },
+    )
+    .unwrap();
+
+    // Action
+    let res = its.send_tx(&[ix]).await.unwrap_err();
+
+    // Assert
+    assert_eq!(
+        res.result.unwrap_err(),
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+    );
+    let (mint, _) = token_accounts(&its);
+    assert!(its
+        .try_get_account_no_checks(&mint)
+        .await
+        .unwrap()
+        .is_none());
+}
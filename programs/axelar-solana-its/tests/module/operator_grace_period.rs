@@ -0,0 +1,285 @@
+use axelar_solana_its::error::ItsError;
+use axelar_solana_its::events::{GracePeriodOperatorUsed, OperatorshipTransferredWithGracePeriod};
+use axelar_solana_its::state::{
+    InterchainTokenService, OperatorGracePeriod, MAX_OPERATOR_GRACE_PERIOD_SLOTS,
+};
+use axelar_solana_its_test_fixtures::{SolanaItsIntegration, SolanaItsIntegrationMetadata};
+use borsh::BorshDeserialize;
+use event_cpi_test_utils::assert_event_cpi;
+use solana_program_test::tokio;
+use solana_sdk::clock::Clock;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+const GRACE_PERIOD_SLOTS: u64 = 100;
+
+async fn its_root_config(its: &mut SolanaItsIntegrationMetadata) -> InterchainTokenService {
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let data = its
+        .get_account(&its_root_pda, &axelar_solana_its::id())
+        .await
+        .data;
+
+    InterchainTokenService::try_from_slice(&data).unwrap()
+}
+
+async fn current_slot(its: &mut SolanaItsIntegrationMetadata) -> u64 {
+    its.get_sysvar::<Clock>().await.slot
+}
+
+fn set_trusted_chain(its: &SolanaItsIntegrationMetadata, authority: &Keypair) -> Instruction {
+    axelar_solana_its::instruction::set_trusted_chain(
+        its.payer.pubkey(),
+        authority.pubkey(),
+        "polygon".to_owned(),
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_transfer_operatorship_with_grace_period_records_previous_operator() {
+    // Setup
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let previous_operator = its.operator;
+    let new_operator = Keypair::new();
+
+    // Action
+    let ix = axelar_solana_its::instruction::transfer_operatorship_with_grace_period(
+        its.payer.pubkey(),
+        previous_operator,
+        new_operator.pubkey(),
+        GRACE_PERIOD_SLOTS,
+    )
+    .unwrap();
+    let simulation_result = its.simulate_tx(&[ix.clone()]).await.unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    let slot = current_slot(&mut its).await;
+    its.send_tx(&[ix]).await.unwrap();
+
+    // Assert
+    let expires_at_slot = slot + GRACE_PERIOD_SLOTS;
+    assert_event_cpi(
+        &OperatorshipTransferredWithGracePeriod {
+            previous_operator,
+            new_operator: new_operator.pubkey(),
+            expires_at_slot,
+        },
+        &inner_ixs,
+    );
+    assert_eq!(
+        its_root_config(&mut its).await.operator_grace_period,
+        Some(OperatorGracePeriod {
+            previous_operator,
+            expires_at_slot,
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_previous_operator_is_honored_during_grace_period() {
+    // Setup
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let new_operator = Keypair::new();
+    let ix = axelar_solana_its::instruction::transfer_operatorship_with_grace_period(
+        its.payer.pubkey(),
+        its.operator,
+        new_operator.pubkey(),
+        GRACE_PERIOD_SLOTS,
+    )
+    .unwrap();
+    its.send_tx(&[ix]).await.unwrap();
+    let previous_operator = its.payer.insecure_clone();
+    let expires_at_slot = its_root_config(&mut its)
+        .await
+        .operator_grace_period
+        .unwrap()
+        .expires_at_slot;
+
+    // Action
+    let ix = set_trusted_chain(&its, &previous_operator);
+    let simulation_result = its.simulate_tx(&[ix.clone()]).await.unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    its.send_tx(&[ix]).await.unwrap();
+
+    // Assert
+    assert_event_cpi(
+        &GracePeriodOperatorUsed {
+            operator: previous_operator.pubkey(),
+            expires_at_slot,
+        },
+        &inner_ixs,
+    );
+    assert!(its_root_config(&mut its).await.is_trusted_chain("polygon"));
+}
+
+#[tokio::test]
+async fn test_new_operator_is_honored_without_warning() {
+    // Setup
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let new_operator = Keypair::new();
+    let ix = axelar_solana_its::instruction::transfer_operatorship_with_grace_period(
+        its.payer.pubkey(),
+        its.operator,
+        new_operator.pubkey(),
+        GRACE_PERIOD_SLOTS,
+    )
+    .unwrap();
+    its.send_tx(&[ix]).await.unwrap();
+    let payer = its.payer.insecure_clone();
+
+    // Action
+    let ix = set_trusted_chain(&its, &new_operator);
+    let res = its
+        .send_tx_with_custom_signers(&[ix], &[&payer, &new_operator])
+        .await;
+
+    // Assert
+    assert!(res.is_ok());
+    assert!(its_root_config(&mut its).await.is_trusted_chain("polygon"));
+}
+
+#[tokio::test]
+async fn test_previous_operator_is_rejected_after_grace_period() {
+    // Setup
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let new_operator = Keypair::new();
+    let ix = axelar_solana_its::instruction::transfer_operatorship_with_grace_period(
+        its.payer.pubkey(),
+        its.operator,
+        new_operator.pubkey(),
+        GRACE_PERIOD_SLOTS,
+    )
+    .unwrap();
+    its.send_tx(&[ix]).await.unwrap();
+    let previous_operator = its.payer.insecure_clone();
+    let slot = current_slot(&mut its).await;
+    its.warp_to_slot(slot + GRACE_PERIOD_SLOTS + 1);
+
+    // Action
+    let ix = set_trusted_chain(&its, &previous_operator);
+    let res = its.send_tx(&[ix]).await;
+
+    // Assert
+    assert!(res.is_err());
+    assert!(!its_root_config(&mut its).await.is_trusted_chain("polygon"));
+}
+
+#[tokio::test]
+async fn test_transfer_operatorship_ends_grace_period() {
+    // Setup
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let new_operator = Keypair::new();
+    let ix = axelar_solana_its::instruction::transfer_operatorship_with_grace_period(
+        its.payer.pubkey(),
+        its.operator,
+        new_operator.pubkey(),
+        GRACE_PERIOD_SLOTS,
+    )
+    .unwrap();
+    its.send_tx(&[ix]).await.unwrap();
+    let payer = its.payer.insecure_clone();
+    let next_operator = Keypair::new();
+    let ix = axelar_solana_its::instruction::transfer_operatorship(
+        payer.pubkey(),
+        new_operator.pubkey(),
+        next_operator.pubkey(),
+    )
+    .unwrap();
+    its.send_tx_with_custom_signers(&[ix], &[&payer, &new_operator])
+        .await
+        .unwrap();
+
+    // Action
+    let ix = set_trusted_chain(&its, &payer);
+    let res = its.send_tx(&[ix]).await;
+
+    // Assert
+    assert!(res.is_err());
+    assert_eq!(its_root_config(&mut its).await.operator_grace_period, None);
+}
+
+#[tokio::test]
+async fn test_accept_operatorship_ends_grace_period() {
+    // Setup
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let new_operator = Keypair::new();
+    let ix = axelar_solana_its::instruction::transfer_operatorship_with_grace_period(
+        its.payer.pubkey(),
+        its.operator,
+        new_operator.pubkey(),
+        GRACE_PERIOD_SLOTS,
+    )
+    .unwrap();
+    its.send_tx(&[ix]).await.unwrap();
+    let payer = its.payer.insecure_clone();
+    let next_operator = Keypair::new();
+    let ix = axelar_solana_its::instruction::propose_operatorship(
+        payer.pubkey(),
+        new_operator.pubkey(),
+        next_operator.pubkey(),
+    )
+    .unwrap();
+    its.send_tx_with_custom_signers(&[ix], &[&payer, &new_operator])
+        .await
+        .unwrap();
+    let ix = axelar_solana_its::instruction::accept_operatorship(
+        payer.pubkey(),
+        next_operator.pubkey(),
+        new_operator.pubkey(),
+    )
+    .unwrap();
+    its.send_tx_with_custom_signers(&[ix], &[&payer, &next_operator])
+        .await
+        .unwrap();
+
+    // Action
+    let ix = set_trusted_chain(&its, &payer);
+    let res = its.send_tx(&[ix]).await;
+
+    // Assert
+    assert!(res.is_err());
+    assert_eq!(its_root_config(&mut its).await.operator_grace_period, None);
+}
+
+#[tokio::test]
+async fn test_grace_period_is_bounded() {
+    for grace_period_slots in [0, MAX_OPERATOR_GRACE_PERIOD_SLOTS + 1] {
+        // Setup
+        let mut its = SolanaItsIntegration::builder().build().setup().await;
+
+        // Action
+        let ix = axelar_solana_its::instruction::transfer_operatorship_with_grace_period(
+            its.payer.pubkey(),
+            its.operator,
+            Keypair::new().pubkey(),
+            grace_period_slots,
+        )
+        .unwrap();
+        let res = its.send_tx(&[ix]).await.unwrap_err();
+
+        // Assert
+        assert_eq!(
+            res.result.unwrap_err(),
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(ItsError::InvalidOperatorGracePeriod as u32)
+            )
+        );
+    }
+}
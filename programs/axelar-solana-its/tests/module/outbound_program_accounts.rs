@@ -0,0 +1,51 @@
+use axelar_solana_its::error::ItsError;
+use axelar_solana_its_test_fixtures::SolanaItsIntegration;
+use solana_program_test::tokio;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::TransactionError;
+
+const GATEWAY_PROGRAM_INDEX: usize = 8;
+const GAS_SERVICE_PROGRAM_INDEX: usize = 12;
+
+#[tokio::test]
+async fn test_outbound_call_rejects_spoofed_program_accounts() {
+    // Setup
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let operator = its.operator;
+    let salt = solana_sdk::keccak::hash(b"outbound-program-accounts").to_bytes();
+    its.deploy_interchain_token(salt, "Test Token", "TT", 9, 1_000)
+        .await;
+
+    for (index, expected_program, error) in [
+        (
+            GATEWAY_PROGRAM_INDEX,
+            axelar_solana_gateway::ID,
+            ItsError::InvalidGatewayProgram,
+        ),
+        (
+            GAS_SERVICE_PROGRAM_INDEX,
+            axelar_solana_gas_service::ID,
+            ItsError::InvalidGasServiceProgram,
+        ),
+    ] {
+        // Action
+        let mut ix = axelar_solana_its::instruction::deploy_remote_interchain_token(
+            operator,
+            operator,
+            salt,
+            "ethereum".to_owned(),
+            0,
+        )
+        .unwrap();
+        assert_eq!(ix.accounts[index].pubkey, expected_program);
+        ix.accounts[index].pubkey = Pubkey::new_unique();
+        let res = its.send_tx(&[ix]).await;
+
+        // Assert
+        assert_eq!(
+            res.unwrap_err().result.unwrap_err(),
+            TransactionError::InstructionError(0, InstructionError::Custom(error as u32))
+        );
+    }
+}
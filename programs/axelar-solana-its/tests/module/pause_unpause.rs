@@ -1,5 +1,6 @@
 use borsh::BorshDeserialize;
 use solana_program_test::tokio;
+use solana_sdk::instruction::Instruction;
 use solana_sdk::{pubkey::Pubkey, signer::Signer};
 use spl_associated_token_account::{
     get_associated_token_address_with_program_id, instruction::create_associated_token_account,
@@ -8,11 +9,67 @@ use test_context::test_context;
 
 use axelar_solana_gateway_test_fixtures::assert_msg_present_in_logs;
 use axelar_solana_its::state::token_manager::TokenManager;
+use axelar_solana_its::state::{InterchainTokenService, PauseFlags};
 use evm_contracts_test_suite::ethers::signers::Signer as _;
 use interchain_token_transfer_gmp::{GMPPayload, LinkToken, SendToHub};
 
 use crate::ItsTestContext;
 
+async fn outbound_transfer_ixs(ctx: &mut ItsTestContext) -> Vec<Instruction> {
+    let (its_root_config_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) = axelar_solana_its::find_token_manager_pda(
+        &its_root_config_pda,
+        &ctx.deployed_interchain_token,
+    );
+    let data = ctx
+        .solana_chain
+        .fixture
+        .get_account(&token_manager_pda, &axelar_solana_its::id())
+        .await
+        .data;
+
+    let token_manager = TokenManager::try_from_slice(&data).unwrap();
+    let token_address = token_manager.token_address;
+
+    let token_account = get_associated_token_address_with_program_id(
+        &ctx.solana_wallet,
+        &token_address,
+        &spl_token_2022::id(),
+    );
+
+    let create_ata_ix = create_associated_token_account(
+        &ctx.solana_wallet,
+        &ctx.solana_wallet,
+        &token_address,
+        &spl_token_2022::id(),
+    );
+
+    let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
+        ctx.deployed_interchain_token,
+        token_address,
+        token_account,
+        ctx.solana_wallet,
+        spl_token_2022::id(),
+        900,
+    )
+    .unwrap();
+    let transfer_ix = axelar_solana_its::instruction::interchain_transfer(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        token_account,
+        ctx.deployed_interchain_token,
+        ctx.evm_chain_name.clone(),
+        ctx.evm_signer.wallet.address().as_bytes().to_vec(),
+        500,
+        token_address,
+        spl_token_2022::id(),
+        0,
+    )
+    .unwrap();
+
+    vec![create_ata_ix, mint_ix, transfer_ix]
+}
+
 #[test_context(ItsTestContext)]
 #[tokio::test]
 async fn test_its_gmp_payload_fail_when_paused(ctx: &mut ItsTestContext) {
@@ -85,59 +142,8 @@ async fn test_outbound_message_fails_when_paused(ctx: &mut ItsTestContext) {
         )
         .await;
 
-    let (its_root_config_pda, _) = axelar_solana_its::find_its_root_pda();
-    let (token_manager_pda, _) = axelar_solana_its::find_token_manager_pda(
-        &its_root_config_pda,
-        &ctx.deployed_interchain_token,
-    );
-    let data = ctx
-        .solana_chain
-        .fixture
-        .get_account(&token_manager_pda, &axelar_solana_its::id())
-        .await
-        .data;
-
-    let token_manager = TokenManager::try_from_slice(&data).unwrap();
-    let token_address = token_manager.token_address;
-
-    let token_account = get_associated_token_address_with_program_id(
-        &ctx.solana_wallet,
-        &token_address,
-        &spl_token_2022::id(),
-    );
-
-    let create_ata_ix = create_associated_token_account(
-        &ctx.solana_wallet,
-        &ctx.solana_wallet,
-        &token_address,
-        &spl_token_2022::id(),
-    );
-
-    let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
-        ctx.deployed_interchain_token,
-        token_address,
-        token_account,
-        ctx.solana_wallet,
-        spl_token_2022::id(),
-        900,
-    )
-    .unwrap();
-    let transfer_ix = axelar_solana_its::instruction::interchain_transfer(
-        ctx.solana_wallet,
-        ctx.solana_wallet,
-        token_account,
-        ctx.deployed_interchain_token,
-        ctx.evm_chain_name.clone(),
-        ctx.evm_signer.wallet.address().as_bytes().to_vec(),
-        500,
-        token_address,
-        spl_token_2022::id(),
-        0,
-    )
-    .unwrap();
-
     let tx_metadata = ctx
-        .send_solana_tx(&[create_ata_ix, mint_ix, transfer_ix])
+        .send_solana_tx(&outbound_transfer_ixs(ctx).await)
         .await
         .unwrap_err();
     assert_msg_present_in_logs(
@@ -296,3 +302,89 @@ async fn test_local_deploy_interchain_token_fails_when_paused(ctx: &mut ItsTestC
         "The Interchain Token Service is currently paused.",
     );
 }
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_pause_flags_only_halt_their_group(ctx: &mut ItsTestContext) {
+    let set_pause_flags_ix = axelar_solana_its::instruction::set_pause_flags(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        PauseFlags::OUTBOUND_TRANSFERS,
+    )
+    .unwrap();
+    ctx.send_solana_tx(&[set_pause_flags_ix]).await.unwrap();
+
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let data = ctx
+        .solana_chain
+        .fixture
+        .get_account(&its_root_pda, &axelar_solana_its::id())
+        .await
+        .data;
+    let its_root = InterchainTokenService::try_from_slice(&data).unwrap();
+    assert_eq!(its_root.pause_flags, PauseFlags::OUTBOUND_TRANSFERS);
+
+    let transfer_ixs = outbound_transfer_ixs(ctx).await;
+    let tx_metadata = ctx.send_solana_tx(&transfer_ixs).await.unwrap_err();
+    assert_msg_present_in_logs(
+        tx_metadata,
+        "The Interchain Token Service is currently paused.",
+    );
+
+    // Deployments are still allowed
+    let deploy_local_ix = axelar_solana_its::instruction::deploy_interchain_token(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        solana_sdk::keccak::hash(b"LocalTokenWhileTransfersPaused").0,
+        "Local Token".to_owned(),
+        "LOCAL".to_owned(),
+        9,
+        1_000_000,
+        Some(ctx.solana_wallet),
+    )
+    .unwrap();
+    ctx.send_solana_tx(&[deploy_local_ix]).await.unwrap();
+
+    // Clearing the flag resumes outbound transfers
+    let clear_pause_flags_ix = axelar_solana_its::instruction::set_pause_flags(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        PauseFlags::empty(),
+    )
+    .unwrap();
+    ctx.send_solana_tx(&[clear_pause_flags_ix]).await.unwrap();
+
+    let data = ctx
+        .solana_chain
+        .fixture
+        .get_account(&its_root_pda, &axelar_solana_its::id())
+        .await
+        .data;
+    let its_root = InterchainTokenService::try_from_slice(&data).unwrap();
+    assert!(!its_root.is_paused(PauseFlags::OUTBOUND_TRANSFERS));
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_fail_to_set_pause_flags_not_being_operator(ctx: &mut ItsTestContext) {
+    let payer = ctx.solana_chain.fixture.payer.pubkey();
+    let tx_metadata = ctx
+        .solana_chain
+        .fixture
+        .send_tx_with_custom_signers(
+            &[axelar_solana_its::instruction::set_pause_flags(
+                payer,
+                payer,
+                PauseFlags::INBOUND_TRANSFERS,
+            )
+            .unwrap()],
+            &[ctx.solana_chain.fixture.payer.insecure_clone()],
+        )
+        .await
+        .unwrap_err();
+
+    assert_msg_present_in_logs(
+        tx_metadata,
+        "Account passed as authority is neither upgrade authority nor operator",
+    );
+}
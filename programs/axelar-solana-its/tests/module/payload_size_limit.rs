@@ -0,0 +1,152 @@
+use anyhow::anyhow;
+use axelar_solana_gateway_test_fixtures::assert_msg_present_in_logs;
+use axelar_solana_its::events::MaxPayloadSizeSet;
+use axelar_solana_its::state::InterchainTokenService;
+use borsh::BorshDeserialize;
+use evm_contracts_test_suite::ethers::signers::Signer as EvmSigner;
+use solana_program_test::tokio;
+use solana_sdk::instruction::Instruction;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_associated_token_account::instruction::create_associated_token_account;
+use test_context::test_context;
+
+use event_cpi_test_utils::get_first_event_cpi_occurrence;
+
+use crate::ItsTestContext;
+
+async fn its_root_config(ctx: &mut ItsTestContext) -> InterchainTokenService {
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let data = ctx
+        .solana_chain
+        .fixture
+        .get_account(&its_root_pda, &axelar_solana_its::id())
+        .await
+        .data;
+
+    InterchainTokenService::try_from_slice(&data).unwrap()
+}
+
+async fn mint_and_build_transfer(ctx: &mut ItsTestContext, amount: u64) -> Instruction {
+    let token_id = ctx.deployed_interchain_token;
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (interchain_token_pda, _) =
+        axelar_solana_its::find_interchain_token_pda(&its_root_pda, &token_id);
+    let associated_account_address = get_associated_token_address_with_program_id(
+        &ctx.solana_wallet,
+        &interchain_token_pda,
+        &spl_token_2022::id(),
+    );
+
+    let create_token_account_ix = create_associated_token_account(
+        &ctx.solana_wallet,
+        &ctx.solana_wallet,
+        &interchain_token_pda,
+        &spl_token_2022::id(),
+    );
+    let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
+        token_id,
+        interchain_token_pda,
+        associated_account_address,
+        ctx.solana_wallet,
+        spl_token_2022::id(),
+        amount,
+    )
+    .unwrap();
+    ctx.send_solana_tx(&[create_token_account_ix, mint_ix])
+        .await
+        .unwrap();
+
+    axelar_solana_its::instruction::interchain_transfer(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        associated_account_address,
+        token_id,
+        ctx.evm_chain_name.clone(),
+        ctx.evm_signer.wallet.address().as_bytes().to_vec(),
+        amount,
+        interchain_token_pda,
+        spl_token_2022::id(),
+        0,
+    )
+    .unwrap()
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_outbound_payload_exceeding_max_size_is_rejected(
+    ctx: &mut ItsTestContext,
+) -> anyhow::Result<()> {
+    let max_size = 64;
+    let set_max_size_ix = axelar_solana_its::instruction::set_max_payload_size(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        ctx.evm_chain_name.clone(),
+        Some(max_size),
+    )?;
+    let simulation_details = ctx
+        .simulate_solana_tx(&[set_max_size_ix.clone()])
+        .await
+        .simulation_details
+        .unwrap();
+    let inner_ixs = simulation_details
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    let event = get_first_event_cpi_occurrence::<MaxPayloadSizeSet>(&inner_ixs)
+        .ok_or_else(|| anyhow!("MaxPayloadSizeSet not found"))?;
+    assert_eq!(event.chain_name, ctx.evm_chain_name);
+    assert_eq!(event.max_size, Some(max_size));
+
+    ctx.send_solana_tx(&[set_max_size_ix]).await.unwrap();
+    let its_root = its_root_config(ctx).await;
+    assert_eq!(
+        its_root.max_payload_sizes.get(&ctx.evm_chain_name),
+        Some(&max_size)
+    );
+
+    let transfer_ix = mint_and_build_transfer(ctx, 100).await;
+    let tx = ctx
+        .send_solana_tx(&[transfer_ix.clone()])
+        .await
+        .unwrap_err();
+    assert_msg_present_in_logs(
+        tx,
+        &format!(
+            "towards {} exceeds the maximum of {max_size} bytes",
+            ctx.evm_chain_name
+        ),
+    );
+
+    // Lifting the limit lets the same transfer through
+    let remove_max_size_ix = axelar_solana_its::instruction::set_max_payload_size(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        ctx.evm_chain_name.clone(),
+        None,
+    )?;
+    ctx.send_solana_tx(&[remove_max_size_ix]).await.unwrap();
+    assert!(its_root_config(ctx).await.max_payload_sizes.is_empty());
+
+    ctx.send_solana_tx(&[transfer_ix]).await.unwrap();
+
+    Ok(())
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_payload_within_max_size_is_sent(ctx: &mut ItsTestContext) -> anyhow::Result<()> {
+    let set_max_size_ix = axelar_solana_its::instruction::set_max_payload_size(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        ctx.evm_chain_name.clone(),
+        Some(4096),
+    )?;
+    ctx.send_solana_tx(&[set_max_size_ix]).await.unwrap();
+
+    let transfer_ix = mint_and_build_transfer(ctx, 100).await;
+    ctx.send_solana_tx(&[transfer_ix]).await.unwrap();
+
+    Ok(())
+}
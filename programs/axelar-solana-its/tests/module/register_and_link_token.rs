@@ -0,0 +1,134 @@
+use axelar_solana_gateway::events::CallContractEvent;
+use axelar_solana_its::state::token_manager::Type as TokenManagerType;
+use axelar_solana_its_test_fixtures::SolanaItsIntegration;
+use event_cpi_test_utils::get_first_event_cpi_occurrence;
+use interchain_token_transfer_gmp::GMPPayload;
+use solana_program_test::tokio;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::inner_instruction::InnerInstruction;
+
+const DESTINATION_CHAIN: &str = "ethereum";
+
+/// Decodes the ITS messages wrapped in the `CallContractEvent`s, in the order
+/// they were sent.
+fn hub_messages(inner_ixs: &[InnerInstruction]) -> Vec<GMPPayload> {
+    inner_ixs
+        .iter()
+        .filter_map(|ix| get_first_event_cpi_occurrence::<CallContractEvent>(&[ix.clone()]))
+        .map(|event| {
+            let GMPPayload::SendToHub(send_to_hub) = GMPPayload::decode(&event.payload).unwrap()
+            else {
+                panic!("expected a message to the ITS Hub");
+            };
+            GMPPayload::decode(send_to_hub.payload.as_ref()).unwrap()
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_register_and_link_token_in_one_transaction() {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let operator = its.operator;
+    let salt = solana_sdk::keccak::hash(b"register-and-link-token").to_bytes();
+    let token_id = axelar_solana_its::linked_token_id(&operator, &salt);
+    let destination_token_address = vec![0xCD; 20];
+    let mint = its.init_new_mint(operator, spl_token_2022::id(), 9).await;
+
+    let ix = axelar_solana_its::instruction::register_and_link_token(
+        operator,
+        operator,
+        salt,
+        mint,
+        TokenManagerType::LockUnlock,
+        spl_token_2022::id(),
+        Some(operator),
+        DESTINATION_CHAIN.to_owned(),
+        destination_token_address.clone(),
+        TokenManagerType::MintBurn,
+        vec![],
+        0,
+        0,
+    )
+    .unwrap();
+    let ixs = [
+        ComputeBudgetInstruction::set_compute_unit_limit(400_000),
+        ix,
+    ];
+
+    let simulation_result = its.simulate_tx(&ixs).await.unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .last()
+        .cloned()
+        .unwrap();
+    let messages = hub_messages(&inner_ixs);
+    assert_eq!(messages.len(), 2);
+
+    let Some(GMPPayload::RegisterTokenMetadata(metadata)) = messages.first() else {
+        panic!("expected the token metadata to be registered first");
+    };
+    assert_eq!(metadata.token_address.as_ref(), mint.as_ref());
+    assert_eq!(metadata.decimals, 9);
+
+    let Some(GMPPayload::LinkToken(link_token)) = messages.last() else {
+        panic!("expected the token to be linked last");
+    };
+    assert_eq!(link_token.token_id.as_slice(), token_id.as_slice());
+    assert_eq!(link_token.source_token_address.as_ref(), mint.as_ref());
+    assert_eq!(
+        link_token.destination_token_address.as_ref(),
+        destination_token_address.as_slice()
+    );
+
+    its.send_tx(&ixs).await.unwrap();
+
+    let token_manager = its.token_manager(&token_id).await;
+    assert_eq!(token_manager.token_address, mint);
+    assert_eq!(token_manager.ty, TokenManagerType::LockUnlock);
+}
+
+#[tokio::test]
+async fn test_register_and_link_token_is_atomic() {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let operator = its.operator;
+    let salt = solana_sdk::keccak::hash(b"register-and-link-untrusted").to_bytes();
+    let token_id = axelar_solana_its::linked_token_id(&operator, &salt);
+    let mint = its.init_new_mint(operator, spl_token_2022::id(), 9).await;
+
+    let ix = axelar_solana_its::instruction::register_and_link_token(
+        operator,
+        operator,
+        salt,
+        mint,
+        TokenManagerType::LockUnlock,
+        spl_token_2022::id(),
+        Some(operator),
+        "untrusted-chain".to_owned(),
+        vec![0xCD; 20],
+        TokenManagerType::MintBurn,
+        vec![],
+        0,
+        0,
+    )
+    .unwrap();
+    let res = its
+        .send_tx(&[
+            ComputeBudgetInstruction::set_compute_unit_limit(400_000),
+            ix,
+        ])
+        .await;
+
+    // The link to an untrusted chain fails, so the token isn't registered either
+    assert!(res.is_err());
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+    assert!(its
+        .try_get_account_no_checks(&token_manager_pda)
+        .await
+        .unwrap()
+        .is_none());
+}
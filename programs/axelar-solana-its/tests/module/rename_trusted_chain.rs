@@ -0,0 +1,147 @@
+use axelar_solana_its::events::TrustedChainRenamed;
+use axelar_solana_its::state::InterchainTokenService;
+use axelar_solana_its_test_fixtures::{SolanaItsIntegration, SolanaItsIntegrationMetadata};
+use borsh::BorshDeserialize;
+use event_cpi_test_utils::assert_event_cpi;
+use solana_program_test::tokio;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+
+async fn its_root_config(its: &mut SolanaItsIntegrationMetadata) -> InterchainTokenService {
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let data = its
+        .get_account(&its_root_pda, &axelar_solana_its::id())
+        .await
+        .data;
+
+    InterchainTokenService::try_from_slice(&data).unwrap()
+}
+
+#[tokio::test]
+async fn test_rename_trusted_chain_moves_configuration() {
+    // Setup
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let operator = its.operator;
+    let set_trusted_chain_ix =
+        axelar_solana_its::instruction::set_trusted_chain(operator, operator, "polygon".to_owned())
+            .unwrap();
+    let set_max_size_ix = axelar_solana_its::instruction::set_max_payload_size(
+        operator,
+        operator,
+        "polygon".to_owned(),
+        Some(512),
+    )
+    .unwrap();
+    its.send_tx(&[set_trusted_chain_ix, set_max_size_ix])
+        .await
+        .unwrap();
+
+    // Action
+    let ix = axelar_solana_its::instruction::rename_trusted_chain(
+        operator,
+        operator,
+        "polygon".to_owned(),
+        "polygon-amoy".to_owned(),
+    )
+    .unwrap();
+    let simulation_result = its.simulate_tx(&[ix.clone()]).await.unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    its.send_tx(&[ix]).await.unwrap();
+
+    // Assert
+    assert_event_cpi(
+        &TrustedChainRenamed {
+            old_chain_name: "polygon".to_owned(),
+            new_chain_name: "polygon-amoy".to_owned(),
+            authority: operator,
+        },
+        &inner_ixs,
+    );
+    let its_root = its_root_config(&mut its).await;
+    assert!(!its_root.is_trusted_chain("polygon"));
+    assert!(its_root.is_trusted_chain("polygon-amoy"));
+    assert_eq!(its_root.max_payload_sizes.get("polygon"), None);
+    assert_eq!(its_root.max_payload_sizes.get("polygon-amoy"), Some(&512));
+}
+
+#[tokio::test]
+async fn test_rename_untrusted_chain_fails() {
+    // Setup
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let operator = its.operator;
+    let set_trusted_chain_ix =
+        axelar_solana_its::instruction::set_trusted_chain(operator, operator, "polygon".to_owned())
+            .unwrap();
+    let set_max_size_ix = axelar_solana_its::instruction::set_max_payload_size(
+        operator,
+        operator,
+        "polygon".to_owned(),
+        Some(512),
+    )
+    .unwrap();
+    its.send_tx(&[set_trusted_chain_ix, set_max_size_ix])
+        .await
+        .unwrap();
+    let its_root_before = its_root_config(&mut its).await;
+
+    // Action
+    let ix = axelar_solana_its::instruction::rename_trusted_chain(
+        operator,
+        operator,
+        "sui".to_owned(),
+        "sui-testnet".to_owned(),
+    )
+    .unwrap();
+    let res = its.send_tx(&[ix]).await;
+
+    // Assert
+    assert!(res.is_err());
+    assert_eq!(its_root_config(&mut its).await, its_root_before);
+}
+
+#[tokio::test]
+async fn test_rename_trusted_chain_requires_operator_or_owner() {
+    // Setup
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let operator = its.operator;
+    let set_trusted_chain_ix =
+        axelar_solana_its::instruction::set_trusted_chain(operator, operator, "polygon".to_owned())
+            .unwrap();
+    let set_max_size_ix = axelar_solana_its::instruction::set_max_payload_size(
+        operator,
+        operator,
+        "polygon".to_owned(),
+        Some(512),
+    )
+    .unwrap();
+    its.send_tx(&[set_trusted_chain_ix, set_max_size_ix])
+        .await
+        .unwrap();
+    let payer = its.payer.insecure_clone();
+    let stranger = Keypair::new();
+
+    // Action
+    let ix = axelar_solana_its::instruction::rename_trusted_chain(
+        operator,
+        stranger.pubkey(),
+        "polygon".to_owned(),
+        "polygon-amoy".to_owned(),
+    )
+    .unwrap();
+    let res = its
+        .send_tx_with_custom_signers(&[ix], &[&payer, &stranger])
+        .await;
+
+    // Assert
+    assert!(res.is_err());
+    let its_root = its_root_config(&mut its).await;
+    assert!(its_root.is_trusted_chain("polygon"));
+    assert!(!its_root.is_trusted_chain("polygon-amoy"));
+}
@@ -0,0 +1,117 @@
+use axelar_solana_gateway_test_fixtures::assert_msg_present_in_logs;
+use solana_program_test::tokio;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer as _;
+use test_context::test_context;
+
+use crate::ItsTestContext;
+
+async fn token_manager_account_len(ctx: &mut ItsTestContext) -> usize {
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &ctx.deployed_interchain_token);
+
+    ctx.solana_chain
+        .try_get_account_no_checks(&token_manager_pda)
+        .await
+        .unwrap()
+        .unwrap()
+        .data
+        .len()
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_operator_can_resize_token_manager(ctx: &mut ItsTestContext) {
+    let initial_len = token_manager_account_len(ctx).await;
+    let new_size = initial_len + 128;
+
+    let resize_ix = axelar_solana_its::instruction::token_manager::resize(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        ctx.deployed_interchain_token,
+        new_size.try_into().unwrap(),
+    )
+    .unwrap();
+    ctx.send_solana_tx(&[resize_ix]).await.unwrap();
+    assert_eq!(token_manager_account_len(ctx).await, new_size);
+
+    // The reserved room is kept when the token manager is stored again
+    let flow_limit_ix = axelar_solana_its::instruction::set_flow_limit(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        ctx.deployed_interchain_token,
+        Some(1_000),
+    )
+    .unwrap();
+    ctx.send_solana_tx(&[flow_limit_ix]).await.unwrap();
+    assert_eq!(token_manager_account_len(ctx).await, new_size);
+
+    let token_manager = ctx
+        .solana_chain
+        .token_manager(&ctx.deployed_interchain_token)
+        .await;
+    assert_eq!(token_manager.flow_slot.flow_limit, Some(1_000));
+
+    let shrink_ix = axelar_solana_its::instruction::token_manager::resize(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        ctx.deployed_interchain_token,
+        initial_len.try_into().unwrap(),
+    )
+    .unwrap();
+    assert!(ctx.send_solana_tx(&[shrink_ix]).await.is_err());
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_only_operator_can_resize_token_manager(ctx: &mut ItsTestContext) {
+    let initial_len = token_manager_account_len(ctx).await;
+    let stranger = Keypair::new();
+
+    let resize_ix = axelar_solana_its::instruction::token_manager::resize(
+        ctx.solana_wallet,
+        stranger.pubkey(),
+        ctx.deployed_interchain_token,
+        (initial_len + 128).try_into().unwrap(),
+    )
+    .unwrap();
+    let result = ctx
+        .solana_chain
+        .fixture
+        .send_tx_with_custom_signers(
+            &[resize_ix],
+            &[&ctx.solana_chain.fixture.payer.insecure_clone(), &stranger],
+        )
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(token_manager_account_len(ctx).await, initial_len);
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_resize_token_manager_fails_with_underfunded_payer(ctx: &mut ItsTestContext) {
+    let initial_len = token_manager_account_len(ctx).await;
+    let payer = Keypair::new();
+
+    let resize_ix = axelar_solana_its::instruction::token_manager::resize(
+        payer.pubkey(),
+        ctx.solana_wallet,
+        ctx.deployed_interchain_token,
+        (initial_len + 1_024).try_into().unwrap(),
+    )
+    .unwrap();
+    let result = ctx
+        .solana_chain
+        .fixture
+        .send_tx_with_custom_signers(
+            &[resize_ix],
+            &[&ctx.solana_chain.fixture.payer.insecure_clone(), &payer],
+        )
+        .await;
+
+    let tx = result.unwrap_err();
+    assert_msg_present_in_logs(tx, "Rent payer can't cover");
+    assert_eq!(token_manager_account_len(ctx).await, initial_len);
+}
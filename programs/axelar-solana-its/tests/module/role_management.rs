@@ -1,6 +1,7 @@
 use borsh::BorshDeserialize;
 use solana_program::instruction::AccountMeta;
 use solana_program_test::tokio;
+use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signer::Signer;
 use solana_sdk::system_instruction;
 use solana_sdk::{signature::Keypair, system_program};
@@ -184,6 +185,51 @@ async fn test_successful_proposal_acceptance(ctx: &mut ItsTestContext) {
     assert!(bob_roles.contains(roles_to_transfer));
 }
 
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_proposing_operatorship_to_pda_creates_its_roles_account(ctx: &mut ItsTestContext) {
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    // E.g. the config PDA of a governance program
+    let (governance_pda, _) = Pubkey::find_program_address(&[b"governance"], &Pubkey::new_unique());
+
+    let proposal_ix = axelar_solana_its::instruction::propose_operatorship(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        governance_pda,
+    )
+    .unwrap();
+    ctx.send_solana_tx(&[proposal_ix]).await.unwrap();
+
+    // The PDA doesn't need to pay for its roles account when accepting
+    let (governance_roles_pda, _) = role_management::find_user_roles_pda(
+        &axelar_solana_its::id(),
+        &its_root_pda,
+        &governance_pda,
+    );
+    let data = ctx
+        .solana_chain
+        .fixture
+        .get_account(&governance_roles_pda, &axelar_solana_its::id())
+        .await
+        .data;
+    let governance_roles = UserRoles::<Roles>::try_from_slice(&data).unwrap();
+    assert!(!governance_roles.contains(Roles::OPERATOR));
+
+    let (proposal_pda, _) = role_management::find_roles_proposal_pda(
+        &axelar_solana_its::id(),
+        &its_root_pda,
+        &ctx.solana_wallet,
+        &governance_pda,
+        Roles::OPERATOR,
+    );
+    assert!(ctx
+        .solana_chain
+        .try_get_account_no_checks(&proposal_pda)
+        .await
+        .unwrap()
+        .is_some());
+}
+
 #[test_context(ItsTestContext)]
 #[tokio::test]
 async fn test_successful_add_and_remove_flow_limiter(ctx: &mut ItsTestContext) {
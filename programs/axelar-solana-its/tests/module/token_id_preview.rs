@@ -0,0 +1,59 @@
+use solana_program_test::tokio;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use test_context::test_context;
+
+use crate::ItsTestContext;
+
+async fn simulate_token_id(ctx: &mut ItsTestContext, ix: Instruction) -> [u8; 32] {
+    let return_data = ctx
+        .simulate_solana_tx(&[ix])
+        .await
+        .simulation_details
+        .unwrap()
+        .return_data
+        .unwrap();
+
+    assert_eq!(return_data.program_id, axelar_solana_its::id());
+    return_data.data.as_slice().try_into().unwrap()
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_preview_token_ids_match_derivations(ctx: &mut ItsTestContext) {
+    let deployer = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let salt = solana_sdk::keccak::hash(b"token-id-preview").to_bytes();
+
+    let ix = axelar_solana_its::instruction::preview_interchain_token_id(deployer, salt).unwrap();
+    assert_eq!(
+        simulate_token_id(ctx, ix).await,
+        axelar_solana_its::interchain_token_id(&deployer, &salt)
+    );
+
+    let ix = axelar_solana_its::instruction::preview_canonical_token_id(mint).unwrap();
+    assert_eq!(
+        simulate_token_id(ctx, ix).await,
+        axelar_solana_its::canonical_interchain_token_id(&mint)
+    );
+
+    let ix = axelar_solana_its::instruction::preview_linked_token_id(deployer, salt).unwrap();
+    assert_eq!(
+        simulate_token_id(ctx, ix).await,
+        axelar_solana_its::linked_token_id(&deployer, &salt)
+    );
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_preview_interchain_token_id_matches_deployed_token(ctx: &mut ItsTestContext) {
+    // Salt the context deploys its interchain token with
+    let salt = solana_sdk::keccak::hash(b"TestTokenSalt").0;
+    let ix = axelar_solana_its::instruction::preview_interchain_token_id(ctx.solana_wallet, salt)
+        .unwrap();
+
+    assert_eq!(
+        simulate_token_id(ctx, ix).await,
+        ctx.deployed_interchain_token
+    );
+}
@@ -0,0 +1,86 @@
+use axelar_solana_gateway_test_fixtures::assert_msg_present_in_logs;
+use axelar_solana_its::error::ItsError;
+use axelar_solana_its::state::token_manager::Type as TokenManagerType;
+use axelar_solana_its_test_fixtures::TestToken;
+use interchain_token_transfer_gmp::{GMPPayload, InterchainTransfer, SendToHub};
+use solana_program_test::tokio;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::program_option::COption;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::TransactionError;
+use spl_token_2022::extension::StateWithExtensionsMut;
+use spl_token_2022::state::Mint;
+use test_context::test_context;
+
+use crate::ItsTestContext;
+
+/// Creates a `MintBurn` token and changes its mint authority to
+/// `mint_authority` behind the back of the token manager.
+async fn setup_token_with_mint_authority(
+    ctx: &mut ItsTestContext,
+    salt: [u8; 32],
+    mint_authority: COption<Pubkey>,
+) -> TestToken {
+    let token = ctx
+        .solana_chain
+        .create_token(TokenManagerType::MintBurn, spl_token::id(), salt, 9)
+        .await;
+
+    let mut mint = ctx
+        .solana_chain
+        .try_get_account_no_checks(&token.mint)
+        .await
+        .unwrap()
+        .unwrap();
+    {
+        let mut state = StateWithExtensionsMut::<Mint>::unpack(&mut mint.data).unwrap();
+        state.base.mint_authority = mint_authority;
+        state.pack_base();
+    }
+    ctx.solana_chain.set_account_state(&token.mint, mint);
+
+    token
+}
+
+fn transfer_payload(ctx: &ItsTestContext, token: &TestToken) -> Vec<u8> {
+    GMPPayload::SendToHub(SendToHub {
+        selector: SendToHub::MESSAGE_TYPE_ID.try_into().unwrap(),
+        destination_chain: ctx.solana_chain_name.clone(),
+        payload: GMPPayload::InterchainTransfer(InterchainTransfer {
+            selector: InterchainTransfer::MESSAGE_TYPE_ID.try_into().unwrap(),
+            token_id: token.token_id.into(),
+            source_address: [5; 32].into(),
+            destination_address: ctx.solana_wallet.to_bytes().into(),
+            amount: alloy_primitives::U256::from(300_u64),
+            data: vec![].into(),
+        })
+        .encode()
+        .into(),
+    })
+    .encode()
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_inbound_transfer_rejected_when_mint_authority_changed(ctx: &mut ItsTestContext) {
+    for (salt, mint_authority) in [
+        ([1; 32], COption::Some(Pubkey::new_unique())),
+        ([2; 32], COption::None),
+    ] {
+        let token = setup_token_with_mint_authority(ctx, salt, mint_authority).await;
+        let payload = transfer_payload(ctx, &token);
+
+        let (_inner_ixs, tx) = ctx
+            .relay_to_solana(&payload, Some(token.mint), token.token_program)
+            .await;
+
+        assert_eq!(
+            tx.result.clone().unwrap_err(),
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(ItsError::TokenManagerAuthorityMismatch as u32)
+            )
+        );
+        assert_msg_present_in_logs(tx, "TokenManager is no longer the mint authority");
+    }
+}
@@ -0,0 +1,138 @@
+use axelar_solana_its::error::ItsError;
+use axelar_solana_its::events::TokenMetadataAuthoritySet;
+use axelar_solana_its_test_fixtures::{SolanaItsIntegration, SolanaItsIntegrationMetadata};
+use event_cpi_test_utils::assert_event_cpi;
+use mpl_token_metadata::accounts::Metadata;
+use solana_program_test::tokio;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer as _;
+use solana_sdk::transaction::TransactionError;
+
+async fn metadata_update_authority(
+    its: &mut SolanaItsIntegrationMetadata,
+    mint: &Pubkey,
+) -> Pubkey {
+    let (metadata_account, _) = Metadata::find_pda(mint);
+    let account = its
+        .try_get_account_no_checks(&metadata_account)
+        .await
+        .unwrap()
+        .unwrap();
+
+    Metadata::from_bytes(&account.data)
+        .unwrap()
+        .update_authority
+}
+
+#[tokio::test]
+async fn test_minter_and_operator_delegate_metadata_authority() {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let token = its
+        .deploy_interchain_token(
+            solana_sdk::keccak::hash(b"token-metadata-authority").to_bytes(),
+            "Test Token",
+            "TT",
+            9,
+            1_000,
+        )
+        .await;
+    let operator = its.operator;
+    let new_authority = Pubkey::new_unique();
+    assert_eq!(
+        metadata_update_authority(&mut its, &token.mint).await,
+        token.token_manager_pda()
+    );
+
+    let ix = axelar_solana_its::instruction::interchain_token::set_metadata_authority(
+        operator,
+        operator,
+        operator,
+        token.token_id,
+        token.mint,
+        new_authority,
+    )
+    .unwrap();
+    let simulation_result = its.simulate_tx(&[ix.clone()]).await.unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    assert_event_cpi(
+        &TokenMetadataAuthoritySet {
+            token_id: token.token_id,
+            minter: operator,
+            operator,
+            new_authority,
+        },
+        &inner_ixs,
+    );
+
+    its.send_tx(&[ix.clone()]).await.unwrap();
+
+    assert_eq!(
+        metadata_update_authority(&mut its, &token.mint).await,
+        new_authority
+    );
+    assert!(
+        its.token_manager(&token.token_id)
+            .await
+            .metadata_authority_delegated
+    );
+
+    // The handover is one-way
+    let err = its.send_tx(&[ix]).await.unwrap_err();
+    assert_eq!(
+        err.result.unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ItsError::TokenMetadataAuthorityAlreadyDelegated as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_metadata_authority_delegation_requires_operator() {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let token = its
+        .deploy_interchain_token(
+            solana_sdk::keccak::hash(b"token-metadata-authority").to_bytes(),
+            "Test Token",
+            "TT",
+            9,
+            1_000,
+        )
+        .await;
+    let minter = its.operator;
+    let impostor = Keypair::new();
+
+    let ix = axelar_solana_its::instruction::interchain_token::set_metadata_authority(
+        minter,
+        minter,
+        impostor.pubkey(),
+        token.token_id,
+        token.mint,
+        impostor.pubkey(),
+    )
+    .unwrap();
+    let payer = its.fixture.payer.insecure_clone();
+    let res = its
+        .send_tx_with_custom_signers(&[ix], &[&payer, &impostor])
+        .await;
+
+    assert!(res.is_err());
+    assert_eq!(
+        metadata_update_authority(&mut its, &token.mint).await,
+        token.token_manager_pda()
+    );
+    assert!(
+        !its.token_manager(&token.token_id)
+            .await
+            .metadata_authority_delegated
+    );
+}
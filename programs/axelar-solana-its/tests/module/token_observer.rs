@@ -0,0 +1,209 @@
+use alloy_primitives::Bytes;
+use axelar_solana_gateway_test_fixtures::assert_msg_present_in_logs;
+use axelar_solana_gateway_test_fixtures::base::FindLog;
+use axelar_solana_its::events::TokenObserverSet;
+use event_cpi_test_utils::get_first_event_cpi_occurrence;
+use evm_contracts_test_suite::ethers::signers::Signer as EvmSigner;
+use interchain_token_transfer_gmp::{GMPPayload, InterchainTransfer, SendToHub};
+use solana_program_test::tokio;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use test_context::test_context;
+
+use crate::ItsTestContext;
+
+async fn relay_transfer_to_wallet(ctx: &mut ItsTestContext, amount: u64) {
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (interchain_token_pda, _) =
+        axelar_solana_its::find_interchain_token_pda(&its_root_pda, &ctx.deployed_interchain_token);
+
+    let inner_transfer_payload = GMPPayload::SendToHub(SendToHub {
+        selector: SendToHub::MESSAGE_TYPE_ID.try_into().unwrap(),
+        destination_chain: ctx.solana_chain_name.clone(),
+        payload: GMPPayload::InterchainTransfer(InterchainTransfer {
+            selector: InterchainTransfer::MESSAGE_TYPE_ID.try_into().unwrap(),
+            token_id: ctx.deployed_interchain_token.into(),
+            source_address: [5; 32].into(),
+            destination_address: ctx.solana_wallet.to_bytes().into(),
+            amount: amount.try_into().unwrap(),
+            data: Bytes::new(),
+        })
+        .encode()
+        .into(),
+    })
+    .encode();
+
+    let (_inner_ixs, tx) = ctx
+        .relay_to_solana(
+            &inner_transfer_payload,
+            Some(interchain_token_pda),
+            spl_token_2022::id(),
+        )
+        .await;
+
+    assert_msg_present_in_logs(
+        tx,
+        &format!(
+            "Observed Inbound transfer of {amount} tokens with {}",
+            ctx.evm_chain_name
+        ),
+    );
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_token_observer_is_notified_of_transfers(ctx: &mut ItsTestContext) {
+    let set_observer_ix = axelar_solana_its::instruction::set_token_observer(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        ctx.deployed_interchain_token,
+        Some(axelar_solana_memo_program::id()),
+    )
+    .unwrap();
+
+    let simulation_result = ctx.simulate_solana_tx(&[set_observer_ix.clone()]).await;
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    let event = get_first_event_cpi_occurrence::<TokenObserverSet>(&inner_ixs).unwrap();
+    assert_eq!(event.token_id, ctx.deployed_interchain_token);
+    assert_eq!(event.observer, Some(axelar_solana_memo_program::id()));
+
+    ctx.send_solana_tx(&[set_observer_ix]).await.unwrap();
+
+    relay_transfer_to_wallet(ctx, 500).await;
+
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (interchain_token_pda, _) =
+        axelar_solana_its::find_interchain_token_pda(&its_root_pda, &ctx.deployed_interchain_token);
+    let token_account = get_associated_token_address_with_program_id(
+        &ctx.solana_wallet,
+        &interchain_token_pda,
+        &spl_token_2022::id(),
+    );
+    let transfer_ix = axelar_solana_its::instruction::interchain_transfer(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        token_account,
+        ctx.deployed_interchain_token,
+        ctx.evm_chain_name.clone(),
+        ctx.evm_signer.wallet.address().as_bytes().to_vec(),
+        200,
+        interchain_token_pda,
+        spl_token_2022::id(),
+        0,
+    )
+    .unwrap();
+
+    // The registered observer must be provided
+    assert!(ctx
+        .send_solana_tx(&[transfer_ix.clone()])
+        .await
+        .unwrap_err()
+        .find_log("The registered token observer")
+        .is_some());
+
+    let transfer_ix = axelar_solana_its::instruction::with_token_observer(
+        transfer_ix,
+        axelar_solana_memo_program::id(),
+    )
+    .unwrap();
+    let tx = ctx.send_solana_tx(&[transfer_ix]).await.unwrap();
+    assert!(tx
+        .find_log(&format!(
+            "Observed Outbound transfer of 200 tokens with {}",
+            ctx.evm_chain_name
+        ))
+        .is_some());
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_only_operator_can_set_token_observer(ctx: &mut ItsTestContext) {
+    let stranger = Keypair::new();
+    let set_observer_ix = axelar_solana_its::instruction::set_token_observer(
+        ctx.solana_wallet,
+        stranger.pubkey(),
+        ctx.deployed_interchain_token,
+        Some(axelar_solana_memo_program::id()),
+    )
+    .unwrap();
+
+    let payer = ctx.solana_chain.fixture.payer.insecure_clone();
+    assert!(ctx
+        .send_solana_tx_with(
+            &payer,
+            &[set_observer_ix],
+            &[payer.insecure_clone(), stranger]
+        )
+        .await
+        .is_err());
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_removed_token_observer_is_not_notified(ctx: &mut ItsTestContext) {
+    let set_observer_ix = axelar_solana_its::instruction::set_token_observer(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        ctx.deployed_interchain_token,
+        Some(axelar_solana_memo_program::id()),
+    )
+    .unwrap();
+    ctx.send_solana_tx(&[set_observer_ix]).await.unwrap();
+
+    let remove_observer_ix = axelar_solana_its::instruction::set_token_observer(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        ctx.deployed_interchain_token,
+        None,
+    )
+    .unwrap();
+    ctx.send_solana_tx(&[remove_observer_ix]).await.unwrap();
+
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &ctx.deployed_interchain_token);
+    let (token_observer_pda, _) = axelar_solana_its::find_token_observer_pda(&token_manager_pda);
+    assert!(ctx
+        .solana_chain
+        .try_get_account_no_checks(&token_observer_pda)
+        .await
+        .unwrap()
+        .is_none());
+
+    let (interchain_token_pda, _) =
+        axelar_solana_its::find_interchain_token_pda(&its_root_pda, &ctx.deployed_interchain_token);
+    let inner_transfer_payload = GMPPayload::SendToHub(SendToHub {
+        selector: SendToHub::MESSAGE_TYPE_ID.try_into().unwrap(),
+        destination_chain: ctx.solana_chain_name.clone(),
+        payload: GMPPayload::InterchainTransfer(InterchainTransfer {
+            selector: InterchainTransfer::MESSAGE_TYPE_ID.try_into().unwrap(),
+            token_id: ctx.deployed_interchain_token.into(),
+            source_address: [5; 32].into(),
+            destination_address: ctx.solana_wallet.to_bytes().into(),
+            amount: 100_u64.try_into().unwrap(),
+            data: Bytes::new(),
+        })
+        .encode()
+        .into(),
+    })
+    .encode();
+
+    let (_inner_ixs, tx) = ctx
+        .relay_to_solana(
+            &inner_transfer_payload,
+            Some(interchain_token_pda),
+            spl_token_2022::id(),
+        )
+        .await;
+
+    assert!(tx.result.is_ok());
+    assert!(tx.find_log("Observed").is_none());
+}
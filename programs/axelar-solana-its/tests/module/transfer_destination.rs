@@ -133,9 +133,9 @@ async fn test_inbound_transfer_using_token_account_mint_burn(
         .await;
 
     let transfer_received_event = get_first_event_cpi_occurrence::<
-        axelar_solana_its::events::InterchainTransferReceived,
+        axelar_solana_its::events::InterchainTransferReceivedV2,
     >(&inner_ixs)
-    .expect("InterchainTransferReceived event should be present");
+    .expect("InterchainTransferReceivedV2 event should be present");
 
     assert_eq!(transfer_received_event.amount, transfer_amount);
     assert_eq!(transfer_received_event.token_id, token_id);
@@ -211,9 +211,9 @@ async fn test_inbound_transfer_using_token_account_lock_unlock(
         .await;
 
     let transfer_received_event = get_first_event_cpi_occurrence::<
-        axelar_solana_its::events::InterchainTransferReceived,
+        axelar_solana_its::events::InterchainTransferReceivedV2,
     >(&inner_ixs)
-    .expect("InterchainTransferReceived event should be present");
+    .expect("InterchainTransferReceivedV2 event should be present");
 
     assert_eq!(transfer_received_event.amount, transfer_amount);
     assert_eq!(transfer_received_event.token_id, token_id);
@@ -277,9 +277,9 @@ async fn test_inbound_transfer_using_wallet_mint_burn(
         .await;
 
     let transfer_received_event = get_first_event_cpi_occurrence::<
-        axelar_solana_its::events::InterchainTransferReceived,
+        axelar_solana_its::events::InterchainTransferReceivedV2,
     >(&inner_ixs)
-    .expect("InterchainTransferReceived event should be present");
+    .expect("InterchainTransferReceivedV2 event should be present");
 
     assert_eq!(transfer_received_event.amount, transfer_amount);
     assert_eq!(transfer_received_event.token_id, token_id);
@@ -361,9 +361,9 @@ async fn test_inbound_transfer_using_wallet_lock_unlock(
         .await;
 
     let transfer_received_event = get_first_event_cpi_occurrence::<
-        axelar_solana_its::events::InterchainTransferReceived,
+        axelar_solana_its::events::InterchainTransferReceivedV2,
     >(&inner_ixs)
-    .expect("InterchainTransferReceived event should be present");
+    .expect("InterchainTransferReceivedV2 event should be present");
 
     assert_eq!(transfer_received_event.amount, transfer_amount);
     assert_eq!(transfer_received_event.token_id, token_id);
@@ -0,0 +1,364 @@
+//! Property-based tests for the amount arithmetic of interchain transfers.
+//!
+//! Random sequences of inbound and outbound transfers of a Token-2022 mint
+//! with random decimals, transfer fee and flow limit are replayed against
+//! program-test. ITS never scales amounts by decimals, so after every step
+//! the balances must add up to the unchanged supply and the amounts reported
+//! by the events and the GMP payload must match the tokens that moved.
+
+use axelar_solana_gateway::events::CallContractEvent;
+use axelar_solana_its::events::{
+    InterchainTransferReceivedV2, InterchainTransferV2 as InterchainTransferEvent,
+};
+use axelar_solana_its::state::token_manager::Type as TokenManagerType;
+use axelar_solana_its_test_fixtures::gmp::receive_from_hub;
+use axelar_solana_its_test_fixtures::{
+    SolanaItsIntegration, SolanaItsIntegrationMetadata, TestToken,
+};
+use event_cpi_test_utils::get_first_event_cpi_occurrence;
+use interchain_token_transfer_gmp::alloy_primitives::{Bytes, U256};
+use interchain_token_transfer_gmp::{GMPPayload, InterchainTransfer};
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+use solana_program_test::tokio;
+use solana_sdk::clock::Clock;
+use solana_sdk::inner_instruction::InnerInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use spl_token_2022::extension::transfer_fee::{TransferFeeAmount, TransferFeeConfig};
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use spl_token_2022::state::{Account, Mint};
+
+const DESTINATION_CHAIN: &str = "ethereum";
+const INITIAL_SUPPLY: u64 = 1_000_000_000;
+
+#[derive(Debug, Clone)]
+struct TokenParams {
+    decimals: u8,
+    fee_basis_points: u16,
+    maximum_fee: u64,
+    flow_limit: Option<u64>,
+}
+
+fn token_params_strategy() -> impl Strategy<Value = TokenParams> {
+    (
+        0_u8..=12,
+        0_u16..=1_000,
+        prop_oneof![Just(u64::MAX), 0_u64..10_000],
+        prop::option::of(1_u64..INITIAL_SUPPLY),
+    )
+        .prop_map(
+            |(decimals, fee_basis_points, maximum_fee, flow_limit)| TokenParams {
+                decimals,
+                fee_basis_points,
+                maximum_fee,
+                flow_limit,
+            },
+        )
+}
+
+#[derive(Debug, Clone)]
+enum Op {
+    Outbound { amount: u64 },
+    Inbound { amount: u64 },
+}
+
+fn amount_strategy() -> impl Strategy<Value = u64> {
+    prop_oneof![1_u64..1_000, 1_u64..INITIAL_SUPPLY, Just(INITIAL_SUPPLY)]
+}
+
+fn op_strategy() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        amount_strategy().prop_map(|amount| Op::Outbound { amount }),
+        amount_strategy().prop_map(|amount| Op::Inbound { amount }),
+    ]
+}
+
+/// Balance of a token account including the fees withheld in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Balance {
+    amount: u64,
+    withheld: u64,
+}
+
+impl Balance {
+    const fn total(self) -> u64 {
+        self.amount + self.withheld
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Snapshot {
+    user: Balance,
+    token_manager: Balance,
+    supply: u64,
+}
+
+struct Harness {
+    its: SolanaItsIntegrationMetadata,
+    token: TestToken,
+    params: TokenParams,
+}
+
+impl Harness {
+    async fn new(params: TokenParams) -> Self {
+        let mut its = SolanaItsIntegration::builder().build().setup().await;
+        let operator = its.operator;
+        let mint = its
+            .init_new_mint_with_fee(
+                &operator,
+                &spl_token_2022::id(),
+                params.fee_basis_points,
+                params.maximum_fee,
+                params.decimals,
+                None,
+                None,
+            )
+            .await;
+        let token = its
+            .register_custom_token(
+                solana_sdk::keccak::hash(b"transfer-properties").to_bytes(),
+                mint,
+                TokenManagerType::LockUnlockFee,
+                spl_token_2022::id(),
+            )
+            .await;
+        its.mint_test_tokens(&token, &operator, INITIAL_SUPPLY)
+            .await
+            .unwrap();
+
+        if params.flow_limit.is_some() {
+            let ix = axelar_solana_its::instruction::set_flow_limit(
+                operator,
+                operator,
+                token.token_id,
+                params.flow_limit,
+            )
+            .unwrap();
+            its.send_tx(&[ix]).await.unwrap();
+        }
+
+        Self { its, token, params }
+    }
+
+    async fn balance(&mut self, token_account: &Pubkey) -> Balance {
+        let Some(account) = self
+            .its
+            .try_get_account_no_checks(token_account)
+            .await
+            .unwrap()
+        else {
+            return Balance {
+                amount: 0,
+                withheld: 0,
+            };
+        };
+        let account = StateWithExtensions::<Account>::unpack(&account.data).unwrap();
+        let withheld = account
+            .get_extension::<TransferFeeAmount>()
+            .map_or(0, |fee_amount| fee_amount.withheld_amount.into());
+
+        Balance {
+            amount: account.base.amount,
+            withheld,
+        }
+    }
+
+    async fn snapshot(&mut self) -> Snapshot {
+        let operator = self.its.operator;
+        let user_ata = self.token.associated_token_account(&operator);
+        let token_manager_ata = self
+            .token
+            .associated_token_account(&self.token.token_manager_pda());
+        let mint = self
+            .its
+            .try_get_account_no_checks(&self.token.mint)
+            .await
+            .unwrap()
+            .unwrap();
+        let supply = StateWithExtensions::<Mint>::unpack(&mint.data)
+            .unwrap()
+            .base
+            .supply;
+
+        Snapshot {
+            user: self.balance(&user_ata).await,
+            token_manager: self.balance(&token_manager_ata).await,
+            supply,
+        }
+    }
+
+    async fn transfer_fee(&mut self, amount: u64) -> u64 {
+        let mint = self
+            .its
+            .try_get_account_no_checks(&self.token.mint)
+            .await
+            .unwrap()
+            .unwrap();
+        let mint = StateWithExtensions::<Mint>::unpack(&mint.data).unwrap();
+        let epoch = self.its.get_sysvar::<Clock>().await.epoch;
+
+        mint.get_extension::<TransferFeeConfig>()
+            .unwrap()
+            .calculate_epoch_fee(epoch, amount)
+            .unwrap()
+    }
+
+    /// Simulates `ix` to capture its events, then executes it. Returns `None`
+    /// when the transaction failed.
+    async fn execute(&mut self, ix: Instruction) -> Option<Vec<InnerInstruction>> {
+        let simulation = self.its.simulate_tx(&[ix.clone()]).await.unwrap();
+        if simulation.result.as_ref().is_some_and(Result::is_err) {
+            return None;
+        }
+        self.its.send_tx(&[ix]).await.ok()?;
+
+        simulation
+            .simulation_details
+            .and_then(|details| details.inner_instructions)
+            .and_then(|inner_ixs| inner_ixs.first().cloned())
+    }
+
+    async fn outbound(&mut self, amount: u64) -> Result<(), TestCaseError> {
+        let operator = self.its.operator;
+        let before = self.snapshot().await;
+        let fee = self.transfer_fee(amount).await;
+        let ix = axelar_solana_its::instruction::interchain_transfer(
+            operator,
+            operator,
+            self.token.associated_token_account(&operator),
+            self.token.token_id,
+            DESTINATION_CHAIN.to_owned(),
+            vec![0xAB; 20],
+            amount,
+            self.token.mint,
+            self.token.token_program,
+            0,
+        )
+        .unwrap();
+
+        let Some(inner_ixs) = self.execute(ix).await else {
+            prop_assert_eq!(self.snapshot().await, before);
+            return Ok(());
+        };
+        let after = self.snapshot().await;
+
+        let event = get_first_event_cpi_occurrence::<InterchainTransferEvent>(&inner_ixs).unwrap();
+        prop_assert_eq!(event.amount, amount - fee);
+        prop_assert_eq!(after.user.amount, before.user.amount - amount);
+        prop_assert_eq!(
+            after.token_manager.amount,
+            before.token_manager.amount + event.amount
+        );
+        prop_assert_eq!(
+            after.token_manager.withheld,
+            before.token_manager.withheld + fee
+        );
+
+        // The amount sent to the destination chain is the amount ITS locked,
+        // regardless of the decimals of the mint
+        let call_contract =
+            get_first_event_cpi_occurrence::<CallContractEvent>(&inner_ixs).unwrap();
+        let GMPPayload::SendToHub(send_to_hub) =
+            GMPPayload::decode(&call_contract.payload).unwrap()
+        else {
+            return Err(TestCaseError::fail("expected a message to the ITS Hub"));
+        };
+        let GMPPayload::InterchainTransfer(transfer) =
+            GMPPayload::decode(send_to_hub.payload.as_ref()).unwrap()
+        else {
+            return Err(TestCaseError::fail("expected an interchain transfer"));
+        };
+        prop_assert_eq!(transfer.amount, U256::from(event.amount));
+
+        Ok(())
+    }
+
+    async fn inbound(&mut self, amount: u64) -> Result<(), TestCaseError> {
+        let operator = self.its.operator;
+        let before = self.snapshot().await;
+        let fee = self.transfer_fee(amount).await;
+        let payload = receive_from_hub(
+            DESTINATION_CHAIN,
+            &GMPPayload::InterchainTransfer(InterchainTransfer {
+                selector: U256::from(InterchainTransfer::MESSAGE_TYPE_ID),
+                token_id: self.token.token_id.into(),
+                source_address: Bytes::from(vec![0xAB; 20]),
+                destination_address: Bytes::from(operator.to_bytes().to_vec()),
+                amount: U256::from(amount),
+                data: Bytes::new(),
+            }),
+        );
+        let ix = self
+            .its
+            .approve_its_message(payload, Some(self.token.mint), self.token.token_program)
+            .await
+            .unwrap();
+
+        let Some(inner_ixs) = self.execute(ix).await else {
+            prop_assert_eq!(self.snapshot().await, before);
+            return Ok(());
+        };
+        let after = self.snapshot().await;
+
+        let event =
+            get_first_event_cpi_occurrence::<InterchainTransferReceivedV2>(&inner_ixs).unwrap();
+        prop_assert_eq!(event.amount, amount - fee);
+        prop_assert_eq!(
+            after.token_manager.amount,
+            before.token_manager.amount - amount
+        );
+        prop_assert_eq!(after.user.amount, before.user.amount + event.amount);
+        prop_assert_eq!(after.user.withheld, before.user.withheld + fee);
+
+        Ok(())
+    }
+
+    async fn check_invariants(&mut self) -> Result<(), TestCaseError> {
+        let snapshot = self.snapshot().await;
+
+        // No tokens are ever created nor destroyed
+        prop_assert_eq!(snapshot.supply, INITIAL_SUPPLY);
+        prop_assert_eq!(
+            snapshot.user.total() + snapshot.token_manager.total(),
+            INITIAL_SUPPLY
+        );
+
+        let flow = self.its.token_manager(&self.token.token_id).await.flow_slot;
+        prop_assert_eq!(flow.flow_limit, self.params.flow_limit);
+        if let Some(flow_limit) = flow.flow_limit {
+            prop_assert!(flow.flow_in.abs_diff(flow.flow_out) <= flow_limit);
+        }
+
+        Ok(())
+    }
+}
+
+async fn run(params: TokenParams, ops: Vec<Op>) -> Result<(), TestCaseError> {
+    let mut harness = Harness::new(params).await;
+    harness.check_invariants().await?;
+
+    for op in ops {
+        match op {
+            Op::Outbound { amount } => harness.outbound(amount).await?,
+            Op::Inbound { amount } => harness.inbound(amount).await?,
+        }
+        harness.check_invariants().await?;
+    }
+
+    Ok(())
+}
+
+proptest! {
+    // Every case boots a fresh program-test bank, keep the amount modest
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn transfers_conserve_tokens_and_report_moved_amounts(
+        params in token_params_strategy(),
+        ops in prop::collection::vec(op_strategy(), 1..12)
+    ) {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(run(params, ops))?;
+    }
+}
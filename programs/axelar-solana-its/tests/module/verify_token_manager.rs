@@ -0,0 +1,140 @@
+use axelar_solana_its::events::{TokenManagerMismatch, TokenManagerVerified};
+use axelar_solana_its_test_fixtures::{SolanaItsIntegration, SolanaItsIntegrationMetadata};
+use event_cpi_test_utils::get_first_event_cpi_occurrence;
+use solana_program_test::tokio;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use spl_token_2022::extension::StateWithExtensionsMut;
+use spl_token_2022::state::Account;
+
+const SALT: [u8; 32] = [9; 32];
+
+/// Deploys an interchain token and returns its token id.
+async fn deploy(its: &mut SolanaItsIntegrationMetadata) -> [u8; 32] {
+    let operator = its.operator;
+    let ix = axelar_solana_its::instruction::deploy_interchain_token(
+        operator,
+        operator,
+        SALT,
+        "Test Token".to_owned(),
+        "TT".to_owned(),
+        9,
+        1_000,
+        None,
+    )
+    .unwrap();
+    its.send_tx(&[ix]).await.unwrap();
+
+    axelar_solana_its::interchain_token_id(&operator, &SALT)
+}
+
+async fn verify_ix(its: &mut SolanaItsIntegrationMetadata, token_id: [u8; 32]) -> Instruction {
+    let token_manager = its.token_manager(&token_id).await;
+    axelar_solana_its::instruction::token_manager::verify_token_manager(
+        token_id,
+        token_manager.token_address,
+        token_manager.associated_token_account,
+    )
+    .unwrap()
+}
+
+async fn verify(
+    its: &mut SolanaItsIntegrationMetadata,
+    token_id: [u8; 32],
+) -> TokenManagerVerified {
+    let ix = verify_ix(its, token_id).await;
+    let simulation_result = its.simulate_tx(&[ix]).await.unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+
+    get_first_event_cpi_occurrence::<TokenManagerVerified>(&inner_ixs).unwrap()
+}
+
+#[tokio::test]
+async fn test_verify_consistent_token_manager() {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let token_id = deploy(&mut its).await;
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+
+    let event = verify(&mut its, token_id).await;
+
+    assert_eq!(
+        event,
+        TokenManagerVerified {
+            token_id,
+            token_manager: token_manager_pda,
+            mismatches: Vec::new(),
+        }
+    );
+    let ix = verify_ix(&mut its, token_id).await;
+    its.send_tx(&[ix]).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_verify_reports_associated_token_account_owner_mismatch() {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let token_id = deploy(&mut its).await;
+    let token_manager = its.token_manager(&token_id).await;
+    let other_owner = Pubkey::new_unique();
+    let mut ata = its
+        .try_get_account_no_checks(&token_manager.associated_token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    {
+        let mut state = StateWithExtensionsMut::<Account>::unpack(&mut ata.data).unwrap();
+        state.base.owner = other_owner;
+        state.pack_base();
+    }
+    its.set_account_state(&token_manager.associated_token_account, ata);
+
+    let event = verify(&mut its, token_id).await;
+
+    assert_eq!(
+        event.mismatches,
+        vec![TokenManagerMismatch::AssociatedTokenAccountOwner { owner: other_owner }]
+    );
+}
+
+#[tokio::test]
+async fn test_verify_reports_missing_associated_token_account() {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let token_id = deploy(&mut its).await;
+    let token_manager = its.token_manager(&token_id).await;
+    its.set_account_state(
+        &token_manager.associated_token_account,
+        solana_sdk::account::Account::default(),
+    );
+
+    let event = verify(&mut its, token_id).await;
+
+    assert_eq!(
+        event.mismatches,
+        vec![TokenManagerMismatch::AssociatedTokenAccountNotInitialized]
+    );
+}
+
+#[tokio::test]
+async fn test_verify_rejects_other_mint() {
+    let mut its = SolanaItsIntegration::builder().build().setup().await;
+    let token_id = deploy(&mut its).await;
+    let token_manager = its.token_manager(&token_id).await;
+
+    let ix = axelar_solana_its::instruction::token_manager::verify_token_manager(
+        token_id,
+        Pubkey::new_unique(),
+        token_manager.associated_token_account,
+    )
+    .unwrap();
+    let res = its.send_tx(&[ix]).await;
+
+    assert!(res.is_err());
+}
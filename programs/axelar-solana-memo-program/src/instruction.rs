@@ -67,12 +67,18 @@ pub enum AxelarMemoInstruction {
     /// 5. [] Gateway root PDA
     /// 6. [] Gateway program ID
     /// 7. [] Gas Service root PDA
-    /// 8. [] Gas Service program ID
-    /// 9. [] Token mint
-    /// 10. [] Token program
-    /// 11. [] Call contract signing PDA
-    /// 12. [] ITS program ID
-    /// 13. [] System program
+    /// 8. [] Gas Service paused chains PDA
+    /// 9. [] Gas Service program ID
+    /// 10. [] Token mint
+    /// 11. [] Token program
+    /// 12. [] Call contract signing PDA
+    /// 13. [] ITS program ID
+    /// 14. [] System program
+    /// 15. [] ITS event authority PDA
+    /// 16. [] Gateway event authority PDA
+    /// 17. [] Gas Service event authority PDA
+    /// 18. [w] ITS outbound nonce PDA of the memo program
+    /// 19. [] ITS token observer PDA of the token
     SendInterchainTransfer {
         /// Token ID for the transfer
         token_id: [u8; 32],
@@ -215,6 +221,7 @@ pub fn send_interchain_transfer(
     // Additional required accounts for proper ITS instruction
     let gateway_program = axelar_solana_gateway::id();
     let gas_service_program = axelar_solana_gas_service::id();
+    let (gas_service_paused_chains_pda, _bump) = axelar_solana_gas_service::get_paused_chains_pda();
     let (call_contract_signing_pda, _) =
         axelar_solana_gateway::get_call_contract_signing_pda(axelar_solana_its::id());
     let its_program = axelar_solana_its::id();
@@ -228,6 +235,9 @@ pub fn send_interchain_transfer(
         &[event_cpi::EVENT_AUTHORITY_SEED],
         &axelar_solana_gas_service::ID,
     );
+    let (its_outbound_nonce_pda, _bump) = axelar_solana_its::find_outbound_nonce_pda(&crate::ID);
+    let (its_token_observer_pda, _bump) =
+        axelar_solana_its::find_token_observer_pda(token_manager_pda);
 
     let accounts = vec![
         AccountMeta::new(*payer, true),
@@ -239,6 +249,7 @@ pub fn send_interchain_transfer(
         AccountMeta::new_readonly(*gateway_root_pda, false),
         AccountMeta::new_readonly(gateway_program, false),
         AccountMeta::new(*gas_service_root_pda, false),
+        AccountMeta::new_readonly(gas_service_paused_chains_pda, false),
         AccountMeta::new_readonly(gas_service_program, false),
         AccountMeta::new(*token_mint, false),
         AccountMeta::new_readonly(*token_program, false),
@@ -248,6 +259,8 @@ pub fn send_interchain_transfer(
         AccountMeta::new_readonly(its_event_authority, false),
         AccountMeta::new_readonly(gateway_event_authority, false),
         AccountMeta::new_readonly(gas_service_event_authority, false),
+        AccountMeta::new(its_outbound_nonce_pda, false),
+        AccountMeta::new_readonly(its_token_observer_pda, false),
     ];
 
     Ok(Instruction {
@@ -296,6 +309,7 @@ pub fn send_interchain_transfer_with_wrong_seeds(
     // Additional required accounts for proper ITS instruction
     let gateway_program = axelar_solana_gateway::id();
     let gas_service_program = axelar_solana_gas_service::id();
+    let (gas_service_paused_chains_pda, _bump) = axelar_solana_gas_service::get_paused_chains_pda();
     let (call_contract_signing_pda, _) =
         axelar_solana_gateway::get_call_contract_signing_pda(axelar_solana_its::id());
     let its_program = axelar_solana_its::id();
@@ -309,6 +323,9 @@ pub fn send_interchain_transfer_with_wrong_seeds(
         &[event_cpi::EVENT_AUTHORITY_SEED],
         &axelar_solana_gas_service::ID,
     );
+    let (its_outbound_nonce_pda, _bump) = axelar_solana_its::find_outbound_nonce_pda(&crate::ID);
+    let (its_token_observer_pda, _bump) =
+        axelar_solana_its::find_token_observer_pda(token_manager_pda);
 
     let accounts = vec![
         AccountMeta::new(*payer, true),
@@ -320,6 +337,7 @@ pub fn send_interchain_transfer_with_wrong_seeds(
         AccountMeta::new_readonly(*gateway_root_pda, false),
         AccountMeta::new_readonly(gateway_program, false),
         AccountMeta::new(*gas_service_root_pda, false),
+        AccountMeta::new_readonly(gas_service_paused_chains_pda, false),
         AccountMeta::new_readonly(gas_service_program, false),
         AccountMeta::new(*token_mint, false),
         AccountMeta::new_readonly(*token_program, false),
@@ -329,6 +347,8 @@ pub fn send_interchain_transfer_with_wrong_seeds(
         AccountMeta::new_readonly(its_event_authority, false),
         AccountMeta::new_readonly(gateway_event_authority, false),
         AccountMeta::new_readonly(gas_service_event_authority, false),
+        AccountMeta::new(its_outbound_nonce_pda, false),
+        AccountMeta::new_readonly(its_token_observer_pda, false),
     ];
 
     Ok(Instruction {
@@ -377,6 +397,7 @@ pub fn call_contract_with_interchain_token(
     // Additional required accounts for proper ITS instruction
     let gateway_program = axelar_solana_gateway::id();
     let gas_service_program = axelar_solana_gas_service::id();
+    let (gas_service_paused_chains_pda, _bump) = axelar_solana_gas_service::get_paused_chains_pda();
     let (call_contract_signing_pda, _) =
         axelar_solana_gateway::get_call_contract_signing_pda(axelar_solana_its::id());
     let its_program = axelar_solana_its::id();
@@ -390,6 +411,9 @@ pub fn call_contract_with_interchain_token(
         &[event_cpi::EVENT_AUTHORITY_SEED],
         &axelar_solana_gas_service::ID,
     );
+    let (its_outbound_nonce_pda, _bump) = axelar_solana_its::find_outbound_nonce_pda(&crate::ID);
+    let (its_token_observer_pda, _bump) =
+        axelar_solana_its::find_token_observer_pda(token_manager_pda);
 
     let accounts = vec![
         AccountMeta::new(*payer, true),
@@ -401,6 +425,7 @@ pub fn call_contract_with_interchain_token(
         AccountMeta::new_readonly(*gateway_root_pda, false),
         AccountMeta::new_readonly(gateway_program, false),
         AccountMeta::new(*gas_service_root_pda, false),
+        AccountMeta::new_readonly(gas_service_paused_chains_pda, false),
         AccountMeta::new_readonly(gas_service_program, false),
         AccountMeta::new(*token_mint, false),
         AccountMeta::new_readonly(*token_program, false),
@@ -410,6 +435,8 @@ pub fn call_contract_with_interchain_token(
         AccountMeta::new_readonly(its_event_authority, false),
         AccountMeta::new_readonly(gateway_event_authority, false),
         AccountMeta::new_readonly(gas_service_event_authority, false),
+        AccountMeta::new(its_outbound_nonce_pda, false),
+        AccountMeta::new_readonly(its_token_observer_pda, false),
     ];
 
     Ok(Instruction {
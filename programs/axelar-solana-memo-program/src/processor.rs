@@ -2,12 +2,14 @@
 
 use axelar_solana_encoding::types::messages::Message;
 use axelar_solana_gateway::executable::{
-    validate_message, AxelarMessagePayload, PROGRAM_ACCOUNTS_START_INDEX,
+    validate_gateway_call, validate_message, AxelarMessagePayload, GatewayCall,
+    GATEWAY_CALL_ACCOUNTS_START_INDEX, PROGRAM_ACCOUNTS_START_INDEX,
 };
 use axelar_solana_gateway::state::message_payload::ImmutMessagePayload;
 use axelar_solana_its::executable::{
     AxelarInterchainTokenExecuteInfo, MaybeAxelarInterchainTokenExecutablePayload,
 };
+use axelar_solana_its::observer::{validate_transfer_notification, TransferNotification};
 use borsh::{self, BorshDeserialize};
 use mpl_token_metadata::accounts::Metadata;
 use program_utils::{check_program_account, pda::ValidPDA};
@@ -39,6 +41,11 @@ pub fn process_instruction<'a>(
         return process_message_from_axelar(program_id, accounts, &message);
     }
 
+    if let Some(call) = axelar_solana_gateway::executable::parse_gateway_call(input).transpose()? {
+        msg!("Instruction: AxelarGatewayCall");
+        return process_gateway_call(program_id, accounts, &call);
+    }
+
     if let Some((execute_info, call_data)) = input
         .try_get_axelar_interchain_token_executable_payload(accounts)
         .transpose()?
@@ -52,6 +59,13 @@ pub fn process_instruction<'a>(
         );
     }
 
+    if let Some(notification) =
+        axelar_solana_its::observer::parse_transfer_notification(input).transpose()?
+    {
+        msg!("Instruction: TokenObserverNotification");
+        return process_transfer_notification(accounts, &notification);
+    }
+
     msg!("Instruction: Native");
     let instruction = AxelarMemoInstruction::try_from_slice(input)?;
     process_native_ix(program_id, accounts, instruction)
@@ -65,12 +79,11 @@ pub fn process_message_from_axelar_with_token<'a>(
     execute_info: &AxelarInterchainTokenExecuteInfo,
     call_data: Vec<u8>,
 ) -> ProgramResult {
+    axelar_solana_its::executable::verify_its_signer(program_id, accounts)?;
+
+    let (_protocol_accounts, accounts) =
+        accounts.split_at(axelar_solana_its::executable::PROGRAM_ACCOUNTS_START_INDEX);
     let accounts_iter = &mut accounts.iter();
-    let _its_root_pda = next_account_info(accounts_iter)?;
-    let _message_payload_account = next_account_info(accounts_iter)?;
-    let _token_program = next_account_info(accounts_iter)?;
-    let _token_mint = next_account_info(accounts_iter)?;
-    let _ata_account = next_account_info(accounts_iter)?;
     let mpl_token_metadata_account = next_account_info(accounts_iter)?;
     let instruction_accounts = accounts_iter.as_slice();
     let token_metadata = Metadata::from_bytes(&mpl_token_metadata_account.try_borrow_data()?)?;
@@ -117,6 +130,41 @@ pub fn process_message_from_axelar(
     Ok(())
 }
 
+/// Process a message executed by the gateway on behalf of this program
+pub fn process_gateway_call(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    call: &GatewayCall,
+) -> ProgramResult {
+    validate_gateway_call(accounts, &call.message)?;
+    let (_protocol_accounts, accounts) = accounts.split_at(GATEWAY_CALL_ACCOUNTS_START_INDEX);
+
+    let memo = from_utf8(&call.payload).map_err(|err| {
+        msg!("Invalid UTF-8, from byte {}", err.valid_up_to());
+        ProgramError::InvalidInstructionData
+    })?;
+
+    process_memo(program_id, accounts, memo)
+}
+
+/// Process a transfer notification sent by ITS for a token observed by this
+/// program
+pub fn process_transfer_notification(
+    accounts: &[AccountInfo<'_>],
+    notification: &TransferNotification,
+) -> ProgramResult {
+    validate_transfer_notification(accounts, notification)?;
+
+    msg!(
+        "Observed {:?} transfer of {} tokens with {}",
+        notification.direction,
+        notification.amount,
+        notification.counterpart_chain
+    );
+
+    Ok(())
+}
+
 /// Process a native instruction submitted by another program or user ON the
 /// Solana network
 pub fn process_native_ix(
@@ -303,6 +351,7 @@ pub fn process_send_interchain_transfer(
     let gateway_root_pda = next_account_info(accounts_iter)?;
     let gateway_program_account = next_account_info(accounts_iter)?;
     let gas_service_root_pda = next_account_info(accounts_iter)?;
+    let gas_service_paused_chains_pda = next_account_info(accounts_iter)?;
     let gas_service_program_account = next_account_info(accounts_iter)?;
     let token_mint = next_account_info(accounts_iter)?;
     let token_program = next_account_info(accounts_iter)?;
@@ -312,6 +361,8 @@ pub fn process_send_interchain_transfer(
     let its_event_authority = next_account_info(accounts_iter)?;
     let gateway_event_authority = next_account_info(accounts_iter)?;
     let gas_service_event_authority = next_account_info(accounts_iter)?;
+    let its_outbound_nonce = next_account_info(accounts_iter)?;
+    let its_token_observer = next_account_info(accounts_iter)?;
 
     let counter_pda_account = counter_pda.check_initialized_pda::<Counter>(program_id)?;
     assert_counter_pda_seeds(&counter_pda_account, counter_pda.key);
@@ -365,6 +416,7 @@ pub fn process_send_interchain_transfer(
             gateway_root_pda.clone(),
             gateway_program_account.clone(),
             gas_service_root_pda.clone(),
+            gas_service_paused_chains_pda.clone(),
             gas_service_program_account.clone(),
             system_program.clone(),
             its_root_pda.clone(),
@@ -373,6 +425,8 @@ pub fn process_send_interchain_transfer(
             its_event_authority.clone(),
             gateway_event_authority.clone(),
             gas_service_event_authority.clone(),
+            its_outbound_nonce.clone(),
+            its_token_observer.clone(),
         ],
         &[&[&[counter_bump]]],
     )?;
@@ -439,6 +493,7 @@ pub fn process_send_interchain_transfer_with_wrong_seeds(
     let gateway_root_pda = next_account_info(accounts_iter)?;
     let gateway_program_account = next_account_info(accounts_iter)?;
     let gas_service_root_pda = next_account_info(accounts_iter)?;
+    let gas_service_paused_chains_pda = next_account_info(accounts_iter)?;
     let gas_service_program_account = next_account_info(accounts_iter)?;
     let token_mint = next_account_info(accounts_iter)?;
     let token_program = next_account_info(accounts_iter)?;
@@ -448,6 +503,8 @@ pub fn process_send_interchain_transfer_with_wrong_seeds(
     let its_event_authority = next_account_info(accounts_iter)?;
     let gateway_event_authority = next_account_info(accounts_iter)?;
     let gas_service_event_authority = next_account_info(accounts_iter)?;
+    let its_outbound_nonce = next_account_info(accounts_iter)?;
+    let its_token_observer = next_account_info(accounts_iter)?;
 
     let counter_pda_account = counter_pda.check_initialized_pda::<Counter>(program_id)?;
     assert_counter_pda_seeds(&counter_pda_account, counter_pda.key);
@@ -499,6 +556,7 @@ pub fn process_send_interchain_transfer_with_wrong_seeds(
             gateway_root_pda.clone(),
             gateway_program_account.clone(),
             gas_service_root_pda.clone(),
+            gas_service_paused_chains_pda.clone(),
             gas_service_program_account.clone(),
             system_program.clone(),
             its_root_pda.clone(),
@@ -507,6 +565,8 @@ pub fn process_send_interchain_transfer_with_wrong_seeds(
             its_event_authority.clone(),
             gateway_event_authority.clone(),
             gas_service_event_authority.clone(),
+            its_outbound_nonce.clone(),
+            its_token_observer.clone(),
         ],
         &[&[&[counter_bump]]],
     )?;
@@ -537,6 +597,7 @@ pub fn process_call_contract_with_interchain_token(
     let gateway_root_pda = next_account_info(accounts_iter)?;
     let gateway_program_account = next_account_info(accounts_iter)?;
     let gas_service_root_pda = next_account_info(accounts_iter)?;
+    let gas_service_paused_chains_pda = next_account_info(accounts_iter)?;
     let gas_service_program_account = next_account_info(accounts_iter)?;
     let token_mint = next_account_info(accounts_iter)?;
     let token_program = next_account_info(accounts_iter)?;
@@ -546,6 +607,8 @@ pub fn process_call_contract_with_interchain_token(
     let its_event_authority = next_account_info(accounts_iter)?;
     let gateway_event_authority = next_account_info(accounts_iter)?;
     let gas_service_event_authority = next_account_info(accounts_iter)?;
+    let its_outbound_nonce = next_account_info(accounts_iter)?;
+    let its_token_observer = next_account_info(accounts_iter)?;
 
     let counter_pda_account = counter_pda.check_initialized_pda::<Counter>(program_id)?;
     assert_counter_pda_seeds(&counter_pda_account, counter_pda.key);
@@ -599,6 +662,7 @@ pub fn process_call_contract_with_interchain_token(
             gateway_root_pda.clone(),
             gateway_program_account.clone(),
             gas_service_root_pda.clone(),
+            gas_service_paused_chains_pda.clone(),
             gas_service_program_account.clone(),
             system_program.clone(),
             its_root_pda.clone(),
@@ -607,6 +671,8 @@ pub fn process_call_contract_with_interchain_token(
             its_event_authority.clone(),
             gateway_event_authority.clone(),
             gas_service_event_authority.clone(),
+            its_outbound_nonce.clone(),
+            its_token_observer.clone(),
         ],
         &[&[&[counter_bump]]],
     )?;
@@ -0,0 +1,158 @@
+use std::str::FromStr;
+
+use axelar_solana_gateway::events::MessageExecutedEvent;
+use axelar_solana_gateway::executable::{EncodingScheme, GatewayCall, AXELAR_GATEWAY_CALL};
+use axelar_solana_gateway::get_incoming_message_pda;
+use axelar_solana_gateway::instructions::execute_and_call;
+use axelar_solana_gateway::state::incoming_message;
+use axelar_solana_gateway_test_fixtures::base::FindLog;
+use axelar_solana_gateway_test_fixtures::gateway::random_message;
+use axelar_solana_memo_program::instruction::from_axelar_to_solana::build_memo;
+use axelar_solana_memo_program::state::Counter;
+use borsh::BorshDeserialize;
+use event_cpi_test_utils::assert_event_cpi;
+use solana_program_test::tokio;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::program_test;
+
+#[tokio::test]
+async fn test_successful_execute_and_call() {
+    // Setup
+    let mut solana_chain = program_test().await;
+    let (counter_pda, counter_bump) = axelar_solana_memo_program::get_counter_pda();
+    solana_chain
+        .fixture
+        .send_tx(&[axelar_solana_memo_program::instruction::initialize(
+            &solana_chain.fixture.payer.pubkey(),
+            &(counter_pda, counter_bump),
+        )
+        .unwrap()])
+        .await
+        .unwrap();
+
+    let random_account_used_by_ix = Keypair::new();
+    let message_payload = build_memo(
+        "🐪🐪🐪🐪".as_bytes(),
+        &counter_pda,
+        &[&random_account_used_by_ix.pubkey()],
+        EncodingScheme::Borsh,
+    );
+    let raw_payload = message_payload.encode().unwrap();
+    let mut message = random_message();
+    message.destination_address = axelar_solana_memo_program::id().to_string();
+    message.payload_hash = *message_payload.hash().unwrap().0;
+
+    solana_chain
+        .sign_session_and_approve_messages(&solana_chain.signers.clone(), &[message.clone()])
+        .await
+        .unwrap();
+    solana_chain
+        .upload_message_payload(&message, &raw_payload)
+        .await
+        .unwrap();
+
+    // Action: the gateway executes the message and calls the memo program
+    let ix = execute_and_call(solana_chain.payer.pubkey(), message.clone(), &raw_payload).unwrap();
+    let simulation_result = solana_chain.simulate_tx(&[ix.clone()]).await.unwrap();
+    let inner_ixs = simulation_result
+        .simulation_details
+        .unwrap()
+        .inner_instructions
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap();
+    let command_id = incoming_message::command_id(&message.cc_id.chain, &message.cc_id.id);
+    assert_event_cpi(
+        &MessageExecutedEvent {
+            command_id,
+            source_chain: message.cc_id.chain.clone(),
+            cc_id: message.cc_id.id.clone(),
+            source_address: message.source_address.clone(),
+            destination_address: Pubkey::from_str(&message.destination_address).unwrap(),
+            payload_hash: message.payload_hash,
+            destination_chain: message.destination_chain.clone(),
+        },
+        &inner_ixs,
+    );
+    let tx = solana_chain.send_tx(&[ix]).await.unwrap();
+
+    // Assert
+    let (incoming_message_pda, ..) = get_incoming_message_pda(&command_id);
+    let incoming_message = solana_chain.incoming_message(incoming_message_pda).await;
+    assert!(incoming_message.status.is_executed());
+    assert!(
+        tx.find_log("🐪🐪🐪🐪").is_some(),
+        "expected memo not found in logs"
+    );
+
+    let counter_account = solana_chain
+        .fixture
+        .get_account(&counter_pda, &axelar_solana_memo_program::id())
+        .await;
+    let counter = Counter::try_from_slice(&counter_account.data).unwrap();
+    assert_eq!(counter.counter, 1);
+
+    // The message cannot be executed twice
+    let ix = execute_and_call(solana_chain.payer.pubkey(), message, &raw_payload).unwrap();
+    assert!(solana_chain.send_tx(&[ix]).await.is_err());
+}
+
+#[tokio::test]
+async fn test_gateway_call_cannot_be_forged() {
+    // Setup
+    let mut solana_chain = program_test().await;
+    let (counter_pda, counter_bump) = axelar_solana_memo_program::get_counter_pda();
+    solana_chain
+        .fixture
+        .send_tx(&[axelar_solana_memo_program::instruction::initialize(
+            &solana_chain.fixture.payer.pubkey(),
+            &(counter_pda, counter_bump),
+        )
+        .unwrap()])
+        .await
+        .unwrap();
+
+    let message_payload = build_memo(
+        "forged".as_bytes(),
+        &counter_pda,
+        &[],
+        EncodingScheme::Borsh,
+    );
+    let mut message = random_message();
+    message.destination_address = axelar_solana_memo_program::id().to_string();
+    message.payload_hash = *message_payload.hash().unwrap().0;
+    solana_chain
+        .sign_session_and_approve_messages(&solana_chain.signers.clone(), &[message.clone()])
+        .await
+        .unwrap();
+
+    // Action: call the memo program directly, pretending to be the gateway
+    let (incoming_message_pda, ..) = get_incoming_message_pda(&incoming_message::command_id(
+        &message.cc_id.chain,
+        &message.cc_id.id,
+    ));
+    let mut data = AXELAR_GATEWAY_CALL.to_vec();
+    borsh::to_writer(
+        &mut data,
+        &GatewayCall {
+            message,
+            payload: message_payload.payload_without_accounts().to_vec(),
+        },
+    )
+    .unwrap();
+    let mut accounts = vec![AccountMeta::new_readonly(incoming_message_pda, false)];
+    accounts.extend(message_payload.account_meta());
+    let ix = Instruction {
+        program_id: axelar_solana_memo_program::id(),
+        accounts,
+        data,
+    };
+    let res = solana_chain.send_tx(&[ix]).await;
+
+    // Assert
+    assert!(res.is_err());
+}
@@ -2,6 +2,7 @@ use axelar_solana_gateway_test_fixtures::{
     SolanaAxelarIntegration, SolanaAxelarIntegrationMetadata,
 };
 
+mod execute_and_call;
 mod initialize;
 mod send_to_gateway;
 mod validate_message;